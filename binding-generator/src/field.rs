@@ -18,6 +18,8 @@ use crate::{constant, DefaultElement, Element, GeneratorEnv, StrExt};
 pub enum FieldTypeHint<'tu, 'ge> {
 	None,
 	ArgOverride(ArgOverride),
+	/// Length of the automatically detected byte buffer argument, contains the Rust name of that argument
+	LenForByteSlice(Rc<str>),
 	Specialized(Rc<TypeRef<'tu, 'ge>>),
 }
 
@@ -118,11 +120,11 @@ impl<'tu, 'ge> Field<'tu, 'ge> {
 			&& self.type_ref().is_void_ptr()
 	}
 
-	pub fn as_slice_len(&self) -> Option<(&'static str, usize)> {
-		if let &FieldTypeHint::ArgOverride(ArgOverride::LenForSlice(ptr_arg, len_div)) = self.type_hint() {
-			Some((ptr_arg, len_div))
-		} else {
-			None
+	pub fn as_slice_len(&self) -> Option<(&str, usize)> {
+		match self.type_hint() {
+			&FieldTypeHint::ArgOverride(ArgOverride::LenForSlice(ptr_arg, len_div)) => Some((ptr_arg, len_div)),
+			FieldTypeHint::LenForByteSlice(ptr_arg) => Some((ptr_arg, 1)),
+			_ => None,
 		}
 	}
 }
@@ -14,8 +14,8 @@ use crate::element::{ExcludeKind, UNNAMED};
 use crate::entity::WalkAction;
 use crate::field::FieldDesc;
 use crate::name_pool::NamePool;
-use crate::settings::TypeRefFactory;
-use crate::type_ref::{Constness, CppNameStyle, TypeRefTypeHint};
+use crate::settings::{ArgOverride, TypeRefFactory};
+use crate::type_ref::{Constness, CppNameStyle, Dir, FishStyle, StrType, TypeRefTypeHint};
 use crate::writer::rust_native::element::RustElement;
 use crate::writer::rust_native::type_ref::TypeRefExt;
 use crate::{
@@ -348,7 +348,7 @@ impl<'tu, 'ge> Func<'tu, 'ge> {
 		match self {
 			&Self::Clang { entity, gen_env, .. } => {
 				let arg_overrides = settings::ARGUMENT_OVERRIDE.get(&self.func_id());
-				self
+				let mut out = self
 					.clang_arguments(entity)
 					.into_iter()
 					.map(|a| {
@@ -359,7 +359,13 @@ impl<'tu, 'ge> Func<'tu, 'ge> {
 
 						Field::new(a, gen_env)
 					})
-					.collect()
+					.collect::<Vec<_>>();
+				// explicitly overridden arguments have a type hint and are left untouched
+				mark_byte_slice_args(&mut out, gen_env);
+				let cpp_refname = self.cpp_name(CppNameStyle::Reference);
+				mark_path_args(&mut out, &cpp_refname, gen_env);
+				mark_mat_type_args(&mut out, &cpp_refname, gen_env);
+				out.into()
 			}
 			Self::Desc(desc) => desc.arguments.as_ref().into(),
 		}
@@ -950,6 +956,87 @@ impl fmt::Display for FuncId<'_> {
 	}
 }
 
+/// Detects `const char* buffer, size_t len` argument pairs and marks them so that they're passed as a single `&[u8]`
+fn mark_byte_slice_args<'tu, 'ge>(args: &mut [Field<'tu, 'ge>], gen_env: &'ge GeneratorEnv<'tu>) {
+	for len_idx in 1..args.len() {
+		let buf_idx = len_idx - 1;
+		let (buf, len) = (&args[buf_idx], &args[len_idx]);
+		if let (&Field::Clang { entity: buf_entity, .. }, &Field::Clang { entity: len_entity, .. }) = (buf, len) {
+			let is_byte_slice = matches!(buf.type_hint(), FieldTypeHint::None)
+				&& matches!(len.type_hint(), FieldTypeHint::None)
+				&& buf.type_ref().is_const_byte_ptr()
+				&& len.type_ref().is_size_t()
+				&& is_buffer_len_name(&len.cpp_name(CppNameStyle::Declaration));
+			if is_byte_slice {
+				let buf_rust_name = buf.rust_leafname(FishStyle::No).into_owned();
+				args[buf_idx] = Field::new_ext(buf_entity, FieldTypeHint::ArgOverride(ArgOverride::ByteSlice), gen_env);
+				args[len_idx] = Field::new_ext(len_entity, FieldTypeHint::LenForByteSlice(buf_rust_name.into()), gen_env);
+			}
+		}
+	}
+}
+
+/// Checks whether the C++ argument name looks like a length of the preceding buffer argument, e.g. `lenModel`,
+/// `sizeBuffer` or `bufferModelConfigSize`
+pub fn is_buffer_len_name(name: &str) -> bool {
+	let name = name.to_lowercase();
+	name.starts_with("len")
+		|| name.ends_with("len")
+		|| name.ends_with("length")
+		|| name.starts_with("size")
+		|| name.ends_with("size")
+}
+
+/// Marks input string arguments that hold a filesystem path so that they're passed as `impl AsRef<Path>`, those are either
+/// named like one (see [is_path_arg_name]) and not listed in `settings::NOT_PATH_ARGS` or listed in `settings::PATH_ARGS`
+fn mark_path_args<'tu, 'ge>(args: &mut [Field<'tu, 'ge>], func_cpp_name: &str, gen_env: &'ge GeneratorEnv<'tu>) {
+	for arg in args {
+		if let &mut Field::Clang {
+			entity,
+			type_hint: FieldTypeHint::None,
+			..
+		} = arg
+		{
+			let arg_name = arg.cpp_name(CppNameStyle::Declaration);
+			let arg_id = (func_cpp_name, arg_name.as_ref());
+			let is_path = matches!(
+				arg.type_ref().as_string(),
+				Some(Dir::In(StrType::StdString(_) | StrType::CvString(_)))
+			) && ((is_path_arg_name(&arg_name) && !settings::NOT_PATH_ARGS.contains(&arg_id))
+				|| settings::PATH_ARGS.contains(&arg_id));
+			if is_path {
+				*arg = Field::new_ext(entity, FieldTypeHint::ArgOverride(ArgOverride::StringAsPath), gen_env);
+			}
+		}
+	}
+}
+
+/// Checks whether the C++ argument name unambiguously holds a filesystem path: `filename`, `filepath` or `path` in any
+/// letter case and with optional underscores, e.g. `fileName` or `file_path`
+pub fn is_path_arg_name(name: &str) -> bool {
+	matches!(
+		name.replace('_', "").to_lowercase().as_str(),
+		"filename" | "filepath" | "path"
+	)
+}
+
+/// Marks int arguments listed in `settings::MAT_TYPE_ARGS` so that they're passed as `impl Into<core::MatType>`
+fn mark_mat_type_args<'tu, 'ge>(args: &mut [Field<'tu, 'ge>], func_cpp_name: &str, gen_env: &'ge GeneratorEnv<'tu>) {
+	for arg in args {
+		if let &mut Field::Clang {
+			entity,
+			type_hint: FieldTypeHint::None,
+			..
+		} = arg
+		{
+			let arg_name = arg.cpp_name(CppNameStyle::Declaration);
+			if arg.type_ref().is_int() && settings::MAT_TYPE_ARGS.contains(&(func_cpp_name, arg_name.as_ref())) {
+				*arg = Field::new_ext(entity, FieldTypeHint::ArgOverride(ArgOverride::MatType), gen_env);
+			}
+		}
+	}
+}
+
 fn cpp_method_call_name(extern_by_ptr: bool, method_name: &str) -> String {
 	if extern_by_ptr {
 		format!("instance->{method_name}")
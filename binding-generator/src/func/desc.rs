@@ -4,8 +4,8 @@ use std::rc::Rc;
 use crate::debug::DefinitionLocation;
 use crate::field::Field;
 use crate::func::ReturnKind;
-use crate::type_ref::{Constness, TypeRef, TypeRefDesc};
-use crate::{Class, Func, FuncTypeHint};
+use crate::type_ref::{Constness, CppNameStyle, TypeRef, TypeRefDesc};
+use crate::{settings, Class, Element, Func, FuncTypeHint};
 
 use super::FuncKind;
 
@@ -55,6 +55,10 @@ impl<'tu, 'ge> FuncDesc<'tu, 'ge> {
 	}
 
 	pub fn method_delete(rust_local: &str, class_desc: Class<'tu, 'ge>) -> Func<'tu, 'ge> {
+		let delete_call = settings::CLASS_DELETE_MANUAL
+			.get(class_desc.cpp_name(CppNameStyle::Reference).as_ref())
+			.copied()
+			.unwrap_or("delete instance");
 		Func::new_desc(FuncDesc::new(
 			FuncKind::InstanceMethod(class_desc),
 			Constness::Mut,
@@ -62,7 +66,7 @@ impl<'tu, 'ge> FuncDesc<'tu, 'ge> {
 			format!("cv::{rust_local}::delete"),
 			"<unused>",
 			vec![],
-			FuncCppBody::ManualCall("delete instance".into()),
+			FuncCppBody::ManualCall(delete_call.into()),
 			TypeRefDesc::void(),
 		))
 	}
@@ -777,15 +777,23 @@ pub static FUNC_MANUAL: Lazy<HashMap<&str, CompiledInterpolation>> = Lazy::new(|
 		),
 		(
 			"cv_Mat_at_const_intX",
-			include_str!("../tpl/settings/rust_mat_at_mut.tpl.rs").compile_interpolation(),
+			include_str!("../tpl/settings/rust_mat_at_nd_mut.tpl.rs").compile_interpolation(),
 		),
 		(
 			"cv_Mat_at_const_const_intX",
-			include_str!("../tpl/settings/rust_mat_at_const.tpl.rs").compile_interpolation(),
+			include_str!("../tpl/settings/rust_mat_at_nd_const.tpl.rs").compile_interpolation(),
 		),
 	])
 });
 
+/// cpp_refname => manual C++ call to use instead of `delete instance` in the destructor of the boxed class
+pub static CLASS_DELETE_MANUAL: Lazy<HashMap<&str, &str>> = Lazy::new(|| {
+	HashMap::from([
+		("cv::_InputArray", "ocvrs_input_array_delete(instance)"),
+		("cv::_OutputArray", "ocvrs_output_array_delete(instance)"),
+	])
+});
+
 pub static TYPES: Lazy<fn() -> TypeRef<'static, 'static>> = Lazy::new(|| TypeRefDesc::void);
 
 pub type TypeRefFactory = fn() -> TypeRef<'static, 'static>;
@@ -971,12 +979,187 @@ pub enum ArgOverride {
 	Nullable,
 	NullableSlice,
 	Slice,
+	// `const char*`/`const uchar*` buffer that is passed as `&[u8]`, the length is derived from the slice
+	ByteSlice,
 	LenForSlice(&'static str, usize),
 	StringAsBytes,
+	// string argument that holds a filesystem path, passed as `impl AsRef<Path>`
+	StringAsPath,
+	// int argument that holds the Mat element type, passed as `impl Into<core::MatType>`
+	MatType,
 	// when C++ char needs to be represented as Rust char
 	Char8AsChar,
 }
 
+/// (func cpp_refname, argument cpp name) of the int arguments that hold the Mat element type, they are passed as
+/// `impl Into<core::MatType>`
+pub static MAT_TYPE_ARGS: Lazy<HashSet<(&str, &str)>> = Lazy::new(|| {
+	HashSet::from([
+		("cv::Mat::Mat", "type"),
+		("cv::Mat::assignTo", "type"),
+		("cv::Mat::convertTo", "rtype"),
+		("cv::Mat::create", "type"),
+		("cv::Mat::eye", "type"),
+		("cv::Mat::ones", "type"),
+		("cv::Mat::zeros", "type"),
+		("cv::MatOp::assign", "type"),
+		("cv::SparseMat::Hdr::Hdr", "_type"),
+		("cv::SparseMat::SparseMat", "_type"),
+		("cv::SparseMat::assignTo", "type"),
+		("cv::SparseMat::convertTo", "rtype"),
+		("cv::SparseMat::create", "_type"),
+		("cv::UMat::UMat", "type"),
+		("cv::UMat::assignTo", "type"),
+		("cv::UMat::convertTo", "rtype"),
+		("cv::UMat::create", "type"),
+		("cv::UMat::eye", "type"),
+		("cv::UMat::ones", "type"),
+		("cv::UMat::zeros", "type"),
+		("cv::_OutputArray::create", "type"),
+		("cv::_OutputArray::createSameSize", "mtype"),
+		("cv::add", "dtype"),
+		("cv::addWeighted", "dtype"),
+		("cv::batchDistance", "dtype"),
+		("cv::cuda::BufferPool::getBuffer", "type"),
+		("cv::cuda::GpuMat::GpuMat", "type"),
+		("cv::cuda::GpuMat::assignTo", "type"),
+		("cv::cuda::GpuMat::convertTo", "rtype"),
+		("cv::cuda::GpuMat::create", "type"),
+		("cv::cuda::GpuMatND::GpuMatND", "type"),
+		("cv::cuda::GpuMatND::create", "type"),
+		("cv::cuda::HostMem::HostMem", "type"),
+		("cv::cuda::HostMem::create", "type"),
+		("cv::cuda::add", "dtype"),
+		("cv::cuda::addWeighted", "dtype"),
+		("cv::cuda::createContinuous", "type"),
+		("cv::cuda::createGpuMatFromCudaMemory", "type"),
+		("cv::cuda::divide", "dtype"),
+		("cv::cuda::ensureSizeIsEnough", "type"),
+		("cv::cuda::multiply", "dtype"),
+		("cv::cuda::normalize", "dtype"),
+		("cv::cuda::reduce", "dtype"),
+		("cv::cuda::subtract", "dtype"),
+		("cv::divide", "dtype"),
+		("cv::gapi::boxFilter", "dtype"),
+		("cv::getDerivKernels", "ktype"),
+		("cv::getElemSize", "type"),
+		("cv::getGaborKernel", "ktype"),
+		("cv::getGaussianKernel", "ktype"),
+		("cv::mulTransposed", "dtype"),
+		("cv::multiply", "dtype"),
+		("cv::normalize", "dtype"),
+		("cv::ocl::convertFromBuffer", "type"),
+		("cv::reduce", "dtype"),
+		("cv::subtract", "dtype"),
+		("cv::typeToString", "type"),
+		("cv::utils::generateVectorOfMat", "dtype"),
+	])
+});
+
+/// (func cpp_refname, argument cpp name) of the string arguments that are named like a filesystem path, but can hold something
+/// else, e.g. `FileStorage` takes the document text in `filename` when opened with `FileStorage::MEMORY`
+pub static NOT_PATH_ARGS: Lazy<HashSet<(&str, &str)>> = Lazy::new(|| {
+	HashSet::from([
+		("cv::FileStorage::FileStorage", "filename"),
+		("cv::FileStorage::open", "filename"),
+	])
+});
+
+/// (func cpp_refname, argument cpp name) of the string arguments that hold a filesystem path, but are not named like one
+pub static PATH_ARGS: Lazy<HashSet<(&str, &str)>> = Lazy::new(|| {
+	HashSet::from([
+		("cv::FaceDetectorYN::create", "config"),
+		("cv::FaceDetectorYN::create", "model"),
+		("cv::FaceRecognizerSF::create", "config"),
+		("cv::FaceRecognizerSF::create", "model"),
+		("cv::barcode::BarcodeDetector::BarcodeDetector", "model_path"),
+		("cv::barcode::BarcodeDetector::BarcodeDetector", "prototxt_path"),
+		("cv::bioinspired::Retina::setup", "retinaParameterFile"),
+		(
+			"cv::bioinspired::TransientAreasSegmentationModule::setup",
+			"segmentationParameterFile",
+		),
+		("cv::dnn::ClassificationModel::ClassificationModel", "config"),
+		("cv::dnn::ClassificationModel::ClassificationModel", "model"),
+		("cv::dnn::DetectionModel::DetectionModel", "config"),
+		("cv::dnn::DetectionModel::DetectionModel", "model"),
+		("cv::dnn::KeypointsModel::KeypointsModel", "config"),
+		("cv::dnn::KeypointsModel::KeypointsModel", "model"),
+		("cv::dnn::Model::Model", "config"),
+		("cv::dnn::Model::Model", "model"),
+		("cv::dnn::Net::readFromModelOptimizer", "bin"),
+		("cv::dnn::Net::readFromModelOptimizer", "xml"),
+		("cv::dnn::SegmentationModel::SegmentationModel", "config"),
+		("cv::dnn::SegmentationModel::SegmentationModel", "model"),
+		("cv::dnn::TextDetectionModel_DB::TextDetectionModel_DB", "config"),
+		("cv::dnn::TextDetectionModel_DB::TextDetectionModel_DB", "model"),
+		("cv::dnn::TextDetectionModel_EAST::TextDetectionModel_EAST", "config"),
+		("cv::dnn::TextDetectionModel_EAST::TextDetectionModel_EAST", "model"),
+		("cv::dnn::TextRecognitionModel::TextRecognitionModel", "config"),
+		("cv::dnn::TextRecognitionModel::TextRecognitionModel", "model"),
+		("cv::dnn::readNet", "config"),
+		("cv::dnn::readNet", "model"),
+		("cv::dnn::readNetFromCaffe", "caffeModel"),
+		("cv::dnn::readNetFromCaffe", "prototxt"),
+		("cv::dnn::readNetFromDarknet", "cfgFile"),
+		("cv::dnn::readNetFromDarknet", "darknetModel"),
+		("cv::dnn::readNetFromModelOptimizer", "bin"),
+		("cv::dnn::readNetFromModelOptimizer", "xml"),
+		("cv::dnn::readNetFromONNX", "onnxFile"),
+		("cv::dnn::readNetFromTFLite", "model"),
+		("cv::dnn::readNetFromTensorflow", "config"),
+		("cv::dnn::readNetFromTensorflow", "model"),
+		("cv::dnn::readNetFromTorch", "model"),
+		("cv::dnn::shrinkCaffeModel", "dst"),
+		("cv::dnn::shrinkCaffeModel", "src"),
+		("cv::dnn::writeTextGraph", "model"),
+		("cv::dnn::writeTextGraph", "output"),
+		("cv::face::CParams::CParams", "cascade_model"),
+		("cv::face::Facemark::loadModel", "model"),
+		("cv::face::FacemarkKazemi::training", "configfile"),
+		("cv::face::FacemarkKazemi::training", "modelFilename"),
+		("cv::face::getFacesHAAR", "face_cascade_name"),
+		("cv::freetype::FreeType2::loadFontData", "fontFileName"),
+		("cv::hdf::open", "HDF5Filename"),
+		("cv::quality::QualityBRISQUE::compute", "model_file_path"),
+		("cv::quality::QualityBRISQUE::compute", "range_file_path"),
+		("cv::quality::QualityBRISQUE::create", "model_file_path"),
+		("cv::quality::QualityBRISQUE::create", "range_file_path"),
+		("cv::saliency::ObjectnessBING::setBBResDir", "resultsDir"),
+		("cv::saliency::ObjectnessBING::setTrainingPath", "trainingPath"),
+		("cv::samples::addSamplesDataSearchSubDirectory", "subdir"),
+		("cv::samples::findFile", "relative_path"),
+		("cv::samples::findFileOrKeep", "relative_path"),
+		("cv::sfm::importReconstruction", "file"),
+		("cv::stereo::QuasiDenseStereo::create", "paramFilepath"),
+		("cv::text::OCRHolisticWordRecognizer::create", "archFilename"),
+		("cv::text::OCRHolisticWordRecognizer::create", "weightsFilename"),
+		("cv::text::OCRHolisticWordRecognizer::create", "wordsFilename"),
+		("cv::text::TextDetectorCNN::create", "modelArchFilename"),
+		("cv::text::TextDetectorCNN::create", "modelWeightsFilename"),
+		("cv::viz::Mesh::load", "file"),
+		("cv::viz::Viz3d::saveScreenshot", "file"),
+		("cv::viz::readCloud", "file"),
+		("cv::viz::readMesh", "file"),
+		("cv::viz::readPose", "file"),
+		("cv::viz::writeCloud", "file"),
+		("cv::viz::writePose", "file"),
+		("cv::wechat_qrcode::WeChatQRCode::WeChatQRCode", "detector_caffe_model_path"),
+		("cv::wechat_qrcode::WeChatQRCode::WeChatQRCode", "detector_prototxt_path"),
+		(
+			"cv::wechat_qrcode::WeChatQRCode::WeChatQRCode",
+			"super_resolution_caffe_model_path",
+		),
+		(
+			"cv::wechat_qrcode::WeChatQRCode::WeChatQRCode",
+			"super_resolution_prototxt_path",
+		),
+		("cv::ximgproc::createStructuredEdgeDetection", "model"),
+		("cv::ximgproc::readGT", "src_path"),
+		("cv::xphoto::createLearningBasedWB", "path_to_model"),
+	])
+});
+
 pub static ARGUMENT_OVERRIDE: Lazy<HashMap<FuncId, HashMap<&str, ArgOverride>>> = Lazy::new(|| {
 	HashMap::from([
 		(
@@ -1204,6 +1387,115 @@ pub static PREVENT_VECTOR_TYPEDEF_GENERATION: Lazy<HashSet<&str>> = Lazy::new(||
 	])
 });
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CallbackSlot {
+	/// callback is stored by OpenCV in the slot identified by the window name argument and the optional callback name argument,
+	/// the previous callback stored in the same slot is dropped
+	Set {
+		window: Option<&'static str>,
+		name: Option<&'static str>,
+	},
+	/// all callbacks of the window specified by the argument are dropped
+	ReleaseWindow(&'static str),
+	/// all stored callbacks are dropped
+	ReleaseAll,
+}
+
+/// Functions that take ownership of the passed callbacks or release them, the callbacks passed to other functions are leaked
+///
+/// Only the global highgui window callbacks are tracked here. The following setters store the callback in the object they are
+/// called on, the lifetime of which is not visible to the generated code, so their callbacks are leaked on purpose:
+/// * `cv::cuda::Stream::enqueueHostCallback`
+/// * `cv::face::Facemark*::setFaceDetector`
+/// * `cv::viz::Viz3d::registerKeyboardCallback` and `cv::viz::Viz3d::registerMouseCallback`
+///
+/// `cv::redirectError` is implemented manually, see `src/manual/core/error_handler.rs`.
+pub static CALLBACK_SLOT: Lazy<HashMap<FuncId, CallbackSlot>> = Lazy::new(|| {
+	HashMap::from([
+		(
+			FuncId::new("cv::setMouseCallback", ["winname", "onMouse", "userdata"]),
+			CallbackSlot::Set {
+				window: Some("winname"),
+				name: None,
+			},
+		),
+		(
+			FuncId::new(
+				"cv::createTrackbar",
+				["trackbarname", "winname", "value", "count", "onChange", "userdata"],
+			),
+			CallbackSlot::Set {
+				window: Some("winname"),
+				name: Some("trackbarname"),
+			},
+		),
+		(
+			FuncId::new(
+				"cv::createButton",
+				["bar_name", "on_change", "userdata", "type", "initial_button_state"],
+			),
+			CallbackSlot::Set {
+				window: None,
+				name: Some("bar_name"),
+			},
+		),
+		(
+			FuncId::new("cv::setOpenGlDrawCallback", ["winname", "onOpenGlDraw", "userdata"]),
+			CallbackSlot::Set {
+				window: Some("winname"),
+				name: None,
+			},
+		),
+		(
+			FuncId::new("cv::destroyWindow", ["winname"]),
+			CallbackSlot::ReleaseWindow("winname"),
+		),
+		(FuncId::new("cv::destroyAllWindows", []), CallbackSlot::ReleaseAll),
+	])
+});
+
+#[derive(Default)]
+pub struct DirectorClass {
+	/// non-pure virtual methods that should also be forwarded to Rust
+	pub forward_methods: Vec<FuncId<'static>>,
+	/// pointer arguments that are passed to Rust as slices, maps argument name to the C++ expression that returns the slice
+	/// length, the expression is evaluated in the context of the C++ subclass
+	pub slice_len: HashMap<&'static str, &'static str>,
+}
+
+/// Classes that can be implemented in Rust, elements are Class.cpp_name(Reference)
+///
+/// For every such class the generator emits a C++ subclass that forwards all of its pure virtual methods (and the additionally
+/// specified ones) to the Rust trait object.
+pub static DIRECTOR_CLASSES: Lazy<HashMap<&str, DirectorClass>> = Lazy::new(|| {
+	HashMap::from([
+		("cv::ParallelLoopBody", DirectorClass::default()),
+		(
+			"cv::MinProblemSolver::Function",
+			DirectorClass {
+				slice_len: HashMap::from([("x", "this->getDims()"), ("grad", "this->getDims()")]),
+				..Default::default()
+			},
+		),
+		("cv::LMSolver::Callback", DirectorClass::default()),
+		(
+			"cv::dnn::Layer",
+			DirectorClass {
+				forward_methods: vec![
+					FuncId::new("cv::dnn::Layer::forward", ["inputs", "outputs", "internals"]),
+					FuncId::new(
+						"cv::dnn::Layer::getMemoryShapes",
+						["inputs", "requiredOutputs", "outputs", "internals"],
+					),
+				],
+				..Default::default()
+			},
+		),
+		("cv::videostab::IFrameSource", DirectorClass::default()),
+		("cv::cudacodec::EncoderCallback", DirectorClass::default()),
+	])
+});
+
 #[derive(Default)]
 pub struct ModuleTweak {
 	pub includes: Vec<&'static str>,
@@ -1245,7 +1537,8 @@ pub static GENERATOR_MODULE_TWEAKS: Lazy<HashMap<&str, ModuleTweak>> = Lazy::new
 			ModuleTweak {
 				includes: vec!["core.hpp"],
 				generate_types: vec![
-					"cv::Ptr<float>", // for 3.2, no function uses that so it's not generated
+					"cv::Ptr<float>",                // for 3.2, no function uses that so it's not generated
+					"cv::Ptr<cv::ParallelLoopBody>", // for ParallelLoopBody::from_director
 				],
 			},
 		),
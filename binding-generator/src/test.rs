@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use regex::Regex;
 
+use crate::func::{is_buffer_len_name, is_path_arg_name};
 use crate::string_ext::Indent;
 use crate::type_ref::FishStyle;
 use crate::{StrExt, StringExt};
@@ -475,3 +476,31 @@ fn fish_apply() {
 	assert_eq!("crate::VecN", FishStyle::No.apply(rust_no_generics_fullname));
 	assert_eq!("crate::VecN", FishStyle::Turbo.apply(rust_no_generics_fullname));
 }
+
+#[test]
+fn buffer_len_name() {
+	assert!(is_buffer_len_name("lenProto"));
+	assert!(is_buffer_len_name("len"));
+	assert!(is_buffer_len_name("bufLen"));
+	assert!(is_buffer_len_name("sizeBuffer"));
+	assert!(is_buffer_len_name("bufferModelConfigSize"));
+	assert!(is_buffer_len_name("size"));
+	assert!(!is_buffer_len_name("line"));
+	assert!(!is_buffer_len_name("filename"));
+	assert!(!is_buffer_len_name("count"));
+}
+
+#[test]
+fn path_arg_name() {
+	assert!(is_path_arg_name("filename"));
+	assert!(is_path_arg_name("fileName"));
+	assert!(is_path_arg_name("file_path"));
+	assert!(is_path_arg_name("path"));
+	assert!(!is_path_arg_name("cfgFile"));
+	assert!(!is_path_arg_name("model"));
+	assert!(!is_path_arg_name("config"));
+	assert!(!is_path_arg_name("file"));
+	assert!(!is_path_arg_name("framework"));
+	assert!(!is_path_arg_name("winname"));
+	assert!(!is_path_arg_name("encoding"));
+}
@@ -306,7 +306,7 @@ impl<'tu, 'ge> TypeRef<'tu, 'ge> {
 					}
 				}
 			}
-			TypeRefKind::Array(inner, ..) => {
+			TypeRefKind::Array(inner, ..) if !self.is_byte_slice() => {
 				let inner_cpp_ref = inner.cpp_name(CppNameStyle::Reference);
 				if inner_cpp_ref == "char" || inner_cpp_ref == "const char" {
 					return Some(Dir::In(StrType::CharPtr));
@@ -325,6 +325,12 @@ impl<'tu, 'ge> TypeRef<'tu, 'ge> {
 		matches!(self.as_string(), Some(Dir::In(StrType::CvString(_))))
 	}
 
+	/// True for input string arguments that hold a filesystem path
+	pub fn is_path(&self) -> bool {
+		matches!(self.type_hint(), TypeRefTypeHint::ArgOverride(ArgOverride::StringAsPath))
+			&& matches!(self.as_string(), Some(Dir::In(StrType::StdString(_) | StrType::CvString(_))))
+	}
+
 	pub fn is_char_ptr_string(&self) -> bool {
 		matches!(self.as_string(), Some(Dir::In(StrType::CharPtr)))
 	}
@@ -365,10 +371,38 @@ impl<'tu, 'ge> TypeRef<'tu, 'ge> {
 		}
 	}
 
+	/// True for `const char*` and `const unsigned char*` pointers that can be used to pass a binary data buffer
+	pub fn is_const_byte_ptr(&self) -> bool {
+		self.as_pointer().map_or(false, |inner| {
+			inner.clang_constness().is_const()
+				&& matches!(
+					inner.canonical().kind().as_ref(),
+					TypeRefKind::Primitive(_, "char" | "unsigned char")
+				)
+		})
+	}
+
+	pub fn is_byte_slice(&self) -> bool {
+		matches!(self.type_hint(), TypeRefTypeHint::ArgOverride(ArgOverride::ByteSlice))
+	}
+
+	pub fn is_size_t(&self) -> bool {
+		matches!(self.canonical().kind().as_ref(), TypeRefKind::Primitive(_, "size_t"))
+	}
+
 	pub fn is_void(&self) -> bool {
 		matches!(self.canonical().kind().as_ref(), TypeRefKind::Primitive(_, "void"))
 	}
 
+	pub fn is_int(&self) -> bool {
+		matches!(self.canonical().kind().as_ref(), TypeRefKind::Primitive(_, "int"))
+	}
+
+	/// True for int arguments that hold the Mat element type
+	pub fn is_mat_type(&self) -> bool {
+		matches!(self.type_hint(), TypeRefTypeHint::ArgOverride(ArgOverride::MatType)) && self.is_int()
+	}
+
 	pub fn is_bool(&self) -> bool {
 		matches!(self.canonical().kind().as_ref(), TypeRefKind::Primitive(_, "bool"))
 	}
@@ -764,6 +798,12 @@ impl fmt::Debug for TypeRef<'_, '_> {
 				}
 			}
 		}
+		if self.is_path() {
+			props.push("path");
+		}
+		if self.is_mat_type() {
+			props.push("mat_type");
+		}
 		if self.is_input_array() {
 			props.push("input_array");
 		}
@@ -788,6 +828,9 @@ impl fmt::Debug for TypeRef<'_, '_> {
 		if self.is_nullable() {
 			props.push("nullable");
 		}
+		if self.is_byte_slice() {
+			props.push("byte_slice");
+		}
 		if self.is_data_type() {
 			props.push("data_type");
 		}
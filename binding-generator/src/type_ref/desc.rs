@@ -120,6 +120,9 @@ impl<'tu> ClangTypeExt<'tu> for Type<'tu> {
 		match self.get_kind() {
 			TypeKind::Void => TypeRefKind::Primitive("()", "void"),
 			TypeKind::Bool => TypeRefKind::Primitive("bool", "bool"),
+			TypeKind::CharS if matches!(type_hint, TypeRefTypeHint::ArgOverride(ArgOverride::ByteSlice)) => {
+				TypeRefKind::Primitive("u8", "char")
+			}
 			TypeKind::CharS => TypeRefKind::Primitive("i8", "char"),
 			TypeKind::CharU => TypeRefKind::Primitive("u8", "char"),
 			TypeKind::SChar => TypeRefKind::Primitive("i8", "signed char"),
@@ -147,7 +150,7 @@ impl<'tu> ClangTypeExt<'tu> for Type<'tu> {
 					pointee_typeref.kind().into_owned()
 				} else if matches!(
 					type_hint,
-					TypeRefTypeHint::ArgOverride(ArgOverride::Slice | ArgOverride::NullableSlice)
+					TypeRefTypeHint::ArgOverride(ArgOverride::Slice | ArgOverride::NullableSlice | ArgOverride::ByteSlice)
 				) {
 					TypeRefKind::Array(pointee_typeref, None)
 				} else {
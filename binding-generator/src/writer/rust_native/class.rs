@@ -11,6 +11,7 @@ use crate::type_ref::{Constness, CppNameStyle, ExternDir, FishStyle, NameStyle,
 use crate::writer::rust_native::func::cpp_return_map;
 use crate::{settings, Class, CompiledInterpolation, Element, Func, IteratorExt, NamePool, StrExt};
 
+use super::director::{director_settings, gen_cpp_director, gen_rust_director};
 use super::element::{DefaultRustNativeElement, RustElement};
 use super::type_ref::TypeRefExt;
 use super::RustNativeGeneratedElement;
@@ -469,9 +470,14 @@ impl RustNativeGeneratedElement for Class<'_, '_> {
 	}
 
 	fn gen_rust(&self, opencv_version: &str) -> String {
-		match self.kind() {
+		let out = match self.kind() {
 			ClassKind::Simple | ClassKind::Boxed | ClassKind::BoxedForced => gen_rust_class(self, opencv_version),
 			ClassKind::System | ClassKind::Other => "".to_string(),
+		};
+		if let Some(director) = director_settings(self) {
+			out + &gen_rust_director(self, director)
+		} else {
+			out
 		}
 	}
 
@@ -519,6 +525,10 @@ impl RustNativeGeneratedElement for Class<'_, '_> {
 			}
 		}
 
+		if let Some(director) = director_settings(self) {
+			cpp_methods.push(gen_cpp_director(self, director));
+		}
+
 		out + &cpp_methods.join("")
 	}
 }
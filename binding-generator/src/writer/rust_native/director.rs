@@ -0,0 +1,470 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+use crate::settings::DirectorClass;
+use crate::type_ref::{Constness, CppNameStyle, FishStyle, NameStyle, TypeRef};
+use crate::{settings, Class, CompiledInterpolation, Element, Func, NamePool, StrExt};
+
+use super::element::RustElement;
+use super::type_ref::TypeRefExt;
+
+/// How a single argument of the forwarded method is passed from C++ to Rust
+enum DirectorArg<'tu, 'ge> {
+	/// primitives and enums, passed by value
+	Value(TypeRef<'tu, 'ge>),
+	/// simple classes, passed by pointer and received as reference
+	Simple(TypeRef<'tu, 'ge>),
+	/// boxed classes, vectors and smart pointers, the Rust side gets a non-owning wrapper around the C++ object
+	Boxed(TypeRef<'tu, 'ge>, Constness),
+	/// `std::string` and `cv::String`, received as `&str`
+	Str,
+	/// pointer to primitives with the length specified in the settings, received as slice
+	Slice(TypeRef<'tu, 'ge>, Constness, &'static str),
+}
+
+impl<'tu, 'ge> DirectorArg<'tu, 'ge> {
+	fn new(type_ref: TypeRef<'tu, 'ge>, name: &str, director: &DirectorClass) -> Option<Self> {
+		if type_ref.is_std_string() || type_ref.is_cv_string() {
+			return Some(Self::Str);
+		}
+		let (inner, is_ref) = type_ref
+			.as_reference()
+			.map_or_else(|| (type_ref.clone(), false), |inner| (inner, true));
+		// mutable references to values can't be forwarded because the changes wouldn't propagate back to C++
+		let is_out_value = is_ref && inner.constness().is_mut();
+		if inner.is_primitive() || inner.is_enum() {
+			if is_out_value {
+				None
+			} else {
+				Some(Self::Value(inner))
+			}
+		} else if inner.as_simple_class().is_some() {
+			if is_out_value {
+				None
+			} else {
+				Some(Self::Simple(inner))
+			}
+		} else if inner.as_class().is_some() || inner.as_vector().is_some() || inner.as_smart_ptr().is_some() {
+			let constness = if is_ref {
+				inner.constness()
+			} else {
+				Constness::Const
+			};
+			Some(Self::Boxed(inner, constness))
+		} else if let Some(pointee) = inner.as_pointer().filter(|p| p.is_primitive()) {
+			director
+				.slice_len
+				.get(name)
+				.map(|&len| Self::Slice(pointee.clone(), pointee.constness(), len))
+		} else {
+			None
+		}
+	}
+
+	fn rust_trait_arg(&self, name: &str) -> String {
+		match self {
+			Self::Value(typ) => format!("{name}: {}", typ.rust_name(NameStyle::ref_())),
+			Self::Simple(typ) => format!("{name}: &{}", typ.rust_name(NameStyle::ref_())),
+			Self::Boxed(typ, constness) => format!(
+				"{name}: &{cnst}{typ}",
+				cnst = constness.rust_qual(false),
+				typ = typ.rust_name(NameStyle::ref_())
+			),
+			Self::Str => format!("{name}: &str"),
+			Self::Slice(typ, constness, _) => format!(
+				"{name}: &{cnst}[{typ}]",
+				cnst = constness.rust_qual(false),
+				typ = typ.rust_name(NameStyle::ref_())
+			),
+		}
+	}
+
+	fn rust_extern_arg(&self, name: &str) -> String {
+		match self {
+			Self::Value(typ) => format!("{name}: {}", typ.rust_name(NameStyle::ref_())),
+			Self::Simple(typ) => format!("{name}: *const {}", typ.rust_name(NameStyle::ref_())),
+			Self::Boxed(..) => format!("{name}: *mut c_void"),
+			Self::Str => format!("{name}: *const c_char"),
+			Self::Slice(typ, constness, _) => format!(
+				"{name}: *{cnst}{typ}, {name}_len: size_t",
+				cnst = constness.rust_qual(true),
+				typ = typ.rust_name(NameStyle::ref_())
+			),
+		}
+	}
+
+	fn rust_pre_call(&self, name: &str) -> String {
+		match self {
+			Self::Value(_) | Self::Simple(_) => "".to_string(),
+			Self::Boxed(typ, constness) => format!(
+				"let {mut_}{name} = ::std::mem::ManuallyDrop::new(<{typ}>::from_raw({name}));",
+				mut_ = if constness.is_mut() {
+					"mut "
+				} else {
+					""
+				},
+				typ = typ.rust_name(NameStyle::ref_())
+			),
+			Self::Str => format!("let {name} = ::std::ffi::CStr::from_ptr({name}).to_string_lossy();"),
+			Self::Slice(_, constness, _) => format!(
+				"let {name} = ::std::slice::from_raw_parts{sfx}({name}, {name}_len);",
+				sfx = if constness.is_mut() {
+					"_mut"
+				} else {
+					""
+				}
+			),
+		}
+	}
+
+	fn rust_call(&self, name: &str) -> String {
+		match self {
+			Self::Value(_) | Self::Slice(..) => name.to_string(),
+			Self::Simple(_) => format!("&*{name}"),
+			Self::Boxed(_, constness) => format!("&{cnst}*{name}", cnst = constness.rust_qual(false)),
+			Self::Str => format!("&{name}"),
+		}
+	}
+
+	fn cpp_extern_arg(&self, name: &str) -> String {
+		match self {
+			Self::Value(typ) => typ
+				.with_constness(Constness::Mut)
+				.cpp_name_ext(CppNameStyle::Reference, name, false)
+				.into_owned(),
+			Self::Simple(typ) => format!(
+				"const {typ}* {name}",
+				typ = typ.with_constness(Constness::Mut).cpp_name(CppNameStyle::Reference)
+			),
+			Self::Boxed(..) => format!("void* {name}"),
+			Self::Str => format!("const char* {name}"),
+			Self::Slice(typ, constness, _) => format!(
+				"{cnst}{typ}* {name}, size_t {name}_len",
+				cnst = constness.cpp_qual(),
+				typ = typ.with_constness(Constness::Mut).cpp_name(CppNameStyle::Reference)
+			),
+		}
+	}
+
+	fn cpp_call(&self, name: &str) -> String {
+		match self {
+			Self::Value(_) => name.to_string(),
+			Self::Simple(_) => format!("&{name}"),
+			Self::Boxed(..) => format!("const_cast<void*>(static_cast<const void*>(&{name}))"),
+			Self::Str => format!("{name}.c_str()"),
+			Self::Slice(_, _, len) => format!("{name}, {len}"),
+		}
+	}
+}
+
+/// How the return value of the forwarded method is passed from Rust to C++
+enum DirectorReturn<'tu, 'ge> {
+	Void,
+	/// primitives, enums and simple classes, passed by value
+	Value(TypeRef<'tu, 'ge>),
+	/// boxed classes, ownership of the heap allocated object is passed to C++
+	Boxed(TypeRef<'tu, 'ge>),
+}
+
+impl<'tu, 'ge> DirectorReturn<'tu, 'ge> {
+	fn new(type_ref: TypeRef<'tu, 'ge>) -> Option<Self> {
+		if type_ref.is_void() {
+			Some(Self::Void)
+		} else if type_ref.as_reference().is_some() || type_ref.as_pointer().is_some() || type_ref.as_string().is_some() {
+			None
+		} else if type_ref.is_primitive() || type_ref.is_enum() || type_ref.as_simple_class().is_some() {
+			Some(Self::Value(type_ref))
+		} else if type_ref.as_class().is_some() || type_ref.as_vector().is_some() || type_ref.as_smart_ptr().is_some() {
+			Some(Self::Boxed(type_ref))
+		} else {
+			None
+		}
+	}
+
+	fn rust_trait_return(&self) -> String {
+		match self {
+			Self::Void => "Result<()>".to_string(),
+			Self::Value(typ) | Self::Boxed(typ) => format!("Result<{}>", typ.rust_name(NameStyle::ref_())),
+		}
+	}
+
+	fn rust_extern_return(&self) -> Cow<str> {
+		match self {
+			Self::Void => "()".into(),
+			Self::Value(typ) => typ.rust_name(NameStyle::ref_()),
+			Self::Boxed(_) => "*mut c_void".into(),
+		}
+	}
+
+	fn rust_return_map(&self) -> &'static str {
+		match self {
+			Self::Void | Self::Value(_) => "",
+			Self::Boxed(_) => ".map(|ret| ret.into_raw())",
+		}
+	}
+
+	fn cpp_extern_return(&self) -> String {
+		match self {
+			Self::Void => "Result_void".to_string(),
+			Self::Value(typ) => format!("Result<{}>", typ.cpp_name_ext(CppNameStyle::Reference, "", false)),
+			Self::Boxed(_) => "Result<void*>".to_string(),
+		}
+	}
+
+	fn cpp_return(&self) -> String {
+		match self {
+			Self::Void => "".to_string(),
+			Self::Value(_) => "return ocvrs_return.result;".to_string(),
+			Self::Boxed(typ) => format!(
+				"return ocvrs_director_receive<{typ}>(ocvrs_return.result);",
+				typ = typ.cpp_name_ext(CppNameStyle::Reference, "", false)
+			),
+		}
+	}
+}
+
+/// Method of the director class that is forwarded to the Rust implementation
+struct DirectorMethod<'tu, 'ge> {
+	func: Func<'tu, 'ge>,
+	rust_name: String,
+	args: Vec<(String, String, DirectorArg<'tu, 'ge>)>,
+	ret: DirectorReturn<'tu, 'ge>,
+}
+
+/// Returns settings for the class if it's a director class, i.e. it can be implemented in Rust
+pub fn director_settings(c: &Class) -> Option<&'static DirectorClass> {
+	if c.kind().is_boxed() && c.has_virtual_destructor() {
+		settings::DIRECTOR_CLASSES.get(c.cpp_name(CppNameStyle::Reference).as_ref())
+	} else {
+		None
+	}
+}
+
+/// Collects the virtual methods of the class that need to be overridden, returns the supported ones and the C++ signatures
+/// of the pure virtual methods that can't be forwarded to Rust
+fn director_methods<'tu, 'ge>(
+	c: &Class<'tu, 'ge>,
+	director: &DirectorClass,
+) -> (Vec<DirectorMethod<'tu, 'ge>>, Vec<Func<'tu, 'ge>>) {
+	let own_methods = c.methods(None);
+	let mut funcs = own_methods
+		.iter()
+		.filter(|m| m.is_abstract() || director.forward_methods.contains(&m.func_id()))
+		.cloned()
+		.collect::<Vec<_>>();
+	for base in c.all_bases() {
+		funcs.extend(base.methods(None).into_iter().filter(|m| {
+			m.is_abstract()
+				&& !own_methods
+					.iter()
+					.any(|own| own.cpp_name(CppNameStyle::Declaration) == m.cpp_name(CppNameStyle::Declaration))
+		}));
+	}
+	let mut name_pool = NamePool::with_capacity(funcs.len());
+	let mut supported = Vec::with_capacity(funcs.len());
+	let mut unsupported = vec![];
+	'funcs: for func in funcs {
+		let ret = if let Some(ret) = DirectorReturn::new(func.return_type_ref()) {
+			ret
+		} else {
+			unsupported.push(func);
+			continue;
+		};
+		let mut args = Vec::with_capacity(func.num_arguments());
+		for arg in func.arguments().iter() {
+			let cpp_name = arg.cpp_name(CppNameStyle::Declaration).into_owned();
+			if let Some(director_arg) = DirectorArg::new(arg.type_ref(), &cpp_name, director) {
+				args.push((arg.rust_leafname(FishStyle::No).into_owned(), cpp_name, director_arg));
+			} else {
+				unsupported.push(func);
+				continue 'funcs;
+			}
+		}
+		let mut rust_name = func.rust_leafname(FishStyle::No);
+		name_pool.make_unique_name(&mut rust_name);
+		supported.push(DirectorMethod {
+			rust_name: rust_name.into_owned(),
+			func,
+			args,
+			ret,
+		});
+	}
+	(supported, unsupported)
+}
+
+pub fn gen_rust_director(c: &Class, director: &DirectorClass) -> String {
+	static TPL: Lazy<CompiledInterpolation> = Lazy::new(|| include_str!("tpl/director/rust.tpl.rs").compile_interpolation());
+
+	static METHOD_TPL: Lazy<CompiledInterpolation> =
+		Lazy::new(|| include_str!("tpl/director/rust_method.tpl.rs").compile_interpolation());
+
+	let rust_local = c.rust_name(NameStyle::decl());
+	let rust_full = c.rust_name(NameStyle::ref_());
+	let rust_director_full = format!("{rust_full}Director");
+
+	let (methods, _) = director_methods(c, director);
+	let mut trait_methods = String::new();
+	let mut extern_methods = Vec::with_capacity(methods.len());
+	for method in methods {
+		let self_arg = if method.func.constness().is_const() {
+			"&self"
+		} else {
+			"&mut self"
+		};
+		let trait_args = method
+			.args
+			.iter()
+			.map(|(name, _, arg)| format!(", {}", arg.rust_trait_arg(name)))
+			.collect::<String>();
+		trait_methods += &format!(
+			"fn {name}({self_arg}{trait_args}) -> {ret};\n",
+			name = method.rust_name,
+			ret = method.ret.rust_trait_return(),
+		);
+		let extern_args = method
+			.args
+			.iter()
+			.map(|(name, _, arg)| format!("{}, ", arg.rust_extern_arg(name)))
+			.collect::<String>();
+		let pre_call_args = method
+			.args
+			.iter()
+			.map(|(name, _, arg)| arg.rust_pre_call(name))
+			.filter(|s| !s.is_empty())
+			.collect::<Vec<_>>()
+			.join("\n");
+		let call_args = method
+			.args
+			.iter()
+			.map(|(name, _, arg)| arg.rust_call(name))
+			.collect::<Vec<_>>()
+			.join(", ");
+		let (self_qual, self_qual_ref) = if method.func.constness().is_const() {
+			("const", "")
+		} else {
+			("mut", "mut ")
+		};
+		extern_methods.push(METHOD_TPL.interpolate(&HashMap::from([
+			("rust_local", rust_local.as_ref()),
+			("rust_director_full", &rust_director_full),
+			("method_name", &method.rust_name),
+			("self_qual", self_qual),
+			("self_qual_ref", self_qual_ref),
+			("extern_args", &extern_args),
+			("extern_return", &method.ret.rust_extern_return()),
+			("pre_call_args", &pre_call_args),
+			("call_args", &call_args),
+			("return_map", method.ret.rust_return_map()),
+		])));
+	}
+
+	TPL.interpolate(&HashMap::from([
+		("rust_local", rust_local.as_ref()),
+		("rust_full", rust_full.as_ref()),
+		("rust_director_full", &rust_director_full),
+		("trait_methods", &trait_methods),
+		("extern_methods", &extern_methods.join("\n")),
+	]))
+}
+
+pub fn gen_cpp_director(c: &Class, director: &DirectorClass) -> String {
+	static TPL: Lazy<CompiledInterpolation> = Lazy::new(|| include_str!("tpl/director/cpp.tpl.cpp").compile_interpolation());
+
+	static METHOD_TPL: Lazy<CompiledInterpolation> =
+		Lazy::new(|| include_str!("tpl/director/cpp_method.tpl.cpp").compile_interpolation());
+
+	let rust_local = c.rust_name(NameStyle::decl());
+	let cpp_full = c.cpp_name(CppNameStyle::Reference);
+
+	let (methods, unsupported) = director_methods(c, director);
+	let mut extern_methods = String::new();
+	let mut cpp_methods = String::new();
+	for method in methods {
+		let cnst = if method.func.constness().is_const() {
+			" const"
+		} else {
+			""
+		};
+		let extern_args = method
+			.args
+			.iter()
+			.map(|(_, name, arg)| format!("{}, ", arg.cpp_extern_arg(name)))
+			.collect::<String>();
+		let ocvrs_return_type = method.ret.cpp_extern_return();
+		extern_methods += &format!(
+			"void ocvrs_director_{rust_local}_{name}({cnst_self}void* ocvrs_self, {extern_args}{ocvrs_return_type}* ocvrs_return);\n",
+			name = method.rust_name,
+			cnst_self = if cnst.is_empty() {
+				""
+			} else {
+				"const "
+			},
+		);
+		let decl_args = method
+			.func
+			.arguments()
+			.iter()
+			.map(|arg| {
+				arg.type_ref()
+					.cpp_name_ext(CppNameStyle::Reference, &arg.cpp_name(CppNameStyle::Declaration), false)
+					.into_owned()
+			})
+			.collect::<Vec<_>>()
+			.join(", ");
+		let call_args = method
+			.args
+			.iter()
+			.map(|(_, name, arg)| format!("{}, ", arg.cpp_call(name)))
+			.collect::<String>();
+		let return_type = method
+			.func
+			.return_type_ref()
+			.cpp_name_ext(CppNameStyle::Reference, "", false)
+			.into_owned();
+		cpp_methods += &METHOD_TPL.interpolate(&HashMap::from([
+			("return_type", return_type.as_str()),
+			("cpp_name", &method.func.cpp_name(CppNameStyle::Declaration)),
+			("cpp_full", &method.func.cpp_name(CppNameStyle::Reference)),
+			("decl_args", &decl_args),
+			("cnst", cnst),
+			("ocvrs_return_type", &ocvrs_return_type),
+			("rust_local", &rust_local),
+			("method_name", &method.rust_name),
+			("call_args", &call_args),
+			("return", &method.ret.cpp_return()),
+		]));
+	}
+	// pure virtual methods with unsupported signatures must still be overridden for the subclass to be instantiable
+	for func in unsupported.into_iter().filter(|f| f.is_abstract()) {
+		let decl_args = func
+			.arguments()
+			.iter()
+			.map(|arg| {
+				arg.type_ref()
+					.cpp_name_ext(CppNameStyle::Reference, &arg.cpp_name(CppNameStyle::Declaration), false)
+					.into_owned()
+			})
+			.collect::<Vec<_>>()
+			.join(", ");
+		cpp_methods += &format!(
+			"{ret} {name}({decl_args}){cnst} CV_OVERRIDE {{\n\tCV_Error(cv::Error::StsNotImplemented, \"{cpp_full} can't be implemented in Rust\");\n}}\n\n",
+			ret = func.return_type_ref().cpp_name_ext(CppNameStyle::Reference, "", false),
+			name = func.cpp_name(CppNameStyle::Declaration),
+			cnst = if func.constness().is_const() {
+				" const"
+			} else {
+				""
+			},
+			cpp_full = func.cpp_name(CppNameStyle::Reference),
+		);
+	}
+
+	TPL.interpolate(&HashMap::from([
+		("rust_local", rust_local.as_ref()),
+		("cpp_full", cpp_full.as_ref()),
+		("extern_methods", &extern_methods),
+		("methods", &cpp_methods),
+	]))
+}
@@ -7,6 +7,7 @@ use regex::Regex;
 
 use crate::field::Field;
 use crate::func::{cpp_disambiguate_names, OperatorKind, ReturnKind, Safety};
+use crate::settings::CallbackSlot;
 use crate::type_ref::{Constness, CppNameStyle, Dir, ExternDir, FishStyle, NameStyle, StrEnc, StrType, TypeRef};
 use crate::writer::rust_native::disambiguate_single_name;
 use crate::{reserved_rename, settings, CompiledInterpolation, Element, Func, IteratorExt, NameDebug, StrExt, StringExt};
@@ -241,11 +242,44 @@ impl RustNativeGeneratedElement for Func<'_, '_> {
 			decl_args.push(cls_type_ref.rust_self_func_decl(constness));
 			call_args.push(cls_type_ref.rust_self_func_call(constness));
 		}
+		// handling of the callbacks whose ownership is tracked after the call, see `settings::CALLBACK_SLOT`
+		let (callback_register, callback_release) = if let Some(slot) = settings::CALLBACK_SLOT.get(&self.func_id()) {
+			let rust_arg_bytes = |cpp_name: Option<&str>| {
+				cpp_name.map_or_else(
+					|| "b\"\"".to_string(),
+					|cpp_name| {
+						let rust_name = args
+							.iter()
+							.find(|(_, arg)| arg.cpp_name(CppNameStyle::Declaration) == cpp_name)
+							.map(|(name, _)| name.as_str())
+							.expect("Can't find callback slot argument");
+						format!("{rust_name}.as_bytes()")
+					},
+				)
+			};
+			match *slot {
+				CallbackSlot::Set { window, name } => (
+					Some(format!(
+						"callback_register!(callbacks, ret => \"{slot_name}\", {window}, {name});",
+						slot_name = self.cpp_name(CppNameStyle::Reference),
+						window = rust_arg_bytes(window),
+						name = rust_arg_bytes(name),
+					)),
+					None,
+				),
+				CallbackSlot::ReleaseWindow(window) => (None, Some(format!("callback_release!({})", rust_arg_bytes(Some(window))))),
+				CallbackSlot::ReleaseAll => (None, Some("callback_release!()".to_string())),
+			}
+		} else {
+			(None, None)
+		};
 		let mut callback_arg_name: Option<String> = None;
+		let mut has_userdata = false;
 		for (name, arg) in args {
 			let arg_type_ref = arg.type_ref();
 			let arg_as_slice_len = arg.as_slice_len();
 			if arg.is_user_data() {
+				has_userdata = true;
 				pre_post_arg_handle(
 					arg.type_ref().rust_userdata_pre_call(
 						&name,
@@ -281,6 +315,9 @@ impl RustNativeGeneratedElement for Func<'_, '_> {
 				&mut post_call_args,
 			);
 		}
+		if let Some(callback_release) = callback_release {
+			pre_post_arg_handle(callback_release, &mut post_call_args);
+		}
 		if !return_kind.is_naked() {
 			pre_call_args.push("return_send!(via ocvrs_return);".to_string());
 			call_args.push("ocvrs_return.as_mut_ptr()".to_string());
@@ -325,6 +362,15 @@ impl RustNativeGeneratedElement for Func<'_, '_> {
 			};
 			ret_convert.push(Cow::Borrowed(spec));
 		}
+		// the callback must be registered or leaked before the panic in it is resumed or the error is returned, OpenCV can
+		// already hold it
+		if has_userdata {
+			ret_convert.push(
+				callback_register
+					.unwrap_or_else(|| "callback_register!(callbacks, ret);".to_string())
+					.into(),
+			);
+		}
 		if !return_kind.is_infallible() {
 			ret_convert.push("let ret = ret.into_result()?;".into())
 		}
@@ -23,6 +23,7 @@ use crate::{
 mod class;
 mod comment;
 mod constant;
+mod director;
 pub mod element;
 mod enumeration;
 mod field;
@@ -77,6 +77,9 @@ impl RustNativeGeneratedElement for SmartPtr<'_, '_> {
 		static CTOR_TPL: Lazy<CompiledInterpolation> =
 			Lazy::new(|| include_str!("tpl/smart_ptr/ctor.tpl.rs").compile_interpolation());
 
+		static DESCENDANT_CAST_TPL: Lazy<CompiledInterpolation> =
+			Lazy::new(|| include_str!("tpl/smart_ptr/descendant_cast.tpl.rs").compile_interpolation());
+
 		let rust_localalias = self.rust_localalias();
 		let rust_full = self.rust_name(NameStyle::ref_());
 		let pointee_type = self.pointee();
@@ -92,7 +95,8 @@ impl RustNativeGeneratedElement for SmartPtr<'_, '_> {
 		)
 		.identifier();
 		let extern_get_inner_ptr_mut =
-			method_get_inner_ptr(smartptr_class, &rust_localalias, pointee_type.clone(), Constness::Mut).identifier();
+			method_get_inner_ptr(smartptr_class.clone(), &rust_localalias, pointee_type.clone(), Constness::Mut).identifier();
+		let extern_clone = method_clone(smartptr_class, &rust_localalias, type_ref.clone()).identifier();
 
 		let mut inter_vars = HashMap::from([
 			("rust_localalias", rust_localalias.clone()),
@@ -104,6 +108,7 @@ impl RustNativeGeneratedElement for SmartPtr<'_, '_> {
 			("extern_delete", extern_delete.into()),
 			("extern_get_inner_ptr", extern_get_inner_ptr.into()),
 			("extern_get_inner_ptr_mut", extern_get_inner_ptr_mut.into()),
+			("extern_clone", extern_clone.into()),
 		]);
 
 		let mut impls = String::new();
@@ -138,6 +143,9 @@ impl RustNativeGeneratedElement for SmartPtr<'_, '_> {
 				impls += &TRAIT_RAW_TPL.interpolate(&inter_vars);
 				if gen_env.is_used_in_smart_ptr(base.cpp_name(CppNameStyle::Reference).as_ref()) {
 					impls += &BASE_CAST_TPL.interpolate(&inter_vars);
+					if base.is_polymorphic() {
+						impls += &DESCENDANT_CAST_TPL.interpolate(&inter_vars);
+					}
 				}
 				let base_fields = base.fields();
 				let base_field_const_methods = base.field_methods(
@@ -184,6 +192,7 @@ impl RustNativeGeneratedElement for SmartPtr<'_, '_> {
 			&method_get_inner_ptr(smartptr_class.clone(), &rust_localalias, pointee_type.clone(), Constness::Mut).gen_rust_exports(),
 		);
 		out.push_str(&FuncDesc::method_delete(&rust_localalias, smartptr_class.clone()).gen_rust_exports());
+		out.push_str(&method_clone(smartptr_class.clone(), &rust_localalias, type_ref.clone()).gen_rust_exports());
 		if let Some(cls) = pointee_type.as_class().filter(Class::is_trait) {
 			let gen_env = self.gen_env();
 			for base in all_bases(&cls)
@@ -200,6 +209,18 @@ impl RustNativeGeneratedElement for SmartPtr<'_, '_> {
 					)
 					.gen_rust_exports(),
 				);
+				if base.is_polymorphic() {
+					out.push_str(
+						&method_cast_to_descendant(
+							base.type_ref(),
+							pointee_type.clone(),
+							&rust_localalias,
+							&base.rust_name(NameStyle::decl()),
+							self.gen_env(),
+						)
+						.gen_rust_exports(),
+					);
+				}
 			}
 		}
 		if gen_ctor(&pointee_type) {
@@ -229,6 +250,7 @@ impl RustNativeGeneratedElement for SmartPtr<'_, '_> {
 		methods
 			.push(method_get_inner_ptr(smartptr_class.clone(), &rust_localalias, pointee_type.clone(), Constness::Mut).gen_cpp());
 		methods.push(FuncDesc::method_delete(&rust_localalias, smartptr_class.clone()).gen_cpp());
+		methods.push(method_clone(smartptr_class.clone(), &rust_localalias, type_ref.clone()).gen_cpp());
 		if let Some(cls) = pointee_type.as_class().filter(Class::is_trait) {
 			let gen_env = self.gen_env();
 			for base in all_bases(&cls)
@@ -245,6 +267,18 @@ impl RustNativeGeneratedElement for SmartPtr<'_, '_> {
 					)
 					.gen_cpp(),
 				);
+				if base.is_polymorphic() {
+					methods.push(
+						method_cast_to_descendant(
+							base.type_ref(),
+							pointee_type.clone(),
+							&rust_localalias,
+							&base.rust_name(NameStyle::decl()),
+							self.gen_env(),
+						)
+						.gen_cpp(),
+					);
+				}
 			}
 		}
 
@@ -342,6 +376,59 @@ fn method_cast_to_base<'tu, 'ge>(
 	))
 }
 
+fn method_cast_to_descendant<'tu, 'ge>(
+	base_type_ref: TypeRef<'tu, 'ge>,
+	pointee_type: TypeRef<'tu, 'ge>,
+	rust_localalias: &str,
+	base_rust_local: &str,
+	gen_env: &'ge GeneratorEnv<'tu>,
+) -> Func<'tu, 'ge> {
+	let base_smartptr_class = smartptr_class(&TypeRef::new_desc(TypeRefDesc::new(TypeRefKind::SmartPtr(
+		SmartPtr::new_desc(SmartPtrDesc {
+			pointee_type_ref: base_type_ref,
+			gen_env,
+		}),
+	))));
+	// returns null if the object is not of the descendant type
+	let cpp_body = FuncCppBody::ManualFull(
+		format!(
+			"{{{{ret_type}}}} ret = instance->dynamicCast<{descendant_type}>();\n\treturn ret ? new {{{{ret_type}}}}(ret) : nullptr;",
+			descendant_type = pointee_type.cpp_name(CppNameStyle::Reference)
+		)
+		.into(),
+	);
+	Func::new_desc(FuncDesc::new(
+		FuncKind::InstanceMethod(base_smartptr_class),
+		Constness::Mut,
+		ReturnKind::InfallibleNaked,
+		format!("cv::PtrOf{base_rust_local}::to_{rust_localalias}"),
+		"<unused>",
+		vec![],
+		cpp_body,
+		TypeRef::new_desc(TypeRefDesc::new(TypeRefKind::SmartPtr(SmartPtr::new_desc(SmartPtrDesc {
+			pointee_type_ref: pointee_type,
+			gen_env,
+		})))),
+	))
+}
+
+fn method_clone<'tu, 'ge>(
+	smartptr_class: Class<'tu, 'ge>,
+	rust_localalias: &str,
+	smartptr_type_ref: TypeRef<'tu, 'ge>,
+) -> Func<'tu, 'ge> {
+	Func::new_desc(FuncDesc::new(
+		FuncKind::InstanceMethod(smartptr_class),
+		Constness::Const,
+		ReturnKind::InfallibleNaked,
+		format!("cv::{rust_localalias}::clone"),
+		"<unused>",
+		vec![],
+		FuncCppBody::ManualFull("return new {{ret_type}}(*instance);".into()),
+		smartptr_type_ref,
+	))
+}
+
 fn method_get_inner_ptr<'tu, 'ge>(
 	smartptr_class: Class<'tu, 'ge>,
 	rust_localalias: &str,
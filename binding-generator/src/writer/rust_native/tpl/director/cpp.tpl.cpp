@@ -0,0 +1,23 @@
+void ocvrs_director_{{rust_local}}_delete(void* ocvrs_self);
+{{extern_methods}}
+
+class cv_{{rust_local}}_Director : public {{cpp_full}} {
+	void* ocvrs_self;
+public:
+	explicit cv_{{rust_local}}_Director(void* ocvrs_self) : ocvrs_self(ocvrs_self) {}
+
+	~cv_{{rust_local}}_Director() {
+		ocvrs_director_{{rust_local}}_delete(ocvrs_self);
+	}
+
+	{{methods}}
+};
+
+{{cpp_full}}* cv_{{rust_local}}_director_new(void* ocvrs_self) {
+	return new cv_{{rust_local}}_Director(ocvrs_self);
+}
+
+cv::Ptr<{{cpp_full}}>* cv_PtrOf{{rust_local}}_director_new(void* ocvrs_self) {
+	return new cv::Ptr<{{cpp_full}}>(new cv_{{rust_local}}_Director(ocvrs_self));
+}
+
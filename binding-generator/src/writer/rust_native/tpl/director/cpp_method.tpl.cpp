@@ -0,0 +1,7 @@
+{{return_type}} {{cpp_name}}({{decl_args}}){{cnst}} CV_OVERRIDE {
+	{{ocvrs_return_type}} ocvrs_return;
+	ocvrs_director_{{rust_local}}_{{method_name}}(ocvrs_self, {{call_args}}&ocvrs_return);
+	ocvrs_director_rethrow(ocvrs_return, "{{cpp_full}}");
+	{{return}}
+}
+
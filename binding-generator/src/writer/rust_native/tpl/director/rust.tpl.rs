@@ -0,0 +1,34 @@
+/// Rust implementation of the virtual methods of [{{rust_full}}]
+///
+/// Use [{{rust_full}}::from_director] or [core::Ptr::<{{rust_full}}>::from_director] to get an OpenCV object that forwards
+/// the calls to this implementation. Panics and errors returned from the methods are passed to OpenCV as `cv::Exception`.
+pub trait {{rust_local}}Director: Send + Sync {
+	{{trait_methods}}
+}
+
+impl {{rust_local}} {
+	/// Creates [{{rust_full}}] that forwards its virtual method calls to the passed Rust implementation
+	#[inline]
+	pub fn from_director(director: Box<dyn {{rust_director_full}}>) -> Self {
+		extern "C" { fn cv_{{rust_local}}_director_new(ocvrs_self: *mut c_void) -> *mut c_void; }
+		let ocvrs_self = Box::into_raw(Box::new(director)) as *mut c_void;
+		unsafe { Self::from_raw(cv_{{rust_local}}_director_new(ocvrs_self)) }
+	}
+}
+
+impl core::Ptr<{{rust_full}}> {
+	/// Creates [{{rust_full}}] that forwards its virtual method calls to the passed Rust implementation
+	#[inline]
+	pub fn from_director(director: Box<dyn {{rust_director_full}}>) -> Self {
+		extern "C" { fn cv_PtrOf{{rust_local}}_director_new(ocvrs_self: *mut c_void) -> *mut c_void; }
+		let ocvrs_self = Box::into_raw(Box::new(director)) as *mut c_void;
+		unsafe { Self::from_raw(cv_PtrOf{{rust_local}}_director_new(ocvrs_self)) }
+	}
+}
+
+#[no_mangle]
+unsafe extern "C" fn ocvrs_director_{{rust_local}}_delete(ocvrs_self: *mut c_void) {
+	crate::templ::director_delete(ocvrs_self as *mut Box<dyn {{rust_director_full}}>);
+}
+
+{{extern_methods}}
@@ -0,0 +1,7 @@
+#[no_mangle]
+unsafe extern "C" fn ocvrs_director_{{rust_local}}_{{method_name}}(ocvrs_self: *{{self_qual}} c_void, {{extern_args}}ocvrs_return: *mut sys::Result<{{extern_return}}>) {
+	let ocvrs_self = &{{self_qual_ref}}*(ocvrs_self as *{{self_qual}} Box<dyn {{rust_director_full}}>);
+	{{pre_call_args}}
+	crate::templ::director_call(ocvrs_return, || ocvrs_self.{{method_name}}({{call_args}}){{return_map}});
+}
+
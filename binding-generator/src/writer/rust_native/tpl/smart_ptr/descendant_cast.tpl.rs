@@ -0,0 +1,2 @@
+ptr_cast_descendant! { core::Ptr<{{base_rust_full_ref}}>, {{rust_localalias}}, cv_PtrOf{{base_rust_local}}_to_{{rust_localalias}} }
+
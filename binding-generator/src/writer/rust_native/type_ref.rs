@@ -143,7 +143,11 @@ impl TypeRefExt for TypeRef<'_, '_> {
 	fn rust_arg_func_decl(&self, name: &str) -> String {
 		#[allow(clippy::never_loop)] // fixme use named block when MSRV is 1.65
 		let typ = 'decl_type: loop {
-			if let Some(dir) = self.as_string() {
+			if self.is_path() {
+				break 'decl_type "impl AsRef<std::path::Path>".into();
+			} else if self.is_mat_type() {
+				break 'decl_type "impl Into<core::MatType>".into();
+			} else if let Some(dir) = self.as_string() {
 				break 'decl_type match dir {
 					Dir::In(StrType::StdString(StrEnc::Text) | StrType::CvString(StrEnc::Text) | StrType::CharPtr) => "&str".into(),
 					Dir::In(StrType::StdString(StrEnc::Binary) | StrType::CvString(StrEnc::Binary)) => "&[u8]".into(),
@@ -185,7 +189,7 @@ impl TypeRefExt for TypeRef<'_, '_> {
 	}
 
 	fn rust_arg_pre_call(&self, name: &str, is_function_infallible: bool) -> String {
-		let extern_container_arg = || {
+		let extern_container_arg = |macro_name: &str| {
 			let mut flags = vec![];
 			if is_function_infallible {
 				flags.push("nofail")
@@ -197,16 +201,19 @@ impl TypeRefExt for TypeRef<'_, '_> {
 			if !flags.is_empty() {
 				flags.push(' ');
 			}
-			format!("extern_container_arg!({flags}{name})")
+			format!("{macro_name}!({flags}{name})")
 		};
 
 		if let Some(dir) = self.as_string() {
 			return match dir {
-				Dir::In(_) => extern_container_arg(),
+				Dir::In(_) if self.is_path() => extern_container_arg("path_arg"),
+				Dir::In(_) => extern_container_arg("extern_container_arg"),
 				Dir::Out(_) => {
 					format!("string_arg_output_send!(via {name}_via)")
 				}
 			};
+		} else if self.is_mat_type() {
+			return format!("let {name} = i32::from({name}.into());");
 		} else if self.is_input_array() {
 			return format!("input_array_arg!({name})");
 		} else if self.is_output_array() {
@@ -344,6 +351,9 @@ impl TypeRefExt for TypeRef<'_, '_> {
 	}
 
 	fn rust_arg_post_call(&self, name: &str, _is_function_infallible: bool) -> String {
+		if self.is_output_array() {
+			return format!("output_array_finish!({name})");
+		}
 		match self.as_string() {
 			Some(Dir::Out(StrType::StdString(StrEnc::Text) | StrType::CvString(StrEnc::Text) | StrType::CharPtr)) => {
 				format!("string_arg_output_receive!({name}_via => {name})")
@@ -0,0 +1,5 @@
+{{doc_comment}}
+{{debug}}
+{{visibility}}fn {{name}}<T: core::MatNdElement + ?Sized>({{decl_args}}) -> Result<&mut T> { core::mat_forward::{{name}}(self, {{forward_args}}) }
+
+
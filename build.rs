@@ -142,6 +142,24 @@ fn get_module_header_dir(header_dir: &Path) -> Option<PathBuf> {
 	None
 }
 
+/// Revisions of OpenCV 4 that the manual code depends on, `ocvrs_opencv_since_4_<minor>_<revision>` cfg is emitted for each
+/// of them that is not newer than the used OpenCV version
+static OPENCV_4_REVISIONS: [(u64, u64); 1] = [(5, 2)];
+
+/// Emits `ocvrs_opencv_since_4_<minor>` cfg for every minor version up to and including the one of `version`, it's used to
+/// gate the manual code that depends on the functions added in the later minor versions of OpenCV 4, and the revision-level
+/// `ocvrs_opencv_since_4_<minor>_<revision>` cfgs from [OPENCV_4_REVISIONS] for the functions added in the patch releases
+fn emit_opencv_4_version_cfgs(version: &Version) {
+	for minor in 0..=version.minor {
+		println!("cargo:rustc-cfg=ocvrs_opencv_since_4_{minor}");
+	}
+	for (minor, revision) in OPENCV_4_REVISIONS {
+		if (version.minor, version.patch) >= (minor, revision) {
+			println!("cargo:rustc-cfg=ocvrs_opencv_since_4_{minor}_{revision}");
+		}
+	}
+}
+
 fn get_version_header(header_dir: &Path) -> Option<PathBuf> {
 	get_module_header_dir(header_dir)
 		.map(|dir| dir.join("core/version.hpp"))
@@ -329,6 +347,7 @@ fn main() -> Result<()> {
 	eprintln!("=== OpenCV library configuration: {opencv:#?}");
 	if OPENCV_BRANCH_4.matches(&opencv.version) {
 		println!("cargo:rustc-cfg=ocvrs_opencv_branch_4");
+		emit_opencv_4_version_cfgs(&opencv.version);
 	} else if OPENCV_BRANCH_34.matches(&opencv.version) {
 		println!("cargo:rustc-cfg=ocvrs_opencv_branch_34");
 	} else if OPENCV_BRANCH_32.matches(&opencv.version) {
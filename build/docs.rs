@@ -2,13 +2,16 @@ use std::ffi::OsStr;
 use std::path::Path;
 use std::{env, fs};
 
-use crate::{files_with_extension, GenerateFullBindings, MANIFEST_DIR, OUT_DIR};
+use semver::Version;
+
+use crate::{emit_opencv_4_version_cfgs, files_with_extension, GenerateFullBindings, MANIFEST_DIR, OUT_DIR};
 
 pub fn handle_running_in_docsrs() -> GenerateFullBindings {
 	if env::var_os("DOCS_RS").is_some() {
 		let docs_dir = MANIFEST_DIR.join("docs");
 		// fake setup for docs.rs
 		println!(r#"cargo:rustc-cfg=ocvrs_opencv_branch_4"#);
+		emit_opencv_4_version_cfgs(&get_version_from_docs(&docs_dir).expect("Can't get OpenCV version from docs/core.rs"));
 		transfer_bindings_from_docs(&docs_dir, &OUT_DIR);
 		for path in files_with_extension(&docs_dir, "rs").expect("Can't read hub dir") {
 			if let Some(module) = path.file_stem().and_then(OsStr::to_str) {
@@ -21,6 +24,28 @@ pub fn handle_running_in_docsrs() -> GenerateFullBindings {
 	}
 }
 
+/// Version of OpenCV that the bindings in docs/ are generated for, taken from the `CV_VERSION_*` constants in `core.rs`
+fn get_version_from_docs(docs_dir: &Path) -> Option<Version> {
+	let core = fs::read_to_string(docs_dir.join("core.rs")).ok()?;
+	let mut major = None;
+	let mut minor = None;
+	let mut revision = None;
+	for line in core.lines() {
+		if let Some(line) = line.trim_start().strip_prefix("pub const CV_VERSION_") {
+			if let Some((ver_spec, version)) = line.split_once(": i32 = ") {
+				let version = version.trim_end_matches(';').parse().ok();
+				match ver_spec {
+					"MAJOR" => major = version,
+					"MINOR" => minor = version,
+					"REVISION" => revision = version,
+					_ => {}
+				}
+			}
+		}
+	}
+	Some(Version::new(major?, minor?, revision?))
+}
+
 /// Copies files from docs/ to OUT_DIR, for building in docs.rs
 pub fn transfer_bindings_from_docs(src_dir: &Path, out_dir: &Path) {
 	let target_dir = out_dir.join("opencv");
@@ -59,7 +59,7 @@ rustc --print=cfg
 
 cargo test -vv -p opencv-binding-generator
 
-FEATURES=rgb
+FEATURES=rgb,flate2
 
 cargo test -vv --features "$FEATURES"
 cargo test --release -vv --features "$FEATURES"
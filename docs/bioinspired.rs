@@ -125,8 +125,8 @@ pub mod bioinspired {
 		/// * retina_parameter_file: ""
 		/// * apply_default_setup_on_failure: true
 		#[inline]
-		fn setup_from_file(&mut self, retina_parameter_file: &str, apply_default_setup_on_failure: bool) -> Result<()> {
-			extern_container_arg!(mut retina_parameter_file);
+		fn setup_from_file(&mut self, retina_parameter_file: impl AsRef<std::path::Path>, apply_default_setup_on_failure: bool) -> Result<()> {
+			path_arg!(mut retina_parameter_file);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_bioinspired_Retina_setup_String_const_bool(self.as_raw_mut_Retina(), retina_parameter_file.opencv_as_extern_mut(), apply_default_setup_on_failure, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -348,6 +348,7 @@ pub mod bioinspired {
 			unsafe { sys::cv_bioinspired_Retina_applyFastToneMapping_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Retina(), input_image.as_raw__InputArray(), output_tone_mapped_image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(output_tone_mapped_image);
 			Ok(ret)
 		}
 		
@@ -371,6 +372,7 @@ pub mod bioinspired {
 			unsafe { sys::cv_bioinspired_Retina_getParvo_const__OutputArrayR(self.as_raw_mut_Retina(), retina_output_parvo.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(retina_output_parvo);
 			Ok(ret)
 		}
 		
@@ -384,6 +386,7 @@ pub mod bioinspired {
 			unsafe { sys::cv_bioinspired_Retina_getParvoRAW_const__OutputArrayR(self.as_raw_mut_Retina(), retina_output_parvo.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(retina_output_parvo);
 			Ok(ret)
 		}
 		
@@ -405,6 +408,7 @@ pub mod bioinspired {
 			unsafe { sys::cv_bioinspired_Retina_getMagno_const__OutputArrayR(self.as_raw_mut_Retina(), retina_output_magno.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(retina_output_magno);
 			Ok(ret)
 		}
 		
@@ -418,6 +422,7 @@ pub mod bioinspired {
 			unsafe { sys::cv_bioinspired_Retina_getMagnoRAW_const__OutputArrayR(self.as_raw_mut_Retina(), retina_output_magno.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(retina_output_magno);
 			Ok(ret)
 		}
 		
@@ -645,6 +650,7 @@ pub mod bioinspired {
 			unsafe { sys::cv_bioinspired_RetinaFastToneMapping_applyFastToneMapping_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_RetinaFastToneMapping(), input_image.as_raw__InputArray(), output_tone_mapped_image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(output_tone_mapped_image);
 			Ok(ret)
 		}
 		
@@ -1044,8 +1050,8 @@ pub mod bioinspired {
 		/// * segmentation_parameter_file: ""
 		/// * apply_default_setup_on_failure: true
 		#[inline]
-		fn setup_from_file(&mut self, segmentation_parameter_file: &str, apply_default_setup_on_failure: bool) -> Result<()> {
-			extern_container_arg!(mut segmentation_parameter_file);
+		fn setup_from_file(&mut self, segmentation_parameter_file: impl AsRef<std::path::Path>, apply_default_setup_on_failure: bool) -> Result<()> {
+			path_arg!(mut segmentation_parameter_file);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_bioinspired_TransientAreasSegmentationModule_setup_String_const_bool(self.as_raw_mut_TransientAreasSegmentationModule(), segmentation_parameter_file.opencv_as_extern_mut(), apply_default_setup_on_failure, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1136,6 +1142,7 @@ pub mod bioinspired {
 			unsafe { sys::cv_bioinspired_TransientAreasSegmentationModule_getSegmentationPicture_const__OutputArrayR(self.as_raw_mut_TransientAreasSegmentationModule(), transient_areas.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(transient_areas);
 			Ok(ret)
 		}
 		
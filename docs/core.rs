@@ -1928,6 +1928,7 @@ pub mod core {
 		unsafe { sys::cv_LUT_const__InputArrayR_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), lut.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -1984,6 +1985,7 @@ pub mod core {
 		unsafe { sys::cv_PCABackProject_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(data.as_raw__InputArray(), mean.as_raw__InputArray(), eigenvectors.as_raw__InputArray(), result.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(result);
 		Ok(ret)
 	}
 	
@@ -1998,6 +2000,8 @@ pub mod core {
 		unsafe { sys::cv_PCACompute_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_double(data.as_raw__InputArray(), mean.as_raw__InputOutputArray(), eigenvectors.as_raw__OutputArray(), eigenvalues.as_raw__OutputArray(), retained_variance, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(eigenvectors);
+		output_array_finish!(eigenvalues);
 		Ok(ret)
 	}
 	
@@ -2015,6 +2019,8 @@ pub mod core {
 		unsafe { sys::cv_PCACompute_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int(data.as_raw__InputArray(), mean.as_raw__InputOutputArray(), eigenvectors.as_raw__OutputArray(), eigenvalues.as_raw__OutputArray(), max_components, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(eigenvectors);
+		output_array_finish!(eigenvalues);
 		Ok(ret)
 	}
 	
@@ -2028,6 +2034,7 @@ pub mod core {
 		unsafe { sys::cv_PCACompute_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_double(data.as_raw__InputArray(), mean.as_raw__InputOutputArray(), eigenvectors.as_raw__OutputArray(), retained_variance, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(eigenvectors);
 		Ok(ret)
 	}
 	
@@ -2044,6 +2051,7 @@ pub mod core {
 		unsafe { sys::cv_PCACompute_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_int(data.as_raw__InputArray(), mean.as_raw__InputOutputArray(), eigenvectors.as_raw__OutputArray(), max_components, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(eigenvectors);
 		Ok(ret)
 	}
 	
@@ -2058,6 +2066,7 @@ pub mod core {
 		unsafe { sys::cv_PCAProject_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(data.as_raw__InputArray(), mean.as_raw__InputArray(), eigenvectors.as_raw__InputArray(), result.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(result);
 		Ok(ret)
 	}
 	
@@ -2103,6 +2112,7 @@ pub mod core {
 		unsafe { sys::cv_SVBackSubst_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(w.as_raw__InputArray(), u.as_raw__InputArray(), vt.as_raw__InputArray(), rhs.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -2120,6 +2130,9 @@ pub mod core {
 		unsafe { sys::cv_SVDecomp_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), w.as_raw__OutputArray(), u.as_raw__OutputArray(), vt.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(w);
+		output_array_finish!(u);
+		output_array_finish!(vt);
 		Ok(ret)
 	}
 	
@@ -2209,6 +2222,7 @@ pub mod core {
 		unsafe { sys::cv_absdiff_const__InputArrayR_const__InputArrayR_const__OutputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -2241,14 +2255,16 @@ pub mod core {
 	/// ## C++ default parameters
 	/// * dtype: -1
 	#[inline]
-	pub fn add_weighted(src1: &impl core::ToInputArray, alpha: f64, src2: &impl core::ToInputArray, beta: f64, gamma: f64, dst: &mut impl core::ToOutputArray, dtype: i32) -> Result<()> {
+	pub fn add_weighted(src1: &impl core::ToInputArray, alpha: f64, src2: &impl core::ToInputArray, beta: f64, gamma: f64, dst: &mut impl core::ToOutputArray, dtype: impl Into<core::MatType>) -> Result<()> {
 		input_array_arg!(src1);
 		input_array_arg!(src2);
 		output_array_arg!(dst);
+		let dtype = i32::from(dtype.into());
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_addWeighted_const__InputArrayR_double_const__InputArrayR_double_double_const__OutputArrayR_int(src1.as_raw__InputArray(), alpha, src2.as_raw__InputArray(), beta, gamma, dst.as_raw__OutputArray(), dtype, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -2296,15 +2312,17 @@ pub mod core {
 	/// * mask: noArray()
 	/// * dtype: -1
 	#[inline]
-	pub fn add(src1: &impl core::ToInputArray, src2: &impl core::ToInputArray, dst: &mut impl core::ToOutputArray, mask: &impl core::ToInputArray, dtype: i32) -> Result<()> {
+	pub fn add(src1: &impl core::ToInputArray, src2: &impl core::ToInputArray, dst: &mut impl core::ToOutputArray, mask: &impl core::ToInputArray, dtype: impl Into<core::MatType>) -> Result<()> {
 		input_array_arg!(src1);
 		input_array_arg!(src2);
 		output_array_arg!(dst);
 		input_array_arg!(mask);
+		let dtype = i32::from(dtype.into());
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_add_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_int(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), dtype, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -2320,16 +2338,19 @@ pub mod core {
 	/// * update: 0
 	/// * crosscheck: false
 	#[inline]
-	pub fn batch_distance(src1: &impl core::ToInputArray, src2: &impl core::ToInputArray, dist: &mut impl core::ToOutputArray, dtype: i32, nidx: &mut impl core::ToOutputArray, norm_type: i32, k: i32, mask: &impl core::ToInputArray, update: i32, crosscheck: bool) -> Result<()> {
+	pub fn batch_distance(src1: &impl core::ToInputArray, src2: &impl core::ToInputArray, dist: &mut impl core::ToOutputArray, dtype: impl Into<core::MatType>, nidx: &mut impl core::ToOutputArray, norm_type: i32, k: i32, mask: &impl core::ToInputArray, update: i32, crosscheck: bool) -> Result<()> {
 		input_array_arg!(src1);
 		input_array_arg!(src2);
 		output_array_arg!(dist);
+		let dtype = i32::from(dtype.into());
 		output_array_arg!(nidx);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_batchDistance_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_const__OutputArrayR_int_int_const__InputArrayR_int_bool(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dist.as_raw__OutputArray(), dtype, nidx.as_raw__OutputArray(), norm_type, k, mask.as_raw__InputArray(), update, crosscheck, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dist);
+		output_array_finish!(nidx);
 		Ok(ret)
 	}
 	
@@ -2371,6 +2392,7 @@ pub mod core {
 		unsafe { sys::cv_bitwise_and_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -2400,6 +2422,7 @@ pub mod core {
 		unsafe { sys::cv_bitwise_not_const__InputArrayR_const__OutputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -2440,6 +2463,7 @@ pub mod core {
 		unsafe { sys::cv_bitwise_or_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -2481,6 +2505,7 @@ pub mod core {
 		unsafe { sys::cv_bitwise_xor_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -2551,6 +2576,7 @@ pub mod core {
 		unsafe { sys::cv_calcCovarMatrix_const__InputArrayR_const__OutputArrayR_const__InputOutputArrayR_int_int(samples.as_raw__InputArray(), covar.as_raw__OutputArray(), mean.as_raw__InputOutputArray(), flags, ctype, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(covar);
 		Ok(ret)
 	}
 	
@@ -2586,6 +2612,8 @@ pub mod core {
 		unsafe { sys::cv_cartToPolar_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool(x.as_raw__InputArray(), y.as_raw__InputArray(), magnitude.as_raw__OutputArray(), angle.as_raw__OutputArray(), angle_in_degrees, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(magnitude);
+		output_array_finish!(angle);
 		Ok(ret)
 	}
 	
@@ -2674,6 +2702,7 @@ pub mod core {
 		unsafe { sys::cv_compare_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), cmpop, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -2723,6 +2752,7 @@ pub mod core {
 		unsafe { sys::cv_convertFp16_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -2765,6 +2795,7 @@ pub mod core {
 		unsafe { sys::cv_convertScaleAbs_const__InputArrayR_const__OutputArrayR_double_double(src.as_raw__InputArray(), dst.as_raw__OutputArray(), alpha, beta, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -2824,6 +2855,7 @@ pub mod core {
 		unsafe { sys::cv_copyMakeBorder_const__InputArrayR_const__OutputArrayR_int_int_int_int_int_const_ScalarR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), top, bottom, left, right, border_type, &value, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -2845,6 +2877,7 @@ pub mod core {
 		unsafe { sys::cv_copyTo_const__InputArrayR_const__OutputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -2894,12 +2927,14 @@ pub mod core {
 	/// Matrix is called continuous if its elements are stored continuously, that is, without gaps at the
 	/// end of each row.
 	#[inline]
-	pub fn create_continuous(rows: i32, cols: i32, typ: i32, arr: &mut impl core::ToOutputArray) -> Result<()> {
+	pub fn create_continuous(rows: i32, cols: i32, typ: impl Into<core::MatType>, arr: &mut impl core::ToOutputArray) -> Result<()> {
+		let typ = i32::from(typ.into());
 		output_array_arg!(arr);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_createContinuous_int_int_int_const__OutputArrayR(rows, cols, typ, arr.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(arr);
 		Ok(ret)
 	}
 	
@@ -2925,7 +2960,8 @@ pub mod core {
 	/// ## C++ default parameters
 	/// * step: Mat::AUTO_STEP
 	#[inline]
-	pub fn create_gpu_mat_from_cuda_memory_1(size: core::Size, typ: i32, cuda_memory_address: size_t, step: size_t) -> Result<core::GpuMat> {
+	pub fn create_gpu_mat_from_cuda_memory_1(size: core::Size, typ: impl Into<core::MatType>, cuda_memory_address: size_t, step: size_t) -> Result<core::GpuMat> {
+		let typ = i32::from(typ.into());
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_createGpuMatFromCudaMemory_Size_int_size_t_size_t(size.opencv_as_extern(), typ, cuda_memory_address, step, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2947,7 +2983,8 @@ pub mod core {
 	/// ## C++ default parameters
 	/// * step: Mat::AUTO_STEP
 	#[inline]
-	pub fn create_gpu_mat_from_cuda_memory(rows: i32, cols: i32, typ: i32, cuda_memory_address: size_t, step: size_t) -> Result<core::GpuMat> {
+	pub fn create_gpu_mat_from_cuda_memory(rows: i32, cols: i32, typ: impl Into<core::MatType>, cuda_memory_address: size_t, step: size_t) -> Result<core::GpuMat> {
+		let typ = i32::from(typ.into());
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_createGpuMatFromCudaMemory_int_int_int_size_t_size_t(rows, cols, typ, cuda_memory_address, step, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2976,12 +3013,14 @@ pub mod core {
 	/// 
 	/// The function does not reallocate memory if the matrix has proper attributes already.
 	#[inline]
-	pub fn ensure_size_is_enough(rows: i32, cols: i32, typ: i32, arr: &mut impl core::ToOutputArray) -> Result<()> {
+	pub fn ensure_size_is_enough(rows: i32, cols: i32, typ: impl Into<core::MatType>, arr: &mut impl core::ToOutputArray) -> Result<()> {
+		let typ = i32::from(typ.into());
 		output_array_arg!(arr);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_ensureSizeIsEnough_int_int_int_const__OutputArrayR(rows, cols, typ, arr.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(arr);
 		Ok(ret)
 	}
 	
@@ -3186,6 +3225,7 @@ pub mod core {
 		unsafe { sys::cv_dct_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -3552,6 +3592,7 @@ pub mod core {
 		unsafe { sys::cv_dft_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), flags, nonzero_rows, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -3616,14 +3657,16 @@ pub mod core {
 	/// * scale: 1
 	/// * dtype: -1
 	#[inline]
-	pub fn divide2(src1: &impl core::ToInputArray, src2: &impl core::ToInputArray, dst: &mut impl core::ToOutputArray, scale: f64, dtype: i32) -> Result<()> {
+	pub fn divide2(src1: &impl core::ToInputArray, src2: &impl core::ToInputArray, dst: &mut impl core::ToOutputArray, scale: f64, dtype: impl Into<core::MatType>) -> Result<()> {
 		input_array_arg!(src1);
 		input_array_arg!(src2);
 		output_array_arg!(dst);
+		let dtype = i32::from(dtype.into());
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_divide_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_int(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), scale, dtype, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -3661,13 +3704,15 @@ pub mod core {
 	/// ## C++ default parameters
 	/// * dtype: -1
 	#[inline]
-	pub fn divide(scale: f64, src2: &impl core::ToInputArray, dst: &mut impl core::ToOutputArray, dtype: i32) -> Result<()> {
+	pub fn divide(scale: f64, src2: &impl core::ToInputArray, dst: &mut impl core::ToOutputArray, dtype: impl Into<core::MatType>) -> Result<()> {
 		input_array_arg!(src2);
 		output_array_arg!(dst);
+		let dtype = i32::from(dtype.into());
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_divide_double_const__InputArrayR_const__OutputArrayR_int(scale, src2.as_raw__InputArray(), dst.as_raw__OutputArray(), dtype, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -3697,6 +3742,8 @@ pub mod core {
 		unsafe { sys::cv_eigenNonSymmetric_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(src.as_raw__InputArray(), eigenvalues.as_raw__OutputArray(), eigenvectors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(eigenvalues);
+		output_array_finish!(eigenvectors);
 		Ok(ret)
 	}
 	
@@ -3734,6 +3781,8 @@ pub mod core {
 		unsafe { sys::cv_eigen_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(src.as_raw__InputArray(), eigenvalues.as_raw__OutputArray(), eigenvectors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(eigenvalues);
+		output_array_finish!(eigenvectors);
 		Ok(ret)
 	}
 	
@@ -3804,6 +3853,7 @@ pub mod core {
 		unsafe { sys::cv_exp_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -3822,6 +3872,7 @@ pub mod core {
 		unsafe { sys::cv_extractChannel_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), coi, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -3877,6 +3928,7 @@ pub mod core {
 		unsafe { sys::cv_findNonZero_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), idx.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(idx);
 		Ok(ret)
 	}
 	
@@ -3893,6 +3945,7 @@ pub mod core {
 		unsafe { sys::cv_flipND_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), axis, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -3930,6 +3983,7 @@ pub mod core {
 		unsafe { sys::cv_flip_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), flip_code, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -3976,6 +4030,7 @@ pub mod core {
 		unsafe { sys::cv_gemm_const__InputArrayR_const__InputArrayR_double_const__InputArrayR_double_const__OutputArrayR_int(src1.as_raw__InputArray(), src2.as_raw__InputArray(), alpha, src3.as_raw__InputArray(), beta, dst.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -4034,7 +4089,8 @@ pub mod core {
 	}
 	
 	#[inline]
-	pub fn get_elem_size(typ: i32) -> Result<size_t> {
+	pub fn get_elem_size(typ: impl Into<core::MatType>) -> Result<size_t> {
+		let typ = i32::from(typ.into());
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_getElemSize_int(typ, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -4320,6 +4376,7 @@ pub mod core {
 		unsafe { sys::cv_hconcat_const__InputArrayR_const__InputArrayR_const__OutputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -4374,6 +4431,7 @@ pub mod core {
 		unsafe { sys::cv_hconcat_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -4397,6 +4455,7 @@ pub mod core {
 		unsafe { sys::cv_idct_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -4426,6 +4485,7 @@ pub mod core {
 		unsafe { sys::cv_idft_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), flags, nonzero_rows, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -4458,6 +4518,7 @@ pub mod core {
 		unsafe { sys::cv_inRange_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), lowerb.as_raw__InputArray(), upperb.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -4561,6 +4622,7 @@ pub mod core {
 		unsafe { sys::cv_invert_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -4700,6 +4762,7 @@ pub mod core {
 		unsafe { sys::cv_kmeans_const__InputArrayR_int_const__InputOutputArrayR_TermCriteria_int_int_const__OutputArrayR(data.as_raw__InputArray(), k, best_labels.as_raw__InputOutputArray(), criteria.opencv_as_extern(), attempts, flags, centers.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(centers);
 		Ok(ret)
 	}
 	
@@ -4723,6 +4786,7 @@ pub mod core {
 		unsafe { sys::cv_log_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -4747,6 +4811,7 @@ pub mod core {
 		unsafe { sys::cv_magnitude_const__InputArrayR_const__InputArrayR_const__OutputArrayR(x.as_raw__InputArray(), y.as_raw__InputArray(), magnitude.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(magnitude);
 		Ok(ret)
 	}
 	
@@ -4841,6 +4906,7 @@ pub mod core {
 		unsafe { sys::cv_max_const__InputArrayR_const__InputArrayR_const__OutputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -4889,6 +4955,8 @@ pub mod core {
 		unsafe { sys::cv_meanStdDev_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputArrayR(src.as_raw__InputArray(), mean.as_raw__OutputArray(), stddev.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(mean);
+		output_array_finish!(stddev);
 		Ok(ret)
 	}
 	
@@ -4953,6 +5021,7 @@ pub mod core {
 		unsafe { sys::cv_merge_const__InputArrayR_const__OutputArrayR(mv.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -5166,6 +5235,7 @@ pub mod core {
 		unsafe { sys::cv_min_const__InputArrayR_const__InputArrayR_const__OutputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -5347,6 +5417,7 @@ pub mod core {
 		unsafe { sys::cv_mulSpectrums_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_bool(a.as_raw__InputArray(), b.as_raw__InputArray(), c.as_raw__OutputArray(), flags, conj_b, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(c);
 		Ok(ret)
 	}
 	
@@ -5385,14 +5456,16 @@ pub mod core {
 	/// * scale: 1
 	/// * dtype: -1
 	#[inline]
-	pub fn mul_transposed(src: &impl core::ToInputArray, dst: &mut impl core::ToOutputArray, a_ta: bool, delta: &impl core::ToInputArray, scale: f64, dtype: i32) -> Result<()> {
+	pub fn mul_transposed(src: &impl core::ToInputArray, dst: &mut impl core::ToOutputArray, a_ta: bool, delta: &impl core::ToInputArray, scale: f64, dtype: impl Into<core::MatType>) -> Result<()> {
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		input_array_arg!(delta);
+		let dtype = i32::from(dtype.into());
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_mulTransposed_const__InputArrayR_const__OutputArrayR_bool_const__InputArrayR_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), a_ta, delta.as_raw__InputArray(), scale, dtype, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -5424,14 +5497,16 @@ pub mod core {
 	/// * scale: 1
 	/// * dtype: -1
 	#[inline]
-	pub fn multiply(src1: &impl core::ToInputArray, src2: &impl core::ToInputArray, dst: &mut impl core::ToOutputArray, scale: f64, dtype: i32) -> Result<()> {
+	pub fn multiply(src1: &impl core::ToInputArray, src2: &impl core::ToInputArray, dst: &mut impl core::ToOutputArray, scale: f64, dtype: impl Into<core::MatType>) -> Result<()> {
 		input_array_arg!(src1);
 		input_array_arg!(src2);
 		output_array_arg!(dst);
+		let dtype = i32::from(dtype.into());
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_multiply_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_int(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), scale, dtype, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -5690,9 +5765,10 @@ pub mod core {
 	/// * dtype: -1
 	/// * mask: noArray()
 	#[inline]
-	pub fn normalize(src: &impl core::ToInputArray, dst: &mut impl core::ToInputOutputArray, alpha: f64, beta: f64, norm_type: i32, dtype: i32, mask: &impl core::ToInputArray) -> Result<()> {
+	pub fn normalize(src: &impl core::ToInputArray, dst: &mut impl core::ToInputOutputArray, alpha: f64, beta: f64, norm_type: i32, dtype: impl Into<core::MatType>, mask: &impl core::ToInputArray) -> Result<()> {
 		input_array_arg!(src);
 		input_output_array_arg!(dst);
+		let dtype = i32::from(dtype.into());
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_normalize_const__InputArrayR_const__InputOutputArrayR_double_double_int_int_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__InputOutputArray(), alpha, beta, norm_type, dtype, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -5777,7 +5853,8 @@ pub mod core {
 	/// * type: OpenCV type of image
 	/// * dst: destination UMat
 	#[inline]
-	pub unsafe fn convert_from_buffer(cl_mem_buffer: *mut c_void, step: size_t, rows: i32, cols: i32, typ: i32, dst: &mut core::UMat) -> Result<()> {
+	pub unsafe fn convert_from_buffer(cl_mem_buffer: *mut c_void, step: size_t, rows: i32, cols: i32, typ: impl Into<core::MatType>, dst: &mut core::UMat) -> Result<()> {
+		let typ = i32::from(typ.into());
 		return_send!(via ocvrs_return);
 		{ sys::cv_ocl_convertFromBuffer_voidX_size_t_int_int_int_UMatR(cl_mem_buffer, step, rows, cols, typ, dst.as_raw_mut_UMat(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(ocvrs_return => ret);
@@ -6019,6 +6096,7 @@ pub mod core {
 		unsafe { sys::cv_ogl_convertFromGLTexture2D_const_Texture2DR_const__OutputArrayR(texture.as_raw_Texture2D(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -6865,6 +6943,7 @@ pub mod core {
 		unsafe { sys::cv_perspectiveTransform_const__InputArrayR_const__OutputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -6896,6 +6975,7 @@ pub mod core {
 		unsafe { sys::cv_phase_const__InputArrayR_const__InputArrayR_const__OutputArrayR_bool(x.as_raw__InputArray(), y.as_raw__InputArray(), angle.as_raw__OutputArray(), angle_in_degrees, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(angle);
 		Ok(ret)
 	}
 	
@@ -6933,6 +7013,8 @@ pub mod core {
 		unsafe { sys::cv_polarToCart_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool(magnitude.as_raw__InputArray(), angle.as_raw__InputArray(), x.as_raw__OutputArray(), y.as_raw__OutputArray(), angle_in_degrees, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(x);
+		output_array_finish!(y);
 		Ok(ret)
 	}
 	
@@ -6969,6 +7051,7 @@ pub mod core {
 		unsafe { sys::cv_pow_const__InputArrayR_double_const__OutputArrayR(src.as_raw__InputArray(), power, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -7187,6 +7270,7 @@ pub mod core {
 		unsafe { sys::cv_reduceArgMax_const__InputArrayR_const__OutputArrayR_int_bool(src.as_raw__InputArray(), dst.as_raw__OutputArray(), axis, last_index, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -7217,6 +7301,7 @@ pub mod core {
 		unsafe { sys::cv_reduceArgMin_const__InputArrayR_const__OutputArrayR_int_bool(src.as_raw__InputArray(), dst.as_raw__OutputArray(), axis, last_index, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -7249,13 +7334,15 @@ pub mod core {
 	/// ## C++ default parameters
 	/// * dtype: -1
 	#[inline]
-	pub fn reduce(src: &impl core::ToInputArray, dst: &mut impl core::ToOutputArray, dim: i32, rtype: i32, dtype: i32) -> Result<()> {
+	pub fn reduce(src: &impl core::ToInputArray, dst: &mut impl core::ToOutputArray, dim: i32, rtype: i32, dtype: impl Into<core::MatType>) -> Result<()> {
 		input_array_arg!(src);
 		output_array_arg!(dst);
+		let dtype = i32::from(dtype.into());
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_reduce_const__InputArrayR_const__OutputArrayR_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), dim, rtype, dtype, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -7313,6 +7400,7 @@ pub mod core {
 		unsafe { sys::cv_repeat_const__InputArrayR_int_int_const__OutputArrayR(src.as_raw__InputArray(), ny, nx, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -7336,6 +7424,7 @@ pub mod core {
 		unsafe { sys::cv_rotate_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), rotate_code, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -7347,8 +7436,8 @@ pub mod core {
 	/// ## Parameters
 	/// * path: Path to used samples data
 	#[inline]
-	pub fn add_samples_data_search_path(path: &str) -> Result<()> {
-		extern_container_arg!(path);
+	pub fn add_samples_data_search_path(path: impl AsRef<std::path::Path>) -> Result<()> {
+		path_arg!(path);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_samples_addSamplesDataSearchPath_const_StringR(path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -7364,8 +7453,8 @@ pub mod core {
 	/// ## Parameters
 	/// * subdir: samples data sub directory
 	#[inline]
-	pub fn add_samples_data_search_sub_directory(subdir: &str) -> Result<()> {
-		extern_container_arg!(subdir);
+	pub fn add_samples_data_search_sub_directory(subdir: impl AsRef<std::path::Path>) -> Result<()> {
+		path_arg!(subdir);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_samples_addSamplesDataSearchSubDirectory_const_StringR(subdir.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -7376,8 +7465,8 @@ pub mod core {
 	/// ## C++ default parameters
 	/// * silent_mode: false
 	#[inline]
-	pub fn find_file_or_keep(relative_path: &str, silent_mode: bool) -> Result<String> {
-		extern_container_arg!(relative_path);
+	pub fn find_file_or_keep(relative_path: impl AsRef<std::path::Path>, silent_mode: bool) -> Result<String> {
+		path_arg!(relative_path);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_samples_findFileOrKeep_const_StringR_bool(relative_path.opencv_as_extern(), silent_mode, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -7415,8 +7504,8 @@ pub mod core {
 	/// * required: true
 	/// * silent_mode: false
 	#[inline]
-	pub fn find_file(relative_path: &str, required: bool, silent_mode: bool) -> Result<String> {
-		extern_container_arg!(relative_path);
+	pub fn find_file(relative_path: impl AsRef<std::path::Path>, required: bool, silent_mode: bool) -> Result<String> {
+		path_arg!(relative_path);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_samples_findFile_const_StringR_bool_bool(relative_path.opencv_as_extern(), required, silent_mode, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -7454,6 +7543,7 @@ pub mod core {
 		unsafe { sys::cv_scaleAdd_const__InputArrayR_double_const__InputArrayR_const__OutputArrayR(src1.as_raw__InputArray(), alpha, src2.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -7610,6 +7700,7 @@ pub mod core {
 		unsafe { sys::cv_solveCubic_const__InputArrayR_const__OutputArrayR(coeffs.as_raw__InputArray(), roots.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(roots);
 		Ok(ret)
 	}
 	
@@ -7654,6 +7745,7 @@ pub mod core {
 		unsafe { sys::cv_solveLP_const__InputArrayR_const__InputArrayR_const__OutputArrayR(func.as_raw__InputArray(), constr.as_raw__InputArray(), z.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(z);
 		Ok(ret)
 	}
 	
@@ -7696,6 +7788,7 @@ pub mod core {
 		unsafe { sys::cv_solveLP_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double(func.as_raw__InputArray(), constr.as_raw__InputArray(), z.as_raw__OutputArray(), constr_eps, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(z);
 		Ok(ret)
 	}
 	
@@ -7718,6 +7811,7 @@ pub mod core {
 		unsafe { sys::cv_solvePoly_const__InputArrayR_const__OutputArrayR_int(coeffs.as_raw__InputArray(), roots.as_raw__OutputArray(), max_iters, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(roots);
 		Ok(ret)
 	}
 	
@@ -7757,6 +7851,7 @@ pub mod core {
 		unsafe { sys::cv_solve_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -7788,6 +7883,7 @@ pub mod core {
 		unsafe { sys::cv_sortIdx_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -7813,6 +7909,7 @@ pub mod core {
 		unsafe { sys::cv_sort_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -7870,6 +7967,7 @@ pub mod core {
 		unsafe { sys::cv_split_const__InputArrayR_const__OutputArrayR(m.as_raw__InputArray(), mv.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(mv);
 		Ok(ret)
 	}
 	
@@ -7890,6 +7988,7 @@ pub mod core {
 		unsafe { sys::cv_sqrt_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -7938,15 +8037,17 @@ pub mod core {
 	/// * mask: noArray()
 	/// * dtype: -1
 	#[inline]
-	pub fn subtract(src1: &impl core::ToInputArray, src2: &impl core::ToInputArray, dst: &mut impl core::ToOutputArray, mask: &impl core::ToInputArray, dtype: i32) -> Result<()> {
+	pub fn subtract(src1: &impl core::ToInputArray, src2: &impl core::ToInputArray, dst: &mut impl core::ToOutputArray, mask: &impl core::ToInputArray, dtype: impl Into<core::MatType>) -> Result<()> {
 		input_array_arg!(src1);
 		input_array_arg!(src2);
 		output_array_arg!(dst);
 		input_array_arg!(mask);
+		let dtype = i32::from(dtype.into());
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_subtract_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_int(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), dtype, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -8073,6 +8174,7 @@ pub mod core {
 		unsafe { sys::cv_transform_const__InputArrayR_const__OutputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -8093,6 +8195,7 @@ pub mod core {
 		unsafe { sys::cv_transposeND_const__InputArrayR_const_vectorLintGR_const__OutputArrayR(src.as_raw__InputArray(), order.as_raw_VectorOfi32(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -8114,12 +8217,14 @@ pub mod core {
 		unsafe { sys::cv_transpose_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
 	/// Returns string of cv::Mat depth value: CV_8UC3 -> "CV_8UC3" or "<invalid type>"
 	#[inline]
-	pub fn type_to_string(typ: i32) -> Result<String> {
+	pub fn type_to_string(typ: impl Into<core::MatType>) -> Result<String> {
+		let typ = i32::from(typ.into());
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_typeToString_int(typ, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -8161,6 +8266,7 @@ pub mod core {
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -8392,7 +8498,8 @@ pub mod core {
 	}
 	
 	#[inline]
-	pub fn generate_vector_of_mat(len: size_t, rows: i32, cols: i32, dtype: i32, vec: &mut core::Vector<core::Mat>) -> Result<()> {
+	pub fn generate_vector_of_mat(len: size_t, rows: i32, cols: i32, dtype: impl Into<core::MatType>, vec: &mut core::Vector<core::Mat>) -> Result<()> {
+		let dtype = i32::from(dtype.into());
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_utils_generateVectorOfMat_size_t_int_int_int_vectorLMatGR(len, rows, cols, dtype, vec.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -8620,6 +8727,7 @@ pub mod core {
 		{ sys::cv_va_intel_convertFromVASurface_VADisplay_VASurfaceID_Size_const__OutputArrayR(display, surface, size.opencv_as_extern(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -8714,6 +8822,7 @@ pub mod core {
 		unsafe { sys::cv_vconcat_const__InputArrayR_const__InputArrayR_const__OutputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -8766,6 +8875,7 @@ pub mod core {
 		unsafe { sys::cv_vconcat_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(dst);
 		Ok(ret)
 	}
 	
@@ -8942,8 +9052,8 @@ pub mod core {
 		/// Saves the algorithm to a file.
 		/// In order to make this method work, the derived class must implement Algorithm::write(FileStorage& fs).
 		#[inline]
-		fn save(&self, filename: &str) -> Result<()> {
-			extern_container_arg!(filename);
+		fn save(&self, filename: impl AsRef<std::path::Path>) -> Result<()> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_Algorithm_save_const_const_StringR(self.as_raw_Algorithm(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -9070,6 +9180,7 @@ pub mod core {
 			unsafe { sys::cv_AsyncArray_get_const_const__OutputArrayR(self.as_raw_AsyncArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
@@ -9090,6 +9201,7 @@ pub mod core {
 			unsafe { sys::cv_AsyncArray_get_const_const__OutputArrayR_int64_t(self.as_raw_AsyncArray(), dst.as_raw__OutputArray(), timeout_ns, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
@@ -9100,6 +9212,7 @@ pub mod core {
 			unsafe { sys::cv_AsyncArray_get_const_const__OutputArrayR_double(self.as_raw_AsyncArray(), dst.as_raw__OutputArray(), timeout_ns, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
@@ -9792,6 +9905,7 @@ pub mod core {
 			unsafe { sys::cv_DownhillSolver_getInitStep_const_const__OutputArrayR(self.as_raw_DownhillSolver(), step.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(step);
 			Ok(ret)
 		}
 		
@@ -11250,8 +11364,8 @@ pub mod core {
 		/// ## Returns
 		/// The normalized object name.
 		#[inline]
-		pub fn get_default_object_name(filename: &str) -> Result<String> {
-			extern_container_arg!(filename);
+		pub fn get_default_object_name(filename: impl AsRef<std::path::Path>) -> Result<String> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_FileStorage_getDefaultObjectName_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -11812,8 +11926,8 @@ pub mod core {
 	
 		/// Serializes this object to a given filename.
 		#[inline]
-		fn save(&self, filename: &str) -> Result<()> {
-			extern_container_arg!(filename);
+		fn save(&self, filename: impl AsRef<std::path::Path>) -> Result<()> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_LDA_save_const_const_StringR(self.as_raw_LDA(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -11861,8 +11975,8 @@ pub mod core {
 	
 		/// Deserializes this object from a given filename.
 		#[inline]
-		fn load(&mut self, filename: &str) -> Result<()> {
-			extern_container_arg!(filename);
+		fn load(&mut self, filename: impl AsRef<std::path::Path>) -> Result<()> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_LDA_load_const_StringR(self.as_raw_mut_LDA(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -12327,6 +12441,7 @@ pub mod core {
 			unsafe { sys::cv_Mat_copyTo_const_const__OutputArrayR(self.as_raw_Mat(), m.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(m);
 			Ok(ret)
 		}
 		
@@ -12361,6 +12476,7 @@ pub mod core {
 			unsafe { sys::cv_Mat_copyTo_const_const__OutputArrayR_const__InputArrayR(self.as_raw_Mat(), m.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(m);
 			Ok(ret)
 		}
 		
@@ -12382,12 +12498,14 @@ pub mod core {
 		/// * alpha: 1
 		/// * beta: 0
 		#[inline]
-		fn convert_to(&self, m: &mut impl core::ToOutputArray, rtype: i32, alpha: f64, beta: f64) -> Result<()> {
+		fn convert_to(&self, m: &mut impl core::ToOutputArray, rtype: impl Into<core::MatType>, alpha: f64, beta: f64) -> Result<()> {
 			output_array_arg!(m);
+			let rtype = i32::from(rtype.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_Mat_convertTo_const_const__OutputArrayR_int_double_double(self.as_raw_Mat(), m.as_raw__OutputArray(), rtype, alpha, beta, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(m);
 			Ok(ret)
 		}
 		
@@ -12401,7 +12519,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * typ: -1
 		#[inline]
-		fn assign_to(&self, m: &mut core::Mat, typ: i32) -> Result<()> {
+		fn assign_to(&self, m: &mut core::Mat, typ: impl Into<core::MatType>) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_Mat_assignTo_const_MatR_int(self.as_raw_Mat(), m.as_raw_mut_Mat(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -13250,7 +13369,7 @@ pub mod core {
 		/// ## Overloaded parameters
 		/// 
 		/// * idx: Array of Mat::dims indices.
-		fn at_nd<T: core::DataType>(&self, idx: &[i32]) -> Result<&T> { core::mat_forward::at_nd(self, idx) }
+		fn at_nd<T: core::MatNdElement + ?Sized>(&self, idx: &[i32]) -> Result<&T> { core::mat_forward::at_nd(self, idx) }
 		
 		/// Returns a reference to the specified array element.
 		/// 
@@ -13415,7 +13534,8 @@ pub mod core {
 		/// * cols: New number of columns.
 		/// * type: New matrix type.
 		#[inline]
-		unsafe fn create_rows_cols(&mut self, rows: i32, cols: i32, typ: i32) -> Result<()> {
+		unsafe fn create_rows_cols(&mut self, rows: i32, cols: i32, typ: impl Into<core::MatType>) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_Mat_create_int_int_int(self.as_raw_mut_Mat(), rows, cols, typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -13464,7 +13584,8 @@ pub mod core {
 		/// * size: Alternative new matrix size specification: Size(cols, rows)
 		/// * type: New matrix type.
 		#[inline]
-		unsafe fn create_size(&mut self, size: core::Size, typ: i32) -> Result<()> {
+		unsafe fn create_size(&mut self, size: core::Size, typ: impl Into<core::MatType>) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_Mat_create_Size_int(self.as_raw_mut_Mat(), size.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -13514,7 +13635,8 @@ pub mod core {
 		/// * sizes: Array of integers specifying a new array shape.
 		/// * type: New matrix type.
 		#[inline]
-		unsafe fn create_nd(&mut self, sizes: &[i32], typ: i32) -> Result<()> {
+		unsafe fn create_nd(&mut self, sizes: &[i32], typ: impl Into<core::MatType>) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_Mat_create_int_const_intX_int(self.as_raw_mut_Mat(), sizes.len() as _, sizes.as_ptr(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -13563,7 +13685,8 @@ pub mod core {
 		/// * sizes: Array of integers specifying a new array shape.
 		/// * type: New matrix type.
 		#[inline]
-		unsafe fn create_nd_vec(&mut self, sizes: &core::Vector<i32>, typ: i32) -> Result<()> {
+		unsafe fn create_nd_vec(&mut self, sizes: &core::Vector<i32>, typ: impl Into<core::MatType>) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_Mat_create_const_vectorLintGR_int(self.as_raw_mut_Mat(), sizes.as_raw_VectorOfi32(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -13984,7 +14107,7 @@ pub mod core {
 		/// ## Overloaded parameters
 		/// 
 		/// * idx: Array of Mat::dims indices.
-		fn at_nd_mut<T: core::DataType>(&mut self, idx: &[i32]) -> Result<&mut T> { core::mat_forward::at_nd_mut(self, idx) }
+		fn at_nd_mut<T: core::MatNdElement + ?Sized>(&mut self, idx: &[i32]) -> Result<&mut T> { core::mat_forward::at_nd_mut(self, idx) }
 		
 		/// Returns a reference to the specified array element.
 		/// 
@@ -14288,7 +14411,8 @@ pub mod core {
 		/// * type: Array type. Use CV_8UC1, ..., CV_64FC4 to create 1-4 channel matrices, or
 		///    CV_8UC(n), ..., CV_64FC(n) to create multi-channel (up to CV_CN_MAX channels) matrices.
 		#[inline]
-		pub unsafe fn new_rows_cols(rows: i32, cols: i32, typ: i32) -> Result<core::Mat> {
+		pub unsafe fn new_rows_cols(rows: i32, cols: i32, typ: impl Into<core::MatType>) -> Result<core::Mat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_Mat_Mat_int_int_int(rows, cols, typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -14310,7 +14434,8 @@ pub mod core {
 		/// * type: Array type. Use CV_8UC1, ..., CV_64FC4 to create 1-4 channel matrices, or
 		///    CV_8UC(n), ..., CV_64FC(n) to create multi-channel (up to CV_CN_MAX channels) matrices.
 		#[inline]
-		pub unsafe fn new_size(size: core::Size, typ: i32) -> Result<core::Mat> {
+		pub unsafe fn new_size(size: core::Size, typ: impl Into<core::MatType>) -> Result<core::Mat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_Mat_Mat_Size_int(size.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -14335,7 +14460,8 @@ pub mod core {
 		///    the particular value after the construction, use the assignment operator
 		///    Mat::operator=(const Scalar& value) .
 		#[inline]
-		pub fn new_rows_cols_with_default(rows: i32, cols: i32, typ: i32, s: core::Scalar) -> Result<core::Mat> {
+		pub fn new_rows_cols_with_default(rows: i32, cols: i32, typ: impl Into<core::MatType>, s: core::Scalar) -> Result<core::Mat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_Mat_Mat_int_int_int_const_ScalarR(rows, cols, typ, &s, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -14360,7 +14486,8 @@ pub mod core {
 		///    the particular value after the construction, use the assignment operator
 		///    Mat::operator=(const Scalar& value) .
 		#[inline]
-		pub fn new_size_with_default(size: core::Size, typ: i32, s: core::Scalar) -> Result<core::Mat> {
+		pub fn new_size_with_default(size: core::Size, typ: impl Into<core::MatType>, s: core::Scalar) -> Result<core::Mat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_Mat_Mat_Size_int_const_ScalarR(size.opencv_as_extern(), typ, &s, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -14382,7 +14509,8 @@ pub mod core {
 		/// * type: Array type. Use CV_8UC1, ..., CV_64FC4 to create 1-4 channel matrices, or
 		///    CV_8UC(n), ..., CV_64FC(n) to create multi-channel (up to CV_CN_MAX channels) matrices.
 		#[inline]
-		pub unsafe fn new_nd(ndims: i32, sizes: &i32, typ: i32) -> Result<core::Mat> {
+		pub unsafe fn new_nd(ndims: i32, sizes: &i32, typ: impl Into<core::MatType>) -> Result<core::Mat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_Mat_Mat_int_const_intX_int(ndims, sizes, typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -14403,7 +14531,8 @@ pub mod core {
 		/// * type: Array type. Use CV_8UC1, ..., CV_64FC4 to create 1-4 channel matrices, or
 		///    CV_8UC(n), ..., CV_64FC(n) to create multi-channel (up to CV_CN_MAX channels) matrices.
 		#[inline]
-		pub unsafe fn new_nd_vec(sizes: &core::Vector<i32>, typ: i32) -> Result<core::Mat> {
+		pub unsafe fn new_nd_vec(sizes: &core::Vector<i32>, typ: impl Into<core::MatType>) -> Result<core::Mat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_Mat_Mat_const_vectorLintGR_int(sizes.as_raw_VectorOfi32(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -14428,7 +14557,8 @@ pub mod core {
 		///    the particular value after the construction, use the assignment operator
 		///    Mat::operator=(const Scalar& value) .
 		#[inline]
-		pub fn new_nd_with_default(sizes: &[i32], typ: i32, s: core::Scalar) -> Result<core::Mat> {
+		pub fn new_nd_with_default(sizes: &[i32], typ: impl Into<core::MatType>, s: core::Scalar) -> Result<core::Mat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_Mat_Mat_int_const_intX_int_const_ScalarR(sizes.len() as _, sizes.as_ptr(), typ, &s, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -14452,7 +14582,8 @@ pub mod core {
 		///    the particular value after the construction, use the assignment operator
 		///    Mat::operator=(const Scalar& value) .
 		#[inline]
-		pub fn new_nd_vec_with_default(sizes: &core::Vector<i32>, typ: i32, s: core::Scalar) -> Result<core::Mat> {
+		pub fn new_nd_vec_with_default(sizes: &core::Vector<i32>, typ: impl Into<core::MatType>, s: core::Scalar) -> Result<core::Mat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_Mat_Mat_const_vectorLintGR_int_const_ScalarR(sizes.as_raw_VectorOfi32(), typ, &s, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -14508,7 +14639,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * step: AUTO_STEP
 		#[inline]
-		pub unsafe fn new_rows_cols_with_data(rows: i32, cols: i32, typ: i32, data: *mut c_void, step: size_t) -> Result<core::Mat> {
+		pub unsafe fn new_rows_cols_with_data(rows: i32, cols: i32, typ: impl Into<core::MatType>, data: *mut c_void, step: size_t) -> Result<core::Mat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_Mat_Mat_int_int_int_voidX_size_t(rows, cols, typ, data, step, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -14541,7 +14673,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * step: AUTO_STEP
 		#[inline]
-		pub unsafe fn new_size_with_data(size: core::Size, typ: i32, data: *mut c_void, step: size_t) -> Result<core::Mat> {
+		pub unsafe fn new_size_with_data(size: core::Size, typ: impl Into<core::MatType>, data: *mut c_void, step: size_t) -> Result<core::Mat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_Mat_Mat_Size_int_voidX_size_t(size.opencv_as_extern(), typ, data, step, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -14573,7 +14706,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * steps: 0
 		#[inline]
-		pub unsafe fn new_nd_with_data(sizes: &[i32], typ: i32, data: *mut c_void, steps: Option<&[size_t]>) -> Result<core::Mat> {
+		pub unsafe fn new_nd_with_data(sizes: &[i32], typ: impl Into<core::MatType>, data: *mut c_void, steps: Option<&[size_t]>) -> Result<core::Mat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_Mat_Mat_int_const_intX_int_voidX_const_size_tX(sizes.len() as _, sizes.as_ptr(), typ, data, steps.map_or(::core::ptr::null(), |steps| steps.as_ptr()), ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -14604,7 +14738,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * steps: 0
 		#[inline]
-		pub unsafe fn new_nd_vec_with_data(sizes: &core::Vector<i32>, typ: i32, data: *mut c_void, steps: Option<&[size_t]>) -> Result<core::Mat> {
+		pub unsafe fn new_nd_vec_with_data(sizes: &core::Vector<i32>, typ: impl Into<core::MatType>, data: *mut c_void, steps: Option<&[size_t]>) -> Result<core::Mat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_Mat_Mat_const_vectorLintGR_int_voidX_const_size_tX(sizes.as_raw_VectorOfi32(), typ, data, steps.map_or(::core::ptr::null(), |steps| steps.as_ptr()), ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -14734,7 +14869,8 @@ pub mod core {
 		/// * type: Created matrix type.
 		#[inline]
 		#[must_use]
-		pub fn zeros(rows: i32, cols: i32, typ: i32) -> Result<core::MatExpr> {
+		pub fn zeros(rows: i32, cols: i32, typ: impl Into<core::MatType>) -> Result<core::MatExpr> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_Mat_zeros_int_int_int(rows, cols, typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -14765,7 +14901,8 @@ pub mod core {
 		/// * type: Created matrix type.
 		#[inline]
 		#[must_use]
-		pub fn zeros_size(size: core::Size, typ: i32) -> Result<core::MatExpr> {
+		pub fn zeros_size(size: core::Size, typ: impl Into<core::MatType>) -> Result<core::MatExpr> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_Mat_zeros_Size_int(size.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -14797,7 +14934,8 @@ pub mod core {
 		/// * type: Created matrix type.
 		#[inline]
 		#[must_use]
-		pub fn zeros_nd(sz: &[i32], typ: i32) -> Result<core::MatExpr> {
+		pub fn zeros_nd(sz: &[i32], typ: impl Into<core::MatType>) -> Result<core::MatExpr> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_Mat_zeros_int_const_intX_int(sz.len() as _, sz.as_ptr(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -14826,7 +14964,8 @@ pub mod core {
 		/// * type: Created matrix type.
 		#[inline]
 		#[must_use]
-		pub fn ones(rows: i32, cols: i32, typ: i32) -> Result<core::MatExpr> {
+		pub fn ones(rows: i32, cols: i32, typ: impl Into<core::MatType>) -> Result<core::MatExpr> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_Mat_ones_int_int_int(rows, cols, typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -14860,7 +14999,8 @@ pub mod core {
 		/// * type: Created matrix type.
 		#[inline]
 		#[must_use]
-		pub fn ones_size(size: core::Size, typ: i32) -> Result<core::MatExpr> {
+		pub fn ones_size(size: core::Size, typ: impl Into<core::MatType>) -> Result<core::MatExpr> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_Mat_ones_Size_int(size.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -14895,7 +15035,8 @@ pub mod core {
 		/// * type: Created matrix type.
 		#[inline]
 		#[must_use]
-		pub fn ones_nd(sz: &[i32], typ: i32) -> Result<core::MatExpr> {
+		pub fn ones_nd(sz: &[i32], typ: impl Into<core::MatType>) -> Result<core::MatExpr> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_Mat_ones_int_const_intX_int(sz.len() as _, sz.as_ptr(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -14922,7 +15063,8 @@ pub mod core {
 		/// * type: Created matrix type.
 		#[inline]
 		#[must_use]
-		pub fn eye(rows: i32, cols: i32, typ: i32) -> Result<core::MatExpr> {
+		pub fn eye(rows: i32, cols: i32, typ: impl Into<core::MatType>) -> Result<core::MatExpr> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_Mat_eye_int_int_int(rows, cols, typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -14954,7 +15096,8 @@ pub mod core {
 		/// * type: Created matrix type.
 		#[inline]
 		#[must_use]
-		pub fn eye_size(size: core::Size, typ: i32) -> Result<core::MatExpr> {
+		pub fn eye_size(size: core::Size, typ: impl Into<core::MatType>) -> Result<core::MatExpr> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_Mat_eye_Size_int(size.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -15629,7 +15772,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * typ: -1
 		#[inline]
-		fn assign(&self, expr: &core::MatExpr, m: &mut core::Mat, typ: i32) -> Result<()> {
+		fn assign(&self, expr: &core::MatExpr, m: &mut core::Mat, typ: impl Into<core::MatType>) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_MatOp_assign_const_const_MatExprR_MatR_int(self.as_raw_MatOp(), expr.as_raw_MatExpr(), m.as_raw_mut_Mat(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -16809,6 +16953,53 @@ pub mod core {
 		}
 	}
 	
+	/// Rust implementation of the virtual methods of [core::MinProblemSolver_Function]
+	///
+	/// Use [core::MinProblemSolver_Function::from_director] or [core::Ptr::<core::MinProblemSolver_Function>::from_director] to get an OpenCV object that forwards
+	/// the calls to this implementation. Panics and errors returned from the methods are passed to OpenCV as `cv::Exception`.
+	pub trait MinProblemSolver_FunctionDirector: Send + Sync {
+		fn get_dims(&self) -> Result<i32>;
+		fn calc(&self, x: &[f64]) -> Result<f64>;
+	}
+	
+	impl MinProblemSolver_Function {
+		/// Creates [core::MinProblemSolver_Function] that forwards its virtual method calls to the passed Rust implementation
+		#[inline]
+		pub fn from_director(director: Box<dyn core::MinProblemSolver_FunctionDirector>) -> Self {
+			extern "C" { fn cv_MinProblemSolver_Function_director_new(ocvrs_self: *mut c_void) -> *mut c_void; }
+			let ocvrs_self = Box::into_raw(Box::new(director)) as *mut c_void;
+			unsafe { Self::from_raw(cv_MinProblemSolver_Function_director_new(ocvrs_self)) }
+		}
+	}
+	
+	impl core::Ptr<core::MinProblemSolver_Function> {
+		/// Creates [core::MinProblemSolver_Function] that forwards its virtual method calls to the passed Rust implementation
+		#[inline]
+		pub fn from_director(director: Box<dyn core::MinProblemSolver_FunctionDirector>) -> Self {
+			extern "C" { fn cv_PtrOfMinProblemSolver_Function_director_new(ocvrs_self: *mut c_void) -> *mut c_void; }
+			let ocvrs_self = Box::into_raw(Box::new(director)) as *mut c_void;
+			unsafe { Self::from_raw(cv_PtrOfMinProblemSolver_Function_director_new(ocvrs_self)) }
+		}
+	}
+	
+	#[no_mangle]
+	unsafe extern "C" fn ocvrs_director_MinProblemSolver_Function_delete(ocvrs_self: *mut c_void) {
+		crate::templ::director_delete(ocvrs_self as *mut Box<dyn core::MinProblemSolver_FunctionDirector>);
+	}
+	
+	#[no_mangle]
+	unsafe extern "C" fn ocvrs_director_MinProblemSolver_Function_get_dims(ocvrs_self: *const c_void, ocvrs_return: *mut sys::Result<i32>) {
+		let ocvrs_self = &*(ocvrs_self as *const Box<dyn core::MinProblemSolver_FunctionDirector>);
+		crate::templ::director_call(ocvrs_return, || ocvrs_self.get_dims());
+	}
+	
+	#[no_mangle]
+	unsafe extern "C" fn ocvrs_director_MinProblemSolver_Function_calc(ocvrs_self: *const c_void, x: *const f64, x_len: size_t, ocvrs_return: *mut sys::Result<f64>) {
+		let ocvrs_self = &*(ocvrs_self as *const Box<dyn core::MinProblemSolver_FunctionDirector>);
+		let x = ::std::slice::from_raw_parts(x, x_len);
+		crate::templ::director_call(ocvrs_return, || ocvrs_self.calc(x));
+	}
+	
 	/// struct returned by cv::moments
 	/// 
 	/// The spatial moments ![inline formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7BMoments%3A%3Am%7D%5F%7Bji%7D) are computed as:
@@ -17008,6 +17199,7 @@ pub mod core {
 			unsafe { sys::cv_PCA_project_const_const__InputArrayR_const__OutputArrayR(self.as_raw_PCA(), vec.as_raw__InputArray(), result.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(result);
 			Ok(ret)
 		}
 		
@@ -17064,6 +17256,7 @@ pub mod core {
 			unsafe { sys::cv_PCA_backProject_const_const__InputArrayR_const__OutputArrayR(self.as_raw_PCA(), vec.as_raw__InputArray(), result.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(result);
 			Ok(ret)
 		}
 		
@@ -17431,6 +17624,46 @@ pub mod core {
 		}
 	}
 	
+	/// Rust implementation of the virtual methods of [core::ParallelLoopBody]
+	///
+	/// Use [core::ParallelLoopBody::from_director] or [core::Ptr::<core::ParallelLoopBody>::from_director] to get an OpenCV object that forwards
+	/// the calls to this implementation. Panics and errors returned from the methods are passed to OpenCV as `cv::Exception`.
+	pub trait ParallelLoopBodyDirector: Send + Sync {
+		fn apply(&self, range: &core::Range) -> Result<()>;
+	}
+	
+	impl ParallelLoopBody {
+		/// Creates [core::ParallelLoopBody] that forwards its virtual method calls to the passed Rust implementation
+		#[inline]
+		pub fn from_director(director: Box<dyn core::ParallelLoopBodyDirector>) -> Self {
+			extern "C" { fn cv_ParallelLoopBody_director_new(ocvrs_self: *mut c_void) -> *mut c_void; }
+			let ocvrs_self = Box::into_raw(Box::new(director)) as *mut c_void;
+			unsafe { Self::from_raw(cv_ParallelLoopBody_director_new(ocvrs_self)) }
+		}
+	}
+	
+	impl core::Ptr<core::ParallelLoopBody> {
+		/// Creates [core::ParallelLoopBody] that forwards its virtual method calls to the passed Rust implementation
+		#[inline]
+		pub fn from_director(director: Box<dyn core::ParallelLoopBodyDirector>) -> Self {
+			extern "C" { fn cv_PtrOfParallelLoopBody_director_new(ocvrs_self: *mut c_void) -> *mut c_void; }
+			let ocvrs_self = Box::into_raw(Box::new(director)) as *mut c_void;
+			unsafe { Self::from_raw(cv_PtrOfParallelLoopBody_director_new(ocvrs_self)) }
+		}
+	}
+	
+	#[no_mangle]
+	unsafe extern "C" fn ocvrs_director_ParallelLoopBody_delete(ocvrs_self: *mut c_void) {
+		crate::templ::director_delete(ocvrs_self as *mut Box<dyn core::ParallelLoopBodyDirector>);
+	}
+	
+	#[no_mangle]
+	unsafe extern "C" fn ocvrs_director_ParallelLoopBody_apply(ocvrs_self: *const c_void, range: *mut c_void, ocvrs_return: *mut sys::Result<()>) {
+		let ocvrs_self = &*(ocvrs_self as *const Box<dyn core::ParallelLoopBodyDirector>);
+		let range = ::std::mem::ManuallyDrop::new(<core::Range>::from_raw(range));
+		crate::templ::director_call(ocvrs_return, || ocvrs_self.apply(&*range));
+	}
+	
 	/// Constant methods for [core::RNG]
 	pub trait RNGTraitConst {
 		fn as_raw_RNG(&self) -> *const c_void;
@@ -18369,6 +18602,7 @@ pub mod core {
 			unsafe { sys::cv_SVD_backSubst_const_const__InputArrayR_const__OutputArrayR(self.as_raw_SVD(), rhs.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
@@ -18529,6 +18763,9 @@ pub mod core {
 			unsafe { sys::cv_SVD_compute_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), w.as_raw__OutputArray(), u.as_raw__OutputArray(), vt.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(w);
+			output_array_finish!(u);
+			output_array_finish!(vt);
 			Ok(ret)
 		}
 		
@@ -18568,6 +18805,7 @@ pub mod core {
 			unsafe { sys::cv_SVD_compute_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), w.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(w);
 			Ok(ret)
 		}
 		
@@ -18583,6 +18821,7 @@ pub mod core {
 			unsafe { sys::cv_SVD_backSubst_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(w.as_raw__InputArray(), u.as_raw__InputArray(), vt.as_raw__InputArray(), rhs.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
@@ -18604,6 +18843,7 @@ pub mod core {
 			unsafe { sys::cv_SVD_solveZ_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
@@ -18667,7 +18907,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * alpha: 1
 		#[inline]
-		fn convert_to(&self, m: &mut core::SparseMat, rtype: i32, alpha: f64) -> Result<()> {
+		fn convert_to(&self, m: &mut core::SparseMat, rtype: impl Into<core::MatType>, alpha: f64) -> Result<()> {
+			let rtype = i32::from(rtype.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_SparseMat_convertTo_const_SparseMatR_int_double(self.as_raw_SparseMat(), m.as_raw_mut_SparseMat(), rtype, alpha, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -18690,7 +18931,8 @@ pub mod core {
 		/// * alpha: 1
 		/// * beta: 0
 		#[inline]
-		fn convert_to_1(&self, m: &mut core::Mat, rtype: i32, alpha: f64, beta: f64) -> Result<()> {
+		fn convert_to_1(&self, m: &mut core::Mat, rtype: impl Into<core::MatType>, alpha: f64, beta: f64) -> Result<()> {
+			let rtype = i32::from(rtype.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_SparseMat_convertTo_const_MatR_int_double_double(self.as_raw_SparseMat(), m.as_raw_mut_Mat(), rtype, alpha, beta, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -18701,7 +18943,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * typ: -1
 		#[inline]
-		fn assign_to(&self, m: &mut core::SparseMat, typ: i32) -> Result<()> {
+		fn assign_to(&self, m: &mut core::SparseMat, typ: impl Into<core::MatType>) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_SparseMat_assignTo_const_SparseMatR_int(self.as_raw_SparseMat(), m.as_raw_mut_SparseMat(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -18887,7 +19130,8 @@ pub mod core {
 		///    it is simply cleared with clear(), otherwise,
 		///    the old matrix is released (using release()) and the new one is allocated.
 		#[inline]
-		fn create(&mut self, dims: i32, _sizes: &i32, _type: i32) -> Result<()> {
+		fn create(&mut self, dims: i32, _sizes: &i32, _type: impl Into<core::MatType>) -> Result<()> {
+			let _type = i32::from(_type.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_SparseMat_create_int_const_intX_int(self.as_raw_mut_SparseMat(), dims, _sizes, _type, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -19216,7 +19460,8 @@ pub mod core {
 		/// * _sizes: Sparce matrix size on all dementions.
 		/// * _type: Sparse matrix data type.
 		#[inline]
-		pub fn new(dims: i32, _sizes: &i32, _type: i32) -> Result<core::SparseMat> {
+		pub fn new(dims: i32, _sizes: &i32, _type: impl Into<core::MatType>) -> Result<core::SparseMat> {
+			let _type = i32::from(_type.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_SparseMat_SparseMat_int_const_intX_int(dims, _sizes, _type, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -19431,7 +19676,8 @@ pub mod core {
 	
 	impl SparseMat_Hdr {
 		#[inline]
-		pub fn new(_sizes: &[i32], _type: i32) -> Result<core::SparseMat_Hdr> {
+		pub fn new(_sizes: &[i32], _type: impl Into<core::MatType>) -> Result<core::SparseMat_Hdr> {
+			let _type = i32::from(_type.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_SparseMat_Hdr_Hdr_int_const_intX_int(_sizes.len() as _, _sizes.as_ptr(), _type, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -20239,6 +20485,7 @@ pub mod core {
 			unsafe { sys::cv_UMat_copyTo_const_const__OutputArrayR(self.as_raw_UMat(), m.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(m);
 			Ok(ret)
 		}
 		
@@ -20251,6 +20498,7 @@ pub mod core {
 			unsafe { sys::cv_UMat_copyTo_const_const__OutputArrayR_const__InputArrayR(self.as_raw_UMat(), m.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(m);
 			Ok(ret)
 		}
 		
@@ -20260,19 +20508,22 @@ pub mod core {
 		/// * alpha: 1
 		/// * beta: 0
 		#[inline]
-		fn convert_to(&self, m: &mut impl core::ToOutputArray, rtype: i32, alpha: f64, beta: f64) -> Result<()> {
+		fn convert_to(&self, m: &mut impl core::ToOutputArray, rtype: impl Into<core::MatType>, alpha: f64, beta: f64) -> Result<()> {
 			output_array_arg!(m);
+			let rtype = i32::from(rtype.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_convertTo_const_const__OutputArrayR_int_double_double(self.as_raw_UMat(), m.as_raw__OutputArray(), rtype, alpha, beta, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(m);
 			Ok(ret)
 		}
 		
 		/// ## C++ default parameters
 		/// * typ: -1
 		#[inline]
-		fn assign_to(&self, m: &mut core::UMat, typ: i32) -> Result<()> {
+		fn assign_to(&self, m: &mut core::UMat, typ: impl Into<core::MatType>) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_assignTo_const_UMatR_int(self.as_raw_UMat(), m.as_raw_mut_UMat(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -20608,7 +20859,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * usage_flags: USAGE_DEFAULT
 		#[inline]
-		unsafe fn create_rows_cols(&mut self, rows: i32, cols: i32, typ: i32, usage_flags: core::UMatUsageFlags) -> Result<()> {
+		unsafe fn create_rows_cols(&mut self, rows: i32, cols: i32, typ: impl Into<core::MatType>, usage_flags: core::UMatUsageFlags) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_UMat_create_int_int_int_UMatUsageFlags(self.as_raw_mut_UMat(), rows, cols, typ, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -20619,7 +20871,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * usage_flags: USAGE_DEFAULT
 		#[inline]
-		unsafe fn create_size(&mut self, size: core::Size, typ: i32, usage_flags: core::UMatUsageFlags) -> Result<()> {
+		unsafe fn create_size(&mut self, size: core::Size, typ: impl Into<core::MatType>, usage_flags: core::UMatUsageFlags) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_UMat_create_Size_int_UMatUsageFlags(self.as_raw_mut_UMat(), size.opencv_as_extern(), typ, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -20630,7 +20883,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * usage_flags: USAGE_DEFAULT
 		#[inline]
-		unsafe fn create_nd(&mut self, sizes: &[i32], typ: i32, usage_flags: core::UMatUsageFlags) -> Result<()> {
+		unsafe fn create_nd(&mut self, sizes: &[i32], typ: impl Into<core::MatType>, usage_flags: core::UMatUsageFlags) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_UMat_create_int_const_intX_int_UMatUsageFlags(self.as_raw_mut_UMat(), sizes.len() as _, sizes.as_ptr(), typ, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -20641,7 +20895,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * usage_flags: USAGE_DEFAULT
 		#[inline]
-		unsafe fn create_nd_vec(&mut self, sizes: &core::Vector<i32>, typ: i32, usage_flags: core::UMatUsageFlags) -> Result<()> {
+		unsafe fn create_nd_vec(&mut self, sizes: &core::Vector<i32>, typ: impl Into<core::MatType>, usage_flags: core::UMatUsageFlags) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_UMat_create_const_vectorLintGR_int_UMatUsageFlags(self.as_raw_mut_UMat(), sizes.as_raw_VectorOfi32(), typ, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -20744,7 +20999,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * usage_flags: USAGE_DEFAULT
 		#[inline]
-		pub unsafe fn new_rows_cols(rows: i32, cols: i32, typ: i32, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+		pub unsafe fn new_rows_cols(rows: i32, cols: i32, typ: impl Into<core::MatType>, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_UMat_UMat_int_int_int_UMatUsageFlags(rows, cols, typ, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -20756,7 +21012,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * usage_flags: USAGE_DEFAULT
 		#[inline]
-		pub unsafe fn new_size(size: core::Size, typ: i32, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+		pub unsafe fn new_size(size: core::Size, typ: impl Into<core::MatType>, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_UMat_UMat_Size_int_UMatUsageFlags(size.opencv_as_extern(), typ, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -20770,7 +21027,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * usage_flags: USAGE_DEFAULT
 		#[inline]
-		pub fn new_rows_cols_with_default(rows: i32, cols: i32, typ: i32, s: core::Scalar, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+		pub fn new_rows_cols_with_default(rows: i32, cols: i32, typ: impl Into<core::MatType>, s: core::Scalar, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_UMat_int_int_int_const_ScalarR_UMatUsageFlags(rows, cols, typ, &s, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -20782,7 +21040,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * usage_flags: USAGE_DEFAULT
 		#[inline]
-		pub fn new_size_with_default(size: core::Size, typ: i32, s: core::Scalar, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+		pub fn new_size_with_default(size: core::Size, typ: impl Into<core::MatType>, s: core::Scalar, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_UMat_Size_int_const_ScalarR_UMatUsageFlags(size.opencv_as_extern(), typ, &s, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -20796,7 +21055,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * usage_flags: USAGE_DEFAULT
 		#[inline]
-		pub unsafe fn new_nd(sizes: &[i32], typ: i32, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+		pub unsafe fn new_nd(sizes: &[i32], typ: impl Into<core::MatType>, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_UMat_UMat_int_const_intX_int_UMatUsageFlags(sizes.len() as _, sizes.as_ptr(), typ, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -20808,7 +21068,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * usage_flags: USAGE_DEFAULT
 		#[inline]
-		pub fn new_nd_with_default(sizes: &[i32], typ: i32, s: core::Scalar, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+		pub fn new_nd_with_default(sizes: &[i32], typ: impl Into<core::MatType>, s: core::Scalar, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_UMat_int_const_intX_int_const_ScalarR_UMatUsageFlags(sizes.len() as _, sizes.as_ptr(), typ, &s, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -20888,7 +21149,8 @@ pub mod core {
 		/// Matlab-style matrix initialization
 		#[inline]
 		#[must_use]
-		pub fn zeros(rows: i32, cols: i32, typ: i32, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+		pub fn zeros(rows: i32, cols: i32, typ: impl Into<core::MatType>, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_zeros_int_int_int_UMatUsageFlags(rows, cols, typ, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -20899,7 +21161,8 @@ pub mod core {
 		
 		#[inline]
 		#[must_use]
-		pub fn zeros_1(size: core::Size, typ: i32, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+		pub fn zeros_1(size: core::Size, typ: impl Into<core::MatType>, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_zeros_Size_int_UMatUsageFlags(size.opencv_as_extern(), typ, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -20910,7 +21173,8 @@ pub mod core {
 		
 		#[inline]
 		#[must_use]
-		pub fn zeros_2(ndims: i32, sz: &i32, typ: i32, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+		pub fn zeros_2(ndims: i32, sz: &i32, typ: impl Into<core::MatType>, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_zeros_int_const_intX_int_UMatUsageFlags(ndims, sz, typ, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -20921,7 +21185,8 @@ pub mod core {
 		
 		#[inline]
 		#[must_use]
-		pub fn zeros_3(rows: i32, cols: i32, typ: i32) -> Result<core::UMat> {
+		pub fn zeros_3(rows: i32, cols: i32, typ: impl Into<core::MatType>) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_zeros_int_int_int(rows, cols, typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -20932,7 +21197,8 @@ pub mod core {
 		
 		#[inline]
 		#[must_use]
-		pub fn zeros_4(size: core::Size, typ: i32) -> Result<core::UMat> {
+		pub fn zeros_4(size: core::Size, typ: impl Into<core::MatType>) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_zeros_Size_int(size.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -20943,7 +21209,8 @@ pub mod core {
 		
 		#[inline]
 		#[must_use]
-		pub fn zeros_5(ndims: i32, sz: &i32, typ: i32) -> Result<core::UMat> {
+		pub fn zeros_5(ndims: i32, sz: &i32, typ: impl Into<core::MatType>) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_zeros_int_const_intX_int(ndims, sz, typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -20954,7 +21221,8 @@ pub mod core {
 		
 		#[inline]
 		#[must_use]
-		pub fn ones(rows: i32, cols: i32, typ: i32, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+		pub fn ones(rows: i32, cols: i32, typ: impl Into<core::MatType>, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_ones_int_int_int_UMatUsageFlags(rows, cols, typ, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -20965,7 +21233,8 @@ pub mod core {
 		
 		#[inline]
 		#[must_use]
-		pub fn ones_1(size: core::Size, typ: i32, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+		pub fn ones_1(size: core::Size, typ: impl Into<core::MatType>, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_ones_Size_int_UMatUsageFlags(size.opencv_as_extern(), typ, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -20976,7 +21245,8 @@ pub mod core {
 		
 		#[inline]
 		#[must_use]
-		pub fn ones_2(ndims: i32, sz: &i32, typ: i32, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+		pub fn ones_2(ndims: i32, sz: &i32, typ: impl Into<core::MatType>, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_ones_int_const_intX_int_UMatUsageFlags(ndims, sz, typ, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -20987,7 +21257,8 @@ pub mod core {
 		
 		#[inline]
 		#[must_use]
-		pub fn ones_3(rows: i32, cols: i32, typ: i32) -> Result<core::UMat> {
+		pub fn ones_3(rows: i32, cols: i32, typ: impl Into<core::MatType>) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_ones_int_int_int(rows, cols, typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -20998,7 +21269,8 @@ pub mod core {
 		
 		#[inline]
 		#[must_use]
-		pub fn ones_4(size: core::Size, typ: i32) -> Result<core::UMat> {
+		pub fn ones_4(size: core::Size, typ: impl Into<core::MatType>) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_ones_Size_int(size.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -21009,7 +21281,8 @@ pub mod core {
 		
 		#[inline]
 		#[must_use]
-		pub fn ones_5(ndims: i32, sz: &i32, typ: i32) -> Result<core::UMat> {
+		pub fn ones_5(ndims: i32, sz: &i32, typ: impl Into<core::MatType>) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_ones_int_const_intX_int(ndims, sz, typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -21020,7 +21293,8 @@ pub mod core {
 		
 		#[inline]
 		#[must_use]
-		pub fn eye(rows: i32, cols: i32, typ: i32, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+		pub fn eye(rows: i32, cols: i32, typ: impl Into<core::MatType>, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_eye_int_int_int_UMatUsageFlags(rows, cols, typ, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -21031,7 +21305,8 @@ pub mod core {
 		
 		#[inline]
 		#[must_use]
-		pub fn eye_1(size: core::Size, typ: i32, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+		pub fn eye_1(size: core::Size, typ: impl Into<core::MatType>, usage_flags: core::UMatUsageFlags) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_eye_Size_int_UMatUsageFlags(size.opencv_as_extern(), typ, usage_flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -21042,7 +21317,8 @@ pub mod core {
 		
 		#[inline]
 		#[must_use]
-		pub fn eye_2(rows: i32, cols: i32, typ: i32) -> Result<core::UMat> {
+		pub fn eye_2(rows: i32, cols: i32, typ: impl Into<core::MatType>) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_eye_int_int_int(rows, cols, typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -21053,7 +21329,8 @@ pub mod core {
 		
 		#[inline]
 		#[must_use]
-		pub fn eye_3(size: core::Size, typ: i32) -> Result<core::UMat> {
+		pub fn eye_3(size: core::Size, typ: impl Into<core::MatType>) -> Result<core::UMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_UMat_eye_Size_int(size.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -21655,6 +21932,7 @@ pub mod core {
 			unsafe { sys::cv__InputArray_copyTo_const_const__OutputArrayR(self.as_raw__InputArray(), arr.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(arr);
 			Ok(ret)
 		}
 		
@@ -21666,6 +21944,7 @@ pub mod core {
 			unsafe { sys::cv__InputArray_copyTo_const_const__OutputArrayR_const__InputArrayR(self.as_raw__InputArray(), arr.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(arr);
 			Ok(ret)
 		}
 		
@@ -22360,7 +22639,8 @@ pub mod core {
 		/// * allow_transposed: false
 		/// * fixed_depth_mask: static_cast<_OutputArray::DepthMask>(0)
 		#[inline]
-		fn create_size(&self, sz: core::Size, typ: i32, i: i32, allow_transposed: bool, fixed_depth_mask: core::_OutputArray_DepthMask) -> Result<()> {
+		fn create_size(&self, sz: core::Size, typ: impl Into<core::MatType>, i: i32, allow_transposed: bool, fixed_depth_mask: core::_OutputArray_DepthMask) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv__OutputArray_create_const_Size_int_int_bool_DepthMask(self.as_raw__OutputArray(), sz.opencv_as_extern(), typ, i, allow_transposed, fixed_depth_mask, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -22373,7 +22653,8 @@ pub mod core {
 		/// * allow_transposed: false
 		/// * fixed_depth_mask: static_cast<_OutputArray::DepthMask>(0)
 		#[inline]
-		fn create(&self, rows: i32, cols: i32, typ: i32, i: i32, allow_transposed: bool, fixed_depth_mask: core::_OutputArray_DepthMask) -> Result<()> {
+		fn create(&self, rows: i32, cols: i32, typ: impl Into<core::MatType>, i: i32, allow_transposed: bool, fixed_depth_mask: core::_OutputArray_DepthMask) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv__OutputArray_create_const_int_int_int_int_bool_DepthMask(self.as_raw__OutputArray(), rows, cols, typ, i, allow_transposed, fixed_depth_mask, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -22386,7 +22667,8 @@ pub mod core {
 		/// * allow_transposed: false
 		/// * fixed_depth_mask: static_cast<_OutputArray::DepthMask>(0)
 		#[inline]
-		fn create_nd(&self, size: &[i32], typ: i32, i: i32, allow_transposed: bool, fixed_depth_mask: core::_OutputArray_DepthMask) -> Result<()> {
+		fn create_nd(&self, size: &[i32], typ: impl Into<core::MatType>, i: i32, allow_transposed: bool, fixed_depth_mask: core::_OutputArray_DepthMask) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv__OutputArray_create_const_int_const_intX_int_int_bool_DepthMask(self.as_raw__OutputArray(), size.len() as _, size.as_ptr(), typ, i, allow_transposed, fixed_depth_mask, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -22395,8 +22677,9 @@ pub mod core {
 		}
 		
 		#[inline]
-		unsafe fn create_same_size(&self, arr: &impl core::ToInputArray, mtype: i32) -> Result<()> {
+		unsafe fn create_same_size(&self, arr: &impl core::ToInputArray, mtype: impl Into<core::MatType>) -> Result<()> {
 			input_array_arg!(arr);
+			let mtype = i32::from(mtype.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv__OutputArray_createSameSize_const_const__InputArrayR_int(self.as_raw__OutputArray(), arr.as_raw__InputArray(), mtype, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -22772,7 +23055,8 @@ pub mod core {
 	
 		/// Allocates a new GpuMat of given size and type.
 		#[inline]
-		fn get_buffer(&mut self, rows: i32, cols: i32, typ: i32) -> Result<core::GpuMat> {
+		fn get_buffer(&mut self, rows: i32, cols: i32, typ: impl Into<core::MatType>) -> Result<core::GpuMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_BufferPool_getBuffer_int_int_int(self.as_raw_mut_BufferPool(), rows, cols, typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -22783,7 +23067,8 @@ pub mod core {
 		
 		/// Allocates a new GpuMat of given size and type.
 		#[inline]
-		fn get_buffer_1(&mut self, size: core::Size, typ: i32) -> Result<core::GpuMat> {
+		fn get_buffer_1(&mut self, size: core::Size, typ: impl Into<core::MatType>) -> Result<core::GpuMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_BufferPool_getBuffer_Size_int(self.as_raw_mut_BufferPool(), size.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -23863,6 +24148,7 @@ pub mod core {
 			unsafe { sys::cv_cuda_GpuMat_download_const_const__OutputArrayR(self.as_raw_GpuMat(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
@@ -23880,6 +24166,7 @@ pub mod core {
 			unsafe { sys::cv_cuda_GpuMat_download_const_const__OutputArrayR_StreamR(self.as_raw_GpuMat(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
@@ -23902,6 +24189,7 @@ pub mod core {
 			unsafe { sys::cv_cuda_GpuMat_copyTo_const_const__OutputArrayR(self.as_raw_GpuMat(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
@@ -23913,6 +24201,7 @@ pub mod core {
 			unsafe { sys::cv_cuda_GpuMat_copyTo_const_const__OutputArrayR_StreamR(self.as_raw_GpuMat(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
@@ -23925,6 +24214,7 @@ pub mod core {
 			unsafe { sys::cv_cuda_GpuMat_copyTo_const_const__OutputArrayR_const__InputArrayR(self.as_raw_GpuMat(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
@@ -23937,28 +24227,33 @@ pub mod core {
 			unsafe { sys::cv_cuda_GpuMat_copyTo_const_const__OutputArrayR_const__InputArrayR_StreamR(self.as_raw_GpuMat(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
 		/// converts GpuMat to another datatype (Blocking call)
 		#[inline]
-		fn convert_to(&self, dst: &mut impl core::ToOutputArray, rtype: i32) -> Result<()> {
+		fn convert_to(&self, dst: &mut impl core::ToOutputArray, rtype: impl Into<core::MatType>) -> Result<()> {
 			output_array_arg!(dst);
+			let rtype = i32::from(rtype.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_GpuMat_convertTo_const_const__OutputArrayR_int(self.as_raw_GpuMat(), dst.as_raw__OutputArray(), rtype, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
 		/// converts GpuMat to another datatype (Non-Blocking call)
 		#[inline]
-		fn convert_to_1(&self, dst: &mut impl core::ToOutputArray, rtype: i32, stream: &mut core::Stream) -> Result<()> {
+		fn convert_to_1(&self, dst: &mut impl core::ToOutputArray, rtype: impl Into<core::MatType>, stream: &mut core::Stream) -> Result<()> {
 			output_array_arg!(dst);
+			let rtype = i32::from(rtype.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_GpuMat_convertTo_const_const__OutputArrayR_int_StreamR(self.as_raw_GpuMat(), dst.as_raw__OutputArray(), rtype, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
@@ -23967,41 +24262,48 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * beta: 0.0
 		#[inline]
-		fn convert_to_2(&self, dst: &mut impl core::ToOutputArray, rtype: i32, alpha: f64, beta: f64) -> Result<()> {
+		fn convert_to_2(&self, dst: &mut impl core::ToOutputArray, rtype: impl Into<core::MatType>, alpha: f64, beta: f64) -> Result<()> {
 			output_array_arg!(dst);
+			let rtype = i32::from(rtype.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_GpuMat_convertTo_const_const__OutputArrayR_int_double_double(self.as_raw_GpuMat(), dst.as_raw__OutputArray(), rtype, alpha, beta, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
 		/// converts GpuMat to another datatype with scaling (Non-Blocking call)
 		#[inline]
-		fn convert_to_3(&self, dst: &mut impl core::ToOutputArray, rtype: i32, alpha: f64, stream: &mut core::Stream) -> Result<()> {
+		fn convert_to_3(&self, dst: &mut impl core::ToOutputArray, rtype: impl Into<core::MatType>, alpha: f64, stream: &mut core::Stream) -> Result<()> {
 			output_array_arg!(dst);
+			let rtype = i32::from(rtype.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_GpuMat_convertTo_const_const__OutputArrayR_int_double_StreamR(self.as_raw_GpuMat(), dst.as_raw__OutputArray(), rtype, alpha, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
 		/// converts GpuMat to another datatype with scaling (Non-Blocking call)
 		#[inline]
-		fn convert_to_4(&self, dst: &mut impl core::ToOutputArray, rtype: i32, alpha: f64, beta: f64, stream: &mut core::Stream) -> Result<()> {
+		fn convert_to_4(&self, dst: &mut impl core::ToOutputArray, rtype: impl Into<core::MatType>, alpha: f64, beta: f64, stream: &mut core::Stream) -> Result<()> {
 			output_array_arg!(dst);
+			let rtype = i32::from(rtype.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_GpuMat_convertTo_const_const__OutputArrayR_int_double_double_StreamR(self.as_raw_GpuMat(), dst.as_raw__OutputArray(), rtype, alpha, beta, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
 		/// ## C++ default parameters
 		/// * typ: -1
 		#[inline]
-		fn assign_to(&self, m: &mut core::GpuMat, typ: i32) -> Result<()> {
+		fn assign_to(&self, m: &mut core::GpuMat, typ: impl Into<core::MatType>) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_GpuMat_assignTo_const_GpuMatR_int(self.as_raw_GpuMat(), m.as_raw_mut_GpuMat(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -24329,7 +24631,8 @@ pub mod core {
 		
 		/// allocates new GpuMat data unless the GpuMat already has specified size and type
 		#[inline]
-		fn create(&mut self, rows: i32, cols: i32, typ: i32) -> Result<()> {
+		fn create(&mut self, rows: i32, cols: i32, typ: impl Into<core::MatType>) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_GpuMat_create_int_int_int(self.as_raw_mut_GpuMat(), rows, cols, typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -24338,7 +24641,8 @@ pub mod core {
 		}
 		
 		#[inline]
-		fn create_1(&mut self, size: core::Size, typ: i32) -> Result<()> {
+		fn create_1(&mut self, size: core::Size, typ: impl Into<core::MatType>) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_GpuMat_create_Size_int(self.as_raw_mut_GpuMat(), size.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -24575,7 +24879,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * allocator: GpuMat::defaultAllocator()
 		#[inline]
-		pub unsafe fn new_rows_cols(rows: i32, cols: i32, typ: i32, allocator: &mut core::GpuMat_Allocator) -> Result<core::GpuMat> {
+		pub unsafe fn new_rows_cols(rows: i32, cols: i32, typ: impl Into<core::MatType>, allocator: &mut core::GpuMat_Allocator) -> Result<core::GpuMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_cuda_GpuMat_GpuMat_int_int_int_AllocatorX(rows, cols, typ, allocator.as_raw_mut_GpuMat_Allocator(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -24587,7 +24892,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * allocator: GpuMat::defaultAllocator()
 		#[inline]
-		pub unsafe fn new_size(size: core::Size, typ: i32, allocator: &mut core::GpuMat_Allocator) -> Result<core::GpuMat> {
+		pub unsafe fn new_size(size: core::Size, typ: impl Into<core::MatType>, allocator: &mut core::GpuMat_Allocator) -> Result<core::GpuMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_cuda_GpuMat_GpuMat_Size_int_AllocatorX(size.opencv_as_extern(), typ, allocator.as_raw_mut_GpuMat_Allocator(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -24601,7 +24907,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * allocator: GpuMat::defaultAllocator()
 		#[inline]
-		pub unsafe fn new_rows_cols_with_default(rows: i32, cols: i32, typ: i32, s: core::Scalar, allocator: &mut core::GpuMat_Allocator) -> Result<core::GpuMat> {
+		pub unsafe fn new_rows_cols_with_default(rows: i32, cols: i32, typ: impl Into<core::MatType>, s: core::Scalar, allocator: &mut core::GpuMat_Allocator) -> Result<core::GpuMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_cuda_GpuMat_GpuMat_int_int_int_Scalar_AllocatorX(rows, cols, typ, s.opencv_as_extern(), allocator.as_raw_mut_GpuMat_Allocator(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -24613,7 +24920,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * allocator: GpuMat::defaultAllocator()
 		#[inline]
-		pub unsafe fn new_size_with_default(size: core::Size, typ: i32, s: core::Scalar, allocator: &mut core::GpuMat_Allocator) -> Result<core::GpuMat> {
+		pub unsafe fn new_size_with_default(size: core::Size, typ: impl Into<core::MatType>, s: core::Scalar, allocator: &mut core::GpuMat_Allocator) -> Result<core::GpuMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_cuda_GpuMat_GpuMat_Size_int_Scalar_AllocatorX(size.opencv_as_extern(), typ, s.opencv_as_extern(), allocator.as_raw_mut_GpuMat_Allocator(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -24638,7 +24946,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * step: Mat::AUTO_STEP
 		#[inline]
-		pub unsafe fn new_rows_cols_with_data(rows: i32, cols: i32, typ: i32, data: *mut c_void, step: size_t) -> Result<core::GpuMat> {
+		pub unsafe fn new_rows_cols_with_data(rows: i32, cols: i32, typ: impl Into<core::MatType>, data: *mut c_void, step: size_t) -> Result<core::GpuMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_cuda_GpuMat_GpuMat_int_int_int_voidX_size_t(rows, cols, typ, data, step, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -24650,7 +24959,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * step: Mat::AUTO_STEP
 		#[inline]
-		pub unsafe fn new_size_with_data(size: core::Size, typ: i32, data: *mut c_void, step: size_t) -> Result<core::GpuMat> {
+		pub unsafe fn new_size_with_data(size: core::Size, typ: impl Into<core::MatType>, data: *mut c_void, step: size_t) -> Result<core::GpuMat> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_cuda_GpuMat_GpuMat_Size_int_voidX_size_t(size.opencv_as_extern(), typ, data, step, ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -24946,6 +25256,7 @@ pub mod core {
 			unsafe { sys::cv_cuda_GpuMatND_download_const_const__OutputArrayR(self.as_raw_GpuMatND(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
@@ -24956,6 +25267,7 @@ pub mod core {
 			unsafe { sys::cv_cuda_GpuMatND_download_const_const__OutputArrayR_StreamR(self.as_raw_GpuMatND(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
@@ -25106,7 +25418,8 @@ pub mod core {
 		/// (i.e., isSubmatrix() is false). In other words, this method guarantees that the GPU memory allocated by
 		/// this method is always continuous and is not a sub-region of another GpuMatND.
 		#[inline]
-		fn create(&mut self, mut size: core::GpuMatND_SizeArray, typ: i32) -> Result<()> {
+		fn create(&mut self, mut size: core::GpuMatND_SizeArray, typ: impl Into<core::MatType>) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_GpuMatND_create_SizeArray_int(self.as_raw_mut_GpuMatND(), size.as_raw_mut_VectorOfi32(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -25196,7 +25509,8 @@ pub mod core {
 		/// * type: Array type. Use CV_8UC1, ..., CV_16FC4 to create 1-4 channel matrices, or
 		///    CV_8UC(n), ..., CV_64FC(n) to create multi-channel (up to CV_CN_MAX channels) matrices.
 		#[inline]
-		pub fn new(mut size: core::GpuMatND_SizeArray, typ: i32) -> Result<core::GpuMatND> {
+		pub fn new(mut size: core::GpuMatND_SizeArray, typ: impl Into<core::MatType>) -> Result<core::GpuMatND> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_GpuMatND_GpuMatND_SizeArray_int(size.as_raw_mut_VectorOfi32(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -25224,7 +25538,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * step: StepArray()
 		#[inline]
-		pub unsafe fn new_1(mut size: core::GpuMatND_SizeArray, typ: i32, data: *mut c_void, mut step: core::GpuMatND_StepArray) -> Result<core::GpuMatND> {
+		pub unsafe fn new_1(mut size: core::GpuMatND_SizeArray, typ: impl Into<core::MatType>, data: *mut c_void, mut step: core::GpuMatND_StepArray) -> Result<core::GpuMatND> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			{ sys::cv_cuda_GpuMatND_GpuMatND_SizeArray_int_voidX_StepArray(size.as_raw_mut_VectorOfi32(), typ, data, step.as_raw_mut_VectorOfsize_t(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(ocvrs_return => ret);
@@ -25529,7 +25844,8 @@ pub mod core {
 		
 		/// allocates new matrix data unless the matrix already has specified size and type.
 		#[inline]
-		fn create(&mut self, rows: i32, cols: i32, typ: i32) -> Result<()> {
+		fn create(&mut self, rows: i32, cols: i32, typ: impl Into<core::MatType>) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_HostMem_create_int_int_int(self.as_raw_mut_HostMem(), rows, cols, typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -25538,7 +25854,8 @@ pub mod core {
 		}
 		
 		#[inline]
-		fn create_1(&mut self, size: core::Size, typ: i32) -> Result<()> {
+		fn create_1(&mut self, size: core::Size, typ: impl Into<core::MatType>) -> Result<()> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_HostMem_create_Size_int(self.as_raw_mut_HostMem(), size.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -25623,7 +25940,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * alloc_type: HostMem::AllocType::PAGE_LOCKED
 		#[inline]
-		pub fn new_1(rows: i32, cols: i32, typ: i32, alloc_type: core::HostMem_AllocType) -> Result<core::HostMem> {
+		pub fn new_1(rows: i32, cols: i32, typ: impl Into<core::MatType>, alloc_type: core::HostMem_AllocType) -> Result<core::HostMem> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_HostMem_HostMem_int_int_int_AllocType(rows, cols, typ, alloc_type, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -25635,7 +25953,8 @@ pub mod core {
 		/// ## C++ default parameters
 		/// * alloc_type: HostMem::AllocType::PAGE_LOCKED
 		#[inline]
-		pub fn new_2(size: core::Size, typ: i32, alloc_type: core::HostMem_AllocType) -> Result<core::HostMem> {
+		pub fn new_2(size: core::Size, typ: impl Into<core::MatType>, alloc_type: core::HostMem_AllocType) -> Result<core::HostMem> {
+			let typ = i32::from(typ.into());
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_HostMem_HostMem_Size_int_AllocType(size.opencv_as_extern(), typ, alloc_type, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -25748,6 +26067,7 @@ pub mod core {
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_cuda_Stream_enqueueHostCallback_StreamCallback_voidX(self.as_raw_mut_Stream(), callback_trampoline, user_data, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
+			callback_register!(callbacks, ret);
 			let ret = ret.into_result()?;
 			Ok(ret)
 		}
@@ -29664,6 +29984,7 @@ pub mod core {
 			unsafe { sys::cv_ogl_Buffer_copyTo_const_const__OutputArrayR(self.as_raw_Buffer(), arr.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(arr);
 			Ok(ret)
 		}
 		
@@ -29681,6 +30002,7 @@ pub mod core {
 			unsafe { sys::cv_ogl_Buffer_copyTo_const_const__OutputArrayR_StreamR(self.as_raw_Buffer(), arr.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(arr);
 			Ok(ret)
 		}
 		
@@ -30237,6 +30559,7 @@ pub mod core {
 			unsafe { sys::cv_ogl_Texture2D_copyTo_const_const__OutputArrayR_int_bool(self.as_raw_Texture2D(), arr.as_raw__OutputArray(), ddepth, auto_release, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(arr);
 			Ok(ret)
 		}
 		
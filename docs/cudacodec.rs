@@ -363,8 +363,8 @@ pub mod cudacodec {
 	/// * source_params: {}
 	/// * params: VideoReaderInitParams()
 	#[inline]
-	pub fn create_video_reader(filename: &str, source_params: &core::Vector<i32>, params: crate::cudacodec::CUDA_VideoReaderInitParams) -> Result<core::Ptr<crate::cudacodec::CUDA_VideoReader>> {
-		extern_container_arg!(filename);
+	pub fn create_video_reader(filename: impl AsRef<std::path::Path>, source_params: &core::Vector<i32>, params: crate::cudacodec::CUDA_VideoReaderInitParams) -> Result<core::Ptr<crate::cudacodec::CUDA_VideoReader>> {
+		path_arg!(filename);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cudacodec_createVideoReader_const_StringR_const_vectorLintGR_const_VideoReaderInitParams(filename.opencv_as_extern(), source_params.as_raw_VectorOfi32(), params.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -391,8 +391,8 @@ pub mod cudacodec {
 	/// * encoder_callback: 0
 	/// * stream: Stream::Null()
 	#[inline]
-	pub fn create_video_writer(file_name: &str, frame_size: core::Size, codec: crate::cudacodec::CUDA_Codec, fps: f64, color_format: crate::cudacodec::CUDA_ColorFormat, mut encoder_callback: core::Ptr<crate::cudacodec::CUDA_EncoderCallback>, stream: &core::Stream) -> Result<core::Ptr<crate::cudacodec::CUDA_VideoWriter>> {
-		extern_container_arg!(file_name);
+	pub fn create_video_writer(file_name: impl AsRef<std::path::Path>, frame_size: core::Size, codec: crate::cudacodec::CUDA_Codec, fps: f64, color_format: crate::cudacodec::CUDA_ColorFormat, mut encoder_callback: core::Ptr<crate::cudacodec::CUDA_EncoderCallback>, stream: &core::Stream) -> Result<core::Ptr<crate::cudacodec::CUDA_VideoWriter>> {
+		path_arg!(file_name);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cudacodec_createVideoWriter_const_StringR_const_Size_const_Codec_const_double_const_ColorFormat_PtrLEncoderCallbackG_const_StreamR(file_name.opencv_as_extern(), frame_size.opencv_as_extern(), codec, fps, color_format, encoder_callback.as_raw_mut_PtrOfCUDA_EncoderCallback(), stream.as_raw_Stream(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -417,8 +417,8 @@ pub mod cudacodec {
 	/// * encoder_callback: 0
 	/// * stream: Stream::Null()
 	#[inline]
-	pub fn create_video_writer_1(file_name: &str, frame_size: core::Size, codec: crate::cudacodec::CUDA_Codec, fps: f64, color_format: crate::cudacodec::CUDA_ColorFormat, params: crate::cudacodec::CUDA_EncoderParams, mut encoder_callback: core::Ptr<crate::cudacodec::CUDA_EncoderCallback>, stream: &core::Stream) -> Result<core::Ptr<crate::cudacodec::CUDA_VideoWriter>> {
-		extern_container_arg!(file_name);
+	pub fn create_video_writer_1(file_name: impl AsRef<std::path::Path>, frame_size: core::Size, codec: crate::cudacodec::CUDA_Codec, fps: f64, color_format: crate::cudacodec::CUDA_ColorFormat, params: crate::cudacodec::CUDA_EncoderParams, mut encoder_callback: core::Ptr<crate::cudacodec::CUDA_EncoderCallback>, stream: &core::Stream) -> Result<core::Ptr<crate::cudacodec::CUDA_VideoWriter>> {
+		path_arg!(file_name);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cudacodec_createVideoWriter_const_StringR_const_Size_const_Codec_const_double_const_ColorFormat_const_EncoderParamsR_PtrLEncoderCallbackG_const_StreamR(file_name.opencv_as_extern(), frame_size.opencv_as_extern(), codec, fps, color_format, &params, encoder_callback.as_raw_mut_PtrOfCUDA_EncoderCallback(), stream.as_raw_Stream(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -526,6 +526,53 @@ pub mod cudacodec {
 		}
 	}
 	
+	/// Rust implementation of the virtual methods of [crate::cudacodec::CUDA_EncoderCallback]
+	///
+	/// Use [crate::cudacodec::CUDA_EncoderCallback::from_director] or [core::Ptr::<crate::cudacodec::CUDA_EncoderCallback>::from_director] to get an OpenCV object that forwards
+	/// the calls to this implementation. Panics and errors returned from the methods are passed to OpenCV as `cv::Exception`.
+	pub trait CUDA_EncoderCallbackDirector: Send + Sync {
+		fn on_encoded(&mut self, v_packet: &core::Vector<core::Vector<u8>>) -> Result<()>;
+		fn on_encoding_finished(&mut self) -> Result<()>;
+	}
+	
+	impl CUDA_EncoderCallback {
+		/// Creates [crate::cudacodec::CUDA_EncoderCallback] that forwards its virtual method calls to the passed Rust implementation
+		#[inline]
+		pub fn from_director(director: Box<dyn crate::cudacodec::CUDA_EncoderCallbackDirector>) -> Self {
+			extern "C" { fn cv_CUDA_EncoderCallback_director_new(ocvrs_self: *mut c_void) -> *mut c_void; }
+			let ocvrs_self = Box::into_raw(Box::new(director)) as *mut c_void;
+			unsafe { Self::from_raw(cv_CUDA_EncoderCallback_director_new(ocvrs_self)) }
+		}
+	}
+	
+	impl core::Ptr<crate::cudacodec::CUDA_EncoderCallback> {
+		/// Creates [crate::cudacodec::CUDA_EncoderCallback] that forwards its virtual method calls to the passed Rust implementation
+		#[inline]
+		pub fn from_director(director: Box<dyn crate::cudacodec::CUDA_EncoderCallbackDirector>) -> Self {
+			extern "C" { fn cv_PtrOfCUDA_EncoderCallback_director_new(ocvrs_self: *mut c_void) -> *mut c_void; }
+			let ocvrs_self = Box::into_raw(Box::new(director)) as *mut c_void;
+			unsafe { Self::from_raw(cv_PtrOfCUDA_EncoderCallback_director_new(ocvrs_self)) }
+		}
+	}
+	
+	#[no_mangle]
+	unsafe extern "C" fn ocvrs_director_CUDA_EncoderCallback_delete(ocvrs_self: *mut c_void) {
+		crate::templ::director_delete(ocvrs_self as *mut Box<dyn crate::cudacodec::CUDA_EncoderCallbackDirector>);
+	}
+	
+	#[no_mangle]
+	unsafe extern "C" fn ocvrs_director_CUDA_EncoderCallback_on_encoded(ocvrs_self: *mut c_void, v_packet: *mut c_void, ocvrs_return: *mut sys::Result<()>) {
+		let ocvrs_self = &mut *(ocvrs_self as *mut Box<dyn crate::cudacodec::CUDA_EncoderCallbackDirector>);
+		let v_packet = ::std::mem::ManuallyDrop::new(<core::Vector<core::Vector<u8>>>::from_raw(v_packet));
+		crate::templ::director_call(ocvrs_return, || ocvrs_self.on_encoded(&*v_packet));
+	}
+	
+	#[no_mangle]
+	unsafe extern "C" fn ocvrs_director_CUDA_EncoderCallback_on_encoding_finished(ocvrs_self: *mut c_void, ocvrs_return: *mut sys::Result<()>) {
+		let ocvrs_self = &mut *(ocvrs_self as *mut Box<dyn crate::cudacodec::CUDA_EncoderCallbackDirector>);
+		crate::templ::director_call(ocvrs_return, || ocvrs_self.on_encoding_finished());
+	}
+	
 	/// Different parameters for CUDA video encoder.
 	#[repr(C)]
 	#[derive(Copy, Clone, Debug, PartialEq)]
@@ -771,6 +818,7 @@ pub mod cudacodec {
 			unsafe { sys::cv_cudacodec_VideoReader_retrieve_const_const__OutputArrayR_const_size_t(self.as_raw_CUDA_VideoReader(), frame.as_raw__OutputArray(), idx, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(frame);
 			Ok(ret)
 		}
 		
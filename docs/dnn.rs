@@ -308,6 +308,7 @@ pub mod dnn {
 		unsafe { sys::cv_dnn_blobFromImageWithParams_const__InputArrayR_const__OutputArrayR_const_Image2BlobParamsR(image.as_raw__InputArray(), blob.as_raw__OutputArray(), &param, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(blob);
 		Ok(ret)
 	}
 	
@@ -330,6 +331,7 @@ pub mod dnn {
 		unsafe { sys::cv_dnn_blobFromImage_const__InputArrayR_const__OutputArrayR_double_const_SizeR_const_ScalarR_bool_bool_int(image.as_raw__InputArray(), blob.as_raw__OutputArray(), scalefactor, &size, &mean, swap_rb, crop, ddepth, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(blob);
 		Ok(ret)
 	}
 	
@@ -420,6 +422,7 @@ pub mod dnn {
 		unsafe { sys::cv_dnn_blobFromImagesWithParams_const__InputArrayR_const__OutputArrayR_const_Image2BlobParamsR(images.as_raw__InputArray(), blob.as_raw__OutputArray(), &param, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(blob);
 		Ok(ret)
 	}
 	
@@ -442,6 +445,7 @@ pub mod dnn {
 		unsafe { sys::cv_dnn_blobFromImages_const__InputArrayR_const__OutputArrayR_double_Size_const_ScalarR_bool_bool_int(images.as_raw__InputArray(), blob.as_raw__OutputArray(), scalefactor, size.opencv_as_extern(), &mean, swap_rb, crop, ddepth, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(blob);
 		Ok(ret)
 	}
 	
@@ -603,6 +607,7 @@ pub mod dnn {
 		unsafe { sys::cv_dnn_imagesFromBlob_const_MatR_const__OutputArrayR(blob_.as_raw_Mat(), images_.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(images_);
 		Ok(ret)
 	}
 	
@@ -616,9 +621,9 @@ pub mod dnn {
 	/// ## C++ default parameters
 	/// * caffe_model: String()
 	#[inline]
-	pub fn read_net_from_caffe(prototxt: &str, caffe_model: &str) -> Result<crate::dnn::Net> {
-		extern_container_arg!(prototxt);
-		extern_container_arg!(caffe_model);
+	pub fn read_net_from_caffe(prototxt: impl AsRef<std::path::Path>, caffe_model: impl AsRef<std::path::Path>) -> Result<crate::dnn::Net> {
+		path_arg!(prototxt);
+		path_arg!(caffe_model);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_readNetFromCaffe_const_StringR_const_StringR(prototxt.opencv_as_extern(), caffe_model.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -642,11 +647,9 @@ pub mod dnn {
 	/// * buffer_model: NULL
 	/// * len_model: 0
 	#[inline]
-	pub fn read_net_from_caffe_str(buffer_proto: &str, len_proto: size_t, buffer_model: &str, len_model: size_t) -> Result<crate::dnn::Net> {
-		extern_container_arg!(buffer_proto);
-		extern_container_arg!(buffer_model);
+	pub fn read_net_from_caffe_str(buffer_proto: &[u8], buffer_model: &[u8]) -> Result<crate::dnn::Net> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_readNetFromCaffe_const_charX_size_t_const_charX_size_t(buffer_proto.opencv_as_extern(), len_proto, buffer_model.opencv_as_extern(), len_model, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_dnn_readNetFromCaffe_const_charX_size_t_const_charX_size_t(buffer_proto.as_ptr(), buffer_proto.len() as _, buffer_model.as_ptr(), buffer_model.len() as _, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -682,9 +685,9 @@ pub mod dnn {
 	/// ## C++ default parameters
 	/// * darknet_model: String()
 	#[inline]
-	pub fn read_net_from_darknet(cfg_file: &str, darknet_model: &str) -> Result<crate::dnn::Net> {
-		extern_container_arg!(cfg_file);
-		extern_container_arg!(darknet_model);
+	pub fn read_net_from_darknet(cfg_file: impl AsRef<std::path::Path>, darknet_model: impl AsRef<std::path::Path>) -> Result<crate::dnn::Net> {
+		path_arg!(cfg_file);
+		path_arg!(darknet_model);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_readNetFromDarknet_const_StringR_const_StringR(cfg_file.opencv_as_extern(), darknet_model.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -706,11 +709,9 @@ pub mod dnn {
 	/// * buffer_model: NULL
 	/// * len_model: 0
 	#[inline]
-	pub fn read_net_from_darknet_str(buffer_cfg: &str, len_cfg: size_t, buffer_model: &str, len_model: size_t) -> Result<crate::dnn::Net> {
-		extern_container_arg!(buffer_cfg);
-		extern_container_arg!(buffer_model);
+	pub fn read_net_from_darknet_str(buffer_cfg: &[u8], buffer_model: &[u8]) -> Result<crate::dnn::Net> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_readNetFromDarknet_const_charX_size_t_const_charX_size_t(buffer_cfg.opencv_as_extern(), len_cfg, buffer_model.opencv_as_extern(), len_model, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_dnn_readNetFromDarknet_const_charX_size_t_const_charX_size_t(buffer_cfg.as_ptr(), buffer_cfg.len() as _, buffer_model.as_ptr(), buffer_model.len() as _, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -745,9 +746,9 @@ pub mod dnn {
 	/// Networks imported from Intel's Model Optimizer are launched in Intel's Inference Engine
 	/// backend.
 	#[inline]
-	pub fn read_net_from_model_optimizer(xml: &str, bin: &str) -> Result<crate::dnn::Net> {
-		extern_container_arg!(xml);
-		extern_container_arg!(bin);
+	pub fn read_net_from_model_optimizer(xml: impl AsRef<std::path::Path>, bin: impl AsRef<std::path::Path>) -> Result<crate::dnn::Net> {
+		path_arg!(xml);
+		path_arg!(bin);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_readNetFromModelOptimizer_const_StringR_const_StringR(xml.opencv_as_extern(), bin.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -767,9 +768,9 @@ pub mod dnn {
 	/// Networks imported from Intel's Model Optimizer are launched in Intel's Inference Engine
 	/// backend.
 	#[inline]
-	pub fn read_net_from_model_optimizer_2(buffer_model_config_ptr: &u8, buffer_model_config_size: size_t, buffer_weights_ptr: &u8, buffer_weights_size: size_t) -> Result<crate::dnn::Net> {
+	pub fn read_net_from_model_optimizer_2(buffer_model_config_ptr: &[u8], buffer_weights_ptr: &[u8]) -> Result<crate::dnn::Net> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_readNetFromModelOptimizer_const_unsigned_charX_size_t_const_unsigned_charX_size_t(buffer_model_config_ptr, buffer_model_config_size, buffer_weights_ptr, buffer_weights_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_dnn_readNetFromModelOptimizer_const_unsigned_charX_size_t_const_unsigned_charX_size_t(buffer_model_config_ptr.as_ptr(), buffer_model_config_ptr.len() as _, buffer_weights_ptr.as_ptr(), buffer_weights_ptr.len() as _, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -800,8 +801,8 @@ pub mod dnn {
 	/// ## Returns
 	/// Network object that ready to do forward, throw an exception in failure cases.
 	#[inline]
-	pub fn read_net_from_onnx(onnx_file: &str) -> Result<crate::dnn::Net> {
-		extern_container_arg!(onnx_file);
+	pub fn read_net_from_onnx(onnx_file: impl AsRef<std::path::Path>) -> Result<crate::dnn::Net> {
+		path_arg!(onnx_file);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_readNetFromONNX_const_StringR(onnx_file.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -819,10 +820,9 @@ pub mod dnn {
 	/// Network object that ready to do forward, throw an exception
 	///       in failure cases.
 	#[inline]
-	pub fn read_net_from_onnx_str(buffer: &str, size_buffer: size_t) -> Result<crate::dnn::Net> {
-		extern_container_arg!(buffer);
+	pub fn read_net_from_onnx_str(buffer: &[u8]) -> Result<crate::dnn::Net> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_readNetFromONNX_const_charX_size_t(buffer.opencv_as_extern(), size_buffer, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_dnn_readNetFromONNX_const_charX_size_t(buffer.as_ptr(), buffer.len() as _, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -852,8 +852,8 @@ pub mod dnn {
 	/// ## Returns
 	/// Net object.
 	#[inline]
-	pub fn read_net_from_tf_lite(model: &str) -> Result<crate::dnn::Net> {
-		extern_container_arg!(model);
+	pub fn read_net_from_tf_lite(model: impl AsRef<std::path::Path>) -> Result<crate::dnn::Net> {
+		path_arg!(model);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_readNetFromTFLite_const_StringR(model.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -869,10 +869,9 @@ pub mod dnn {
 	/// * bufferModel: buffer containing the content of the tflite file
 	/// * lenModel: length of bufferModel
 	#[inline]
-	pub fn read_net_from_tf_lite_2(buffer_model: &str, len_model: size_t) -> Result<crate::dnn::Net> {
-		extern_container_arg!(buffer_model);
+	pub fn read_net_from_tf_lite_2(buffer_model: &[u8]) -> Result<crate::dnn::Net> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_readNetFromTFLite_const_charX_size_t(buffer_model.opencv_as_extern(), len_model, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_dnn_readNetFromTFLite_const_charX_size_t(buffer_model.as_ptr(), buffer_model.len() as _, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -906,9 +905,9 @@ pub mod dnn {
 	/// ## C++ default parameters
 	/// * config: String()
 	#[inline]
-	pub fn read_net_from_tensorflow(model: &str, config: &str) -> Result<crate::dnn::Net> {
-		extern_container_arg!(model);
-		extern_container_arg!(config);
+	pub fn read_net_from_tensorflow(model: impl AsRef<std::path::Path>, config: impl AsRef<std::path::Path>) -> Result<crate::dnn::Net> {
+		path_arg!(model);
+		path_arg!(config);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_readNetFromTensorflow_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -930,11 +929,9 @@ pub mod dnn {
 	/// * buffer_config: NULL
 	/// * len_config: 0
 	#[inline]
-	pub fn read_net_from_tensorflow_str(buffer_model: &str, len_model: size_t, buffer_config: &str, len_config: size_t) -> Result<crate::dnn::Net> {
-		extern_container_arg!(buffer_model);
-		extern_container_arg!(buffer_config);
+	pub fn read_net_from_tensorflow_str(buffer_model: &[u8], buffer_config: &[u8]) -> Result<crate::dnn::Net> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_readNetFromTensorflow_const_charX_size_t_const_charX_size_t(buffer_model.opencv_as_extern(), len_model, buffer_config.opencv_as_extern(), len_config, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_dnn_readNetFromTensorflow_const_charX_size_t_const_charX_size_t(buffer_model.as_ptr(), buffer_model.len() as _, buffer_config.as_ptr(), buffer_config.len() as _, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -992,8 +989,8 @@ pub mod dnn {
 	/// * is_binary: true
 	/// * evaluate: true
 	#[inline]
-	pub fn read_net_from_torch(model: &str, is_binary: bool, evaluate: bool) -> Result<crate::dnn::Net> {
-		extern_container_arg!(model);
+	pub fn read_net_from_torch(model: impl AsRef<std::path::Path>, is_binary: bool, evaluate: bool) -> Result<crate::dnn::Net> {
+		path_arg!(model);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_readNetFromTorch_const_StringR_bool_bool(model.opencv_as_extern(), is_binary, evaluate, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1031,9 +1028,9 @@ pub mod dnn {
 	/// * config: ""
 	/// * framework: ""
 	#[inline]
-	pub fn read_net(model: &str, config: &str, framework: &str) -> Result<crate::dnn::Net> {
-		extern_container_arg!(model);
-		extern_container_arg!(config);
+	pub fn read_net(model: impl AsRef<std::path::Path>, config: impl AsRef<std::path::Path>, framework: &str) -> Result<crate::dnn::Net> {
+		path_arg!(model);
+		path_arg!(config);
 		extern_container_arg!(framework);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_readNet_const_StringR_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), framework.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -1072,8 +1069,8 @@ pub mod dnn {
 	/// ## Returns
 	/// Mat.
 	#[inline]
-	pub fn read_tensor_from_onnx(path: &str) -> Result<core::Mat> {
-		extern_container_arg!(path);
+	pub fn read_tensor_from_onnx(path: impl AsRef<std::path::Path>) -> Result<core::Mat> {
+		path_arg!(path);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_readTensorFromONNX_const_StringR(path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1088,8 +1085,8 @@ pub mod dnn {
 	/// ## C++ default parameters
 	/// * is_binary: true
 	#[inline]
-	pub fn read_torch_blob(filename: &str, is_binary: bool) -> Result<core::Mat> {
-		extern_container_arg!(filename);
+	pub fn read_torch_blob(filename: impl AsRef<std::path::Path>, is_binary: bool) -> Result<core::Mat> {
+		path_arg!(filename);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_readTorchBlob_const_StringR_bool(filename.opencv_as_extern(), is_binary, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1212,9 +1209,9 @@ pub mod dnn {
 	/// ## C++ default parameters
 	/// * layers_types: std::vector<String>()
 	#[inline]
-	pub fn shrink_caffe_model(src: &str, dst: &str, layers_types: &core::Vector<String>) -> Result<()> {
-		extern_container_arg!(src);
-		extern_container_arg!(dst);
+	pub fn shrink_caffe_model(src: impl AsRef<std::path::Path>, dst: impl AsRef<std::path::Path>, layers_types: &core::Vector<String>) -> Result<()> {
+		path_arg!(src);
+		path_arg!(dst);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_shrinkCaffeModel_const_StringR_const_StringR_const_vectorLStringGR(src.opencv_as_extern(), dst.opencv_as_extern(), layers_types.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1322,9 +1319,9 @@ pub mod dnn {
 	/// 
 	/// Note: To reduce output file size, trained weights are not included.
 	#[inline]
-	pub fn write_text_graph(model: &str, output: &str) -> Result<()> {
-		extern_container_arg!(model);
-		extern_container_arg!(output);
+	pub fn write_text_graph(model: impl AsRef<std::path::Path>, output: impl AsRef<std::path::Path>) -> Result<()> {
+		path_arg!(model);
+		path_arg!(output);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_writeTextGraph_const_StringR_const_StringR(model.opencv_as_extern(), output.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -3712,9 +3709,9 @@ pub mod dnn {
 		/// ## C++ default parameters
 		/// * config: ""
 		#[inline]
-		pub fn new(model: &str, config: &str) -> Result<crate::dnn::ClassificationModel> {
-			extern_container_arg!(model);
-			extern_container_arg!(config);
+		pub fn new(model: impl AsRef<std::path::Path>, config: impl AsRef<std::path::Path>) -> Result<crate::dnn::ClassificationModel> {
+			path_arg!(model);
+			path_arg!(config);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_dnn_ClassificationModel_ClassificationModel_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -5314,9 +5311,9 @@ pub mod dnn {
 		/// ## C++ default parameters
 		/// * config: ""
 		#[inline]
-		pub fn new(model: &str, config: &str) -> Result<crate::dnn::DetectionModel> {
-			extern_container_arg!(model);
-			extern_container_arg!(config);
+		pub fn new(model: impl AsRef<std::path::Path>, config: impl AsRef<std::path::Path>) -> Result<crate::dnn::DetectionModel> {
+			path_arg!(model);
+			path_arg!(config);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_dnn_DetectionModel_DetectionModel_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -7641,9 +7638,9 @@ pub mod dnn {
 		/// ## C++ default parameters
 		/// * config: ""
 		#[inline]
-		pub fn new(model: &str, config: &str) -> Result<crate::dnn::KeypointsModel> {
-			extern_container_arg!(model);
-			extern_container_arg!(config);
+		pub fn new(model: impl AsRef<std::path::Path>, config: impl AsRef<std::path::Path>) -> Result<crate::dnn::KeypointsModel> {
+			path_arg!(model);
+			path_arg!(config);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_dnn_KeypointsModel_KeypointsModel_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -8204,6 +8201,7 @@ pub mod dnn {
 			unsafe { sys::cv_dnn_Layer_finalize_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Layer(), inputs.as_raw__InputArray(), outputs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(outputs);
 			Ok(ret)
 		}
 		
@@ -8238,6 +8236,8 @@ pub mod dnn {
 			unsafe { sys::cv_dnn_Layer_forward_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Layer(), inputs.as_raw__InputArray(), outputs.as_raw__OutputArray(), internals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(outputs);
+			output_array_finish!(internals);
 			Ok(ret)
 		}
 		
@@ -8271,6 +8271,8 @@ pub mod dnn {
 			unsafe { sys::cv_dnn_Layer_forward_fallback_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Layer(), inputs.as_raw__InputArray(), outputs.as_raw__OutputArray(), internals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(outputs);
+			output_array_finish!(internals);
 			Ok(ret)
 		}
 		
@@ -8732,6 +8734,58 @@ pub mod dnn {
 		}
 	}
 	
+	/// Rust implementation of the virtual methods of [crate::dnn::Layer]
+	///
+	/// Use [crate::dnn::Layer::from_director] or [core::Ptr::<crate::dnn::Layer>::from_director] to get an OpenCV object that forwards
+	/// the calls to this implementation. Panics and errors returned from the methods are passed to OpenCV as `cv::Exception`.
+	pub trait LayerDirector: Send + Sync {
+		fn forward(&mut self, inputs: &core::_InputArray, outputs: &core::_OutputArray, internals: &core::_OutputArray) -> Result<()>;
+		fn get_memory_shapes(&self, inputs: &core::Vector<crate::dnn::MatShape>, required_outputs: i32, outputs: &mut core::Vector<crate::dnn::MatShape>, internals: &mut core::Vector<crate::dnn::MatShape>) -> Result<bool>;
+	}
+	
+	impl Layer {
+		/// Creates [crate::dnn::Layer] that forwards its virtual method calls to the passed Rust implementation
+		#[inline]
+		pub fn from_director(director: Box<dyn crate::dnn::LayerDirector>) -> Self {
+			extern "C" { fn cv_Layer_director_new(ocvrs_self: *mut c_void) -> *mut c_void; }
+			let ocvrs_self = Box::into_raw(Box::new(director)) as *mut c_void;
+			unsafe { Self::from_raw(cv_Layer_director_new(ocvrs_self)) }
+		}
+	}
+	
+	impl core::Ptr<crate::dnn::Layer> {
+		/// Creates [crate::dnn::Layer] that forwards its virtual method calls to the passed Rust implementation
+		#[inline]
+		pub fn from_director(director: Box<dyn crate::dnn::LayerDirector>) -> Self {
+			extern "C" { fn cv_PtrOfLayer_director_new(ocvrs_self: *mut c_void) -> *mut c_void; }
+			let ocvrs_self = Box::into_raw(Box::new(director)) as *mut c_void;
+			unsafe { Self::from_raw(cv_PtrOfLayer_director_new(ocvrs_self)) }
+		}
+	}
+	
+	#[no_mangle]
+	unsafe extern "C" fn ocvrs_director_Layer_delete(ocvrs_self: *mut c_void) {
+		crate::templ::director_delete(ocvrs_self as *mut Box<dyn crate::dnn::LayerDirector>);
+	}
+	
+	#[no_mangle]
+	unsafe extern "C" fn ocvrs_director_Layer_forward(ocvrs_self: *mut c_void, inputs: *mut c_void, outputs: *mut c_void, internals: *mut c_void, ocvrs_return: *mut sys::Result<()>) {
+		let ocvrs_self = &mut *(ocvrs_self as *mut Box<dyn crate::dnn::LayerDirector>);
+		let inputs = ::std::mem::ManuallyDrop::new(<core::_InputArray>::from_raw(inputs));
+		let outputs = ::std::mem::ManuallyDrop::new(<core::_OutputArray>::from_raw(outputs));
+		let internals = ::std::mem::ManuallyDrop::new(<core::_OutputArray>::from_raw(internals));
+		crate::templ::director_call(ocvrs_return, || ocvrs_self.forward(&*inputs, &*outputs, &*internals));
+	}
+	
+	#[no_mangle]
+	unsafe extern "C" fn ocvrs_director_Layer_get_memory_shapes(ocvrs_self: *const c_void, inputs: *mut c_void, required_outputs: i32, outputs: *mut c_void, internals: *mut c_void, ocvrs_return: *mut sys::Result<bool>) {
+		let ocvrs_self = &*(ocvrs_self as *const Box<dyn crate::dnn::LayerDirector>);
+		let inputs = ::std::mem::ManuallyDrop::new(<core::Vector<crate::dnn::MatShape>>::from_raw(inputs));
+		let mut outputs = ::std::mem::ManuallyDrop::new(<core::Vector<crate::dnn::MatShape>>::from_raw(outputs));
+		let mut internals = ::std::mem::ManuallyDrop::new(<core::Vector<crate::dnn::MatShape>>::from_raw(internals));
+		crate::templ::director_call(ocvrs_return, || ocvrs_self.get_memory_shapes(&*inputs, required_outputs, &mut *outputs, &mut *internals));
+	}
+	
 	/// Constant methods for [crate::dnn::LayerFactory]
 	pub trait LayerFactoryTraitConst {
 		fn as_raw_LayerFactory(&self) -> *const c_void;
@@ -9505,6 +9559,7 @@ pub mod dnn {
 			unsafe { sys::cv_dnn_Model_predict_const_const__InputArrayR_const__OutputArrayR(self.as_raw_Model(), frame.as_raw__InputArray(), outs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(outs);
 			Ok(ret)
 		}
 		
@@ -9734,9 +9789,9 @@ pub mod dnn {
 		/// ## C++ default parameters
 		/// * config: ""
 		#[inline]
-		pub fn new(model: &str, config: &str) -> Result<crate::dnn::Model> {
-			extern_container_arg!(model);
-			extern_container_arg!(config);
+		pub fn new(model: impl AsRef<std::path::Path>, config: impl AsRef<std::path::Path>) -> Result<crate::dnn::Model> {
+			path_arg!(model);
+			path_arg!(config);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_dnn_Model_Model_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -10326,8 +10381,8 @@ pub mod dnn {
 		/// ## See also
 		/// dump()
 		#[inline]
-		fn dump_to_file(&mut self, path: &str) -> Result<()> {
-			extern_container_arg!(path);
+		fn dump_to_file(&mut self, path: impl AsRef<std::path::Path>) -> Result<()> {
+			path_arg!(path);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_dnn_Net_dumpToFile_const_StringR(self.as_raw_mut_Net(), path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -10549,6 +10604,7 @@ pub mod dnn {
 			unsafe { sys::cv_dnn_Net_forward_const__OutputArrayR_const_StringR(self.as_raw_mut_Net(), output_blobs.as_raw__OutputArray(), output_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(output_blobs);
 			Ok(ret)
 		}
 		
@@ -10563,6 +10619,7 @@ pub mod dnn {
 			unsafe { sys::cv_dnn_Net_forward_const__OutputArrayR_const_vectorLStringGR(self.as_raw_mut_Net(), output_blobs.as_raw__OutputArray(), out_blob_names.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(output_blobs);
 			Ok(ret)
 		}
 		
@@ -10815,9 +10872,9 @@ pub mod dnn {
 		/// Networks imported from Intel's Model Optimizer are launched in Intel's Inference Engine
 		/// backend.
 		#[inline]
-		pub fn read_from_model_optimizer(xml: &str, bin: &str) -> Result<crate::dnn::Net> {
-			extern_container_arg!(xml);
-			extern_container_arg!(bin);
+		pub fn read_from_model_optimizer(xml: impl AsRef<std::path::Path>, bin: impl AsRef<std::path::Path>) -> Result<crate::dnn::Net> {
+			path_arg!(xml);
+			path_arg!(bin);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_dnn_Net_readFromModelOptimizer_const_StringR_const_StringR(xml.opencv_as_extern(), bin.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -10851,9 +10908,9 @@ pub mod dnn {
 		/// ## Returns
 		/// Net object.
 		#[inline]
-		pub fn read_from_model_optimizer_2(buffer_model_config_ptr: &u8, buffer_model_config_size: size_t, buffer_weights_ptr: &u8, buffer_weights_size: size_t) -> Result<crate::dnn::Net> {
+		pub fn read_from_model_optimizer_2(buffer_model_config_ptr: &[u8], buffer_weights_ptr: &[u8]) -> Result<crate::dnn::Net> {
 			return_send!(via ocvrs_return);
-			unsafe { sys::cv_dnn_Net_readFromModelOptimizer_const_unsigned_charX_size_t_const_unsigned_charX_size_t(buffer_model_config_ptr, buffer_model_config_size, buffer_weights_ptr, buffer_weights_size, ocvrs_return.as_mut_ptr()) };
+			unsafe { sys::cv_dnn_Net_readFromModelOptimizer_const_unsigned_charX_size_t_const_unsigned_charX_size_t(buffer_model_config_ptr.as_ptr(), buffer_model_config_ptr.len() as _, buffer_weights_ptr.as_ptr(), buffer_weights_ptr.len() as _, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
 			let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -13643,6 +13700,7 @@ pub mod dnn {
 			unsafe { sys::cv_dnn_SegmentationModel_segment_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_SegmentationModel(), frame.as_raw__InputArray(), mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(mask);
 			Ok(ret)
 		}
 		
@@ -13695,9 +13753,9 @@ pub mod dnn {
 		/// ## C++ default parameters
 		/// * config: ""
 		#[inline]
-		pub fn new(model: &str, config: &str) -> Result<crate::dnn::SegmentationModel> {
-			extern_container_arg!(model);
-			extern_container_arg!(config);
+		pub fn new(model: impl AsRef<std::path::Path>, config: impl AsRef<std::path::Path>) -> Result<crate::dnn::SegmentationModel> {
+			path_arg!(model);
+			path_arg!(config);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_dnn_SegmentationModel_SegmentationModel_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -15959,9 +16017,9 @@ pub mod dnn {
 		/// ## C++ default parameters
 		/// * config: ""
 		#[inline]
-		pub fn new_1(model: &str, config: &str) -> Result<crate::dnn::TextDetectionModel_DB> {
-			extern_container_arg!(model);
-			extern_container_arg!(config);
+		pub fn new_1(model: impl AsRef<std::path::Path>, config: impl AsRef<std::path::Path>) -> Result<crate::dnn::TextDetectionModel_DB> {
+			path_arg!(model);
+			path_arg!(config);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_dnn_TextDetectionModel_DB_TextDetectionModel_DB_const_stringR_const_stringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -16128,9 +16186,9 @@ pub mod dnn {
 		/// ## C++ default parameters
 		/// * config: ""
 		#[inline]
-		pub fn from_file(model: &str, config: &str) -> Result<crate::dnn::TextDetectionModel_EAST> {
-			extern_container_arg!(model);
-			extern_container_arg!(config);
+		pub fn from_file(model: impl AsRef<std::path::Path>, config: impl AsRef<std::path::Path>) -> Result<crate::dnn::TextDetectionModel_EAST> {
+			path_arg!(model);
+			path_arg!(config);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_dnn_TextDetectionModel_EAST_TextDetectionModel_EAST_const_stringR_const_stringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -16350,9 +16408,9 @@ pub mod dnn {
 		/// ## C++ default parameters
 		/// * config: ""
 		#[inline]
-		pub fn from_file(model: &str, config: &str) -> Result<crate::dnn::TextRecognitionModel> {
-			extern_container_arg!(model);
-			extern_container_arg!(config);
+		pub fn from_file(model: impl AsRef<std::path::Path>, config: impl AsRef<std::path::Path>) -> Result<crate::dnn::TextRecognitionModel> {
+			path_arg!(model);
+			path_arg!(config);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_dnn_TextRecognitionModel_TextRecognitionModel_const_stringR_const_stringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
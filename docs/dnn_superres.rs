@@ -27,8 +27,8 @@ pub mod dnn_superres {
 		/// ## Parameters
 		/// * path: Path to the model file.
 		#[inline]
-		fn read_model(&mut self, path: &str) -> Result<()> {
-			extern_container_arg!(path);
+		fn read_model(&mut self, path: impl AsRef<std::path::Path>) -> Result<()> {
+			path_arg!(path);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_dnn_superres_DnnSuperResImpl_readModel_const_StringR(self.as_raw_mut_DnnSuperResImpl(), path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -101,6 +101,7 @@ pub mod dnn_superres {
 			unsafe { sys::cv_dnn_superres_DnnSuperResImpl_upsample_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_DnnSuperResImpl(), img.as_raw__InputArray(), result.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(result);
 			Ok(ret)
 		}
 		
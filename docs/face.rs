@@ -75,14 +75,15 @@ pub mod face {
 	}
 	
 	#[inline]
-	pub fn get_faces_haar(image: &impl core::ToInputArray, faces: &mut impl core::ToOutputArray, face_cascade_name: &str) -> Result<bool> {
+	pub fn get_faces_haar(image: &impl core::ToInputArray, faces: &mut impl core::ToOutputArray, face_cascade_name: impl AsRef<std::path::Path>) -> Result<bool> {
 		input_array_arg!(image);
 		output_array_arg!(faces);
-		extern_container_arg!(face_cascade_name);
+		path_arg!(face_cascade_name);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_face_getFacesHAAR_const__InputArrayR_const__OutputArrayR_const_StringR(image.as_raw__InputArray(), faces.as_raw__OutputArray(), face_cascade_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(faces);
 		Ok(ret)
 	}
 	
@@ -116,6 +117,7 @@ pub mod face {
 		unsafe { sys::cv_face_getFaces_const__InputArrayR_const__OutputArrayR_CParamsX(image.as_raw__InputArray(), faces.as_raw__OutputArray(), params.as_raw_mut_CParams(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(faces);
 		Ok(ret)
 	}
 	
@@ -177,13 +179,14 @@ pub mod face {
 	/// ## C++ default parameters
 	/// * offset: 0.0f
 	#[inline]
-	pub fn load_face_points(filename: &str, points: &mut impl core::ToOutputArray, offset: f32) -> Result<bool> {
-		extern_container_arg!(mut filename);
+	pub fn load_face_points(filename: impl AsRef<std::path::Path>, points: &mut impl core::ToOutputArray, offset: f32) -> Result<bool> {
+		path_arg!(mut filename);
 		output_array_arg!(points);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_face_loadFacePoints_String_const__OutputArrayR_float(filename.opencv_as_extern_mut(), points.as_raw__OutputArray(), offset, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(points);
 		Ok(ret)
 	}
 	
@@ -238,6 +241,7 @@ pub mod face {
 		unsafe { sys::cv_face_loadTrainingData_String_String_vectorLStringGR_const__OutputArrayR_float(image_list.opencv_as_extern_mut(), ground_truth.opencv_as_extern_mut(), images.as_raw_mut_VectorOfString(), face_points.as_raw__OutputArray(), offset, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(face_points);
 		Ok(ret)
 	}
 	
@@ -273,13 +277,14 @@ pub mod face {
 	/// * delim: ' '
 	/// * offset: 0.0f
 	#[inline]
-	pub fn load_training_data(filename: &str, images: &mut core::Vector<String>, face_points: &mut impl core::ToOutputArray, delim: i8, offset: f32) -> Result<bool> {
-		extern_container_arg!(mut filename);
+	pub fn load_training_data(filename: impl AsRef<std::path::Path>, images: &mut core::Vector<String>, face_points: &mut impl core::ToOutputArray, delim: i8, offset: f32) -> Result<bool> {
+		path_arg!(mut filename);
 		output_array_arg!(face_points);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_face_loadTrainingData_String_vectorLStringGR_const__OutputArrayR_char_float(filename.opencv_as_extern_mut(), images.as_raw_mut_VectorOfString(), face_points.as_raw__OutputArray(), delim, offset, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(face_points);
 		Ok(ret)
 	}
 	
@@ -347,6 +352,7 @@ pub mod face {
 			unsafe { sys::cv_face_BIF_compute_const_const__InputArrayR_const__OutputArrayR(self.as_raw_BIF(), image.as_raw__InputArray(), features.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(features);
 			Ok(ret)
 		}
 		
@@ -749,8 +755,8 @@ pub mod face {
 		/// * min_sz: Size(30,30)
 		/// * max_sz: Size()
 		#[inline]
-		pub fn new(cascade_model: &str, sf: f64, min_n: i32, min_sz: core::Size, max_sz: core::Size) -> Result<crate::face::CParams> {
-			extern_container_arg!(mut cascade_model);
+		pub fn new(cascade_model: impl AsRef<std::path::Path>, sf: f64, min_n: i32, min_sz: core::Size, max_sz: core::Size) -> Result<crate::face::CParams> {
+			path_arg!(mut cascade_model);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_face_CParams_CParams_String_double_int_Size_Size(cascade_model.opencv_as_extern_mut(), sf, min_n, min_sz.opencv_as_extern(), max_sz.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1024,8 +1030,8 @@ pub mod face {
 		/// The suffix const means that prediction does not affect the internal model state, so the method can
 		/// be safely called from within different threads.
 		#[inline]
-		fn write(&self, filename: &str) -> Result<()> {
-			extern_container_arg!(filename);
+		fn write(&self, filename: impl AsRef<std::path::Path>) -> Result<()> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_face_FaceRecognizer_write_const_const_StringR(self.as_raw_FaceRecognizer(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1245,8 +1251,8 @@ pub mod face {
 		/// FaceRecognizer::load(FileStorage& fs) in turn gets called by
 		/// FaceRecognizer::load(const String& filename), to ease saving a model.
 		#[inline]
-		fn read(&mut self, filename: &str) -> Result<()> {
-			extern_container_arg!(filename);
+		fn read(&mut self, filename: impl AsRef<std::path::Path>) -> Result<()> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_face_FaceRecognizer_read_const_StringR(self.as_raw_mut_FaceRecognizer(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1466,8 +1472,8 @@ pub mod face {
 		/// ```
 		/// 
 		#[inline]
-		fn load_model(&mut self, model: &str) -> Result<()> {
-			extern_container_arg!(mut model);
+		fn load_model(&mut self, model: impl AsRef<std::path::Path>) -> Result<()> {
+			path_arg!(mut model);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_face_Facemark_loadModel_String(self.as_raw_mut_Facemark(), model.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1499,6 +1505,7 @@ pub mod face {
 			unsafe { sys::cv_face_Facemark_fit_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Facemark(), image.as_raw__InputArray(), faces.as_raw__InputArray(), landmarks.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(landmarks);
 			Ok(ret)
 		}
 		
@@ -1591,6 +1598,7 @@ pub mod face {
 			unsafe { sys::cv_face_FacemarkAAM_fitConfig_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const_vectorLConfigGR(self.as_raw_mut_FacemarkAAM(), image.as_raw__InputArray(), roi.as_raw__InputArray(), _landmarks.as_raw__OutputArray(), runtime_params.as_raw_VectorOfFacemarkAAM_Config(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(_landmarks);
 			Ok(ret)
 		}
 		
@@ -2411,9 +2419,9 @@ pub mod face {
 		/// ## C++ default parameters
 		/// * model_filename: "face_landmarks.dat"
 		#[inline]
-		fn training(&mut self, images: &mut core::Vector<core::Mat>, landmarks: &mut core::Vector<core::Vector<core::Point2f>>, configfile: &str, scale: core::Size, model_filename: &str) -> Result<bool> {
-			extern_container_arg!(mut configfile);
-			extern_container_arg!(mut model_filename);
+		fn training(&mut self, images: &mut core::Vector<core::Mat>, landmarks: &mut core::Vector<core::Vector<core::Point2f>>, configfile: impl AsRef<std::path::Path>, scale: core::Size, model_filename: impl AsRef<std::path::Path>) -> Result<bool> {
+			path_arg!(mut configfile);
+			path_arg!(mut model_filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_face_FacemarkKazemi_training_vectorLMatGR_vectorLvectorLPoint2fGGR_string_Size_string(self.as_raw_mut_FacemarkKazemi(), images.as_raw_mut_VectorOfMat(), landmarks.as_raw_mut_VectorOfVectorOfPoint2f(), configfile.opencv_as_extern_mut(), scale.opencv_as_extern(), model_filename.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -2429,6 +2437,7 @@ pub mod face {
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_face_FacemarkKazemi_setFaceDetector_bool__X__const_cv__InputArrayR__const_cv__OutputArrayR__voidX__voidX(self.as_raw_mut_FacemarkKazemi(), f_trampoline, user_data, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
+			callback_register!(callbacks, ret);
 			let ret = ret.into_result()?;
 			Ok(ret)
 		}
@@ -2442,6 +2451,7 @@ pub mod face {
 			unsafe { sys::cv_face_FacemarkKazemi_getFaces_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FacemarkKazemi(), image.as_raw__InputArray(), faces.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(faces);
 			Ok(ret)
 		}
 		
@@ -3210,6 +3220,7 @@ pub mod face {
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_face_FacemarkTrain_setFaceDetector_FN_FaceDetector_voidX(self.as_raw_mut_FacemarkTrain(), detector_trampoline, user_data, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
+			callback_register!(callbacks, ret);
 			let ret = ret.into_result()?;
 			Ok(ret)
 		}
@@ -3238,6 +3249,7 @@ pub mod face {
 			unsafe { sys::cv_face_FacemarkTrain_getFaces_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FacemarkTrain(), image.as_raw__InputArray(), faces.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(faces);
 			Ok(ret)
 		}
 		
@@ -3869,8 +3881,8 @@ pub mod face {
 		/// ## C++ default parameters
 		/// * objname: String()
 		#[inline]
-		pub fn load(filename: &str, objname: &str) -> Result<core::Ptr<crate::face::MACE>> {
-			extern_container_arg!(filename);
+		pub fn load(filename: impl AsRef<std::path::Path>, objname: &str) -> Result<core::Ptr<crate::face::MACE>> {
+			path_arg!(filename);
 			extern_container_arg!(objname);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_face_MACE_load_const_StringR_const_StringR(filename.opencv_as_extern(), objname.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
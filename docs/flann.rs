@@ -487,8 +487,8 @@ pub mod flann {
 		fn as_raw_Index(&self) -> *const c_void;
 	
 		#[inline]
-		fn save(&self, filename: &str) -> Result<()> {
-			extern_container_arg!(filename);
+		fn save(&self, filename: impl AsRef<std::path::Path>) -> Result<()> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_flann_Index_save_const_const_StringR(self.as_raw_Index(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -543,6 +543,8 @@ pub mod flann {
 			unsafe { sys::cv_flann_Index_knnSearch_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int_const_SearchParamsR(self.as_raw_mut_Index(), query.as_raw__InputArray(), indices.as_raw__OutputArray(), dists.as_raw__OutputArray(), knn, params.as_raw_SearchParams(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(indices);
+			output_array_finish!(dists);
 			Ok(ret)
 		}
 		
@@ -557,13 +559,15 @@ pub mod flann {
 			unsafe { sys::cv_flann_Index_radiusSearch_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_int_const_SearchParamsR(self.as_raw_mut_Index(), query.as_raw__InputArray(), indices.as_raw__OutputArray(), dists.as_raw__OutputArray(), radius, max_results, params.as_raw_SearchParams(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(indices);
+			output_array_finish!(dists);
 			Ok(ret)
 		}
 		
 		#[inline]
-		fn load(&mut self, features: &impl core::ToInputArray, filename: &str) -> Result<bool> {
+		fn load(&mut self, features: &impl core::ToInputArray, filename: impl AsRef<std::path::Path>) -> Result<bool> {
 			input_array_arg!(features);
-			extern_container_arg!(filename);
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_flann_Index_load_const__InputArrayR_const_StringR(self.as_raw_mut_Index(), features.as_raw__InputArray(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1137,8 +1141,8 @@ pub mod flann {
 	
 	impl SavedIndexParams {
 		#[inline]
-		pub fn new(filename: &str) -> Result<crate::flann::SavedIndexParams> {
-			extern_container_arg!(filename);
+		pub fn new(filename: impl AsRef<std::path::Path>) -> Result<crate::flann::SavedIndexParams> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_flann_SavedIndexParams_SavedIndexParams_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
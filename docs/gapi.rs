@@ -1575,7 +1575,8 @@ pub mod gapi {
 	/// * border_type: BORDER_DEFAULT
 	/// * border_value: Scalar(0)
 	#[inline]
-	pub fn box_filter(src: &crate::gapi::GMat, dtype: i32, ksize: core::Size, anchor: core::Point, normalize: bool, border_type: i32, border_value: core::Scalar) -> Result<crate::gapi::GMat> {
+	pub fn box_filter(src: &crate::gapi::GMat, dtype: impl Into<core::MatType>, ksize: core::Size, anchor: core::Point, normalize: bool, border_type: i32, border_value: core::Scalar) -> Result<crate::gapi::GMat> {
+		let dtype = i32::from(dtype.into());
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_gapi_boxFilter_const_GMatR_int_const_SizeR_const_PointR_bool_int_const_ScalarR(src.as_raw_GMat(), dtype, &ksize, &anchor, normalize, border_type, &border_value, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
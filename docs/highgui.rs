@@ -474,6 +474,7 @@ pub mod highgui {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_createButton_const_StringR_ButtonCallback_voidX_int_bool(bar_name.opencv_as_extern(), on_change_trampoline, userdata, typ, initial_button_state, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
+		callback_register!(callbacks, ret => "cv::createButton", b"", bar_name.as_bytes());
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
@@ -518,6 +519,7 @@ pub mod highgui {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_createTrackbar_const_StringR_const_StringR_intX_int_TrackbarCallback_voidX(trackbarname.opencv_as_extern(), winname.opencv_as_extern(), value.map_or(::core::ptr::null_mut(), |value| value as *mut _), count, on_change_trampoline, userdata, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
+		callback_register!(callbacks, ret => "cv::createTrackbar", winname.as_bytes(), trackbarname.as_bytes());
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
@@ -531,6 +533,7 @@ pub mod highgui {
 		unsafe { sys::cv_destroyAllWindows(ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		callback_release!();
 		Ok(ret)
 	}
 	
@@ -547,6 +550,7 @@ pub mod highgui {
 		unsafe { sys::cv_destroyWindow_const_StringR(winname.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		callback_release!(winname.as_bytes());
 		Ok(ret)
 	}
 	
@@ -1064,6 +1068,7 @@ pub mod highgui {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_setMouseCallback_const_StringR_MouseCallback_voidX(winname.opencv_as_extern(), on_mouse_trampoline, userdata, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
+		callback_register!(callbacks, ret => "cv::setMouseCallback", winname.as_bytes(), b"");
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
@@ -1134,6 +1139,7 @@ pub mod highgui {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_setOpenGlDrawCallback_const_StringR_OpenGlDrawCallback_voidX(winname.opencv_as_extern(), on_opengl_draw_trampoline, userdata, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
+		callback_register!(callbacks, ret => "cv::setOpenGlDrawCallback", winname.as_bytes(), b"");
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
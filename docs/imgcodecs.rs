@@ -337,8 +337,8 @@ pub mod imgcodecs {
 	/// ## Parameters
 	/// * filename: File name of the image
 	#[inline]
-	pub fn have_image_reader(filename: &str) -> Result<bool> {
-		extern_container_arg!(filename);
+	pub fn have_image_reader(filename: impl AsRef<std::path::Path>) -> Result<bool> {
+		path_arg!(filename);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_haveImageReader_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -351,8 +351,8 @@ pub mod imgcodecs {
 	/// ## Parameters
 	/// * filename: File name of the image
 	#[inline]
-	pub fn have_image_writer(filename: &str) -> Result<bool> {
-		extern_container_arg!(filename);
+	pub fn have_image_writer(filename: impl AsRef<std::path::Path>) -> Result<bool> {
+		path_arg!(filename);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_haveImageWriter_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -370,8 +370,8 @@ pub mod imgcodecs {
 	/// ## C++ default parameters
 	/// * flags: IMREAD_ANYCOLOR
 	#[inline]
-	pub fn imcount(filename: &str, flags: i32) -> Result<size_t> {
-		extern_container_arg!(filename);
+	pub fn imcount(filename: impl AsRef<std::path::Path>, flags: i32) -> Result<size_t> {
+		path_arg!(filename);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_imcount_const_StringR_int(filename.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -536,8 +536,8 @@ pub mod imgcodecs {
 	/// ## C++ default parameters
 	/// * flags: IMREAD_COLOR
 	#[inline]
-	pub fn imread(filename: &str, flags: i32) -> Result<core::Mat> {
-		extern_container_arg!(filename);
+	pub fn imread(filename: impl AsRef<std::path::Path>, flags: i32) -> Result<core::Mat> {
+		path_arg!(filename);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_imread_const_StringR_int(filename.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -559,8 +559,8 @@ pub mod imgcodecs {
 	/// ## C++ default parameters
 	/// * flags: IMREAD_ANYCOLOR
 	#[inline]
-	pub fn imreadmulti(filename: &str, mats: &mut core::Vector<core::Mat>, flags: i32) -> Result<bool> {
-		extern_container_arg!(filename);
+	pub fn imreadmulti(filename: impl AsRef<std::path::Path>, mats: &mut core::Vector<core::Mat>, flags: i32) -> Result<bool> {
+		path_arg!(filename);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_imreadmulti_const_StringR_vectorLMatGR_int(filename.opencv_as_extern(), mats.as_raw_mut_VectorOfMat(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -583,8 +583,8 @@ pub mod imgcodecs {
 	/// ## C++ default parameters
 	/// * flags: IMREAD_ANYCOLOR
 	#[inline]
-	pub fn imreadmulti_range(filename: &str, mats: &mut core::Vector<core::Mat>, start: i32, count: i32, flags: i32) -> Result<bool> {
-		extern_container_arg!(filename);
+	pub fn imreadmulti_range(filename: impl AsRef<std::path::Path>, mats: &mut core::Vector<core::Mat>, start: i32, count: i32, flags: i32) -> Result<bool> {
+		path_arg!(filename);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_imreadmulti_const_StringR_vectorLMatGR_int_int_int(filename.opencv_as_extern(), mats.as_raw_mut_VectorOfMat(), start, count, flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -633,8 +633,8 @@ pub mod imgcodecs {
 	/// ## C++ default parameters
 	/// * params: std::vector<int>()
 	#[inline]
-	pub fn imwrite(filename: &str, img: &impl core::ToInputArray, params: &core::Vector<i32>) -> Result<bool> {
-		extern_container_arg!(filename);
+	pub fn imwrite(filename: impl AsRef<std::path::Path>, img: &impl core::ToInputArray, params: &core::Vector<i32>) -> Result<bool> {
+		path_arg!(filename);
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_imwrite_const_StringR_const__InputArrayR_const_vectorLintGR(filename.opencv_as_extern(), img.as_raw__InputArray(), params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
@@ -648,8 +648,8 @@ pub mod imgcodecs {
 	/// ## C++ default parameters
 	/// * params: std::vector<int>()
 	#[inline]
-	pub fn imwritemulti(filename: &str, img: &impl core::ToInputArray, params: &core::Vector<i32>) -> Result<bool> {
-		extern_container_arg!(filename);
+	pub fn imwritemulti(filename: impl AsRef<std::path::Path>, img: &impl core::ToInputArray, params: &core::Vector<i32>) -> Result<bool> {
+		path_arg!(filename);
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_imwritemulti_const_StringR_const__InputArrayR_const_vectorLintGR(filename.opencv_as_extern(), img.as_raw__InputArray(), params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
@@ -786,8 +786,8 @@ pub mod imgcodecs {
 		}
 		
 		#[inline]
-		pub fn new(filename: &str, flags: i32) -> Result<crate::imgcodecs::ImageCollection> {
-			extern_container_arg!(filename);
+		pub fn new(filename: impl AsRef<std::path::Path>, flags: i32) -> Result<crate::imgcodecs::ImageCollection> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ImageCollection_ImageCollection_const_StringR_int(filename.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
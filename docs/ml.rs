@@ -479,6 +479,8 @@ pub mod ml {
 		unsafe { sys::cv_ml_createConcentricSpheresTestSet_int_int_int_const__OutputArrayR_const__OutputArrayR(nsamples, nfeatures, nclasses, samples.as_raw__OutputArray(), responses.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(samples);
+		output_array_finish!(responses);
 		Ok(ret)
 	}
 	
@@ -498,6 +500,7 @@ pub mod ml {
 		unsafe { sys::cv_ml_randMVNormal_const__InputArrayR_const__InputArrayR_int_const__OutputArrayR(mean.as_raw__InputArray(), cov.as_raw__InputArray(), nsamples, samples.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(samples);
 		Ok(ret)
 	}
 	
@@ -1004,8 +1007,8 @@ pub mod ml {
 		/// ## Parameters
 		/// * filepath: path to serialized ANN
 		#[inline]
-		pub fn load(filepath: &str) -> Result<core::Ptr<crate::ml::ANN_MLP>> {
-			extern_container_arg!(filepath);
+		pub fn load(filepath: impl AsRef<std::path::Path>) -> Result<core::Ptr<crate::ml::ANN_MLP>> {
+			path_arg!(filepath);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ml_ANN_MLP_load_const_StringR(filepath.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1197,8 +1200,8 @@ pub mod ml {
 		/// ## C++ default parameters
 		/// * node_name: String()
 		#[inline]
-		pub fn load(filepath: &str, node_name: &str) -> Result<core::Ptr<crate::ml::Boost>> {
-			extern_container_arg!(filepath);
+		pub fn load(filepath: impl AsRef<std::path::Path>, node_name: &str) -> Result<core::Ptr<crate::ml::Boost>> {
+			path_arg!(filepath);
 			extern_container_arg!(node_name);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ml_Boost_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -1666,8 +1669,8 @@ pub mod ml {
 		/// ## C++ default parameters
 		/// * node_name: String()
 		#[inline]
-		pub fn load(filepath: &str, node_name: &str) -> Result<core::Ptr<crate::ml::DTrees>> {
-			extern_container_arg!(filepath);
+		pub fn load(filepath: impl AsRef<std::path::Path>, node_name: &str) -> Result<core::Ptr<crate::ml::DTrees>> {
+			path_arg!(filepath);
 			extern_container_arg!(node_name);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ml_DTrees_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -2148,6 +2151,7 @@ pub mod ml {
 			unsafe { sys::cv_ml_EM_predict_const_const__InputArrayR_const__OutputArrayR_int(self.as_raw_EM(), samples.as_raw__InputArray(), results.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(results);
 			Ok(ret)
 		}
 		
@@ -2171,6 +2175,7 @@ pub mod ml {
 			unsafe { sys::cv_ml_EM_predict2_const_const__InputArrayR_const__OutputArrayR(self.as_raw_EM(), sample.as_raw__InputArray(), probs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(probs);
 			Ok(ret)
 		}
 		
@@ -2266,6 +2271,9 @@ pub mod ml {
 			unsafe { sys::cv_ml_EM_trainEM_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_EM(), samples.as_raw__InputArray(), log_likelihoods.as_raw__OutputArray(), labels.as_raw__OutputArray(), probs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(log_likelihoods);
+			output_array_finish!(labels);
+			output_array_finish!(probs);
 			Ok(ret)
 		}
 		
@@ -2316,6 +2324,9 @@ pub mod ml {
 			unsafe { sys::cv_ml_EM_trainE_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_EM(), samples.as_raw__InputArray(), means0.as_raw__InputArray(), covs0.as_raw__InputArray(), weights0.as_raw__InputArray(), log_likelihoods.as_raw__OutputArray(), labels.as_raw__OutputArray(), probs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(log_likelihoods);
+			output_array_finish!(labels);
+			output_array_finish!(probs);
 			Ok(ret)
 		}
 		
@@ -2353,6 +2364,9 @@ pub mod ml {
 			unsafe { sys::cv_ml_EM_trainM_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_EM(), samples.as_raw__InputArray(), probs0.as_raw__InputArray(), log_likelihoods.as_raw__OutputArray(), labels.as_raw__OutputArray(), probs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(log_likelihoods);
+			output_array_finish!(labels);
+			output_array_finish!(probs);
 			Ok(ret)
 		}
 		
@@ -2428,8 +2442,8 @@ pub mod ml {
 		/// ## C++ default parameters
 		/// * node_name: String()
 		#[inline]
-		pub fn load(filepath: &str, node_name: &str) -> Result<core::Ptr<crate::ml::EM>> {
-			extern_container_arg!(filepath);
+		pub fn load(filepath: impl AsRef<std::path::Path>, node_name: &str) -> Result<core::Ptr<crate::ml::EM>> {
+			path_arg!(filepath);
 			extern_container_arg!(node_name);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ml_EM_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -2545,6 +2559,9 @@ pub mod ml {
 			unsafe { sys::cv_ml_KNearest_findNearest_const_const__InputArrayR_int_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_KNearest(), samples.as_raw__InputArray(), k, results.as_raw__OutputArray(), neighbor_responses.as_raw__OutputArray(), dist.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(results);
+			output_array_finish!(neighbor_responses);
+			output_array_finish!(dist);
 			Ok(ret)
 		}
 		
@@ -2669,8 +2686,8 @@ pub mod ml {
 		/// ## Parameters
 		/// * filepath: path to serialized KNearest
 		#[inline]
-		pub fn load(filepath: &str) -> Result<core::Ptr<crate::ml::KNearest>> {
-			extern_container_arg!(filepath);
+		pub fn load(filepath: impl AsRef<std::path::Path>) -> Result<core::Ptr<crate::ml::KNearest>> {
+			path_arg!(filepath);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ml_KNearest_load_const_StringR(filepath.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -2790,6 +2807,7 @@ pub mod ml {
 			unsafe { sys::cv_ml_LogisticRegression_predict_const_const__InputArrayR_const__OutputArrayR_int(self.as_raw_LogisticRegression(), samples.as_raw__InputArray(), results.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(results);
 			Ok(ret)
 		}
 		
@@ -2959,8 +2977,8 @@ pub mod ml {
 		/// ## C++ default parameters
 		/// * node_name: String()
 		#[inline]
-		pub fn load(filepath: &str, node_name: &str) -> Result<core::Ptr<crate::ml::LogisticRegression>> {
-			extern_container_arg!(filepath);
+		pub fn load(filepath: impl AsRef<std::path::Path>, node_name: &str) -> Result<core::Ptr<crate::ml::LogisticRegression>> {
+			path_arg!(filepath);
 			extern_container_arg!(node_name);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ml_LogisticRegression_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -3007,6 +3025,8 @@ pub mod ml {
 			unsafe { sys::cv_ml_NormalBayesClassifier_predictProb_const_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int(self.as_raw_NormalBayesClassifier(), inputs.as_raw__InputArray(), outputs.as_raw__OutputArray(), output_probs.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(outputs);
+			output_array_finish!(output_probs);
 			Ok(ret)
 		}
 		
@@ -3087,8 +3107,8 @@ pub mod ml {
 		/// ## C++ default parameters
 		/// * node_name: String()
 		#[inline]
-		pub fn load(filepath: &str, node_name: &str) -> Result<core::Ptr<crate::ml::NormalBayesClassifier>> {
-			extern_container_arg!(filepath);
+		pub fn load(filepath: impl AsRef<std::path::Path>, node_name: &str) -> Result<core::Ptr<crate::ml::NormalBayesClassifier>> {
+			path_arg!(filepath);
 			extern_container_arg!(node_name);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ml_NormalBayesClassifier_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -3344,6 +3364,7 @@ pub mod ml {
 			unsafe { sys::cv_ml_RTrees_getVotes_const_const__InputArrayR_const__OutputArrayR_int(self.as_raw_RTrees(), samples.as_raw__InputArray(), results.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(results);
 			Ok(ret)
 		}
 		
@@ -3488,8 +3509,8 @@ pub mod ml {
 		/// ## C++ default parameters
 		/// * node_name: String()
 		#[inline]
-		pub fn load(filepath: &str, node_name: &str) -> Result<core::Ptr<crate::ml::RTrees>> {
-			extern_container_arg!(filepath);
+		pub fn load(filepath: impl AsRef<std::path::Path>, node_name: &str) -> Result<core::Ptr<crate::ml::RTrees>> {
+			path_arg!(filepath);
 			extern_container_arg!(node_name);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ml_RTrees_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -3705,6 +3726,8 @@ pub mod ml {
 			unsafe { sys::cv_ml_SVM_getDecisionFunction_const_int_const__OutputArrayR_const__OutputArrayR(self.as_raw_SVM(), i, alpha.as_raw__OutputArray(), svidx.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(alpha);
+			output_array_finish!(svidx);
 			Ok(ret)
 		}
 		
@@ -4063,8 +4086,8 @@ pub mod ml {
 		/// ## Parameters
 		/// * filepath: path to serialized svm
 		#[inline]
-		pub fn load(filepath: &str) -> Result<core::Ptr<crate::ml::SVM>> {
-			extern_container_arg!(filepath);
+		pub fn load(filepath: impl AsRef<std::path::Path>) -> Result<core::Ptr<crate::ml::SVM>> {
+			path_arg!(filepath);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ml_SVM_load_const_StringR(filepath.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -4500,8 +4523,8 @@ pub mod ml {
 		/// ## C++ default parameters
 		/// * node_name: String()
 		#[inline]
-		pub fn load(filepath: &str, node_name: &str) -> Result<core::Ptr<crate::ml::SVMSGD>> {
-			extern_container_arg!(filepath);
+		pub fn load(filepath: impl AsRef<std::path::Path>, node_name: &str) -> Result<core::Ptr<crate::ml::SVMSGD>> {
+			path_arg!(filepath);
 			extern_container_arg!(node_name);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ml_SVMSGD_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -4588,6 +4611,7 @@ pub mod ml {
 			unsafe { sys::cv_ml_StatModel_calcError_const_const_PtrLTrainDataGR_bool_const__OutputArrayR(self.as_raw_StatModel(), data.as_raw_PtrOfTrainData(), test, resp.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(resp);
 			Ok(ret)
 		}
 		
@@ -4609,6 +4633,7 @@ pub mod ml {
 			unsafe { sys::cv_ml_StatModel_predict_const_const__InputArrayR_const__OutputArrayR_int(self.as_raw_StatModel(), samples.as_raw__InputArray(), results.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(results);
 			Ok(ret)
 		}
 		
@@ -5245,8 +5270,8 @@ pub mod ml {
 		/// * delimiter: ','
 		/// * missch: '?'
 		#[inline]
-		pub fn load_from_csv(filename: &str, header_line_count: i32, response_start_idx: i32, response_end_idx: i32, var_type_spec: &str, delimiter: i8, missch: i8) -> Result<core::Ptr<crate::ml::TrainData>> {
-			extern_container_arg!(filename);
+		pub fn load_from_csv(filename: impl AsRef<std::path::Path>, header_line_count: i32, response_start_idx: i32, response_end_idx: i32, var_type_spec: &str, delimiter: i8, missch: i8) -> Result<core::Ptr<crate::ml::TrainData>> {
+			path_arg!(filename);
 			extern_container_arg!(var_type_spec);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ml_TrainData_loadFromCSV_const_StringR_int_int_int_const_StringR_char_char(filename.opencv_as_extern(), header_line_count, response_start_idx, response_end_idx, var_type_spec.opencv_as_extern(), delimiter, missch, ocvrs_return.as_mut_ptr()) };
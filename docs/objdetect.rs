@@ -439,6 +439,7 @@ pub mod objdetect {
 		unsafe { sys::cv_aruco_generateImageMarker_const_DictionaryR_int_int_const__OutputArrayR_int(dictionary.as_raw_Dictionary(), id, side_pixels, img.as_raw__OutputArray(), border_bits, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(img);
 		Ok(ret)
 	}
 	
@@ -646,8 +647,8 @@ pub mod objdetect {
 		fn as_raw_mut_BaseCascadeClassifier(&mut self) -> *mut c_void;
 	
 		#[inline]
-		fn load(&mut self, filename: &str) -> Result<bool> {
-			extern_container_arg!(filename);
+		fn load(&mut self, filename: impl AsRef<std::path::Path>) -> Result<bool> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_BaseCascadeClassifier_load_const_StringR(self.as_raw_mut_BaseCascadeClassifier(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -893,8 +894,8 @@ pub mod objdetect {
 		/// HAAR classifier trained by the haartraining application or a new cascade classifier trained by the
 		/// traincascade application.
 		#[inline]
-		fn load(&mut self, filename: &str) -> Result<bool> {
-			extern_container_arg!(filename);
+		fn load(&mut self, filename: impl AsRef<std::path::Path>) -> Result<bool> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_CascadeClassifier_load_const_StringR(self.as_raw_mut_CascadeClassifier(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1119,8 +1120,8 @@ pub mod objdetect {
 		/// ## Parameters
 		/// * filename: Name of the file from which the classifier is loaded.
 		#[inline]
-		pub fn new(filename: &str) -> Result<crate::objdetect::CascadeClassifier> {
-			extern_container_arg!(filename);
+		pub fn new(filename: impl AsRef<std::path::Path>) -> Result<crate::objdetect::CascadeClassifier> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_CascadeClassifier_CascadeClassifier_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1833,6 +1834,7 @@ pub mod objdetect {
 			unsafe { sys::cv_FaceDetectorYN_detect_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FaceDetectorYN(), image.as_raw__InputArray(), faces.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(faces);
 			Ok(ret)
 		}
 		
@@ -1885,9 +1887,9 @@ pub mod objdetect {
 		/// * backend_id: 0
 		/// * target_id: 0
 		#[inline]
-		pub fn create(model: &str, config: &str, input_size: core::Size, score_threshold: f32, nms_threshold: f32, top_k: i32, backend_id: i32, target_id: i32) -> Result<core::Ptr<crate::objdetect::FaceDetectorYN>> {
-			extern_container_arg!(model);
-			extern_container_arg!(config);
+		pub fn create(model: impl AsRef<std::path::Path>, config: impl AsRef<std::path::Path>, input_size: core::Size, score_threshold: f32, nms_threshold: f32, top_k: i32, backend_id: i32, target_id: i32) -> Result<core::Ptr<crate::objdetect::FaceDetectorYN>> {
+			path_arg!(model);
+			path_arg!(config);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_FaceDetectorYN_create_const_StringR_const_StringR_const_SizeR_float_float_int_int_int(model.opencv_as_extern(), config.opencv_as_extern(), &input_size, score_threshold, nms_threshold, top_k, backend_id, target_id, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1924,6 +1926,7 @@ pub mod objdetect {
 			unsafe { sys::cv_FaceRecognizerSF_alignCrop_const_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_FaceRecognizerSF(), src_img.as_raw__InputArray(), face_box.as_raw__InputArray(), aligned_img.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(aligned_img);
 			Ok(ret)
 		}
 		
@@ -1964,6 +1967,7 @@ pub mod objdetect {
 			unsafe { sys::cv_FaceRecognizerSF_feature_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FaceRecognizerSF(), aligned_img.as_raw__InputArray(), face_feature.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(face_feature);
 			Ok(ret)
 		}
 		
@@ -2008,9 +2012,9 @@ pub mod objdetect {
 		/// * backend_id: 0
 		/// * target_id: 0
 		#[inline]
-		pub fn create(model: &str, config: &str, backend_id: i32, target_id: i32) -> Result<core::Ptr<crate::objdetect::FaceRecognizerSF>> {
-			extern_container_arg!(model);
-			extern_container_arg!(config);
+		pub fn create(model: impl AsRef<std::path::Path>, config: impl AsRef<std::path::Path>, backend_id: i32, target_id: i32) -> Result<core::Ptr<crate::objdetect::FaceRecognizerSF>> {
+			path_arg!(model);
+			path_arg!(config);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_FaceRecognizerSF_create_const_StringR_const_StringR_int_int(model.opencv_as_extern(), config.opencv_as_extern(), backend_id, target_id, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -2045,6 +2049,7 @@ pub mod objdetect {
 			unsafe { sys::cv_GraphicalCodeDetector_detect_const_const__InputArrayR_const__OutputArrayR(self.as_raw_GraphicalCodeDetector(), img.as_raw__InputArray(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(points);
 			Ok(ret)
 		}
 		
@@ -2068,6 +2073,7 @@ pub mod objdetect {
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
 			let ret = unsafe { Vec::<u8>::opencv_from_extern(ret) };
+			output_array_finish!(straight_code);
 			Ok(ret)
 		}
 		
@@ -2091,6 +2097,8 @@ pub mod objdetect {
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
 			let ret = unsafe { Vec::<u8>::opencv_from_extern(ret) };
+			output_array_finish!(points);
+			output_array_finish!(straight_code);
 			Ok(ret)
 		}
 		
@@ -2106,6 +2114,7 @@ pub mod objdetect {
 			unsafe { sys::cv_GraphicalCodeDetector_detectMulti_const_const__InputArrayR_const__OutputArrayR(self.as_raw_GraphicalCodeDetector(), img.as_raw__InputArray(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(points);
 			Ok(ret)
 		}
 		
@@ -2127,6 +2136,7 @@ pub mod objdetect {
 			unsafe { sys::cv_GraphicalCodeDetector_decodeMulti_const_const__InputArrayR_const__InputArrayR_vectorLstringGR_const__OutputArrayR(self.as_raw_GraphicalCodeDetector(), img.as_raw__InputArray(), points.as_raw__InputArray(), decoded_info.as_raw_mut_VectorOfString(), straight_code.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(straight_code);
 			Ok(ret)
 		}
 		
@@ -2149,6 +2159,8 @@ pub mod objdetect {
 			unsafe { sys::cv_GraphicalCodeDetector_detectAndDecodeMulti_const_const__InputArrayR_vectorLstringGR_const__OutputArrayR_const__OutputArrayR(self.as_raw_GraphicalCodeDetector(), img.as_raw__InputArray(), decoded_info.as_raw_mut_VectorOfString(), points.as_raw__OutputArray(), straight_code.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(points);
+			output_array_finish!(straight_code);
 			Ok(ret)
 		}
 		
@@ -2400,8 +2412,8 @@ pub mod objdetect {
 		/// ## C++ default parameters
 		/// * objname: String()
 		#[inline]
-		fn save(&self, filename: &str, objname: &str) -> Result<()> {
-			extern_container_arg!(filename);
+		fn save(&self, filename: impl AsRef<std::path::Path>, objname: &str) -> Result<()> {
+			path_arg!(filename);
 			extern_container_arg!(objname);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_HOGDescriptor_save_const_const_StringR_const_StringR(self.as_raw_HOGDescriptor(), filename.opencv_as_extern(), objname.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -2796,8 +2808,8 @@ pub mod objdetect {
 		/// ## C++ default parameters
 		/// * objname: String()
 		#[inline]
-		fn load(&mut self, filename: &str, objname: &str) -> Result<bool> {
-			extern_container_arg!(filename);
+		fn load(&mut self, filename: impl AsRef<std::path::Path>, objname: &str) -> Result<bool> {
+			path_arg!(filename);
 			extern_container_arg!(objname);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_HOGDescriptor_load_const_StringR_const_StringR(self.as_raw_mut_HOGDescriptor(), filename.opencv_as_extern(), objname.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -2910,8 +2922,8 @@ pub mod objdetect {
 		/// ## Parameters
 		/// * filename: The file name containing HOGDescriptor properties and coefficients for the linear SVM classifier.
 		#[inline]
-		pub fn new_from_file(filename: &str) -> Result<crate::objdetect::HOGDescriptor> {
-			extern_container_arg!(filename);
+		pub fn new_from_file(filename: impl AsRef<std::path::Path>) -> Result<crate::objdetect::HOGDescriptor> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_HOGDescriptor_HOGDescriptor_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -3058,6 +3070,7 @@ pub mod objdetect {
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
 			let ret = unsafe { Vec::<u8>::opencv_from_extern(ret) };
+			output_array_finish!(straight_qrcode);
 			Ok(ret)
 		}
 		
@@ -3081,6 +3094,8 @@ pub mod objdetect {
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
 			let ret = unsafe { Vec::<u8>::opencv_from_extern(ret) };
+			output_array_finish!(points);
+			output_array_finish!(straight_qrcode);
 			Ok(ret)
 		}
 		
@@ -3339,6 +3354,7 @@ pub mod objdetect {
 			unsafe { sys::cv_QRCodeEncoder_encode_const_StringR_const__OutputArrayR(self.as_raw_mut_QRCodeEncoder(), encoded_info.opencv_as_extern(), qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(qrcode);
 			Ok(ret)
 		}
 		
@@ -3354,6 +3370,7 @@ pub mod objdetect {
 			unsafe { sys::cv_QRCodeEncoder_encodeStructuredAppend_const_StringR_const__OutputArrayR(self.as_raw_mut_QRCodeEncoder(), encoded_info.opencv_as_extern(), qrcodes.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(qrcodes);
 			Ok(ret)
 		}
 		
@@ -3561,6 +3578,9 @@ pub mod objdetect {
 			unsafe { sys::cv_aruco_ArucoDetector_detectMarkers_const_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_ArucoDetector(), image.as_raw__InputArray(), corners.as_raw__OutputArray(), ids.as_raw__OutputArray(), rejected_img_points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(corners);
+			output_array_finish!(ids);
+			output_array_finish!(rejected_img_points);
 			Ok(ret)
 		}
 		
@@ -3604,6 +3624,7 @@ pub mod objdetect {
 			unsafe { sys::cv_aruco_ArucoDetector_refineDetectedMarkers_const_const__InputArrayR_const_BoardR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_ArucoDetector(), image.as_raw__InputArray(), board.as_raw_Board(), detected_corners.as_raw__InputOutputArray(), detected_ids.as_raw__InputOutputArray(), rejected_corners.as_raw__InputOutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), recovered_idxs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(recovered_idxs);
 			Ok(ret)
 		}
 		
@@ -3859,6 +3880,8 @@ pub mod objdetect {
 			unsafe { sys::cv_aruco_Board_matchImagePoints_const_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_Board(), detected_corners.as_raw__InputArray(), detected_ids.as_raw__InputArray(), obj_points.as_raw__OutputArray(), img_points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(obj_points);
+			output_array_finish!(img_points);
 			Ok(ret)
 		}
 		
@@ -3883,6 +3906,7 @@ pub mod objdetect {
 			unsafe { sys::cv_aruco_Board_generateImage_const_Size_const__OutputArrayR_int_int(self.as_raw_Board(), out_size.opencv_as_extern(), img.as_raw__OutputArray(), margin_size, border_bits, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(img);
 			Ok(ret)
 		}
 		
@@ -4263,6 +4287,8 @@ pub mod objdetect {
 			unsafe { sys::cv_aruco_CharucoDetector_detectBoard_const_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR(self.as_raw_CharucoDetector(), image.as_raw__InputArray(), charuco_corners.as_raw__OutputArray(), charuco_ids.as_raw__OutputArray(), marker_corners.as_raw__InputOutputArray(), marker_ids.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(charuco_corners);
+			output_array_finish!(charuco_ids);
 			Ok(ret)
 		}
 		
@@ -4300,6 +4326,8 @@ pub mod objdetect {
 			unsafe { sys::cv_aruco_CharucoDetector_detectDiamonds_const_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR(self.as_raw_CharucoDetector(), image.as_raw__InputArray(), diamond_corners.as_raw__OutputArray(), diamond_ids.as_raw__OutputArray(), marker_corners.as_raw__InputOutputArray(), marker_ids.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(diamond_corners);
+			output_array_finish!(diamond_ids);
 			Ok(ret)
 		}
 		
@@ -5223,6 +5251,7 @@ pub mod objdetect {
 			unsafe { sys::cv_aruco_Dictionary_generateImageMarker_const_int_int_const__OutputArrayR_int(self.as_raw_Dictionary(), id, side_pixels, _img.as_raw__OutputArray(), border_bits, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(_img);
 			Ok(ret)
 		}
 		
@@ -5621,6 +5650,7 @@ pub mod objdetect {
 			unsafe { sys::cv_barcode_BarcodeDetector_detectAndDecodeWithType_const_const__InputArrayR_vectorLstringGR_vectorLstringGR_const__OutputArrayR(self.as_raw_BarcodeDetector(), img.as_raw__InputArray(), decoded_info.as_raw_mut_VectorOfString(), decoded_type.as_raw_mut_VectorOfString(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(points);
 			Ok(ret)
 		}
 		
@@ -5683,9 +5713,9 @@ pub mod objdetect {
 		/// * prototxt_path: prototxt file path for the super resolution model
 		/// * model_path: model file path for the super resolution model
 		#[inline]
-		pub fn new(prototxt_path: &str, model_path: &str) -> Result<crate::objdetect::BarcodeDetector> {
-			extern_container_arg!(prototxt_path);
-			extern_container_arg!(model_path);
+		pub fn new(prototxt_path: impl AsRef<std::path::Path>, model_path: impl AsRef<std::path::Path>) -> Result<crate::objdetect::BarcodeDetector> {
+			path_arg!(prototxt_path);
+			path_arg!(model_path);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_barcode_BarcodeDetector_BarcodeDetector_const_stringR_const_stringR(prototxt_path.opencv_as_extern(), model_path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -113,8 +113,8 @@ pub mod ovis {
 	/// ## Parameters
 	/// * path: folder or Zip archive.
 	#[inline]
-	pub fn add_resource_location(path: &str) -> Result<()> {
-		extern_container_arg!(path);
+	pub fn add_resource_location(path: impl AsRef<std::path::Path>) -> Result<()> {
+		path_arg!(path);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ovis_addResourceLocation_const_StringR(path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -478,6 +478,7 @@ pub mod ovis {
 			unsafe { sys::cv_ovis_WindowScene_getEntityProperty_const_StringR_int_const__OutputArrayR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), prop, value.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(value);
 			Ok(ret)
 		}
 		
@@ -601,6 +602,8 @@ pub mod ovis {
 			unsafe { sys::cv_ovis_WindowScene_getEntityPose_const_StringR_const__OutputArrayR_const__OutputArrayR_bool(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), r.as_raw__OutputArray(), tvec.as_raw__OutputArray(), invert, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(r);
+			output_array_finish!(tvec);
 			Ok(ret)
 		}
 		
@@ -662,6 +665,7 @@ pub mod ovis {
 			unsafe { sys::cv_ovis_WindowScene_getScreenshot_const__OutputArrayR(self.as_raw_mut_WindowScene(), frame.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(frame);
 			Ok(ret)
 		}
 		
@@ -683,6 +687,7 @@ pub mod ovis {
 			unsafe { sys::cv_ovis_WindowScene_getCompositorTexture_const_StringR_const_StringR_const__OutputArrayR_int(self.as_raw_mut_WindowScene(), compname.opencv_as_extern(), texname.opencv_as_extern(), out.as_raw__OutputArray(), mrt_index, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(out);
 			Ok(ret)
 		}
 		
@@ -696,6 +701,7 @@ pub mod ovis {
 			unsafe { sys::cv_ovis_WindowScene_getDepth_const__OutputArrayR(self.as_raw_mut_WindowScene(), depth.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(depth);
 			Ok(ret)
 		}
 		
@@ -796,6 +802,8 @@ pub mod ovis {
 			unsafe { sys::cv_ovis_WindowScene_getCameraPose_const__OutputArrayR_const__OutputArrayR_bool(self.as_raw_mut_WindowScene(), r.as_raw__OutputArray(), tvec.as_raw__OutputArray(), invert, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(r);
+			output_array_finish!(tvec);
 			Ok(ret)
 		}
 		
@@ -85,9 +85,9 @@ pub mod quality {
 		/// * model_file_path: cv::String which contains a path to the BRISQUE model data, eg. /path/to/brisque_model_live.yml
 		/// * range_file_path: cv::String which contains a path to the BRISQUE range data, eg. /path/to/brisque_range_live.yml
 		#[inline]
-		pub fn create(model_file_path: &str, range_file_path: &str) -> Result<core::Ptr<crate::quality::QualityBRISQUE>> {
-			extern_container_arg!(model_file_path);
-			extern_container_arg!(range_file_path);
+		pub fn create(model_file_path: impl AsRef<std::path::Path>, range_file_path: impl AsRef<std::path::Path>) -> Result<core::Ptr<crate::quality::QualityBRISQUE>> {
+			path_arg!(model_file_path);
+			path_arg!(range_file_path);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_quality_QualityBRISQUE_create_const_StringR_const_StringR(model_file_path.opencv_as_extern(), range_file_path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -118,10 +118,10 @@ pub mod quality {
 		/// ## Returns
 		/// cv::Scalar with the score in the first element.  The score ranges from 0 (best quality) to 100 (worst quality)
 		#[inline]
-		pub fn compute(img: &impl core::ToInputArray, model_file_path: &str, range_file_path: &str) -> Result<core::Scalar> {
+		pub fn compute(img: &impl core::ToInputArray, model_file_path: impl AsRef<std::path::Path>, range_file_path: impl AsRef<std::path::Path>) -> Result<core::Scalar> {
 			input_array_arg!(img);
-			extern_container_arg!(model_file_path);
-			extern_container_arg!(range_file_path);
+			path_arg!(model_file_path);
+			path_arg!(range_file_path);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_quality_QualityBRISQUE_compute_const__InputArrayR_const_StringR_const_StringR(img.as_raw__InputArray(), model_file_path.opencv_as_extern(), range_file_path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -141,6 +141,7 @@ pub mod quality {
 			unsafe { sys::cv_quality_QualityBRISQUE_computeFeatures_const__InputArrayR_const__OutputArrayR(img.as_raw__InputArray(), features.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(features);
 			Ok(ret)
 		}
 		
@@ -170,6 +171,7 @@ pub mod quality {
 			unsafe { sys::cv_quality_QualityBase_getQualityMap_const_const__OutputArrayR(self.as_raw_QualityBase(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(dst);
 			Ok(ret)
 		}
 		
@@ -390,6 +392,7 @@ pub mod quality {
 			unsafe { sys::cv_quality_QualityGMSD_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR(ref_.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(quality_map);
 			Ok(ret)
 		}
 		
@@ -526,6 +529,7 @@ pub mod quality {
 			unsafe { sys::cv_quality_QualityMSE_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR(ref_.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(quality_map);
 			Ok(ret)
 		}
 		
@@ -693,6 +697,7 @@ pub mod quality {
 			unsafe { sys::cv_quality_QualityPSNR_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double(ref_.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray(), max_pixel_value, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(quality_map);
 			Ok(ret)
 		}
 		
@@ -829,6 +834,7 @@ pub mod quality {
 			unsafe { sys::cv_quality_QualitySSIM_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR(ref_.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(quality_map);
 			Ok(ret)
 		}
 		
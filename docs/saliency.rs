@@ -143,6 +143,7 @@ pub mod saliency {
 			unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_computeSaliency_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_MotionSaliencyBinWangApr2014(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(saliency_map);
 			Ok(ret)
 		}
 		
@@ -408,6 +409,7 @@ pub mod saliency {
 			unsafe { sys::cv_saliency_ObjectnessBING_computeSaliency_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_ObjectnessBING(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(saliency_map);
 			Ok(ret)
 		}
 		
@@ -440,8 +442,8 @@ pub mod saliency {
 		/// ## Parameters
 		/// * trainingPath: trained model path
 		#[inline]
-		fn set_training_path(&mut self, training_path: &str) -> Result<()> {
-			extern_container_arg!(training_path);
+		fn set_training_path(&mut self, training_path: impl AsRef<std::path::Path>) -> Result<()> {
+			path_arg!(training_path);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_saliency_ObjectnessBING_setTrainingPath_const_StringR(self.as_raw_mut_ObjectnessBING(), training_path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -457,8 +459,8 @@ pub mod saliency {
 		/// ## Parameters
 		/// * resultsDir: results' folder path
 		#[inline]
-		fn set_bb_res_dir(&mut self, results_dir: &str) -> Result<()> {
-			extern_container_arg!(results_dir);
+		fn set_bb_res_dir(&mut self, results_dir: impl AsRef<std::path::Path>) -> Result<()> {
+			path_arg!(results_dir);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_saliency_ObjectnessBING_setBBResDir_const_StringR(self.as_raw_mut_ObjectnessBING(), results_dir.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -603,6 +605,7 @@ pub mod saliency {
 			unsafe { sys::cv_saliency_Saliency_computeSaliency_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Saliency(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(saliency_map);
 			Ok(ret)
 		}
 		
@@ -693,6 +696,7 @@ pub mod saliency {
 			unsafe { sys::cv_saliency_StaticSaliency_computeBinaryMap_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_StaticSaliency(), _saliency_map.as_raw__InputArray(), _binary_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(_binary_map);
 			Ok(ret)
 		}
 		
@@ -776,6 +780,7 @@ pub mod saliency {
 			unsafe { sys::cv_saliency_StaticSaliencyFineGrained_computeSaliency_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_StaticSaliencyFineGrained(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(saliency_map);
 			Ok(ret)
 		}
 		
@@ -915,6 +920,7 @@ pub mod saliency {
 			unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_computeSaliency_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_StaticSaliencySpectralResidual(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(saliency_map);
 			Ok(ret)
 		}
 		
@@ -209,8 +209,8 @@ pub mod stereo {
 		/// ## See also
 		/// loadParameters
 		#[inline]
-		fn load_parameters(&mut self, filepath: &str) -> Result<i32> {
-			extern_container_arg!(mut filepath);
+		fn load_parameters(&mut self, filepath: impl AsRef<std::path::Path>) -> Result<i32> {
+			path_arg!(mut filepath);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_stereo_QuasiDenseStereo_loadParameters_String(self.as_raw_mut_QuasiDenseStereo(), filepath.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -229,8 +229,8 @@ pub mod stereo {
 		/// ## See also
 		/// loadParameters
 		#[inline]
-		fn save_parameters(&mut self, filepath: &str) -> Result<i32> {
-			extern_container_arg!(mut filepath);
+		fn save_parameters(&mut self, filepath: impl AsRef<std::path::Path>) -> Result<i32> {
+			path_arg!(mut filepath);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_stereo_QuasiDenseStereo_saveParameters_String(self.as_raw_mut_QuasiDenseStereo(), filepath.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -378,8 +378,8 @@ pub mod stereo {
 		/// ## C++ default parameters
 		/// * param_filepath: cv::String()
 		#[inline]
-		pub fn create(mono_img_size: core::Size, param_filepath: &str) -> Result<core::Ptr<crate::stereo::QuasiDenseStereo>> {
-			extern_container_arg!(mut param_filepath);
+		pub fn create(mono_img_size: core::Size, param_filepath: impl AsRef<std::path::Path>) -> Result<core::Ptr<crate::stereo::QuasiDenseStereo>> {
+			path_arg!(mut param_filepath);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_stereo_QuasiDenseStereo_create_Size_String(mono_img_size.opencv_as_extern(), param_filepath.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -32,8 +32,8 @@ pub mod superres {
 	}
 	
 	#[inline]
-	pub fn create_frame_source_video_cuda(file_name: &str) -> Result<core::Ptr<crate::superres::SuperRes_FrameSource>> {
-		extern_container_arg!(file_name);
+	pub fn create_frame_source_video_cuda(file_name: impl AsRef<std::path::Path>) -> Result<core::Ptr<crate::superres::SuperRes_FrameSource>> {
+		path_arg!(file_name);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_superres_createFrameSource_Video_CUDA_const_StringR(file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -43,8 +43,8 @@ pub mod superres {
 	}
 	
 	#[inline]
-	pub fn create_frame_source_video(file_name: &str) -> Result<core::Ptr<crate::superres::SuperRes_FrameSource>> {
-		extern_container_arg!(file_name);
+	pub fn create_frame_source_video(file_name: impl AsRef<std::path::Path>) -> Result<core::Ptr<crate::superres::SuperRes_FrameSource>> {
+		path_arg!(file_name);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_superres_createFrameSource_Video_const_StringR(file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -384,6 +384,8 @@ pub mod superres {
 			unsafe { sys::cv_superres_DenseOpticalFlowExt_calc_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SuperRes_DenseOpticalFlowExt(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), flow1.as_raw__OutputArray(), flow2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(flow1);
+			output_array_finish!(flow2);
 			Ok(ret)
 		}
 		
@@ -932,6 +934,7 @@ pub mod superres {
 			unsafe { sys::cv_superres_FrameSource_nextFrame_const__OutputArrayR(self.as_raw_mut_SuperRes_FrameSource(), frame.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(frame);
 			Ok(ret)
 		}
 		
@@ -1271,6 +1274,7 @@ pub mod superres {
 			unsafe { sys::cv_superres_SuperResolution_nextFrame_const__OutputArrayR(self.as_raw_mut_SuperRes_SuperResolution(), frame.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(frame);
 			Ok(ret)
 		}
 		
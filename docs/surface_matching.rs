@@ -822,8 +822,8 @@ pub mod surface_matching {
 		}
 		
 		#[inline]
-		fn write_pose(&mut self, file_name: &str) -> Result<i32> {
-			extern_container_arg!(file_name);
+		fn write_pose(&mut self, file_name: impl AsRef<std::path::Path>) -> Result<i32> {
+			path_arg!(file_name);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ppf_match_3d_Pose3D_writePose_const_stringR(self.as_raw_mut_Pose3D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -832,8 +832,8 @@ pub mod surface_matching {
 		}
 		
 		#[inline]
-		fn read_pose(&mut self, file_name: &str) -> Result<i32> {
-			extern_container_arg!(file_name);
+		fn read_pose(&mut self, file_name: impl AsRef<std::path::Path>) -> Result<i32> {
+			path_arg!(file_name);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ppf_match_3d_Pose3D_readPose_const_stringR(self.as_raw_mut_Pose3D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -972,8 +972,8 @@ pub mod surface_matching {
 		}
 		
 		#[inline]
-		fn write_pose_cluster(&mut self, file_name: &str) -> Result<i32> {
-			extern_container_arg!(file_name);
+		fn write_pose_cluster(&mut self, file_name: impl AsRef<std::path::Path>) -> Result<i32> {
+			path_arg!(file_name);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ppf_match_3d_PoseCluster3D_writePoseCluster_const_stringR(self.as_raw_mut_PoseCluster3D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -982,8 +982,8 @@ pub mod surface_matching {
 		}
 		
 		#[inline]
-		fn read_pose_cluster(&mut self, file_name: &str) -> Result<i32> {
-			extern_container_arg!(file_name);
+		fn read_pose_cluster(&mut self, file_name: impl AsRef<std::path::Path>) -> Result<i32> {
+			path_arg!(file_name);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_ppf_match_3d_PoseCluster3D_readPoseCluster_const_stringR(self.as_raw_mut_PoseCluster3D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
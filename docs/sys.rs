@@ -41,6 +41,7 @@ mod aruco_sys {
 		pub fn cv_PtrOfEstimateParameters_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEstimateParameters_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfEstimateParameters_delete(instance: *mut c_void);
+		pub fn cv_PtrOfEstimateParameters_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEstimateParameters_new_const_EstimateParameters(val: *mut c_void) -> *mut c_void;
 	}
 }
@@ -108,21 +109,27 @@ mod bgsegm_sys {
 		pub fn cv_PtrOfBackgroundSubtractorCNT_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorCNT_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorCNT_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBackgroundSubtractorCNT_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorGMG_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorGMG_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorGMG_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBackgroundSubtractorGMG_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorGSOC_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorGSOC_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorGSOC_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBackgroundSubtractorGSOC_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorLSBP_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorLSBP_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorLSBP_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBackgroundSubtractorLSBP_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorMOG_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorMOG_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorMOG_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBackgroundSubtractorMOG_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSyntheticSequenceGenerator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSyntheticSequenceGenerator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSyntheticSequenceGenerator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSyntheticSequenceGenerator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSyntheticSequenceGenerator_new_const_SyntheticSequenceGenerator(val: *mut c_void) -> *mut c_void;
 	}
 }
@@ -184,12 +191,15 @@ mod bioinspired_sys {
 		pub fn cv_PtrOfRetina_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRetina_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRetina_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRetina_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRetinaFastToneMapping_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRetinaFastToneMapping_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRetinaFastToneMapping_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRetinaFastToneMapping_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTransientAreasSegmentationModule_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTransientAreasSegmentationModule_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTransientAreasSegmentationModule_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTransientAreasSegmentationModule_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_bioinspired)]
@@ -346,18 +356,23 @@ mod calib3d_sys {
 		pub fn cv_PtrOfLMSolver_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLMSolver_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLMSolver_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLMSolver_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLMSolver_Callback_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLMSolver_Callback_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLMSolver_Callback_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLMSolver_Callback_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStereoBM_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStereoBM_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStereoBM_delete(instance: *mut c_void);
+		pub fn cv_PtrOfStereoBM_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStereoMatcher_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStereoMatcher_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStereoMatcher_delete(instance: *mut c_void);
+		pub fn cv_PtrOfStereoMatcher_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStereoSGBM_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStereoSGBM_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStereoSGBM_delete(instance: *mut c_void);
+		pub fn cv_PtrOfStereoSGBM_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_calib3d)]
@@ -2233,32 +2248,45 @@ mod core_sys {
 		pub fn cv_PtrOfConjGradSolver_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfConjGradSolver_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfConjGradSolver_delete(instance: *mut c_void);
+		pub fn cv_PtrOfConjGradSolver_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDownhillSolver_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDownhillSolver_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDownhillSolver_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDownhillSolver_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFileStorage_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFileStorage_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFileStorage_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFileStorage_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFileStorage_new_const_FileStorage(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFormatted_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFormatted_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFormatted_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFormatted_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFormatter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFormatter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFormatter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFormatter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGpuMat_Allocator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGpuMat_Allocator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGpuMat_Allocator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfGpuMat_Allocator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMinProblemSolver_Function_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMinProblemSolver_Function_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMinProblemSolver_Function_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMinProblemSolver_Function_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOriginalClassName_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOriginalClassName_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOriginalClassName_delete(instance: *mut c_void);
+		pub fn cv_PtrOfOriginalClassName_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOriginalClassName_new_const_OriginalClassName(val: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfParallelLoopBody_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_PtrOfParallelLoopBody_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfParallelLoopBody_delete(instance: *mut c_void);
+		pub fn cv_PtrOfParallelLoopBody_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOff32_getInnerPtr_const(instance: *const c_void) -> *const f32;
 		pub fn cv_PtrOff32_getInnerPtrMut(instance: *mut c_void) -> *mut f32;
 		pub fn cv_PtrOff32_delete(instance: *mut c_void);
+		pub fn cv_PtrOff32_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOff32_new_const_float(val: f32) -> *mut c_void;
 		pub fn cv_VectorOfDMatch_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_VectorOfDMatch_fromSlice_const_const_DMatchX_size_t(data: *const core::DMatch, len: size_t) -> *mut c_void;
@@ -2509,12 +2537,15 @@ mod cudaarithm_sys {
 		pub fn cv_PtrOfConvolution_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfConvolution_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfConvolution_delete(instance: *mut c_void);
+		pub fn cv_PtrOfConvolution_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDFT_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDFT_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDFT_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDFT_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLookUpTable_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLookUpTable_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLookUpTable_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLookUpTable_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_cudaarithm)]
@@ -2544,9 +2575,11 @@ mod cudabgsegm_sys {
 		pub fn cv_PtrOfCUDA_BackgroundSubtractorMOG_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_BackgroundSubtractorMOG_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_BackgroundSubtractorMOG_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_BackgroundSubtractorMOG_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_BackgroundSubtractorMOG2_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_BackgroundSubtractorMOG2_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_BackgroundSubtractorMOG2_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_BackgroundSubtractorMOG2_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_cudabgsegm)]
@@ -2591,15 +2624,19 @@ mod cudacodec_sys {
 		pub fn cv_PtrOfCUDA_EncoderCallback_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_EncoderCallback_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_EncoderCallback_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_EncoderCallback_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_RawVideoSource_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_RawVideoSource_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_RawVideoSource_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_RawVideoSource_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_VideoReader_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_VideoReader_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_VideoReader_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_VideoReader_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_VideoWriter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_VideoWriter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_VideoWriter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_VideoWriter_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_cudacodec)]
@@ -2664,12 +2701,15 @@ mod cudafeatures2d_sys {
 		pub fn cv_PtrOfCUDA_DescriptorMatcher_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_DescriptorMatcher_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_DescriptorMatcher_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_DescriptorMatcher_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_FastFeatureDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_FastFeatureDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_FastFeatureDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_FastFeatureDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_ORB_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_ORB_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_ORB_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_ORB_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_cudafeatures2d)]
@@ -2698,6 +2738,7 @@ mod cudafilters_sys {
 		pub fn cv_PtrOfFilter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFilter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFilter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFilter_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_cudafilters)]
@@ -2794,27 +2835,35 @@ mod cudaimgproc_sys {
 		pub fn cv_PtrOfCUDA_CLAHE_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_CLAHE_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_CLAHE_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_CLAHE_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_CannyEdgeDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_CannyEdgeDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_CannyEdgeDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_CannyEdgeDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_CornernessCriteria_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_CornernessCriteria_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_CornernessCriteria_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_CornernessCriteria_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_CornersDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_CornersDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_CornersDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_CornersDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_HoughCirclesDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_HoughCirclesDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_HoughCirclesDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_HoughCirclesDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_HoughLinesDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_HoughLinesDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_HoughLinesDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_HoughLinesDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_HoughSegmentDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_HoughSegmentDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_HoughSegmentDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_HoughSegmentDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_TemplateMatching_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_TemplateMatching_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_TemplateMatching_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_TemplateMatching_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_cudaimgproc)]
@@ -2875,9 +2924,11 @@ mod cudaobjdetect_sys {
 		pub fn cv_PtrOfCUDA_CascadeClassifier_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_CascadeClassifier_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_CascadeClassifier_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_CascadeClassifier_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_HOG_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_HOG_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_HOG_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_HOG_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_cudaobjdetect)]
@@ -2970,24 +3021,31 @@ mod cudaoptflow_sys {
 		pub fn cv_PtrOfCUDA_BroxOpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_BroxOpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_BroxOpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_BroxOpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_DensePyrLKOpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_DensePyrLKOpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_DensePyrLKOpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_DensePyrLKOpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_FarnebackOpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_FarnebackOpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_FarnebackOpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_FarnebackOpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_NvidiaOpticalFlow_1_0_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_NvidiaOpticalFlow_1_0_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_NvidiaOpticalFlow_1_0_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_NvidiaOpticalFlow_1_0_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_NvidiaOpticalFlow_2_0_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_NvidiaOpticalFlow_2_0_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_NvidiaOpticalFlow_2_0_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_NvidiaOpticalFlow_2_0_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_OpticalFlowDual_TVL1_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_OpticalFlowDual_TVL1_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_OpticalFlowDual_TVL1_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_OpticalFlowDual_TVL1_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_SparsePyrLKOpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_SparsePyrLKOpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_SparsePyrLKOpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_SparsePyrLKOpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_cudaoptflow)]
@@ -3047,18 +3105,23 @@ mod cudastereo_sys {
 		pub fn cv_PtrOfCUDA_DisparityBilateralFilter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_DisparityBilateralFilter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_DisparityBilateralFilter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_DisparityBilateralFilter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_StereoBM_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_StereoBM_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_StereoBM_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_StereoBM_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_StereoBeliefPropagation_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_StereoBeliefPropagation_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_StereoBeliefPropagation_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_StereoBeliefPropagation_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_StereoConstantSpaceBP_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_StereoConstantSpaceBP_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_StereoConstantSpaceBP_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_StereoConstantSpaceBP_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_StereoSGM_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_StereoSGM_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCUDA_StereoSGM_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCUDA_StereoSGM_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_cudastereo)]
@@ -3140,22 +3203,22 @@ mod dnn_sys {
 		pub fn cv_dnn_getPlane_const_MatR_int_int(m: *const c_void, n: i32, cn: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_imagesFromBlob_const_MatR_const__OutputArrayR(blob_: *const c_void, images_: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_dnn_readNetFromCaffe_const_StringR_const_StringR(prototxt: *const c_char, caffe_model: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_dnn_readNetFromCaffe_const_charX_size_t_const_charX_size_t(buffer_proto: *const c_char, len_proto: size_t, buffer_model: *const c_char, len_model: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_dnn_readNetFromCaffe_const_charX_size_t_const_charX_size_t(buffer_proto: *const u8, len_proto: size_t, buffer_model: *const u8, len_model: size_t, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromCaffe_const_vectorLunsigned_charGR_const_vectorLunsigned_charGR(buffer_proto: *const c_void, buffer_model: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromDarknet_const_StringR_const_StringR(cfg_file: *const c_char, darknet_model: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_dnn_readNetFromDarknet_const_charX_size_t_const_charX_size_t(buffer_cfg: *const c_char, len_cfg: size_t, buffer_model: *const c_char, len_model: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_dnn_readNetFromDarknet_const_charX_size_t_const_charX_size_t(buffer_cfg: *const u8, len_cfg: size_t, buffer_model: *const u8, len_model: size_t, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromDarknet_const_vectorLunsigned_charGR_const_vectorLunsigned_charGR(buffer_cfg: *const c_void, buffer_model: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromModelOptimizer_const_StringR_const_StringR(xml: *const c_char, bin: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromModelOptimizer_const_unsigned_charX_size_t_const_unsigned_charX_size_t(buffer_model_config_ptr: *const u8, buffer_model_config_size: size_t, buffer_weights_ptr: *const u8, buffer_weights_size: size_t, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromModelOptimizer_const_vectorLunsigned_charGR_const_vectorLunsigned_charGR(buffer_model_config: *const c_void, buffer_weights: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromONNX_const_StringR(onnx_file: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_dnn_readNetFromONNX_const_charX_size_t(buffer: *const c_char, size_buffer: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_dnn_readNetFromONNX_const_charX_size_t(buffer: *const u8, size_buffer: size_t, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromONNX_const_vectorLunsigned_charGR(buffer: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromTFLite_const_StringR(model: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_dnn_readNetFromTFLite_const_charX_size_t(buffer_model: *const c_char, len_model: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_dnn_readNetFromTFLite_const_charX_size_t(buffer_model: *const u8, len_model: size_t, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromTFLite_const_vectorLunsigned_charGR(buffer_model: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromTensorflow_const_StringR_const_StringR(model: *const c_char, config: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_dnn_readNetFromTensorflow_const_charX_size_t_const_charX_size_t(buffer_model: *const c_char, len_model: size_t, buffer_config: *const c_char, len_config: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_dnn_readNetFromTensorflow_const_charX_size_t_const_charX_size_t(buffer_model: *const u8, len_model: size_t, buffer_config: *const u8, len_config: size_t, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromTensorflow_const_vectorLunsigned_charGR_const_vectorLunsigned_charGR(buffer_model: *const c_void, buffer_config: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromTorch_const_StringR_bool_bool(model: *const c_char, is_binary: bool, evaluate: bool, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNet_const_StringR_const_StringR_const_StringR(model: *const c_char, config: *const c_char, framework: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
@@ -3744,379 +3807,474 @@ mod dnn_sys {
 		pub fn cv_PtrOfAbsLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAbsLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAbsLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAbsLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAbsLayer_new_const_AbsLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAccumLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAccumLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAccumLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAccumLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAccumLayer_new_const_AccumLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAcosLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAcosLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAcosLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAcosLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAcosLayer_new_const_AcosLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAcoshLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAcoshLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAcoshLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAcoshLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAcoshLayer_new_const_AcoshLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfActivationLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfActivationLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfActivationLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfActivationLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfActivationLayer_new_const_ActivationLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfActivationLayerInt8_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfActivationLayerInt8_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfActivationLayerInt8_delete(instance: *mut c_void);
+		pub fn cv_PtrOfActivationLayerInt8_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfActivationLayerInt8_new_const_ActivationLayerInt8(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfArgLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfArgLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfArgLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfArgLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfArgLayer_new_const_ArgLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAsinLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAsinLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAsinLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAsinLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAsinLayer_new_const_AsinLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAsinhLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAsinhLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAsinhLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAsinhLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAsinhLayer_new_const_AsinhLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAtanLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAtanLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAtanLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAtanLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAtanLayer_new_const_AtanLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAtanhLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAtanhLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAtanhLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAtanhLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAtanhLayer_new_const_AtanhLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBNLLLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBNLLLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBNLLLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBNLLLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBNLLLayer_new_const_BNLLLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackendNode_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackendNode_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackendNode_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBackendNode_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackendNode_new_const_BackendNode(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackendWrapper_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackendWrapper_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackendWrapper_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBackendWrapper_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBaseConvolutionLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBaseConvolutionLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBaseConvolutionLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBaseConvolutionLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBaseConvolutionLayer_new_const_BaseConvolutionLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBatchNormLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBatchNormLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBatchNormLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBatchNormLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBatchNormLayer_new_const_BatchNormLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBatchNormLayerInt8_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBatchNormLayerInt8_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBatchNormLayerInt8_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBatchNormLayerInt8_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBatchNormLayerInt8_new_const_BatchNormLayerInt8(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCeilLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCeilLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCeilLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCeilLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCeilLayer_new_const_CeilLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCeluLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCeluLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCeluLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCeluLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCeluLayer_new_const_CeluLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfConcatLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfConcatLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfConcatLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfConcatLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfConcatLayer_new_const_ConcatLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCorrelationLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCorrelationLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCorrelationLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCorrelationLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCorrelationLayer_new_const_CorrelationLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCosLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCosLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCosLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCosLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCosLayer_new_const_CosLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCoshLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCoshLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCoshLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCoshLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCoshLayer_new_const_CoshLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCumSumLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCumSumLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCumSumLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCumSumLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCumSumLayer_new_const_CumSumLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDataAugmentationLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDataAugmentationLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDataAugmentationLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDataAugmentationLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDataAugmentationLayer_new_const_DataAugmentationLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDequantizeLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDequantizeLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDequantizeLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDequantizeLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDequantizeLayer_new_const_DequantizeLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetectionOutputLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetectionOutputLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetectionOutputLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetectionOutputLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetectionOutputLayer_new_const_DetectionOutputLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfELULayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfELULayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfELULayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfELULayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfELULayer_new_const_ELULayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfEltwiseLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEltwiseLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfEltwiseLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfEltwiseLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEltwiseLayer_new_const_EltwiseLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfEltwiseLayerInt8_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEltwiseLayerInt8_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfEltwiseLayerInt8_delete(instance: *mut c_void);
+		pub fn cv_PtrOfEltwiseLayerInt8_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEltwiseLayerInt8_new_const_EltwiseLayerInt8(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfErfLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfErfLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfErfLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfErfLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfErfLayer_new_const_ErfLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfExpLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfExpLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfExpLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfExpLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfExpLayer_new_const_ExpLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFlattenLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFlattenLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFlattenLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFlattenLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFlattenLayer_new_const_FlattenLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFloorLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFloorLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFloorLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFloorLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFloorLayer_new_const_FloorLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFlowWarpLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFlowWarpLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFlowWarpLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFlowWarpLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFlowWarpLayer_new_const_FlowWarpLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGRULayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGRULayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGRULayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfGRULayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGRULayer_new_const_GRULayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGatherLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGatherLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGatherLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfGatherLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGatherLayer_new_const_GatherLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGeluApproximationLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGeluApproximationLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGeluApproximationLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfGeluApproximationLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGeluApproximationLayer_new_const_GeluApproximationLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGeluLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGeluLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGeluLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfGeluLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGeluLayer_new_const_GeluLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfHardSigmoidLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfHardSigmoidLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfHardSigmoidLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfHardSigmoidLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfHardSigmoidLayer_new_const_HardSigmoidLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfHardSwishLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfHardSwishLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfHardSwishLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfHardSwishLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfHardSwishLayer_new_const_HardSwishLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfInnerProductLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfInnerProductLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfInnerProductLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfInnerProductLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfInnerProductLayer_new_const_InnerProductLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfInnerProductLayerInt8_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfInnerProductLayerInt8_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfInnerProductLayerInt8_delete(instance: *mut c_void);
+		pub fn cv_PtrOfInnerProductLayerInt8_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfInnerProductLayerInt8_new_const_InnerProductLayerInt8(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLRNLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLRNLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLRNLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLRNLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLRNLayer_new_const_LRNLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLSTMLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLSTMLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLSTMLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLSTMLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLayer_new_const_Layer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLayerNormLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLayerNormLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLayerNormLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLayerNormLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLayerNormLayer_new_const_LayerNormLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLogLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLogLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLogLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLogLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLogLayer_new_const_LogLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMVNLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMVNLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMVNLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMVNLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMVNLayer_new_const_MVNLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMaxUnpoolLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMaxUnpoolLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMaxUnpoolLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMaxUnpoolLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMaxUnpoolLayer_new_const_MaxUnpoolLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMishLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMishLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMishLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMishLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMishLayer_new_const_MishLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNaryEltwiseLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNaryEltwiseLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNaryEltwiseLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfNaryEltwiseLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNaryEltwiseLayer_new_const_NaryEltwiseLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNormalizeBBoxLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNormalizeBBoxLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNormalizeBBoxLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfNormalizeBBoxLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNormalizeBBoxLayer_new_const_NormalizeBBoxLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNotLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNotLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNotLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfNotLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNotLayer_new_const_NotLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPaddingLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPaddingLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPaddingLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPaddingLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPaddingLayer_new_const_PaddingLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPermuteLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPermuteLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPermuteLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPermuteLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPermuteLayer_new_const_PermuteLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPoolingLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPoolingLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPoolingLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPoolingLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPoolingLayer_new_const_PoolingLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPoolingLayerInt8_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPoolingLayerInt8_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPoolingLayerInt8_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPoolingLayerInt8_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPoolingLayerInt8_new_const_PoolingLayerInt8(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPowerLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPowerLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPowerLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPowerLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPowerLayer_new_const_PowerLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPriorBoxLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPriorBoxLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPriorBoxLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPriorBoxLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPriorBoxLayer_new_const_PriorBoxLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfProposalLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfProposalLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfProposalLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfProposalLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfProposalLayer_new_const_ProposalLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfQuantizeLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfQuantizeLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfQuantizeLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfQuantizeLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfQuantizeLayer_new_const_QuantizeLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRNNLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRNNLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRNNLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRNNLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfReLU6Layer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfReLU6Layer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfReLU6Layer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfReLU6Layer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfReLU6Layer_new_const_ReLU6Layer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfReLULayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfReLULayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfReLULayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfReLULayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfReLULayer_new_const_ReLULayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfReciprocalLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfReciprocalLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfReciprocalLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfReciprocalLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfReciprocalLayer_new_const_ReciprocalLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfReduceLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfReduceLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfReduceLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfReduceLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfReduceLayer_new_const_ReduceLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRegionLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRegionLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRegionLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRegionLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRegionLayer_new_const_RegionLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfReorgLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfReorgLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfReorgLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfReorgLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfReorgLayer_new_const_ReorgLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRequantizeLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRequantizeLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRequantizeLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRequantizeLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRequantizeLayer_new_const_RequantizeLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfReshapeLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfReshapeLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfReshapeLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfReshapeLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfReshapeLayer_new_const_ReshapeLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfResizeLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfResizeLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfResizeLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfResizeLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfResizeLayer_new_const_ResizeLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRoundLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRoundLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRoundLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRoundLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRoundLayer_new_const_RoundLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfScaleLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfScaleLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfScaleLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfScaleLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfScaleLayer_new_const_ScaleLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfScaleLayerInt8_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfScaleLayerInt8_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfScaleLayerInt8_delete(instance: *mut c_void);
+		pub fn cv_PtrOfScaleLayerInt8_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfScaleLayerInt8_new_const_ScaleLayerInt8(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfScatterLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfScatterLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfScatterLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfScatterLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfScatterLayer_new_const_ScatterLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfScatterNDLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfScatterNDLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfScatterNDLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfScatterNDLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfScatterNDLayer_new_const_ScatterNDLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSeluLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSeluLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSeluLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSeluLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSeluLayer_new_const_SeluLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfShrinkLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfShrinkLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfShrinkLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfShrinkLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfShrinkLayer_new_const_ShrinkLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSigmoidLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSigmoidLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSigmoidLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSigmoidLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSigmoidLayer_new_const_SigmoidLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSignLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSignLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSignLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSignLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSignLayer_new_const_SignLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSinLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSinLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSinLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSinLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSinLayer_new_const_SinLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSinhLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSinhLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSinhLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSinhLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSinhLayer_new_const_SinhLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSliceLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSliceLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSliceLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSliceLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSliceLayer_new_const_SliceLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftmaxLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftmaxLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftmaxLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSoftmaxLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftmaxLayer_new_const_SoftmaxLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftmaxLayerInt8_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftmaxLayerInt8_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftmaxLayerInt8_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSoftmaxLayerInt8_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftmaxLayerInt8_new_const_SoftmaxLayerInt8(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftplusLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftplusLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftplusLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSoftplusLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftplusLayer_new_const_SoftplusLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftsignLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftsignLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftsignLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSoftsignLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSoftsignLayer_new_const_SoftsignLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSplitLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSplitLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSplitLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSplitLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSplitLayer_new_const_SplitLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSqrtLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSqrtLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSqrtLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSqrtLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSqrtLayer_new_const_SqrtLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSwishLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSwishLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSwishLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSwishLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSwishLayer_new_const_SwishLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTanHLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTanHLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTanHLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTanHLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTanHLayer_new_const_TanHLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTanLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTanLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTanLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTanLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTanLayer_new_const_TanLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfThresholdedReluLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfThresholdedReluLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfThresholdedReluLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfThresholdedReluLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfThresholdedReluLayer_new_const_ThresholdedReluLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTileLayer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTileLayer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTileLayer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTileLayer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTileLayer_new_const_TileLayer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_VectorOfMatShape_inputArray_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_VectorOfMatShape_outputArray(instance: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
@@ -4148,6 +4306,7 @@ mod dnn_superres_sys {
 		pub fn cv_PtrOfDnnSuperResImpl_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDnnSuperResImpl_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDnnSuperResImpl_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDnnSuperResImpl_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDnnSuperResImpl_new_const_DnnSuperResImpl(val: *mut c_void) -> *mut c_void;
 	}
 }
@@ -4175,6 +4334,7 @@ mod dpm_sys {
 		pub fn cv_PtrOfDPMDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDPMDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDPMDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDPMDetector_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_dpm)]
@@ -4405,37 +4565,49 @@ mod face_sys {
 		pub fn cv_PtrOfBIF_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBIF_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBIF_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBIF_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEigenFaceRecognizer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEigenFaceRecognizer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfEigenFaceRecognizer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfEigenFaceRecognizer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFacemark_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFacemark_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFacemark_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFacemark_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFacemarkAAM_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFacemarkAAM_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFacemarkAAM_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFacemarkAAM_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFacemarkKazemi_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFacemarkKazemi_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFacemarkKazemi_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFacemarkKazemi_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFacemarkLBF_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFacemarkLBF_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFacemarkLBF_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFacemarkLBF_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFisherFaceRecognizer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFisherFaceRecognizer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFisherFaceRecognizer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFisherFaceRecognizer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLBPHFaceRecognizer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLBPHFaceRecognizer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLBPHFaceRecognizer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLBPHFaceRecognizer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMACE_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMACE_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMACE_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMACE_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPredictCollector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPredictCollector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPredictCollector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPredictCollector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStandardCollector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStandardCollector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStandardCollector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfStandardCollector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStandardCollector_to_PtrOfPredictCollector(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfPredictCollector_to_PtrOfStandardCollector(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStandardCollector_new_const_StandardCollector(val: *mut c_void) -> *mut c_void;
 	}
 }
@@ -4679,62 +4851,88 @@ mod features2d_sys {
 		pub fn cv_PtrOfAKAZE_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAKAZE_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAKAZE_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAKAZE_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAKAZE_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfAKAZE(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAffineFeature_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAffineFeature_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAffineFeature_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAffineFeature_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAffineFeature_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfAffineFeature(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAgastFeatureDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAgastFeatureDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAgastFeatureDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAgastFeatureDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAgastFeatureDetector_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfAgastFeatureDetector(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBFMatcher_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBFMatcher_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBFMatcher_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBFMatcher_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBFMatcher_new_const_BFMatcher(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBRISK_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBRISK_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBRISK_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBRISK_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBRISK_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfBRISK(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDescriptorMatcher_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDescriptorMatcher_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDescriptorMatcher_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDescriptorMatcher_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastFeatureDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastFeatureDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastFeatureDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFastFeatureDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastFeatureDetector_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfFastFeatureDetector(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFeature2D_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFeature2D_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFeature2D_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFeature2D_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFeature2D_new_const_Feature2D(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFlannBasedMatcher_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFlannBasedMatcher_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFlannBasedMatcher_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFlannBasedMatcher_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFlannBasedMatcher_new_const_FlannBasedMatcher(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGFTTDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGFTTDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGFTTDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfGFTTDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGFTTDetector_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfGFTTDetector(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKAZE_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKAZE_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKAZE_delete(instance: *mut c_void);
+		pub fn cv_PtrOfKAZE_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKAZE_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfKAZE(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMSER_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMSER_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMSER_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMSER_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMSER_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfMSER(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfORB_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfORB_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfORB_delete(instance: *mut c_void);
+		pub fn cv_PtrOfORB_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfORB_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfORB(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSIFT_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSIFT_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSIFT_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSIFT_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSIFT_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfSIFT(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSimpleBlobDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSimpleBlobDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSimpleBlobDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSimpleBlobDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSimpleBlobDetector_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfSimpleBlobDetector(instance: *mut c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_features2d)]
@@ -4783,10 +4981,12 @@ mod flann_sys {
 		pub fn cv_PtrOfIndexParams_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfIndexParams_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfIndexParams_delete(instance: *mut c_void);
+		pub fn cv_PtrOfIndexParams_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfIndexParams_new_const_IndexParams(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSearchParams_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSearchParams_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSearchParams_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSearchParams_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSearchParams_new_const_SearchParams(val: *mut c_void) -> *mut c_void;
 		pub fn cv_VectorOfFlannIndexType_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_VectorOfFlannIndexType_fromSlice_const_const_FlannIndexTypeX_size_t(data: *const crate::flann::FlannIndexType, len: size_t) -> *mut c_void;
@@ -4810,6 +5010,7 @@ mod freetype_sys {
 		pub fn cv_PtrOfFreeType2_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFreeType2_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFreeType2_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFreeType2_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_freetype)]
@@ -5279,6 +5480,7 @@ mod hdf_sys {
 		pub fn cv_PtrOfHDF5_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfHDF5_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfHDF5_delete(instance: *mut c_void);
+		pub fn cv_PtrOfHDF5_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_hdf)]
@@ -5309,6 +5511,7 @@ mod hfs_sys {
 		pub fn cv_PtrOfHfsSegment_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfHfsSegment_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfHfsSegment_delete(instance: *mut c_void);
+		pub fn cv_PtrOfHfsSegment_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_hfs)]
@@ -5417,26 +5620,32 @@ mod img_hash_sys {
 		pub fn cv_PtrOfAverageHash_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAverageHash_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAverageHash_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAverageHash_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAverageHash_new_const_AverageHash(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBlockMeanHash_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBlockMeanHash_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBlockMeanHash_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBlockMeanHash_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBlockMeanHash_new_const_BlockMeanHash(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfColorMomentHash_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfColorMomentHash_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfColorMomentHash_delete(instance: *mut c_void);
+		pub fn cv_PtrOfColorMomentHash_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfColorMomentHash_new_const_ColorMomentHash(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMarrHildrethHash_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMarrHildrethHash_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMarrHildrethHash_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMarrHildrethHash_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMarrHildrethHash_new_const_MarrHildrethHash(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPHash_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPHash_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPHash_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPHash_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPHash_new_const_PHash(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRadialVarianceHash_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRadialVarianceHash_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRadialVarianceHash_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRadialVarianceHash_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRadialVarianceHash_new_const_RadialVarianceHash(val: *mut c_void) -> *mut c_void;
 	}
 }
@@ -5739,15 +5948,19 @@ mod imgproc_sys {
 		pub fn cv_PtrOfCLAHE_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCLAHE_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCLAHE_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCLAHE_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGeneralizedHoughBallard_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGeneralizedHoughBallard_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGeneralizedHoughBallard_delete(instance: *mut c_void);
+		pub fn cv_PtrOfGeneralizedHoughBallard_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGeneralizedHoughGuil_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGeneralizedHoughGuil_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGeneralizedHoughGuil_delete(instance: *mut c_void);
+		pub fn cv_PtrOfGeneralizedHoughGuil_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineSegmentDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineSegmentDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineSegmentDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLineSegmentDetector_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_imgproc)]
@@ -5832,14 +6045,17 @@ mod line_descriptor_sys {
 		pub fn cv_PtrOfBinaryDescriptor_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBinaryDescriptor_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBinaryDescriptor_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBinaryDescriptor_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBinaryDescriptor_new_const_BinaryDescriptor(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBinaryDescriptorMatcher_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBinaryDescriptorMatcher_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBinaryDescriptorMatcher_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBinaryDescriptorMatcher_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBinaryDescriptorMatcher_new_const_BinaryDescriptorMatcher(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLSDDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLSDDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLSDDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLSDDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLSDDetector_new_const_LSDDetector(val: *mut c_void) -> *mut c_void;
 		pub fn cv_VectorOfKeyLine_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_VectorOfKeyLine_fromSlice_const_const_KeyLineX_size_t(data: *const crate::line_descriptor::KeyLine, len: size_t) -> *mut c_void;
@@ -5938,15 +6154,19 @@ mod mcc_sys {
 		pub fn cv_PtrOfMCC_CChecker_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMCC_CChecker_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMCC_CChecker_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMCC_CChecker_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMCC_CCheckerDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMCC_CCheckerDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMCC_CCheckerDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMCC_CCheckerDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMCC_CCheckerDraw_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMCC_CCheckerDraw_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMCC_CCheckerDraw_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMCC_CCheckerDraw_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMCC_DetectorParameters_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMCC_DetectorParameters_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMCC_DetectorParameters_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMCC_DetectorParameters_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMCC_DetectorParameters_new_const_DetectorParameters(val: *mut c_void) -> *mut c_void;
 	}
 }
@@ -6221,43 +6441,56 @@ mod ml_sys {
 		pub fn cv_PtrOfANN_MLP_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfANN_MLP_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfANN_MLP_delete(instance: *mut c_void);
+		pub fn cv_PtrOfANN_MLP_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBoost_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBoost_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBoost_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBoost_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDTrees_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDTrees_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDTrees_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDTrees_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEM_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEM_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfEM_delete(instance: *mut c_void);
+		pub fn cv_PtrOfEM_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKNearest_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKNearest_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKNearest_delete(instance: *mut c_void);
+		pub fn cv_PtrOfKNearest_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLogisticRegression_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLogisticRegression_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLogisticRegression_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLogisticRegression_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNormalBayesClassifier_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNormalBayesClassifier_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNormalBayesClassifier_delete(instance: *mut c_void);
+		pub fn cv_PtrOfNormalBayesClassifier_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfParamGrid_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfParamGrid_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfParamGrid_delete(instance: *mut c_void);
+		pub fn cv_PtrOfParamGrid_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfParamGrid_new_const_ParamGrid(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRTrees_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRTrees_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRTrees_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRTrees_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSVM_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSVM_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSVM_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSVM_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSVMSGD_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSVMSGD_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSVMSGD_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSVMSGD_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSVM_Kernel_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSVM_Kernel_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSVM_Kernel_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSVM_Kernel_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrainData_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrainData_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrainData_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTrainData_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_ml)]
@@ -6595,37 +6828,47 @@ mod objdetect_sys {
 		pub fn cv_PtrOfBaseCascadeClassifier_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBaseCascadeClassifier_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBaseCascadeClassifier_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBaseCascadeClassifier_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBaseCascadeClassifier_MaskGenerator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBaseCascadeClassifier_MaskGenerator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBaseCascadeClassifier_MaskGenerator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBaseCascadeClassifier_MaskGenerator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBoard_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBoard_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBoard_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBoard_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBoard_new_const_Board(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCharucoBoard_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCharucoBoard_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCharucoBoard_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCharucoBoard_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCharucoBoard_new_const_CharucoBoard(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetectionBasedTracker_IDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetectionBasedTracker_IDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetectionBasedTracker_IDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetectionBasedTracker_IDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetectorParameters_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetectorParameters_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetectorParameters_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetectorParameters_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetectorParameters_new_const_DetectorParameters(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDictionary_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDictionary_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDictionary_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDictionary_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDictionary_new_const_Dictionary(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFaceDetectorYN_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFaceDetectorYN_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFaceDetectorYN_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFaceDetectorYN_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFaceRecognizerSF_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFaceRecognizerSF_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFaceRecognizerSF_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFaceRecognizerSF_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfQRCodeEncoder_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfQRCodeEncoder_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfQRCodeEncoder_delete(instance: *mut c_void);
+		pub fn cv_PtrOfQRCodeEncoder_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_objdetect)]
@@ -6812,28 +7055,35 @@ mod optflow_sys {
 		pub fn cv_PtrOfDenseRLOFOpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDenseRLOFOpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDenseRLOFOpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDenseRLOFOpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDualTVL1OpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDualTVL1OpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDualTVL1OpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDualTVL1OpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGPCTrainingSamples_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGPCTrainingSamples_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGPCTrainingSamples_delete(instance: *mut c_void);
+		pub fn cv_PtrOfGPCTrainingSamples_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGPCTrainingSamples_new_const_GPCTrainingSamples(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGPCTree_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGPCTree_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGPCTree_delete(instance: *mut c_void);
+		pub fn cv_PtrOfGPCTree_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGPCTree_new_const_GPCTree(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPCAPrior_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPCAPrior_getInnerPtrMut_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPCAPrior_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPCAPrior_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPCAPrior_new_const_const_PCAPrior(val: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRLOFOpticalFlowParameter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRLOFOpticalFlowParameter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRLOFOpticalFlowParameter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRLOFOpticalFlowParameter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRLOFOpticalFlowParameter_new_const_RLOFOpticalFlowParameter(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparseRLOFOpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparseRLOFOpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparseRLOFOpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSparseRLOFOpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_optflow)]
@@ -6885,6 +7135,7 @@ mod ovis_sys {
 		pub fn cv_PtrOfWindowScene_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfWindowScene_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfWindowScene_delete(instance: *mut c_void);
+		pub fn cv_PtrOfWindowScene_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_ovis)]
@@ -6902,6 +7153,7 @@ mod phase_unwrapping_sys {
 		pub fn cv_PtrOfHistogramPhaseUnwrapping_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfHistogramPhaseUnwrapping_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfHistogramPhaseUnwrapping_delete(instance: *mut c_void);
+		pub fn cv_PtrOfHistogramPhaseUnwrapping_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_phase_unwrapping)]
@@ -7002,33 +7254,43 @@ mod photo_sys {
 		pub fn cv_PtrOfAlignMTB_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAlignMTB_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAlignMTB_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAlignMTB_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCalibrateDebevec_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCalibrateDebevec_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCalibrateDebevec_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCalibrateDebevec_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCalibrateRobertson_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCalibrateRobertson_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCalibrateRobertson_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCalibrateRobertson_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMergeDebevec_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMergeDebevec_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMergeDebevec_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMergeDebevec_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMergeMertens_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMergeMertens_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMergeMertens_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMergeMertens_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMergeRobertson_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMergeRobertson_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMergeRobertson_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMergeRobertson_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTonemap_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTonemap_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTonemap_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTonemap_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTonemapDrago_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTonemapDrago_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTonemapDrago_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTonemapDrago_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTonemapMantiuk_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTonemapMantiuk_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTonemapMantiuk_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTonemapMantiuk_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTonemapReinhard_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTonemapReinhard_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTonemapReinhard_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTonemapReinhard_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_photo)]
@@ -7062,6 +7324,7 @@ mod plot_sys {
 		pub fn cv_PtrOfPlot2d_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPlot2d_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPlot2d_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPlot2d_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_plot)]
@@ -7106,22 +7369,27 @@ mod quality_sys {
 		pub fn cv_PtrOfQualityBRISQUE_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualityBRISQUE_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualityBRISQUE_delete(instance: *mut c_void);
+		pub fn cv_PtrOfQualityBRISQUE_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualityBRISQUE_new_const_QualityBRISQUE(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualityGMSD_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualityGMSD_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualityGMSD_delete(instance: *mut c_void);
+		pub fn cv_PtrOfQualityGMSD_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualityGMSD_new_const_QualityGMSD(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualityMSE_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualityMSE_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualityMSE_delete(instance: *mut c_void);
+		pub fn cv_PtrOfQualityMSE_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualityMSE_new_const_QualityMSE(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualityPSNR_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualityPSNR_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualityPSNR_delete(instance: *mut c_void);
+		pub fn cv_PtrOfQualityPSNR_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualityPSNR_new_const_QualityPSNR(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualitySSIM_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualitySSIM_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualitySSIM_delete(instance: *mut c_void);
+		pub fn cv_PtrOfQualitySSIM_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfQualitySSIM_new_const_QualitySSIM(val: *mut c_void) -> *mut c_void;
 	}
 }
@@ -7149,9 +7417,11 @@ mod rapid_sys {
 		pub fn cv_PtrOfRapid_OLSTracker_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRapid_OLSTracker_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRapid_OLSTracker_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRapid_OLSTracker_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRapid_Rapid_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRapid_Rapid_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRapid_Rapid_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRapid_Rapid_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_rapid)]
@@ -7689,93 +7959,118 @@ mod rgbd_sys {
 		pub fn cv_PtrOfColoredKinfu_ColoredKinFu_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfColoredKinfu_ColoredKinFu_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfColoredKinfu_ColoredKinFu_delete(instance: *mut c_void);
+		pub fn cv_PtrOfColoredKinfu_ColoredKinFu_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfColoredKinfu_Params_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfColoredKinfu_Params_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfColoredKinfu_Params_delete(instance: *mut c_void);
+		pub fn cv_PtrOfColoredKinfu_Params_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfColoredKinfu_Params_new_const_Params(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDepthCleaner_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDepthCleaner_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDepthCleaner_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDepthCleaner_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDepthCleaner_new_const_DepthCleaner(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDynafu_DynaFu_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDynafu_DynaFu_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDynafu_DynaFu_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDynafu_DynaFu_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastICPOdometry_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastICPOdometry_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastICPOdometry_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFastICPOdometry_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastICPOdometry_new_const_FastICPOdometry(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfICPOdometry_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfICPOdometry_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfICPOdometry_delete(instance: *mut c_void);
+		pub fn cv_PtrOfICPOdometry_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfICPOdometry_new_const_ICPOdometry(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_Detail_PoseGraph_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_Detail_PoseGraph_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_Detail_PoseGraph_delete(instance: *mut c_void);
+		pub fn cv_PtrOfKinfu_Detail_PoseGraph_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_KinFu_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_KinFu_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_KinFu_delete(instance: *mut c_void);
+		pub fn cv_PtrOfKinfu_KinFu_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_Params_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_Params_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_Params_delete(instance: *mut c_void);
+		pub fn cv_PtrOfKinfu_Params_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_Params_new_const_Params(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_Volume_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_Volume_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_Volume_delete(instance: *mut c_void);
+		pub fn cv_PtrOfKinfu_Volume_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_VolumeParams_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_VolumeParams_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_VolumeParams_delete(instance: *mut c_void);
+		pub fn cv_PtrOfKinfu_VolumeParams_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKinfu_VolumeParams_new_const_VolumeParams(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLargeKinfu_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLargeKinfu_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLargeKinfu_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLargeKinfu_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_ColorGradient_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_ColorGradient_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_ColorGradient_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLineMod_ColorGradient_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_ColorGradient_new_const_ColorGradient(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_DepthNormal_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_DepthNormal_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_DepthNormal_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLineMod_DepthNormal_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_DepthNormal_new_const_DepthNormal(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_Detector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_Detector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_Detector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLineMod_Detector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_Detector_new_const_Detector(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_Modality_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_Modality_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_Modality_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLineMod_Modality_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_QuantizedPyramid_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_QuantizedPyramid_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLineMod_QuantizedPyramid_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLineMod_QuantizedPyramid_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOdometry_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOdometry_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOdometry_delete(instance: *mut c_void);
+		pub fn cv_PtrOfOdometry_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOdometryFrame_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOdometryFrame_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOdometryFrame_delete(instance: *mut c_void);
+		pub fn cv_PtrOfOdometryFrame_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOdometryFrame_new_const_OdometryFrame(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfParams_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfParams_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfParams_delete(instance: *mut c_void);
+		pub fn cv_PtrOfParams_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfParams_new_const_Params(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdFrame_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdFrame_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdFrame_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRgbdFrame_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdFrame_new_const_RgbdFrame(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdICPOdometry_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdICPOdometry_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdICPOdometry_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRgbdICPOdometry_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdICPOdometry_new_const_RgbdICPOdometry(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdNormals_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdNormals_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdNormals_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRgbdNormals_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdNormals_new_const_RgbdNormals(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdOdometry_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdOdometry_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdOdometry_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRgbdOdometry_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdOdometry_new_const_RgbdOdometry(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdPlane_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdPlane_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdPlane_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRgbdPlane_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRgbdPlane_new_const_RgbdPlane(val: *mut c_void) -> *mut c_void;
 		pub fn cv_VectorOfLineMod_Feature_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_VectorOfLineMod_Feature_fromSlice_const_const_FeatureX_size_t(data: *const crate::rgbd::LineMod_Feature, len: size_t) -> *mut c_void;
@@ -7829,18 +8124,22 @@ mod saliency_sys {
 		pub fn cv_PtrOfMotionSaliencyBinWangApr2014_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionSaliencyBinWangApr2014_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionSaliencyBinWangApr2014_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMotionSaliencyBinWangApr2014_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionSaliencyBinWangApr2014_new_const_MotionSaliencyBinWangApr2014(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfObjectnessBING_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfObjectnessBING_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfObjectnessBING_delete(instance: *mut c_void);
+		pub fn cv_PtrOfObjectnessBING_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfObjectnessBING_new_const_ObjectnessBING(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStaticSaliencyFineGrained_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStaticSaliencyFineGrained_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStaticSaliencyFineGrained_delete(instance: *mut c_void);
+		pub fn cv_PtrOfStaticSaliencyFineGrained_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStaticSaliencyFineGrained_new_const_StaticSaliencyFineGrained(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStaticSaliencySpectralResidual_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStaticSaliencySpectralResidual_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStaticSaliencySpectralResidual_delete(instance: *mut c_void);
+		pub fn cv_PtrOfStaticSaliencySpectralResidual_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStaticSaliencySpectralResidual_new_const_StaticSaliencySpectralResidual(val: *mut c_void) -> *mut c_void;
 	}
 }
@@ -7909,6 +8208,7 @@ mod sfm_sys {
 		pub fn cv_PtrOfSFMLibmvEuclideanReconstruction_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSFMLibmvEuclideanReconstruction_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSFMLibmvEuclideanReconstruction_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSFMLibmvEuclideanReconstruction_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_sfm)]
@@ -7978,39 +8278,55 @@ mod shape_sys {
 		pub fn cv_PtrOfAffineTransformer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAffineTransformer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAffineTransformer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAffineTransformer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAffineTransformer_to_PtrOfShapeTransformer(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfShapeTransformer_to_PtrOfAffineTransformer(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfChiHistogramCostExtractor_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfChiHistogramCostExtractor_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfChiHistogramCostExtractor_delete(instance: *mut c_void);
+		pub fn cv_PtrOfChiHistogramCostExtractor_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfChiHistogramCostExtractor_to_PtrOfHistogramCostExtractor(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfHistogramCostExtractor_to_PtrOfChiHistogramCostExtractor(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfEMDHistogramCostExtractor_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEMDHistogramCostExtractor_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfEMDHistogramCostExtractor_delete(instance: *mut c_void);
+		pub fn cv_PtrOfEMDHistogramCostExtractor_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEMDHistogramCostExtractor_to_PtrOfHistogramCostExtractor(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfHistogramCostExtractor_to_PtrOfEMDHistogramCostExtractor(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfEMDL1HistogramCostExtractor_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEMDL1HistogramCostExtractor_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfEMDL1HistogramCostExtractor_delete(instance: *mut c_void);
+		pub fn cv_PtrOfEMDL1HistogramCostExtractor_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEMDL1HistogramCostExtractor_to_PtrOfHistogramCostExtractor(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfHistogramCostExtractor_to_PtrOfEMDL1HistogramCostExtractor(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfHausdorffDistanceExtractor_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfHausdorffDistanceExtractor_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfHausdorffDistanceExtractor_delete(instance: *mut c_void);
+		pub fn cv_PtrOfHausdorffDistanceExtractor_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfHistogramCostExtractor_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfHistogramCostExtractor_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfHistogramCostExtractor_delete(instance: *mut c_void);
+		pub fn cv_PtrOfHistogramCostExtractor_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNormHistogramCostExtractor_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNormHistogramCostExtractor_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNormHistogramCostExtractor_delete(instance: *mut c_void);
+		pub fn cv_PtrOfNormHistogramCostExtractor_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNormHistogramCostExtractor_to_PtrOfHistogramCostExtractor(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfHistogramCostExtractor_to_PtrOfNormHistogramCostExtractor(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfShapeContextDistanceExtractor_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfShapeContextDistanceExtractor_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfShapeContextDistanceExtractor_delete(instance: *mut c_void);
+		pub fn cv_PtrOfShapeContextDistanceExtractor_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfShapeTransformer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfShapeTransformer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfShapeTransformer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfShapeTransformer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfThinPlateSplineShapeTransformer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfThinPlateSplineShapeTransformer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfThinPlateSplineShapeTransformer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfThinPlateSplineShapeTransformer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfThinPlateSplineShapeTransformer_to_PtrOfShapeTransformer(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfShapeTransformer_to_PtrOfThinPlateSplineShapeTransformer(instance: *mut c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_shape)]
@@ -8044,6 +8360,7 @@ mod stereo_sys {
 		pub fn cv_PtrOfQuasiDenseStereo_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfQuasiDenseStereo_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfQuasiDenseStereo_delete(instance: *mut c_void);
+		pub fn cv_PtrOfQuasiDenseStereo_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_VectorOfMatchQuasiDense_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_VectorOfMatchQuasiDense_fromSlice_const_const_MatchQuasiDenseX_size_t(data: *const crate::stereo::MatchQuasiDense, len: size_t) -> *mut c_void;
 	}
@@ -8451,237 +8768,336 @@ mod stitching_sys {
 		pub fn cv_PtrOfAffineWarper_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAffineWarper_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAffineWarper_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAffineWarper_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAffineWarper_to_PtrOfWarperCreator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWarperCreator_to_PtrOfAffineWarper(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAffineWarper_new_const_AffineWarper(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCompressedRectilinearPortraitWarper_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCompressedRectilinearPortraitWarper_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCompressedRectilinearPortraitWarper_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCompressedRectilinearPortraitWarper_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCompressedRectilinearPortraitWarper_to_PtrOfWarperCreator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWarperCreator_to_PtrOfCompressedRectilinearPortraitWarper(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCompressedRectilinearPortraitWarper_new_const_CompressedRectilinearPortraitWarper(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCompressedRectilinearWarper_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCompressedRectilinearWarper_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCompressedRectilinearWarper_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCompressedRectilinearWarper_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCompressedRectilinearWarper_to_PtrOfWarperCreator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWarperCreator_to_PtrOfCompressedRectilinearWarper(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCompressedRectilinearWarper_new_const_CompressedRectilinearWarper(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCylindricalWarper_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCylindricalWarper_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCylindricalWarper_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCylindricalWarper_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCylindricalWarper_to_PtrOfWarperCreator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWarperCreator_to_PtrOfCylindricalWarper(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCylindricalWarper_new_const_CylindricalWarper(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCylindricalWarperGpu_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCylindricalWarperGpu_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCylindricalWarperGpu_delete(instance: *mut c_void);
+		pub fn cv_PtrOfCylindricalWarperGpu_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfCylindricalWarperGpu_to_PtrOfWarperCreator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWarperCreator_to_PtrOfCylindricalWarperGpu(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfCylindricalWarperGpu_new_const_CylindricalWarperGpu(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_AffineBasedEstimator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_AffineBasedEstimator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_AffineBasedEstimator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_AffineBasedEstimator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_AffineBasedEstimator_to_PtrOfDetail_Estimator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_Estimator_to_PtrOfDetail_AffineBasedEstimator(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_AffineBasedEstimator_new_const_AffineBasedEstimator(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_AffineBestOf2NearestMatcher_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_AffineBestOf2NearestMatcher_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_AffineBestOf2NearestMatcher_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_AffineBestOf2NearestMatcher_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_AffineBestOf2NearestMatcher_to_PtrOfDetail_BestOf2NearestMatcher(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_BestOf2NearestMatcher_to_PtrOfDetail_AffineBestOf2NearestMatcher(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_AffineBestOf2NearestMatcher_to_PtrOfDetail_FeaturesMatcher(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_FeaturesMatcher_to_PtrOfDetail_AffineBestOf2NearestMatcher(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_AffineBestOf2NearestMatcher_new_const_AffineBestOf2NearestMatcher(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BestOf2NearestMatcher_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BestOf2NearestMatcher_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BestOf2NearestMatcher_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_BestOf2NearestMatcher_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BestOf2NearestMatcher_to_PtrOfDetail_FeaturesMatcher(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_FeaturesMatcher_to_PtrOfDetail_BestOf2NearestMatcher(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BestOf2NearestMatcher_new_const_BestOf2NearestMatcher(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BestOf2NearestRangeMatcher_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BestOf2NearestRangeMatcher_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BestOf2NearestRangeMatcher_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_BestOf2NearestRangeMatcher_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BestOf2NearestRangeMatcher_to_PtrOfDetail_BestOf2NearestMatcher(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_BestOf2NearestMatcher_to_PtrOfDetail_BestOf2NearestRangeMatcher(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BestOf2NearestRangeMatcher_to_PtrOfDetail_FeaturesMatcher(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_FeaturesMatcher_to_PtrOfDetail_BestOf2NearestRangeMatcher(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BestOf2NearestRangeMatcher_new_const_BestOf2NearestRangeMatcher(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_Blender_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_Blender_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_Blender_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_Blender_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_Blender_new_const_Blender(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksChannelsCompensator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksChannelsCompensator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksChannelsCompensator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_BlocksChannelsCompensator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksChannelsCompensator_to_PtrOfDetail_BlocksCompensator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_BlocksCompensator_to_PtrOfDetail_BlocksChannelsCompensator(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksChannelsCompensator_to_PtrOfDetail_ExposureCompensator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_ExposureCompensator_to_PtrOfDetail_BlocksChannelsCompensator(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksChannelsCompensator_new_const_BlocksChannelsCompensator(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksCompensator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksCompensator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksCompensator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_BlocksCompensator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksCompensator_to_PtrOfDetail_ExposureCompensator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_ExposureCompensator_to_PtrOfDetail_BlocksCompensator(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksGainCompensator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksGainCompensator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksGainCompensator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_BlocksGainCompensator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksGainCompensator_to_PtrOfDetail_BlocksCompensator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_BlocksCompensator_to_PtrOfDetail_BlocksGainCompensator(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksGainCompensator_to_PtrOfDetail_ExposureCompensator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_ExposureCompensator_to_PtrOfDetail_BlocksGainCompensator(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BlocksGainCompensator_new_const_BlocksGainCompensator(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterAffine_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterAffine_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterAffine_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_BundleAdjusterAffine_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterAffine_to_PtrOfDetail_BundleAdjusterBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_BundleAdjusterBase_to_PtrOfDetail_BundleAdjusterAffine(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterAffine_to_PtrOfDetail_Estimator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_Estimator_to_PtrOfDetail_BundleAdjusterAffine(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterAffine_new_const_BundleAdjusterAffine(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterAffinePartial_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterAffinePartial_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterAffinePartial_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_BundleAdjusterAffinePartial_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterAffinePartial_to_PtrOfDetail_BundleAdjusterBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_BundleAdjusterBase_to_PtrOfDetail_BundleAdjusterAffinePartial(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterAffinePartial_to_PtrOfDetail_Estimator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_Estimator_to_PtrOfDetail_BundleAdjusterAffinePartial(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterAffinePartial_new_const_BundleAdjusterAffinePartial(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterBase_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterBase_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterBase_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_BundleAdjusterBase_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterBase_to_PtrOfDetail_Estimator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_Estimator_to_PtrOfDetail_BundleAdjusterBase(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterRay_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterRay_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterRay_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_BundleAdjusterRay_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterRay_to_PtrOfDetail_BundleAdjusterBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_BundleAdjusterBase_to_PtrOfDetail_BundleAdjusterRay(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterRay_to_PtrOfDetail_Estimator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_Estimator_to_PtrOfDetail_BundleAdjusterRay(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterRay_new_const_BundleAdjusterRay(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterReproj_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterReproj_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterReproj_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_BundleAdjusterReproj_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterReproj_to_PtrOfDetail_BundleAdjusterBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_BundleAdjusterBase_to_PtrOfDetail_BundleAdjusterReproj(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterReproj_to_PtrOfDetail_Estimator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_Estimator_to_PtrOfDetail_BundleAdjusterReproj(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_BundleAdjusterReproj_new_const_BundleAdjusterReproj(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_ChannelsCompensator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_ChannelsCompensator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_ChannelsCompensator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_ChannelsCompensator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_ChannelsCompensator_to_PtrOfDetail_ExposureCompensator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_ExposureCompensator_to_PtrOfDetail_ChannelsCompensator(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_ChannelsCompensator_new_const_ChannelsCompensator(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_DpSeamFinder_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_DpSeamFinder_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_DpSeamFinder_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_DpSeamFinder_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_DpSeamFinder_to_PtrOfDetail_SeamFinder(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_SeamFinder_to_PtrOfDetail_DpSeamFinder(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_DpSeamFinder_new_const_DpSeamFinder(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_Estimator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_Estimator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_Estimator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_Estimator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_ExposureCompensator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_ExposureCompensator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_ExposureCompensator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_ExposureCompensator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_FeatherBlender_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_FeatherBlender_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_FeatherBlender_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_FeatherBlender_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_FeatherBlender_to_PtrOfDetail_Blender(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_Blender_to_PtrOfDetail_FeatherBlender(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_FeatherBlender_new_const_FeatherBlender(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_FeaturesMatcher_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_FeaturesMatcher_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_FeaturesMatcher_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_FeaturesMatcher_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GainCompensator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GainCompensator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GainCompensator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_GainCompensator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GainCompensator_to_PtrOfDetail_ExposureCompensator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_ExposureCompensator_to_PtrOfDetail_GainCompensator(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GainCompensator_new_const_GainCompensator(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GraphCutSeamFinder_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GraphCutSeamFinder_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GraphCutSeamFinder_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_GraphCutSeamFinder_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GraphCutSeamFinder_to_PtrOfDetail_SeamFinder(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_SeamFinder_to_PtrOfDetail_GraphCutSeamFinder(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GraphCutSeamFinder_new_const_GraphCutSeamFinder(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GraphCutSeamFinderGpu_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GraphCutSeamFinderGpu_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GraphCutSeamFinderGpu_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_GraphCutSeamFinderGpu_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GraphCutSeamFinderGpu_to_PtrOfDetail_PairwiseSeamFinder(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_PairwiseSeamFinder_to_PtrOfDetail_GraphCutSeamFinderGpu(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GraphCutSeamFinderGpu_to_PtrOfDetail_SeamFinder(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_SeamFinder_to_PtrOfDetail_GraphCutSeamFinderGpu(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_GraphCutSeamFinderGpu_new_const_GraphCutSeamFinderGpu(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_HomographyBasedEstimator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_HomographyBasedEstimator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_HomographyBasedEstimator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_HomographyBasedEstimator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_HomographyBasedEstimator_to_PtrOfDetail_Estimator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_Estimator_to_PtrOfDetail_HomographyBasedEstimator(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_HomographyBasedEstimator_new_const_HomographyBasedEstimator(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_MultiBandBlender_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_MultiBandBlender_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_MultiBandBlender_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_MultiBandBlender_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_MultiBandBlender_to_PtrOfDetail_Blender(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_Blender_to_PtrOfDetail_MultiBandBlender(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_MultiBandBlender_new_const_MultiBandBlender(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoBundleAdjuster_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoBundleAdjuster_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoBundleAdjuster_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_NoBundleAdjuster_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoBundleAdjuster_to_PtrOfDetail_BundleAdjusterBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_BundleAdjusterBase_to_PtrOfDetail_NoBundleAdjuster(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoBundleAdjuster_to_PtrOfDetail_Estimator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_Estimator_to_PtrOfDetail_NoBundleAdjuster(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoBundleAdjuster_new_const_NoBundleAdjuster(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoExposureCompensator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoExposureCompensator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoExposureCompensator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_NoExposureCompensator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoExposureCompensator_to_PtrOfDetail_ExposureCompensator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_ExposureCompensator_to_PtrOfDetail_NoExposureCompensator(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoExposureCompensator_new_const_NoExposureCompensator(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoSeamFinder_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoSeamFinder_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoSeamFinder_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_NoSeamFinder_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoSeamFinder_to_PtrOfDetail_SeamFinder(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_SeamFinder_to_PtrOfDetail_NoSeamFinder(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_NoSeamFinder_new_const_NoSeamFinder(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_PairwiseSeamFinder_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_PairwiseSeamFinder_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_PairwiseSeamFinder_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_PairwiseSeamFinder_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_PairwiseSeamFinder_to_PtrOfDetail_SeamFinder(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_SeamFinder_to_PtrOfDetail_PairwiseSeamFinder(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_RotationWarper_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_RotationWarper_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_RotationWarper_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_RotationWarper_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_SeamFinder_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_SeamFinder_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_SeamFinder_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_SeamFinder_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_VoronoiSeamFinder_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_VoronoiSeamFinder_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_VoronoiSeamFinder_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDetail_VoronoiSeamFinder_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_VoronoiSeamFinder_to_PtrOfDetail_PairwiseSeamFinder(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_PairwiseSeamFinder_to_PtrOfDetail_VoronoiSeamFinder(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_VoronoiSeamFinder_to_PtrOfDetail_SeamFinder(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDetail_SeamFinder_to_PtrOfDetail_VoronoiSeamFinder(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDetail_VoronoiSeamFinder_new_const_VoronoiSeamFinder(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFisheyeWarper_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFisheyeWarper_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFisheyeWarper_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFisheyeWarper_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFisheyeWarper_to_PtrOfWarperCreator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWarperCreator_to_PtrOfFisheyeWarper(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFisheyeWarper_new_const_FisheyeWarper(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMercatorWarper_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMercatorWarper_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMercatorWarper_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMercatorWarper_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMercatorWarper_to_PtrOfWarperCreator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWarperCreator_to_PtrOfMercatorWarper(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMercatorWarper_new_const_MercatorWarper(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPaniniPortraitWarper_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPaniniPortraitWarper_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPaniniPortraitWarper_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPaniniPortraitWarper_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPaniniPortraitWarper_to_PtrOfWarperCreator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWarperCreator_to_PtrOfPaniniPortraitWarper(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPaniniPortraitWarper_new_const_PaniniPortraitWarper(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPaniniWarper_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPaniniWarper_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPaniniWarper_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPaniniWarper_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPaniniWarper_to_PtrOfWarperCreator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWarperCreator_to_PtrOfPaniniWarper(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPaniniWarper_new_const_PaniniWarper(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPlaneWarper_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPlaneWarper_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPlaneWarper_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPlaneWarper_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPlaneWarper_to_PtrOfWarperCreator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWarperCreator_to_PtrOfPlaneWarper(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPlaneWarper_new_const_PlaneWarper(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPlaneWarperGpu_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPlaneWarperGpu_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPlaneWarperGpu_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPlaneWarperGpu_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPlaneWarperGpu_to_PtrOfWarperCreator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWarperCreator_to_PtrOfPlaneWarperGpu(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPlaneWarperGpu_new_const_PlaneWarperGpu(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSphericalWarper_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSphericalWarper_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSphericalWarper_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSphericalWarper_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSphericalWarper_to_PtrOfWarperCreator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWarperCreator_to_PtrOfSphericalWarper(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSphericalWarper_new_const_SphericalWarper(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSphericalWarperGpu_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSphericalWarperGpu_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSphericalWarperGpu_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSphericalWarperGpu_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSphericalWarperGpu_to_PtrOfWarperCreator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWarperCreator_to_PtrOfSphericalWarperGpu(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSphericalWarperGpu_new_const_SphericalWarperGpu(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStereographicWarper_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStereographicWarper_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStereographicWarper_delete(instance: *mut c_void);
+		pub fn cv_PtrOfStereographicWarper_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStereographicWarper_to_PtrOfWarperCreator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWarperCreator_to_PtrOfStereographicWarper(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStereographicWarper_new_const_StereographicWarper(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStitcher_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStitcher_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStitcher_delete(instance: *mut c_void);
+		pub fn cv_PtrOfStitcher_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStitcher_new_const_Stitcher(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTransverseMercatorWarper_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTransverseMercatorWarper_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTransverseMercatorWarper_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTransverseMercatorWarper_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTransverseMercatorWarper_to_PtrOfWarperCreator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWarperCreator_to_PtrOfTransverseMercatorWarper(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTransverseMercatorWarper_new_const_TransverseMercatorWarper(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfWarperCreator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfWarperCreator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfWarperCreator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfWarperCreator_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_stitching)]
@@ -8733,12 +9149,15 @@ mod structured_light_sys {
 		pub fn cv_PtrOfGrayCodePattern_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGrayCodePattern_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGrayCodePattern_delete(instance: *mut c_void);
+		pub fn cv_PtrOfGrayCodePattern_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSinusoidalPattern_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSinusoidalPattern_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSinusoidalPattern_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSinusoidalPattern_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSinusoidalPattern_Params_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSinusoidalPattern_Params_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSinusoidalPattern_Params_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSinusoidalPattern_Params_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSinusoidalPattern_Params_new_const_Params(val: *mut c_void) -> *mut c_void;
 	}
 }
@@ -8841,24 +9260,31 @@ mod superres_sys {
 		pub fn cv_PtrOfSuperRes_BroxOpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_BroxOpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_BroxOpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSuperRes_BroxOpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_DenseOpticalFlowExt_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_DenseOpticalFlowExt_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_DenseOpticalFlowExt_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSuperRes_DenseOpticalFlowExt_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_DualTVL1OpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_DualTVL1OpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_DualTVL1OpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSuperRes_DualTVL1OpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_FarnebackOpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_FarnebackOpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_FarnebackOpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSuperRes_FarnebackOpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_FrameSource_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_FrameSource_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_FrameSource_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSuperRes_FrameSource_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_PyrLKOpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_PyrLKOpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_PyrLKOpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSuperRes_PyrLKOpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_SuperResolution_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_SuperResolution_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperRes_SuperResolution_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSuperRes_SuperResolution_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_superres)]
@@ -8919,10 +9345,12 @@ mod surface_matching_sys {
 		pub fn cv_PtrOfPose3D_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPose3D_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPose3D_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPose3D_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPose3D_new_const_Pose3D(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPoseCluster3D_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPoseCluster3D_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPoseCluster3D_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPoseCluster3D_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPoseCluster3D_new_const_PoseCluster3D(val: *mut c_void) -> *mut c_void;
 	}
 }
@@ -9036,34 +9464,43 @@ mod text_sys {
 		pub fn cv_PtrOfERFilter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfERFilter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfERFilter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfERFilter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfERFilter_Callback_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfERFilter_Callback_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfERFilter_Callback_delete(instance: *mut c_void);
+		pub fn cv_PtrOfERFilter_Callback_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRBeamSearchDecoder_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRBeamSearchDecoder_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRBeamSearchDecoder_delete(instance: *mut c_void);
+		pub fn cv_PtrOfOCRBeamSearchDecoder_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRBeamSearchDecoder_new_const_OCRBeamSearchDecoder(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRBeamSearchDecoder_ClassifierCallback_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRBeamSearchDecoder_ClassifierCallback_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRBeamSearchDecoder_ClassifierCallback_delete(instance: *mut c_void);
+		pub fn cv_PtrOfOCRBeamSearchDecoder_ClassifierCallback_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRBeamSearchDecoder_ClassifierCallback_new_const_ClassifierCallback(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRHMMDecoder_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRHMMDecoder_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRHMMDecoder_delete(instance: *mut c_void);
+		pub fn cv_PtrOfOCRHMMDecoder_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRHMMDecoder_new_const_OCRHMMDecoder(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRHMMDecoder_ClassifierCallback_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRHMMDecoder_ClassifierCallback_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRHMMDecoder_ClassifierCallback_delete(instance: *mut c_void);
+		pub fn cv_PtrOfOCRHMMDecoder_ClassifierCallback_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRHMMDecoder_ClassifierCallback_new_const_ClassifierCallback(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRHolisticWordRecognizer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRHolisticWordRecognizer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRHolisticWordRecognizer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfOCRHolisticWordRecognizer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRTesseract_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRTesseract_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOCRTesseract_delete(instance: *mut c_void);
+		pub fn cv_PtrOfOCRTesseract_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTextDetectorCNN_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTextDetectorCNN_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTextDetectorCNN_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTextDetectorCNN_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_text)]
@@ -9137,9 +9574,11 @@ mod tracking_sys {
 		pub fn cv_PtrOfTrackerCSRT_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerCSRT_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerCSRT_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTrackerCSRT_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerKCF_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerKCF_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerKCF_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTrackerKCF_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_tracking)]
@@ -9342,39 +9781,51 @@ mod video_sys {
 		pub fn cv_PtrOfBackgroundSubtractorKNN_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorKNN_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorKNN_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBackgroundSubtractorKNN_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorMOG2_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorMOG2_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBackgroundSubtractorMOG2_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBackgroundSubtractorMOG2_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDISOpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDISOpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDISOpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDISOpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDenseOpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDenseOpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDenseOpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDenseOpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFarnebackOpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFarnebackOpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFarnebackOpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFarnebackOpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparseOpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparseOpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparseOpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSparseOpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparsePyrLKOpticalFlow_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparsePyrLKOpticalFlow_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparsePyrLKOpticalFlow_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSparsePyrLKOpticalFlow_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerDaSiamRPN_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerDaSiamRPN_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerDaSiamRPN_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTrackerDaSiamRPN_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerGOTURN_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerGOTURN_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerGOTURN_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTrackerGOTURN_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerMIL_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerMIL_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerMIL_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTrackerMIL_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerNano_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerNano_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTrackerNano_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTrackerNano_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfVariationalRefinement_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfVariationalRefinement_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfVariationalRefinement_delete(instance: *mut c_void);
+		pub fn cv_PtrOfVariationalRefinement_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_video)]
@@ -9680,207 +10131,289 @@ mod videostab_sys {
 		pub fn cv_PtrOfColorAverageInpainter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfColorAverageInpainter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfColorAverageInpainter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfColorAverageInpainter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfColorAverageInpainter_to_PtrOfInpainterBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfInpainterBase_to_PtrOfColorAverageInpainter(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfColorAverageInpainter_new_const_ColorAverageInpainter(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfColorInpainter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfColorInpainter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfColorInpainter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfColorInpainter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfColorInpainter_to_PtrOfInpainterBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfInpainterBase_to_PtrOfColorInpainter(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfColorInpainter_new_const_ColorInpainter(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfConsistentMosaicInpainter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfConsistentMosaicInpainter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfConsistentMosaicInpainter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfConsistentMosaicInpainter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfConsistentMosaicInpainter_to_PtrOfInpainterBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfInpainterBase_to_PtrOfConsistentMosaicInpainter(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfConsistentMosaicInpainter_new_const_ConsistentMosaicInpainter(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDeblurerBase_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDeblurerBase_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDeblurerBase_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDeblurerBase_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDensePyrLkOptFlowEstimatorGpu_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDensePyrLkOptFlowEstimatorGpu_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDensePyrLkOptFlowEstimatorGpu_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDensePyrLkOptFlowEstimatorGpu_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDensePyrLkOptFlowEstimatorGpu_to_PtrOfIDenseOptFlowEstimator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfIDenseOptFlowEstimator_to_PtrOfDensePyrLkOptFlowEstimatorGpu(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDensePyrLkOptFlowEstimatorGpu_new_const_DensePyrLkOptFlowEstimatorGpu(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFromFileMotionReader_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFromFileMotionReader_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFromFileMotionReader_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFromFileMotionReader_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFromFileMotionReader_to_PtrOfImageMotionEstimatorBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfImageMotionEstimatorBase_to_PtrOfFromFileMotionReader(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFromFileMotionReader_new_const_FromFileMotionReader(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGaussianMotionFilter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGaussianMotionFilter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGaussianMotionFilter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfGaussianMotionFilter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGaussianMotionFilter_to_PtrOfIMotionStabilizer(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfIMotionStabilizer_to_PtrOfGaussianMotionFilter(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGaussianMotionFilter_to_PtrOfMotionFilterBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfMotionFilterBase_to_PtrOfGaussianMotionFilter(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGaussianMotionFilter_new_const_GaussianMotionFilter(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfIDenseOptFlowEstimator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfIDenseOptFlowEstimator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfIDenseOptFlowEstimator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfIDenseOptFlowEstimator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfIFrameSource_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfIFrameSource_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfIFrameSource_delete(instance: *mut c_void);
+		pub fn cv_PtrOfIFrameSource_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfILog_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfILog_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfILog_delete(instance: *mut c_void);
+		pub fn cv_PtrOfILog_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfIMotionStabilizer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfIMotionStabilizer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfIMotionStabilizer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfIMotionStabilizer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfIOutlierRejector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfIOutlierRejector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfIOutlierRejector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfIOutlierRejector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfISparseOptFlowEstimator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfISparseOptFlowEstimator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfISparseOptFlowEstimator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfISparseOptFlowEstimator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfImageMotionEstimatorBase_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfImageMotionEstimatorBase_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfImageMotionEstimatorBase_delete(instance: *mut c_void);
+		pub fn cv_PtrOfImageMotionEstimatorBase_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfInpainterBase_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfInpainterBase_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfInpainterBase_delete(instance: *mut c_void);
+		pub fn cv_PtrOfInpainterBase_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfInpaintingPipeline_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfInpaintingPipeline_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfInpaintingPipeline_delete(instance: *mut c_void);
+		pub fn cv_PtrOfInpaintingPipeline_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfInpaintingPipeline_to_PtrOfInpainterBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfInpainterBase_to_PtrOfInpaintingPipeline(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfInpaintingPipeline_new_const_InpaintingPipeline(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKeypointBasedMotionEstimator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKeypointBasedMotionEstimator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKeypointBasedMotionEstimator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfKeypointBasedMotionEstimator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKeypointBasedMotionEstimator_to_PtrOfImageMotionEstimatorBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfImageMotionEstimatorBase_to_PtrOfKeypointBasedMotionEstimator(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKeypointBasedMotionEstimator_new_const_KeypointBasedMotionEstimator(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKeypointBasedMotionEstimatorGpu_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKeypointBasedMotionEstimatorGpu_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKeypointBasedMotionEstimatorGpu_delete(instance: *mut c_void);
+		pub fn cv_PtrOfKeypointBasedMotionEstimatorGpu_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfKeypointBasedMotionEstimatorGpu_to_PtrOfImageMotionEstimatorBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfImageMotionEstimatorBase_to_PtrOfKeypointBasedMotionEstimatorGpu(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfKeypointBasedMotionEstimatorGpu_new_const_KeypointBasedMotionEstimatorGpu(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLogToStdout_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLogToStdout_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLogToStdout_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLogToStdout_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLogToStdout_to_PtrOfILog(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfILog_to_PtrOfLogToStdout(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLogToStdout_new_const_LogToStdout(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLpMotionStabilizer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLpMotionStabilizer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLpMotionStabilizer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLpMotionStabilizer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLpMotionStabilizer_to_PtrOfIMotionStabilizer(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfIMotionStabilizer_to_PtrOfLpMotionStabilizer(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLpMotionStabilizer_new_const_LpMotionStabilizer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMaskFrameSource_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMaskFrameSource_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMaskFrameSource_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMaskFrameSource_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMaskFrameSource_to_PtrOfIFrameSource(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfIFrameSource_to_PtrOfMaskFrameSource(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMaskFrameSource_new_const_MaskFrameSource(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressor_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressor_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressor_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressor_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressor_to_PtrOfMoreAccurateMotionWobbleSuppressorBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_to_PtrOfMoreAccurateMotionWobbleSuppressor(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressor_to_PtrOfWobbleSuppressorBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWobbleSuppressorBase_to_PtrOfMoreAccurateMotionWobbleSuppressor(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressor_new_const_MoreAccurateMotionWobbleSuppressor(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_to_PtrOfWobbleSuppressorBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWobbleSuppressorBase_to_PtrOfMoreAccurateMotionWobbleSuppressorBase(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_to_PtrOfMoreAccurateMotionWobbleSuppressorBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_to_PtrOfMoreAccurateMotionWobbleSuppressorGpu(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_to_PtrOfWobbleSuppressorBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWobbleSuppressorBase_to_PtrOfMoreAccurateMotionWobbleSuppressorGpu(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_new_const_MoreAccurateMotionWobbleSuppressorGpu(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionEstimatorBase_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionEstimatorBase_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionEstimatorBase_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMotionEstimatorBase_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionEstimatorL1_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionEstimatorL1_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionEstimatorL1_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMotionEstimatorL1_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionEstimatorL1_to_PtrOfMotionEstimatorBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfMotionEstimatorBase_to_PtrOfMotionEstimatorL1(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionEstimatorL1_new_const_MotionEstimatorL1(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionEstimatorRansacL2_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionEstimatorRansacL2_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionEstimatorRansacL2_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMotionEstimatorRansacL2_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionEstimatorRansacL2_to_PtrOfMotionEstimatorBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfMotionEstimatorBase_to_PtrOfMotionEstimatorRansacL2(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionEstimatorRansacL2_new_const_MotionEstimatorRansacL2(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionFilterBase_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionFilterBase_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionFilterBase_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMotionFilterBase_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionFilterBase_to_PtrOfIMotionStabilizer(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfIMotionStabilizer_to_PtrOfMotionFilterBase(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionInpainter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionInpainter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionInpainter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMotionInpainter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionInpainter_to_PtrOfInpainterBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfInpainterBase_to_PtrOfMotionInpainter(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionInpainter_new_const_MotionInpainter(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionStabilizationPipeline_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionStabilizationPipeline_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionStabilizationPipeline_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMotionStabilizationPipeline_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionStabilizationPipeline_to_PtrOfIMotionStabilizer(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfIMotionStabilizer_to_PtrOfMotionStabilizationPipeline(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMotionStabilizationPipeline_new_const_MotionStabilizationPipeline(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullDeblurer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullDeblurer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullDeblurer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfNullDeblurer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullDeblurer_to_PtrOfDeblurerBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDeblurerBase_to_PtrOfNullDeblurer(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullDeblurer_new_const_NullDeblurer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullFrameSource_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullFrameSource_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullFrameSource_delete(instance: *mut c_void);
+		pub fn cv_PtrOfNullFrameSource_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullFrameSource_to_PtrOfIFrameSource(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfIFrameSource_to_PtrOfNullFrameSource(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullFrameSource_new_const_NullFrameSource(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullInpainter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullInpainter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullInpainter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfNullInpainter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullInpainter_to_PtrOfInpainterBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfInpainterBase_to_PtrOfNullInpainter(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullInpainter_new_const_NullInpainter(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullLog_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullLog_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullLog_delete(instance: *mut c_void);
+		pub fn cv_PtrOfNullLog_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullLog_to_PtrOfILog(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfILog_to_PtrOfNullLog(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullLog_new_const_NullLog(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullOutlierRejector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullOutlierRejector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullOutlierRejector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfNullOutlierRejector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullOutlierRejector_to_PtrOfIOutlierRejector(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfIOutlierRejector_to_PtrOfNullOutlierRejector(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullOutlierRejector_new_const_NullOutlierRejector(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullWobbleSuppressor_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullWobbleSuppressor_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullWobbleSuppressor_delete(instance: *mut c_void);
+		pub fn cv_PtrOfNullWobbleSuppressor_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullWobbleSuppressor_to_PtrOfWobbleSuppressorBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfWobbleSuppressorBase_to_PtrOfNullWobbleSuppressor(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfNullWobbleSuppressor_new_const_NullWobbleSuppressor(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOnePassStabilizer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOnePassStabilizer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOnePassStabilizer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfOnePassStabilizer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfOnePassStabilizer_to_PtrOfIFrameSource(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfIFrameSource_to_PtrOfOnePassStabilizer(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfOnePassStabilizer_new_const_OnePassStabilizer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparsePyrLkOptFlowEstimator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparsePyrLkOptFlowEstimator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparsePyrLkOptFlowEstimator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSparsePyrLkOptFlowEstimator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparsePyrLkOptFlowEstimator_to_PtrOfISparseOptFlowEstimator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfISparseOptFlowEstimator_to_PtrOfSparsePyrLkOptFlowEstimator(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparsePyrLkOptFlowEstimator_new_const_SparsePyrLkOptFlowEstimator(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparsePyrLkOptFlowEstimatorGpu_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparsePyrLkOptFlowEstimatorGpu_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparsePyrLkOptFlowEstimatorGpu_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSparsePyrLkOptFlowEstimatorGpu_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparsePyrLkOptFlowEstimatorGpu_to_PtrOfISparseOptFlowEstimator(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfISparseOptFlowEstimator_to_PtrOfSparsePyrLkOptFlowEstimatorGpu(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSparsePyrLkOptFlowEstimatorGpu_new_const_SparsePyrLkOptFlowEstimatorGpu(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfToFileMotionWriter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfToFileMotionWriter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfToFileMotionWriter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfToFileMotionWriter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfToFileMotionWriter_to_PtrOfImageMotionEstimatorBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfImageMotionEstimatorBase_to_PtrOfToFileMotionWriter(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfToFileMotionWriter_new_const_ToFileMotionWriter(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTranslationBasedLocalOutlierRejector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTranslationBasedLocalOutlierRejector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTranslationBasedLocalOutlierRejector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTranslationBasedLocalOutlierRejector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTranslationBasedLocalOutlierRejector_to_PtrOfIOutlierRejector(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfIOutlierRejector_to_PtrOfTranslationBasedLocalOutlierRejector(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTranslationBasedLocalOutlierRejector_new_const_TranslationBasedLocalOutlierRejector(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTwoPassStabilizer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTwoPassStabilizer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTwoPassStabilizer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTwoPassStabilizer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTwoPassStabilizer_to_PtrOfIFrameSource(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfIFrameSource_to_PtrOfTwoPassStabilizer(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTwoPassStabilizer_new_const_TwoPassStabilizer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfVideoFileSource_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfVideoFileSource_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfVideoFileSource_delete(instance: *mut c_void);
+		pub fn cv_PtrOfVideoFileSource_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfVideoFileSource_to_PtrOfIFrameSource(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfIFrameSource_to_PtrOfVideoFileSource(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfVideoFileSource_new_const_VideoFileSource(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfWeightingDeblurer_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfWeightingDeblurer_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfWeightingDeblurer_delete(instance: *mut c_void);
+		pub fn cv_PtrOfWeightingDeblurer_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfWeightingDeblurer_to_PtrOfDeblurerBase(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfDeblurerBase_to_PtrOfWeightingDeblurer(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfWeightingDeblurer_new_const_WeightingDeblurer(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfWobbleSuppressorBase_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfWobbleSuppressorBase_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfWobbleSuppressorBase_delete(instance: *mut c_void);
+		pub fn cv_PtrOfWobbleSuppressorBase_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_videostab)]
@@ -10377,74 +10910,107 @@ mod xfeatures2d_sys {
 		pub fn cv_PtrOfAffineFeature2D_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAffineFeature2D_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAffineFeature2D_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAffineFeature2D_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAffineFeature2D_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfAffineFeature2D(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBEBLID_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBEBLID_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBEBLID_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBEBLID_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBEBLID_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfBEBLID(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBoostDesc_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBoostDesc_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBoostDesc_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBoostDesc_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBoostDesc_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfBoostDesc(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBriefDescriptorExtractor_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBriefDescriptorExtractor_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfBriefDescriptorExtractor_delete(instance: *mut c_void);
+		pub fn cv_PtrOfBriefDescriptorExtractor_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfBriefDescriptorExtractor_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfBriefDescriptorExtractor(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDAISY_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDAISY_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDAISY_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDAISY_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDAISY_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfDAISY(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFREAK_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFREAK_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFREAK_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFREAK_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFREAK_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfFREAK(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfHarrisLaplaceFeatureDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfHarrisLaplaceFeatureDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfHarrisLaplaceFeatureDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfHarrisLaplaceFeatureDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfHarrisLaplaceFeatureDetector_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfHarrisLaplaceFeatureDetector(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLATCH_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLATCH_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLATCH_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLATCH_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLATCH_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfLATCH(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLUCID_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLUCID_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLUCID_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLUCID_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLUCID_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfLUCID(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMSDDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMSDDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfMSDDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfMSDDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfMSDDetector_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfMSDDetector(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPCTSignatures_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPCTSignatures_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPCTSignatures_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPCTSignatures_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPCTSignaturesSQFD_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfPCTSignaturesSQFD_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfPCTSignaturesSQFD_delete(instance: *mut c_void);
+		pub fn cv_PtrOfPCTSignaturesSQFD_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSURF_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSURF_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSURF_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSURF_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSURF_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfSURF(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSURF_CUDA_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSURF_CUDA_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSURF_CUDA_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSURF_CUDA_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSURF_CUDA_new_const_SURF_CUDA(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStarDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStarDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStarDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfStarDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStarDetector_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfStarDetector(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTBMR_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTBMR_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTBMR_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTBMR_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTBMR_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfTBMR(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTEBLID_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTEBLID_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTEBLID_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTEBLID_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTEBLID_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfTEBLID(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTEBLID_new_const_TEBLID(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfVGG_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfVGG_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfVGG_delete(instance: *mut c_void);
+		pub fn cv_PtrOfVGG_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfVGG_to_PtrOfFeature2D(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfFeature2D_to_PtrOfVGG(instance: *mut c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_xfeatures2d)]
@@ -10701,90 +11267,122 @@ mod ximgproc_sys {
 		pub fn cv_PtrOfAdaptiveManifoldFilter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfAdaptiveManifoldFilter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfAdaptiveManifoldFilter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfAdaptiveManifoldFilter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfContourFitting_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfContourFitting_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfContourFitting_delete(instance: *mut c_void);
+		pub fn cv_PtrOfContourFitting_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfContourFitting_new_const_ContourFitting(val: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDTFilter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDTFilter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDTFilter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDTFilter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDisparityWLSFilter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfDisparityWLSFilter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfDisparityWLSFilter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfDisparityWLSFilter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEdgeAwareInterpolator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEdgeAwareInterpolator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfEdgeAwareInterpolator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfEdgeAwareInterpolator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEdgeBoxes_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEdgeBoxes_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfEdgeBoxes_delete(instance: *mut c_void);
+		pub fn cv_PtrOfEdgeBoxes_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEdgeDrawing_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfEdgeDrawing_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfEdgeDrawing_delete(instance: *mut c_void);
+		pub fn cv_PtrOfEdgeDrawing_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastBilateralSolverFilter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastBilateralSolverFilter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastBilateralSolverFilter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFastBilateralSolverFilter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastGlobalSmootherFilter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastGlobalSmootherFilter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastGlobalSmootherFilter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFastGlobalSmootherFilter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastLineDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastLineDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfFastLineDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfFastLineDetector_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGraphSegmentation_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGraphSegmentation_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGraphSegmentation_delete(instance: *mut c_void);
+		pub fn cv_PtrOfGraphSegmentation_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGuidedFilter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGuidedFilter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGuidedFilter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfGuidedFilter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRFFeatureGetter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRFFeatureGetter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRFFeatureGetter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRFFeatureGetter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRICInterpolator_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRICInterpolator_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRICInterpolator_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRICInterpolator_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRidgeDetectionFilter_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfRidgeDetectionFilter_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfRidgeDetectionFilter_delete(instance: *mut c_void);
+		pub fn cv_PtrOfRidgeDetectionFilter_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfScanSegment_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfScanSegment_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfScanSegment_delete(instance: *mut c_void);
+		pub fn cv_PtrOfScanSegment_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentation_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentation_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentation_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSelectiveSearchSegmentation_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategy_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategy_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategy_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSelectiveSearchSegmentationStrategy_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyColor_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyColor_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyColor_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyColor_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyColor_to_PtrOfSelectiveSearchSegmentationStrategy(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfSelectiveSearchSegmentationStrategy_to_PtrOfSelectiveSearchSegmentationStrategyColor(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyFill_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyFill_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyFill_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyFill_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyFill_to_PtrOfSelectiveSearchSegmentationStrategy(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfSelectiveSearchSegmentationStrategy_to_PtrOfSelectiveSearchSegmentationStrategyFill(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyMultiple_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyMultiple_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyMultiple_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyMultiple_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyMultiple_to_PtrOfSelectiveSearchSegmentationStrategy(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfSelectiveSearchSegmentationStrategy_to_PtrOfSelectiveSearchSegmentationStrategyMultiple(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategySize_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategySize_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategySize_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSelectiveSearchSegmentationStrategySize_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategySize_to_PtrOfSelectiveSearchSegmentationStrategy(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfSelectiveSearchSegmentationStrategy_to_PtrOfSelectiveSearchSegmentationStrategySize(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyTexture_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyTexture_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyTexture_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyTexture_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSelectiveSearchSegmentationStrategyTexture_to_PtrOfSelectiveSearchSegmentationStrategy(instance: *mut c_void) -> *mut c_void;
+		pub fn cv_PtrOfSelectiveSearchSegmentationStrategy_to_PtrOfSelectiveSearchSegmentationStrategyTexture(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStructuredEdgeDetection_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfStructuredEdgeDetection_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfStructuredEdgeDetection_delete(instance: *mut c_void);
+		pub fn cv_PtrOfStructuredEdgeDetection_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperpixelLSC_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperpixelLSC_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperpixelLSC_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSuperpixelLSC_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperpixelSEEDS_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperpixelSEEDS_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperpixelSEEDS_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSuperpixelSEEDS_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperpixelSLIC_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperpixelSLIC_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSuperpixelSLIC_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSuperpixelSLIC_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_VectorOfBox_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_VectorOfBox_fromSlice_const_const_BoxX_size_t(data: *const crate::ximgproc::Box, len: size_t) -> *mut c_void;
 	}
@@ -10805,6 +11403,7 @@ mod xobjdetect_sys {
 		pub fn cv_PtrOfWBDetector_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfWBDetector_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfWBDetector_delete(instance: *mut c_void);
+		pub fn cv_PtrOfWBDetector_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_xobjdetect)]
@@ -10857,15 +11456,19 @@ mod xphoto_sys {
 		pub fn cv_PtrOfGrayworldWB_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfGrayworldWB_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfGrayworldWB_delete(instance: *mut c_void);
+		pub fn cv_PtrOfGrayworldWB_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLearningBasedWB_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfLearningBasedWB_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfLearningBasedWB_delete(instance: *mut c_void);
+		pub fn cv_PtrOfLearningBasedWB_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSimpleWB_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfSimpleWB_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfSimpleWB_delete(instance: *mut c_void);
+		pub fn cv_PtrOfSimpleWB_clone_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTonemapDurand_getInnerPtr_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_PtrOfTonemapDurand_getInnerPtrMut(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_PtrOfTonemapDurand_delete(instance: *mut c_void);
+		pub fn cv_PtrOfTonemapDurand_clone_const(instance: *const c_void) -> *mut c_void;
 	}
 }
 #[cfg(ocvrs_has_module_xphoto)]
@@ -6,7 +6,7 @@ mod aruco_types {
 	pub type PtrOfEstimateParameters = core::Ptr<crate::aruco::EstimateParameters>;
 	
 	ptr_extern! { crate::aruco::EstimateParameters,
-		cv_PtrOfEstimateParameters_delete, cv_PtrOfEstimateParameters_getInnerPtr_const, cv_PtrOfEstimateParameters_getInnerPtrMut
+		cv_PtrOfEstimateParameters_delete, cv_PtrOfEstimateParameters_getInnerPtr_const, cv_PtrOfEstimateParameters_getInnerPtrMut, cv_PtrOfEstimateParameters_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::aruco::EstimateParameters, cv_PtrOfEstimateParameters_new_const_EstimateParameters }
@@ -45,7 +45,7 @@ mod bgsegm_types {
 	pub type PtrOfBackgroundSubtractorCNT = core::Ptr<crate::bgsegm::BackgroundSubtractorCNT>;
 	
 	ptr_extern! { crate::bgsegm::BackgroundSubtractorCNT,
-		cv_PtrOfBackgroundSubtractorCNT_delete, cv_PtrOfBackgroundSubtractorCNT_getInnerPtr_const, cv_PtrOfBackgroundSubtractorCNT_getInnerPtrMut
+		cv_PtrOfBackgroundSubtractorCNT_delete, cv_PtrOfBackgroundSubtractorCNT_getInnerPtr_const, cv_PtrOfBackgroundSubtractorCNT_getInnerPtrMut, cv_PtrOfBackgroundSubtractorCNT_clone_const
 	}
 	
 	impl core::Ptr<crate::bgsegm::BackgroundSubtractorCNT> {
@@ -88,7 +88,7 @@ mod bgsegm_types {
 	pub type PtrOfBackgroundSubtractorGMG = core::Ptr<crate::bgsegm::BackgroundSubtractorGMG>;
 	
 	ptr_extern! { crate::bgsegm::BackgroundSubtractorGMG,
-		cv_PtrOfBackgroundSubtractorGMG_delete, cv_PtrOfBackgroundSubtractorGMG_getInnerPtr_const, cv_PtrOfBackgroundSubtractorGMG_getInnerPtrMut
+		cv_PtrOfBackgroundSubtractorGMG_delete, cv_PtrOfBackgroundSubtractorGMG_getInnerPtr_const, cv_PtrOfBackgroundSubtractorGMG_getInnerPtrMut, cv_PtrOfBackgroundSubtractorGMG_clone_const
 	}
 	
 	impl core::Ptr<crate::bgsegm::BackgroundSubtractorGMG> {
@@ -131,7 +131,7 @@ mod bgsegm_types {
 	pub type PtrOfBackgroundSubtractorGSOC = core::Ptr<crate::bgsegm::BackgroundSubtractorGSOC>;
 	
 	ptr_extern! { crate::bgsegm::BackgroundSubtractorGSOC,
-		cv_PtrOfBackgroundSubtractorGSOC_delete, cv_PtrOfBackgroundSubtractorGSOC_getInnerPtr_const, cv_PtrOfBackgroundSubtractorGSOC_getInnerPtrMut
+		cv_PtrOfBackgroundSubtractorGSOC_delete, cv_PtrOfBackgroundSubtractorGSOC_getInnerPtr_const, cv_PtrOfBackgroundSubtractorGSOC_getInnerPtrMut, cv_PtrOfBackgroundSubtractorGSOC_clone_const
 	}
 	
 	impl core::Ptr<crate::bgsegm::BackgroundSubtractorGSOC> {
@@ -174,7 +174,7 @@ mod bgsegm_types {
 	pub type PtrOfBackgroundSubtractorLSBP = core::Ptr<crate::bgsegm::BackgroundSubtractorLSBP>;
 	
 	ptr_extern! { crate::bgsegm::BackgroundSubtractorLSBP,
-		cv_PtrOfBackgroundSubtractorLSBP_delete, cv_PtrOfBackgroundSubtractorLSBP_getInnerPtr_const, cv_PtrOfBackgroundSubtractorLSBP_getInnerPtrMut
+		cv_PtrOfBackgroundSubtractorLSBP_delete, cv_PtrOfBackgroundSubtractorLSBP_getInnerPtr_const, cv_PtrOfBackgroundSubtractorLSBP_getInnerPtrMut, cv_PtrOfBackgroundSubtractorLSBP_clone_const
 	}
 	
 	impl core::Ptr<crate::bgsegm::BackgroundSubtractorLSBP> {
@@ -217,7 +217,7 @@ mod bgsegm_types {
 	pub type PtrOfBackgroundSubtractorMOG = core::Ptr<crate::bgsegm::BackgroundSubtractorMOG>;
 	
 	ptr_extern! { crate::bgsegm::BackgroundSubtractorMOG,
-		cv_PtrOfBackgroundSubtractorMOG_delete, cv_PtrOfBackgroundSubtractorMOG_getInnerPtr_const, cv_PtrOfBackgroundSubtractorMOG_getInnerPtrMut
+		cv_PtrOfBackgroundSubtractorMOG_delete, cv_PtrOfBackgroundSubtractorMOG_getInnerPtr_const, cv_PtrOfBackgroundSubtractorMOG_getInnerPtrMut, cv_PtrOfBackgroundSubtractorMOG_clone_const
 	}
 	
 	impl core::Ptr<crate::bgsegm::BackgroundSubtractorMOG> {
@@ -260,7 +260,7 @@ mod bgsegm_types {
 	pub type PtrOfSyntheticSequenceGenerator = core::Ptr<crate::bgsegm::SyntheticSequenceGenerator>;
 	
 	ptr_extern! { crate::bgsegm::SyntheticSequenceGenerator,
-		cv_PtrOfSyntheticSequenceGenerator_delete, cv_PtrOfSyntheticSequenceGenerator_getInnerPtr_const, cv_PtrOfSyntheticSequenceGenerator_getInnerPtrMut
+		cv_PtrOfSyntheticSequenceGenerator_delete, cv_PtrOfSyntheticSequenceGenerator_getInnerPtr_const, cv_PtrOfSyntheticSequenceGenerator_getInnerPtrMut, cv_PtrOfSyntheticSequenceGenerator_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::bgsegm::SyntheticSequenceGenerator, cv_PtrOfSyntheticSequenceGenerator_new_const_SyntheticSequenceGenerator }
@@ -304,7 +304,7 @@ mod bioinspired_types {
 	pub type PtrOfRetina = core::Ptr<crate::bioinspired::Retina>;
 	
 	ptr_extern! { crate::bioinspired::Retina,
-		cv_PtrOfRetina_delete, cv_PtrOfRetina_getInnerPtr_const, cv_PtrOfRetina_getInnerPtrMut
+		cv_PtrOfRetina_delete, cv_PtrOfRetina_getInnerPtr_const, cv_PtrOfRetina_getInnerPtrMut, cv_PtrOfRetina_clone_const
 	}
 	
 	impl core::Ptr<crate::bioinspired::Retina> {
@@ -339,7 +339,7 @@ mod bioinspired_types {
 	pub type PtrOfRetinaFastToneMapping = core::Ptr<crate::bioinspired::RetinaFastToneMapping>;
 	
 	ptr_extern! { crate::bioinspired::RetinaFastToneMapping,
-		cv_PtrOfRetinaFastToneMapping_delete, cv_PtrOfRetinaFastToneMapping_getInnerPtr_const, cv_PtrOfRetinaFastToneMapping_getInnerPtrMut
+		cv_PtrOfRetinaFastToneMapping_delete, cv_PtrOfRetinaFastToneMapping_getInnerPtr_const, cv_PtrOfRetinaFastToneMapping_getInnerPtrMut, cv_PtrOfRetinaFastToneMapping_clone_const
 	}
 	
 	impl core::Ptr<crate::bioinspired::RetinaFastToneMapping> {
@@ -374,7 +374,7 @@ mod bioinspired_types {
 	pub type PtrOfTransientAreasSegmentationModule = core::Ptr<crate::bioinspired::TransientAreasSegmentationModule>;
 	
 	ptr_extern! { crate::bioinspired::TransientAreasSegmentationModule,
-		cv_PtrOfTransientAreasSegmentationModule_delete, cv_PtrOfTransientAreasSegmentationModule_getInnerPtr_const, cv_PtrOfTransientAreasSegmentationModule_getInnerPtrMut
+		cv_PtrOfTransientAreasSegmentationModule_delete, cv_PtrOfTransientAreasSegmentationModule_getInnerPtr_const, cv_PtrOfTransientAreasSegmentationModule_getInnerPtrMut, cv_PtrOfTransientAreasSegmentationModule_clone_const
 	}
 	
 	impl core::Ptr<crate::bioinspired::TransientAreasSegmentationModule> {
@@ -417,7 +417,7 @@ mod calib3d_types {
 	pub type PtrOfLMSolver = core::Ptr<crate::calib3d::LMSolver>;
 	
 	ptr_extern! { crate::calib3d::LMSolver,
-		cv_PtrOfLMSolver_delete, cv_PtrOfLMSolver_getInnerPtr_const, cv_PtrOfLMSolver_getInnerPtrMut
+		cv_PtrOfLMSolver_delete, cv_PtrOfLMSolver_getInnerPtr_const, cv_PtrOfLMSolver_getInnerPtrMut, cv_PtrOfLMSolver_clone_const
 	}
 	
 	impl core::Ptr<crate::calib3d::LMSolver> {
@@ -452,7 +452,7 @@ mod calib3d_types {
 	pub type PtrOfLMSolver_Callback = core::Ptr<crate::calib3d::LMSolver_Callback>;
 	
 	ptr_extern! { crate::calib3d::LMSolver_Callback,
-		cv_PtrOfLMSolver_Callback_delete, cv_PtrOfLMSolver_Callback_getInnerPtr_const, cv_PtrOfLMSolver_Callback_getInnerPtrMut
+		cv_PtrOfLMSolver_Callback_delete, cv_PtrOfLMSolver_Callback_getInnerPtr_const, cv_PtrOfLMSolver_Callback_getInnerPtrMut, cv_PtrOfLMSolver_Callback_clone_const
 	}
 	
 	impl core::Ptr<crate::calib3d::LMSolver_Callback> {
@@ -479,7 +479,7 @@ mod calib3d_types {
 	pub type PtrOfStereoBM = core::Ptr<crate::calib3d::StereoBM>;
 	
 	ptr_extern! { crate::calib3d::StereoBM,
-		cv_PtrOfStereoBM_delete, cv_PtrOfStereoBM_getInnerPtr_const, cv_PtrOfStereoBM_getInnerPtrMut
+		cv_PtrOfStereoBM_delete, cv_PtrOfStereoBM_getInnerPtr_const, cv_PtrOfStereoBM_getInnerPtrMut, cv_PtrOfStereoBM_clone_const
 	}
 	
 	impl core::Ptr<crate::calib3d::StereoBM> {
@@ -522,7 +522,7 @@ mod calib3d_types {
 	pub type PtrOfStereoMatcher = core::Ptr<crate::calib3d::StereoMatcher>;
 	
 	ptr_extern! { crate::calib3d::StereoMatcher,
-		cv_PtrOfStereoMatcher_delete, cv_PtrOfStereoMatcher_getInnerPtr_const, cv_PtrOfStereoMatcher_getInnerPtrMut
+		cv_PtrOfStereoMatcher_delete, cv_PtrOfStereoMatcher_getInnerPtr_const, cv_PtrOfStereoMatcher_getInnerPtrMut, cv_PtrOfStereoMatcher_clone_const
 	}
 	
 	impl core::Ptr<crate::calib3d::StereoMatcher> {
@@ -557,7 +557,7 @@ mod calib3d_types {
 	pub type PtrOfStereoSGBM = core::Ptr<crate::calib3d::StereoSGBM>;
 	
 	ptr_extern! { crate::calib3d::StereoSGBM,
-		cv_PtrOfStereoSGBM_delete, cv_PtrOfStereoSGBM_getInnerPtr_const, cv_PtrOfStereoSGBM_getInnerPtrMut
+		cv_PtrOfStereoSGBM_delete, cv_PtrOfStereoSGBM_getInnerPtr_const, cv_PtrOfStereoSGBM_getInnerPtrMut, cv_PtrOfStereoSGBM_clone_const
 	}
 	
 	impl core::Ptr<crate::calib3d::StereoSGBM> {
@@ -608,7 +608,7 @@ mod core_types {
 	pub type PtrOfConjGradSolver = core::Ptr<core::ConjGradSolver>;
 	
 	ptr_extern! { core::ConjGradSolver,
-		cv_PtrOfConjGradSolver_delete, cv_PtrOfConjGradSolver_getInnerPtr_const, cv_PtrOfConjGradSolver_getInnerPtrMut
+		cv_PtrOfConjGradSolver_delete, cv_PtrOfConjGradSolver_getInnerPtr_const, cv_PtrOfConjGradSolver_getInnerPtrMut, cv_PtrOfConjGradSolver_clone_const
 	}
 	
 	impl core::Ptr<core::ConjGradSolver> {
@@ -651,7 +651,7 @@ mod core_types {
 	pub type PtrOfDownhillSolver = core::Ptr<core::DownhillSolver>;
 	
 	ptr_extern! { core::DownhillSolver,
-		cv_PtrOfDownhillSolver_delete, cv_PtrOfDownhillSolver_getInnerPtr_const, cv_PtrOfDownhillSolver_getInnerPtrMut
+		cv_PtrOfDownhillSolver_delete, cv_PtrOfDownhillSolver_getInnerPtr_const, cv_PtrOfDownhillSolver_getInnerPtrMut, cv_PtrOfDownhillSolver_clone_const
 	}
 	
 	impl core::Ptr<core::DownhillSolver> {
@@ -694,7 +694,7 @@ mod core_types {
 	pub type PtrOfFileStorage = core::Ptr<core::FileStorage>;
 	
 	ptr_extern! { core::FileStorage,
-		cv_PtrOfFileStorage_delete, cv_PtrOfFileStorage_getInnerPtr_const, cv_PtrOfFileStorage_getInnerPtrMut
+		cv_PtrOfFileStorage_delete, cv_PtrOfFileStorage_getInnerPtr_const, cv_PtrOfFileStorage_getInnerPtrMut, cv_PtrOfFileStorage_clone_const
 	}
 	
 	ptr_extern_ctor! { core::FileStorage, cv_PtrOfFileStorage_new_const_FileStorage }
@@ -724,7 +724,7 @@ mod core_types {
 	pub type PtrOfFormatted = core::Ptr<core::Formatted>;
 	
 	ptr_extern! { core::Formatted,
-		cv_PtrOfFormatted_delete, cv_PtrOfFormatted_getInnerPtr_const, cv_PtrOfFormatted_getInnerPtrMut
+		cv_PtrOfFormatted_delete, cv_PtrOfFormatted_getInnerPtr_const, cv_PtrOfFormatted_getInnerPtrMut, cv_PtrOfFormatted_clone_const
 	}
 	
 	impl core::Ptr<core::Formatted> {
@@ -751,7 +751,7 @@ mod core_types {
 	pub type PtrOfFormatter = core::Ptr<core::Formatter>;
 	
 	ptr_extern! { core::Formatter,
-		cv_PtrOfFormatter_delete, cv_PtrOfFormatter_getInnerPtr_const, cv_PtrOfFormatter_getInnerPtrMut
+		cv_PtrOfFormatter_delete, cv_PtrOfFormatter_getInnerPtr_const, cv_PtrOfFormatter_getInnerPtrMut, cv_PtrOfFormatter_clone_const
 	}
 	
 	impl core::Ptr<core::Formatter> {
@@ -778,7 +778,7 @@ mod core_types {
 	pub type PtrOfGpuMat_Allocator = core::Ptr<core::GpuMat_Allocator>;
 	
 	ptr_extern! { core::GpuMat_Allocator,
-		cv_PtrOfGpuMat_Allocator_delete, cv_PtrOfGpuMat_Allocator_getInnerPtr_const, cv_PtrOfGpuMat_Allocator_getInnerPtrMut
+		cv_PtrOfGpuMat_Allocator_delete, cv_PtrOfGpuMat_Allocator_getInnerPtr_const, cv_PtrOfGpuMat_Allocator_getInnerPtrMut, cv_PtrOfGpuMat_Allocator_clone_const
 	}
 	
 	impl core::Ptr<core::GpuMat_Allocator> {
@@ -805,7 +805,7 @@ mod core_types {
 	pub type PtrOfMinProblemSolver_Function = core::Ptr<core::MinProblemSolver_Function>;
 	
 	ptr_extern! { core::MinProblemSolver_Function,
-		cv_PtrOfMinProblemSolver_Function_delete, cv_PtrOfMinProblemSolver_Function_getInnerPtr_const, cv_PtrOfMinProblemSolver_Function_getInnerPtrMut
+		cv_PtrOfMinProblemSolver_Function_delete, cv_PtrOfMinProblemSolver_Function_getInnerPtr_const, cv_PtrOfMinProblemSolver_Function_getInnerPtrMut, cv_PtrOfMinProblemSolver_Function_clone_const
 	}
 	
 	impl core::Ptr<core::MinProblemSolver_Function> {
@@ -832,7 +832,7 @@ mod core_types {
 	pub type PtrOfOriginalClassName = core::Ptr<core::OriginalClassName>;
 	
 	ptr_extern! { core::OriginalClassName,
-		cv_PtrOfOriginalClassName_delete, cv_PtrOfOriginalClassName_getInnerPtr_const, cv_PtrOfOriginalClassName_getInnerPtrMut
+		cv_PtrOfOriginalClassName_delete, cv_PtrOfOriginalClassName_getInnerPtr_const, cv_PtrOfOriginalClassName_getInnerPtrMut, cv_PtrOfOriginalClassName_clone_const
 	}
 	
 	ptr_extern_ctor! { core::OriginalClassName, cv_PtrOfOriginalClassName_new_const_OriginalClassName }
@@ -857,10 +857,37 @@ mod core_types {
 		}
 	}
 	
+	pub type PtrOfParallelLoopBody = core::Ptr<core::ParallelLoopBody>;
+	
+	ptr_extern! { core::ParallelLoopBody,
+		cv_PtrOfParallelLoopBody_delete, cv_PtrOfParallelLoopBody_getInnerPtr_const, cv_PtrOfParallelLoopBody_getInnerPtrMut, cv_PtrOfParallelLoopBody_clone_const
+	}
+	
+	impl core::Ptr<core::ParallelLoopBody> {
+		#[inline] pub fn as_raw_PtrOfParallelLoopBody(&self) -> extern_send!(Self) { self.as_raw() }
+		#[inline] pub fn as_raw_mut_PtrOfParallelLoopBody(&mut self) -> extern_send!(mut Self) { self.as_raw_mut() }
+	}
+	
+	impl core::ParallelLoopBodyTraitConst for core::Ptr<core::ParallelLoopBody> {
+		#[inline] fn as_raw_ParallelLoopBody(&self) -> *const c_void { self.inner_as_raw() }
+	}
+	
+	impl core::ParallelLoopBodyTrait for core::Ptr<core::ParallelLoopBody> {
+		#[inline] fn as_raw_mut_ParallelLoopBody(&mut self) -> *mut c_void { self.inner_as_raw_mut() }
+	}
+	
+	impl std::fmt::Debug for core::Ptr<core::ParallelLoopBody> {
+		#[inline]
+		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+			f.debug_struct("PtrOfParallelLoopBody")
+				.finish()
+		}
+	}
+	
 	pub type PtrOff32 = core::Ptr<f32>;
 	
 	ptr_extern! { f32,
-		cv_PtrOff32_delete, cv_PtrOff32_getInnerPtr_const, cv_PtrOff32_getInnerPtrMut
+		cv_PtrOff32_delete, cv_PtrOff32_getInnerPtr_const, cv_PtrOff32_getInnerPtrMut, cv_PtrOff32_clone_const
 	}
 	
 	ptr_extern_ctor! { f32, cv_PtrOff32_new_const_float }
@@ -3474,7 +3501,7 @@ mod cudaarithm_types {
 	pub type PtrOfConvolution = core::Ptr<crate::cudaarithm::Convolution>;
 	
 	ptr_extern! { crate::cudaarithm::Convolution,
-		cv_PtrOfConvolution_delete, cv_PtrOfConvolution_getInnerPtr_const, cv_PtrOfConvolution_getInnerPtrMut
+		cv_PtrOfConvolution_delete, cv_PtrOfConvolution_getInnerPtr_const, cv_PtrOfConvolution_getInnerPtrMut, cv_PtrOfConvolution_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaarithm::Convolution> {
@@ -3509,7 +3536,7 @@ mod cudaarithm_types {
 	pub type PtrOfDFT = core::Ptr<crate::cudaarithm::DFT>;
 	
 	ptr_extern! { crate::cudaarithm::DFT,
-		cv_PtrOfDFT_delete, cv_PtrOfDFT_getInnerPtr_const, cv_PtrOfDFT_getInnerPtrMut
+		cv_PtrOfDFT_delete, cv_PtrOfDFT_getInnerPtr_const, cv_PtrOfDFT_getInnerPtrMut, cv_PtrOfDFT_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaarithm::DFT> {
@@ -3544,7 +3571,7 @@ mod cudaarithm_types {
 	pub type PtrOfLookUpTable = core::Ptr<crate::cudaarithm::LookUpTable>;
 	
 	ptr_extern! { crate::cudaarithm::LookUpTable,
-		cv_PtrOfLookUpTable_delete, cv_PtrOfLookUpTable_getInnerPtr_const, cv_PtrOfLookUpTable_getInnerPtrMut
+		cv_PtrOfLookUpTable_delete, cv_PtrOfLookUpTable_getInnerPtr_const, cv_PtrOfLookUpTable_getInnerPtrMut, cv_PtrOfLookUpTable_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaarithm::LookUpTable> {
@@ -3587,7 +3614,7 @@ mod cudabgsegm_types {
 	pub type PtrOfCUDA_BackgroundSubtractorMOG = core::Ptr<crate::cudabgsegm::CUDA_BackgroundSubtractorMOG>;
 	
 	ptr_extern! { crate::cudabgsegm::CUDA_BackgroundSubtractorMOG,
-		cv_PtrOfCUDA_BackgroundSubtractorMOG_delete, cv_PtrOfCUDA_BackgroundSubtractorMOG_getInnerPtr_const, cv_PtrOfCUDA_BackgroundSubtractorMOG_getInnerPtrMut
+		cv_PtrOfCUDA_BackgroundSubtractorMOG_delete, cv_PtrOfCUDA_BackgroundSubtractorMOG_getInnerPtr_const, cv_PtrOfCUDA_BackgroundSubtractorMOG_getInnerPtrMut, cv_PtrOfCUDA_BackgroundSubtractorMOG_clone_const
 	}
 	
 	impl core::Ptr<crate::cudabgsegm::CUDA_BackgroundSubtractorMOG> {
@@ -3630,7 +3657,7 @@ mod cudabgsegm_types {
 	pub type PtrOfCUDA_BackgroundSubtractorMOG2 = core::Ptr<crate::cudabgsegm::CUDA_BackgroundSubtractorMOG2>;
 	
 	ptr_extern! { crate::cudabgsegm::CUDA_BackgroundSubtractorMOG2,
-		cv_PtrOfCUDA_BackgroundSubtractorMOG2_delete, cv_PtrOfCUDA_BackgroundSubtractorMOG2_getInnerPtr_const, cv_PtrOfCUDA_BackgroundSubtractorMOG2_getInnerPtrMut
+		cv_PtrOfCUDA_BackgroundSubtractorMOG2_delete, cv_PtrOfCUDA_BackgroundSubtractorMOG2_getInnerPtr_const, cv_PtrOfCUDA_BackgroundSubtractorMOG2_getInnerPtrMut, cv_PtrOfCUDA_BackgroundSubtractorMOG2_clone_const
 	}
 	
 	impl core::Ptr<crate::cudabgsegm::CUDA_BackgroundSubtractorMOG2> {
@@ -3689,7 +3716,7 @@ mod cudacodec_types {
 	pub type PtrOfCUDA_EncoderCallback = core::Ptr<crate::cudacodec::CUDA_EncoderCallback>;
 	
 	ptr_extern! { crate::cudacodec::CUDA_EncoderCallback,
-		cv_PtrOfCUDA_EncoderCallback_delete, cv_PtrOfCUDA_EncoderCallback_getInnerPtr_const, cv_PtrOfCUDA_EncoderCallback_getInnerPtrMut
+		cv_PtrOfCUDA_EncoderCallback_delete, cv_PtrOfCUDA_EncoderCallback_getInnerPtr_const, cv_PtrOfCUDA_EncoderCallback_getInnerPtrMut, cv_PtrOfCUDA_EncoderCallback_clone_const
 	}
 	
 	impl core::Ptr<crate::cudacodec::CUDA_EncoderCallback> {
@@ -3716,7 +3743,7 @@ mod cudacodec_types {
 	pub type PtrOfCUDA_RawVideoSource = core::Ptr<crate::cudacodec::CUDA_RawVideoSource>;
 	
 	ptr_extern! { crate::cudacodec::CUDA_RawVideoSource,
-		cv_PtrOfCUDA_RawVideoSource_delete, cv_PtrOfCUDA_RawVideoSource_getInnerPtr_const, cv_PtrOfCUDA_RawVideoSource_getInnerPtrMut
+		cv_PtrOfCUDA_RawVideoSource_delete, cv_PtrOfCUDA_RawVideoSource_getInnerPtr_const, cv_PtrOfCUDA_RawVideoSource_getInnerPtrMut, cv_PtrOfCUDA_RawVideoSource_clone_const
 	}
 	
 	impl core::Ptr<crate::cudacodec::CUDA_RawVideoSource> {
@@ -3743,7 +3770,7 @@ mod cudacodec_types {
 	pub type PtrOfCUDA_VideoReader = core::Ptr<crate::cudacodec::CUDA_VideoReader>;
 	
 	ptr_extern! { crate::cudacodec::CUDA_VideoReader,
-		cv_PtrOfCUDA_VideoReader_delete, cv_PtrOfCUDA_VideoReader_getInnerPtr_const, cv_PtrOfCUDA_VideoReader_getInnerPtrMut
+		cv_PtrOfCUDA_VideoReader_delete, cv_PtrOfCUDA_VideoReader_getInnerPtr_const, cv_PtrOfCUDA_VideoReader_getInnerPtrMut, cv_PtrOfCUDA_VideoReader_clone_const
 	}
 	
 	impl core::Ptr<crate::cudacodec::CUDA_VideoReader> {
@@ -3770,7 +3797,7 @@ mod cudacodec_types {
 	pub type PtrOfCUDA_VideoWriter = core::Ptr<crate::cudacodec::CUDA_VideoWriter>;
 	
 	ptr_extern! { crate::cudacodec::CUDA_VideoWriter,
-		cv_PtrOfCUDA_VideoWriter_delete, cv_PtrOfCUDA_VideoWriter_getInnerPtr_const, cv_PtrOfCUDA_VideoWriter_getInnerPtrMut
+		cv_PtrOfCUDA_VideoWriter_delete, cv_PtrOfCUDA_VideoWriter_getInnerPtr_const, cv_PtrOfCUDA_VideoWriter_getInnerPtrMut, cv_PtrOfCUDA_VideoWriter_clone_const
 	}
 	
 	impl core::Ptr<crate::cudacodec::CUDA_VideoWriter> {
@@ -3805,7 +3832,7 @@ mod cudafeatures2d_types {
 	pub type PtrOfCUDA_DescriptorMatcher = core::Ptr<crate::cudafeatures2d::CUDA_DescriptorMatcher>;
 	
 	ptr_extern! { crate::cudafeatures2d::CUDA_DescriptorMatcher,
-		cv_PtrOfCUDA_DescriptorMatcher_delete, cv_PtrOfCUDA_DescriptorMatcher_getInnerPtr_const, cv_PtrOfCUDA_DescriptorMatcher_getInnerPtrMut
+		cv_PtrOfCUDA_DescriptorMatcher_delete, cv_PtrOfCUDA_DescriptorMatcher_getInnerPtr_const, cv_PtrOfCUDA_DescriptorMatcher_getInnerPtrMut, cv_PtrOfCUDA_DescriptorMatcher_clone_const
 	}
 	
 	impl core::Ptr<crate::cudafeatures2d::CUDA_DescriptorMatcher> {
@@ -3840,7 +3867,7 @@ mod cudafeatures2d_types {
 	pub type PtrOfCUDA_FastFeatureDetector = core::Ptr<crate::cudafeatures2d::CUDA_FastFeatureDetector>;
 	
 	ptr_extern! { crate::cudafeatures2d::CUDA_FastFeatureDetector,
-		cv_PtrOfCUDA_FastFeatureDetector_delete, cv_PtrOfCUDA_FastFeatureDetector_getInnerPtr_const, cv_PtrOfCUDA_FastFeatureDetector_getInnerPtrMut
+		cv_PtrOfCUDA_FastFeatureDetector_delete, cv_PtrOfCUDA_FastFeatureDetector_getInnerPtr_const, cv_PtrOfCUDA_FastFeatureDetector_getInnerPtrMut, cv_PtrOfCUDA_FastFeatureDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::cudafeatures2d::CUDA_FastFeatureDetector> {
@@ -3891,7 +3918,7 @@ mod cudafeatures2d_types {
 	pub type PtrOfCUDA_ORB = core::Ptr<crate::cudafeatures2d::CUDA_ORB>;
 	
 	ptr_extern! { crate::cudafeatures2d::CUDA_ORB,
-		cv_PtrOfCUDA_ORB_delete, cv_PtrOfCUDA_ORB_getInnerPtr_const, cv_PtrOfCUDA_ORB_getInnerPtrMut
+		cv_PtrOfCUDA_ORB_delete, cv_PtrOfCUDA_ORB_getInnerPtr_const, cv_PtrOfCUDA_ORB_getInnerPtrMut, cv_PtrOfCUDA_ORB_clone_const
 	}
 	
 	impl core::Ptr<crate::cudafeatures2d::CUDA_ORB> {
@@ -3950,7 +3977,7 @@ mod cudafilters_types {
 	pub type PtrOfFilter = core::Ptr<crate::cudafilters::Filter>;
 	
 	ptr_extern! { crate::cudafilters::Filter,
-		cv_PtrOfFilter_delete, cv_PtrOfFilter_getInnerPtr_const, cv_PtrOfFilter_getInnerPtrMut
+		cv_PtrOfFilter_delete, cv_PtrOfFilter_getInnerPtr_const, cv_PtrOfFilter_getInnerPtrMut, cv_PtrOfFilter_clone_const
 	}
 	
 	impl core::Ptr<crate::cudafilters::Filter> {
@@ -3993,7 +4020,7 @@ mod cudaimgproc_types {
 	pub type PtrOfCUDA_CLAHE = core::Ptr<crate::cudaimgproc::CUDA_CLAHE>;
 	
 	ptr_extern! { crate::cudaimgproc::CUDA_CLAHE,
-		cv_PtrOfCUDA_CLAHE_delete, cv_PtrOfCUDA_CLAHE_getInnerPtr_const, cv_PtrOfCUDA_CLAHE_getInnerPtrMut
+		cv_PtrOfCUDA_CLAHE_delete, cv_PtrOfCUDA_CLAHE_getInnerPtr_const, cv_PtrOfCUDA_CLAHE_getInnerPtrMut, cv_PtrOfCUDA_CLAHE_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaimgproc::CUDA_CLAHE> {
@@ -4036,7 +4063,7 @@ mod cudaimgproc_types {
 	pub type PtrOfCUDA_CannyEdgeDetector = core::Ptr<crate::cudaimgproc::CUDA_CannyEdgeDetector>;
 	
 	ptr_extern! { crate::cudaimgproc::CUDA_CannyEdgeDetector,
-		cv_PtrOfCUDA_CannyEdgeDetector_delete, cv_PtrOfCUDA_CannyEdgeDetector_getInnerPtr_const, cv_PtrOfCUDA_CannyEdgeDetector_getInnerPtrMut
+		cv_PtrOfCUDA_CannyEdgeDetector_delete, cv_PtrOfCUDA_CannyEdgeDetector_getInnerPtr_const, cv_PtrOfCUDA_CannyEdgeDetector_getInnerPtrMut, cv_PtrOfCUDA_CannyEdgeDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaimgproc::CUDA_CannyEdgeDetector> {
@@ -4071,7 +4098,7 @@ mod cudaimgproc_types {
 	pub type PtrOfCUDA_CornernessCriteria = core::Ptr<crate::cudaimgproc::CUDA_CornernessCriteria>;
 	
 	ptr_extern! { crate::cudaimgproc::CUDA_CornernessCriteria,
-		cv_PtrOfCUDA_CornernessCriteria_delete, cv_PtrOfCUDA_CornernessCriteria_getInnerPtr_const, cv_PtrOfCUDA_CornernessCriteria_getInnerPtrMut
+		cv_PtrOfCUDA_CornernessCriteria_delete, cv_PtrOfCUDA_CornernessCriteria_getInnerPtr_const, cv_PtrOfCUDA_CornernessCriteria_getInnerPtrMut, cv_PtrOfCUDA_CornernessCriteria_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaimgproc::CUDA_CornernessCriteria> {
@@ -4106,7 +4133,7 @@ mod cudaimgproc_types {
 	pub type PtrOfCUDA_CornersDetector = core::Ptr<crate::cudaimgproc::CUDA_CornersDetector>;
 	
 	ptr_extern! { crate::cudaimgproc::CUDA_CornersDetector,
-		cv_PtrOfCUDA_CornersDetector_delete, cv_PtrOfCUDA_CornersDetector_getInnerPtr_const, cv_PtrOfCUDA_CornersDetector_getInnerPtrMut
+		cv_PtrOfCUDA_CornersDetector_delete, cv_PtrOfCUDA_CornersDetector_getInnerPtr_const, cv_PtrOfCUDA_CornersDetector_getInnerPtrMut, cv_PtrOfCUDA_CornersDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaimgproc::CUDA_CornersDetector> {
@@ -4141,7 +4168,7 @@ mod cudaimgproc_types {
 	pub type PtrOfCUDA_HoughCirclesDetector = core::Ptr<crate::cudaimgproc::CUDA_HoughCirclesDetector>;
 	
 	ptr_extern! { crate::cudaimgproc::CUDA_HoughCirclesDetector,
-		cv_PtrOfCUDA_HoughCirclesDetector_delete, cv_PtrOfCUDA_HoughCirclesDetector_getInnerPtr_const, cv_PtrOfCUDA_HoughCirclesDetector_getInnerPtrMut
+		cv_PtrOfCUDA_HoughCirclesDetector_delete, cv_PtrOfCUDA_HoughCirclesDetector_getInnerPtr_const, cv_PtrOfCUDA_HoughCirclesDetector_getInnerPtrMut, cv_PtrOfCUDA_HoughCirclesDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaimgproc::CUDA_HoughCirclesDetector> {
@@ -4176,7 +4203,7 @@ mod cudaimgproc_types {
 	pub type PtrOfCUDA_HoughLinesDetector = core::Ptr<crate::cudaimgproc::CUDA_HoughLinesDetector>;
 	
 	ptr_extern! { crate::cudaimgproc::CUDA_HoughLinesDetector,
-		cv_PtrOfCUDA_HoughLinesDetector_delete, cv_PtrOfCUDA_HoughLinesDetector_getInnerPtr_const, cv_PtrOfCUDA_HoughLinesDetector_getInnerPtrMut
+		cv_PtrOfCUDA_HoughLinesDetector_delete, cv_PtrOfCUDA_HoughLinesDetector_getInnerPtr_const, cv_PtrOfCUDA_HoughLinesDetector_getInnerPtrMut, cv_PtrOfCUDA_HoughLinesDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaimgproc::CUDA_HoughLinesDetector> {
@@ -4211,7 +4238,7 @@ mod cudaimgproc_types {
 	pub type PtrOfCUDA_HoughSegmentDetector = core::Ptr<crate::cudaimgproc::CUDA_HoughSegmentDetector>;
 	
 	ptr_extern! { crate::cudaimgproc::CUDA_HoughSegmentDetector,
-		cv_PtrOfCUDA_HoughSegmentDetector_delete, cv_PtrOfCUDA_HoughSegmentDetector_getInnerPtr_const, cv_PtrOfCUDA_HoughSegmentDetector_getInnerPtrMut
+		cv_PtrOfCUDA_HoughSegmentDetector_delete, cv_PtrOfCUDA_HoughSegmentDetector_getInnerPtr_const, cv_PtrOfCUDA_HoughSegmentDetector_getInnerPtrMut, cv_PtrOfCUDA_HoughSegmentDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaimgproc::CUDA_HoughSegmentDetector> {
@@ -4246,7 +4273,7 @@ mod cudaimgproc_types {
 	pub type PtrOfCUDA_TemplateMatching = core::Ptr<crate::cudaimgproc::CUDA_TemplateMatching>;
 	
 	ptr_extern! { crate::cudaimgproc::CUDA_TemplateMatching,
-		cv_PtrOfCUDA_TemplateMatching_delete, cv_PtrOfCUDA_TemplateMatching_getInnerPtr_const, cv_PtrOfCUDA_TemplateMatching_getInnerPtrMut
+		cv_PtrOfCUDA_TemplateMatching_delete, cv_PtrOfCUDA_TemplateMatching_getInnerPtr_const, cv_PtrOfCUDA_TemplateMatching_getInnerPtrMut, cv_PtrOfCUDA_TemplateMatching_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaimgproc::CUDA_TemplateMatching> {
@@ -4289,7 +4316,7 @@ mod cudaobjdetect_types {
 	pub type PtrOfCUDA_CascadeClassifier = core::Ptr<crate::cudaobjdetect::CUDA_CascadeClassifier>;
 	
 	ptr_extern! { crate::cudaobjdetect::CUDA_CascadeClassifier,
-		cv_PtrOfCUDA_CascadeClassifier_delete, cv_PtrOfCUDA_CascadeClassifier_getInnerPtr_const, cv_PtrOfCUDA_CascadeClassifier_getInnerPtrMut
+		cv_PtrOfCUDA_CascadeClassifier_delete, cv_PtrOfCUDA_CascadeClassifier_getInnerPtr_const, cv_PtrOfCUDA_CascadeClassifier_getInnerPtrMut, cv_PtrOfCUDA_CascadeClassifier_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaobjdetect::CUDA_CascadeClassifier> {
@@ -4324,7 +4351,7 @@ mod cudaobjdetect_types {
 	pub type PtrOfCUDA_HOG = core::Ptr<crate::cudaobjdetect::CUDA_HOG>;
 	
 	ptr_extern! { crate::cudaobjdetect::CUDA_HOG,
-		cv_PtrOfCUDA_HOG_delete, cv_PtrOfCUDA_HOG_getInnerPtr_const, cv_PtrOfCUDA_HOG_getInnerPtrMut
+		cv_PtrOfCUDA_HOG_delete, cv_PtrOfCUDA_HOG_getInnerPtr_const, cv_PtrOfCUDA_HOG_getInnerPtrMut, cv_PtrOfCUDA_HOG_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaobjdetect::CUDA_HOG> {
@@ -4367,7 +4394,7 @@ mod cudaoptflow_types {
 	pub type PtrOfCUDA_BroxOpticalFlow = core::Ptr<crate::cudaoptflow::CUDA_BroxOpticalFlow>;
 	
 	ptr_extern! { crate::cudaoptflow::CUDA_BroxOpticalFlow,
-		cv_PtrOfCUDA_BroxOpticalFlow_delete, cv_PtrOfCUDA_BroxOpticalFlow_getInnerPtr_const, cv_PtrOfCUDA_BroxOpticalFlow_getInnerPtrMut
+		cv_PtrOfCUDA_BroxOpticalFlow_delete, cv_PtrOfCUDA_BroxOpticalFlow_getInnerPtr_const, cv_PtrOfCUDA_BroxOpticalFlow_getInnerPtrMut, cv_PtrOfCUDA_BroxOpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaoptflow::CUDA_BroxOpticalFlow> {
@@ -4410,7 +4437,7 @@ mod cudaoptflow_types {
 	pub type PtrOfCUDA_DensePyrLKOpticalFlow = core::Ptr<crate::cudaoptflow::CUDA_DensePyrLKOpticalFlow>;
 	
 	ptr_extern! { crate::cudaoptflow::CUDA_DensePyrLKOpticalFlow,
-		cv_PtrOfCUDA_DensePyrLKOpticalFlow_delete, cv_PtrOfCUDA_DensePyrLKOpticalFlow_getInnerPtr_const, cv_PtrOfCUDA_DensePyrLKOpticalFlow_getInnerPtrMut
+		cv_PtrOfCUDA_DensePyrLKOpticalFlow_delete, cv_PtrOfCUDA_DensePyrLKOpticalFlow_getInnerPtr_const, cv_PtrOfCUDA_DensePyrLKOpticalFlow_getInnerPtrMut, cv_PtrOfCUDA_DensePyrLKOpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaoptflow::CUDA_DensePyrLKOpticalFlow> {
@@ -4453,7 +4480,7 @@ mod cudaoptflow_types {
 	pub type PtrOfCUDA_FarnebackOpticalFlow = core::Ptr<crate::cudaoptflow::CUDA_FarnebackOpticalFlow>;
 	
 	ptr_extern! { crate::cudaoptflow::CUDA_FarnebackOpticalFlow,
-		cv_PtrOfCUDA_FarnebackOpticalFlow_delete, cv_PtrOfCUDA_FarnebackOpticalFlow_getInnerPtr_const, cv_PtrOfCUDA_FarnebackOpticalFlow_getInnerPtrMut
+		cv_PtrOfCUDA_FarnebackOpticalFlow_delete, cv_PtrOfCUDA_FarnebackOpticalFlow_getInnerPtr_const, cv_PtrOfCUDA_FarnebackOpticalFlow_getInnerPtrMut, cv_PtrOfCUDA_FarnebackOpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaoptflow::CUDA_FarnebackOpticalFlow> {
@@ -4496,7 +4523,7 @@ mod cudaoptflow_types {
 	pub type PtrOfCUDA_NvidiaOpticalFlow_1_0 = core::Ptr<crate::cudaoptflow::CUDA_NvidiaOpticalFlow_1_0>;
 	
 	ptr_extern! { crate::cudaoptflow::CUDA_NvidiaOpticalFlow_1_0,
-		cv_PtrOfCUDA_NvidiaOpticalFlow_1_0_delete, cv_PtrOfCUDA_NvidiaOpticalFlow_1_0_getInnerPtr_const, cv_PtrOfCUDA_NvidiaOpticalFlow_1_0_getInnerPtrMut
+		cv_PtrOfCUDA_NvidiaOpticalFlow_1_0_delete, cv_PtrOfCUDA_NvidiaOpticalFlow_1_0_getInnerPtr_const, cv_PtrOfCUDA_NvidiaOpticalFlow_1_0_getInnerPtrMut, cv_PtrOfCUDA_NvidiaOpticalFlow_1_0_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaoptflow::CUDA_NvidiaOpticalFlow_1_0> {
@@ -4539,7 +4566,7 @@ mod cudaoptflow_types {
 	pub type PtrOfCUDA_NvidiaOpticalFlow_2_0 = core::Ptr<crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0>;
 	
 	ptr_extern! { crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0,
-		cv_PtrOfCUDA_NvidiaOpticalFlow_2_0_delete, cv_PtrOfCUDA_NvidiaOpticalFlow_2_0_getInnerPtr_const, cv_PtrOfCUDA_NvidiaOpticalFlow_2_0_getInnerPtrMut
+		cv_PtrOfCUDA_NvidiaOpticalFlow_2_0_delete, cv_PtrOfCUDA_NvidiaOpticalFlow_2_0_getInnerPtr_const, cv_PtrOfCUDA_NvidiaOpticalFlow_2_0_getInnerPtrMut, cv_PtrOfCUDA_NvidiaOpticalFlow_2_0_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0> {
@@ -4582,7 +4609,7 @@ mod cudaoptflow_types {
 	pub type PtrOfCUDA_OpticalFlowDual_TVL1 = core::Ptr<crate::cudaoptflow::CUDA_OpticalFlowDual_TVL1>;
 	
 	ptr_extern! { crate::cudaoptflow::CUDA_OpticalFlowDual_TVL1,
-		cv_PtrOfCUDA_OpticalFlowDual_TVL1_delete, cv_PtrOfCUDA_OpticalFlowDual_TVL1_getInnerPtr_const, cv_PtrOfCUDA_OpticalFlowDual_TVL1_getInnerPtrMut
+		cv_PtrOfCUDA_OpticalFlowDual_TVL1_delete, cv_PtrOfCUDA_OpticalFlowDual_TVL1_getInnerPtr_const, cv_PtrOfCUDA_OpticalFlowDual_TVL1_getInnerPtrMut, cv_PtrOfCUDA_OpticalFlowDual_TVL1_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaoptflow::CUDA_OpticalFlowDual_TVL1> {
@@ -4625,7 +4652,7 @@ mod cudaoptflow_types {
 	pub type PtrOfCUDA_SparsePyrLKOpticalFlow = core::Ptr<crate::cudaoptflow::CUDA_SparsePyrLKOpticalFlow>;
 	
 	ptr_extern! { crate::cudaoptflow::CUDA_SparsePyrLKOpticalFlow,
-		cv_PtrOfCUDA_SparsePyrLKOpticalFlow_delete, cv_PtrOfCUDA_SparsePyrLKOpticalFlow_getInnerPtr_const, cv_PtrOfCUDA_SparsePyrLKOpticalFlow_getInnerPtrMut
+		cv_PtrOfCUDA_SparsePyrLKOpticalFlow_delete, cv_PtrOfCUDA_SparsePyrLKOpticalFlow_getInnerPtr_const, cv_PtrOfCUDA_SparsePyrLKOpticalFlow_getInnerPtrMut, cv_PtrOfCUDA_SparsePyrLKOpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::cudaoptflow::CUDA_SparsePyrLKOpticalFlow> {
@@ -4676,7 +4703,7 @@ mod cudastereo_types {
 	pub type PtrOfCUDA_DisparityBilateralFilter = core::Ptr<crate::cudastereo::CUDA_DisparityBilateralFilter>;
 	
 	ptr_extern! { crate::cudastereo::CUDA_DisparityBilateralFilter,
-		cv_PtrOfCUDA_DisparityBilateralFilter_delete, cv_PtrOfCUDA_DisparityBilateralFilter_getInnerPtr_const, cv_PtrOfCUDA_DisparityBilateralFilter_getInnerPtrMut
+		cv_PtrOfCUDA_DisparityBilateralFilter_delete, cv_PtrOfCUDA_DisparityBilateralFilter_getInnerPtr_const, cv_PtrOfCUDA_DisparityBilateralFilter_getInnerPtrMut, cv_PtrOfCUDA_DisparityBilateralFilter_clone_const
 	}
 	
 	impl core::Ptr<crate::cudastereo::CUDA_DisparityBilateralFilter> {
@@ -4711,7 +4738,7 @@ mod cudastereo_types {
 	pub type PtrOfCUDA_StereoBM = core::Ptr<crate::cudastereo::CUDA_StereoBM>;
 	
 	ptr_extern! { crate::cudastereo::CUDA_StereoBM,
-		cv_PtrOfCUDA_StereoBM_delete, cv_PtrOfCUDA_StereoBM_getInnerPtr_const, cv_PtrOfCUDA_StereoBM_getInnerPtrMut
+		cv_PtrOfCUDA_StereoBM_delete, cv_PtrOfCUDA_StereoBM_getInnerPtr_const, cv_PtrOfCUDA_StereoBM_getInnerPtrMut, cv_PtrOfCUDA_StereoBM_clone_const
 	}
 	
 	impl core::Ptr<crate::cudastereo::CUDA_StereoBM> {
@@ -4762,7 +4789,7 @@ mod cudastereo_types {
 	pub type PtrOfCUDA_StereoBeliefPropagation = core::Ptr<crate::cudastereo::CUDA_StereoBeliefPropagation>;
 	
 	ptr_extern! { crate::cudastereo::CUDA_StereoBeliefPropagation,
-		cv_PtrOfCUDA_StereoBeliefPropagation_delete, cv_PtrOfCUDA_StereoBeliefPropagation_getInnerPtr_const, cv_PtrOfCUDA_StereoBeliefPropagation_getInnerPtrMut
+		cv_PtrOfCUDA_StereoBeliefPropagation_delete, cv_PtrOfCUDA_StereoBeliefPropagation_getInnerPtr_const, cv_PtrOfCUDA_StereoBeliefPropagation_getInnerPtrMut, cv_PtrOfCUDA_StereoBeliefPropagation_clone_const
 	}
 	
 	impl core::Ptr<crate::cudastereo::CUDA_StereoBeliefPropagation> {
@@ -4805,7 +4832,7 @@ mod cudastereo_types {
 	pub type PtrOfCUDA_StereoConstantSpaceBP = core::Ptr<crate::cudastereo::CUDA_StereoConstantSpaceBP>;
 	
 	ptr_extern! { crate::cudastereo::CUDA_StereoConstantSpaceBP,
-		cv_PtrOfCUDA_StereoConstantSpaceBP_delete, cv_PtrOfCUDA_StereoConstantSpaceBP_getInnerPtr_const, cv_PtrOfCUDA_StereoConstantSpaceBP_getInnerPtrMut
+		cv_PtrOfCUDA_StereoConstantSpaceBP_delete, cv_PtrOfCUDA_StereoConstantSpaceBP_getInnerPtr_const, cv_PtrOfCUDA_StereoConstantSpaceBP_getInnerPtrMut, cv_PtrOfCUDA_StereoConstantSpaceBP_clone_const
 	}
 	
 	impl core::Ptr<crate::cudastereo::CUDA_StereoConstantSpaceBP> {
@@ -4856,7 +4883,7 @@ mod cudastereo_types {
 	pub type PtrOfCUDA_StereoSGM = core::Ptr<crate::cudastereo::CUDA_StereoSGM>;
 	
 	ptr_extern! { crate::cudastereo::CUDA_StereoSGM,
-		cv_PtrOfCUDA_StereoSGM_delete, cv_PtrOfCUDA_StereoSGM_getInnerPtr_const, cv_PtrOfCUDA_StereoSGM_getInnerPtrMut
+		cv_PtrOfCUDA_StereoSGM_delete, cv_PtrOfCUDA_StereoSGM_getInnerPtr_const, cv_PtrOfCUDA_StereoSGM_getInnerPtrMut, cv_PtrOfCUDA_StereoSGM_clone_const
 	}
 	
 	impl core::Ptr<crate::cudastereo::CUDA_StereoSGM> {
@@ -4915,7 +4942,7 @@ mod dnn_types {
 	pub type PtrOfAbsLayer = core::Ptr<crate::dnn::AbsLayer>;
 	
 	ptr_extern! { crate::dnn::AbsLayer,
-		cv_PtrOfAbsLayer_delete, cv_PtrOfAbsLayer_getInnerPtr_const, cv_PtrOfAbsLayer_getInnerPtrMut
+		cv_PtrOfAbsLayer_delete, cv_PtrOfAbsLayer_getInnerPtr_const, cv_PtrOfAbsLayer_getInnerPtrMut, cv_PtrOfAbsLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::AbsLayer, cv_PtrOfAbsLayer_new_const_AbsLayer }
@@ -4971,7 +4998,7 @@ mod dnn_types {
 	pub type PtrOfAccumLayer = core::Ptr<crate::dnn::AccumLayer>;
 	
 	ptr_extern! { crate::dnn::AccumLayer,
-		cv_PtrOfAccumLayer_delete, cv_PtrOfAccumLayer_getInnerPtr_const, cv_PtrOfAccumLayer_getInnerPtrMut
+		cv_PtrOfAccumLayer_delete, cv_PtrOfAccumLayer_getInnerPtr_const, cv_PtrOfAccumLayer_getInnerPtrMut, cv_PtrOfAccumLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::AccumLayer, cv_PtrOfAccumLayer_new_const_AccumLayer }
@@ -5019,7 +5046,7 @@ mod dnn_types {
 	pub type PtrOfAcosLayer = core::Ptr<crate::dnn::AcosLayer>;
 	
 	ptr_extern! { crate::dnn::AcosLayer,
-		cv_PtrOfAcosLayer_delete, cv_PtrOfAcosLayer_getInnerPtr_const, cv_PtrOfAcosLayer_getInnerPtrMut
+		cv_PtrOfAcosLayer_delete, cv_PtrOfAcosLayer_getInnerPtr_const, cv_PtrOfAcosLayer_getInnerPtrMut, cv_PtrOfAcosLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::AcosLayer, cv_PtrOfAcosLayer_new_const_AcosLayer }
@@ -5075,7 +5102,7 @@ mod dnn_types {
 	pub type PtrOfAcoshLayer = core::Ptr<crate::dnn::AcoshLayer>;
 	
 	ptr_extern! { crate::dnn::AcoshLayer,
-		cv_PtrOfAcoshLayer_delete, cv_PtrOfAcoshLayer_getInnerPtr_const, cv_PtrOfAcoshLayer_getInnerPtrMut
+		cv_PtrOfAcoshLayer_delete, cv_PtrOfAcoshLayer_getInnerPtr_const, cv_PtrOfAcoshLayer_getInnerPtrMut, cv_PtrOfAcoshLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::AcoshLayer, cv_PtrOfAcoshLayer_new_const_AcoshLayer }
@@ -5131,7 +5158,7 @@ mod dnn_types {
 	pub type PtrOfActivationLayer = core::Ptr<crate::dnn::ActivationLayer>;
 	
 	ptr_extern! { crate::dnn::ActivationLayer,
-		cv_PtrOfActivationLayer_delete, cv_PtrOfActivationLayer_getInnerPtr_const, cv_PtrOfActivationLayer_getInnerPtrMut
+		cv_PtrOfActivationLayer_delete, cv_PtrOfActivationLayer_getInnerPtr_const, cv_PtrOfActivationLayer_getInnerPtrMut, cv_PtrOfActivationLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ActivationLayer, cv_PtrOfActivationLayer_new_const_ActivationLayer }
@@ -5179,7 +5206,7 @@ mod dnn_types {
 	pub type PtrOfActivationLayerInt8 = core::Ptr<crate::dnn::ActivationLayerInt8>;
 	
 	ptr_extern! { crate::dnn::ActivationLayerInt8,
-		cv_PtrOfActivationLayerInt8_delete, cv_PtrOfActivationLayerInt8_getInnerPtr_const, cv_PtrOfActivationLayerInt8_getInnerPtrMut
+		cv_PtrOfActivationLayerInt8_delete, cv_PtrOfActivationLayerInt8_getInnerPtr_const, cv_PtrOfActivationLayerInt8_getInnerPtrMut, cv_PtrOfActivationLayerInt8_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ActivationLayerInt8, cv_PtrOfActivationLayerInt8_new_const_ActivationLayerInt8 }
@@ -5235,7 +5262,7 @@ mod dnn_types {
 	pub type PtrOfArgLayer = core::Ptr<crate::dnn::ArgLayer>;
 	
 	ptr_extern! { crate::dnn::ArgLayer,
-		cv_PtrOfArgLayer_delete, cv_PtrOfArgLayer_getInnerPtr_const, cv_PtrOfArgLayer_getInnerPtrMut
+		cv_PtrOfArgLayer_delete, cv_PtrOfArgLayer_getInnerPtr_const, cv_PtrOfArgLayer_getInnerPtrMut, cv_PtrOfArgLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ArgLayer, cv_PtrOfArgLayer_new_const_ArgLayer }
@@ -5283,7 +5310,7 @@ mod dnn_types {
 	pub type PtrOfAsinLayer = core::Ptr<crate::dnn::AsinLayer>;
 	
 	ptr_extern! { crate::dnn::AsinLayer,
-		cv_PtrOfAsinLayer_delete, cv_PtrOfAsinLayer_getInnerPtr_const, cv_PtrOfAsinLayer_getInnerPtrMut
+		cv_PtrOfAsinLayer_delete, cv_PtrOfAsinLayer_getInnerPtr_const, cv_PtrOfAsinLayer_getInnerPtrMut, cv_PtrOfAsinLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::AsinLayer, cv_PtrOfAsinLayer_new_const_AsinLayer }
@@ -5339,7 +5366,7 @@ mod dnn_types {
 	pub type PtrOfAsinhLayer = core::Ptr<crate::dnn::AsinhLayer>;
 	
 	ptr_extern! { crate::dnn::AsinhLayer,
-		cv_PtrOfAsinhLayer_delete, cv_PtrOfAsinhLayer_getInnerPtr_const, cv_PtrOfAsinhLayer_getInnerPtrMut
+		cv_PtrOfAsinhLayer_delete, cv_PtrOfAsinhLayer_getInnerPtr_const, cv_PtrOfAsinhLayer_getInnerPtrMut, cv_PtrOfAsinhLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::AsinhLayer, cv_PtrOfAsinhLayer_new_const_AsinhLayer }
@@ -5395,7 +5422,7 @@ mod dnn_types {
 	pub type PtrOfAtanLayer = core::Ptr<crate::dnn::AtanLayer>;
 	
 	ptr_extern! { crate::dnn::AtanLayer,
-		cv_PtrOfAtanLayer_delete, cv_PtrOfAtanLayer_getInnerPtr_const, cv_PtrOfAtanLayer_getInnerPtrMut
+		cv_PtrOfAtanLayer_delete, cv_PtrOfAtanLayer_getInnerPtr_const, cv_PtrOfAtanLayer_getInnerPtrMut, cv_PtrOfAtanLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::AtanLayer, cv_PtrOfAtanLayer_new_const_AtanLayer }
@@ -5451,7 +5478,7 @@ mod dnn_types {
 	pub type PtrOfAtanhLayer = core::Ptr<crate::dnn::AtanhLayer>;
 	
 	ptr_extern! { crate::dnn::AtanhLayer,
-		cv_PtrOfAtanhLayer_delete, cv_PtrOfAtanhLayer_getInnerPtr_const, cv_PtrOfAtanhLayer_getInnerPtrMut
+		cv_PtrOfAtanhLayer_delete, cv_PtrOfAtanhLayer_getInnerPtr_const, cv_PtrOfAtanhLayer_getInnerPtrMut, cv_PtrOfAtanhLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::AtanhLayer, cv_PtrOfAtanhLayer_new_const_AtanhLayer }
@@ -5507,7 +5534,7 @@ mod dnn_types {
 	pub type PtrOfBNLLLayer = core::Ptr<crate::dnn::BNLLLayer>;
 	
 	ptr_extern! { crate::dnn::BNLLLayer,
-		cv_PtrOfBNLLLayer_delete, cv_PtrOfBNLLLayer_getInnerPtr_const, cv_PtrOfBNLLLayer_getInnerPtrMut
+		cv_PtrOfBNLLLayer_delete, cv_PtrOfBNLLLayer_getInnerPtr_const, cv_PtrOfBNLLLayer_getInnerPtrMut, cv_PtrOfBNLLLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::BNLLLayer, cv_PtrOfBNLLLayer_new_const_BNLLLayer }
@@ -5563,7 +5590,7 @@ mod dnn_types {
 	pub type PtrOfBackendNode = core::Ptr<crate::dnn::BackendNode>;
 	
 	ptr_extern! { crate::dnn::BackendNode,
-		cv_PtrOfBackendNode_delete, cv_PtrOfBackendNode_getInnerPtr_const, cv_PtrOfBackendNode_getInnerPtrMut
+		cv_PtrOfBackendNode_delete, cv_PtrOfBackendNode_getInnerPtr_const, cv_PtrOfBackendNode_getInnerPtrMut, cv_PtrOfBackendNode_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::BackendNode, cv_PtrOfBackendNode_new_const_BackendNode }
@@ -5592,7 +5619,7 @@ mod dnn_types {
 	pub type PtrOfBackendWrapper = core::Ptr<crate::dnn::BackendWrapper>;
 	
 	ptr_extern! { crate::dnn::BackendWrapper,
-		cv_PtrOfBackendWrapper_delete, cv_PtrOfBackendWrapper_getInnerPtr_const, cv_PtrOfBackendWrapper_getInnerPtrMut
+		cv_PtrOfBackendWrapper_delete, cv_PtrOfBackendWrapper_getInnerPtr_const, cv_PtrOfBackendWrapper_getInnerPtrMut, cv_PtrOfBackendWrapper_clone_const
 	}
 	
 	impl core::Ptr<crate::dnn::BackendWrapper> {
@@ -5621,7 +5648,7 @@ mod dnn_types {
 	pub type PtrOfBaseConvolutionLayer = core::Ptr<crate::dnn::BaseConvolutionLayer>;
 	
 	ptr_extern! { crate::dnn::BaseConvolutionLayer,
-		cv_PtrOfBaseConvolutionLayer_delete, cv_PtrOfBaseConvolutionLayer_getInnerPtr_const, cv_PtrOfBaseConvolutionLayer_getInnerPtrMut
+		cv_PtrOfBaseConvolutionLayer_delete, cv_PtrOfBaseConvolutionLayer_getInnerPtr_const, cv_PtrOfBaseConvolutionLayer_getInnerPtrMut, cv_PtrOfBaseConvolutionLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::BaseConvolutionLayer, cv_PtrOfBaseConvolutionLayer_new_const_BaseConvolutionLayer }
@@ -5682,7 +5709,7 @@ mod dnn_types {
 	pub type PtrOfBatchNormLayer = core::Ptr<crate::dnn::BatchNormLayer>;
 	
 	ptr_extern! { crate::dnn::BatchNormLayer,
-		cv_PtrOfBatchNormLayer_delete, cv_PtrOfBatchNormLayer_getInnerPtr_const, cv_PtrOfBatchNormLayer_getInnerPtrMut
+		cv_PtrOfBatchNormLayer_delete, cv_PtrOfBatchNormLayer_getInnerPtr_const, cv_PtrOfBatchNormLayer_getInnerPtrMut, cv_PtrOfBatchNormLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::BatchNormLayer, cv_PtrOfBatchNormLayer_new_const_BatchNormLayer }
@@ -5741,7 +5768,7 @@ mod dnn_types {
 	pub type PtrOfBatchNormLayerInt8 = core::Ptr<crate::dnn::BatchNormLayerInt8>;
 	
 	ptr_extern! { crate::dnn::BatchNormLayerInt8,
-		cv_PtrOfBatchNormLayerInt8_delete, cv_PtrOfBatchNormLayerInt8_getInnerPtr_const, cv_PtrOfBatchNormLayerInt8_getInnerPtrMut
+		cv_PtrOfBatchNormLayerInt8_delete, cv_PtrOfBatchNormLayerInt8_getInnerPtr_const, cv_PtrOfBatchNormLayerInt8_getInnerPtrMut, cv_PtrOfBatchNormLayerInt8_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::BatchNormLayerInt8, cv_PtrOfBatchNormLayerInt8_new_const_BatchNormLayerInt8 }
@@ -5812,7 +5839,7 @@ mod dnn_types {
 	pub type PtrOfCeilLayer = core::Ptr<crate::dnn::CeilLayer>;
 	
 	ptr_extern! { crate::dnn::CeilLayer,
-		cv_PtrOfCeilLayer_delete, cv_PtrOfCeilLayer_getInnerPtr_const, cv_PtrOfCeilLayer_getInnerPtrMut
+		cv_PtrOfCeilLayer_delete, cv_PtrOfCeilLayer_getInnerPtr_const, cv_PtrOfCeilLayer_getInnerPtrMut, cv_PtrOfCeilLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::CeilLayer, cv_PtrOfCeilLayer_new_const_CeilLayer }
@@ -5868,7 +5895,7 @@ mod dnn_types {
 	pub type PtrOfCeluLayer = core::Ptr<crate::dnn::CeluLayer>;
 	
 	ptr_extern! { crate::dnn::CeluLayer,
-		cv_PtrOfCeluLayer_delete, cv_PtrOfCeluLayer_getInnerPtr_const, cv_PtrOfCeluLayer_getInnerPtrMut
+		cv_PtrOfCeluLayer_delete, cv_PtrOfCeluLayer_getInnerPtr_const, cv_PtrOfCeluLayer_getInnerPtrMut, cv_PtrOfCeluLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::CeluLayer, cv_PtrOfCeluLayer_new_const_CeluLayer }
@@ -5925,7 +5952,7 @@ mod dnn_types {
 	pub type PtrOfConcatLayer = core::Ptr<crate::dnn::ConcatLayer>;
 	
 	ptr_extern! { crate::dnn::ConcatLayer,
-		cv_PtrOfConcatLayer_delete, cv_PtrOfConcatLayer_getInnerPtr_const, cv_PtrOfConcatLayer_getInnerPtrMut
+		cv_PtrOfConcatLayer_delete, cv_PtrOfConcatLayer_getInnerPtr_const, cv_PtrOfConcatLayer_getInnerPtrMut, cv_PtrOfConcatLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ConcatLayer, cv_PtrOfConcatLayer_new_const_ConcatLayer }
@@ -5976,7 +6003,7 @@ mod dnn_types {
 	pub type PtrOfCorrelationLayer = core::Ptr<crate::dnn::CorrelationLayer>;
 	
 	ptr_extern! { crate::dnn::CorrelationLayer,
-		cv_PtrOfCorrelationLayer_delete, cv_PtrOfCorrelationLayer_getInnerPtr_const, cv_PtrOfCorrelationLayer_getInnerPtrMut
+		cv_PtrOfCorrelationLayer_delete, cv_PtrOfCorrelationLayer_getInnerPtr_const, cv_PtrOfCorrelationLayer_getInnerPtrMut, cv_PtrOfCorrelationLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::CorrelationLayer, cv_PtrOfCorrelationLayer_new_const_CorrelationLayer }
@@ -6024,7 +6051,7 @@ mod dnn_types {
 	pub type PtrOfCosLayer = core::Ptr<crate::dnn::CosLayer>;
 	
 	ptr_extern! { crate::dnn::CosLayer,
-		cv_PtrOfCosLayer_delete, cv_PtrOfCosLayer_getInnerPtr_const, cv_PtrOfCosLayer_getInnerPtrMut
+		cv_PtrOfCosLayer_delete, cv_PtrOfCosLayer_getInnerPtr_const, cv_PtrOfCosLayer_getInnerPtrMut, cv_PtrOfCosLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::CosLayer, cv_PtrOfCosLayer_new_const_CosLayer }
@@ -6080,7 +6107,7 @@ mod dnn_types {
 	pub type PtrOfCoshLayer = core::Ptr<crate::dnn::CoshLayer>;
 	
 	ptr_extern! { crate::dnn::CoshLayer,
-		cv_PtrOfCoshLayer_delete, cv_PtrOfCoshLayer_getInnerPtr_const, cv_PtrOfCoshLayer_getInnerPtrMut
+		cv_PtrOfCoshLayer_delete, cv_PtrOfCoshLayer_getInnerPtr_const, cv_PtrOfCoshLayer_getInnerPtrMut, cv_PtrOfCoshLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::CoshLayer, cv_PtrOfCoshLayer_new_const_CoshLayer }
@@ -6136,7 +6163,7 @@ mod dnn_types {
 	pub type PtrOfCumSumLayer = core::Ptr<crate::dnn::CumSumLayer>;
 	
 	ptr_extern! { crate::dnn::CumSumLayer,
-		cv_PtrOfCumSumLayer_delete, cv_PtrOfCumSumLayer_getInnerPtr_const, cv_PtrOfCumSumLayer_getInnerPtrMut
+		cv_PtrOfCumSumLayer_delete, cv_PtrOfCumSumLayer_getInnerPtr_const, cv_PtrOfCumSumLayer_getInnerPtrMut, cv_PtrOfCumSumLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::CumSumLayer, cv_PtrOfCumSumLayer_new_const_CumSumLayer }
@@ -6186,7 +6213,7 @@ mod dnn_types {
 	pub type PtrOfDataAugmentationLayer = core::Ptr<crate::dnn::DataAugmentationLayer>;
 	
 	ptr_extern! { crate::dnn::DataAugmentationLayer,
-		cv_PtrOfDataAugmentationLayer_delete, cv_PtrOfDataAugmentationLayer_getInnerPtr_const, cv_PtrOfDataAugmentationLayer_getInnerPtrMut
+		cv_PtrOfDataAugmentationLayer_delete, cv_PtrOfDataAugmentationLayer_getInnerPtr_const, cv_PtrOfDataAugmentationLayer_getInnerPtrMut, cv_PtrOfDataAugmentationLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::DataAugmentationLayer, cv_PtrOfDataAugmentationLayer_new_const_DataAugmentationLayer }
@@ -6234,7 +6261,7 @@ mod dnn_types {
 	pub type PtrOfDequantizeLayer = core::Ptr<crate::dnn::DequantizeLayer>;
 	
 	ptr_extern! { crate::dnn::DequantizeLayer,
-		cv_PtrOfDequantizeLayer_delete, cv_PtrOfDequantizeLayer_getInnerPtr_const, cv_PtrOfDequantizeLayer_getInnerPtrMut
+		cv_PtrOfDequantizeLayer_delete, cv_PtrOfDequantizeLayer_getInnerPtr_const, cv_PtrOfDequantizeLayer_getInnerPtrMut, cv_PtrOfDequantizeLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::DequantizeLayer, cv_PtrOfDequantizeLayer_new_const_DequantizeLayer }
@@ -6284,7 +6311,7 @@ mod dnn_types {
 	pub type PtrOfDetectionOutputLayer = core::Ptr<crate::dnn::DetectionOutputLayer>;
 	
 	ptr_extern! { crate::dnn::DetectionOutputLayer,
-		cv_PtrOfDetectionOutputLayer_delete, cv_PtrOfDetectionOutputLayer_getInnerPtr_const, cv_PtrOfDetectionOutputLayer_getInnerPtrMut
+		cv_PtrOfDetectionOutputLayer_delete, cv_PtrOfDetectionOutputLayer_getInnerPtr_const, cv_PtrOfDetectionOutputLayer_getInnerPtrMut, cv_PtrOfDetectionOutputLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::DetectionOutputLayer, cv_PtrOfDetectionOutputLayer_new_const_DetectionOutputLayer }
@@ -6332,7 +6359,7 @@ mod dnn_types {
 	pub type PtrOfELULayer = core::Ptr<crate::dnn::ELULayer>;
 	
 	ptr_extern! { crate::dnn::ELULayer,
-		cv_PtrOfELULayer_delete, cv_PtrOfELULayer_getInnerPtr_const, cv_PtrOfELULayer_getInnerPtrMut
+		cv_PtrOfELULayer_delete, cv_PtrOfELULayer_getInnerPtr_const, cv_PtrOfELULayer_getInnerPtrMut, cv_PtrOfELULayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ELULayer, cv_PtrOfELULayer_new_const_ELULayer }
@@ -6389,7 +6416,7 @@ mod dnn_types {
 	pub type PtrOfEltwiseLayer = core::Ptr<crate::dnn::EltwiseLayer>;
 	
 	ptr_extern! { crate::dnn::EltwiseLayer,
-		cv_PtrOfEltwiseLayer_delete, cv_PtrOfEltwiseLayer_getInnerPtr_const, cv_PtrOfEltwiseLayer_getInnerPtrMut
+		cv_PtrOfEltwiseLayer_delete, cv_PtrOfEltwiseLayer_getInnerPtr_const, cv_PtrOfEltwiseLayer_getInnerPtrMut, cv_PtrOfEltwiseLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::EltwiseLayer, cv_PtrOfEltwiseLayer_new_const_EltwiseLayer }
@@ -6437,7 +6464,7 @@ mod dnn_types {
 	pub type PtrOfEltwiseLayerInt8 = core::Ptr<crate::dnn::EltwiseLayerInt8>;
 	
 	ptr_extern! { crate::dnn::EltwiseLayerInt8,
-		cv_PtrOfEltwiseLayerInt8_delete, cv_PtrOfEltwiseLayerInt8_getInnerPtr_const, cv_PtrOfEltwiseLayerInt8_getInnerPtrMut
+		cv_PtrOfEltwiseLayerInt8_delete, cv_PtrOfEltwiseLayerInt8_getInnerPtr_const, cv_PtrOfEltwiseLayerInt8_getInnerPtrMut, cv_PtrOfEltwiseLayerInt8_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::EltwiseLayerInt8, cv_PtrOfEltwiseLayerInt8_new_const_EltwiseLayerInt8 }
@@ -6485,7 +6512,7 @@ mod dnn_types {
 	pub type PtrOfErfLayer = core::Ptr<crate::dnn::ErfLayer>;
 	
 	ptr_extern! { crate::dnn::ErfLayer,
-		cv_PtrOfErfLayer_delete, cv_PtrOfErfLayer_getInnerPtr_const, cv_PtrOfErfLayer_getInnerPtrMut
+		cv_PtrOfErfLayer_delete, cv_PtrOfErfLayer_getInnerPtr_const, cv_PtrOfErfLayer_getInnerPtrMut, cv_PtrOfErfLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ErfLayer, cv_PtrOfErfLayer_new_const_ErfLayer }
@@ -6541,7 +6568,7 @@ mod dnn_types {
 	pub type PtrOfExpLayer = core::Ptr<crate::dnn::ExpLayer>;
 	
 	ptr_extern! { crate::dnn::ExpLayer,
-		cv_PtrOfExpLayer_delete, cv_PtrOfExpLayer_getInnerPtr_const, cv_PtrOfExpLayer_getInnerPtrMut
+		cv_PtrOfExpLayer_delete, cv_PtrOfExpLayer_getInnerPtr_const, cv_PtrOfExpLayer_getInnerPtrMut, cv_PtrOfExpLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ExpLayer, cv_PtrOfExpLayer_new_const_ExpLayer }
@@ -6600,7 +6627,7 @@ mod dnn_types {
 	pub type PtrOfFlattenLayer = core::Ptr<crate::dnn::FlattenLayer>;
 	
 	ptr_extern! { crate::dnn::FlattenLayer,
-		cv_PtrOfFlattenLayer_delete, cv_PtrOfFlattenLayer_getInnerPtr_const, cv_PtrOfFlattenLayer_getInnerPtrMut
+		cv_PtrOfFlattenLayer_delete, cv_PtrOfFlattenLayer_getInnerPtr_const, cv_PtrOfFlattenLayer_getInnerPtrMut, cv_PtrOfFlattenLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::FlattenLayer, cv_PtrOfFlattenLayer_new_const_FlattenLayer }
@@ -6648,7 +6675,7 @@ mod dnn_types {
 	pub type PtrOfFloorLayer = core::Ptr<crate::dnn::FloorLayer>;
 	
 	ptr_extern! { crate::dnn::FloorLayer,
-		cv_PtrOfFloorLayer_delete, cv_PtrOfFloorLayer_getInnerPtr_const, cv_PtrOfFloorLayer_getInnerPtrMut
+		cv_PtrOfFloorLayer_delete, cv_PtrOfFloorLayer_getInnerPtr_const, cv_PtrOfFloorLayer_getInnerPtrMut, cv_PtrOfFloorLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::FloorLayer, cv_PtrOfFloorLayer_new_const_FloorLayer }
@@ -6704,7 +6731,7 @@ mod dnn_types {
 	pub type PtrOfFlowWarpLayer = core::Ptr<crate::dnn::FlowWarpLayer>;
 	
 	ptr_extern! { crate::dnn::FlowWarpLayer,
-		cv_PtrOfFlowWarpLayer_delete, cv_PtrOfFlowWarpLayer_getInnerPtr_const, cv_PtrOfFlowWarpLayer_getInnerPtrMut
+		cv_PtrOfFlowWarpLayer_delete, cv_PtrOfFlowWarpLayer_getInnerPtr_const, cv_PtrOfFlowWarpLayer_getInnerPtrMut, cv_PtrOfFlowWarpLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::FlowWarpLayer, cv_PtrOfFlowWarpLayer_new_const_FlowWarpLayer }
@@ -6752,7 +6779,7 @@ mod dnn_types {
 	pub type PtrOfGRULayer = core::Ptr<crate::dnn::GRULayer>;
 	
 	ptr_extern! { crate::dnn::GRULayer,
-		cv_PtrOfGRULayer_delete, cv_PtrOfGRULayer_getInnerPtr_const, cv_PtrOfGRULayer_getInnerPtrMut
+		cv_PtrOfGRULayer_delete, cv_PtrOfGRULayer_getInnerPtr_const, cv_PtrOfGRULayer_getInnerPtrMut, cv_PtrOfGRULayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::GRULayer, cv_PtrOfGRULayer_new_const_GRULayer }
@@ -6800,7 +6827,7 @@ mod dnn_types {
 	pub type PtrOfGatherLayer = core::Ptr<crate::dnn::GatherLayer>;
 	
 	ptr_extern! { crate::dnn::GatherLayer,
-		cv_PtrOfGatherLayer_delete, cv_PtrOfGatherLayer_getInnerPtr_const, cv_PtrOfGatherLayer_getInnerPtrMut
+		cv_PtrOfGatherLayer_delete, cv_PtrOfGatherLayer_getInnerPtr_const, cv_PtrOfGatherLayer_getInnerPtrMut, cv_PtrOfGatherLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::GatherLayer, cv_PtrOfGatherLayer_new_const_GatherLayer }
@@ -6848,7 +6875,7 @@ mod dnn_types {
 	pub type PtrOfGeluApproximationLayer = core::Ptr<crate::dnn::GeluApproximationLayer>;
 	
 	ptr_extern! { crate::dnn::GeluApproximationLayer,
-		cv_PtrOfGeluApproximationLayer_delete, cv_PtrOfGeluApproximationLayer_getInnerPtr_const, cv_PtrOfGeluApproximationLayer_getInnerPtrMut
+		cv_PtrOfGeluApproximationLayer_delete, cv_PtrOfGeluApproximationLayer_getInnerPtr_const, cv_PtrOfGeluApproximationLayer_getInnerPtrMut, cv_PtrOfGeluApproximationLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::GeluApproximationLayer, cv_PtrOfGeluApproximationLayer_new_const_GeluApproximationLayer }
@@ -6904,7 +6931,7 @@ mod dnn_types {
 	pub type PtrOfGeluLayer = core::Ptr<crate::dnn::GeluLayer>;
 	
 	ptr_extern! { crate::dnn::GeluLayer,
-		cv_PtrOfGeluLayer_delete, cv_PtrOfGeluLayer_getInnerPtr_const, cv_PtrOfGeluLayer_getInnerPtrMut
+		cv_PtrOfGeluLayer_delete, cv_PtrOfGeluLayer_getInnerPtr_const, cv_PtrOfGeluLayer_getInnerPtrMut, cv_PtrOfGeluLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::GeluLayer, cv_PtrOfGeluLayer_new_const_GeluLayer }
@@ -6960,7 +6987,7 @@ mod dnn_types {
 	pub type PtrOfHardSigmoidLayer = core::Ptr<crate::dnn::HardSigmoidLayer>;
 	
 	ptr_extern! { crate::dnn::HardSigmoidLayer,
-		cv_PtrOfHardSigmoidLayer_delete, cv_PtrOfHardSigmoidLayer_getInnerPtr_const, cv_PtrOfHardSigmoidLayer_getInnerPtrMut
+		cv_PtrOfHardSigmoidLayer_delete, cv_PtrOfHardSigmoidLayer_getInnerPtr_const, cv_PtrOfHardSigmoidLayer_getInnerPtrMut, cv_PtrOfHardSigmoidLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::HardSigmoidLayer, cv_PtrOfHardSigmoidLayer_new_const_HardSigmoidLayer }
@@ -7018,7 +7045,7 @@ mod dnn_types {
 	pub type PtrOfHardSwishLayer = core::Ptr<crate::dnn::HardSwishLayer>;
 	
 	ptr_extern! { crate::dnn::HardSwishLayer,
-		cv_PtrOfHardSwishLayer_delete, cv_PtrOfHardSwishLayer_getInnerPtr_const, cv_PtrOfHardSwishLayer_getInnerPtrMut
+		cv_PtrOfHardSwishLayer_delete, cv_PtrOfHardSwishLayer_getInnerPtr_const, cv_PtrOfHardSwishLayer_getInnerPtrMut, cv_PtrOfHardSwishLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::HardSwishLayer, cv_PtrOfHardSwishLayer_new_const_HardSwishLayer }
@@ -7074,7 +7101,7 @@ mod dnn_types {
 	pub type PtrOfInnerProductLayer = core::Ptr<crate::dnn::InnerProductLayer>;
 	
 	ptr_extern! { crate::dnn::InnerProductLayer,
-		cv_PtrOfInnerProductLayer_delete, cv_PtrOfInnerProductLayer_getInnerPtr_const, cv_PtrOfInnerProductLayer_getInnerPtrMut
+		cv_PtrOfInnerProductLayer_delete, cv_PtrOfInnerProductLayer_getInnerPtr_const, cv_PtrOfInnerProductLayer_getInnerPtrMut, cv_PtrOfInnerProductLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::InnerProductLayer, cv_PtrOfInnerProductLayer_new_const_InnerProductLayer }
@@ -7123,7 +7150,7 @@ mod dnn_types {
 	pub type PtrOfInnerProductLayerInt8 = core::Ptr<crate::dnn::InnerProductLayerInt8>;
 	
 	ptr_extern! { crate::dnn::InnerProductLayerInt8,
-		cv_PtrOfInnerProductLayerInt8_delete, cv_PtrOfInnerProductLayerInt8_getInnerPtr_const, cv_PtrOfInnerProductLayerInt8_getInnerPtrMut
+		cv_PtrOfInnerProductLayerInt8_delete, cv_PtrOfInnerProductLayerInt8_getInnerPtr_const, cv_PtrOfInnerProductLayerInt8_getInnerPtrMut, cv_PtrOfInnerProductLayerInt8_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::InnerProductLayerInt8, cv_PtrOfInnerProductLayerInt8_new_const_InnerProductLayerInt8 }
@@ -7185,7 +7212,7 @@ mod dnn_types {
 	pub type PtrOfLRNLayer = core::Ptr<crate::dnn::LRNLayer>;
 	
 	ptr_extern! { crate::dnn::LRNLayer,
-		cv_PtrOfLRNLayer_delete, cv_PtrOfLRNLayer_getInnerPtr_const, cv_PtrOfLRNLayer_getInnerPtrMut
+		cv_PtrOfLRNLayer_delete, cv_PtrOfLRNLayer_getInnerPtr_const, cv_PtrOfLRNLayer_getInnerPtrMut, cv_PtrOfLRNLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::LRNLayer, cv_PtrOfLRNLayer_new_const_LRNLayer }
@@ -7239,7 +7266,7 @@ mod dnn_types {
 	pub type PtrOfLSTMLayer = core::Ptr<crate::dnn::LSTMLayer>;
 	
 	ptr_extern! { crate::dnn::LSTMLayer,
-		cv_PtrOfLSTMLayer_delete, cv_PtrOfLSTMLayer_getInnerPtr_const, cv_PtrOfLSTMLayer_getInnerPtrMut
+		cv_PtrOfLSTMLayer_delete, cv_PtrOfLSTMLayer_getInnerPtr_const, cv_PtrOfLSTMLayer_getInnerPtrMut, cv_PtrOfLSTMLayer_clone_const
 	}
 	
 	impl core::Ptr<crate::dnn::LSTMLayer> {
@@ -7286,7 +7313,7 @@ mod dnn_types {
 	pub type PtrOfLayer = core::Ptr<crate::dnn::Layer>;
 	
 	ptr_extern! { crate::dnn::Layer,
-		cv_PtrOfLayer_delete, cv_PtrOfLayer_getInnerPtr_const, cv_PtrOfLayer_getInnerPtrMut
+		cv_PtrOfLayer_delete, cv_PtrOfLayer_getInnerPtr_const, cv_PtrOfLayer_getInnerPtrMut, cv_PtrOfLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::Layer, cv_PtrOfLayer_new_const_Layer }
@@ -7326,7 +7353,7 @@ mod dnn_types {
 	pub type PtrOfLayerNormLayer = core::Ptr<crate::dnn::LayerNormLayer>;
 	
 	ptr_extern! { crate::dnn::LayerNormLayer,
-		cv_PtrOfLayerNormLayer_delete, cv_PtrOfLayerNormLayer_getInnerPtr_const, cv_PtrOfLayerNormLayer_getInnerPtrMut
+		cv_PtrOfLayerNormLayer_delete, cv_PtrOfLayerNormLayer_getInnerPtr_const, cv_PtrOfLayerNormLayer_getInnerPtrMut, cv_PtrOfLayerNormLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::LayerNormLayer, cv_PtrOfLayerNormLayer_new_const_LayerNormLayer }
@@ -7377,7 +7404,7 @@ mod dnn_types {
 	pub type PtrOfLogLayer = core::Ptr<crate::dnn::LogLayer>;
 	
 	ptr_extern! { crate::dnn::LogLayer,
-		cv_PtrOfLogLayer_delete, cv_PtrOfLogLayer_getInnerPtr_const, cv_PtrOfLogLayer_getInnerPtrMut
+		cv_PtrOfLogLayer_delete, cv_PtrOfLogLayer_getInnerPtr_const, cv_PtrOfLogLayer_getInnerPtrMut, cv_PtrOfLogLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::LogLayer, cv_PtrOfLogLayer_new_const_LogLayer }
@@ -7433,7 +7460,7 @@ mod dnn_types {
 	pub type PtrOfMVNLayer = core::Ptr<crate::dnn::MVNLayer>;
 	
 	ptr_extern! { crate::dnn::MVNLayer,
-		cv_PtrOfMVNLayer_delete, cv_PtrOfMVNLayer_getInnerPtr_const, cv_PtrOfMVNLayer_getInnerPtrMut
+		cv_PtrOfMVNLayer_delete, cv_PtrOfMVNLayer_getInnerPtr_const, cv_PtrOfMVNLayer_getInnerPtrMut, cv_PtrOfMVNLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::MVNLayer, cv_PtrOfMVNLayer_new_const_MVNLayer }
@@ -7484,7 +7511,7 @@ mod dnn_types {
 	pub type PtrOfMaxUnpoolLayer = core::Ptr<crate::dnn::MaxUnpoolLayer>;
 	
 	ptr_extern! { crate::dnn::MaxUnpoolLayer,
-		cv_PtrOfMaxUnpoolLayer_delete, cv_PtrOfMaxUnpoolLayer_getInnerPtr_const, cv_PtrOfMaxUnpoolLayer_getInnerPtrMut
+		cv_PtrOfMaxUnpoolLayer_delete, cv_PtrOfMaxUnpoolLayer_getInnerPtr_const, cv_PtrOfMaxUnpoolLayer_getInnerPtrMut, cv_PtrOfMaxUnpoolLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::MaxUnpoolLayer, cv_PtrOfMaxUnpoolLayer_new_const_MaxUnpoolLayer }
@@ -7535,7 +7562,7 @@ mod dnn_types {
 	pub type PtrOfMishLayer = core::Ptr<crate::dnn::MishLayer>;
 	
 	ptr_extern! { crate::dnn::MishLayer,
-		cv_PtrOfMishLayer_delete, cv_PtrOfMishLayer_getInnerPtr_const, cv_PtrOfMishLayer_getInnerPtrMut
+		cv_PtrOfMishLayer_delete, cv_PtrOfMishLayer_getInnerPtr_const, cv_PtrOfMishLayer_getInnerPtrMut, cv_PtrOfMishLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::MishLayer, cv_PtrOfMishLayer_new_const_MishLayer }
@@ -7591,7 +7618,7 @@ mod dnn_types {
 	pub type PtrOfNaryEltwiseLayer = core::Ptr<crate::dnn::NaryEltwiseLayer>;
 	
 	ptr_extern! { crate::dnn::NaryEltwiseLayer,
-		cv_PtrOfNaryEltwiseLayer_delete, cv_PtrOfNaryEltwiseLayer_getInnerPtr_const, cv_PtrOfNaryEltwiseLayer_getInnerPtrMut
+		cv_PtrOfNaryEltwiseLayer_delete, cv_PtrOfNaryEltwiseLayer_getInnerPtr_const, cv_PtrOfNaryEltwiseLayer_getInnerPtrMut, cv_PtrOfNaryEltwiseLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::NaryEltwiseLayer, cv_PtrOfNaryEltwiseLayer_new_const_NaryEltwiseLayer }
@@ -7639,7 +7666,7 @@ mod dnn_types {
 	pub type PtrOfNormalizeBBoxLayer = core::Ptr<crate::dnn::NormalizeBBoxLayer>;
 	
 	ptr_extern! { crate::dnn::NormalizeBBoxLayer,
-		cv_PtrOfNormalizeBBoxLayer_delete, cv_PtrOfNormalizeBBoxLayer_getInnerPtr_const, cv_PtrOfNormalizeBBoxLayer_getInnerPtrMut
+		cv_PtrOfNormalizeBBoxLayer_delete, cv_PtrOfNormalizeBBoxLayer_getInnerPtr_const, cv_PtrOfNormalizeBBoxLayer_getInnerPtrMut, cv_PtrOfNormalizeBBoxLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::NormalizeBBoxLayer, cv_PtrOfNormalizeBBoxLayer_new_const_NormalizeBBoxLayer }
@@ -7690,7 +7717,7 @@ mod dnn_types {
 	pub type PtrOfNotLayer = core::Ptr<crate::dnn::NotLayer>;
 	
 	ptr_extern! { crate::dnn::NotLayer,
-		cv_PtrOfNotLayer_delete, cv_PtrOfNotLayer_getInnerPtr_const, cv_PtrOfNotLayer_getInnerPtrMut
+		cv_PtrOfNotLayer_delete, cv_PtrOfNotLayer_getInnerPtr_const, cv_PtrOfNotLayer_getInnerPtrMut, cv_PtrOfNotLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::NotLayer, cv_PtrOfNotLayer_new_const_NotLayer }
@@ -7746,7 +7773,7 @@ mod dnn_types {
 	pub type PtrOfPaddingLayer = core::Ptr<crate::dnn::PaddingLayer>;
 	
 	ptr_extern! { crate::dnn::PaddingLayer,
-		cv_PtrOfPaddingLayer_delete, cv_PtrOfPaddingLayer_getInnerPtr_const, cv_PtrOfPaddingLayer_getInnerPtrMut
+		cv_PtrOfPaddingLayer_delete, cv_PtrOfPaddingLayer_getInnerPtr_const, cv_PtrOfPaddingLayer_getInnerPtrMut, cv_PtrOfPaddingLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::PaddingLayer, cv_PtrOfPaddingLayer_new_const_PaddingLayer }
@@ -7794,7 +7821,7 @@ mod dnn_types {
 	pub type PtrOfPermuteLayer = core::Ptr<crate::dnn::PermuteLayer>;
 	
 	ptr_extern! { crate::dnn::PermuteLayer,
-		cv_PtrOfPermuteLayer_delete, cv_PtrOfPermuteLayer_getInnerPtr_const, cv_PtrOfPermuteLayer_getInnerPtrMut
+		cv_PtrOfPermuteLayer_delete, cv_PtrOfPermuteLayer_getInnerPtr_const, cv_PtrOfPermuteLayer_getInnerPtrMut, cv_PtrOfPermuteLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::PermuteLayer, cv_PtrOfPermuteLayer_new_const_PermuteLayer }
@@ -7842,7 +7869,7 @@ mod dnn_types {
 	pub type PtrOfPoolingLayer = core::Ptr<crate::dnn::PoolingLayer>;
 	
 	ptr_extern! { crate::dnn::PoolingLayer,
-		cv_PtrOfPoolingLayer_delete, cv_PtrOfPoolingLayer_getInnerPtr_const, cv_PtrOfPoolingLayer_getInnerPtrMut
+		cv_PtrOfPoolingLayer_delete, cv_PtrOfPoolingLayer_getInnerPtr_const, cv_PtrOfPoolingLayer_getInnerPtrMut, cv_PtrOfPoolingLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::PoolingLayer, cv_PtrOfPoolingLayer_new_const_PoolingLayer }
@@ -7904,7 +7931,7 @@ mod dnn_types {
 	pub type PtrOfPoolingLayerInt8 = core::Ptr<crate::dnn::PoolingLayerInt8>;
 	
 	ptr_extern! { crate::dnn::PoolingLayerInt8,
-		cv_PtrOfPoolingLayerInt8_delete, cv_PtrOfPoolingLayerInt8_getInnerPtr_const, cv_PtrOfPoolingLayerInt8_getInnerPtrMut
+		cv_PtrOfPoolingLayerInt8_delete, cv_PtrOfPoolingLayerInt8_getInnerPtr_const, cv_PtrOfPoolingLayerInt8_getInnerPtrMut, cv_PtrOfPoolingLayerInt8_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::PoolingLayerInt8, cv_PtrOfPoolingLayerInt8_new_const_PoolingLayerInt8 }
@@ -7978,7 +8005,7 @@ mod dnn_types {
 	pub type PtrOfPowerLayer = core::Ptr<crate::dnn::PowerLayer>;
 	
 	ptr_extern! { crate::dnn::PowerLayer,
-		cv_PtrOfPowerLayer_delete, cv_PtrOfPowerLayer_getInnerPtr_const, cv_PtrOfPowerLayer_getInnerPtrMut
+		cv_PtrOfPowerLayer_delete, cv_PtrOfPowerLayer_getInnerPtr_const, cv_PtrOfPowerLayer_getInnerPtrMut, cv_PtrOfPowerLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::PowerLayer, cv_PtrOfPowerLayer_new_const_PowerLayer }
@@ -8037,7 +8064,7 @@ mod dnn_types {
 	pub type PtrOfPriorBoxLayer = core::Ptr<crate::dnn::PriorBoxLayer>;
 	
 	ptr_extern! { crate::dnn::PriorBoxLayer,
-		cv_PtrOfPriorBoxLayer_delete, cv_PtrOfPriorBoxLayer_getInnerPtr_const, cv_PtrOfPriorBoxLayer_getInnerPtrMut
+		cv_PtrOfPriorBoxLayer_delete, cv_PtrOfPriorBoxLayer_getInnerPtr_const, cv_PtrOfPriorBoxLayer_getInnerPtrMut, cv_PtrOfPriorBoxLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::PriorBoxLayer, cv_PtrOfPriorBoxLayer_new_const_PriorBoxLayer }
@@ -8085,7 +8112,7 @@ mod dnn_types {
 	pub type PtrOfProposalLayer = core::Ptr<crate::dnn::ProposalLayer>;
 	
 	ptr_extern! { crate::dnn::ProposalLayer,
-		cv_PtrOfProposalLayer_delete, cv_PtrOfProposalLayer_getInnerPtr_const, cv_PtrOfProposalLayer_getInnerPtrMut
+		cv_PtrOfProposalLayer_delete, cv_PtrOfProposalLayer_getInnerPtr_const, cv_PtrOfProposalLayer_getInnerPtrMut, cv_PtrOfProposalLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ProposalLayer, cv_PtrOfProposalLayer_new_const_ProposalLayer }
@@ -8133,7 +8160,7 @@ mod dnn_types {
 	pub type PtrOfQuantizeLayer = core::Ptr<crate::dnn::QuantizeLayer>;
 	
 	ptr_extern! { crate::dnn::QuantizeLayer,
-		cv_PtrOfQuantizeLayer_delete, cv_PtrOfQuantizeLayer_getInnerPtr_const, cv_PtrOfQuantizeLayer_getInnerPtrMut
+		cv_PtrOfQuantizeLayer_delete, cv_PtrOfQuantizeLayer_getInnerPtr_const, cv_PtrOfQuantizeLayer_getInnerPtrMut, cv_PtrOfQuantizeLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::QuantizeLayer, cv_PtrOfQuantizeLayer_new_const_QuantizeLayer }
@@ -8183,7 +8210,7 @@ mod dnn_types {
 	pub type PtrOfRNNLayer = core::Ptr<crate::dnn::RNNLayer>;
 	
 	ptr_extern! { crate::dnn::RNNLayer,
-		cv_PtrOfRNNLayer_delete, cv_PtrOfRNNLayer_getInnerPtr_const, cv_PtrOfRNNLayer_getInnerPtrMut
+		cv_PtrOfRNNLayer_delete, cv_PtrOfRNNLayer_getInnerPtr_const, cv_PtrOfRNNLayer_getInnerPtrMut, cv_PtrOfRNNLayer_clone_const
 	}
 	
 	impl core::Ptr<crate::dnn::RNNLayer> {
@@ -8230,7 +8257,7 @@ mod dnn_types {
 	pub type PtrOfReLU6Layer = core::Ptr<crate::dnn::ReLU6Layer>;
 	
 	ptr_extern! { crate::dnn::ReLU6Layer,
-		cv_PtrOfReLU6Layer_delete, cv_PtrOfReLU6Layer_getInnerPtr_const, cv_PtrOfReLU6Layer_getInnerPtrMut
+		cv_PtrOfReLU6Layer_delete, cv_PtrOfReLU6Layer_getInnerPtr_const, cv_PtrOfReLU6Layer_getInnerPtrMut, cv_PtrOfReLU6Layer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ReLU6Layer, cv_PtrOfReLU6Layer_new_const_ReLU6Layer }
@@ -8288,7 +8315,7 @@ mod dnn_types {
 	pub type PtrOfReLULayer = core::Ptr<crate::dnn::ReLULayer>;
 	
 	ptr_extern! { crate::dnn::ReLULayer,
-		cv_PtrOfReLULayer_delete, cv_PtrOfReLULayer_getInnerPtr_const, cv_PtrOfReLULayer_getInnerPtrMut
+		cv_PtrOfReLULayer_delete, cv_PtrOfReLULayer_getInnerPtr_const, cv_PtrOfReLULayer_getInnerPtrMut, cv_PtrOfReLULayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ReLULayer, cv_PtrOfReLULayer_new_const_ReLULayer }
@@ -8345,7 +8372,7 @@ mod dnn_types {
 	pub type PtrOfReciprocalLayer = core::Ptr<crate::dnn::ReciprocalLayer>;
 	
 	ptr_extern! { crate::dnn::ReciprocalLayer,
-		cv_PtrOfReciprocalLayer_delete, cv_PtrOfReciprocalLayer_getInnerPtr_const, cv_PtrOfReciprocalLayer_getInnerPtrMut
+		cv_PtrOfReciprocalLayer_delete, cv_PtrOfReciprocalLayer_getInnerPtr_const, cv_PtrOfReciprocalLayer_getInnerPtrMut, cv_PtrOfReciprocalLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ReciprocalLayer, cv_PtrOfReciprocalLayer_new_const_ReciprocalLayer }
@@ -8401,7 +8428,7 @@ mod dnn_types {
 	pub type PtrOfReduceLayer = core::Ptr<crate::dnn::ReduceLayer>;
 	
 	ptr_extern! { crate::dnn::ReduceLayer,
-		cv_PtrOfReduceLayer_delete, cv_PtrOfReduceLayer_getInnerPtr_const, cv_PtrOfReduceLayer_getInnerPtrMut
+		cv_PtrOfReduceLayer_delete, cv_PtrOfReduceLayer_getInnerPtr_const, cv_PtrOfReduceLayer_getInnerPtrMut, cv_PtrOfReduceLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ReduceLayer, cv_PtrOfReduceLayer_new_const_ReduceLayer }
@@ -8449,7 +8476,7 @@ mod dnn_types {
 	pub type PtrOfRegionLayer = core::Ptr<crate::dnn::RegionLayer>;
 	
 	ptr_extern! { crate::dnn::RegionLayer,
-		cv_PtrOfRegionLayer_delete, cv_PtrOfRegionLayer_getInnerPtr_const, cv_PtrOfRegionLayer_getInnerPtrMut
+		cv_PtrOfRegionLayer_delete, cv_PtrOfRegionLayer_getInnerPtr_const, cv_PtrOfRegionLayer_getInnerPtrMut, cv_PtrOfRegionLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::RegionLayer, cv_PtrOfRegionLayer_new_const_RegionLayer }
@@ -8498,7 +8525,7 @@ mod dnn_types {
 	pub type PtrOfReorgLayer = core::Ptr<crate::dnn::ReorgLayer>;
 	
 	ptr_extern! { crate::dnn::ReorgLayer,
-		cv_PtrOfReorgLayer_delete, cv_PtrOfReorgLayer_getInnerPtr_const, cv_PtrOfReorgLayer_getInnerPtrMut
+		cv_PtrOfReorgLayer_delete, cv_PtrOfReorgLayer_getInnerPtr_const, cv_PtrOfReorgLayer_getInnerPtrMut, cv_PtrOfReorgLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ReorgLayer, cv_PtrOfReorgLayer_new_const_ReorgLayer }
@@ -8546,7 +8573,7 @@ mod dnn_types {
 	pub type PtrOfRequantizeLayer = core::Ptr<crate::dnn::RequantizeLayer>;
 	
 	ptr_extern! { crate::dnn::RequantizeLayer,
-		cv_PtrOfRequantizeLayer_delete, cv_PtrOfRequantizeLayer_getInnerPtr_const, cv_PtrOfRequantizeLayer_getInnerPtrMut
+		cv_PtrOfRequantizeLayer_delete, cv_PtrOfRequantizeLayer_getInnerPtr_const, cv_PtrOfRequantizeLayer_getInnerPtrMut, cv_PtrOfRequantizeLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::RequantizeLayer, cv_PtrOfRequantizeLayer_new_const_RequantizeLayer }
@@ -8596,7 +8623,7 @@ mod dnn_types {
 	pub type PtrOfReshapeLayer = core::Ptr<crate::dnn::ReshapeLayer>;
 	
 	ptr_extern! { crate::dnn::ReshapeLayer,
-		cv_PtrOfReshapeLayer_delete, cv_PtrOfReshapeLayer_getInnerPtr_const, cv_PtrOfReshapeLayer_getInnerPtrMut
+		cv_PtrOfReshapeLayer_delete, cv_PtrOfReshapeLayer_getInnerPtr_const, cv_PtrOfReshapeLayer_getInnerPtrMut, cv_PtrOfReshapeLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ReshapeLayer, cv_PtrOfReshapeLayer_new_const_ReshapeLayer }
@@ -8646,7 +8673,7 @@ mod dnn_types {
 	pub type PtrOfResizeLayer = core::Ptr<crate::dnn::ResizeLayer>;
 	
 	ptr_extern! { crate::dnn::ResizeLayer,
-		cv_PtrOfResizeLayer_delete, cv_PtrOfResizeLayer_getInnerPtr_const, cv_PtrOfResizeLayer_getInnerPtrMut
+		cv_PtrOfResizeLayer_delete, cv_PtrOfResizeLayer_getInnerPtr_const, cv_PtrOfResizeLayer_getInnerPtrMut, cv_PtrOfResizeLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ResizeLayer, cv_PtrOfResizeLayer_new_const_ResizeLayer }
@@ -8694,7 +8721,7 @@ mod dnn_types {
 	pub type PtrOfRoundLayer = core::Ptr<crate::dnn::RoundLayer>;
 	
 	ptr_extern! { crate::dnn::RoundLayer,
-		cv_PtrOfRoundLayer_delete, cv_PtrOfRoundLayer_getInnerPtr_const, cv_PtrOfRoundLayer_getInnerPtrMut
+		cv_PtrOfRoundLayer_delete, cv_PtrOfRoundLayer_getInnerPtr_const, cv_PtrOfRoundLayer_getInnerPtrMut, cv_PtrOfRoundLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::RoundLayer, cv_PtrOfRoundLayer_new_const_RoundLayer }
@@ -8750,7 +8777,7 @@ mod dnn_types {
 	pub type PtrOfScaleLayer = core::Ptr<crate::dnn::ScaleLayer>;
 	
 	ptr_extern! { crate::dnn::ScaleLayer,
-		cv_PtrOfScaleLayer_delete, cv_PtrOfScaleLayer_getInnerPtr_const, cv_PtrOfScaleLayer_getInnerPtrMut
+		cv_PtrOfScaleLayer_delete, cv_PtrOfScaleLayer_getInnerPtr_const, cv_PtrOfScaleLayer_getInnerPtrMut, cv_PtrOfScaleLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ScaleLayer, cv_PtrOfScaleLayer_new_const_ScaleLayer }
@@ -8801,7 +8828,7 @@ mod dnn_types {
 	pub type PtrOfScaleLayerInt8 = core::Ptr<crate::dnn::ScaleLayerInt8>;
 	
 	ptr_extern! { crate::dnn::ScaleLayerInt8,
-		cv_PtrOfScaleLayerInt8_delete, cv_PtrOfScaleLayerInt8_getInnerPtr_const, cv_PtrOfScaleLayerInt8_getInnerPtrMut
+		cv_PtrOfScaleLayerInt8_delete, cv_PtrOfScaleLayerInt8_getInnerPtr_const, cv_PtrOfScaleLayerInt8_getInnerPtrMut, cv_PtrOfScaleLayerInt8_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ScaleLayerInt8, cv_PtrOfScaleLayerInt8_new_const_ScaleLayerInt8 }
@@ -8862,7 +8889,7 @@ mod dnn_types {
 	pub type PtrOfScatterLayer = core::Ptr<crate::dnn::ScatterLayer>;
 	
 	ptr_extern! { crate::dnn::ScatterLayer,
-		cv_PtrOfScatterLayer_delete, cv_PtrOfScatterLayer_getInnerPtr_const, cv_PtrOfScatterLayer_getInnerPtrMut
+		cv_PtrOfScatterLayer_delete, cv_PtrOfScatterLayer_getInnerPtr_const, cv_PtrOfScatterLayer_getInnerPtrMut, cv_PtrOfScatterLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ScatterLayer, cv_PtrOfScatterLayer_new_const_ScatterLayer }
@@ -8910,7 +8937,7 @@ mod dnn_types {
 	pub type PtrOfScatterNDLayer = core::Ptr<crate::dnn::ScatterNDLayer>;
 	
 	ptr_extern! { crate::dnn::ScatterNDLayer,
-		cv_PtrOfScatterNDLayer_delete, cv_PtrOfScatterNDLayer_getInnerPtr_const, cv_PtrOfScatterNDLayer_getInnerPtrMut
+		cv_PtrOfScatterNDLayer_delete, cv_PtrOfScatterNDLayer_getInnerPtr_const, cv_PtrOfScatterNDLayer_getInnerPtrMut, cv_PtrOfScatterNDLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ScatterNDLayer, cv_PtrOfScatterNDLayer_new_const_ScatterNDLayer }
@@ -8958,7 +8985,7 @@ mod dnn_types {
 	pub type PtrOfSeluLayer = core::Ptr<crate::dnn::SeluLayer>;
 	
 	ptr_extern! { crate::dnn::SeluLayer,
-		cv_PtrOfSeluLayer_delete, cv_PtrOfSeluLayer_getInnerPtr_const, cv_PtrOfSeluLayer_getInnerPtrMut
+		cv_PtrOfSeluLayer_delete, cv_PtrOfSeluLayer_getInnerPtr_const, cv_PtrOfSeluLayer_getInnerPtrMut, cv_PtrOfSeluLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::SeluLayer, cv_PtrOfSeluLayer_new_const_SeluLayer }
@@ -9016,7 +9043,7 @@ mod dnn_types {
 	pub type PtrOfShrinkLayer = core::Ptr<crate::dnn::ShrinkLayer>;
 	
 	ptr_extern! { crate::dnn::ShrinkLayer,
-		cv_PtrOfShrinkLayer_delete, cv_PtrOfShrinkLayer_getInnerPtr_const, cv_PtrOfShrinkLayer_getInnerPtrMut
+		cv_PtrOfShrinkLayer_delete, cv_PtrOfShrinkLayer_getInnerPtr_const, cv_PtrOfShrinkLayer_getInnerPtrMut, cv_PtrOfShrinkLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ShrinkLayer, cv_PtrOfShrinkLayer_new_const_ShrinkLayer }
@@ -9074,7 +9101,7 @@ mod dnn_types {
 	pub type PtrOfSigmoidLayer = core::Ptr<crate::dnn::SigmoidLayer>;
 	
 	ptr_extern! { crate::dnn::SigmoidLayer,
-		cv_PtrOfSigmoidLayer_delete, cv_PtrOfSigmoidLayer_getInnerPtr_const, cv_PtrOfSigmoidLayer_getInnerPtrMut
+		cv_PtrOfSigmoidLayer_delete, cv_PtrOfSigmoidLayer_getInnerPtr_const, cv_PtrOfSigmoidLayer_getInnerPtrMut, cv_PtrOfSigmoidLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::SigmoidLayer, cv_PtrOfSigmoidLayer_new_const_SigmoidLayer }
@@ -9130,7 +9157,7 @@ mod dnn_types {
 	pub type PtrOfSignLayer = core::Ptr<crate::dnn::SignLayer>;
 	
 	ptr_extern! { crate::dnn::SignLayer,
-		cv_PtrOfSignLayer_delete, cv_PtrOfSignLayer_getInnerPtr_const, cv_PtrOfSignLayer_getInnerPtrMut
+		cv_PtrOfSignLayer_delete, cv_PtrOfSignLayer_getInnerPtr_const, cv_PtrOfSignLayer_getInnerPtrMut, cv_PtrOfSignLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::SignLayer, cv_PtrOfSignLayer_new_const_SignLayer }
@@ -9186,7 +9213,7 @@ mod dnn_types {
 	pub type PtrOfSinLayer = core::Ptr<crate::dnn::SinLayer>;
 	
 	ptr_extern! { crate::dnn::SinLayer,
-		cv_PtrOfSinLayer_delete, cv_PtrOfSinLayer_getInnerPtr_const, cv_PtrOfSinLayer_getInnerPtrMut
+		cv_PtrOfSinLayer_delete, cv_PtrOfSinLayer_getInnerPtr_const, cv_PtrOfSinLayer_getInnerPtrMut, cv_PtrOfSinLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::SinLayer, cv_PtrOfSinLayer_new_const_SinLayer }
@@ -9242,7 +9269,7 @@ mod dnn_types {
 	pub type PtrOfSinhLayer = core::Ptr<crate::dnn::SinhLayer>;
 	
 	ptr_extern! { crate::dnn::SinhLayer,
-		cv_PtrOfSinhLayer_delete, cv_PtrOfSinhLayer_getInnerPtr_const, cv_PtrOfSinhLayer_getInnerPtrMut
+		cv_PtrOfSinhLayer_delete, cv_PtrOfSinhLayer_getInnerPtr_const, cv_PtrOfSinhLayer_getInnerPtrMut, cv_PtrOfSinhLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::SinhLayer, cv_PtrOfSinhLayer_new_const_SinhLayer }
@@ -9298,7 +9325,7 @@ mod dnn_types {
 	pub type PtrOfSliceLayer = core::Ptr<crate::dnn::SliceLayer>;
 	
 	ptr_extern! { crate::dnn::SliceLayer,
-		cv_PtrOfSliceLayer_delete, cv_PtrOfSliceLayer_getInnerPtr_const, cv_PtrOfSliceLayer_getInnerPtrMut
+		cv_PtrOfSliceLayer_delete, cv_PtrOfSliceLayer_getInnerPtr_const, cv_PtrOfSliceLayer_getInnerPtrMut, cv_PtrOfSliceLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::SliceLayer, cv_PtrOfSliceLayer_new_const_SliceLayer }
@@ -9350,7 +9377,7 @@ mod dnn_types {
 	pub type PtrOfSoftmaxLayer = core::Ptr<crate::dnn::SoftmaxLayer>;
 	
 	ptr_extern! { crate::dnn::SoftmaxLayer,
-		cv_PtrOfSoftmaxLayer_delete, cv_PtrOfSoftmaxLayer_getInnerPtr_const, cv_PtrOfSoftmaxLayer_getInnerPtrMut
+		cv_PtrOfSoftmaxLayer_delete, cv_PtrOfSoftmaxLayer_getInnerPtr_const, cv_PtrOfSoftmaxLayer_getInnerPtrMut, cv_PtrOfSoftmaxLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::SoftmaxLayer, cv_PtrOfSoftmaxLayer_new_const_SoftmaxLayer }
@@ -9399,7 +9426,7 @@ mod dnn_types {
 	pub type PtrOfSoftmaxLayerInt8 = core::Ptr<crate::dnn::SoftmaxLayerInt8>;
 	
 	ptr_extern! { crate::dnn::SoftmaxLayerInt8,
-		cv_PtrOfSoftmaxLayerInt8_delete, cv_PtrOfSoftmaxLayerInt8_getInnerPtr_const, cv_PtrOfSoftmaxLayerInt8_getInnerPtrMut
+		cv_PtrOfSoftmaxLayerInt8_delete, cv_PtrOfSoftmaxLayerInt8_getInnerPtr_const, cv_PtrOfSoftmaxLayerInt8_getInnerPtrMut, cv_PtrOfSoftmaxLayerInt8_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::SoftmaxLayerInt8, cv_PtrOfSoftmaxLayerInt8_new_const_SoftmaxLayerInt8 }
@@ -9458,7 +9485,7 @@ mod dnn_types {
 	pub type PtrOfSoftplusLayer = core::Ptr<crate::dnn::SoftplusLayer>;
 	
 	ptr_extern! { crate::dnn::SoftplusLayer,
-		cv_PtrOfSoftplusLayer_delete, cv_PtrOfSoftplusLayer_getInnerPtr_const, cv_PtrOfSoftplusLayer_getInnerPtrMut
+		cv_PtrOfSoftplusLayer_delete, cv_PtrOfSoftplusLayer_getInnerPtr_const, cv_PtrOfSoftplusLayer_getInnerPtrMut, cv_PtrOfSoftplusLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::SoftplusLayer, cv_PtrOfSoftplusLayer_new_const_SoftplusLayer }
@@ -9514,7 +9541,7 @@ mod dnn_types {
 	pub type PtrOfSoftsignLayer = core::Ptr<crate::dnn::SoftsignLayer>;
 	
 	ptr_extern! { crate::dnn::SoftsignLayer,
-		cv_PtrOfSoftsignLayer_delete, cv_PtrOfSoftsignLayer_getInnerPtr_const, cv_PtrOfSoftsignLayer_getInnerPtrMut
+		cv_PtrOfSoftsignLayer_delete, cv_PtrOfSoftsignLayer_getInnerPtr_const, cv_PtrOfSoftsignLayer_getInnerPtrMut, cv_PtrOfSoftsignLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::SoftsignLayer, cv_PtrOfSoftsignLayer_new_const_SoftsignLayer }
@@ -9570,7 +9597,7 @@ mod dnn_types {
 	pub type PtrOfSplitLayer = core::Ptr<crate::dnn::SplitLayer>;
 	
 	ptr_extern! { crate::dnn::SplitLayer,
-		cv_PtrOfSplitLayer_delete, cv_PtrOfSplitLayer_getInnerPtr_const, cv_PtrOfSplitLayer_getInnerPtrMut
+		cv_PtrOfSplitLayer_delete, cv_PtrOfSplitLayer_getInnerPtr_const, cv_PtrOfSplitLayer_getInnerPtrMut, cv_PtrOfSplitLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::SplitLayer, cv_PtrOfSplitLayer_new_const_SplitLayer }
@@ -9619,7 +9646,7 @@ mod dnn_types {
 	pub type PtrOfSqrtLayer = core::Ptr<crate::dnn::SqrtLayer>;
 	
 	ptr_extern! { crate::dnn::SqrtLayer,
-		cv_PtrOfSqrtLayer_delete, cv_PtrOfSqrtLayer_getInnerPtr_const, cv_PtrOfSqrtLayer_getInnerPtrMut
+		cv_PtrOfSqrtLayer_delete, cv_PtrOfSqrtLayer_getInnerPtr_const, cv_PtrOfSqrtLayer_getInnerPtrMut, cv_PtrOfSqrtLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::SqrtLayer, cv_PtrOfSqrtLayer_new_const_SqrtLayer }
@@ -9675,7 +9702,7 @@ mod dnn_types {
 	pub type PtrOfSwishLayer = core::Ptr<crate::dnn::SwishLayer>;
 	
 	ptr_extern! { crate::dnn::SwishLayer,
-		cv_PtrOfSwishLayer_delete, cv_PtrOfSwishLayer_getInnerPtr_const, cv_PtrOfSwishLayer_getInnerPtrMut
+		cv_PtrOfSwishLayer_delete, cv_PtrOfSwishLayer_getInnerPtr_const, cv_PtrOfSwishLayer_getInnerPtrMut, cv_PtrOfSwishLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::SwishLayer, cv_PtrOfSwishLayer_new_const_SwishLayer }
@@ -9731,7 +9758,7 @@ mod dnn_types {
 	pub type PtrOfTanHLayer = core::Ptr<crate::dnn::TanHLayer>;
 	
 	ptr_extern! { crate::dnn::TanHLayer,
-		cv_PtrOfTanHLayer_delete, cv_PtrOfTanHLayer_getInnerPtr_const, cv_PtrOfTanHLayer_getInnerPtrMut
+		cv_PtrOfTanHLayer_delete, cv_PtrOfTanHLayer_getInnerPtr_const, cv_PtrOfTanHLayer_getInnerPtrMut, cv_PtrOfTanHLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::TanHLayer, cv_PtrOfTanHLayer_new_const_TanHLayer }
@@ -9787,7 +9814,7 @@ mod dnn_types {
 	pub type PtrOfTanLayer = core::Ptr<crate::dnn::TanLayer>;
 	
 	ptr_extern! { crate::dnn::TanLayer,
-		cv_PtrOfTanLayer_delete, cv_PtrOfTanLayer_getInnerPtr_const, cv_PtrOfTanLayer_getInnerPtrMut
+		cv_PtrOfTanLayer_delete, cv_PtrOfTanLayer_getInnerPtr_const, cv_PtrOfTanLayer_getInnerPtrMut, cv_PtrOfTanLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::TanLayer, cv_PtrOfTanLayer_new_const_TanLayer }
@@ -9843,7 +9870,7 @@ mod dnn_types {
 	pub type PtrOfThresholdedReluLayer = core::Ptr<crate::dnn::ThresholdedReluLayer>;
 	
 	ptr_extern! { crate::dnn::ThresholdedReluLayer,
-		cv_PtrOfThresholdedReluLayer_delete, cv_PtrOfThresholdedReluLayer_getInnerPtr_const, cv_PtrOfThresholdedReluLayer_getInnerPtrMut
+		cv_PtrOfThresholdedReluLayer_delete, cv_PtrOfThresholdedReluLayer_getInnerPtr_const, cv_PtrOfThresholdedReluLayer_getInnerPtrMut, cv_PtrOfThresholdedReluLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::ThresholdedReluLayer, cv_PtrOfThresholdedReluLayer_new_const_ThresholdedReluLayer }
@@ -9900,7 +9927,7 @@ mod dnn_types {
 	pub type PtrOfTileLayer = core::Ptr<crate::dnn::TileLayer>;
 	
 	ptr_extern! { crate::dnn::TileLayer,
-		cv_PtrOfTileLayer_delete, cv_PtrOfTileLayer_getInnerPtr_const, cv_PtrOfTileLayer_getInnerPtrMut
+		cv_PtrOfTileLayer_delete, cv_PtrOfTileLayer_getInnerPtr_const, cv_PtrOfTileLayer_getInnerPtrMut, cv_PtrOfTileLayer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn::TileLayer, cv_PtrOfTileLayer_new_const_TileLayer }
@@ -10087,7 +10114,7 @@ mod dnn_superres_types {
 	pub type PtrOfDnnSuperResImpl = core::Ptr<crate::dnn_superres::DnnSuperResImpl>;
 	
 	ptr_extern! { crate::dnn_superres::DnnSuperResImpl,
-		cv_PtrOfDnnSuperResImpl_delete, cv_PtrOfDnnSuperResImpl_getInnerPtr_const, cv_PtrOfDnnSuperResImpl_getInnerPtrMut
+		cv_PtrOfDnnSuperResImpl_delete, cv_PtrOfDnnSuperResImpl_getInnerPtr_const, cv_PtrOfDnnSuperResImpl_getInnerPtrMut, cv_PtrOfDnnSuperResImpl_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::dnn_superres::DnnSuperResImpl, cv_PtrOfDnnSuperResImpl_new_const_DnnSuperResImpl }
@@ -10123,7 +10150,7 @@ mod dpm_types {
 	pub type PtrOfDPMDetector = core::Ptr<crate::dpm::DPMDetector>;
 	
 	ptr_extern! { crate::dpm::DPMDetector,
-		cv_PtrOfDPMDetector_delete, cv_PtrOfDPMDetector_getInnerPtr_const, cv_PtrOfDPMDetector_getInnerPtrMut
+		cv_PtrOfDPMDetector_delete, cv_PtrOfDPMDetector_getInnerPtr_const, cv_PtrOfDPMDetector_getInnerPtrMut, cv_PtrOfDPMDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::dpm::DPMDetector> {
@@ -10176,7 +10203,7 @@ mod face_types {
 	pub type PtrOfBIF = core::Ptr<crate::face::BIF>;
 	
 	ptr_extern! { crate::face::BIF,
-		cv_PtrOfBIF_delete, cv_PtrOfBIF_getInnerPtr_const, cv_PtrOfBIF_getInnerPtrMut
+		cv_PtrOfBIF_delete, cv_PtrOfBIF_getInnerPtr_const, cv_PtrOfBIF_getInnerPtrMut, cv_PtrOfBIF_clone_const
 	}
 	
 	impl core::Ptr<crate::face::BIF> {
@@ -10211,7 +10238,7 @@ mod face_types {
 	pub type PtrOfEigenFaceRecognizer = core::Ptr<crate::face::EigenFaceRecognizer>;
 	
 	ptr_extern! { crate::face::EigenFaceRecognizer,
-		cv_PtrOfEigenFaceRecognizer_delete, cv_PtrOfEigenFaceRecognizer_getInnerPtr_const, cv_PtrOfEigenFaceRecognizer_getInnerPtrMut
+		cv_PtrOfEigenFaceRecognizer_delete, cv_PtrOfEigenFaceRecognizer_getInnerPtr_const, cv_PtrOfEigenFaceRecognizer_getInnerPtrMut, cv_PtrOfEigenFaceRecognizer_clone_const
 	}
 	
 	impl core::Ptr<crate::face::EigenFaceRecognizer> {
@@ -10262,7 +10289,7 @@ mod face_types {
 	pub type PtrOfFacemark = core::Ptr<crate::face::Facemark>;
 	
 	ptr_extern! { crate::face::Facemark,
-		cv_PtrOfFacemark_delete, cv_PtrOfFacemark_getInnerPtr_const, cv_PtrOfFacemark_getInnerPtrMut
+		cv_PtrOfFacemark_delete, cv_PtrOfFacemark_getInnerPtr_const, cv_PtrOfFacemark_getInnerPtrMut, cv_PtrOfFacemark_clone_const
 	}
 	
 	impl core::Ptr<crate::face::Facemark> {
@@ -10297,7 +10324,7 @@ mod face_types {
 	pub type PtrOfFacemarkAAM = core::Ptr<crate::face::FacemarkAAM>;
 	
 	ptr_extern! { crate::face::FacemarkAAM,
-		cv_PtrOfFacemarkAAM_delete, cv_PtrOfFacemarkAAM_getInnerPtr_const, cv_PtrOfFacemarkAAM_getInnerPtrMut
+		cv_PtrOfFacemarkAAM_delete, cv_PtrOfFacemarkAAM_getInnerPtr_const, cv_PtrOfFacemarkAAM_getInnerPtrMut, cv_PtrOfFacemarkAAM_clone_const
 	}
 	
 	impl core::Ptr<crate::face::FacemarkAAM> {
@@ -10348,7 +10375,7 @@ mod face_types {
 	pub type PtrOfFacemarkKazemi = core::Ptr<crate::face::FacemarkKazemi>;
 	
 	ptr_extern! { crate::face::FacemarkKazemi,
-		cv_PtrOfFacemarkKazemi_delete, cv_PtrOfFacemarkKazemi_getInnerPtr_const, cv_PtrOfFacemarkKazemi_getInnerPtrMut
+		cv_PtrOfFacemarkKazemi_delete, cv_PtrOfFacemarkKazemi_getInnerPtr_const, cv_PtrOfFacemarkKazemi_getInnerPtrMut, cv_PtrOfFacemarkKazemi_clone_const
 	}
 	
 	impl core::Ptr<crate::face::FacemarkKazemi> {
@@ -10391,7 +10418,7 @@ mod face_types {
 	pub type PtrOfFacemarkLBF = core::Ptr<crate::face::FacemarkLBF>;
 	
 	ptr_extern! { crate::face::FacemarkLBF,
-		cv_PtrOfFacemarkLBF_delete, cv_PtrOfFacemarkLBF_getInnerPtr_const, cv_PtrOfFacemarkLBF_getInnerPtrMut
+		cv_PtrOfFacemarkLBF_delete, cv_PtrOfFacemarkLBF_getInnerPtr_const, cv_PtrOfFacemarkLBF_getInnerPtrMut, cv_PtrOfFacemarkLBF_clone_const
 	}
 	
 	impl core::Ptr<crate::face::FacemarkLBF> {
@@ -10442,7 +10469,7 @@ mod face_types {
 	pub type PtrOfFisherFaceRecognizer = core::Ptr<crate::face::FisherFaceRecognizer>;
 	
 	ptr_extern! { crate::face::FisherFaceRecognizer,
-		cv_PtrOfFisherFaceRecognizer_delete, cv_PtrOfFisherFaceRecognizer_getInnerPtr_const, cv_PtrOfFisherFaceRecognizer_getInnerPtrMut
+		cv_PtrOfFisherFaceRecognizer_delete, cv_PtrOfFisherFaceRecognizer_getInnerPtr_const, cv_PtrOfFisherFaceRecognizer_getInnerPtrMut, cv_PtrOfFisherFaceRecognizer_clone_const
 	}
 	
 	impl core::Ptr<crate::face::FisherFaceRecognizer> {
@@ -10493,7 +10520,7 @@ mod face_types {
 	pub type PtrOfLBPHFaceRecognizer = core::Ptr<crate::face::LBPHFaceRecognizer>;
 	
 	ptr_extern! { crate::face::LBPHFaceRecognizer,
-		cv_PtrOfLBPHFaceRecognizer_delete, cv_PtrOfLBPHFaceRecognizer_getInnerPtr_const, cv_PtrOfLBPHFaceRecognizer_getInnerPtrMut
+		cv_PtrOfLBPHFaceRecognizer_delete, cv_PtrOfLBPHFaceRecognizer_getInnerPtr_const, cv_PtrOfLBPHFaceRecognizer_getInnerPtrMut, cv_PtrOfLBPHFaceRecognizer_clone_const
 	}
 	
 	impl core::Ptr<crate::face::LBPHFaceRecognizer> {
@@ -10536,7 +10563,7 @@ mod face_types {
 	pub type PtrOfMACE = core::Ptr<crate::face::MACE>;
 	
 	ptr_extern! { crate::face::MACE,
-		cv_PtrOfMACE_delete, cv_PtrOfMACE_getInnerPtr_const, cv_PtrOfMACE_getInnerPtrMut
+		cv_PtrOfMACE_delete, cv_PtrOfMACE_getInnerPtr_const, cv_PtrOfMACE_getInnerPtrMut, cv_PtrOfMACE_clone_const
 	}
 	
 	impl core::Ptr<crate::face::MACE> {
@@ -10571,7 +10598,7 @@ mod face_types {
 	pub type PtrOfPredictCollector = core::Ptr<crate::face::PredictCollector>;
 	
 	ptr_extern! { crate::face::PredictCollector,
-		cv_PtrOfPredictCollector_delete, cv_PtrOfPredictCollector_getInnerPtr_const, cv_PtrOfPredictCollector_getInnerPtrMut
+		cv_PtrOfPredictCollector_delete, cv_PtrOfPredictCollector_getInnerPtr_const, cv_PtrOfPredictCollector_getInnerPtrMut, cv_PtrOfPredictCollector_clone_const
 	}
 	
 	impl core::Ptr<crate::face::PredictCollector> {
@@ -10598,7 +10625,7 @@ mod face_types {
 	pub type PtrOfStandardCollector = core::Ptr<crate::face::StandardCollector>;
 	
 	ptr_extern! { crate::face::StandardCollector,
-		cv_PtrOfStandardCollector_delete, cv_PtrOfStandardCollector_getInnerPtr_const, cv_PtrOfStandardCollector_getInnerPtrMut
+		cv_PtrOfStandardCollector_delete, cv_PtrOfStandardCollector_getInnerPtr_const, cv_PtrOfStandardCollector_getInnerPtrMut, cv_PtrOfStandardCollector_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::face::StandardCollector, cv_PtrOfStandardCollector_new_const_StandardCollector }
@@ -10625,6 +10652,7 @@ mod face_types {
 	
 	ptr_cast_base! { PtrOfStandardCollector, core::Ptr<crate::face::PredictCollector>, cv_PtrOfStandardCollector_to_PtrOfPredictCollector }
 	
+	ptr_cast_descendant! { core::Ptr<crate::face::PredictCollector>, PtrOfStandardCollector, cv_PtrOfPredictCollector_to_PtrOfStandardCollector }
 	impl std::fmt::Debug for core::Ptr<crate::face::StandardCollector> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -10680,7 +10708,7 @@ mod features2d_types {
 	pub type PtrOfAKAZE = core::Ptr<crate::features2d::AKAZE>;
 	
 	ptr_extern! { crate::features2d::AKAZE,
-		cv_PtrOfAKAZE_delete, cv_PtrOfAKAZE_getInnerPtr_const, cv_PtrOfAKAZE_getInnerPtrMut
+		cv_PtrOfAKAZE_delete, cv_PtrOfAKAZE_getInnerPtr_const, cv_PtrOfAKAZE_getInnerPtrMut, cv_PtrOfAKAZE_clone_const
 	}
 	
 	impl core::Ptr<crate::features2d::AKAZE> {
@@ -10714,6 +10742,7 @@ mod features2d_types {
 	
 	ptr_cast_base! { PtrOfAKAZE, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfAKAZE_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfAKAZE, cv_PtrOfFeature2D_to_PtrOfAKAZE }
 	impl std::fmt::Debug for core::Ptr<crate::features2d::AKAZE> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -10725,7 +10754,7 @@ mod features2d_types {
 	pub type PtrOfAffineFeature = core::Ptr<crate::features2d::AffineFeature>;
 	
 	ptr_extern! { crate::features2d::AffineFeature,
-		cv_PtrOfAffineFeature_delete, cv_PtrOfAffineFeature_getInnerPtr_const, cv_PtrOfAffineFeature_getInnerPtrMut
+		cv_PtrOfAffineFeature_delete, cv_PtrOfAffineFeature_getInnerPtr_const, cv_PtrOfAffineFeature_getInnerPtrMut, cv_PtrOfAffineFeature_clone_const
 	}
 	
 	impl core::Ptr<crate::features2d::AffineFeature> {
@@ -10759,6 +10788,7 @@ mod features2d_types {
 	
 	ptr_cast_base! { PtrOfAffineFeature, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfAffineFeature_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfAffineFeature, cv_PtrOfFeature2D_to_PtrOfAffineFeature }
 	impl std::fmt::Debug for core::Ptr<crate::features2d::AffineFeature> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -10770,7 +10800,7 @@ mod features2d_types {
 	pub type PtrOfAgastFeatureDetector = core::Ptr<crate::features2d::AgastFeatureDetector>;
 	
 	ptr_extern! { crate::features2d::AgastFeatureDetector,
-		cv_PtrOfAgastFeatureDetector_delete, cv_PtrOfAgastFeatureDetector_getInnerPtr_const, cv_PtrOfAgastFeatureDetector_getInnerPtrMut
+		cv_PtrOfAgastFeatureDetector_delete, cv_PtrOfAgastFeatureDetector_getInnerPtr_const, cv_PtrOfAgastFeatureDetector_getInnerPtrMut, cv_PtrOfAgastFeatureDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::features2d::AgastFeatureDetector> {
@@ -10804,6 +10834,7 @@ mod features2d_types {
 	
 	ptr_cast_base! { PtrOfAgastFeatureDetector, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfAgastFeatureDetector_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfAgastFeatureDetector, cv_PtrOfFeature2D_to_PtrOfAgastFeatureDetector }
 	impl std::fmt::Debug for core::Ptr<crate::features2d::AgastFeatureDetector> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -10815,7 +10846,7 @@ mod features2d_types {
 	pub type PtrOfBFMatcher = core::Ptr<crate::features2d::BFMatcher>;
 	
 	ptr_extern! { crate::features2d::BFMatcher,
-		cv_PtrOfBFMatcher_delete, cv_PtrOfBFMatcher_getInnerPtr_const, cv_PtrOfBFMatcher_getInnerPtrMut
+		cv_PtrOfBFMatcher_delete, cv_PtrOfBFMatcher_getInnerPtr_const, cv_PtrOfBFMatcher_getInnerPtrMut, cv_PtrOfBFMatcher_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::features2d::BFMatcher, cv_PtrOfBFMatcher_new_const_BFMatcher }
@@ -10859,7 +10890,7 @@ mod features2d_types {
 	pub type PtrOfBRISK = core::Ptr<crate::features2d::BRISK>;
 	
 	ptr_extern! { crate::features2d::BRISK,
-		cv_PtrOfBRISK_delete, cv_PtrOfBRISK_getInnerPtr_const, cv_PtrOfBRISK_getInnerPtrMut
+		cv_PtrOfBRISK_delete, cv_PtrOfBRISK_getInnerPtr_const, cv_PtrOfBRISK_getInnerPtrMut, cv_PtrOfBRISK_clone_const
 	}
 	
 	impl core::Ptr<crate::features2d::BRISK> {
@@ -10893,6 +10924,7 @@ mod features2d_types {
 	
 	ptr_cast_base! { PtrOfBRISK, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfBRISK_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfBRISK, cv_PtrOfFeature2D_to_PtrOfBRISK }
 	impl std::fmt::Debug for core::Ptr<crate::features2d::BRISK> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -10904,7 +10936,7 @@ mod features2d_types {
 	pub type PtrOfDescriptorMatcher = core::Ptr<crate::features2d::DescriptorMatcher>;
 	
 	ptr_extern! { crate::features2d::DescriptorMatcher,
-		cv_PtrOfDescriptorMatcher_delete, cv_PtrOfDescriptorMatcher_getInnerPtr_const, cv_PtrOfDescriptorMatcher_getInnerPtrMut
+		cv_PtrOfDescriptorMatcher_delete, cv_PtrOfDescriptorMatcher_getInnerPtr_const, cv_PtrOfDescriptorMatcher_getInnerPtrMut, cv_PtrOfDescriptorMatcher_clone_const
 	}
 	
 	impl core::Ptr<crate::features2d::DescriptorMatcher> {
@@ -10939,7 +10971,7 @@ mod features2d_types {
 	pub type PtrOfFastFeatureDetector = core::Ptr<crate::features2d::FastFeatureDetector>;
 	
 	ptr_extern! { crate::features2d::FastFeatureDetector,
-		cv_PtrOfFastFeatureDetector_delete, cv_PtrOfFastFeatureDetector_getInnerPtr_const, cv_PtrOfFastFeatureDetector_getInnerPtrMut
+		cv_PtrOfFastFeatureDetector_delete, cv_PtrOfFastFeatureDetector_getInnerPtr_const, cv_PtrOfFastFeatureDetector_getInnerPtrMut, cv_PtrOfFastFeatureDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::features2d::FastFeatureDetector> {
@@ -10973,6 +11005,7 @@ mod features2d_types {
 	
 	ptr_cast_base! { PtrOfFastFeatureDetector, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfFastFeatureDetector_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfFastFeatureDetector, cv_PtrOfFeature2D_to_PtrOfFastFeatureDetector }
 	impl std::fmt::Debug for core::Ptr<crate::features2d::FastFeatureDetector> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -10984,7 +11017,7 @@ mod features2d_types {
 	pub type PtrOfFeature2D = core::Ptr<crate::features2d::Feature2D>;
 	
 	ptr_extern! { crate::features2d::Feature2D,
-		cv_PtrOfFeature2D_delete, cv_PtrOfFeature2D_getInnerPtr_const, cv_PtrOfFeature2D_getInnerPtrMut
+		cv_PtrOfFeature2D_delete, cv_PtrOfFeature2D_getInnerPtr_const, cv_PtrOfFeature2D_getInnerPtrMut, cv_PtrOfFeature2D_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::features2d::Feature2D, cv_PtrOfFeature2D_new_const_Feature2D }
@@ -11020,7 +11053,7 @@ mod features2d_types {
 	pub type PtrOfFlannBasedMatcher = core::Ptr<crate::features2d::FlannBasedMatcher>;
 	
 	ptr_extern! { crate::features2d::FlannBasedMatcher,
-		cv_PtrOfFlannBasedMatcher_delete, cv_PtrOfFlannBasedMatcher_getInnerPtr_const, cv_PtrOfFlannBasedMatcher_getInnerPtrMut
+		cv_PtrOfFlannBasedMatcher_delete, cv_PtrOfFlannBasedMatcher_getInnerPtr_const, cv_PtrOfFlannBasedMatcher_getInnerPtrMut, cv_PtrOfFlannBasedMatcher_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::features2d::FlannBasedMatcher, cv_PtrOfFlannBasedMatcher_new_const_FlannBasedMatcher }
@@ -11064,7 +11097,7 @@ mod features2d_types {
 	pub type PtrOfGFTTDetector = core::Ptr<crate::features2d::GFTTDetector>;
 	
 	ptr_extern! { crate::features2d::GFTTDetector,
-		cv_PtrOfGFTTDetector_delete, cv_PtrOfGFTTDetector_getInnerPtr_const, cv_PtrOfGFTTDetector_getInnerPtrMut
+		cv_PtrOfGFTTDetector_delete, cv_PtrOfGFTTDetector_getInnerPtr_const, cv_PtrOfGFTTDetector_getInnerPtrMut, cv_PtrOfGFTTDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::features2d::GFTTDetector> {
@@ -11098,6 +11131,7 @@ mod features2d_types {
 	
 	ptr_cast_base! { PtrOfGFTTDetector, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfGFTTDetector_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfGFTTDetector, cv_PtrOfFeature2D_to_PtrOfGFTTDetector }
 	impl std::fmt::Debug for core::Ptr<crate::features2d::GFTTDetector> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -11109,7 +11143,7 @@ mod features2d_types {
 	pub type PtrOfKAZE = core::Ptr<crate::features2d::KAZE>;
 	
 	ptr_extern! { crate::features2d::KAZE,
-		cv_PtrOfKAZE_delete, cv_PtrOfKAZE_getInnerPtr_const, cv_PtrOfKAZE_getInnerPtrMut
+		cv_PtrOfKAZE_delete, cv_PtrOfKAZE_getInnerPtr_const, cv_PtrOfKAZE_getInnerPtrMut, cv_PtrOfKAZE_clone_const
 	}
 	
 	impl core::Ptr<crate::features2d::KAZE> {
@@ -11143,6 +11177,7 @@ mod features2d_types {
 	
 	ptr_cast_base! { PtrOfKAZE, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfKAZE_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfKAZE, cv_PtrOfFeature2D_to_PtrOfKAZE }
 	impl std::fmt::Debug for core::Ptr<crate::features2d::KAZE> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -11154,7 +11189,7 @@ mod features2d_types {
 	pub type PtrOfMSER = core::Ptr<crate::features2d::MSER>;
 	
 	ptr_extern! { crate::features2d::MSER,
-		cv_PtrOfMSER_delete, cv_PtrOfMSER_getInnerPtr_const, cv_PtrOfMSER_getInnerPtrMut
+		cv_PtrOfMSER_delete, cv_PtrOfMSER_getInnerPtr_const, cv_PtrOfMSER_getInnerPtrMut, cv_PtrOfMSER_clone_const
 	}
 	
 	impl core::Ptr<crate::features2d::MSER> {
@@ -11188,6 +11223,7 @@ mod features2d_types {
 	
 	ptr_cast_base! { PtrOfMSER, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfMSER_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfMSER, cv_PtrOfFeature2D_to_PtrOfMSER }
 	impl std::fmt::Debug for core::Ptr<crate::features2d::MSER> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -11199,7 +11235,7 @@ mod features2d_types {
 	pub type PtrOfORB = core::Ptr<crate::features2d::ORB>;
 	
 	ptr_extern! { crate::features2d::ORB,
-		cv_PtrOfORB_delete, cv_PtrOfORB_getInnerPtr_const, cv_PtrOfORB_getInnerPtrMut
+		cv_PtrOfORB_delete, cv_PtrOfORB_getInnerPtr_const, cv_PtrOfORB_getInnerPtrMut, cv_PtrOfORB_clone_const
 	}
 	
 	impl core::Ptr<crate::features2d::ORB> {
@@ -11233,6 +11269,7 @@ mod features2d_types {
 	
 	ptr_cast_base! { PtrOfORB, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfORB_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfORB, cv_PtrOfFeature2D_to_PtrOfORB }
 	impl std::fmt::Debug for core::Ptr<crate::features2d::ORB> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -11244,7 +11281,7 @@ mod features2d_types {
 	pub type PtrOfSIFT = core::Ptr<crate::features2d::SIFT>;
 	
 	ptr_extern! { crate::features2d::SIFT,
-		cv_PtrOfSIFT_delete, cv_PtrOfSIFT_getInnerPtr_const, cv_PtrOfSIFT_getInnerPtrMut
+		cv_PtrOfSIFT_delete, cv_PtrOfSIFT_getInnerPtr_const, cv_PtrOfSIFT_getInnerPtrMut, cv_PtrOfSIFT_clone_const
 	}
 	
 	impl core::Ptr<crate::features2d::SIFT> {
@@ -11278,6 +11315,7 @@ mod features2d_types {
 	
 	ptr_cast_base! { PtrOfSIFT, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfSIFT_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfSIFT, cv_PtrOfFeature2D_to_PtrOfSIFT }
 	impl std::fmt::Debug for core::Ptr<crate::features2d::SIFT> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -11289,7 +11327,7 @@ mod features2d_types {
 	pub type PtrOfSimpleBlobDetector = core::Ptr<crate::features2d::SimpleBlobDetector>;
 	
 	ptr_extern! { crate::features2d::SimpleBlobDetector,
-		cv_PtrOfSimpleBlobDetector_delete, cv_PtrOfSimpleBlobDetector_getInnerPtr_const, cv_PtrOfSimpleBlobDetector_getInnerPtrMut
+		cv_PtrOfSimpleBlobDetector_delete, cv_PtrOfSimpleBlobDetector_getInnerPtr_const, cv_PtrOfSimpleBlobDetector_getInnerPtrMut, cv_PtrOfSimpleBlobDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::features2d::SimpleBlobDetector> {
@@ -11323,6 +11361,7 @@ mod features2d_types {
 	
 	ptr_cast_base! { PtrOfSimpleBlobDetector, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfSimpleBlobDetector_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfSimpleBlobDetector, cv_PtrOfFeature2D_to_PtrOfSimpleBlobDetector }
 	impl std::fmt::Debug for core::Ptr<crate::features2d::SimpleBlobDetector> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -11342,7 +11381,7 @@ mod flann_types {
 	pub type PtrOfIndexParams = core::Ptr<crate::flann::IndexParams>;
 	
 	ptr_extern! { crate::flann::IndexParams,
-		cv_PtrOfIndexParams_delete, cv_PtrOfIndexParams_getInnerPtr_const, cv_PtrOfIndexParams_getInnerPtrMut
+		cv_PtrOfIndexParams_delete, cv_PtrOfIndexParams_getInnerPtr_const, cv_PtrOfIndexParams_getInnerPtrMut, cv_PtrOfIndexParams_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::flann::IndexParams, cv_PtrOfIndexParams_new_const_IndexParams }
@@ -11370,7 +11409,7 @@ mod flann_types {
 	pub type PtrOfSearchParams = core::Ptr<crate::flann::SearchParams>;
 	
 	ptr_extern! { crate::flann::SearchParams,
-		cv_PtrOfSearchParams_delete, cv_PtrOfSearchParams_getInnerPtr_const, cv_PtrOfSearchParams_getInnerPtrMut
+		cv_PtrOfSearchParams_delete, cv_PtrOfSearchParams_getInnerPtr_const, cv_PtrOfSearchParams_getInnerPtrMut, cv_PtrOfSearchParams_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::flann::SearchParams, cv_PtrOfSearchParams_new_const_SearchParams }
@@ -11456,7 +11495,7 @@ mod freetype_types {
 	pub type PtrOfFreeType2 = core::Ptr<crate::freetype::FreeType2>;
 	
 	ptr_extern! { crate::freetype::FreeType2,
-		cv_PtrOfFreeType2_delete, cv_PtrOfFreeType2_getInnerPtr_const, cv_PtrOfFreeType2_getInnerPtrMut
+		cv_PtrOfFreeType2_delete, cv_PtrOfFreeType2_getInnerPtr_const, cv_PtrOfFreeType2_getInnerPtrMut, cv_PtrOfFreeType2_clone_const
 	}
 	
 	impl core::Ptr<crate::freetype::FreeType2> {
@@ -11743,7 +11782,7 @@ mod hdf_types {
 	pub type PtrOfHDF5 = core::Ptr<crate::hdf::HDF5>;
 	
 	ptr_extern! { crate::hdf::HDF5,
-		cv_PtrOfHDF5_delete, cv_PtrOfHDF5_getInnerPtr_const, cv_PtrOfHDF5_getInnerPtrMut
+		cv_PtrOfHDF5_delete, cv_PtrOfHDF5_getInnerPtr_const, cv_PtrOfHDF5_getInnerPtrMut, cv_PtrOfHDF5_clone_const
 	}
 	
 	impl core::Ptr<crate::hdf::HDF5> {
@@ -11778,7 +11817,7 @@ mod hfs_types {
 	pub type PtrOfHfsSegment = core::Ptr<crate::hfs::HfsSegment>;
 	
 	ptr_extern! { crate::hfs::HfsSegment,
-		cv_PtrOfHfsSegment_delete, cv_PtrOfHfsSegment_getInnerPtr_const, cv_PtrOfHfsSegment_getInnerPtrMut
+		cv_PtrOfHfsSegment_delete, cv_PtrOfHfsSegment_getInnerPtr_const, cv_PtrOfHfsSegment_getInnerPtrMut, cv_PtrOfHfsSegment_clone_const
 	}
 	
 	impl core::Ptr<crate::hfs::HfsSegment> {
@@ -11821,7 +11860,7 @@ mod img_hash_types {
 	pub type PtrOfAverageHash = core::Ptr<crate::img_hash::AverageHash>;
 	
 	ptr_extern! { crate::img_hash::AverageHash,
-		cv_PtrOfAverageHash_delete, cv_PtrOfAverageHash_getInnerPtr_const, cv_PtrOfAverageHash_getInnerPtrMut
+		cv_PtrOfAverageHash_delete, cv_PtrOfAverageHash_getInnerPtr_const, cv_PtrOfAverageHash_getInnerPtrMut, cv_PtrOfAverageHash_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::img_hash::AverageHash, cv_PtrOfAverageHash_new_const_AverageHash }
@@ -11865,7 +11904,7 @@ mod img_hash_types {
 	pub type PtrOfBlockMeanHash = core::Ptr<crate::img_hash::BlockMeanHash>;
 	
 	ptr_extern! { crate::img_hash::BlockMeanHash,
-		cv_PtrOfBlockMeanHash_delete, cv_PtrOfBlockMeanHash_getInnerPtr_const, cv_PtrOfBlockMeanHash_getInnerPtrMut
+		cv_PtrOfBlockMeanHash_delete, cv_PtrOfBlockMeanHash_getInnerPtr_const, cv_PtrOfBlockMeanHash_getInnerPtrMut, cv_PtrOfBlockMeanHash_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::img_hash::BlockMeanHash, cv_PtrOfBlockMeanHash_new_const_BlockMeanHash }
@@ -11909,7 +11948,7 @@ mod img_hash_types {
 	pub type PtrOfColorMomentHash = core::Ptr<crate::img_hash::ColorMomentHash>;
 	
 	ptr_extern! { crate::img_hash::ColorMomentHash,
-		cv_PtrOfColorMomentHash_delete, cv_PtrOfColorMomentHash_getInnerPtr_const, cv_PtrOfColorMomentHash_getInnerPtrMut
+		cv_PtrOfColorMomentHash_delete, cv_PtrOfColorMomentHash_getInnerPtr_const, cv_PtrOfColorMomentHash_getInnerPtrMut, cv_PtrOfColorMomentHash_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::img_hash::ColorMomentHash, cv_PtrOfColorMomentHash_new_const_ColorMomentHash }
@@ -11953,7 +11992,7 @@ mod img_hash_types {
 	pub type PtrOfMarrHildrethHash = core::Ptr<crate::img_hash::MarrHildrethHash>;
 	
 	ptr_extern! { crate::img_hash::MarrHildrethHash,
-		cv_PtrOfMarrHildrethHash_delete, cv_PtrOfMarrHildrethHash_getInnerPtr_const, cv_PtrOfMarrHildrethHash_getInnerPtrMut
+		cv_PtrOfMarrHildrethHash_delete, cv_PtrOfMarrHildrethHash_getInnerPtr_const, cv_PtrOfMarrHildrethHash_getInnerPtrMut, cv_PtrOfMarrHildrethHash_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::img_hash::MarrHildrethHash, cv_PtrOfMarrHildrethHash_new_const_MarrHildrethHash }
@@ -11997,7 +12036,7 @@ mod img_hash_types {
 	pub type PtrOfPHash = core::Ptr<crate::img_hash::PHash>;
 	
 	ptr_extern! { crate::img_hash::PHash,
-		cv_PtrOfPHash_delete, cv_PtrOfPHash_getInnerPtr_const, cv_PtrOfPHash_getInnerPtrMut
+		cv_PtrOfPHash_delete, cv_PtrOfPHash_getInnerPtr_const, cv_PtrOfPHash_getInnerPtrMut, cv_PtrOfPHash_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::img_hash::PHash, cv_PtrOfPHash_new_const_PHash }
@@ -12041,7 +12080,7 @@ mod img_hash_types {
 	pub type PtrOfRadialVarianceHash = core::Ptr<crate::img_hash::RadialVarianceHash>;
 	
 	ptr_extern! { crate::img_hash::RadialVarianceHash,
-		cv_PtrOfRadialVarianceHash_delete, cv_PtrOfRadialVarianceHash_getInnerPtr_const, cv_PtrOfRadialVarianceHash_getInnerPtrMut
+		cv_PtrOfRadialVarianceHash_delete, cv_PtrOfRadialVarianceHash_getInnerPtr_const, cv_PtrOfRadialVarianceHash_getInnerPtrMut, cv_PtrOfRadialVarianceHash_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::img_hash::RadialVarianceHash, cv_PtrOfRadialVarianceHash_new_const_RadialVarianceHash }
@@ -12093,7 +12132,7 @@ mod imgproc_types {
 	pub type PtrOfCLAHE = core::Ptr<crate::imgproc::CLAHE>;
 	
 	ptr_extern! { crate::imgproc::CLAHE,
-		cv_PtrOfCLAHE_delete, cv_PtrOfCLAHE_getInnerPtr_const, cv_PtrOfCLAHE_getInnerPtrMut
+		cv_PtrOfCLAHE_delete, cv_PtrOfCLAHE_getInnerPtr_const, cv_PtrOfCLAHE_getInnerPtrMut, cv_PtrOfCLAHE_clone_const
 	}
 	
 	impl core::Ptr<crate::imgproc::CLAHE> {
@@ -12128,7 +12167,7 @@ mod imgproc_types {
 	pub type PtrOfGeneralizedHoughBallard = core::Ptr<crate::imgproc::GeneralizedHoughBallard>;
 	
 	ptr_extern! { crate::imgproc::GeneralizedHoughBallard,
-		cv_PtrOfGeneralizedHoughBallard_delete, cv_PtrOfGeneralizedHoughBallard_getInnerPtr_const, cv_PtrOfGeneralizedHoughBallard_getInnerPtrMut
+		cv_PtrOfGeneralizedHoughBallard_delete, cv_PtrOfGeneralizedHoughBallard_getInnerPtr_const, cv_PtrOfGeneralizedHoughBallard_getInnerPtrMut, cv_PtrOfGeneralizedHoughBallard_clone_const
 	}
 	
 	impl core::Ptr<crate::imgproc::GeneralizedHoughBallard> {
@@ -12171,7 +12210,7 @@ mod imgproc_types {
 	pub type PtrOfGeneralizedHoughGuil = core::Ptr<crate::imgproc::GeneralizedHoughGuil>;
 	
 	ptr_extern! { crate::imgproc::GeneralizedHoughGuil,
-		cv_PtrOfGeneralizedHoughGuil_delete, cv_PtrOfGeneralizedHoughGuil_getInnerPtr_const, cv_PtrOfGeneralizedHoughGuil_getInnerPtrMut
+		cv_PtrOfGeneralizedHoughGuil_delete, cv_PtrOfGeneralizedHoughGuil_getInnerPtr_const, cv_PtrOfGeneralizedHoughGuil_getInnerPtrMut, cv_PtrOfGeneralizedHoughGuil_clone_const
 	}
 	
 	impl core::Ptr<crate::imgproc::GeneralizedHoughGuil> {
@@ -12214,7 +12253,7 @@ mod imgproc_types {
 	pub type PtrOfLineSegmentDetector = core::Ptr<crate::imgproc::LineSegmentDetector>;
 	
 	ptr_extern! { crate::imgproc::LineSegmentDetector,
-		cv_PtrOfLineSegmentDetector_delete, cv_PtrOfLineSegmentDetector_getInnerPtr_const, cv_PtrOfLineSegmentDetector_getInnerPtrMut
+		cv_PtrOfLineSegmentDetector_delete, cv_PtrOfLineSegmentDetector_getInnerPtr_const, cv_PtrOfLineSegmentDetector_getInnerPtrMut, cv_PtrOfLineSegmentDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::imgproc::LineSegmentDetector> {
@@ -12257,7 +12296,7 @@ mod line_descriptor_types {
 	pub type PtrOfBinaryDescriptor = core::Ptr<crate::line_descriptor::BinaryDescriptor>;
 	
 	ptr_extern! { crate::line_descriptor::BinaryDescriptor,
-		cv_PtrOfBinaryDescriptor_delete, cv_PtrOfBinaryDescriptor_getInnerPtr_const, cv_PtrOfBinaryDescriptor_getInnerPtrMut
+		cv_PtrOfBinaryDescriptor_delete, cv_PtrOfBinaryDescriptor_getInnerPtr_const, cv_PtrOfBinaryDescriptor_getInnerPtrMut, cv_PtrOfBinaryDescriptor_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::line_descriptor::BinaryDescriptor, cv_PtrOfBinaryDescriptor_new_const_BinaryDescriptor }
@@ -12293,7 +12332,7 @@ mod line_descriptor_types {
 	pub type PtrOfBinaryDescriptorMatcher = core::Ptr<crate::line_descriptor::BinaryDescriptorMatcher>;
 	
 	ptr_extern! { crate::line_descriptor::BinaryDescriptorMatcher,
-		cv_PtrOfBinaryDescriptorMatcher_delete, cv_PtrOfBinaryDescriptorMatcher_getInnerPtr_const, cv_PtrOfBinaryDescriptorMatcher_getInnerPtrMut
+		cv_PtrOfBinaryDescriptorMatcher_delete, cv_PtrOfBinaryDescriptorMatcher_getInnerPtr_const, cv_PtrOfBinaryDescriptorMatcher_getInnerPtrMut, cv_PtrOfBinaryDescriptorMatcher_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::line_descriptor::BinaryDescriptorMatcher, cv_PtrOfBinaryDescriptorMatcher_new_const_BinaryDescriptorMatcher }
@@ -12329,7 +12368,7 @@ mod line_descriptor_types {
 	pub type PtrOfLSDDetector = core::Ptr<crate::line_descriptor::LSDDetector>;
 	
 	ptr_extern! { crate::line_descriptor::LSDDetector,
-		cv_PtrOfLSDDetector_delete, cv_PtrOfLSDDetector_getInnerPtr_const, cv_PtrOfLSDDetector_getInnerPtrMut
+		cv_PtrOfLSDDetector_delete, cv_PtrOfLSDDetector_getInnerPtr_const, cv_PtrOfLSDDetector_getInnerPtrMut, cv_PtrOfLSDDetector_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::line_descriptor::LSDDetector, cv_PtrOfLSDDetector_new_const_LSDDetector }
@@ -12412,7 +12451,7 @@ mod mcc_types {
 	pub type PtrOfMCC_CChecker = core::Ptr<crate::mcc::MCC_CChecker>;
 	
 	ptr_extern! { crate::mcc::MCC_CChecker,
-		cv_PtrOfMCC_CChecker_delete, cv_PtrOfMCC_CChecker_getInnerPtr_const, cv_PtrOfMCC_CChecker_getInnerPtrMut
+		cv_PtrOfMCC_CChecker_delete, cv_PtrOfMCC_CChecker_getInnerPtr_const, cv_PtrOfMCC_CChecker_getInnerPtrMut, cv_PtrOfMCC_CChecker_clone_const
 	}
 	
 	impl core::Ptr<crate::mcc::MCC_CChecker> {
@@ -12439,7 +12478,7 @@ mod mcc_types {
 	pub type PtrOfMCC_CCheckerDetector = core::Ptr<crate::mcc::MCC_CCheckerDetector>;
 	
 	ptr_extern! { crate::mcc::MCC_CCheckerDetector,
-		cv_PtrOfMCC_CCheckerDetector_delete, cv_PtrOfMCC_CCheckerDetector_getInnerPtr_const, cv_PtrOfMCC_CCheckerDetector_getInnerPtrMut
+		cv_PtrOfMCC_CCheckerDetector_delete, cv_PtrOfMCC_CCheckerDetector_getInnerPtr_const, cv_PtrOfMCC_CCheckerDetector_getInnerPtrMut, cv_PtrOfMCC_CCheckerDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::mcc::MCC_CCheckerDetector> {
@@ -12474,7 +12513,7 @@ mod mcc_types {
 	pub type PtrOfMCC_CCheckerDraw = core::Ptr<crate::mcc::MCC_CCheckerDraw>;
 	
 	ptr_extern! { crate::mcc::MCC_CCheckerDraw,
-		cv_PtrOfMCC_CCheckerDraw_delete, cv_PtrOfMCC_CCheckerDraw_getInnerPtr_const, cv_PtrOfMCC_CCheckerDraw_getInnerPtrMut
+		cv_PtrOfMCC_CCheckerDraw_delete, cv_PtrOfMCC_CCheckerDraw_getInnerPtr_const, cv_PtrOfMCC_CCheckerDraw_getInnerPtrMut, cv_PtrOfMCC_CCheckerDraw_clone_const
 	}
 	
 	impl core::Ptr<crate::mcc::MCC_CCheckerDraw> {
@@ -12501,7 +12540,7 @@ mod mcc_types {
 	pub type PtrOfMCC_DetectorParameters = core::Ptr<crate::mcc::MCC_DetectorParameters>;
 	
 	ptr_extern! { crate::mcc::MCC_DetectorParameters,
-		cv_PtrOfMCC_DetectorParameters_delete, cv_PtrOfMCC_DetectorParameters_getInnerPtr_const, cv_PtrOfMCC_DetectorParameters_getInnerPtrMut
+		cv_PtrOfMCC_DetectorParameters_delete, cv_PtrOfMCC_DetectorParameters_getInnerPtr_const, cv_PtrOfMCC_DetectorParameters_getInnerPtrMut, cv_PtrOfMCC_DetectorParameters_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::mcc::MCC_DetectorParameters, cv_PtrOfMCC_DetectorParameters_new_const_DetectorParameters }
@@ -12573,7 +12612,7 @@ mod ml_types {
 	pub type PtrOfANN_MLP = core::Ptr<crate::ml::ANN_MLP>;
 	
 	ptr_extern! { crate::ml::ANN_MLP,
-		cv_PtrOfANN_MLP_delete, cv_PtrOfANN_MLP_getInnerPtr_const, cv_PtrOfANN_MLP_getInnerPtrMut
+		cv_PtrOfANN_MLP_delete, cv_PtrOfANN_MLP_getInnerPtr_const, cv_PtrOfANN_MLP_getInnerPtrMut, cv_PtrOfANN_MLP_clone_const
 	}
 	
 	impl core::Ptr<crate::ml::ANN_MLP> {
@@ -12616,7 +12655,7 @@ mod ml_types {
 	pub type PtrOfBoost = core::Ptr<crate::ml::Boost>;
 	
 	ptr_extern! { crate::ml::Boost,
-		cv_PtrOfBoost_delete, cv_PtrOfBoost_getInnerPtr_const, cv_PtrOfBoost_getInnerPtrMut
+		cv_PtrOfBoost_delete, cv_PtrOfBoost_getInnerPtr_const, cv_PtrOfBoost_getInnerPtrMut, cv_PtrOfBoost_clone_const
 	}
 	
 	impl core::Ptr<crate::ml::Boost> {
@@ -12667,7 +12706,7 @@ mod ml_types {
 	pub type PtrOfDTrees = core::Ptr<crate::ml::DTrees>;
 	
 	ptr_extern! { crate::ml::DTrees,
-		cv_PtrOfDTrees_delete, cv_PtrOfDTrees_getInnerPtr_const, cv_PtrOfDTrees_getInnerPtrMut
+		cv_PtrOfDTrees_delete, cv_PtrOfDTrees_getInnerPtr_const, cv_PtrOfDTrees_getInnerPtrMut, cv_PtrOfDTrees_clone_const
 	}
 	
 	impl core::Ptr<crate::ml::DTrees> {
@@ -12710,7 +12749,7 @@ mod ml_types {
 	pub type PtrOfEM = core::Ptr<crate::ml::EM>;
 	
 	ptr_extern! { crate::ml::EM,
-		cv_PtrOfEM_delete, cv_PtrOfEM_getInnerPtr_const, cv_PtrOfEM_getInnerPtrMut
+		cv_PtrOfEM_delete, cv_PtrOfEM_getInnerPtr_const, cv_PtrOfEM_getInnerPtrMut, cv_PtrOfEM_clone_const
 	}
 	
 	impl core::Ptr<crate::ml::EM> {
@@ -12753,7 +12792,7 @@ mod ml_types {
 	pub type PtrOfKNearest = core::Ptr<crate::ml::KNearest>;
 	
 	ptr_extern! { crate::ml::KNearest,
-		cv_PtrOfKNearest_delete, cv_PtrOfKNearest_getInnerPtr_const, cv_PtrOfKNearest_getInnerPtrMut
+		cv_PtrOfKNearest_delete, cv_PtrOfKNearest_getInnerPtr_const, cv_PtrOfKNearest_getInnerPtrMut, cv_PtrOfKNearest_clone_const
 	}
 	
 	impl core::Ptr<crate::ml::KNearest> {
@@ -12796,7 +12835,7 @@ mod ml_types {
 	pub type PtrOfLogisticRegression = core::Ptr<crate::ml::LogisticRegression>;
 	
 	ptr_extern! { crate::ml::LogisticRegression,
-		cv_PtrOfLogisticRegression_delete, cv_PtrOfLogisticRegression_getInnerPtr_const, cv_PtrOfLogisticRegression_getInnerPtrMut
+		cv_PtrOfLogisticRegression_delete, cv_PtrOfLogisticRegression_getInnerPtr_const, cv_PtrOfLogisticRegression_getInnerPtrMut, cv_PtrOfLogisticRegression_clone_const
 	}
 	
 	impl core::Ptr<crate::ml::LogisticRegression> {
@@ -12839,7 +12878,7 @@ mod ml_types {
 	pub type PtrOfNormalBayesClassifier = core::Ptr<crate::ml::NormalBayesClassifier>;
 	
 	ptr_extern! { crate::ml::NormalBayesClassifier,
-		cv_PtrOfNormalBayesClassifier_delete, cv_PtrOfNormalBayesClassifier_getInnerPtr_const, cv_PtrOfNormalBayesClassifier_getInnerPtrMut
+		cv_PtrOfNormalBayesClassifier_delete, cv_PtrOfNormalBayesClassifier_getInnerPtr_const, cv_PtrOfNormalBayesClassifier_getInnerPtrMut, cv_PtrOfNormalBayesClassifier_clone_const
 	}
 	
 	impl core::Ptr<crate::ml::NormalBayesClassifier> {
@@ -12882,7 +12921,7 @@ mod ml_types {
 	pub type PtrOfParamGrid = core::Ptr<crate::ml::ParamGrid>;
 	
 	ptr_extern! { crate::ml::ParamGrid,
-		cv_PtrOfParamGrid_delete, cv_PtrOfParamGrid_getInnerPtr_const, cv_PtrOfParamGrid_getInnerPtrMut
+		cv_PtrOfParamGrid_delete, cv_PtrOfParamGrid_getInnerPtr_const, cv_PtrOfParamGrid_getInnerPtrMut, cv_PtrOfParamGrid_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::ml::ParamGrid, cv_PtrOfParamGrid_new_const_ParamGrid }
@@ -12913,7 +12952,7 @@ mod ml_types {
 	pub type PtrOfRTrees = core::Ptr<crate::ml::RTrees>;
 	
 	ptr_extern! { crate::ml::RTrees,
-		cv_PtrOfRTrees_delete, cv_PtrOfRTrees_getInnerPtr_const, cv_PtrOfRTrees_getInnerPtrMut
+		cv_PtrOfRTrees_delete, cv_PtrOfRTrees_getInnerPtr_const, cv_PtrOfRTrees_getInnerPtrMut, cv_PtrOfRTrees_clone_const
 	}
 	
 	impl core::Ptr<crate::ml::RTrees> {
@@ -12964,7 +13003,7 @@ mod ml_types {
 	pub type PtrOfSVM = core::Ptr<crate::ml::SVM>;
 	
 	ptr_extern! { crate::ml::SVM,
-		cv_PtrOfSVM_delete, cv_PtrOfSVM_getInnerPtr_const, cv_PtrOfSVM_getInnerPtrMut
+		cv_PtrOfSVM_delete, cv_PtrOfSVM_getInnerPtr_const, cv_PtrOfSVM_getInnerPtrMut, cv_PtrOfSVM_clone_const
 	}
 	
 	impl core::Ptr<crate::ml::SVM> {
@@ -13007,7 +13046,7 @@ mod ml_types {
 	pub type PtrOfSVMSGD = core::Ptr<crate::ml::SVMSGD>;
 	
 	ptr_extern! { crate::ml::SVMSGD,
-		cv_PtrOfSVMSGD_delete, cv_PtrOfSVMSGD_getInnerPtr_const, cv_PtrOfSVMSGD_getInnerPtrMut
+		cv_PtrOfSVMSGD_delete, cv_PtrOfSVMSGD_getInnerPtr_const, cv_PtrOfSVMSGD_getInnerPtrMut, cv_PtrOfSVMSGD_clone_const
 	}
 	
 	impl core::Ptr<crate::ml::SVMSGD> {
@@ -13050,7 +13089,7 @@ mod ml_types {
 	pub type PtrOfSVM_Kernel = core::Ptr<crate::ml::SVM_Kernel>;
 	
 	ptr_extern! { crate::ml::SVM_Kernel,
-		cv_PtrOfSVM_Kernel_delete, cv_PtrOfSVM_Kernel_getInnerPtr_const, cv_PtrOfSVM_Kernel_getInnerPtrMut
+		cv_PtrOfSVM_Kernel_delete, cv_PtrOfSVM_Kernel_getInnerPtr_const, cv_PtrOfSVM_Kernel_getInnerPtrMut, cv_PtrOfSVM_Kernel_clone_const
 	}
 	
 	impl core::Ptr<crate::ml::SVM_Kernel> {
@@ -13085,7 +13124,7 @@ mod ml_types {
 	pub type PtrOfTrainData = core::Ptr<crate::ml::TrainData>;
 	
 	ptr_extern! { crate::ml::TrainData,
-		cv_PtrOfTrainData_delete, cv_PtrOfTrainData_getInnerPtr_const, cv_PtrOfTrainData_getInnerPtrMut
+		cv_PtrOfTrainData_delete, cv_PtrOfTrainData_getInnerPtr_const, cv_PtrOfTrainData_getInnerPtrMut, cv_PtrOfTrainData_clone_const
 	}
 	
 	impl core::Ptr<crate::ml::TrainData> {
@@ -13156,7 +13195,7 @@ mod objdetect_types {
 	pub type PtrOfBaseCascadeClassifier = core::Ptr<crate::objdetect::BaseCascadeClassifier>;
 	
 	ptr_extern! { crate::objdetect::BaseCascadeClassifier,
-		cv_PtrOfBaseCascadeClassifier_delete, cv_PtrOfBaseCascadeClassifier_getInnerPtr_const, cv_PtrOfBaseCascadeClassifier_getInnerPtrMut
+		cv_PtrOfBaseCascadeClassifier_delete, cv_PtrOfBaseCascadeClassifier_getInnerPtr_const, cv_PtrOfBaseCascadeClassifier_getInnerPtrMut, cv_PtrOfBaseCascadeClassifier_clone_const
 	}
 	
 	impl core::Ptr<crate::objdetect::BaseCascadeClassifier> {
@@ -13191,7 +13230,7 @@ mod objdetect_types {
 	pub type PtrOfBaseCascadeClassifier_MaskGenerator = core::Ptr<crate::objdetect::BaseCascadeClassifier_MaskGenerator>;
 	
 	ptr_extern! { crate::objdetect::BaseCascadeClassifier_MaskGenerator,
-		cv_PtrOfBaseCascadeClassifier_MaskGenerator_delete, cv_PtrOfBaseCascadeClassifier_MaskGenerator_getInnerPtr_const, cv_PtrOfBaseCascadeClassifier_MaskGenerator_getInnerPtrMut
+		cv_PtrOfBaseCascadeClassifier_MaskGenerator_delete, cv_PtrOfBaseCascadeClassifier_MaskGenerator_getInnerPtr_const, cv_PtrOfBaseCascadeClassifier_MaskGenerator_getInnerPtrMut, cv_PtrOfBaseCascadeClassifier_MaskGenerator_clone_const
 	}
 	
 	impl core::Ptr<crate::objdetect::BaseCascadeClassifier_MaskGenerator> {
@@ -13218,7 +13257,7 @@ mod objdetect_types {
 	pub type PtrOfBoard = core::Ptr<crate::objdetect::Board>;
 	
 	ptr_extern! { crate::objdetect::Board,
-		cv_PtrOfBoard_delete, cv_PtrOfBoard_getInnerPtr_const, cv_PtrOfBoard_getInnerPtrMut
+		cv_PtrOfBoard_delete, cv_PtrOfBoard_getInnerPtr_const, cv_PtrOfBoard_getInnerPtrMut, cv_PtrOfBoard_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::objdetect::Board, cv_PtrOfBoard_new_const_Board }
@@ -13246,7 +13285,7 @@ mod objdetect_types {
 	pub type PtrOfCharucoBoard = core::Ptr<crate::objdetect::CharucoBoard>;
 	
 	ptr_extern! { crate::objdetect::CharucoBoard,
-		cv_PtrOfCharucoBoard_delete, cv_PtrOfCharucoBoard_getInnerPtr_const, cv_PtrOfCharucoBoard_getInnerPtrMut
+		cv_PtrOfCharucoBoard_delete, cv_PtrOfCharucoBoard_getInnerPtr_const, cv_PtrOfCharucoBoard_getInnerPtrMut, cv_PtrOfCharucoBoard_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::objdetect::CharucoBoard, cv_PtrOfCharucoBoard_new_const_CharucoBoard }
@@ -13282,7 +13321,7 @@ mod objdetect_types {
 	pub type PtrOfDetectionBasedTracker_IDetector = core::Ptr<crate::objdetect::DetectionBasedTracker_IDetector>;
 	
 	ptr_extern! { crate::objdetect::DetectionBasedTracker_IDetector,
-		cv_PtrOfDetectionBasedTracker_IDetector_delete, cv_PtrOfDetectionBasedTracker_IDetector_getInnerPtr_const, cv_PtrOfDetectionBasedTracker_IDetector_getInnerPtrMut
+		cv_PtrOfDetectionBasedTracker_IDetector_delete, cv_PtrOfDetectionBasedTracker_IDetector_getInnerPtr_const, cv_PtrOfDetectionBasedTracker_IDetector_getInnerPtrMut, cv_PtrOfDetectionBasedTracker_IDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::objdetect::DetectionBasedTracker_IDetector> {
@@ -13309,7 +13348,7 @@ mod objdetect_types {
 	pub type PtrOfDetectorParameters = core::Ptr<crate::objdetect::DetectorParameters>;
 	
 	ptr_extern! { crate::objdetect::DetectorParameters,
-		cv_PtrOfDetectorParameters_delete, cv_PtrOfDetectorParameters_getInnerPtr_const, cv_PtrOfDetectorParameters_getInnerPtrMut
+		cv_PtrOfDetectorParameters_delete, cv_PtrOfDetectorParameters_getInnerPtr_const, cv_PtrOfDetectorParameters_getInnerPtrMut, cv_PtrOfDetectorParameters_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::objdetect::DetectorParameters, cv_PtrOfDetectorParameters_new_const_DetectorParameters }
@@ -13369,7 +13408,7 @@ mod objdetect_types {
 	pub type PtrOfDictionary = core::Ptr<crate::objdetect::Dictionary>;
 	
 	ptr_extern! { crate::objdetect::Dictionary,
-		cv_PtrOfDictionary_delete, cv_PtrOfDictionary_getInnerPtr_const, cv_PtrOfDictionary_getInnerPtrMut
+		cv_PtrOfDictionary_delete, cv_PtrOfDictionary_getInnerPtr_const, cv_PtrOfDictionary_getInnerPtrMut, cv_PtrOfDictionary_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::objdetect::Dictionary, cv_PtrOfDictionary_new_const_Dictionary }
@@ -13400,7 +13439,7 @@ mod objdetect_types {
 	pub type PtrOfFaceDetectorYN = core::Ptr<crate::objdetect::FaceDetectorYN>;
 	
 	ptr_extern! { crate::objdetect::FaceDetectorYN,
-		cv_PtrOfFaceDetectorYN_delete, cv_PtrOfFaceDetectorYN_getInnerPtr_const, cv_PtrOfFaceDetectorYN_getInnerPtrMut
+		cv_PtrOfFaceDetectorYN_delete, cv_PtrOfFaceDetectorYN_getInnerPtr_const, cv_PtrOfFaceDetectorYN_getInnerPtrMut, cv_PtrOfFaceDetectorYN_clone_const
 	}
 	
 	impl core::Ptr<crate::objdetect::FaceDetectorYN> {
@@ -13427,7 +13466,7 @@ mod objdetect_types {
 	pub type PtrOfFaceRecognizerSF = core::Ptr<crate::objdetect::FaceRecognizerSF>;
 	
 	ptr_extern! { crate::objdetect::FaceRecognizerSF,
-		cv_PtrOfFaceRecognizerSF_delete, cv_PtrOfFaceRecognizerSF_getInnerPtr_const, cv_PtrOfFaceRecognizerSF_getInnerPtrMut
+		cv_PtrOfFaceRecognizerSF_delete, cv_PtrOfFaceRecognizerSF_getInnerPtr_const, cv_PtrOfFaceRecognizerSF_getInnerPtrMut, cv_PtrOfFaceRecognizerSF_clone_const
 	}
 	
 	impl core::Ptr<crate::objdetect::FaceRecognizerSF> {
@@ -13454,7 +13493,7 @@ mod objdetect_types {
 	pub type PtrOfQRCodeEncoder = core::Ptr<crate::objdetect::QRCodeEncoder>;
 	
 	ptr_extern! { crate::objdetect::QRCodeEncoder,
-		cv_PtrOfQRCodeEncoder_delete, cv_PtrOfQRCodeEncoder_getInnerPtr_const, cv_PtrOfQRCodeEncoder_getInnerPtrMut
+		cv_PtrOfQRCodeEncoder_delete, cv_PtrOfQRCodeEncoder_getInnerPtr_const, cv_PtrOfQRCodeEncoder_getInnerPtrMut, cv_PtrOfQRCodeEncoder_clone_const
 	}
 	
 	impl core::Ptr<crate::objdetect::QRCodeEncoder> {
@@ -13543,7 +13582,7 @@ mod optflow_types {
 	pub type PtrOfDenseRLOFOpticalFlow = core::Ptr<crate::optflow::DenseRLOFOpticalFlow>;
 	
 	ptr_extern! { crate::optflow::DenseRLOFOpticalFlow,
-		cv_PtrOfDenseRLOFOpticalFlow_delete, cv_PtrOfDenseRLOFOpticalFlow_getInnerPtr_const, cv_PtrOfDenseRLOFOpticalFlow_getInnerPtrMut
+		cv_PtrOfDenseRLOFOpticalFlow_delete, cv_PtrOfDenseRLOFOpticalFlow_getInnerPtr_const, cv_PtrOfDenseRLOFOpticalFlow_getInnerPtrMut, cv_PtrOfDenseRLOFOpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::optflow::DenseRLOFOpticalFlow> {
@@ -13586,7 +13625,7 @@ mod optflow_types {
 	pub type PtrOfDualTVL1OpticalFlow = core::Ptr<crate::optflow::DualTVL1OpticalFlow>;
 	
 	ptr_extern! { crate::optflow::DualTVL1OpticalFlow,
-		cv_PtrOfDualTVL1OpticalFlow_delete, cv_PtrOfDualTVL1OpticalFlow_getInnerPtr_const, cv_PtrOfDualTVL1OpticalFlow_getInnerPtrMut
+		cv_PtrOfDualTVL1OpticalFlow_delete, cv_PtrOfDualTVL1OpticalFlow_getInnerPtr_const, cv_PtrOfDualTVL1OpticalFlow_getInnerPtrMut, cv_PtrOfDualTVL1OpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::optflow::DualTVL1OpticalFlow> {
@@ -13629,7 +13668,7 @@ mod optflow_types {
 	pub type PtrOfGPCTrainingSamples = core::Ptr<crate::optflow::GPCTrainingSamples>;
 	
 	ptr_extern! { crate::optflow::GPCTrainingSamples,
-		cv_PtrOfGPCTrainingSamples_delete, cv_PtrOfGPCTrainingSamples_getInnerPtr_const, cv_PtrOfGPCTrainingSamples_getInnerPtrMut
+		cv_PtrOfGPCTrainingSamples_delete, cv_PtrOfGPCTrainingSamples_getInnerPtr_const, cv_PtrOfGPCTrainingSamples_getInnerPtrMut, cv_PtrOfGPCTrainingSamples_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::optflow::GPCTrainingSamples, cv_PtrOfGPCTrainingSamples_new_const_GPCTrainingSamples }
@@ -13657,7 +13696,7 @@ mod optflow_types {
 	pub type PtrOfGPCTree = core::Ptr<crate::optflow::GPCTree>;
 	
 	ptr_extern! { crate::optflow::GPCTree,
-		cv_PtrOfGPCTree_delete, cv_PtrOfGPCTree_getInnerPtr_const, cv_PtrOfGPCTree_getInnerPtrMut
+		cv_PtrOfGPCTree_delete, cv_PtrOfGPCTree_getInnerPtr_const, cv_PtrOfGPCTree_getInnerPtrMut, cv_PtrOfGPCTree_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::optflow::GPCTree, cv_PtrOfGPCTree_new_const_GPCTree }
@@ -13693,7 +13732,7 @@ mod optflow_types {
 	pub type PtrOfPCAPrior = core::Ptr<crate::optflow::PCAPrior>;
 	
 	ptr_extern! { crate::optflow::PCAPrior,
-		cv_PtrOfPCAPrior_delete, cv_PtrOfPCAPrior_getInnerPtr_const, cv_PtrOfPCAPrior_getInnerPtrMut_const
+		cv_PtrOfPCAPrior_delete, cv_PtrOfPCAPrior_getInnerPtr_const, cv_PtrOfPCAPrior_getInnerPtrMut_const, cv_PtrOfPCAPrior_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::optflow::PCAPrior, cv_PtrOfPCAPrior_new_const_const_PCAPrior }
@@ -13721,7 +13760,7 @@ mod optflow_types {
 	pub type PtrOfRLOFOpticalFlowParameter = core::Ptr<crate::optflow::RLOFOpticalFlowParameter>;
 	
 	ptr_extern! { crate::optflow::RLOFOpticalFlowParameter,
-		cv_PtrOfRLOFOpticalFlowParameter_delete, cv_PtrOfRLOFOpticalFlowParameter_getInnerPtr_const, cv_PtrOfRLOFOpticalFlowParameter_getInnerPtrMut
+		cv_PtrOfRLOFOpticalFlowParameter_delete, cv_PtrOfRLOFOpticalFlowParameter_getInnerPtr_const, cv_PtrOfRLOFOpticalFlowParameter_getInnerPtrMut, cv_PtrOfRLOFOpticalFlowParameter_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::optflow::RLOFOpticalFlowParameter, cv_PtrOfRLOFOpticalFlowParameter_new_const_RLOFOpticalFlowParameter }
@@ -13763,7 +13802,7 @@ mod optflow_types {
 	pub type PtrOfSparseRLOFOpticalFlow = core::Ptr<crate::optflow::SparseRLOFOpticalFlow>;
 	
 	ptr_extern! { crate::optflow::SparseRLOFOpticalFlow,
-		cv_PtrOfSparseRLOFOpticalFlow_delete, cv_PtrOfSparseRLOFOpticalFlow_getInnerPtr_const, cv_PtrOfSparseRLOFOpticalFlow_getInnerPtrMut
+		cv_PtrOfSparseRLOFOpticalFlow_delete, cv_PtrOfSparseRLOFOpticalFlow_getInnerPtr_const, cv_PtrOfSparseRLOFOpticalFlow_getInnerPtrMut, cv_PtrOfSparseRLOFOpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::optflow::SparseRLOFOpticalFlow> {
@@ -13850,7 +13889,7 @@ mod ovis_types {
 	pub type PtrOfWindowScene = core::Ptr<crate::ovis::WindowScene>;
 	
 	ptr_extern! { crate::ovis::WindowScene,
-		cv_PtrOfWindowScene_delete, cv_PtrOfWindowScene_getInnerPtr_const, cv_PtrOfWindowScene_getInnerPtrMut
+		cv_PtrOfWindowScene_delete, cv_PtrOfWindowScene_getInnerPtr_const, cv_PtrOfWindowScene_getInnerPtrMut, cv_PtrOfWindowScene_clone_const
 	}
 	
 	impl core::Ptr<crate::ovis::WindowScene> {
@@ -13885,7 +13924,7 @@ mod phase_unwrapping_types {
 	pub type PtrOfHistogramPhaseUnwrapping = core::Ptr<crate::phase_unwrapping::HistogramPhaseUnwrapping>;
 	
 	ptr_extern! { crate::phase_unwrapping::HistogramPhaseUnwrapping,
-		cv_PtrOfHistogramPhaseUnwrapping_delete, cv_PtrOfHistogramPhaseUnwrapping_getInnerPtr_const, cv_PtrOfHistogramPhaseUnwrapping_getInnerPtrMut
+		cv_PtrOfHistogramPhaseUnwrapping_delete, cv_PtrOfHistogramPhaseUnwrapping_getInnerPtr_const, cv_PtrOfHistogramPhaseUnwrapping_getInnerPtrMut, cv_PtrOfHistogramPhaseUnwrapping_clone_const
 	}
 	
 	impl core::Ptr<crate::phase_unwrapping::HistogramPhaseUnwrapping> {
@@ -13936,7 +13975,7 @@ mod photo_types {
 	pub type PtrOfAlignMTB = core::Ptr<crate::photo::AlignMTB>;
 	
 	ptr_extern! { crate::photo::AlignMTB,
-		cv_PtrOfAlignMTB_delete, cv_PtrOfAlignMTB_getInnerPtr_const, cv_PtrOfAlignMTB_getInnerPtrMut
+		cv_PtrOfAlignMTB_delete, cv_PtrOfAlignMTB_getInnerPtr_const, cv_PtrOfAlignMTB_getInnerPtrMut, cv_PtrOfAlignMTB_clone_const
 	}
 	
 	impl core::Ptr<crate::photo::AlignMTB> {
@@ -13979,7 +14018,7 @@ mod photo_types {
 	pub type PtrOfCalibrateDebevec = core::Ptr<crate::photo::CalibrateDebevec>;
 	
 	ptr_extern! { crate::photo::CalibrateDebevec,
-		cv_PtrOfCalibrateDebevec_delete, cv_PtrOfCalibrateDebevec_getInnerPtr_const, cv_PtrOfCalibrateDebevec_getInnerPtrMut
+		cv_PtrOfCalibrateDebevec_delete, cv_PtrOfCalibrateDebevec_getInnerPtr_const, cv_PtrOfCalibrateDebevec_getInnerPtrMut, cv_PtrOfCalibrateDebevec_clone_const
 	}
 	
 	impl core::Ptr<crate::photo::CalibrateDebevec> {
@@ -14022,7 +14061,7 @@ mod photo_types {
 	pub type PtrOfCalibrateRobertson = core::Ptr<crate::photo::CalibrateRobertson>;
 	
 	ptr_extern! { crate::photo::CalibrateRobertson,
-		cv_PtrOfCalibrateRobertson_delete, cv_PtrOfCalibrateRobertson_getInnerPtr_const, cv_PtrOfCalibrateRobertson_getInnerPtrMut
+		cv_PtrOfCalibrateRobertson_delete, cv_PtrOfCalibrateRobertson_getInnerPtr_const, cv_PtrOfCalibrateRobertson_getInnerPtrMut, cv_PtrOfCalibrateRobertson_clone_const
 	}
 	
 	impl core::Ptr<crate::photo::CalibrateRobertson> {
@@ -14065,7 +14104,7 @@ mod photo_types {
 	pub type PtrOfMergeDebevec = core::Ptr<crate::photo::MergeDebevec>;
 	
 	ptr_extern! { crate::photo::MergeDebevec,
-		cv_PtrOfMergeDebevec_delete, cv_PtrOfMergeDebevec_getInnerPtr_const, cv_PtrOfMergeDebevec_getInnerPtrMut
+		cv_PtrOfMergeDebevec_delete, cv_PtrOfMergeDebevec_getInnerPtr_const, cv_PtrOfMergeDebevec_getInnerPtrMut, cv_PtrOfMergeDebevec_clone_const
 	}
 	
 	impl core::Ptr<crate::photo::MergeDebevec> {
@@ -14108,7 +14147,7 @@ mod photo_types {
 	pub type PtrOfMergeMertens = core::Ptr<crate::photo::MergeMertens>;
 	
 	ptr_extern! { crate::photo::MergeMertens,
-		cv_PtrOfMergeMertens_delete, cv_PtrOfMergeMertens_getInnerPtr_const, cv_PtrOfMergeMertens_getInnerPtrMut
+		cv_PtrOfMergeMertens_delete, cv_PtrOfMergeMertens_getInnerPtr_const, cv_PtrOfMergeMertens_getInnerPtrMut, cv_PtrOfMergeMertens_clone_const
 	}
 	
 	impl core::Ptr<crate::photo::MergeMertens> {
@@ -14151,7 +14190,7 @@ mod photo_types {
 	pub type PtrOfMergeRobertson = core::Ptr<crate::photo::MergeRobertson>;
 	
 	ptr_extern! { crate::photo::MergeRobertson,
-		cv_PtrOfMergeRobertson_delete, cv_PtrOfMergeRobertson_getInnerPtr_const, cv_PtrOfMergeRobertson_getInnerPtrMut
+		cv_PtrOfMergeRobertson_delete, cv_PtrOfMergeRobertson_getInnerPtr_const, cv_PtrOfMergeRobertson_getInnerPtrMut, cv_PtrOfMergeRobertson_clone_const
 	}
 	
 	impl core::Ptr<crate::photo::MergeRobertson> {
@@ -14194,7 +14233,7 @@ mod photo_types {
 	pub type PtrOfTonemap = core::Ptr<crate::photo::Tonemap>;
 	
 	ptr_extern! { crate::photo::Tonemap,
-		cv_PtrOfTonemap_delete, cv_PtrOfTonemap_getInnerPtr_const, cv_PtrOfTonemap_getInnerPtrMut
+		cv_PtrOfTonemap_delete, cv_PtrOfTonemap_getInnerPtr_const, cv_PtrOfTonemap_getInnerPtrMut, cv_PtrOfTonemap_clone_const
 	}
 	
 	impl core::Ptr<crate::photo::Tonemap> {
@@ -14229,7 +14268,7 @@ mod photo_types {
 	pub type PtrOfTonemapDrago = core::Ptr<crate::photo::TonemapDrago>;
 	
 	ptr_extern! { crate::photo::TonemapDrago,
-		cv_PtrOfTonemapDrago_delete, cv_PtrOfTonemapDrago_getInnerPtr_const, cv_PtrOfTonemapDrago_getInnerPtrMut
+		cv_PtrOfTonemapDrago_delete, cv_PtrOfTonemapDrago_getInnerPtr_const, cv_PtrOfTonemapDrago_getInnerPtrMut, cv_PtrOfTonemapDrago_clone_const
 	}
 	
 	impl core::Ptr<crate::photo::TonemapDrago> {
@@ -14272,7 +14311,7 @@ mod photo_types {
 	pub type PtrOfTonemapMantiuk = core::Ptr<crate::photo::TonemapMantiuk>;
 	
 	ptr_extern! { crate::photo::TonemapMantiuk,
-		cv_PtrOfTonemapMantiuk_delete, cv_PtrOfTonemapMantiuk_getInnerPtr_const, cv_PtrOfTonemapMantiuk_getInnerPtrMut
+		cv_PtrOfTonemapMantiuk_delete, cv_PtrOfTonemapMantiuk_getInnerPtr_const, cv_PtrOfTonemapMantiuk_getInnerPtrMut, cv_PtrOfTonemapMantiuk_clone_const
 	}
 	
 	impl core::Ptr<crate::photo::TonemapMantiuk> {
@@ -14315,7 +14354,7 @@ mod photo_types {
 	pub type PtrOfTonemapReinhard = core::Ptr<crate::photo::TonemapReinhard>;
 	
 	ptr_extern! { crate::photo::TonemapReinhard,
-		cv_PtrOfTonemapReinhard_delete, cv_PtrOfTonemapReinhard_getInnerPtr_const, cv_PtrOfTonemapReinhard_getInnerPtrMut
+		cv_PtrOfTonemapReinhard_delete, cv_PtrOfTonemapReinhard_getInnerPtr_const, cv_PtrOfTonemapReinhard_getInnerPtrMut, cv_PtrOfTonemapReinhard_clone_const
 	}
 	
 	impl core::Ptr<crate::photo::TonemapReinhard> {
@@ -14366,7 +14405,7 @@ mod plot_types {
 	pub type PtrOfPlot2d = core::Ptr<crate::plot::Plot2d>;
 	
 	ptr_extern! { crate::plot::Plot2d,
-		cv_PtrOfPlot2d_delete, cv_PtrOfPlot2d_getInnerPtr_const, cv_PtrOfPlot2d_getInnerPtrMut
+		cv_PtrOfPlot2d_delete, cv_PtrOfPlot2d_getInnerPtr_const, cv_PtrOfPlot2d_getInnerPtrMut, cv_PtrOfPlot2d_clone_const
 	}
 	
 	impl core::Ptr<crate::plot::Plot2d> {
@@ -14409,7 +14448,7 @@ mod quality_types {
 	pub type PtrOfQualityBRISQUE = core::Ptr<crate::quality::QualityBRISQUE>;
 	
 	ptr_extern! { crate::quality::QualityBRISQUE,
-		cv_PtrOfQualityBRISQUE_delete, cv_PtrOfQualityBRISQUE_getInnerPtr_const, cv_PtrOfQualityBRISQUE_getInnerPtrMut
+		cv_PtrOfQualityBRISQUE_delete, cv_PtrOfQualityBRISQUE_getInnerPtr_const, cv_PtrOfQualityBRISQUE_getInnerPtrMut, cv_PtrOfQualityBRISQUE_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::quality::QualityBRISQUE, cv_PtrOfQualityBRISQUE_new_const_QualityBRISQUE }
@@ -14453,7 +14492,7 @@ mod quality_types {
 	pub type PtrOfQualityGMSD = core::Ptr<crate::quality::QualityGMSD>;
 	
 	ptr_extern! { crate::quality::QualityGMSD,
-		cv_PtrOfQualityGMSD_delete, cv_PtrOfQualityGMSD_getInnerPtr_const, cv_PtrOfQualityGMSD_getInnerPtrMut
+		cv_PtrOfQualityGMSD_delete, cv_PtrOfQualityGMSD_getInnerPtr_const, cv_PtrOfQualityGMSD_getInnerPtrMut, cv_PtrOfQualityGMSD_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::quality::QualityGMSD, cv_PtrOfQualityGMSD_new_const_QualityGMSD }
@@ -14497,7 +14536,7 @@ mod quality_types {
 	pub type PtrOfQualityMSE = core::Ptr<crate::quality::QualityMSE>;
 	
 	ptr_extern! { crate::quality::QualityMSE,
-		cv_PtrOfQualityMSE_delete, cv_PtrOfQualityMSE_getInnerPtr_const, cv_PtrOfQualityMSE_getInnerPtrMut
+		cv_PtrOfQualityMSE_delete, cv_PtrOfQualityMSE_getInnerPtr_const, cv_PtrOfQualityMSE_getInnerPtrMut, cv_PtrOfQualityMSE_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::quality::QualityMSE, cv_PtrOfQualityMSE_new_const_QualityMSE }
@@ -14541,7 +14580,7 @@ mod quality_types {
 	pub type PtrOfQualityPSNR = core::Ptr<crate::quality::QualityPSNR>;
 	
 	ptr_extern! { crate::quality::QualityPSNR,
-		cv_PtrOfQualityPSNR_delete, cv_PtrOfQualityPSNR_getInnerPtr_const, cv_PtrOfQualityPSNR_getInnerPtrMut
+		cv_PtrOfQualityPSNR_delete, cv_PtrOfQualityPSNR_getInnerPtr_const, cv_PtrOfQualityPSNR_getInnerPtrMut, cv_PtrOfQualityPSNR_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::quality::QualityPSNR, cv_PtrOfQualityPSNR_new_const_QualityPSNR }
@@ -14585,7 +14624,7 @@ mod quality_types {
 	pub type PtrOfQualitySSIM = core::Ptr<crate::quality::QualitySSIM>;
 	
 	ptr_extern! { crate::quality::QualitySSIM,
-		cv_PtrOfQualitySSIM_delete, cv_PtrOfQualitySSIM_getInnerPtr_const, cv_PtrOfQualitySSIM_getInnerPtrMut
+		cv_PtrOfQualitySSIM_delete, cv_PtrOfQualitySSIM_getInnerPtr_const, cv_PtrOfQualitySSIM_getInnerPtrMut, cv_PtrOfQualitySSIM_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::quality::QualitySSIM, cv_PtrOfQualitySSIM_new_const_QualitySSIM }
@@ -14637,7 +14676,7 @@ mod rapid_types {
 	pub type PtrOfRapid_OLSTracker = core::Ptr<crate::rapid::Rapid_OLSTracker>;
 	
 	ptr_extern! { crate::rapid::Rapid_OLSTracker,
-		cv_PtrOfRapid_OLSTracker_delete, cv_PtrOfRapid_OLSTracker_getInnerPtr_const, cv_PtrOfRapid_OLSTracker_getInnerPtrMut
+		cv_PtrOfRapid_OLSTracker_delete, cv_PtrOfRapid_OLSTracker_getInnerPtr_const, cv_PtrOfRapid_OLSTracker_getInnerPtrMut, cv_PtrOfRapid_OLSTracker_clone_const
 	}
 	
 	impl core::Ptr<crate::rapid::Rapid_OLSTracker> {
@@ -14680,7 +14719,7 @@ mod rapid_types {
 	pub type PtrOfRapid_Rapid = core::Ptr<crate::rapid::Rapid_Rapid>;
 	
 	ptr_extern! { crate::rapid::Rapid_Rapid,
-		cv_PtrOfRapid_Rapid_delete, cv_PtrOfRapid_Rapid_getInnerPtr_const, cv_PtrOfRapid_Rapid_getInnerPtrMut
+		cv_PtrOfRapid_Rapid_delete, cv_PtrOfRapid_Rapid_getInnerPtr_const, cv_PtrOfRapid_Rapid_getInnerPtrMut, cv_PtrOfRapid_Rapid_clone_const
 	}
 	
 	impl core::Ptr<crate::rapid::Rapid_Rapid> {
@@ -14731,7 +14770,7 @@ mod rgbd_types {
 	pub type PtrOfColoredKinfu_ColoredKinFu = core::Ptr<crate::rgbd::ColoredKinfu_ColoredKinFu>;
 	
 	ptr_extern! { crate::rgbd::ColoredKinfu_ColoredKinFu,
-		cv_PtrOfColoredKinfu_ColoredKinFu_delete, cv_PtrOfColoredKinfu_ColoredKinFu_getInnerPtr_const, cv_PtrOfColoredKinfu_ColoredKinFu_getInnerPtrMut
+		cv_PtrOfColoredKinfu_ColoredKinFu_delete, cv_PtrOfColoredKinfu_ColoredKinFu_getInnerPtr_const, cv_PtrOfColoredKinfu_ColoredKinFu_getInnerPtrMut, cv_PtrOfColoredKinfu_ColoredKinFu_clone_const
 	}
 	
 	impl core::Ptr<crate::rgbd::ColoredKinfu_ColoredKinFu> {
@@ -14758,7 +14797,7 @@ mod rgbd_types {
 	pub type PtrOfColoredKinfu_Params = core::Ptr<crate::rgbd::ColoredKinfu_Params>;
 	
 	ptr_extern! { crate::rgbd::ColoredKinfu_Params,
-		cv_PtrOfColoredKinfu_Params_delete, cv_PtrOfColoredKinfu_Params_getInnerPtr_const, cv_PtrOfColoredKinfu_Params_getInnerPtrMut
+		cv_PtrOfColoredKinfu_Params_delete, cv_PtrOfColoredKinfu_Params_getInnerPtr_const, cv_PtrOfColoredKinfu_Params_getInnerPtrMut, cv_PtrOfColoredKinfu_Params_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::ColoredKinfu_Params, cv_PtrOfColoredKinfu_Params_new_const_Params }
@@ -14808,7 +14847,7 @@ mod rgbd_types {
 	pub type PtrOfDepthCleaner = core::Ptr<crate::rgbd::DepthCleaner>;
 	
 	ptr_extern! { crate::rgbd::DepthCleaner,
-		cv_PtrOfDepthCleaner_delete, cv_PtrOfDepthCleaner_getInnerPtr_const, cv_PtrOfDepthCleaner_getInnerPtrMut
+		cv_PtrOfDepthCleaner_delete, cv_PtrOfDepthCleaner_getInnerPtr_const, cv_PtrOfDepthCleaner_getInnerPtrMut, cv_PtrOfDepthCleaner_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::DepthCleaner, cv_PtrOfDepthCleaner_new_const_DepthCleaner }
@@ -14844,7 +14883,7 @@ mod rgbd_types {
 	pub type PtrOfDynafu_DynaFu = core::Ptr<crate::rgbd::Dynafu_DynaFu>;
 	
 	ptr_extern! { crate::rgbd::Dynafu_DynaFu,
-		cv_PtrOfDynafu_DynaFu_delete, cv_PtrOfDynafu_DynaFu_getInnerPtr_const, cv_PtrOfDynafu_DynaFu_getInnerPtrMut
+		cv_PtrOfDynafu_DynaFu_delete, cv_PtrOfDynafu_DynaFu_getInnerPtr_const, cv_PtrOfDynafu_DynaFu_getInnerPtrMut, cv_PtrOfDynafu_DynaFu_clone_const
 	}
 	
 	impl core::Ptr<crate::rgbd::Dynafu_DynaFu> {
@@ -14871,7 +14910,7 @@ mod rgbd_types {
 	pub type PtrOfFastICPOdometry = core::Ptr<crate::rgbd::FastICPOdometry>;
 	
 	ptr_extern! { crate::rgbd::FastICPOdometry,
-		cv_PtrOfFastICPOdometry_delete, cv_PtrOfFastICPOdometry_getInnerPtr_const, cv_PtrOfFastICPOdometry_getInnerPtrMut
+		cv_PtrOfFastICPOdometry_delete, cv_PtrOfFastICPOdometry_getInnerPtr_const, cv_PtrOfFastICPOdometry_getInnerPtrMut, cv_PtrOfFastICPOdometry_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::FastICPOdometry, cv_PtrOfFastICPOdometry_new_const_FastICPOdometry }
@@ -14915,7 +14954,7 @@ mod rgbd_types {
 	pub type PtrOfICPOdometry = core::Ptr<crate::rgbd::ICPOdometry>;
 	
 	ptr_extern! { crate::rgbd::ICPOdometry,
-		cv_PtrOfICPOdometry_delete, cv_PtrOfICPOdometry_getInnerPtr_const, cv_PtrOfICPOdometry_getInnerPtrMut
+		cv_PtrOfICPOdometry_delete, cv_PtrOfICPOdometry_getInnerPtr_const, cv_PtrOfICPOdometry_getInnerPtrMut, cv_PtrOfICPOdometry_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::ICPOdometry, cv_PtrOfICPOdometry_new_const_ICPOdometry }
@@ -14959,7 +14998,7 @@ mod rgbd_types {
 	pub type PtrOfKinfu_Detail_PoseGraph = core::Ptr<crate::rgbd::Kinfu_Detail_PoseGraph>;
 	
 	ptr_extern! { crate::rgbd::Kinfu_Detail_PoseGraph,
-		cv_PtrOfKinfu_Detail_PoseGraph_delete, cv_PtrOfKinfu_Detail_PoseGraph_getInnerPtr_const, cv_PtrOfKinfu_Detail_PoseGraph_getInnerPtrMut
+		cv_PtrOfKinfu_Detail_PoseGraph_delete, cv_PtrOfKinfu_Detail_PoseGraph_getInnerPtr_const, cv_PtrOfKinfu_Detail_PoseGraph_getInnerPtrMut, cv_PtrOfKinfu_Detail_PoseGraph_clone_const
 	}
 	
 	impl core::Ptr<crate::rgbd::Kinfu_Detail_PoseGraph> {
@@ -14986,7 +15025,7 @@ mod rgbd_types {
 	pub type PtrOfKinfu_KinFu = core::Ptr<crate::rgbd::Kinfu_KinFu>;
 	
 	ptr_extern! { crate::rgbd::Kinfu_KinFu,
-		cv_PtrOfKinfu_KinFu_delete, cv_PtrOfKinfu_KinFu_getInnerPtr_const, cv_PtrOfKinfu_KinFu_getInnerPtrMut
+		cv_PtrOfKinfu_KinFu_delete, cv_PtrOfKinfu_KinFu_getInnerPtr_const, cv_PtrOfKinfu_KinFu_getInnerPtrMut, cv_PtrOfKinfu_KinFu_clone_const
 	}
 	
 	impl core::Ptr<crate::rgbd::Kinfu_KinFu> {
@@ -15013,7 +15052,7 @@ mod rgbd_types {
 	pub type PtrOfKinfu_Params = core::Ptr<crate::rgbd::Kinfu_Params>;
 	
 	ptr_extern! { crate::rgbd::Kinfu_Params,
-		cv_PtrOfKinfu_Params_delete, cv_PtrOfKinfu_Params_getInnerPtr_const, cv_PtrOfKinfu_Params_getInnerPtrMut
+		cv_PtrOfKinfu_Params_delete, cv_PtrOfKinfu_Params_getInnerPtr_const, cv_PtrOfKinfu_Params_getInnerPtrMut, cv_PtrOfKinfu_Params_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::Kinfu_Params, cv_PtrOfKinfu_Params_new_const_Params }
@@ -15062,7 +15101,7 @@ mod rgbd_types {
 	pub type PtrOfKinfu_Volume = core::Ptr<crate::rgbd::Kinfu_Volume>;
 	
 	ptr_extern! { crate::rgbd::Kinfu_Volume,
-		cv_PtrOfKinfu_Volume_delete, cv_PtrOfKinfu_Volume_getInnerPtr_const, cv_PtrOfKinfu_Volume_getInnerPtrMut
+		cv_PtrOfKinfu_Volume_delete, cv_PtrOfKinfu_Volume_getInnerPtr_const, cv_PtrOfKinfu_Volume_getInnerPtrMut, cv_PtrOfKinfu_Volume_clone_const
 	}
 	
 	impl core::Ptr<crate::rgbd::Kinfu_Volume> {
@@ -15093,7 +15132,7 @@ mod rgbd_types {
 	pub type PtrOfKinfu_VolumeParams = core::Ptr<crate::rgbd::Kinfu_VolumeParams>;
 	
 	ptr_extern! { crate::rgbd::Kinfu_VolumeParams,
-		cv_PtrOfKinfu_VolumeParams_delete, cv_PtrOfKinfu_VolumeParams_getInnerPtr_const, cv_PtrOfKinfu_VolumeParams_getInnerPtrMut
+		cv_PtrOfKinfu_VolumeParams_delete, cv_PtrOfKinfu_VolumeParams_getInnerPtr_const, cv_PtrOfKinfu_VolumeParams_getInnerPtrMut, cv_PtrOfKinfu_VolumeParams_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::Kinfu_VolumeParams, cv_PtrOfKinfu_VolumeParams_new_const_VolumeParams }
@@ -15130,7 +15169,7 @@ mod rgbd_types {
 	pub type PtrOfLargeKinfu = core::Ptr<crate::rgbd::LargeKinfu>;
 	
 	ptr_extern! { crate::rgbd::LargeKinfu,
-		cv_PtrOfLargeKinfu_delete, cv_PtrOfLargeKinfu_getInnerPtr_const, cv_PtrOfLargeKinfu_getInnerPtrMut
+		cv_PtrOfLargeKinfu_delete, cv_PtrOfLargeKinfu_getInnerPtr_const, cv_PtrOfLargeKinfu_getInnerPtrMut, cv_PtrOfLargeKinfu_clone_const
 	}
 	
 	impl core::Ptr<crate::rgbd::LargeKinfu> {
@@ -15157,7 +15196,7 @@ mod rgbd_types {
 	pub type PtrOfLineMod_ColorGradient = core::Ptr<crate::rgbd::LineMod_ColorGradient>;
 	
 	ptr_extern! { crate::rgbd::LineMod_ColorGradient,
-		cv_PtrOfLineMod_ColorGradient_delete, cv_PtrOfLineMod_ColorGradient_getInnerPtr_const, cv_PtrOfLineMod_ColorGradient_getInnerPtrMut
+		cv_PtrOfLineMod_ColorGradient_delete, cv_PtrOfLineMod_ColorGradient_getInnerPtr_const, cv_PtrOfLineMod_ColorGradient_getInnerPtrMut, cv_PtrOfLineMod_ColorGradient_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::LineMod_ColorGradient, cv_PtrOfLineMod_ColorGradient_new_const_ColorGradient }
@@ -15196,7 +15235,7 @@ mod rgbd_types {
 	pub type PtrOfLineMod_DepthNormal = core::Ptr<crate::rgbd::LineMod_DepthNormal>;
 	
 	ptr_extern! { crate::rgbd::LineMod_DepthNormal,
-		cv_PtrOfLineMod_DepthNormal_delete, cv_PtrOfLineMod_DepthNormal_getInnerPtr_const, cv_PtrOfLineMod_DepthNormal_getInnerPtrMut
+		cv_PtrOfLineMod_DepthNormal_delete, cv_PtrOfLineMod_DepthNormal_getInnerPtr_const, cv_PtrOfLineMod_DepthNormal_getInnerPtrMut, cv_PtrOfLineMod_DepthNormal_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::LineMod_DepthNormal, cv_PtrOfLineMod_DepthNormal_new_const_DepthNormal }
@@ -15236,7 +15275,7 @@ mod rgbd_types {
 	pub type PtrOfLineMod_Detector = core::Ptr<crate::rgbd::LineMod_Detector>;
 	
 	ptr_extern! { crate::rgbd::LineMod_Detector,
-		cv_PtrOfLineMod_Detector_delete, cv_PtrOfLineMod_Detector_getInnerPtr_const, cv_PtrOfLineMod_Detector_getInnerPtrMut
+		cv_PtrOfLineMod_Detector_delete, cv_PtrOfLineMod_Detector_getInnerPtr_const, cv_PtrOfLineMod_Detector_getInnerPtrMut, cv_PtrOfLineMod_Detector_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::LineMod_Detector, cv_PtrOfLineMod_Detector_new_const_Detector }
@@ -15264,7 +15303,7 @@ mod rgbd_types {
 	pub type PtrOfLineMod_Modality = core::Ptr<crate::rgbd::LineMod_Modality>;
 	
 	ptr_extern! { crate::rgbd::LineMod_Modality,
-		cv_PtrOfLineMod_Modality_delete, cv_PtrOfLineMod_Modality_getInnerPtr_const, cv_PtrOfLineMod_Modality_getInnerPtrMut
+		cv_PtrOfLineMod_Modality_delete, cv_PtrOfLineMod_Modality_getInnerPtr_const, cv_PtrOfLineMod_Modality_getInnerPtrMut, cv_PtrOfLineMod_Modality_clone_const
 	}
 	
 	impl core::Ptr<crate::rgbd::LineMod_Modality> {
@@ -15291,7 +15330,7 @@ mod rgbd_types {
 	pub type PtrOfLineMod_QuantizedPyramid = core::Ptr<crate::rgbd::LineMod_QuantizedPyramid>;
 	
 	ptr_extern! { crate::rgbd::LineMod_QuantizedPyramid,
-		cv_PtrOfLineMod_QuantizedPyramid_delete, cv_PtrOfLineMod_QuantizedPyramid_getInnerPtr_const, cv_PtrOfLineMod_QuantizedPyramid_getInnerPtrMut
+		cv_PtrOfLineMod_QuantizedPyramid_delete, cv_PtrOfLineMod_QuantizedPyramid_getInnerPtr_const, cv_PtrOfLineMod_QuantizedPyramid_getInnerPtrMut, cv_PtrOfLineMod_QuantizedPyramid_clone_const
 	}
 	
 	impl core::Ptr<crate::rgbd::LineMod_QuantizedPyramid> {
@@ -15318,7 +15357,7 @@ mod rgbd_types {
 	pub type PtrOfOdometry = core::Ptr<crate::rgbd::Odometry>;
 	
 	ptr_extern! { crate::rgbd::Odometry,
-		cv_PtrOfOdometry_delete, cv_PtrOfOdometry_getInnerPtr_const, cv_PtrOfOdometry_getInnerPtrMut
+		cv_PtrOfOdometry_delete, cv_PtrOfOdometry_getInnerPtr_const, cv_PtrOfOdometry_getInnerPtrMut, cv_PtrOfOdometry_clone_const
 	}
 	
 	impl core::Ptr<crate::rgbd::Odometry> {
@@ -15353,7 +15392,7 @@ mod rgbd_types {
 	pub type PtrOfOdometryFrame = core::Ptr<crate::rgbd::OdometryFrame>;
 	
 	ptr_extern! { crate::rgbd::OdometryFrame,
-		cv_PtrOfOdometryFrame_delete, cv_PtrOfOdometryFrame_getInnerPtr_const, cv_PtrOfOdometryFrame_getInnerPtrMut
+		cv_PtrOfOdometryFrame_delete, cv_PtrOfOdometryFrame_getInnerPtr_const, cv_PtrOfOdometryFrame_getInnerPtrMut, cv_PtrOfOdometryFrame_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::OdometryFrame, cv_PtrOfOdometryFrame_new_const_OdometryFrame }
@@ -15403,7 +15442,7 @@ mod rgbd_types {
 	pub type PtrOfParams = core::Ptr<crate::rgbd::Params>;
 	
 	ptr_extern! { crate::rgbd::Params,
-		cv_PtrOfParams_delete, cv_PtrOfParams_getInnerPtr_const, cv_PtrOfParams_getInnerPtrMut
+		cv_PtrOfParams_delete, cv_PtrOfParams_getInnerPtr_const, cv_PtrOfParams_getInnerPtrMut, cv_PtrOfParams_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::Params, cv_PtrOfParams_new_const_Params }
@@ -15446,7 +15485,7 @@ mod rgbd_types {
 	pub type PtrOfRgbdFrame = core::Ptr<crate::rgbd::RgbdFrame>;
 	
 	ptr_extern! { crate::rgbd::RgbdFrame,
-		cv_PtrOfRgbdFrame_delete, cv_PtrOfRgbdFrame_getInnerPtr_const, cv_PtrOfRgbdFrame_getInnerPtrMut
+		cv_PtrOfRgbdFrame_delete, cv_PtrOfRgbdFrame_getInnerPtr_const, cv_PtrOfRgbdFrame_getInnerPtrMut, cv_PtrOfRgbdFrame_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::RgbdFrame, cv_PtrOfRgbdFrame_new_const_RgbdFrame }
@@ -15479,7 +15518,7 @@ mod rgbd_types {
 	pub type PtrOfRgbdICPOdometry = core::Ptr<crate::rgbd::RgbdICPOdometry>;
 	
 	ptr_extern! { crate::rgbd::RgbdICPOdometry,
-		cv_PtrOfRgbdICPOdometry_delete, cv_PtrOfRgbdICPOdometry_getInnerPtr_const, cv_PtrOfRgbdICPOdometry_getInnerPtrMut
+		cv_PtrOfRgbdICPOdometry_delete, cv_PtrOfRgbdICPOdometry_getInnerPtr_const, cv_PtrOfRgbdICPOdometry_getInnerPtrMut, cv_PtrOfRgbdICPOdometry_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::RgbdICPOdometry, cv_PtrOfRgbdICPOdometry_new_const_RgbdICPOdometry }
@@ -15523,7 +15562,7 @@ mod rgbd_types {
 	pub type PtrOfRgbdNormals = core::Ptr<crate::rgbd::RgbdNormals>;
 	
 	ptr_extern! { crate::rgbd::RgbdNormals,
-		cv_PtrOfRgbdNormals_delete, cv_PtrOfRgbdNormals_getInnerPtr_const, cv_PtrOfRgbdNormals_getInnerPtrMut
+		cv_PtrOfRgbdNormals_delete, cv_PtrOfRgbdNormals_getInnerPtr_const, cv_PtrOfRgbdNormals_getInnerPtrMut, cv_PtrOfRgbdNormals_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::RgbdNormals, cv_PtrOfRgbdNormals_new_const_RgbdNormals }
@@ -15559,7 +15598,7 @@ mod rgbd_types {
 	pub type PtrOfRgbdOdometry = core::Ptr<crate::rgbd::RgbdOdometry>;
 	
 	ptr_extern! { crate::rgbd::RgbdOdometry,
-		cv_PtrOfRgbdOdometry_delete, cv_PtrOfRgbdOdometry_getInnerPtr_const, cv_PtrOfRgbdOdometry_getInnerPtrMut
+		cv_PtrOfRgbdOdometry_delete, cv_PtrOfRgbdOdometry_getInnerPtr_const, cv_PtrOfRgbdOdometry_getInnerPtrMut, cv_PtrOfRgbdOdometry_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::RgbdOdometry, cv_PtrOfRgbdOdometry_new_const_RgbdOdometry }
@@ -15603,7 +15642,7 @@ mod rgbd_types {
 	pub type PtrOfRgbdPlane = core::Ptr<crate::rgbd::RgbdPlane>;
 	
 	ptr_extern! { crate::rgbd::RgbdPlane,
-		cv_PtrOfRgbdPlane_delete, cv_PtrOfRgbdPlane_getInnerPtr_const, cv_PtrOfRgbdPlane_getInnerPtrMut
+		cv_PtrOfRgbdPlane_delete, cv_PtrOfRgbdPlane_getInnerPtr_const, cv_PtrOfRgbdPlane_getInnerPtrMut, cv_PtrOfRgbdPlane_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::rgbd::RgbdPlane, cv_PtrOfRgbdPlane_new_const_RgbdPlane }
@@ -15722,7 +15761,7 @@ mod saliency_types {
 	pub type PtrOfMotionSaliencyBinWangApr2014 = core::Ptr<crate::saliency::MotionSaliencyBinWangApr2014>;
 	
 	ptr_extern! { crate::saliency::MotionSaliencyBinWangApr2014,
-		cv_PtrOfMotionSaliencyBinWangApr2014_delete, cv_PtrOfMotionSaliencyBinWangApr2014_getInnerPtr_const, cv_PtrOfMotionSaliencyBinWangApr2014_getInnerPtrMut
+		cv_PtrOfMotionSaliencyBinWangApr2014_delete, cv_PtrOfMotionSaliencyBinWangApr2014_getInnerPtr_const, cv_PtrOfMotionSaliencyBinWangApr2014_getInnerPtrMut, cv_PtrOfMotionSaliencyBinWangApr2014_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::saliency::MotionSaliencyBinWangApr2014, cv_PtrOfMotionSaliencyBinWangApr2014_new_const_MotionSaliencyBinWangApr2014 }
@@ -15774,7 +15813,7 @@ mod saliency_types {
 	pub type PtrOfObjectnessBING = core::Ptr<crate::saliency::ObjectnessBING>;
 	
 	ptr_extern! { crate::saliency::ObjectnessBING,
-		cv_PtrOfObjectnessBING_delete, cv_PtrOfObjectnessBING_getInnerPtr_const, cv_PtrOfObjectnessBING_getInnerPtrMut
+		cv_PtrOfObjectnessBING_delete, cv_PtrOfObjectnessBING_getInnerPtr_const, cv_PtrOfObjectnessBING_getInnerPtrMut, cv_PtrOfObjectnessBING_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::saliency::ObjectnessBING, cv_PtrOfObjectnessBING_new_const_ObjectnessBING }
@@ -15826,7 +15865,7 @@ mod saliency_types {
 	pub type PtrOfStaticSaliencyFineGrained = core::Ptr<crate::saliency::StaticSaliencyFineGrained>;
 	
 	ptr_extern! { crate::saliency::StaticSaliencyFineGrained,
-		cv_PtrOfStaticSaliencyFineGrained_delete, cv_PtrOfStaticSaliencyFineGrained_getInnerPtr_const, cv_PtrOfStaticSaliencyFineGrained_getInnerPtrMut
+		cv_PtrOfStaticSaliencyFineGrained_delete, cv_PtrOfStaticSaliencyFineGrained_getInnerPtr_const, cv_PtrOfStaticSaliencyFineGrained_getInnerPtrMut, cv_PtrOfStaticSaliencyFineGrained_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::saliency::StaticSaliencyFineGrained, cv_PtrOfStaticSaliencyFineGrained_new_const_StaticSaliencyFineGrained }
@@ -15878,7 +15917,7 @@ mod saliency_types {
 	pub type PtrOfStaticSaliencySpectralResidual = core::Ptr<crate::saliency::StaticSaliencySpectralResidual>;
 	
 	ptr_extern! { crate::saliency::StaticSaliencySpectralResidual,
-		cv_PtrOfStaticSaliencySpectralResidual_delete, cv_PtrOfStaticSaliencySpectralResidual_getInnerPtr_const, cv_PtrOfStaticSaliencySpectralResidual_getInnerPtrMut
+		cv_PtrOfStaticSaliencySpectralResidual_delete, cv_PtrOfStaticSaliencySpectralResidual_getInnerPtr_const, cv_PtrOfStaticSaliencySpectralResidual_getInnerPtrMut, cv_PtrOfStaticSaliencySpectralResidual_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::saliency::StaticSaliencySpectralResidual, cv_PtrOfStaticSaliencySpectralResidual_new_const_StaticSaliencySpectralResidual }
@@ -15938,7 +15977,7 @@ mod sfm_types {
 	pub type PtrOfSFMLibmvEuclideanReconstruction = core::Ptr<crate::sfm::SFMLibmvEuclideanReconstruction>;
 	
 	ptr_extern! { crate::sfm::SFMLibmvEuclideanReconstruction,
-		cv_PtrOfSFMLibmvEuclideanReconstruction_delete, cv_PtrOfSFMLibmvEuclideanReconstruction_getInnerPtr_const, cv_PtrOfSFMLibmvEuclideanReconstruction_getInnerPtrMut
+		cv_PtrOfSFMLibmvEuclideanReconstruction_delete, cv_PtrOfSFMLibmvEuclideanReconstruction_getInnerPtr_const, cv_PtrOfSFMLibmvEuclideanReconstruction_getInnerPtrMut, cv_PtrOfSFMLibmvEuclideanReconstruction_clone_const
 	}
 	
 	impl core::Ptr<crate::sfm::SFMLibmvEuclideanReconstruction> {
@@ -15981,7 +16020,7 @@ mod shape_types {
 	pub type PtrOfAffineTransformer = core::Ptr<crate::shape::AffineTransformer>;
 	
 	ptr_extern! { crate::shape::AffineTransformer,
-		cv_PtrOfAffineTransformer_delete, cv_PtrOfAffineTransformer_getInnerPtr_const, cv_PtrOfAffineTransformer_getInnerPtrMut
+		cv_PtrOfAffineTransformer_delete, cv_PtrOfAffineTransformer_getInnerPtr_const, cv_PtrOfAffineTransformer_getInnerPtrMut, cv_PtrOfAffineTransformer_clone_const
 	}
 	
 	impl core::Ptr<crate::shape::AffineTransformer> {
@@ -16015,6 +16054,7 @@ mod shape_types {
 	
 	ptr_cast_base! { PtrOfAffineTransformer, core::Ptr<crate::shape::ShapeTransformer>, cv_PtrOfAffineTransformer_to_PtrOfShapeTransformer }
 	
+	ptr_cast_descendant! { core::Ptr<crate::shape::ShapeTransformer>, PtrOfAffineTransformer, cv_PtrOfShapeTransformer_to_PtrOfAffineTransformer }
 	impl std::fmt::Debug for core::Ptr<crate::shape::AffineTransformer> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16026,7 +16066,7 @@ mod shape_types {
 	pub type PtrOfChiHistogramCostExtractor = core::Ptr<crate::shape::ChiHistogramCostExtractor>;
 	
 	ptr_extern! { crate::shape::ChiHistogramCostExtractor,
-		cv_PtrOfChiHistogramCostExtractor_delete, cv_PtrOfChiHistogramCostExtractor_getInnerPtr_const, cv_PtrOfChiHistogramCostExtractor_getInnerPtrMut
+		cv_PtrOfChiHistogramCostExtractor_delete, cv_PtrOfChiHistogramCostExtractor_getInnerPtr_const, cv_PtrOfChiHistogramCostExtractor_getInnerPtrMut, cv_PtrOfChiHistogramCostExtractor_clone_const
 	}
 	
 	impl core::Ptr<crate::shape::ChiHistogramCostExtractor> {
@@ -16060,6 +16100,7 @@ mod shape_types {
 	
 	ptr_cast_base! { PtrOfChiHistogramCostExtractor, core::Ptr<crate::shape::HistogramCostExtractor>, cv_PtrOfChiHistogramCostExtractor_to_PtrOfHistogramCostExtractor }
 	
+	ptr_cast_descendant! { core::Ptr<crate::shape::HistogramCostExtractor>, PtrOfChiHistogramCostExtractor, cv_PtrOfHistogramCostExtractor_to_PtrOfChiHistogramCostExtractor }
 	impl std::fmt::Debug for core::Ptr<crate::shape::ChiHistogramCostExtractor> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16071,7 +16112,7 @@ mod shape_types {
 	pub type PtrOfEMDHistogramCostExtractor = core::Ptr<crate::shape::EMDHistogramCostExtractor>;
 	
 	ptr_extern! { crate::shape::EMDHistogramCostExtractor,
-		cv_PtrOfEMDHistogramCostExtractor_delete, cv_PtrOfEMDHistogramCostExtractor_getInnerPtr_const, cv_PtrOfEMDHistogramCostExtractor_getInnerPtrMut
+		cv_PtrOfEMDHistogramCostExtractor_delete, cv_PtrOfEMDHistogramCostExtractor_getInnerPtr_const, cv_PtrOfEMDHistogramCostExtractor_getInnerPtrMut, cv_PtrOfEMDHistogramCostExtractor_clone_const
 	}
 	
 	impl core::Ptr<crate::shape::EMDHistogramCostExtractor> {
@@ -16105,6 +16146,7 @@ mod shape_types {
 	
 	ptr_cast_base! { PtrOfEMDHistogramCostExtractor, core::Ptr<crate::shape::HistogramCostExtractor>, cv_PtrOfEMDHistogramCostExtractor_to_PtrOfHistogramCostExtractor }
 	
+	ptr_cast_descendant! { core::Ptr<crate::shape::HistogramCostExtractor>, PtrOfEMDHistogramCostExtractor, cv_PtrOfHistogramCostExtractor_to_PtrOfEMDHistogramCostExtractor }
 	impl std::fmt::Debug for core::Ptr<crate::shape::EMDHistogramCostExtractor> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16116,7 +16158,7 @@ mod shape_types {
 	pub type PtrOfEMDL1HistogramCostExtractor = core::Ptr<crate::shape::EMDL1HistogramCostExtractor>;
 	
 	ptr_extern! { crate::shape::EMDL1HistogramCostExtractor,
-		cv_PtrOfEMDL1HistogramCostExtractor_delete, cv_PtrOfEMDL1HistogramCostExtractor_getInnerPtr_const, cv_PtrOfEMDL1HistogramCostExtractor_getInnerPtrMut
+		cv_PtrOfEMDL1HistogramCostExtractor_delete, cv_PtrOfEMDL1HistogramCostExtractor_getInnerPtr_const, cv_PtrOfEMDL1HistogramCostExtractor_getInnerPtrMut, cv_PtrOfEMDL1HistogramCostExtractor_clone_const
 	}
 	
 	impl core::Ptr<crate::shape::EMDL1HistogramCostExtractor> {
@@ -16150,6 +16192,7 @@ mod shape_types {
 	
 	ptr_cast_base! { PtrOfEMDL1HistogramCostExtractor, core::Ptr<crate::shape::HistogramCostExtractor>, cv_PtrOfEMDL1HistogramCostExtractor_to_PtrOfHistogramCostExtractor }
 	
+	ptr_cast_descendant! { core::Ptr<crate::shape::HistogramCostExtractor>, PtrOfEMDL1HistogramCostExtractor, cv_PtrOfHistogramCostExtractor_to_PtrOfEMDL1HistogramCostExtractor }
 	impl std::fmt::Debug for core::Ptr<crate::shape::EMDL1HistogramCostExtractor> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16161,7 +16204,7 @@ mod shape_types {
 	pub type PtrOfHausdorffDistanceExtractor = core::Ptr<crate::shape::HausdorffDistanceExtractor>;
 	
 	ptr_extern! { crate::shape::HausdorffDistanceExtractor,
-		cv_PtrOfHausdorffDistanceExtractor_delete, cv_PtrOfHausdorffDistanceExtractor_getInnerPtr_const, cv_PtrOfHausdorffDistanceExtractor_getInnerPtrMut
+		cv_PtrOfHausdorffDistanceExtractor_delete, cv_PtrOfHausdorffDistanceExtractor_getInnerPtr_const, cv_PtrOfHausdorffDistanceExtractor_getInnerPtrMut, cv_PtrOfHausdorffDistanceExtractor_clone_const
 	}
 	
 	impl core::Ptr<crate::shape::HausdorffDistanceExtractor> {
@@ -16204,7 +16247,7 @@ mod shape_types {
 	pub type PtrOfHistogramCostExtractor = core::Ptr<crate::shape::HistogramCostExtractor>;
 	
 	ptr_extern! { crate::shape::HistogramCostExtractor,
-		cv_PtrOfHistogramCostExtractor_delete, cv_PtrOfHistogramCostExtractor_getInnerPtr_const, cv_PtrOfHistogramCostExtractor_getInnerPtrMut
+		cv_PtrOfHistogramCostExtractor_delete, cv_PtrOfHistogramCostExtractor_getInnerPtr_const, cv_PtrOfHistogramCostExtractor_getInnerPtrMut, cv_PtrOfHistogramCostExtractor_clone_const
 	}
 	
 	impl core::Ptr<crate::shape::HistogramCostExtractor> {
@@ -16239,7 +16282,7 @@ mod shape_types {
 	pub type PtrOfNormHistogramCostExtractor = core::Ptr<crate::shape::NormHistogramCostExtractor>;
 	
 	ptr_extern! { crate::shape::NormHistogramCostExtractor,
-		cv_PtrOfNormHistogramCostExtractor_delete, cv_PtrOfNormHistogramCostExtractor_getInnerPtr_const, cv_PtrOfNormHistogramCostExtractor_getInnerPtrMut
+		cv_PtrOfNormHistogramCostExtractor_delete, cv_PtrOfNormHistogramCostExtractor_getInnerPtr_const, cv_PtrOfNormHistogramCostExtractor_getInnerPtrMut, cv_PtrOfNormHistogramCostExtractor_clone_const
 	}
 	
 	impl core::Ptr<crate::shape::NormHistogramCostExtractor> {
@@ -16273,6 +16316,7 @@ mod shape_types {
 	
 	ptr_cast_base! { PtrOfNormHistogramCostExtractor, core::Ptr<crate::shape::HistogramCostExtractor>, cv_PtrOfNormHistogramCostExtractor_to_PtrOfHistogramCostExtractor }
 	
+	ptr_cast_descendant! { core::Ptr<crate::shape::HistogramCostExtractor>, PtrOfNormHistogramCostExtractor, cv_PtrOfHistogramCostExtractor_to_PtrOfNormHistogramCostExtractor }
 	impl std::fmt::Debug for core::Ptr<crate::shape::NormHistogramCostExtractor> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16284,7 +16328,7 @@ mod shape_types {
 	pub type PtrOfShapeContextDistanceExtractor = core::Ptr<crate::shape::ShapeContextDistanceExtractor>;
 	
 	ptr_extern! { crate::shape::ShapeContextDistanceExtractor,
-		cv_PtrOfShapeContextDistanceExtractor_delete, cv_PtrOfShapeContextDistanceExtractor_getInnerPtr_const, cv_PtrOfShapeContextDistanceExtractor_getInnerPtrMut
+		cv_PtrOfShapeContextDistanceExtractor_delete, cv_PtrOfShapeContextDistanceExtractor_getInnerPtr_const, cv_PtrOfShapeContextDistanceExtractor_getInnerPtrMut, cv_PtrOfShapeContextDistanceExtractor_clone_const
 	}
 	
 	impl core::Ptr<crate::shape::ShapeContextDistanceExtractor> {
@@ -16327,7 +16371,7 @@ mod shape_types {
 	pub type PtrOfShapeTransformer = core::Ptr<crate::shape::ShapeTransformer>;
 	
 	ptr_extern! { crate::shape::ShapeTransformer,
-		cv_PtrOfShapeTransformer_delete, cv_PtrOfShapeTransformer_getInnerPtr_const, cv_PtrOfShapeTransformer_getInnerPtrMut
+		cv_PtrOfShapeTransformer_delete, cv_PtrOfShapeTransformer_getInnerPtr_const, cv_PtrOfShapeTransformer_getInnerPtrMut, cv_PtrOfShapeTransformer_clone_const
 	}
 	
 	impl core::Ptr<crate::shape::ShapeTransformer> {
@@ -16362,7 +16406,7 @@ mod shape_types {
 	pub type PtrOfThinPlateSplineShapeTransformer = core::Ptr<crate::shape::ThinPlateSplineShapeTransformer>;
 	
 	ptr_extern! { crate::shape::ThinPlateSplineShapeTransformer,
-		cv_PtrOfThinPlateSplineShapeTransformer_delete, cv_PtrOfThinPlateSplineShapeTransformer_getInnerPtr_const, cv_PtrOfThinPlateSplineShapeTransformer_getInnerPtrMut
+		cv_PtrOfThinPlateSplineShapeTransformer_delete, cv_PtrOfThinPlateSplineShapeTransformer_getInnerPtr_const, cv_PtrOfThinPlateSplineShapeTransformer_getInnerPtrMut, cv_PtrOfThinPlateSplineShapeTransformer_clone_const
 	}
 	
 	impl core::Ptr<crate::shape::ThinPlateSplineShapeTransformer> {
@@ -16396,6 +16440,7 @@ mod shape_types {
 	
 	ptr_cast_base! { PtrOfThinPlateSplineShapeTransformer, core::Ptr<crate::shape::ShapeTransformer>, cv_PtrOfThinPlateSplineShapeTransformer_to_PtrOfShapeTransformer }
 	
+	ptr_cast_descendant! { core::Ptr<crate::shape::ShapeTransformer>, PtrOfThinPlateSplineShapeTransformer, cv_PtrOfShapeTransformer_to_PtrOfThinPlateSplineShapeTransformer }
 	impl std::fmt::Debug for core::Ptr<crate::shape::ThinPlateSplineShapeTransformer> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16415,7 +16460,7 @@ mod stereo_types {
 	pub type PtrOfQuasiDenseStereo = core::Ptr<crate::stereo::QuasiDenseStereo>;
 	
 	ptr_extern! { crate::stereo::QuasiDenseStereo,
-		cv_PtrOfQuasiDenseStereo_delete, cv_PtrOfQuasiDenseStereo_getInnerPtr_const, cv_PtrOfQuasiDenseStereo_getInnerPtrMut
+		cv_PtrOfQuasiDenseStereo_delete, cv_PtrOfQuasiDenseStereo_getInnerPtr_const, cv_PtrOfQuasiDenseStereo_getInnerPtrMut, cv_PtrOfQuasiDenseStereo_clone_const
 	}
 	
 	impl core::Ptr<crate::stereo::QuasiDenseStereo> {
@@ -16472,7 +16517,7 @@ mod stitching_types {
 	pub type PtrOfAffineWarper = core::Ptr<crate::stitching::AffineWarper>;
 	
 	ptr_extern! { crate::stitching::AffineWarper,
-		cv_PtrOfAffineWarper_delete, cv_PtrOfAffineWarper_getInnerPtr_const, cv_PtrOfAffineWarper_getInnerPtrMut
+		cv_PtrOfAffineWarper_delete, cv_PtrOfAffineWarper_getInnerPtr_const, cv_PtrOfAffineWarper_getInnerPtrMut, cv_PtrOfAffineWarper_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::AffineWarper, cv_PtrOfAffineWarper_new_const_AffineWarper }
@@ -16499,6 +16544,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfAffineWarper, core::Ptr<crate::stitching::WarperCreator>, cv_PtrOfAffineWarper_to_PtrOfWarperCreator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::WarperCreator>, PtrOfAffineWarper, cv_PtrOfWarperCreator_to_PtrOfAffineWarper }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::AffineWarper> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16510,7 +16556,7 @@ mod stitching_types {
 	pub type PtrOfCompressedRectilinearPortraitWarper = core::Ptr<crate::stitching::CompressedRectilinearPortraitWarper>;
 	
 	ptr_extern! { crate::stitching::CompressedRectilinearPortraitWarper,
-		cv_PtrOfCompressedRectilinearPortraitWarper_delete, cv_PtrOfCompressedRectilinearPortraitWarper_getInnerPtr_const, cv_PtrOfCompressedRectilinearPortraitWarper_getInnerPtrMut
+		cv_PtrOfCompressedRectilinearPortraitWarper_delete, cv_PtrOfCompressedRectilinearPortraitWarper_getInnerPtr_const, cv_PtrOfCompressedRectilinearPortraitWarper_getInnerPtrMut, cv_PtrOfCompressedRectilinearPortraitWarper_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::CompressedRectilinearPortraitWarper, cv_PtrOfCompressedRectilinearPortraitWarper_new_const_CompressedRectilinearPortraitWarper }
@@ -16537,6 +16583,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfCompressedRectilinearPortraitWarper, core::Ptr<crate::stitching::WarperCreator>, cv_PtrOfCompressedRectilinearPortraitWarper_to_PtrOfWarperCreator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::WarperCreator>, PtrOfCompressedRectilinearPortraitWarper, cv_PtrOfWarperCreator_to_PtrOfCompressedRectilinearPortraitWarper }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::CompressedRectilinearPortraitWarper> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16548,7 +16595,7 @@ mod stitching_types {
 	pub type PtrOfCompressedRectilinearWarper = core::Ptr<crate::stitching::CompressedRectilinearWarper>;
 	
 	ptr_extern! { crate::stitching::CompressedRectilinearWarper,
-		cv_PtrOfCompressedRectilinearWarper_delete, cv_PtrOfCompressedRectilinearWarper_getInnerPtr_const, cv_PtrOfCompressedRectilinearWarper_getInnerPtrMut
+		cv_PtrOfCompressedRectilinearWarper_delete, cv_PtrOfCompressedRectilinearWarper_getInnerPtr_const, cv_PtrOfCompressedRectilinearWarper_getInnerPtrMut, cv_PtrOfCompressedRectilinearWarper_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::CompressedRectilinearWarper, cv_PtrOfCompressedRectilinearWarper_new_const_CompressedRectilinearWarper }
@@ -16575,6 +16622,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfCompressedRectilinearWarper, core::Ptr<crate::stitching::WarperCreator>, cv_PtrOfCompressedRectilinearWarper_to_PtrOfWarperCreator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::WarperCreator>, PtrOfCompressedRectilinearWarper, cv_PtrOfWarperCreator_to_PtrOfCompressedRectilinearWarper }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::CompressedRectilinearWarper> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16586,7 +16634,7 @@ mod stitching_types {
 	pub type PtrOfCylindricalWarper = core::Ptr<crate::stitching::CylindricalWarper>;
 	
 	ptr_extern! { crate::stitching::CylindricalWarper,
-		cv_PtrOfCylindricalWarper_delete, cv_PtrOfCylindricalWarper_getInnerPtr_const, cv_PtrOfCylindricalWarper_getInnerPtrMut
+		cv_PtrOfCylindricalWarper_delete, cv_PtrOfCylindricalWarper_getInnerPtr_const, cv_PtrOfCylindricalWarper_getInnerPtrMut, cv_PtrOfCylindricalWarper_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::CylindricalWarper, cv_PtrOfCylindricalWarper_new_const_CylindricalWarper }
@@ -16613,6 +16661,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfCylindricalWarper, core::Ptr<crate::stitching::WarperCreator>, cv_PtrOfCylindricalWarper_to_PtrOfWarperCreator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::WarperCreator>, PtrOfCylindricalWarper, cv_PtrOfWarperCreator_to_PtrOfCylindricalWarper }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::CylindricalWarper> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16624,7 +16673,7 @@ mod stitching_types {
 	pub type PtrOfCylindricalWarperGpu = core::Ptr<crate::stitching::CylindricalWarperGpu>;
 	
 	ptr_extern! { crate::stitching::CylindricalWarperGpu,
-		cv_PtrOfCylindricalWarperGpu_delete, cv_PtrOfCylindricalWarperGpu_getInnerPtr_const, cv_PtrOfCylindricalWarperGpu_getInnerPtrMut
+		cv_PtrOfCylindricalWarperGpu_delete, cv_PtrOfCylindricalWarperGpu_getInnerPtr_const, cv_PtrOfCylindricalWarperGpu_getInnerPtrMut, cv_PtrOfCylindricalWarperGpu_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::CylindricalWarperGpu, cv_PtrOfCylindricalWarperGpu_new_const_CylindricalWarperGpu }
@@ -16651,6 +16700,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfCylindricalWarperGpu, core::Ptr<crate::stitching::WarperCreator>, cv_PtrOfCylindricalWarperGpu_to_PtrOfWarperCreator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::WarperCreator>, PtrOfCylindricalWarperGpu, cv_PtrOfWarperCreator_to_PtrOfCylindricalWarperGpu }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::CylindricalWarperGpu> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16662,7 +16712,7 @@ mod stitching_types {
 	pub type PtrOfDetail_AffineBasedEstimator = core::Ptr<crate::stitching::Detail_AffineBasedEstimator>;
 	
 	ptr_extern! { crate::stitching::Detail_AffineBasedEstimator,
-		cv_PtrOfDetail_AffineBasedEstimator_delete, cv_PtrOfDetail_AffineBasedEstimator_getInnerPtr_const, cv_PtrOfDetail_AffineBasedEstimator_getInnerPtrMut
+		cv_PtrOfDetail_AffineBasedEstimator_delete, cv_PtrOfDetail_AffineBasedEstimator_getInnerPtr_const, cv_PtrOfDetail_AffineBasedEstimator_getInnerPtrMut, cv_PtrOfDetail_AffineBasedEstimator_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_AffineBasedEstimator, cv_PtrOfDetail_AffineBasedEstimator_new_const_AffineBasedEstimator }
@@ -16689,6 +16739,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_AffineBasedEstimator, core::Ptr<crate::stitching::Detail_Estimator>, cv_PtrOfDetail_AffineBasedEstimator_to_PtrOfDetail_Estimator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_Estimator>, PtrOfDetail_AffineBasedEstimator, cv_PtrOfDetail_Estimator_to_PtrOfDetail_AffineBasedEstimator }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_AffineBasedEstimator> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16700,7 +16751,7 @@ mod stitching_types {
 	pub type PtrOfDetail_AffineBestOf2NearestMatcher = core::Ptr<crate::stitching::Detail_AffineBestOf2NearestMatcher>;
 	
 	ptr_extern! { crate::stitching::Detail_AffineBestOf2NearestMatcher,
-		cv_PtrOfDetail_AffineBestOf2NearestMatcher_delete, cv_PtrOfDetail_AffineBestOf2NearestMatcher_getInnerPtr_const, cv_PtrOfDetail_AffineBestOf2NearestMatcher_getInnerPtrMut
+		cv_PtrOfDetail_AffineBestOf2NearestMatcher_delete, cv_PtrOfDetail_AffineBestOf2NearestMatcher_getInnerPtr_const, cv_PtrOfDetail_AffineBestOf2NearestMatcher_getInnerPtrMut, cv_PtrOfDetail_AffineBestOf2NearestMatcher_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_AffineBestOf2NearestMatcher, cv_PtrOfDetail_AffineBestOf2NearestMatcher_new_const_AffineBestOf2NearestMatcher }
@@ -16727,6 +16778,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_AffineBestOf2NearestMatcher, core::Ptr<crate::stitching::Detail_BestOf2NearestMatcher>, cv_PtrOfDetail_AffineBestOf2NearestMatcher_to_PtrOfDetail_BestOf2NearestMatcher }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_BestOf2NearestMatcher>, PtrOfDetail_AffineBestOf2NearestMatcher, cv_PtrOfDetail_BestOf2NearestMatcher_to_PtrOfDetail_AffineBestOf2NearestMatcher }
 	impl crate::stitching::Detail_FeaturesMatcherTraitConst for core::Ptr<crate::stitching::Detail_AffineBestOf2NearestMatcher> {
 		#[inline] fn as_raw_Detail_FeaturesMatcher(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -16737,6 +16789,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_AffineBestOf2NearestMatcher, core::Ptr<crate::stitching::Detail_FeaturesMatcher>, cv_PtrOfDetail_AffineBestOf2NearestMatcher_to_PtrOfDetail_FeaturesMatcher }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_FeaturesMatcher>, PtrOfDetail_AffineBestOf2NearestMatcher, cv_PtrOfDetail_FeaturesMatcher_to_PtrOfDetail_AffineBestOf2NearestMatcher }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_AffineBestOf2NearestMatcher> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16748,7 +16801,7 @@ mod stitching_types {
 	pub type PtrOfDetail_BestOf2NearestMatcher = core::Ptr<crate::stitching::Detail_BestOf2NearestMatcher>;
 	
 	ptr_extern! { crate::stitching::Detail_BestOf2NearestMatcher,
-		cv_PtrOfDetail_BestOf2NearestMatcher_delete, cv_PtrOfDetail_BestOf2NearestMatcher_getInnerPtr_const, cv_PtrOfDetail_BestOf2NearestMatcher_getInnerPtrMut
+		cv_PtrOfDetail_BestOf2NearestMatcher_delete, cv_PtrOfDetail_BestOf2NearestMatcher_getInnerPtr_const, cv_PtrOfDetail_BestOf2NearestMatcher_getInnerPtrMut, cv_PtrOfDetail_BestOf2NearestMatcher_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_BestOf2NearestMatcher, cv_PtrOfDetail_BestOf2NearestMatcher_new_const_BestOf2NearestMatcher }
@@ -16775,6 +16828,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BestOf2NearestMatcher, core::Ptr<crate::stitching::Detail_FeaturesMatcher>, cv_PtrOfDetail_BestOf2NearestMatcher_to_PtrOfDetail_FeaturesMatcher }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_FeaturesMatcher>, PtrOfDetail_BestOf2NearestMatcher, cv_PtrOfDetail_FeaturesMatcher_to_PtrOfDetail_BestOf2NearestMatcher }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_BestOf2NearestMatcher> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16786,7 +16840,7 @@ mod stitching_types {
 	pub type PtrOfDetail_BestOf2NearestRangeMatcher = core::Ptr<crate::stitching::Detail_BestOf2NearestRangeMatcher>;
 	
 	ptr_extern! { crate::stitching::Detail_BestOf2NearestRangeMatcher,
-		cv_PtrOfDetail_BestOf2NearestRangeMatcher_delete, cv_PtrOfDetail_BestOf2NearestRangeMatcher_getInnerPtr_const, cv_PtrOfDetail_BestOf2NearestRangeMatcher_getInnerPtrMut
+		cv_PtrOfDetail_BestOf2NearestRangeMatcher_delete, cv_PtrOfDetail_BestOf2NearestRangeMatcher_getInnerPtr_const, cv_PtrOfDetail_BestOf2NearestRangeMatcher_getInnerPtrMut, cv_PtrOfDetail_BestOf2NearestRangeMatcher_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_BestOf2NearestRangeMatcher, cv_PtrOfDetail_BestOf2NearestRangeMatcher_new_const_BestOf2NearestRangeMatcher }
@@ -16813,6 +16867,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BestOf2NearestRangeMatcher, core::Ptr<crate::stitching::Detail_BestOf2NearestMatcher>, cv_PtrOfDetail_BestOf2NearestRangeMatcher_to_PtrOfDetail_BestOf2NearestMatcher }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_BestOf2NearestMatcher>, PtrOfDetail_BestOf2NearestRangeMatcher, cv_PtrOfDetail_BestOf2NearestMatcher_to_PtrOfDetail_BestOf2NearestRangeMatcher }
 	impl crate::stitching::Detail_FeaturesMatcherTraitConst for core::Ptr<crate::stitching::Detail_BestOf2NearestRangeMatcher> {
 		#[inline] fn as_raw_Detail_FeaturesMatcher(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -16823,6 +16878,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BestOf2NearestRangeMatcher, core::Ptr<crate::stitching::Detail_FeaturesMatcher>, cv_PtrOfDetail_BestOf2NearestRangeMatcher_to_PtrOfDetail_FeaturesMatcher }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_FeaturesMatcher>, PtrOfDetail_BestOf2NearestRangeMatcher, cv_PtrOfDetail_FeaturesMatcher_to_PtrOfDetail_BestOf2NearestRangeMatcher }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_BestOf2NearestRangeMatcher> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16834,7 +16890,7 @@ mod stitching_types {
 	pub type PtrOfDetail_Blender = core::Ptr<crate::stitching::Detail_Blender>;
 	
 	ptr_extern! { crate::stitching::Detail_Blender,
-		cv_PtrOfDetail_Blender_delete, cv_PtrOfDetail_Blender_getInnerPtr_const, cv_PtrOfDetail_Blender_getInnerPtrMut
+		cv_PtrOfDetail_Blender_delete, cv_PtrOfDetail_Blender_getInnerPtr_const, cv_PtrOfDetail_Blender_getInnerPtrMut, cv_PtrOfDetail_Blender_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_Blender, cv_PtrOfDetail_Blender_new_const_Blender }
@@ -16862,7 +16918,7 @@ mod stitching_types {
 	pub type PtrOfDetail_BlocksChannelsCompensator = core::Ptr<crate::stitching::Detail_BlocksChannelsCompensator>;
 	
 	ptr_extern! { crate::stitching::Detail_BlocksChannelsCompensator,
-		cv_PtrOfDetail_BlocksChannelsCompensator_delete, cv_PtrOfDetail_BlocksChannelsCompensator_getInnerPtr_const, cv_PtrOfDetail_BlocksChannelsCompensator_getInnerPtrMut
+		cv_PtrOfDetail_BlocksChannelsCompensator_delete, cv_PtrOfDetail_BlocksChannelsCompensator_getInnerPtr_const, cv_PtrOfDetail_BlocksChannelsCompensator_getInnerPtrMut, cv_PtrOfDetail_BlocksChannelsCompensator_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_BlocksChannelsCompensator, cv_PtrOfDetail_BlocksChannelsCompensator_new_const_BlocksChannelsCompensator }
@@ -16889,6 +16945,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BlocksChannelsCompensator, core::Ptr<crate::stitching::Detail_BlocksCompensator>, cv_PtrOfDetail_BlocksChannelsCompensator_to_PtrOfDetail_BlocksCompensator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_BlocksCompensator>, PtrOfDetail_BlocksChannelsCompensator, cv_PtrOfDetail_BlocksCompensator_to_PtrOfDetail_BlocksChannelsCompensator }
 	impl crate::stitching::Detail_ExposureCompensatorTraitConst for core::Ptr<crate::stitching::Detail_BlocksChannelsCompensator> {
 		#[inline] fn as_raw_Detail_ExposureCompensator(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -16899,6 +16956,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BlocksChannelsCompensator, core::Ptr<crate::stitching::Detail_ExposureCompensator>, cv_PtrOfDetail_BlocksChannelsCompensator_to_PtrOfDetail_ExposureCompensator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_ExposureCompensator>, PtrOfDetail_BlocksChannelsCompensator, cv_PtrOfDetail_ExposureCompensator_to_PtrOfDetail_BlocksChannelsCompensator }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_BlocksChannelsCompensator> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16910,7 +16968,7 @@ mod stitching_types {
 	pub type PtrOfDetail_BlocksCompensator = core::Ptr<crate::stitching::Detail_BlocksCompensator>;
 	
 	ptr_extern! { crate::stitching::Detail_BlocksCompensator,
-		cv_PtrOfDetail_BlocksCompensator_delete, cv_PtrOfDetail_BlocksCompensator_getInnerPtr_const, cv_PtrOfDetail_BlocksCompensator_getInnerPtrMut
+		cv_PtrOfDetail_BlocksCompensator_delete, cv_PtrOfDetail_BlocksCompensator_getInnerPtr_const, cv_PtrOfDetail_BlocksCompensator_getInnerPtrMut, cv_PtrOfDetail_BlocksCompensator_clone_const
 	}
 	
 	impl core::Ptr<crate::stitching::Detail_BlocksCompensator> {
@@ -16936,6 +16994,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BlocksCompensator, core::Ptr<crate::stitching::Detail_ExposureCompensator>, cv_PtrOfDetail_BlocksCompensator_to_PtrOfDetail_ExposureCompensator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_ExposureCompensator>, PtrOfDetail_BlocksCompensator, cv_PtrOfDetail_ExposureCompensator_to_PtrOfDetail_BlocksCompensator }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_BlocksCompensator> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16947,7 +17006,7 @@ mod stitching_types {
 	pub type PtrOfDetail_BlocksGainCompensator = core::Ptr<crate::stitching::Detail_BlocksGainCompensator>;
 	
 	ptr_extern! { crate::stitching::Detail_BlocksGainCompensator,
-		cv_PtrOfDetail_BlocksGainCompensator_delete, cv_PtrOfDetail_BlocksGainCompensator_getInnerPtr_const, cv_PtrOfDetail_BlocksGainCompensator_getInnerPtrMut
+		cv_PtrOfDetail_BlocksGainCompensator_delete, cv_PtrOfDetail_BlocksGainCompensator_getInnerPtr_const, cv_PtrOfDetail_BlocksGainCompensator_getInnerPtrMut, cv_PtrOfDetail_BlocksGainCompensator_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_BlocksGainCompensator, cv_PtrOfDetail_BlocksGainCompensator_new_const_BlocksGainCompensator }
@@ -16974,6 +17033,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BlocksGainCompensator, core::Ptr<crate::stitching::Detail_BlocksCompensator>, cv_PtrOfDetail_BlocksGainCompensator_to_PtrOfDetail_BlocksCompensator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_BlocksCompensator>, PtrOfDetail_BlocksGainCompensator, cv_PtrOfDetail_BlocksCompensator_to_PtrOfDetail_BlocksGainCompensator }
 	impl crate::stitching::Detail_ExposureCompensatorTraitConst for core::Ptr<crate::stitching::Detail_BlocksGainCompensator> {
 		#[inline] fn as_raw_Detail_ExposureCompensator(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -16984,6 +17044,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BlocksGainCompensator, core::Ptr<crate::stitching::Detail_ExposureCompensator>, cv_PtrOfDetail_BlocksGainCompensator_to_PtrOfDetail_ExposureCompensator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_ExposureCompensator>, PtrOfDetail_BlocksGainCompensator, cv_PtrOfDetail_ExposureCompensator_to_PtrOfDetail_BlocksGainCompensator }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_BlocksGainCompensator> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -16995,7 +17056,7 @@ mod stitching_types {
 	pub type PtrOfDetail_BundleAdjusterAffine = core::Ptr<crate::stitching::Detail_BundleAdjusterAffine>;
 	
 	ptr_extern! { crate::stitching::Detail_BundleAdjusterAffine,
-		cv_PtrOfDetail_BundleAdjusterAffine_delete, cv_PtrOfDetail_BundleAdjusterAffine_getInnerPtr_const, cv_PtrOfDetail_BundleAdjusterAffine_getInnerPtrMut
+		cv_PtrOfDetail_BundleAdjusterAffine_delete, cv_PtrOfDetail_BundleAdjusterAffine_getInnerPtr_const, cv_PtrOfDetail_BundleAdjusterAffine_getInnerPtrMut, cv_PtrOfDetail_BundleAdjusterAffine_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_BundleAdjusterAffine, cv_PtrOfDetail_BundleAdjusterAffine_new_const_BundleAdjusterAffine }
@@ -17022,6 +17083,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BundleAdjusterAffine, core::Ptr<crate::stitching::Detail_BundleAdjusterBase>, cv_PtrOfDetail_BundleAdjusterAffine_to_PtrOfDetail_BundleAdjusterBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_BundleAdjusterBase>, PtrOfDetail_BundleAdjusterAffine, cv_PtrOfDetail_BundleAdjusterBase_to_PtrOfDetail_BundleAdjusterAffine }
 	impl crate::stitching::Detail_EstimatorTraitConst for core::Ptr<crate::stitching::Detail_BundleAdjusterAffine> {
 		#[inline] fn as_raw_Detail_Estimator(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -17032,6 +17094,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BundleAdjusterAffine, core::Ptr<crate::stitching::Detail_Estimator>, cv_PtrOfDetail_BundleAdjusterAffine_to_PtrOfDetail_Estimator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_Estimator>, PtrOfDetail_BundleAdjusterAffine, cv_PtrOfDetail_Estimator_to_PtrOfDetail_BundleAdjusterAffine }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_BundleAdjusterAffine> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17043,7 +17106,7 @@ mod stitching_types {
 	pub type PtrOfDetail_BundleAdjusterAffinePartial = core::Ptr<crate::stitching::Detail_BundleAdjusterAffinePartial>;
 	
 	ptr_extern! { crate::stitching::Detail_BundleAdjusterAffinePartial,
-		cv_PtrOfDetail_BundleAdjusterAffinePartial_delete, cv_PtrOfDetail_BundleAdjusterAffinePartial_getInnerPtr_const, cv_PtrOfDetail_BundleAdjusterAffinePartial_getInnerPtrMut
+		cv_PtrOfDetail_BundleAdjusterAffinePartial_delete, cv_PtrOfDetail_BundleAdjusterAffinePartial_getInnerPtr_const, cv_PtrOfDetail_BundleAdjusterAffinePartial_getInnerPtrMut, cv_PtrOfDetail_BundleAdjusterAffinePartial_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_BundleAdjusterAffinePartial, cv_PtrOfDetail_BundleAdjusterAffinePartial_new_const_BundleAdjusterAffinePartial }
@@ -17070,6 +17133,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BundleAdjusterAffinePartial, core::Ptr<crate::stitching::Detail_BundleAdjusterBase>, cv_PtrOfDetail_BundleAdjusterAffinePartial_to_PtrOfDetail_BundleAdjusterBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_BundleAdjusterBase>, PtrOfDetail_BundleAdjusterAffinePartial, cv_PtrOfDetail_BundleAdjusterBase_to_PtrOfDetail_BundleAdjusterAffinePartial }
 	impl crate::stitching::Detail_EstimatorTraitConst for core::Ptr<crate::stitching::Detail_BundleAdjusterAffinePartial> {
 		#[inline] fn as_raw_Detail_Estimator(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -17080,6 +17144,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BundleAdjusterAffinePartial, core::Ptr<crate::stitching::Detail_Estimator>, cv_PtrOfDetail_BundleAdjusterAffinePartial_to_PtrOfDetail_Estimator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_Estimator>, PtrOfDetail_BundleAdjusterAffinePartial, cv_PtrOfDetail_Estimator_to_PtrOfDetail_BundleAdjusterAffinePartial }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_BundleAdjusterAffinePartial> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17091,7 +17156,7 @@ mod stitching_types {
 	pub type PtrOfDetail_BundleAdjusterBase = core::Ptr<crate::stitching::Detail_BundleAdjusterBase>;
 	
 	ptr_extern! { crate::stitching::Detail_BundleAdjusterBase,
-		cv_PtrOfDetail_BundleAdjusterBase_delete, cv_PtrOfDetail_BundleAdjusterBase_getInnerPtr_const, cv_PtrOfDetail_BundleAdjusterBase_getInnerPtrMut
+		cv_PtrOfDetail_BundleAdjusterBase_delete, cv_PtrOfDetail_BundleAdjusterBase_getInnerPtr_const, cv_PtrOfDetail_BundleAdjusterBase_getInnerPtrMut, cv_PtrOfDetail_BundleAdjusterBase_clone_const
 	}
 	
 	impl core::Ptr<crate::stitching::Detail_BundleAdjusterBase> {
@@ -17117,6 +17182,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BundleAdjusterBase, core::Ptr<crate::stitching::Detail_Estimator>, cv_PtrOfDetail_BundleAdjusterBase_to_PtrOfDetail_Estimator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_Estimator>, PtrOfDetail_BundleAdjusterBase, cv_PtrOfDetail_Estimator_to_PtrOfDetail_BundleAdjusterBase }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_BundleAdjusterBase> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17128,7 +17194,7 @@ mod stitching_types {
 	pub type PtrOfDetail_BundleAdjusterRay = core::Ptr<crate::stitching::Detail_BundleAdjusterRay>;
 	
 	ptr_extern! { crate::stitching::Detail_BundleAdjusterRay,
-		cv_PtrOfDetail_BundleAdjusterRay_delete, cv_PtrOfDetail_BundleAdjusterRay_getInnerPtr_const, cv_PtrOfDetail_BundleAdjusterRay_getInnerPtrMut
+		cv_PtrOfDetail_BundleAdjusterRay_delete, cv_PtrOfDetail_BundleAdjusterRay_getInnerPtr_const, cv_PtrOfDetail_BundleAdjusterRay_getInnerPtrMut, cv_PtrOfDetail_BundleAdjusterRay_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_BundleAdjusterRay, cv_PtrOfDetail_BundleAdjusterRay_new_const_BundleAdjusterRay }
@@ -17155,6 +17221,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BundleAdjusterRay, core::Ptr<crate::stitching::Detail_BundleAdjusterBase>, cv_PtrOfDetail_BundleAdjusterRay_to_PtrOfDetail_BundleAdjusterBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_BundleAdjusterBase>, PtrOfDetail_BundleAdjusterRay, cv_PtrOfDetail_BundleAdjusterBase_to_PtrOfDetail_BundleAdjusterRay }
 	impl crate::stitching::Detail_EstimatorTraitConst for core::Ptr<crate::stitching::Detail_BundleAdjusterRay> {
 		#[inline] fn as_raw_Detail_Estimator(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -17165,6 +17232,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BundleAdjusterRay, core::Ptr<crate::stitching::Detail_Estimator>, cv_PtrOfDetail_BundleAdjusterRay_to_PtrOfDetail_Estimator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_Estimator>, PtrOfDetail_BundleAdjusterRay, cv_PtrOfDetail_Estimator_to_PtrOfDetail_BundleAdjusterRay }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_BundleAdjusterRay> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17176,7 +17244,7 @@ mod stitching_types {
 	pub type PtrOfDetail_BundleAdjusterReproj = core::Ptr<crate::stitching::Detail_BundleAdjusterReproj>;
 	
 	ptr_extern! { crate::stitching::Detail_BundleAdjusterReproj,
-		cv_PtrOfDetail_BundleAdjusterReproj_delete, cv_PtrOfDetail_BundleAdjusterReproj_getInnerPtr_const, cv_PtrOfDetail_BundleAdjusterReproj_getInnerPtrMut
+		cv_PtrOfDetail_BundleAdjusterReproj_delete, cv_PtrOfDetail_BundleAdjusterReproj_getInnerPtr_const, cv_PtrOfDetail_BundleAdjusterReproj_getInnerPtrMut, cv_PtrOfDetail_BundleAdjusterReproj_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_BundleAdjusterReproj, cv_PtrOfDetail_BundleAdjusterReproj_new_const_BundleAdjusterReproj }
@@ -17203,6 +17271,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BundleAdjusterReproj, core::Ptr<crate::stitching::Detail_BundleAdjusterBase>, cv_PtrOfDetail_BundleAdjusterReproj_to_PtrOfDetail_BundleAdjusterBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_BundleAdjusterBase>, PtrOfDetail_BundleAdjusterReproj, cv_PtrOfDetail_BundleAdjusterBase_to_PtrOfDetail_BundleAdjusterReproj }
 	impl crate::stitching::Detail_EstimatorTraitConst for core::Ptr<crate::stitching::Detail_BundleAdjusterReproj> {
 		#[inline] fn as_raw_Detail_Estimator(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -17213,6 +17282,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_BundleAdjusterReproj, core::Ptr<crate::stitching::Detail_Estimator>, cv_PtrOfDetail_BundleAdjusterReproj_to_PtrOfDetail_Estimator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_Estimator>, PtrOfDetail_BundleAdjusterReproj, cv_PtrOfDetail_Estimator_to_PtrOfDetail_BundleAdjusterReproj }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_BundleAdjusterReproj> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17224,7 +17294,7 @@ mod stitching_types {
 	pub type PtrOfDetail_ChannelsCompensator = core::Ptr<crate::stitching::Detail_ChannelsCompensator>;
 	
 	ptr_extern! { crate::stitching::Detail_ChannelsCompensator,
-		cv_PtrOfDetail_ChannelsCompensator_delete, cv_PtrOfDetail_ChannelsCompensator_getInnerPtr_const, cv_PtrOfDetail_ChannelsCompensator_getInnerPtrMut
+		cv_PtrOfDetail_ChannelsCompensator_delete, cv_PtrOfDetail_ChannelsCompensator_getInnerPtr_const, cv_PtrOfDetail_ChannelsCompensator_getInnerPtrMut, cv_PtrOfDetail_ChannelsCompensator_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_ChannelsCompensator, cv_PtrOfDetail_ChannelsCompensator_new_const_ChannelsCompensator }
@@ -17251,6 +17321,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_ChannelsCompensator, core::Ptr<crate::stitching::Detail_ExposureCompensator>, cv_PtrOfDetail_ChannelsCompensator_to_PtrOfDetail_ExposureCompensator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_ExposureCompensator>, PtrOfDetail_ChannelsCompensator, cv_PtrOfDetail_ExposureCompensator_to_PtrOfDetail_ChannelsCompensator }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_ChannelsCompensator> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17262,7 +17333,7 @@ mod stitching_types {
 	pub type PtrOfDetail_DpSeamFinder = core::Ptr<crate::stitching::Detail_DpSeamFinder>;
 	
 	ptr_extern! { crate::stitching::Detail_DpSeamFinder,
-		cv_PtrOfDetail_DpSeamFinder_delete, cv_PtrOfDetail_DpSeamFinder_getInnerPtr_const, cv_PtrOfDetail_DpSeamFinder_getInnerPtrMut
+		cv_PtrOfDetail_DpSeamFinder_delete, cv_PtrOfDetail_DpSeamFinder_getInnerPtr_const, cv_PtrOfDetail_DpSeamFinder_getInnerPtrMut, cv_PtrOfDetail_DpSeamFinder_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_DpSeamFinder, cv_PtrOfDetail_DpSeamFinder_new_const_DpSeamFinder }
@@ -17289,6 +17360,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_DpSeamFinder, core::Ptr<crate::stitching::Detail_SeamFinder>, cv_PtrOfDetail_DpSeamFinder_to_PtrOfDetail_SeamFinder }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_SeamFinder>, PtrOfDetail_DpSeamFinder, cv_PtrOfDetail_SeamFinder_to_PtrOfDetail_DpSeamFinder }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_DpSeamFinder> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17300,7 +17372,7 @@ mod stitching_types {
 	pub type PtrOfDetail_Estimator = core::Ptr<crate::stitching::Detail_Estimator>;
 	
 	ptr_extern! { crate::stitching::Detail_Estimator,
-		cv_PtrOfDetail_Estimator_delete, cv_PtrOfDetail_Estimator_getInnerPtr_const, cv_PtrOfDetail_Estimator_getInnerPtrMut
+		cv_PtrOfDetail_Estimator_delete, cv_PtrOfDetail_Estimator_getInnerPtr_const, cv_PtrOfDetail_Estimator_getInnerPtrMut, cv_PtrOfDetail_Estimator_clone_const
 	}
 	
 	impl core::Ptr<crate::stitching::Detail_Estimator> {
@@ -17327,7 +17399,7 @@ mod stitching_types {
 	pub type PtrOfDetail_ExposureCompensator = core::Ptr<crate::stitching::Detail_ExposureCompensator>;
 	
 	ptr_extern! { crate::stitching::Detail_ExposureCompensator,
-		cv_PtrOfDetail_ExposureCompensator_delete, cv_PtrOfDetail_ExposureCompensator_getInnerPtr_const, cv_PtrOfDetail_ExposureCompensator_getInnerPtrMut
+		cv_PtrOfDetail_ExposureCompensator_delete, cv_PtrOfDetail_ExposureCompensator_getInnerPtr_const, cv_PtrOfDetail_ExposureCompensator_getInnerPtrMut, cv_PtrOfDetail_ExposureCompensator_clone_const
 	}
 	
 	impl core::Ptr<crate::stitching::Detail_ExposureCompensator> {
@@ -17354,7 +17426,7 @@ mod stitching_types {
 	pub type PtrOfDetail_FeatherBlender = core::Ptr<crate::stitching::Detail_FeatherBlender>;
 	
 	ptr_extern! { crate::stitching::Detail_FeatherBlender,
-		cv_PtrOfDetail_FeatherBlender_delete, cv_PtrOfDetail_FeatherBlender_getInnerPtr_const, cv_PtrOfDetail_FeatherBlender_getInnerPtrMut
+		cv_PtrOfDetail_FeatherBlender_delete, cv_PtrOfDetail_FeatherBlender_getInnerPtr_const, cv_PtrOfDetail_FeatherBlender_getInnerPtrMut, cv_PtrOfDetail_FeatherBlender_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_FeatherBlender, cv_PtrOfDetail_FeatherBlender_new_const_FeatherBlender }
@@ -17381,6 +17453,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_FeatherBlender, core::Ptr<crate::stitching::Detail_Blender>, cv_PtrOfDetail_FeatherBlender_to_PtrOfDetail_Blender }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_Blender>, PtrOfDetail_FeatherBlender, cv_PtrOfDetail_Blender_to_PtrOfDetail_FeatherBlender }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_FeatherBlender> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17392,7 +17465,7 @@ mod stitching_types {
 	pub type PtrOfDetail_FeaturesMatcher = core::Ptr<crate::stitching::Detail_FeaturesMatcher>;
 	
 	ptr_extern! { crate::stitching::Detail_FeaturesMatcher,
-		cv_PtrOfDetail_FeaturesMatcher_delete, cv_PtrOfDetail_FeaturesMatcher_getInnerPtr_const, cv_PtrOfDetail_FeaturesMatcher_getInnerPtrMut
+		cv_PtrOfDetail_FeaturesMatcher_delete, cv_PtrOfDetail_FeaturesMatcher_getInnerPtr_const, cv_PtrOfDetail_FeaturesMatcher_getInnerPtrMut, cv_PtrOfDetail_FeaturesMatcher_clone_const
 	}
 	
 	impl core::Ptr<crate::stitching::Detail_FeaturesMatcher> {
@@ -17419,7 +17492,7 @@ mod stitching_types {
 	pub type PtrOfDetail_GainCompensator = core::Ptr<crate::stitching::Detail_GainCompensator>;
 	
 	ptr_extern! { crate::stitching::Detail_GainCompensator,
-		cv_PtrOfDetail_GainCompensator_delete, cv_PtrOfDetail_GainCompensator_getInnerPtr_const, cv_PtrOfDetail_GainCompensator_getInnerPtrMut
+		cv_PtrOfDetail_GainCompensator_delete, cv_PtrOfDetail_GainCompensator_getInnerPtr_const, cv_PtrOfDetail_GainCompensator_getInnerPtrMut, cv_PtrOfDetail_GainCompensator_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_GainCompensator, cv_PtrOfDetail_GainCompensator_new_const_GainCompensator }
@@ -17446,6 +17519,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_GainCompensator, core::Ptr<crate::stitching::Detail_ExposureCompensator>, cv_PtrOfDetail_GainCompensator_to_PtrOfDetail_ExposureCompensator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_ExposureCompensator>, PtrOfDetail_GainCompensator, cv_PtrOfDetail_ExposureCompensator_to_PtrOfDetail_GainCompensator }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_GainCompensator> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17457,7 +17531,7 @@ mod stitching_types {
 	pub type PtrOfDetail_GraphCutSeamFinder = core::Ptr<crate::stitching::Detail_GraphCutSeamFinder>;
 	
 	ptr_extern! { crate::stitching::Detail_GraphCutSeamFinder,
-		cv_PtrOfDetail_GraphCutSeamFinder_delete, cv_PtrOfDetail_GraphCutSeamFinder_getInnerPtr_const, cv_PtrOfDetail_GraphCutSeamFinder_getInnerPtrMut
+		cv_PtrOfDetail_GraphCutSeamFinder_delete, cv_PtrOfDetail_GraphCutSeamFinder_getInnerPtr_const, cv_PtrOfDetail_GraphCutSeamFinder_getInnerPtrMut, cv_PtrOfDetail_GraphCutSeamFinder_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_GraphCutSeamFinder, cv_PtrOfDetail_GraphCutSeamFinder_new_const_GraphCutSeamFinder }
@@ -17492,6 +17566,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_GraphCutSeamFinder, core::Ptr<crate::stitching::Detail_SeamFinder>, cv_PtrOfDetail_GraphCutSeamFinder_to_PtrOfDetail_SeamFinder }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_SeamFinder>, PtrOfDetail_GraphCutSeamFinder, cv_PtrOfDetail_SeamFinder_to_PtrOfDetail_GraphCutSeamFinder }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_GraphCutSeamFinder> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17503,7 +17578,7 @@ mod stitching_types {
 	pub type PtrOfDetail_GraphCutSeamFinderGpu = core::Ptr<crate::stitching::Detail_GraphCutSeamFinderGpu>;
 	
 	ptr_extern! { crate::stitching::Detail_GraphCutSeamFinderGpu,
-		cv_PtrOfDetail_GraphCutSeamFinderGpu_delete, cv_PtrOfDetail_GraphCutSeamFinderGpu_getInnerPtr_const, cv_PtrOfDetail_GraphCutSeamFinderGpu_getInnerPtrMut
+		cv_PtrOfDetail_GraphCutSeamFinderGpu_delete, cv_PtrOfDetail_GraphCutSeamFinderGpu_getInnerPtr_const, cv_PtrOfDetail_GraphCutSeamFinderGpu_getInnerPtrMut, cv_PtrOfDetail_GraphCutSeamFinderGpu_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_GraphCutSeamFinderGpu, cv_PtrOfDetail_GraphCutSeamFinderGpu_new_const_GraphCutSeamFinderGpu }
@@ -17538,6 +17613,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_GraphCutSeamFinderGpu, core::Ptr<crate::stitching::Detail_PairwiseSeamFinder>, cv_PtrOfDetail_GraphCutSeamFinderGpu_to_PtrOfDetail_PairwiseSeamFinder }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_PairwiseSeamFinder>, PtrOfDetail_GraphCutSeamFinderGpu, cv_PtrOfDetail_PairwiseSeamFinder_to_PtrOfDetail_GraphCutSeamFinderGpu }
 	impl crate::stitching::Detail_SeamFinderTraitConst for core::Ptr<crate::stitching::Detail_GraphCutSeamFinderGpu> {
 		#[inline] fn as_raw_Detail_SeamFinder(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -17548,6 +17624,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_GraphCutSeamFinderGpu, core::Ptr<crate::stitching::Detail_SeamFinder>, cv_PtrOfDetail_GraphCutSeamFinderGpu_to_PtrOfDetail_SeamFinder }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_SeamFinder>, PtrOfDetail_GraphCutSeamFinderGpu, cv_PtrOfDetail_SeamFinder_to_PtrOfDetail_GraphCutSeamFinderGpu }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_GraphCutSeamFinderGpu> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17559,7 +17636,7 @@ mod stitching_types {
 	pub type PtrOfDetail_HomographyBasedEstimator = core::Ptr<crate::stitching::Detail_HomographyBasedEstimator>;
 	
 	ptr_extern! { crate::stitching::Detail_HomographyBasedEstimator,
-		cv_PtrOfDetail_HomographyBasedEstimator_delete, cv_PtrOfDetail_HomographyBasedEstimator_getInnerPtr_const, cv_PtrOfDetail_HomographyBasedEstimator_getInnerPtrMut
+		cv_PtrOfDetail_HomographyBasedEstimator_delete, cv_PtrOfDetail_HomographyBasedEstimator_getInnerPtr_const, cv_PtrOfDetail_HomographyBasedEstimator_getInnerPtrMut, cv_PtrOfDetail_HomographyBasedEstimator_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_HomographyBasedEstimator, cv_PtrOfDetail_HomographyBasedEstimator_new_const_HomographyBasedEstimator }
@@ -17586,6 +17663,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_HomographyBasedEstimator, core::Ptr<crate::stitching::Detail_Estimator>, cv_PtrOfDetail_HomographyBasedEstimator_to_PtrOfDetail_Estimator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_Estimator>, PtrOfDetail_HomographyBasedEstimator, cv_PtrOfDetail_Estimator_to_PtrOfDetail_HomographyBasedEstimator }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_HomographyBasedEstimator> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17597,7 +17675,7 @@ mod stitching_types {
 	pub type PtrOfDetail_MultiBandBlender = core::Ptr<crate::stitching::Detail_MultiBandBlender>;
 	
 	ptr_extern! { crate::stitching::Detail_MultiBandBlender,
-		cv_PtrOfDetail_MultiBandBlender_delete, cv_PtrOfDetail_MultiBandBlender_getInnerPtr_const, cv_PtrOfDetail_MultiBandBlender_getInnerPtrMut
+		cv_PtrOfDetail_MultiBandBlender_delete, cv_PtrOfDetail_MultiBandBlender_getInnerPtr_const, cv_PtrOfDetail_MultiBandBlender_getInnerPtrMut, cv_PtrOfDetail_MultiBandBlender_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_MultiBandBlender, cv_PtrOfDetail_MultiBandBlender_new_const_MultiBandBlender }
@@ -17624,6 +17702,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_MultiBandBlender, core::Ptr<crate::stitching::Detail_Blender>, cv_PtrOfDetail_MultiBandBlender_to_PtrOfDetail_Blender }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_Blender>, PtrOfDetail_MultiBandBlender, cv_PtrOfDetail_Blender_to_PtrOfDetail_MultiBandBlender }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_MultiBandBlender> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17635,7 +17714,7 @@ mod stitching_types {
 	pub type PtrOfDetail_NoBundleAdjuster = core::Ptr<crate::stitching::Detail_NoBundleAdjuster>;
 	
 	ptr_extern! { crate::stitching::Detail_NoBundleAdjuster,
-		cv_PtrOfDetail_NoBundleAdjuster_delete, cv_PtrOfDetail_NoBundleAdjuster_getInnerPtr_const, cv_PtrOfDetail_NoBundleAdjuster_getInnerPtrMut
+		cv_PtrOfDetail_NoBundleAdjuster_delete, cv_PtrOfDetail_NoBundleAdjuster_getInnerPtr_const, cv_PtrOfDetail_NoBundleAdjuster_getInnerPtrMut, cv_PtrOfDetail_NoBundleAdjuster_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_NoBundleAdjuster, cv_PtrOfDetail_NoBundleAdjuster_new_const_NoBundleAdjuster }
@@ -17662,6 +17741,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_NoBundleAdjuster, core::Ptr<crate::stitching::Detail_BundleAdjusterBase>, cv_PtrOfDetail_NoBundleAdjuster_to_PtrOfDetail_BundleAdjusterBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_BundleAdjusterBase>, PtrOfDetail_NoBundleAdjuster, cv_PtrOfDetail_BundleAdjusterBase_to_PtrOfDetail_NoBundleAdjuster }
 	impl crate::stitching::Detail_EstimatorTraitConst for core::Ptr<crate::stitching::Detail_NoBundleAdjuster> {
 		#[inline] fn as_raw_Detail_Estimator(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -17672,6 +17752,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_NoBundleAdjuster, core::Ptr<crate::stitching::Detail_Estimator>, cv_PtrOfDetail_NoBundleAdjuster_to_PtrOfDetail_Estimator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_Estimator>, PtrOfDetail_NoBundleAdjuster, cv_PtrOfDetail_Estimator_to_PtrOfDetail_NoBundleAdjuster }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_NoBundleAdjuster> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17683,7 +17764,7 @@ mod stitching_types {
 	pub type PtrOfDetail_NoExposureCompensator = core::Ptr<crate::stitching::Detail_NoExposureCompensator>;
 	
 	ptr_extern! { crate::stitching::Detail_NoExposureCompensator,
-		cv_PtrOfDetail_NoExposureCompensator_delete, cv_PtrOfDetail_NoExposureCompensator_getInnerPtr_const, cv_PtrOfDetail_NoExposureCompensator_getInnerPtrMut
+		cv_PtrOfDetail_NoExposureCompensator_delete, cv_PtrOfDetail_NoExposureCompensator_getInnerPtr_const, cv_PtrOfDetail_NoExposureCompensator_getInnerPtrMut, cv_PtrOfDetail_NoExposureCompensator_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_NoExposureCompensator, cv_PtrOfDetail_NoExposureCompensator_new_const_NoExposureCompensator }
@@ -17710,6 +17791,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_NoExposureCompensator, core::Ptr<crate::stitching::Detail_ExposureCompensator>, cv_PtrOfDetail_NoExposureCompensator_to_PtrOfDetail_ExposureCompensator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_ExposureCompensator>, PtrOfDetail_NoExposureCompensator, cv_PtrOfDetail_ExposureCompensator_to_PtrOfDetail_NoExposureCompensator }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_NoExposureCompensator> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17721,7 +17803,7 @@ mod stitching_types {
 	pub type PtrOfDetail_NoSeamFinder = core::Ptr<crate::stitching::Detail_NoSeamFinder>;
 	
 	ptr_extern! { crate::stitching::Detail_NoSeamFinder,
-		cv_PtrOfDetail_NoSeamFinder_delete, cv_PtrOfDetail_NoSeamFinder_getInnerPtr_const, cv_PtrOfDetail_NoSeamFinder_getInnerPtrMut
+		cv_PtrOfDetail_NoSeamFinder_delete, cv_PtrOfDetail_NoSeamFinder_getInnerPtr_const, cv_PtrOfDetail_NoSeamFinder_getInnerPtrMut, cv_PtrOfDetail_NoSeamFinder_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_NoSeamFinder, cv_PtrOfDetail_NoSeamFinder_new_const_NoSeamFinder }
@@ -17748,6 +17830,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_NoSeamFinder, core::Ptr<crate::stitching::Detail_SeamFinder>, cv_PtrOfDetail_NoSeamFinder_to_PtrOfDetail_SeamFinder }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_SeamFinder>, PtrOfDetail_NoSeamFinder, cv_PtrOfDetail_SeamFinder_to_PtrOfDetail_NoSeamFinder }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_NoSeamFinder> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17759,7 +17842,7 @@ mod stitching_types {
 	pub type PtrOfDetail_PairwiseSeamFinder = core::Ptr<crate::stitching::Detail_PairwiseSeamFinder>;
 	
 	ptr_extern! { crate::stitching::Detail_PairwiseSeamFinder,
-		cv_PtrOfDetail_PairwiseSeamFinder_delete, cv_PtrOfDetail_PairwiseSeamFinder_getInnerPtr_const, cv_PtrOfDetail_PairwiseSeamFinder_getInnerPtrMut
+		cv_PtrOfDetail_PairwiseSeamFinder_delete, cv_PtrOfDetail_PairwiseSeamFinder_getInnerPtr_const, cv_PtrOfDetail_PairwiseSeamFinder_getInnerPtrMut, cv_PtrOfDetail_PairwiseSeamFinder_clone_const
 	}
 	
 	impl core::Ptr<crate::stitching::Detail_PairwiseSeamFinder> {
@@ -17785,6 +17868,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_PairwiseSeamFinder, core::Ptr<crate::stitching::Detail_SeamFinder>, cv_PtrOfDetail_PairwiseSeamFinder_to_PtrOfDetail_SeamFinder }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_SeamFinder>, PtrOfDetail_PairwiseSeamFinder, cv_PtrOfDetail_SeamFinder_to_PtrOfDetail_PairwiseSeamFinder }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_PairwiseSeamFinder> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17796,7 +17880,7 @@ mod stitching_types {
 	pub type PtrOfDetail_RotationWarper = core::Ptr<crate::stitching::Detail_RotationWarper>;
 	
 	ptr_extern! { crate::stitching::Detail_RotationWarper,
-		cv_PtrOfDetail_RotationWarper_delete, cv_PtrOfDetail_RotationWarper_getInnerPtr_const, cv_PtrOfDetail_RotationWarper_getInnerPtrMut
+		cv_PtrOfDetail_RotationWarper_delete, cv_PtrOfDetail_RotationWarper_getInnerPtr_const, cv_PtrOfDetail_RotationWarper_getInnerPtrMut, cv_PtrOfDetail_RotationWarper_clone_const
 	}
 	
 	impl core::Ptr<crate::stitching::Detail_RotationWarper> {
@@ -17823,7 +17907,7 @@ mod stitching_types {
 	pub type PtrOfDetail_SeamFinder = core::Ptr<crate::stitching::Detail_SeamFinder>;
 	
 	ptr_extern! { crate::stitching::Detail_SeamFinder,
-		cv_PtrOfDetail_SeamFinder_delete, cv_PtrOfDetail_SeamFinder_getInnerPtr_const, cv_PtrOfDetail_SeamFinder_getInnerPtrMut
+		cv_PtrOfDetail_SeamFinder_delete, cv_PtrOfDetail_SeamFinder_getInnerPtr_const, cv_PtrOfDetail_SeamFinder_getInnerPtrMut, cv_PtrOfDetail_SeamFinder_clone_const
 	}
 	
 	impl core::Ptr<crate::stitching::Detail_SeamFinder> {
@@ -17850,7 +17934,7 @@ mod stitching_types {
 	pub type PtrOfDetail_VoronoiSeamFinder = core::Ptr<crate::stitching::Detail_VoronoiSeamFinder>;
 	
 	ptr_extern! { crate::stitching::Detail_VoronoiSeamFinder,
-		cv_PtrOfDetail_VoronoiSeamFinder_delete, cv_PtrOfDetail_VoronoiSeamFinder_getInnerPtr_const, cv_PtrOfDetail_VoronoiSeamFinder_getInnerPtrMut
+		cv_PtrOfDetail_VoronoiSeamFinder_delete, cv_PtrOfDetail_VoronoiSeamFinder_getInnerPtr_const, cv_PtrOfDetail_VoronoiSeamFinder_getInnerPtrMut, cv_PtrOfDetail_VoronoiSeamFinder_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Detail_VoronoiSeamFinder, cv_PtrOfDetail_VoronoiSeamFinder_new_const_VoronoiSeamFinder }
@@ -17877,6 +17961,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_VoronoiSeamFinder, core::Ptr<crate::stitching::Detail_PairwiseSeamFinder>, cv_PtrOfDetail_VoronoiSeamFinder_to_PtrOfDetail_PairwiseSeamFinder }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_PairwiseSeamFinder>, PtrOfDetail_VoronoiSeamFinder, cv_PtrOfDetail_PairwiseSeamFinder_to_PtrOfDetail_VoronoiSeamFinder }
 	impl crate::stitching::Detail_SeamFinderTraitConst for core::Ptr<crate::stitching::Detail_VoronoiSeamFinder> {
 		#[inline] fn as_raw_Detail_SeamFinder(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -17887,6 +17972,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfDetail_VoronoiSeamFinder, core::Ptr<crate::stitching::Detail_SeamFinder>, cv_PtrOfDetail_VoronoiSeamFinder_to_PtrOfDetail_SeamFinder }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::Detail_SeamFinder>, PtrOfDetail_VoronoiSeamFinder, cv_PtrOfDetail_SeamFinder_to_PtrOfDetail_VoronoiSeamFinder }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::Detail_VoronoiSeamFinder> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17898,7 +17984,7 @@ mod stitching_types {
 	pub type PtrOfFisheyeWarper = core::Ptr<crate::stitching::FisheyeWarper>;
 	
 	ptr_extern! { crate::stitching::FisheyeWarper,
-		cv_PtrOfFisheyeWarper_delete, cv_PtrOfFisheyeWarper_getInnerPtr_const, cv_PtrOfFisheyeWarper_getInnerPtrMut
+		cv_PtrOfFisheyeWarper_delete, cv_PtrOfFisheyeWarper_getInnerPtr_const, cv_PtrOfFisheyeWarper_getInnerPtrMut, cv_PtrOfFisheyeWarper_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::FisheyeWarper, cv_PtrOfFisheyeWarper_new_const_FisheyeWarper }
@@ -17925,6 +18011,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfFisheyeWarper, core::Ptr<crate::stitching::WarperCreator>, cv_PtrOfFisheyeWarper_to_PtrOfWarperCreator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::WarperCreator>, PtrOfFisheyeWarper, cv_PtrOfWarperCreator_to_PtrOfFisheyeWarper }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::FisheyeWarper> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17936,7 +18023,7 @@ mod stitching_types {
 	pub type PtrOfMercatorWarper = core::Ptr<crate::stitching::MercatorWarper>;
 	
 	ptr_extern! { crate::stitching::MercatorWarper,
-		cv_PtrOfMercatorWarper_delete, cv_PtrOfMercatorWarper_getInnerPtr_const, cv_PtrOfMercatorWarper_getInnerPtrMut
+		cv_PtrOfMercatorWarper_delete, cv_PtrOfMercatorWarper_getInnerPtr_const, cv_PtrOfMercatorWarper_getInnerPtrMut, cv_PtrOfMercatorWarper_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::MercatorWarper, cv_PtrOfMercatorWarper_new_const_MercatorWarper }
@@ -17963,6 +18050,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfMercatorWarper, core::Ptr<crate::stitching::WarperCreator>, cv_PtrOfMercatorWarper_to_PtrOfWarperCreator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::WarperCreator>, PtrOfMercatorWarper, cv_PtrOfWarperCreator_to_PtrOfMercatorWarper }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::MercatorWarper> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -17974,7 +18062,7 @@ mod stitching_types {
 	pub type PtrOfPaniniPortraitWarper = core::Ptr<crate::stitching::PaniniPortraitWarper>;
 	
 	ptr_extern! { crate::stitching::PaniniPortraitWarper,
-		cv_PtrOfPaniniPortraitWarper_delete, cv_PtrOfPaniniPortraitWarper_getInnerPtr_const, cv_PtrOfPaniniPortraitWarper_getInnerPtrMut
+		cv_PtrOfPaniniPortraitWarper_delete, cv_PtrOfPaniniPortraitWarper_getInnerPtr_const, cv_PtrOfPaniniPortraitWarper_getInnerPtrMut, cv_PtrOfPaniniPortraitWarper_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::PaniniPortraitWarper, cv_PtrOfPaniniPortraitWarper_new_const_PaniniPortraitWarper }
@@ -18001,6 +18089,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfPaniniPortraitWarper, core::Ptr<crate::stitching::WarperCreator>, cv_PtrOfPaniniPortraitWarper_to_PtrOfWarperCreator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::WarperCreator>, PtrOfPaniniPortraitWarper, cv_PtrOfWarperCreator_to_PtrOfPaniniPortraitWarper }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::PaniniPortraitWarper> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -18012,7 +18101,7 @@ mod stitching_types {
 	pub type PtrOfPaniniWarper = core::Ptr<crate::stitching::PaniniWarper>;
 	
 	ptr_extern! { crate::stitching::PaniniWarper,
-		cv_PtrOfPaniniWarper_delete, cv_PtrOfPaniniWarper_getInnerPtr_const, cv_PtrOfPaniniWarper_getInnerPtrMut
+		cv_PtrOfPaniniWarper_delete, cv_PtrOfPaniniWarper_getInnerPtr_const, cv_PtrOfPaniniWarper_getInnerPtrMut, cv_PtrOfPaniniWarper_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::PaniniWarper, cv_PtrOfPaniniWarper_new_const_PaniniWarper }
@@ -18039,6 +18128,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfPaniniWarper, core::Ptr<crate::stitching::WarperCreator>, cv_PtrOfPaniniWarper_to_PtrOfWarperCreator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::WarperCreator>, PtrOfPaniniWarper, cv_PtrOfWarperCreator_to_PtrOfPaniniWarper }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::PaniniWarper> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -18050,7 +18140,7 @@ mod stitching_types {
 	pub type PtrOfPlaneWarper = core::Ptr<crate::stitching::PlaneWarper>;
 	
 	ptr_extern! { crate::stitching::PlaneWarper,
-		cv_PtrOfPlaneWarper_delete, cv_PtrOfPlaneWarper_getInnerPtr_const, cv_PtrOfPlaneWarper_getInnerPtrMut
+		cv_PtrOfPlaneWarper_delete, cv_PtrOfPlaneWarper_getInnerPtr_const, cv_PtrOfPlaneWarper_getInnerPtrMut, cv_PtrOfPlaneWarper_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::PlaneWarper, cv_PtrOfPlaneWarper_new_const_PlaneWarper }
@@ -18077,6 +18167,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfPlaneWarper, core::Ptr<crate::stitching::WarperCreator>, cv_PtrOfPlaneWarper_to_PtrOfWarperCreator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::WarperCreator>, PtrOfPlaneWarper, cv_PtrOfWarperCreator_to_PtrOfPlaneWarper }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::PlaneWarper> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -18088,7 +18179,7 @@ mod stitching_types {
 	pub type PtrOfPlaneWarperGpu = core::Ptr<crate::stitching::PlaneWarperGpu>;
 	
 	ptr_extern! { crate::stitching::PlaneWarperGpu,
-		cv_PtrOfPlaneWarperGpu_delete, cv_PtrOfPlaneWarperGpu_getInnerPtr_const, cv_PtrOfPlaneWarperGpu_getInnerPtrMut
+		cv_PtrOfPlaneWarperGpu_delete, cv_PtrOfPlaneWarperGpu_getInnerPtr_const, cv_PtrOfPlaneWarperGpu_getInnerPtrMut, cv_PtrOfPlaneWarperGpu_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::PlaneWarperGpu, cv_PtrOfPlaneWarperGpu_new_const_PlaneWarperGpu }
@@ -18115,6 +18206,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfPlaneWarperGpu, core::Ptr<crate::stitching::WarperCreator>, cv_PtrOfPlaneWarperGpu_to_PtrOfWarperCreator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::WarperCreator>, PtrOfPlaneWarperGpu, cv_PtrOfWarperCreator_to_PtrOfPlaneWarperGpu }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::PlaneWarperGpu> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -18126,7 +18218,7 @@ mod stitching_types {
 	pub type PtrOfSphericalWarper = core::Ptr<crate::stitching::SphericalWarper>;
 	
 	ptr_extern! { crate::stitching::SphericalWarper,
-		cv_PtrOfSphericalWarper_delete, cv_PtrOfSphericalWarper_getInnerPtr_const, cv_PtrOfSphericalWarper_getInnerPtrMut
+		cv_PtrOfSphericalWarper_delete, cv_PtrOfSphericalWarper_getInnerPtr_const, cv_PtrOfSphericalWarper_getInnerPtrMut, cv_PtrOfSphericalWarper_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::SphericalWarper, cv_PtrOfSphericalWarper_new_const_SphericalWarper }
@@ -18153,6 +18245,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfSphericalWarper, core::Ptr<crate::stitching::WarperCreator>, cv_PtrOfSphericalWarper_to_PtrOfWarperCreator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::WarperCreator>, PtrOfSphericalWarper, cv_PtrOfWarperCreator_to_PtrOfSphericalWarper }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::SphericalWarper> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -18164,7 +18257,7 @@ mod stitching_types {
 	pub type PtrOfSphericalWarperGpu = core::Ptr<crate::stitching::SphericalWarperGpu>;
 	
 	ptr_extern! { crate::stitching::SphericalWarperGpu,
-		cv_PtrOfSphericalWarperGpu_delete, cv_PtrOfSphericalWarperGpu_getInnerPtr_const, cv_PtrOfSphericalWarperGpu_getInnerPtrMut
+		cv_PtrOfSphericalWarperGpu_delete, cv_PtrOfSphericalWarperGpu_getInnerPtr_const, cv_PtrOfSphericalWarperGpu_getInnerPtrMut, cv_PtrOfSphericalWarperGpu_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::SphericalWarperGpu, cv_PtrOfSphericalWarperGpu_new_const_SphericalWarperGpu }
@@ -18191,6 +18284,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfSphericalWarperGpu, core::Ptr<crate::stitching::WarperCreator>, cv_PtrOfSphericalWarperGpu_to_PtrOfWarperCreator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::WarperCreator>, PtrOfSphericalWarperGpu, cv_PtrOfWarperCreator_to_PtrOfSphericalWarperGpu }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::SphericalWarperGpu> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -18202,7 +18296,7 @@ mod stitching_types {
 	pub type PtrOfStereographicWarper = core::Ptr<crate::stitching::StereographicWarper>;
 	
 	ptr_extern! { crate::stitching::StereographicWarper,
-		cv_PtrOfStereographicWarper_delete, cv_PtrOfStereographicWarper_getInnerPtr_const, cv_PtrOfStereographicWarper_getInnerPtrMut
+		cv_PtrOfStereographicWarper_delete, cv_PtrOfStereographicWarper_getInnerPtr_const, cv_PtrOfStereographicWarper_getInnerPtrMut, cv_PtrOfStereographicWarper_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::StereographicWarper, cv_PtrOfStereographicWarper_new_const_StereographicWarper }
@@ -18229,6 +18323,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfStereographicWarper, core::Ptr<crate::stitching::WarperCreator>, cv_PtrOfStereographicWarper_to_PtrOfWarperCreator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::WarperCreator>, PtrOfStereographicWarper, cv_PtrOfWarperCreator_to_PtrOfStereographicWarper }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::StereographicWarper> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -18240,7 +18335,7 @@ mod stitching_types {
 	pub type PtrOfStitcher = core::Ptr<crate::stitching::Stitcher>;
 	
 	ptr_extern! { crate::stitching::Stitcher,
-		cv_PtrOfStitcher_delete, cv_PtrOfStitcher_getInnerPtr_const, cv_PtrOfStitcher_getInnerPtrMut
+		cv_PtrOfStitcher_delete, cv_PtrOfStitcher_getInnerPtr_const, cv_PtrOfStitcher_getInnerPtrMut, cv_PtrOfStitcher_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::Stitcher, cv_PtrOfStitcher_new_const_Stitcher }
@@ -18268,7 +18363,7 @@ mod stitching_types {
 	pub type PtrOfTransverseMercatorWarper = core::Ptr<crate::stitching::TransverseMercatorWarper>;
 	
 	ptr_extern! { crate::stitching::TransverseMercatorWarper,
-		cv_PtrOfTransverseMercatorWarper_delete, cv_PtrOfTransverseMercatorWarper_getInnerPtr_const, cv_PtrOfTransverseMercatorWarper_getInnerPtrMut
+		cv_PtrOfTransverseMercatorWarper_delete, cv_PtrOfTransverseMercatorWarper_getInnerPtr_const, cv_PtrOfTransverseMercatorWarper_getInnerPtrMut, cv_PtrOfTransverseMercatorWarper_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::stitching::TransverseMercatorWarper, cv_PtrOfTransverseMercatorWarper_new_const_TransverseMercatorWarper }
@@ -18295,6 +18390,7 @@ mod stitching_types {
 	
 	ptr_cast_base! { PtrOfTransverseMercatorWarper, core::Ptr<crate::stitching::WarperCreator>, cv_PtrOfTransverseMercatorWarper_to_PtrOfWarperCreator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::stitching::WarperCreator>, PtrOfTransverseMercatorWarper, cv_PtrOfWarperCreator_to_PtrOfTransverseMercatorWarper }
 	impl std::fmt::Debug for core::Ptr<crate::stitching::TransverseMercatorWarper> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -18306,7 +18402,7 @@ mod stitching_types {
 	pub type PtrOfWarperCreator = core::Ptr<crate::stitching::WarperCreator>;
 	
 	ptr_extern! { crate::stitching::WarperCreator,
-		cv_PtrOfWarperCreator_delete, cv_PtrOfWarperCreator_getInnerPtr_const, cv_PtrOfWarperCreator_getInnerPtrMut
+		cv_PtrOfWarperCreator_delete, cv_PtrOfWarperCreator_getInnerPtr_const, cv_PtrOfWarperCreator_getInnerPtrMut, cv_PtrOfWarperCreator_clone_const
 	}
 	
 	impl core::Ptr<crate::stitching::WarperCreator> {
@@ -18395,7 +18491,7 @@ mod structured_light_types {
 	pub type PtrOfGrayCodePattern = core::Ptr<crate::structured_light::GrayCodePattern>;
 	
 	ptr_extern! { crate::structured_light::GrayCodePattern,
-		cv_PtrOfGrayCodePattern_delete, cv_PtrOfGrayCodePattern_getInnerPtr_const, cv_PtrOfGrayCodePattern_getInnerPtrMut
+		cv_PtrOfGrayCodePattern_delete, cv_PtrOfGrayCodePattern_getInnerPtr_const, cv_PtrOfGrayCodePattern_getInnerPtrMut, cv_PtrOfGrayCodePattern_clone_const
 	}
 	
 	impl core::Ptr<crate::structured_light::GrayCodePattern> {
@@ -18438,7 +18534,7 @@ mod structured_light_types {
 	pub type PtrOfSinusoidalPattern = core::Ptr<crate::structured_light::SinusoidalPattern>;
 	
 	ptr_extern! { crate::structured_light::SinusoidalPattern,
-		cv_PtrOfSinusoidalPattern_delete, cv_PtrOfSinusoidalPattern_getInnerPtr_const, cv_PtrOfSinusoidalPattern_getInnerPtrMut
+		cv_PtrOfSinusoidalPattern_delete, cv_PtrOfSinusoidalPattern_getInnerPtr_const, cv_PtrOfSinusoidalPattern_getInnerPtrMut, cv_PtrOfSinusoidalPattern_clone_const
 	}
 	
 	impl core::Ptr<crate::structured_light::SinusoidalPattern> {
@@ -18481,7 +18577,7 @@ mod structured_light_types {
 	pub type PtrOfSinusoidalPattern_Params = core::Ptr<crate::structured_light::SinusoidalPattern_Params>;
 	
 	ptr_extern! { crate::structured_light::SinusoidalPattern_Params,
-		cv_PtrOfSinusoidalPattern_Params_delete, cv_PtrOfSinusoidalPattern_Params_getInnerPtr_const, cv_PtrOfSinusoidalPattern_Params_getInnerPtrMut
+		cv_PtrOfSinusoidalPattern_Params_delete, cv_PtrOfSinusoidalPattern_Params_getInnerPtr_const, cv_PtrOfSinusoidalPattern_Params_getInnerPtrMut, cv_PtrOfSinusoidalPattern_Params_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::structured_light::SinusoidalPattern_Params, cv_PtrOfSinusoidalPattern_Params_new_const_Params }
@@ -18526,7 +18622,7 @@ mod superres_types {
 	pub type PtrOfSuperRes_BroxOpticalFlow = core::Ptr<crate::superres::SuperRes_BroxOpticalFlow>;
 	
 	ptr_extern! { crate::superres::SuperRes_BroxOpticalFlow,
-		cv_PtrOfSuperRes_BroxOpticalFlow_delete, cv_PtrOfSuperRes_BroxOpticalFlow_getInnerPtr_const, cv_PtrOfSuperRes_BroxOpticalFlow_getInnerPtrMut
+		cv_PtrOfSuperRes_BroxOpticalFlow_delete, cv_PtrOfSuperRes_BroxOpticalFlow_getInnerPtr_const, cv_PtrOfSuperRes_BroxOpticalFlow_getInnerPtrMut, cv_PtrOfSuperRes_BroxOpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::superres::SuperRes_BroxOpticalFlow> {
@@ -18569,7 +18665,7 @@ mod superres_types {
 	pub type PtrOfSuperRes_DenseOpticalFlowExt = core::Ptr<crate::superres::SuperRes_DenseOpticalFlowExt>;
 	
 	ptr_extern! { crate::superres::SuperRes_DenseOpticalFlowExt,
-		cv_PtrOfSuperRes_DenseOpticalFlowExt_delete, cv_PtrOfSuperRes_DenseOpticalFlowExt_getInnerPtr_const, cv_PtrOfSuperRes_DenseOpticalFlowExt_getInnerPtrMut
+		cv_PtrOfSuperRes_DenseOpticalFlowExt_delete, cv_PtrOfSuperRes_DenseOpticalFlowExt_getInnerPtr_const, cv_PtrOfSuperRes_DenseOpticalFlowExt_getInnerPtrMut, cv_PtrOfSuperRes_DenseOpticalFlowExt_clone_const
 	}
 	
 	impl core::Ptr<crate::superres::SuperRes_DenseOpticalFlowExt> {
@@ -18604,7 +18700,7 @@ mod superres_types {
 	pub type PtrOfSuperRes_DualTVL1OpticalFlow = core::Ptr<crate::superres::SuperRes_DualTVL1OpticalFlow>;
 	
 	ptr_extern! { crate::superres::SuperRes_DualTVL1OpticalFlow,
-		cv_PtrOfSuperRes_DualTVL1OpticalFlow_delete, cv_PtrOfSuperRes_DualTVL1OpticalFlow_getInnerPtr_const, cv_PtrOfSuperRes_DualTVL1OpticalFlow_getInnerPtrMut
+		cv_PtrOfSuperRes_DualTVL1OpticalFlow_delete, cv_PtrOfSuperRes_DualTVL1OpticalFlow_getInnerPtr_const, cv_PtrOfSuperRes_DualTVL1OpticalFlow_getInnerPtrMut, cv_PtrOfSuperRes_DualTVL1OpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::superres::SuperRes_DualTVL1OpticalFlow> {
@@ -18647,7 +18743,7 @@ mod superres_types {
 	pub type PtrOfSuperRes_FarnebackOpticalFlow = core::Ptr<crate::superres::SuperRes_FarnebackOpticalFlow>;
 	
 	ptr_extern! { crate::superres::SuperRes_FarnebackOpticalFlow,
-		cv_PtrOfSuperRes_FarnebackOpticalFlow_delete, cv_PtrOfSuperRes_FarnebackOpticalFlow_getInnerPtr_const, cv_PtrOfSuperRes_FarnebackOpticalFlow_getInnerPtrMut
+		cv_PtrOfSuperRes_FarnebackOpticalFlow_delete, cv_PtrOfSuperRes_FarnebackOpticalFlow_getInnerPtr_const, cv_PtrOfSuperRes_FarnebackOpticalFlow_getInnerPtrMut, cv_PtrOfSuperRes_FarnebackOpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::superres::SuperRes_FarnebackOpticalFlow> {
@@ -18690,7 +18786,7 @@ mod superres_types {
 	pub type PtrOfSuperRes_FrameSource = core::Ptr<crate::superres::SuperRes_FrameSource>;
 	
 	ptr_extern! { crate::superres::SuperRes_FrameSource,
-		cv_PtrOfSuperRes_FrameSource_delete, cv_PtrOfSuperRes_FrameSource_getInnerPtr_const, cv_PtrOfSuperRes_FrameSource_getInnerPtrMut
+		cv_PtrOfSuperRes_FrameSource_delete, cv_PtrOfSuperRes_FrameSource_getInnerPtr_const, cv_PtrOfSuperRes_FrameSource_getInnerPtrMut, cv_PtrOfSuperRes_FrameSource_clone_const
 	}
 	
 	impl core::Ptr<crate::superres::SuperRes_FrameSource> {
@@ -18717,7 +18813,7 @@ mod superres_types {
 	pub type PtrOfSuperRes_PyrLKOpticalFlow = core::Ptr<crate::superres::SuperRes_PyrLKOpticalFlow>;
 	
 	ptr_extern! { crate::superres::SuperRes_PyrLKOpticalFlow,
-		cv_PtrOfSuperRes_PyrLKOpticalFlow_delete, cv_PtrOfSuperRes_PyrLKOpticalFlow_getInnerPtr_const, cv_PtrOfSuperRes_PyrLKOpticalFlow_getInnerPtrMut
+		cv_PtrOfSuperRes_PyrLKOpticalFlow_delete, cv_PtrOfSuperRes_PyrLKOpticalFlow_getInnerPtr_const, cv_PtrOfSuperRes_PyrLKOpticalFlow_getInnerPtrMut, cv_PtrOfSuperRes_PyrLKOpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::superres::SuperRes_PyrLKOpticalFlow> {
@@ -18760,7 +18856,7 @@ mod superres_types {
 	pub type PtrOfSuperRes_SuperResolution = core::Ptr<crate::superres::SuperRes_SuperResolution>;
 	
 	ptr_extern! { crate::superres::SuperRes_SuperResolution,
-		cv_PtrOfSuperRes_SuperResolution_delete, cv_PtrOfSuperRes_SuperResolution_getInnerPtr_const, cv_PtrOfSuperRes_SuperResolution_getInnerPtrMut
+		cv_PtrOfSuperRes_SuperResolution_delete, cv_PtrOfSuperRes_SuperResolution_getInnerPtr_const, cv_PtrOfSuperRes_SuperResolution_getInnerPtrMut, cv_PtrOfSuperRes_SuperResolution_clone_const
 	}
 	
 	impl core::Ptr<crate::superres::SuperRes_SuperResolution> {
@@ -18811,7 +18907,7 @@ mod surface_matching_types {
 	pub type PtrOfPose3D = core::Ptr<crate::surface_matching::Pose3D>;
 	
 	ptr_extern! { crate::surface_matching::Pose3D,
-		cv_PtrOfPose3D_delete, cv_PtrOfPose3D_getInnerPtr_const, cv_PtrOfPose3D_getInnerPtrMut
+		cv_PtrOfPose3D_delete, cv_PtrOfPose3D_getInnerPtr_const, cv_PtrOfPose3D_getInnerPtrMut, cv_PtrOfPose3D_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::surface_matching::Pose3D, cv_PtrOfPose3D_new_const_Pose3D }
@@ -18847,7 +18943,7 @@ mod surface_matching_types {
 	pub type PtrOfPoseCluster3D = core::Ptr<crate::surface_matching::PoseCluster3D>;
 	
 	ptr_extern! { crate::surface_matching::PoseCluster3D,
-		cv_PtrOfPoseCluster3D_delete, cv_PtrOfPoseCluster3D_getInnerPtr_const, cv_PtrOfPoseCluster3D_getInnerPtrMut
+		cv_PtrOfPoseCluster3D_delete, cv_PtrOfPoseCluster3D_getInnerPtr_const, cv_PtrOfPoseCluster3D_getInnerPtrMut, cv_PtrOfPoseCluster3D_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::surface_matching::PoseCluster3D, cv_PtrOfPoseCluster3D_new_const_PoseCluster3D }
@@ -18904,7 +19000,7 @@ mod text_types {
 	pub type PtrOfERFilter = core::Ptr<crate::text::ERFilter>;
 	
 	ptr_extern! { crate::text::ERFilter,
-		cv_PtrOfERFilter_delete, cv_PtrOfERFilter_getInnerPtr_const, cv_PtrOfERFilter_getInnerPtrMut
+		cv_PtrOfERFilter_delete, cv_PtrOfERFilter_getInnerPtr_const, cv_PtrOfERFilter_getInnerPtrMut, cv_PtrOfERFilter_clone_const
 	}
 	
 	impl core::Ptr<crate::text::ERFilter> {
@@ -18939,7 +19035,7 @@ mod text_types {
 	pub type PtrOfERFilter_Callback = core::Ptr<crate::text::ERFilter_Callback>;
 	
 	ptr_extern! { crate::text::ERFilter_Callback,
-		cv_PtrOfERFilter_Callback_delete, cv_PtrOfERFilter_Callback_getInnerPtr_const, cv_PtrOfERFilter_Callback_getInnerPtrMut
+		cv_PtrOfERFilter_Callback_delete, cv_PtrOfERFilter_Callback_getInnerPtr_const, cv_PtrOfERFilter_Callback_getInnerPtrMut, cv_PtrOfERFilter_Callback_clone_const
 	}
 	
 	impl core::Ptr<crate::text::ERFilter_Callback> {
@@ -18966,7 +19062,7 @@ mod text_types {
 	pub type PtrOfOCRBeamSearchDecoder = core::Ptr<crate::text::OCRBeamSearchDecoder>;
 	
 	ptr_extern! { crate::text::OCRBeamSearchDecoder,
-		cv_PtrOfOCRBeamSearchDecoder_delete, cv_PtrOfOCRBeamSearchDecoder_getInnerPtr_const, cv_PtrOfOCRBeamSearchDecoder_getInnerPtrMut
+		cv_PtrOfOCRBeamSearchDecoder_delete, cv_PtrOfOCRBeamSearchDecoder_getInnerPtr_const, cv_PtrOfOCRBeamSearchDecoder_getInnerPtrMut, cv_PtrOfOCRBeamSearchDecoder_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::text::OCRBeamSearchDecoder, cv_PtrOfOCRBeamSearchDecoder_new_const_OCRBeamSearchDecoder }
@@ -19002,7 +19098,7 @@ mod text_types {
 	pub type PtrOfOCRBeamSearchDecoder_ClassifierCallback = core::Ptr<crate::text::OCRBeamSearchDecoder_ClassifierCallback>;
 	
 	ptr_extern! { crate::text::OCRBeamSearchDecoder_ClassifierCallback,
-		cv_PtrOfOCRBeamSearchDecoder_ClassifierCallback_delete, cv_PtrOfOCRBeamSearchDecoder_ClassifierCallback_getInnerPtr_const, cv_PtrOfOCRBeamSearchDecoder_ClassifierCallback_getInnerPtrMut
+		cv_PtrOfOCRBeamSearchDecoder_ClassifierCallback_delete, cv_PtrOfOCRBeamSearchDecoder_ClassifierCallback_getInnerPtr_const, cv_PtrOfOCRBeamSearchDecoder_ClassifierCallback_getInnerPtrMut, cv_PtrOfOCRBeamSearchDecoder_ClassifierCallback_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::text::OCRBeamSearchDecoder_ClassifierCallback, cv_PtrOfOCRBeamSearchDecoder_ClassifierCallback_new_const_ClassifierCallback }
@@ -19030,7 +19126,7 @@ mod text_types {
 	pub type PtrOfOCRHMMDecoder = core::Ptr<crate::text::OCRHMMDecoder>;
 	
 	ptr_extern! { crate::text::OCRHMMDecoder,
-		cv_PtrOfOCRHMMDecoder_delete, cv_PtrOfOCRHMMDecoder_getInnerPtr_const, cv_PtrOfOCRHMMDecoder_getInnerPtrMut
+		cv_PtrOfOCRHMMDecoder_delete, cv_PtrOfOCRHMMDecoder_getInnerPtr_const, cv_PtrOfOCRHMMDecoder_getInnerPtrMut, cv_PtrOfOCRHMMDecoder_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::text::OCRHMMDecoder, cv_PtrOfOCRHMMDecoder_new_const_OCRHMMDecoder }
@@ -19066,7 +19162,7 @@ mod text_types {
 	pub type PtrOfOCRHMMDecoder_ClassifierCallback = core::Ptr<crate::text::OCRHMMDecoder_ClassifierCallback>;
 	
 	ptr_extern! { crate::text::OCRHMMDecoder_ClassifierCallback,
-		cv_PtrOfOCRHMMDecoder_ClassifierCallback_delete, cv_PtrOfOCRHMMDecoder_ClassifierCallback_getInnerPtr_const, cv_PtrOfOCRHMMDecoder_ClassifierCallback_getInnerPtrMut
+		cv_PtrOfOCRHMMDecoder_ClassifierCallback_delete, cv_PtrOfOCRHMMDecoder_ClassifierCallback_getInnerPtr_const, cv_PtrOfOCRHMMDecoder_ClassifierCallback_getInnerPtrMut, cv_PtrOfOCRHMMDecoder_ClassifierCallback_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::text::OCRHMMDecoder_ClassifierCallback, cv_PtrOfOCRHMMDecoder_ClassifierCallback_new_const_ClassifierCallback }
@@ -19094,7 +19190,7 @@ mod text_types {
 	pub type PtrOfOCRHolisticWordRecognizer = core::Ptr<crate::text::OCRHolisticWordRecognizer>;
 	
 	ptr_extern! { crate::text::OCRHolisticWordRecognizer,
-		cv_PtrOfOCRHolisticWordRecognizer_delete, cv_PtrOfOCRHolisticWordRecognizer_getInnerPtr_const, cv_PtrOfOCRHolisticWordRecognizer_getInnerPtrMut
+		cv_PtrOfOCRHolisticWordRecognizer_delete, cv_PtrOfOCRHolisticWordRecognizer_getInnerPtr_const, cv_PtrOfOCRHolisticWordRecognizer_getInnerPtrMut, cv_PtrOfOCRHolisticWordRecognizer_clone_const
 	}
 	
 	impl core::Ptr<crate::text::OCRHolisticWordRecognizer> {
@@ -19129,7 +19225,7 @@ mod text_types {
 	pub type PtrOfOCRTesseract = core::Ptr<crate::text::OCRTesseract>;
 	
 	ptr_extern! { crate::text::OCRTesseract,
-		cv_PtrOfOCRTesseract_delete, cv_PtrOfOCRTesseract_getInnerPtr_const, cv_PtrOfOCRTesseract_getInnerPtrMut
+		cv_PtrOfOCRTesseract_delete, cv_PtrOfOCRTesseract_getInnerPtr_const, cv_PtrOfOCRTesseract_getInnerPtrMut, cv_PtrOfOCRTesseract_clone_const
 	}
 	
 	impl core::Ptr<crate::text::OCRTesseract> {
@@ -19164,7 +19260,7 @@ mod text_types {
 	pub type PtrOfTextDetectorCNN = core::Ptr<crate::text::TextDetectorCNN>;
 	
 	ptr_extern! { crate::text::TextDetectorCNN,
-		cv_PtrOfTextDetectorCNN_delete, cv_PtrOfTextDetectorCNN_getInnerPtr_const, cv_PtrOfTextDetectorCNN_getInnerPtrMut
+		cv_PtrOfTextDetectorCNN_delete, cv_PtrOfTextDetectorCNN_getInnerPtr_const, cv_PtrOfTextDetectorCNN_getInnerPtrMut, cv_PtrOfTextDetectorCNN_clone_const
 	}
 	
 	impl core::Ptr<crate::text::TextDetectorCNN> {
@@ -19243,7 +19339,7 @@ mod tracking_types {
 	pub type PtrOfTrackerCSRT = core::Ptr<crate::tracking::TrackerCSRT>;
 	
 	ptr_extern! { crate::tracking::TrackerCSRT,
-		cv_PtrOfTrackerCSRT_delete, cv_PtrOfTrackerCSRT_getInnerPtr_const, cv_PtrOfTrackerCSRT_getInnerPtrMut
+		cv_PtrOfTrackerCSRT_delete, cv_PtrOfTrackerCSRT_getInnerPtr_const, cv_PtrOfTrackerCSRT_getInnerPtrMut, cv_PtrOfTrackerCSRT_clone_const
 	}
 	
 	impl core::Ptr<crate::tracking::TrackerCSRT> {
@@ -19278,7 +19374,7 @@ mod tracking_types {
 	pub type PtrOfTrackerKCF = core::Ptr<crate::tracking::TrackerKCF>;
 	
 	ptr_extern! { crate::tracking::TrackerKCF,
-		cv_PtrOfTrackerKCF_delete, cv_PtrOfTrackerKCF_getInnerPtr_const, cv_PtrOfTrackerKCF_getInnerPtrMut
+		cv_PtrOfTrackerKCF_delete, cv_PtrOfTrackerKCF_getInnerPtr_const, cv_PtrOfTrackerKCF_getInnerPtrMut, cv_PtrOfTrackerKCF_clone_const
 	}
 	
 	impl core::Ptr<crate::tracking::TrackerKCF> {
@@ -19321,7 +19417,7 @@ mod video_types {
 	pub type PtrOfBackgroundSubtractorKNN = core::Ptr<crate::video::BackgroundSubtractorKNN>;
 	
 	ptr_extern! { crate::video::BackgroundSubtractorKNN,
-		cv_PtrOfBackgroundSubtractorKNN_delete, cv_PtrOfBackgroundSubtractorKNN_getInnerPtr_const, cv_PtrOfBackgroundSubtractorKNN_getInnerPtrMut
+		cv_PtrOfBackgroundSubtractorKNN_delete, cv_PtrOfBackgroundSubtractorKNN_getInnerPtr_const, cv_PtrOfBackgroundSubtractorKNN_getInnerPtrMut, cv_PtrOfBackgroundSubtractorKNN_clone_const
 	}
 	
 	impl core::Ptr<crate::video::BackgroundSubtractorKNN> {
@@ -19364,7 +19460,7 @@ mod video_types {
 	pub type PtrOfBackgroundSubtractorMOG2 = core::Ptr<crate::video::BackgroundSubtractorMOG2>;
 	
 	ptr_extern! { crate::video::BackgroundSubtractorMOG2,
-		cv_PtrOfBackgroundSubtractorMOG2_delete, cv_PtrOfBackgroundSubtractorMOG2_getInnerPtr_const, cv_PtrOfBackgroundSubtractorMOG2_getInnerPtrMut
+		cv_PtrOfBackgroundSubtractorMOG2_delete, cv_PtrOfBackgroundSubtractorMOG2_getInnerPtr_const, cv_PtrOfBackgroundSubtractorMOG2_getInnerPtrMut, cv_PtrOfBackgroundSubtractorMOG2_clone_const
 	}
 	
 	impl core::Ptr<crate::video::BackgroundSubtractorMOG2> {
@@ -19407,7 +19503,7 @@ mod video_types {
 	pub type PtrOfDISOpticalFlow = core::Ptr<crate::video::DISOpticalFlow>;
 	
 	ptr_extern! { crate::video::DISOpticalFlow,
-		cv_PtrOfDISOpticalFlow_delete, cv_PtrOfDISOpticalFlow_getInnerPtr_const, cv_PtrOfDISOpticalFlow_getInnerPtrMut
+		cv_PtrOfDISOpticalFlow_delete, cv_PtrOfDISOpticalFlow_getInnerPtr_const, cv_PtrOfDISOpticalFlow_getInnerPtrMut, cv_PtrOfDISOpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::video::DISOpticalFlow> {
@@ -19450,7 +19546,7 @@ mod video_types {
 	pub type PtrOfDenseOpticalFlow = core::Ptr<crate::video::DenseOpticalFlow>;
 	
 	ptr_extern! { crate::video::DenseOpticalFlow,
-		cv_PtrOfDenseOpticalFlow_delete, cv_PtrOfDenseOpticalFlow_getInnerPtr_const, cv_PtrOfDenseOpticalFlow_getInnerPtrMut
+		cv_PtrOfDenseOpticalFlow_delete, cv_PtrOfDenseOpticalFlow_getInnerPtr_const, cv_PtrOfDenseOpticalFlow_getInnerPtrMut, cv_PtrOfDenseOpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::video::DenseOpticalFlow> {
@@ -19485,7 +19581,7 @@ mod video_types {
 	pub type PtrOfFarnebackOpticalFlow = core::Ptr<crate::video::FarnebackOpticalFlow>;
 	
 	ptr_extern! { crate::video::FarnebackOpticalFlow,
-		cv_PtrOfFarnebackOpticalFlow_delete, cv_PtrOfFarnebackOpticalFlow_getInnerPtr_const, cv_PtrOfFarnebackOpticalFlow_getInnerPtrMut
+		cv_PtrOfFarnebackOpticalFlow_delete, cv_PtrOfFarnebackOpticalFlow_getInnerPtr_const, cv_PtrOfFarnebackOpticalFlow_getInnerPtrMut, cv_PtrOfFarnebackOpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::video::FarnebackOpticalFlow> {
@@ -19528,7 +19624,7 @@ mod video_types {
 	pub type PtrOfSparseOpticalFlow = core::Ptr<crate::video::SparseOpticalFlow>;
 	
 	ptr_extern! { crate::video::SparseOpticalFlow,
-		cv_PtrOfSparseOpticalFlow_delete, cv_PtrOfSparseOpticalFlow_getInnerPtr_const, cv_PtrOfSparseOpticalFlow_getInnerPtrMut
+		cv_PtrOfSparseOpticalFlow_delete, cv_PtrOfSparseOpticalFlow_getInnerPtr_const, cv_PtrOfSparseOpticalFlow_getInnerPtrMut, cv_PtrOfSparseOpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::video::SparseOpticalFlow> {
@@ -19563,7 +19659,7 @@ mod video_types {
 	pub type PtrOfSparsePyrLKOpticalFlow = core::Ptr<crate::video::SparsePyrLKOpticalFlow>;
 	
 	ptr_extern! { crate::video::SparsePyrLKOpticalFlow,
-		cv_PtrOfSparsePyrLKOpticalFlow_delete, cv_PtrOfSparsePyrLKOpticalFlow_getInnerPtr_const, cv_PtrOfSparsePyrLKOpticalFlow_getInnerPtrMut
+		cv_PtrOfSparsePyrLKOpticalFlow_delete, cv_PtrOfSparsePyrLKOpticalFlow_getInnerPtr_const, cv_PtrOfSparsePyrLKOpticalFlow_getInnerPtrMut, cv_PtrOfSparsePyrLKOpticalFlow_clone_const
 	}
 	
 	impl core::Ptr<crate::video::SparsePyrLKOpticalFlow> {
@@ -19606,7 +19702,7 @@ mod video_types {
 	pub type PtrOfTrackerDaSiamRPN = core::Ptr<crate::video::TrackerDaSiamRPN>;
 	
 	ptr_extern! { crate::video::TrackerDaSiamRPN,
-		cv_PtrOfTrackerDaSiamRPN_delete, cv_PtrOfTrackerDaSiamRPN_getInnerPtr_const, cv_PtrOfTrackerDaSiamRPN_getInnerPtrMut
+		cv_PtrOfTrackerDaSiamRPN_delete, cv_PtrOfTrackerDaSiamRPN_getInnerPtr_const, cv_PtrOfTrackerDaSiamRPN_getInnerPtrMut, cv_PtrOfTrackerDaSiamRPN_clone_const
 	}
 	
 	impl core::Ptr<crate::video::TrackerDaSiamRPN> {
@@ -19641,7 +19737,7 @@ mod video_types {
 	pub type PtrOfTrackerGOTURN = core::Ptr<crate::video::TrackerGOTURN>;
 	
 	ptr_extern! { crate::video::TrackerGOTURN,
-		cv_PtrOfTrackerGOTURN_delete, cv_PtrOfTrackerGOTURN_getInnerPtr_const, cv_PtrOfTrackerGOTURN_getInnerPtrMut
+		cv_PtrOfTrackerGOTURN_delete, cv_PtrOfTrackerGOTURN_getInnerPtr_const, cv_PtrOfTrackerGOTURN_getInnerPtrMut, cv_PtrOfTrackerGOTURN_clone_const
 	}
 	
 	impl core::Ptr<crate::video::TrackerGOTURN> {
@@ -19676,7 +19772,7 @@ mod video_types {
 	pub type PtrOfTrackerMIL = core::Ptr<crate::video::TrackerMIL>;
 	
 	ptr_extern! { crate::video::TrackerMIL,
-		cv_PtrOfTrackerMIL_delete, cv_PtrOfTrackerMIL_getInnerPtr_const, cv_PtrOfTrackerMIL_getInnerPtrMut
+		cv_PtrOfTrackerMIL_delete, cv_PtrOfTrackerMIL_getInnerPtr_const, cv_PtrOfTrackerMIL_getInnerPtrMut, cv_PtrOfTrackerMIL_clone_const
 	}
 	
 	impl core::Ptr<crate::video::TrackerMIL> {
@@ -19711,7 +19807,7 @@ mod video_types {
 	pub type PtrOfTrackerNano = core::Ptr<crate::video::TrackerNano>;
 	
 	ptr_extern! { crate::video::TrackerNano,
-		cv_PtrOfTrackerNano_delete, cv_PtrOfTrackerNano_getInnerPtr_const, cv_PtrOfTrackerNano_getInnerPtrMut
+		cv_PtrOfTrackerNano_delete, cv_PtrOfTrackerNano_getInnerPtr_const, cv_PtrOfTrackerNano_getInnerPtrMut, cv_PtrOfTrackerNano_clone_const
 	}
 	
 	impl core::Ptr<crate::video::TrackerNano> {
@@ -19746,7 +19842,7 @@ mod video_types {
 	pub type PtrOfVariationalRefinement = core::Ptr<crate::video::VariationalRefinement>;
 	
 	ptr_extern! { crate::video::VariationalRefinement,
-		cv_PtrOfVariationalRefinement_delete, cv_PtrOfVariationalRefinement_getInnerPtr_const, cv_PtrOfVariationalRefinement_getInnerPtrMut
+		cv_PtrOfVariationalRefinement_delete, cv_PtrOfVariationalRefinement_getInnerPtr_const, cv_PtrOfVariationalRefinement_getInnerPtrMut, cv_PtrOfVariationalRefinement_clone_const
 	}
 	
 	impl core::Ptr<crate::video::VariationalRefinement> {
@@ -19844,7 +19940,7 @@ mod videostab_types {
 	pub type PtrOfColorAverageInpainter = core::Ptr<crate::videostab::ColorAverageInpainter>;
 	
 	ptr_extern! { crate::videostab::ColorAverageInpainter,
-		cv_PtrOfColorAverageInpainter_delete, cv_PtrOfColorAverageInpainter_getInnerPtr_const, cv_PtrOfColorAverageInpainter_getInnerPtrMut
+		cv_PtrOfColorAverageInpainter_delete, cv_PtrOfColorAverageInpainter_getInnerPtr_const, cv_PtrOfColorAverageInpainter_getInnerPtrMut, cv_PtrOfColorAverageInpainter_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::ColorAverageInpainter, cv_PtrOfColorAverageInpainter_new_const_ColorAverageInpainter }
@@ -19871,6 +19967,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfColorAverageInpainter, core::Ptr<crate::videostab::InpainterBase>, cv_PtrOfColorAverageInpainter_to_PtrOfInpainterBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::InpainterBase>, PtrOfColorAverageInpainter, cv_PtrOfInpainterBase_to_PtrOfColorAverageInpainter }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::ColorAverageInpainter> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -19882,7 +19979,7 @@ mod videostab_types {
 	pub type PtrOfColorInpainter = core::Ptr<crate::videostab::ColorInpainter>;
 	
 	ptr_extern! { crate::videostab::ColorInpainter,
-		cv_PtrOfColorInpainter_delete, cv_PtrOfColorInpainter_getInnerPtr_const, cv_PtrOfColorInpainter_getInnerPtrMut
+		cv_PtrOfColorInpainter_delete, cv_PtrOfColorInpainter_getInnerPtr_const, cv_PtrOfColorInpainter_getInnerPtrMut, cv_PtrOfColorInpainter_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::ColorInpainter, cv_PtrOfColorInpainter_new_const_ColorInpainter }
@@ -19909,6 +20006,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfColorInpainter, core::Ptr<crate::videostab::InpainterBase>, cv_PtrOfColorInpainter_to_PtrOfInpainterBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::InpainterBase>, PtrOfColorInpainter, cv_PtrOfInpainterBase_to_PtrOfColorInpainter }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::ColorInpainter> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -19920,7 +20018,7 @@ mod videostab_types {
 	pub type PtrOfConsistentMosaicInpainter = core::Ptr<crate::videostab::ConsistentMosaicInpainter>;
 	
 	ptr_extern! { crate::videostab::ConsistentMosaicInpainter,
-		cv_PtrOfConsistentMosaicInpainter_delete, cv_PtrOfConsistentMosaicInpainter_getInnerPtr_const, cv_PtrOfConsistentMosaicInpainter_getInnerPtrMut
+		cv_PtrOfConsistentMosaicInpainter_delete, cv_PtrOfConsistentMosaicInpainter_getInnerPtr_const, cv_PtrOfConsistentMosaicInpainter_getInnerPtrMut, cv_PtrOfConsistentMosaicInpainter_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::ConsistentMosaicInpainter, cv_PtrOfConsistentMosaicInpainter_new_const_ConsistentMosaicInpainter }
@@ -19947,6 +20045,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfConsistentMosaicInpainter, core::Ptr<crate::videostab::InpainterBase>, cv_PtrOfConsistentMosaicInpainter_to_PtrOfInpainterBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::InpainterBase>, PtrOfConsistentMosaicInpainter, cv_PtrOfInpainterBase_to_PtrOfConsistentMosaicInpainter }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::ConsistentMosaicInpainter> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -19958,7 +20057,7 @@ mod videostab_types {
 	pub type PtrOfDeblurerBase = core::Ptr<crate::videostab::DeblurerBase>;
 	
 	ptr_extern! { crate::videostab::DeblurerBase,
-		cv_PtrOfDeblurerBase_delete, cv_PtrOfDeblurerBase_getInnerPtr_const, cv_PtrOfDeblurerBase_getInnerPtrMut
+		cv_PtrOfDeblurerBase_delete, cv_PtrOfDeblurerBase_getInnerPtr_const, cv_PtrOfDeblurerBase_getInnerPtrMut, cv_PtrOfDeblurerBase_clone_const
 	}
 	
 	impl core::Ptr<crate::videostab::DeblurerBase> {
@@ -19985,7 +20084,7 @@ mod videostab_types {
 	pub type PtrOfDensePyrLkOptFlowEstimatorGpu = core::Ptr<crate::videostab::DensePyrLkOptFlowEstimatorGpu>;
 	
 	ptr_extern! { crate::videostab::DensePyrLkOptFlowEstimatorGpu,
-		cv_PtrOfDensePyrLkOptFlowEstimatorGpu_delete, cv_PtrOfDensePyrLkOptFlowEstimatorGpu_getInnerPtr_const, cv_PtrOfDensePyrLkOptFlowEstimatorGpu_getInnerPtrMut
+		cv_PtrOfDensePyrLkOptFlowEstimatorGpu_delete, cv_PtrOfDensePyrLkOptFlowEstimatorGpu_getInnerPtr_const, cv_PtrOfDensePyrLkOptFlowEstimatorGpu_getInnerPtrMut, cv_PtrOfDensePyrLkOptFlowEstimatorGpu_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::DensePyrLkOptFlowEstimatorGpu, cv_PtrOfDensePyrLkOptFlowEstimatorGpu_new_const_DensePyrLkOptFlowEstimatorGpu }
@@ -20012,6 +20111,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfDensePyrLkOptFlowEstimatorGpu, core::Ptr<crate::videostab::IDenseOptFlowEstimator>, cv_PtrOfDensePyrLkOptFlowEstimatorGpu_to_PtrOfIDenseOptFlowEstimator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::IDenseOptFlowEstimator>, PtrOfDensePyrLkOptFlowEstimatorGpu, cv_PtrOfIDenseOptFlowEstimator_to_PtrOfDensePyrLkOptFlowEstimatorGpu }
 	impl crate::videostab::PyrLkOptFlowEstimatorBaseTraitConst for core::Ptr<crate::videostab::DensePyrLkOptFlowEstimatorGpu> {
 		#[inline] fn as_raw_PyrLkOptFlowEstimatorBase(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -20031,7 +20131,7 @@ mod videostab_types {
 	pub type PtrOfFromFileMotionReader = core::Ptr<crate::videostab::FromFileMotionReader>;
 	
 	ptr_extern! { crate::videostab::FromFileMotionReader,
-		cv_PtrOfFromFileMotionReader_delete, cv_PtrOfFromFileMotionReader_getInnerPtr_const, cv_PtrOfFromFileMotionReader_getInnerPtrMut
+		cv_PtrOfFromFileMotionReader_delete, cv_PtrOfFromFileMotionReader_getInnerPtr_const, cv_PtrOfFromFileMotionReader_getInnerPtrMut, cv_PtrOfFromFileMotionReader_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::FromFileMotionReader, cv_PtrOfFromFileMotionReader_new_const_FromFileMotionReader }
@@ -20058,6 +20158,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfFromFileMotionReader, core::Ptr<crate::videostab::ImageMotionEstimatorBase>, cv_PtrOfFromFileMotionReader_to_PtrOfImageMotionEstimatorBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::ImageMotionEstimatorBase>, PtrOfFromFileMotionReader, cv_PtrOfImageMotionEstimatorBase_to_PtrOfFromFileMotionReader }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::FromFileMotionReader> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20069,7 +20170,7 @@ mod videostab_types {
 	pub type PtrOfGaussianMotionFilter = core::Ptr<crate::videostab::GaussianMotionFilter>;
 	
 	ptr_extern! { crate::videostab::GaussianMotionFilter,
-		cv_PtrOfGaussianMotionFilter_delete, cv_PtrOfGaussianMotionFilter_getInnerPtr_const, cv_PtrOfGaussianMotionFilter_getInnerPtrMut
+		cv_PtrOfGaussianMotionFilter_delete, cv_PtrOfGaussianMotionFilter_getInnerPtr_const, cv_PtrOfGaussianMotionFilter_getInnerPtrMut, cv_PtrOfGaussianMotionFilter_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::GaussianMotionFilter, cv_PtrOfGaussianMotionFilter_new_const_GaussianMotionFilter }
@@ -20096,6 +20197,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfGaussianMotionFilter, core::Ptr<crate::videostab::IMotionStabilizer>, cv_PtrOfGaussianMotionFilter_to_PtrOfIMotionStabilizer }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::IMotionStabilizer>, PtrOfGaussianMotionFilter, cv_PtrOfIMotionStabilizer_to_PtrOfGaussianMotionFilter }
 	impl crate::videostab::MotionFilterBaseTraitConst for core::Ptr<crate::videostab::GaussianMotionFilter> {
 		#[inline] fn as_raw_MotionFilterBase(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -20106,6 +20208,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfGaussianMotionFilter, core::Ptr<crate::videostab::MotionFilterBase>, cv_PtrOfGaussianMotionFilter_to_PtrOfMotionFilterBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::MotionFilterBase>, PtrOfGaussianMotionFilter, cv_PtrOfMotionFilterBase_to_PtrOfGaussianMotionFilter }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::GaussianMotionFilter> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20117,7 +20220,7 @@ mod videostab_types {
 	pub type PtrOfIDenseOptFlowEstimator = core::Ptr<crate::videostab::IDenseOptFlowEstimator>;
 	
 	ptr_extern! { crate::videostab::IDenseOptFlowEstimator,
-		cv_PtrOfIDenseOptFlowEstimator_delete, cv_PtrOfIDenseOptFlowEstimator_getInnerPtr_const, cv_PtrOfIDenseOptFlowEstimator_getInnerPtrMut
+		cv_PtrOfIDenseOptFlowEstimator_delete, cv_PtrOfIDenseOptFlowEstimator_getInnerPtr_const, cv_PtrOfIDenseOptFlowEstimator_getInnerPtrMut, cv_PtrOfIDenseOptFlowEstimator_clone_const
 	}
 	
 	impl core::Ptr<crate::videostab::IDenseOptFlowEstimator> {
@@ -20144,7 +20247,7 @@ mod videostab_types {
 	pub type PtrOfIFrameSource = core::Ptr<crate::videostab::IFrameSource>;
 	
 	ptr_extern! { crate::videostab::IFrameSource,
-		cv_PtrOfIFrameSource_delete, cv_PtrOfIFrameSource_getInnerPtr_const, cv_PtrOfIFrameSource_getInnerPtrMut
+		cv_PtrOfIFrameSource_delete, cv_PtrOfIFrameSource_getInnerPtr_const, cv_PtrOfIFrameSource_getInnerPtrMut, cv_PtrOfIFrameSource_clone_const
 	}
 	
 	impl core::Ptr<crate::videostab::IFrameSource> {
@@ -20171,7 +20274,7 @@ mod videostab_types {
 	pub type PtrOfILog = core::Ptr<crate::videostab::ILog>;
 	
 	ptr_extern! { crate::videostab::ILog,
-		cv_PtrOfILog_delete, cv_PtrOfILog_getInnerPtr_const, cv_PtrOfILog_getInnerPtrMut
+		cv_PtrOfILog_delete, cv_PtrOfILog_getInnerPtr_const, cv_PtrOfILog_getInnerPtrMut, cv_PtrOfILog_clone_const
 	}
 	
 	impl core::Ptr<crate::videostab::ILog> {
@@ -20198,7 +20301,7 @@ mod videostab_types {
 	pub type PtrOfIMotionStabilizer = core::Ptr<crate::videostab::IMotionStabilizer>;
 	
 	ptr_extern! { crate::videostab::IMotionStabilizer,
-		cv_PtrOfIMotionStabilizer_delete, cv_PtrOfIMotionStabilizer_getInnerPtr_const, cv_PtrOfIMotionStabilizer_getInnerPtrMut
+		cv_PtrOfIMotionStabilizer_delete, cv_PtrOfIMotionStabilizer_getInnerPtr_const, cv_PtrOfIMotionStabilizer_getInnerPtrMut, cv_PtrOfIMotionStabilizer_clone_const
 	}
 	
 	impl core::Ptr<crate::videostab::IMotionStabilizer> {
@@ -20225,7 +20328,7 @@ mod videostab_types {
 	pub type PtrOfIOutlierRejector = core::Ptr<crate::videostab::IOutlierRejector>;
 	
 	ptr_extern! { crate::videostab::IOutlierRejector,
-		cv_PtrOfIOutlierRejector_delete, cv_PtrOfIOutlierRejector_getInnerPtr_const, cv_PtrOfIOutlierRejector_getInnerPtrMut
+		cv_PtrOfIOutlierRejector_delete, cv_PtrOfIOutlierRejector_getInnerPtr_const, cv_PtrOfIOutlierRejector_getInnerPtrMut, cv_PtrOfIOutlierRejector_clone_const
 	}
 	
 	impl core::Ptr<crate::videostab::IOutlierRejector> {
@@ -20252,7 +20355,7 @@ mod videostab_types {
 	pub type PtrOfISparseOptFlowEstimator = core::Ptr<crate::videostab::ISparseOptFlowEstimator>;
 	
 	ptr_extern! { crate::videostab::ISparseOptFlowEstimator,
-		cv_PtrOfISparseOptFlowEstimator_delete, cv_PtrOfISparseOptFlowEstimator_getInnerPtr_const, cv_PtrOfISparseOptFlowEstimator_getInnerPtrMut
+		cv_PtrOfISparseOptFlowEstimator_delete, cv_PtrOfISparseOptFlowEstimator_getInnerPtr_const, cv_PtrOfISparseOptFlowEstimator_getInnerPtrMut, cv_PtrOfISparseOptFlowEstimator_clone_const
 	}
 	
 	impl core::Ptr<crate::videostab::ISparseOptFlowEstimator> {
@@ -20279,7 +20382,7 @@ mod videostab_types {
 	pub type PtrOfImageMotionEstimatorBase = core::Ptr<crate::videostab::ImageMotionEstimatorBase>;
 	
 	ptr_extern! { crate::videostab::ImageMotionEstimatorBase,
-		cv_PtrOfImageMotionEstimatorBase_delete, cv_PtrOfImageMotionEstimatorBase_getInnerPtr_const, cv_PtrOfImageMotionEstimatorBase_getInnerPtrMut
+		cv_PtrOfImageMotionEstimatorBase_delete, cv_PtrOfImageMotionEstimatorBase_getInnerPtr_const, cv_PtrOfImageMotionEstimatorBase_getInnerPtrMut, cv_PtrOfImageMotionEstimatorBase_clone_const
 	}
 	
 	impl core::Ptr<crate::videostab::ImageMotionEstimatorBase> {
@@ -20306,7 +20409,7 @@ mod videostab_types {
 	pub type PtrOfInpainterBase = core::Ptr<crate::videostab::InpainterBase>;
 	
 	ptr_extern! { crate::videostab::InpainterBase,
-		cv_PtrOfInpainterBase_delete, cv_PtrOfInpainterBase_getInnerPtr_const, cv_PtrOfInpainterBase_getInnerPtrMut
+		cv_PtrOfInpainterBase_delete, cv_PtrOfInpainterBase_getInnerPtr_const, cv_PtrOfInpainterBase_getInnerPtrMut, cv_PtrOfInpainterBase_clone_const
 	}
 	
 	impl core::Ptr<crate::videostab::InpainterBase> {
@@ -20333,7 +20436,7 @@ mod videostab_types {
 	pub type PtrOfInpaintingPipeline = core::Ptr<crate::videostab::InpaintingPipeline>;
 	
 	ptr_extern! { crate::videostab::InpaintingPipeline,
-		cv_PtrOfInpaintingPipeline_delete, cv_PtrOfInpaintingPipeline_getInnerPtr_const, cv_PtrOfInpaintingPipeline_getInnerPtrMut
+		cv_PtrOfInpaintingPipeline_delete, cv_PtrOfInpaintingPipeline_getInnerPtr_const, cv_PtrOfInpaintingPipeline_getInnerPtrMut, cv_PtrOfInpaintingPipeline_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::InpaintingPipeline, cv_PtrOfInpaintingPipeline_new_const_InpaintingPipeline }
@@ -20360,6 +20463,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfInpaintingPipeline, core::Ptr<crate::videostab::InpainterBase>, cv_PtrOfInpaintingPipeline_to_PtrOfInpainterBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::InpainterBase>, PtrOfInpaintingPipeline, cv_PtrOfInpainterBase_to_PtrOfInpaintingPipeline }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::InpaintingPipeline> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20371,7 +20475,7 @@ mod videostab_types {
 	pub type PtrOfKeypointBasedMotionEstimator = core::Ptr<crate::videostab::KeypointBasedMotionEstimator>;
 	
 	ptr_extern! { crate::videostab::KeypointBasedMotionEstimator,
-		cv_PtrOfKeypointBasedMotionEstimator_delete, cv_PtrOfKeypointBasedMotionEstimator_getInnerPtr_const, cv_PtrOfKeypointBasedMotionEstimator_getInnerPtrMut
+		cv_PtrOfKeypointBasedMotionEstimator_delete, cv_PtrOfKeypointBasedMotionEstimator_getInnerPtr_const, cv_PtrOfKeypointBasedMotionEstimator_getInnerPtrMut, cv_PtrOfKeypointBasedMotionEstimator_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::KeypointBasedMotionEstimator, cv_PtrOfKeypointBasedMotionEstimator_new_const_KeypointBasedMotionEstimator }
@@ -20398,6 +20502,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfKeypointBasedMotionEstimator, core::Ptr<crate::videostab::ImageMotionEstimatorBase>, cv_PtrOfKeypointBasedMotionEstimator_to_PtrOfImageMotionEstimatorBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::ImageMotionEstimatorBase>, PtrOfKeypointBasedMotionEstimator, cv_PtrOfImageMotionEstimatorBase_to_PtrOfKeypointBasedMotionEstimator }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::KeypointBasedMotionEstimator> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20409,7 +20514,7 @@ mod videostab_types {
 	pub type PtrOfKeypointBasedMotionEstimatorGpu = core::Ptr<crate::videostab::KeypointBasedMotionEstimatorGpu>;
 	
 	ptr_extern! { crate::videostab::KeypointBasedMotionEstimatorGpu,
-		cv_PtrOfKeypointBasedMotionEstimatorGpu_delete, cv_PtrOfKeypointBasedMotionEstimatorGpu_getInnerPtr_const, cv_PtrOfKeypointBasedMotionEstimatorGpu_getInnerPtrMut
+		cv_PtrOfKeypointBasedMotionEstimatorGpu_delete, cv_PtrOfKeypointBasedMotionEstimatorGpu_getInnerPtr_const, cv_PtrOfKeypointBasedMotionEstimatorGpu_getInnerPtrMut, cv_PtrOfKeypointBasedMotionEstimatorGpu_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::KeypointBasedMotionEstimatorGpu, cv_PtrOfKeypointBasedMotionEstimatorGpu_new_const_KeypointBasedMotionEstimatorGpu }
@@ -20436,6 +20541,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfKeypointBasedMotionEstimatorGpu, core::Ptr<crate::videostab::ImageMotionEstimatorBase>, cv_PtrOfKeypointBasedMotionEstimatorGpu_to_PtrOfImageMotionEstimatorBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::ImageMotionEstimatorBase>, PtrOfKeypointBasedMotionEstimatorGpu, cv_PtrOfImageMotionEstimatorBase_to_PtrOfKeypointBasedMotionEstimatorGpu }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::KeypointBasedMotionEstimatorGpu> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20447,7 +20553,7 @@ mod videostab_types {
 	pub type PtrOfLogToStdout = core::Ptr<crate::videostab::LogToStdout>;
 	
 	ptr_extern! { crate::videostab::LogToStdout,
-		cv_PtrOfLogToStdout_delete, cv_PtrOfLogToStdout_getInnerPtr_const, cv_PtrOfLogToStdout_getInnerPtrMut
+		cv_PtrOfLogToStdout_delete, cv_PtrOfLogToStdout_getInnerPtr_const, cv_PtrOfLogToStdout_getInnerPtrMut, cv_PtrOfLogToStdout_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::LogToStdout, cv_PtrOfLogToStdout_new_const_LogToStdout }
@@ -20474,6 +20580,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfLogToStdout, core::Ptr<crate::videostab::ILog>, cv_PtrOfLogToStdout_to_PtrOfILog }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::ILog>, PtrOfLogToStdout, cv_PtrOfILog_to_PtrOfLogToStdout }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::LogToStdout> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20485,7 +20592,7 @@ mod videostab_types {
 	pub type PtrOfLpMotionStabilizer = core::Ptr<crate::videostab::LpMotionStabilizer>;
 	
 	ptr_extern! { crate::videostab::LpMotionStabilizer,
-		cv_PtrOfLpMotionStabilizer_delete, cv_PtrOfLpMotionStabilizer_getInnerPtr_const, cv_PtrOfLpMotionStabilizer_getInnerPtrMut
+		cv_PtrOfLpMotionStabilizer_delete, cv_PtrOfLpMotionStabilizer_getInnerPtr_const, cv_PtrOfLpMotionStabilizer_getInnerPtrMut, cv_PtrOfLpMotionStabilizer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::LpMotionStabilizer, cv_PtrOfLpMotionStabilizer_new_const_LpMotionStabilizer }
@@ -20512,6 +20619,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfLpMotionStabilizer, core::Ptr<crate::videostab::IMotionStabilizer>, cv_PtrOfLpMotionStabilizer_to_PtrOfIMotionStabilizer }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::IMotionStabilizer>, PtrOfLpMotionStabilizer, cv_PtrOfIMotionStabilizer_to_PtrOfLpMotionStabilizer }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::LpMotionStabilizer> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20523,7 +20631,7 @@ mod videostab_types {
 	pub type PtrOfMaskFrameSource = core::Ptr<crate::videostab::MaskFrameSource>;
 	
 	ptr_extern! { crate::videostab::MaskFrameSource,
-		cv_PtrOfMaskFrameSource_delete, cv_PtrOfMaskFrameSource_getInnerPtr_const, cv_PtrOfMaskFrameSource_getInnerPtrMut
+		cv_PtrOfMaskFrameSource_delete, cv_PtrOfMaskFrameSource_getInnerPtr_const, cv_PtrOfMaskFrameSource_getInnerPtrMut, cv_PtrOfMaskFrameSource_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::MaskFrameSource, cv_PtrOfMaskFrameSource_new_const_MaskFrameSource }
@@ -20550,6 +20658,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfMaskFrameSource, core::Ptr<crate::videostab::IFrameSource>, cv_PtrOfMaskFrameSource_to_PtrOfIFrameSource }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::IFrameSource>, PtrOfMaskFrameSource, cv_PtrOfIFrameSource_to_PtrOfMaskFrameSource }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::MaskFrameSource> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20561,7 +20670,7 @@ mod videostab_types {
 	pub type PtrOfMoreAccurateMotionWobbleSuppressor = core::Ptr<crate::videostab::MoreAccurateMotionWobbleSuppressor>;
 	
 	ptr_extern! { crate::videostab::MoreAccurateMotionWobbleSuppressor,
-		cv_PtrOfMoreAccurateMotionWobbleSuppressor_delete, cv_PtrOfMoreAccurateMotionWobbleSuppressor_getInnerPtr_const, cv_PtrOfMoreAccurateMotionWobbleSuppressor_getInnerPtrMut
+		cv_PtrOfMoreAccurateMotionWobbleSuppressor_delete, cv_PtrOfMoreAccurateMotionWobbleSuppressor_getInnerPtr_const, cv_PtrOfMoreAccurateMotionWobbleSuppressor_getInnerPtrMut, cv_PtrOfMoreAccurateMotionWobbleSuppressor_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::MoreAccurateMotionWobbleSuppressor, cv_PtrOfMoreAccurateMotionWobbleSuppressor_new_const_MoreAccurateMotionWobbleSuppressor }
@@ -20588,6 +20697,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfMoreAccurateMotionWobbleSuppressor, core::Ptr<crate::videostab::MoreAccurateMotionWobbleSuppressorBase>, cv_PtrOfMoreAccurateMotionWobbleSuppressor_to_PtrOfMoreAccurateMotionWobbleSuppressorBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::MoreAccurateMotionWobbleSuppressorBase>, PtrOfMoreAccurateMotionWobbleSuppressor, cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_to_PtrOfMoreAccurateMotionWobbleSuppressor }
 	impl crate::videostab::WobbleSuppressorBaseTraitConst for core::Ptr<crate::videostab::MoreAccurateMotionWobbleSuppressor> {
 		#[inline] fn as_raw_WobbleSuppressorBase(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -20598,6 +20708,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfMoreAccurateMotionWobbleSuppressor, core::Ptr<crate::videostab::WobbleSuppressorBase>, cv_PtrOfMoreAccurateMotionWobbleSuppressor_to_PtrOfWobbleSuppressorBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::WobbleSuppressorBase>, PtrOfMoreAccurateMotionWobbleSuppressor, cv_PtrOfWobbleSuppressorBase_to_PtrOfMoreAccurateMotionWobbleSuppressor }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::MoreAccurateMotionWobbleSuppressor> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20609,7 +20720,7 @@ mod videostab_types {
 	pub type PtrOfMoreAccurateMotionWobbleSuppressorBase = core::Ptr<crate::videostab::MoreAccurateMotionWobbleSuppressorBase>;
 	
 	ptr_extern! { crate::videostab::MoreAccurateMotionWobbleSuppressorBase,
-		cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_delete, cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_getInnerPtr_const, cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_getInnerPtrMut
+		cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_delete, cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_getInnerPtr_const, cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_getInnerPtrMut, cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_clone_const
 	}
 	
 	impl core::Ptr<crate::videostab::MoreAccurateMotionWobbleSuppressorBase> {
@@ -20635,6 +20746,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfMoreAccurateMotionWobbleSuppressorBase, core::Ptr<crate::videostab::WobbleSuppressorBase>, cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_to_PtrOfWobbleSuppressorBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::WobbleSuppressorBase>, PtrOfMoreAccurateMotionWobbleSuppressorBase, cv_PtrOfWobbleSuppressorBase_to_PtrOfMoreAccurateMotionWobbleSuppressorBase }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::MoreAccurateMotionWobbleSuppressorBase> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20646,7 +20758,7 @@ mod videostab_types {
 	pub type PtrOfMoreAccurateMotionWobbleSuppressorGpu = core::Ptr<crate::videostab::MoreAccurateMotionWobbleSuppressorGpu>;
 	
 	ptr_extern! { crate::videostab::MoreAccurateMotionWobbleSuppressorGpu,
-		cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_delete, cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_getInnerPtr_const, cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_getInnerPtrMut
+		cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_delete, cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_getInnerPtr_const, cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_getInnerPtrMut, cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::MoreAccurateMotionWobbleSuppressorGpu, cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_new_const_MoreAccurateMotionWobbleSuppressorGpu }
@@ -20673,6 +20785,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfMoreAccurateMotionWobbleSuppressorGpu, core::Ptr<crate::videostab::MoreAccurateMotionWobbleSuppressorBase>, cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_to_PtrOfMoreAccurateMotionWobbleSuppressorBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::MoreAccurateMotionWobbleSuppressorBase>, PtrOfMoreAccurateMotionWobbleSuppressorGpu, cv_PtrOfMoreAccurateMotionWobbleSuppressorBase_to_PtrOfMoreAccurateMotionWobbleSuppressorGpu }
 	impl crate::videostab::WobbleSuppressorBaseTraitConst for core::Ptr<crate::videostab::MoreAccurateMotionWobbleSuppressorGpu> {
 		#[inline] fn as_raw_WobbleSuppressorBase(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -20683,6 +20796,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfMoreAccurateMotionWobbleSuppressorGpu, core::Ptr<crate::videostab::WobbleSuppressorBase>, cv_PtrOfMoreAccurateMotionWobbleSuppressorGpu_to_PtrOfWobbleSuppressorBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::WobbleSuppressorBase>, PtrOfMoreAccurateMotionWobbleSuppressorGpu, cv_PtrOfWobbleSuppressorBase_to_PtrOfMoreAccurateMotionWobbleSuppressorGpu }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::MoreAccurateMotionWobbleSuppressorGpu> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20694,7 +20808,7 @@ mod videostab_types {
 	pub type PtrOfMotionEstimatorBase = core::Ptr<crate::videostab::MotionEstimatorBase>;
 	
 	ptr_extern! { crate::videostab::MotionEstimatorBase,
-		cv_PtrOfMotionEstimatorBase_delete, cv_PtrOfMotionEstimatorBase_getInnerPtr_const, cv_PtrOfMotionEstimatorBase_getInnerPtrMut
+		cv_PtrOfMotionEstimatorBase_delete, cv_PtrOfMotionEstimatorBase_getInnerPtr_const, cv_PtrOfMotionEstimatorBase_getInnerPtrMut, cv_PtrOfMotionEstimatorBase_clone_const
 	}
 	
 	impl core::Ptr<crate::videostab::MotionEstimatorBase> {
@@ -20721,7 +20835,7 @@ mod videostab_types {
 	pub type PtrOfMotionEstimatorL1 = core::Ptr<crate::videostab::MotionEstimatorL1>;
 	
 	ptr_extern! { crate::videostab::MotionEstimatorL1,
-		cv_PtrOfMotionEstimatorL1_delete, cv_PtrOfMotionEstimatorL1_getInnerPtr_const, cv_PtrOfMotionEstimatorL1_getInnerPtrMut
+		cv_PtrOfMotionEstimatorL1_delete, cv_PtrOfMotionEstimatorL1_getInnerPtr_const, cv_PtrOfMotionEstimatorL1_getInnerPtrMut, cv_PtrOfMotionEstimatorL1_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::MotionEstimatorL1, cv_PtrOfMotionEstimatorL1_new_const_MotionEstimatorL1 }
@@ -20748,6 +20862,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfMotionEstimatorL1, core::Ptr<crate::videostab::MotionEstimatorBase>, cv_PtrOfMotionEstimatorL1_to_PtrOfMotionEstimatorBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::MotionEstimatorBase>, PtrOfMotionEstimatorL1, cv_PtrOfMotionEstimatorBase_to_PtrOfMotionEstimatorL1 }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::MotionEstimatorL1> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20759,7 +20874,7 @@ mod videostab_types {
 	pub type PtrOfMotionEstimatorRansacL2 = core::Ptr<crate::videostab::MotionEstimatorRansacL2>;
 	
 	ptr_extern! { crate::videostab::MotionEstimatorRansacL2,
-		cv_PtrOfMotionEstimatorRansacL2_delete, cv_PtrOfMotionEstimatorRansacL2_getInnerPtr_const, cv_PtrOfMotionEstimatorRansacL2_getInnerPtrMut
+		cv_PtrOfMotionEstimatorRansacL2_delete, cv_PtrOfMotionEstimatorRansacL2_getInnerPtr_const, cv_PtrOfMotionEstimatorRansacL2_getInnerPtrMut, cv_PtrOfMotionEstimatorRansacL2_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::MotionEstimatorRansacL2, cv_PtrOfMotionEstimatorRansacL2_new_const_MotionEstimatorRansacL2 }
@@ -20786,6 +20901,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfMotionEstimatorRansacL2, core::Ptr<crate::videostab::MotionEstimatorBase>, cv_PtrOfMotionEstimatorRansacL2_to_PtrOfMotionEstimatorBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::MotionEstimatorBase>, PtrOfMotionEstimatorRansacL2, cv_PtrOfMotionEstimatorBase_to_PtrOfMotionEstimatorRansacL2 }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::MotionEstimatorRansacL2> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20797,7 +20913,7 @@ mod videostab_types {
 	pub type PtrOfMotionFilterBase = core::Ptr<crate::videostab::MotionFilterBase>;
 	
 	ptr_extern! { crate::videostab::MotionFilterBase,
-		cv_PtrOfMotionFilterBase_delete, cv_PtrOfMotionFilterBase_getInnerPtr_const, cv_PtrOfMotionFilterBase_getInnerPtrMut
+		cv_PtrOfMotionFilterBase_delete, cv_PtrOfMotionFilterBase_getInnerPtr_const, cv_PtrOfMotionFilterBase_getInnerPtrMut, cv_PtrOfMotionFilterBase_clone_const
 	}
 	
 	impl core::Ptr<crate::videostab::MotionFilterBase> {
@@ -20823,6 +20939,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfMotionFilterBase, core::Ptr<crate::videostab::IMotionStabilizer>, cv_PtrOfMotionFilterBase_to_PtrOfIMotionStabilizer }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::IMotionStabilizer>, PtrOfMotionFilterBase, cv_PtrOfIMotionStabilizer_to_PtrOfMotionFilterBase }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::MotionFilterBase> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20834,7 +20951,7 @@ mod videostab_types {
 	pub type PtrOfMotionInpainter = core::Ptr<crate::videostab::MotionInpainter>;
 	
 	ptr_extern! { crate::videostab::MotionInpainter,
-		cv_PtrOfMotionInpainter_delete, cv_PtrOfMotionInpainter_getInnerPtr_const, cv_PtrOfMotionInpainter_getInnerPtrMut
+		cv_PtrOfMotionInpainter_delete, cv_PtrOfMotionInpainter_getInnerPtr_const, cv_PtrOfMotionInpainter_getInnerPtrMut, cv_PtrOfMotionInpainter_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::MotionInpainter, cv_PtrOfMotionInpainter_new_const_MotionInpainter }
@@ -20861,6 +20978,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfMotionInpainter, core::Ptr<crate::videostab::InpainterBase>, cv_PtrOfMotionInpainter_to_PtrOfInpainterBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::InpainterBase>, PtrOfMotionInpainter, cv_PtrOfInpainterBase_to_PtrOfMotionInpainter }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::MotionInpainter> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20872,7 +20990,7 @@ mod videostab_types {
 	pub type PtrOfMotionStabilizationPipeline = core::Ptr<crate::videostab::MotionStabilizationPipeline>;
 	
 	ptr_extern! { crate::videostab::MotionStabilizationPipeline,
-		cv_PtrOfMotionStabilizationPipeline_delete, cv_PtrOfMotionStabilizationPipeline_getInnerPtr_const, cv_PtrOfMotionStabilizationPipeline_getInnerPtrMut
+		cv_PtrOfMotionStabilizationPipeline_delete, cv_PtrOfMotionStabilizationPipeline_getInnerPtr_const, cv_PtrOfMotionStabilizationPipeline_getInnerPtrMut, cv_PtrOfMotionStabilizationPipeline_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::MotionStabilizationPipeline, cv_PtrOfMotionStabilizationPipeline_new_const_MotionStabilizationPipeline }
@@ -20899,6 +21017,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfMotionStabilizationPipeline, core::Ptr<crate::videostab::IMotionStabilizer>, cv_PtrOfMotionStabilizationPipeline_to_PtrOfIMotionStabilizer }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::IMotionStabilizer>, PtrOfMotionStabilizationPipeline, cv_PtrOfIMotionStabilizer_to_PtrOfMotionStabilizationPipeline }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::MotionStabilizationPipeline> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20910,7 +21029,7 @@ mod videostab_types {
 	pub type PtrOfNullDeblurer = core::Ptr<crate::videostab::NullDeblurer>;
 	
 	ptr_extern! { crate::videostab::NullDeblurer,
-		cv_PtrOfNullDeblurer_delete, cv_PtrOfNullDeblurer_getInnerPtr_const, cv_PtrOfNullDeblurer_getInnerPtrMut
+		cv_PtrOfNullDeblurer_delete, cv_PtrOfNullDeblurer_getInnerPtr_const, cv_PtrOfNullDeblurer_getInnerPtrMut, cv_PtrOfNullDeblurer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::NullDeblurer, cv_PtrOfNullDeblurer_new_const_NullDeblurer }
@@ -20937,6 +21056,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfNullDeblurer, core::Ptr<crate::videostab::DeblurerBase>, cv_PtrOfNullDeblurer_to_PtrOfDeblurerBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::DeblurerBase>, PtrOfNullDeblurer, cv_PtrOfDeblurerBase_to_PtrOfNullDeblurer }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::NullDeblurer> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20948,7 +21068,7 @@ mod videostab_types {
 	pub type PtrOfNullFrameSource = core::Ptr<crate::videostab::NullFrameSource>;
 	
 	ptr_extern! { crate::videostab::NullFrameSource,
-		cv_PtrOfNullFrameSource_delete, cv_PtrOfNullFrameSource_getInnerPtr_const, cv_PtrOfNullFrameSource_getInnerPtrMut
+		cv_PtrOfNullFrameSource_delete, cv_PtrOfNullFrameSource_getInnerPtr_const, cv_PtrOfNullFrameSource_getInnerPtrMut, cv_PtrOfNullFrameSource_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::NullFrameSource, cv_PtrOfNullFrameSource_new_const_NullFrameSource }
@@ -20975,6 +21095,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfNullFrameSource, core::Ptr<crate::videostab::IFrameSource>, cv_PtrOfNullFrameSource_to_PtrOfIFrameSource }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::IFrameSource>, PtrOfNullFrameSource, cv_PtrOfIFrameSource_to_PtrOfNullFrameSource }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::NullFrameSource> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20986,7 +21107,7 @@ mod videostab_types {
 	pub type PtrOfNullInpainter = core::Ptr<crate::videostab::NullInpainter>;
 	
 	ptr_extern! { crate::videostab::NullInpainter,
-		cv_PtrOfNullInpainter_delete, cv_PtrOfNullInpainter_getInnerPtr_const, cv_PtrOfNullInpainter_getInnerPtrMut
+		cv_PtrOfNullInpainter_delete, cv_PtrOfNullInpainter_getInnerPtr_const, cv_PtrOfNullInpainter_getInnerPtrMut, cv_PtrOfNullInpainter_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::NullInpainter, cv_PtrOfNullInpainter_new_const_NullInpainter }
@@ -21013,6 +21134,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfNullInpainter, core::Ptr<crate::videostab::InpainterBase>, cv_PtrOfNullInpainter_to_PtrOfInpainterBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::InpainterBase>, PtrOfNullInpainter, cv_PtrOfInpainterBase_to_PtrOfNullInpainter }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::NullInpainter> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21024,7 +21146,7 @@ mod videostab_types {
 	pub type PtrOfNullLog = core::Ptr<crate::videostab::NullLog>;
 	
 	ptr_extern! { crate::videostab::NullLog,
-		cv_PtrOfNullLog_delete, cv_PtrOfNullLog_getInnerPtr_const, cv_PtrOfNullLog_getInnerPtrMut
+		cv_PtrOfNullLog_delete, cv_PtrOfNullLog_getInnerPtr_const, cv_PtrOfNullLog_getInnerPtrMut, cv_PtrOfNullLog_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::NullLog, cv_PtrOfNullLog_new_const_NullLog }
@@ -21051,6 +21173,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfNullLog, core::Ptr<crate::videostab::ILog>, cv_PtrOfNullLog_to_PtrOfILog }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::ILog>, PtrOfNullLog, cv_PtrOfILog_to_PtrOfNullLog }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::NullLog> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21062,7 +21185,7 @@ mod videostab_types {
 	pub type PtrOfNullOutlierRejector = core::Ptr<crate::videostab::NullOutlierRejector>;
 	
 	ptr_extern! { crate::videostab::NullOutlierRejector,
-		cv_PtrOfNullOutlierRejector_delete, cv_PtrOfNullOutlierRejector_getInnerPtr_const, cv_PtrOfNullOutlierRejector_getInnerPtrMut
+		cv_PtrOfNullOutlierRejector_delete, cv_PtrOfNullOutlierRejector_getInnerPtr_const, cv_PtrOfNullOutlierRejector_getInnerPtrMut, cv_PtrOfNullOutlierRejector_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::NullOutlierRejector, cv_PtrOfNullOutlierRejector_new_const_NullOutlierRejector }
@@ -21089,6 +21212,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfNullOutlierRejector, core::Ptr<crate::videostab::IOutlierRejector>, cv_PtrOfNullOutlierRejector_to_PtrOfIOutlierRejector }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::IOutlierRejector>, PtrOfNullOutlierRejector, cv_PtrOfIOutlierRejector_to_PtrOfNullOutlierRejector }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::NullOutlierRejector> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21100,7 +21224,7 @@ mod videostab_types {
 	pub type PtrOfNullWobbleSuppressor = core::Ptr<crate::videostab::NullWobbleSuppressor>;
 	
 	ptr_extern! { crate::videostab::NullWobbleSuppressor,
-		cv_PtrOfNullWobbleSuppressor_delete, cv_PtrOfNullWobbleSuppressor_getInnerPtr_const, cv_PtrOfNullWobbleSuppressor_getInnerPtrMut
+		cv_PtrOfNullWobbleSuppressor_delete, cv_PtrOfNullWobbleSuppressor_getInnerPtr_const, cv_PtrOfNullWobbleSuppressor_getInnerPtrMut, cv_PtrOfNullWobbleSuppressor_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::NullWobbleSuppressor, cv_PtrOfNullWobbleSuppressor_new_const_NullWobbleSuppressor }
@@ -21127,6 +21251,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfNullWobbleSuppressor, core::Ptr<crate::videostab::WobbleSuppressorBase>, cv_PtrOfNullWobbleSuppressor_to_PtrOfWobbleSuppressorBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::WobbleSuppressorBase>, PtrOfNullWobbleSuppressor, cv_PtrOfWobbleSuppressorBase_to_PtrOfNullWobbleSuppressor }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::NullWobbleSuppressor> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21138,7 +21263,7 @@ mod videostab_types {
 	pub type PtrOfOnePassStabilizer = core::Ptr<crate::videostab::OnePassStabilizer>;
 	
 	ptr_extern! { crate::videostab::OnePassStabilizer,
-		cv_PtrOfOnePassStabilizer_delete, cv_PtrOfOnePassStabilizer_getInnerPtr_const, cv_PtrOfOnePassStabilizer_getInnerPtrMut
+		cv_PtrOfOnePassStabilizer_delete, cv_PtrOfOnePassStabilizer_getInnerPtr_const, cv_PtrOfOnePassStabilizer_getInnerPtrMut, cv_PtrOfOnePassStabilizer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::OnePassStabilizer, cv_PtrOfOnePassStabilizer_new_const_OnePassStabilizer }
@@ -21165,6 +21290,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfOnePassStabilizer, core::Ptr<crate::videostab::IFrameSource>, cv_PtrOfOnePassStabilizer_to_PtrOfIFrameSource }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::IFrameSource>, PtrOfOnePassStabilizer, cv_PtrOfIFrameSource_to_PtrOfOnePassStabilizer }
 	impl crate::videostab::StabilizerBaseTraitConst for core::Ptr<crate::videostab::OnePassStabilizer> {
 		#[inline] fn as_raw_StabilizerBase(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -21184,7 +21310,7 @@ mod videostab_types {
 	pub type PtrOfSparsePyrLkOptFlowEstimator = core::Ptr<crate::videostab::SparsePyrLkOptFlowEstimator>;
 	
 	ptr_extern! { crate::videostab::SparsePyrLkOptFlowEstimator,
-		cv_PtrOfSparsePyrLkOptFlowEstimator_delete, cv_PtrOfSparsePyrLkOptFlowEstimator_getInnerPtr_const, cv_PtrOfSparsePyrLkOptFlowEstimator_getInnerPtrMut
+		cv_PtrOfSparsePyrLkOptFlowEstimator_delete, cv_PtrOfSparsePyrLkOptFlowEstimator_getInnerPtr_const, cv_PtrOfSparsePyrLkOptFlowEstimator_getInnerPtrMut, cv_PtrOfSparsePyrLkOptFlowEstimator_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::SparsePyrLkOptFlowEstimator, cv_PtrOfSparsePyrLkOptFlowEstimator_new_const_SparsePyrLkOptFlowEstimator }
@@ -21211,6 +21337,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfSparsePyrLkOptFlowEstimator, core::Ptr<crate::videostab::ISparseOptFlowEstimator>, cv_PtrOfSparsePyrLkOptFlowEstimator_to_PtrOfISparseOptFlowEstimator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::ISparseOptFlowEstimator>, PtrOfSparsePyrLkOptFlowEstimator, cv_PtrOfISparseOptFlowEstimator_to_PtrOfSparsePyrLkOptFlowEstimator }
 	impl crate::videostab::PyrLkOptFlowEstimatorBaseTraitConst for core::Ptr<crate::videostab::SparsePyrLkOptFlowEstimator> {
 		#[inline] fn as_raw_PyrLkOptFlowEstimatorBase(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -21230,7 +21357,7 @@ mod videostab_types {
 	pub type PtrOfSparsePyrLkOptFlowEstimatorGpu = core::Ptr<crate::videostab::SparsePyrLkOptFlowEstimatorGpu>;
 	
 	ptr_extern! { crate::videostab::SparsePyrLkOptFlowEstimatorGpu,
-		cv_PtrOfSparsePyrLkOptFlowEstimatorGpu_delete, cv_PtrOfSparsePyrLkOptFlowEstimatorGpu_getInnerPtr_const, cv_PtrOfSparsePyrLkOptFlowEstimatorGpu_getInnerPtrMut
+		cv_PtrOfSparsePyrLkOptFlowEstimatorGpu_delete, cv_PtrOfSparsePyrLkOptFlowEstimatorGpu_getInnerPtr_const, cv_PtrOfSparsePyrLkOptFlowEstimatorGpu_getInnerPtrMut, cv_PtrOfSparsePyrLkOptFlowEstimatorGpu_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::SparsePyrLkOptFlowEstimatorGpu, cv_PtrOfSparsePyrLkOptFlowEstimatorGpu_new_const_SparsePyrLkOptFlowEstimatorGpu }
@@ -21257,6 +21384,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfSparsePyrLkOptFlowEstimatorGpu, core::Ptr<crate::videostab::ISparseOptFlowEstimator>, cv_PtrOfSparsePyrLkOptFlowEstimatorGpu_to_PtrOfISparseOptFlowEstimator }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::ISparseOptFlowEstimator>, PtrOfSparsePyrLkOptFlowEstimatorGpu, cv_PtrOfISparseOptFlowEstimator_to_PtrOfSparsePyrLkOptFlowEstimatorGpu }
 	impl crate::videostab::PyrLkOptFlowEstimatorBaseTraitConst for core::Ptr<crate::videostab::SparsePyrLkOptFlowEstimatorGpu> {
 		#[inline] fn as_raw_PyrLkOptFlowEstimatorBase(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -21276,7 +21404,7 @@ mod videostab_types {
 	pub type PtrOfToFileMotionWriter = core::Ptr<crate::videostab::ToFileMotionWriter>;
 	
 	ptr_extern! { crate::videostab::ToFileMotionWriter,
-		cv_PtrOfToFileMotionWriter_delete, cv_PtrOfToFileMotionWriter_getInnerPtr_const, cv_PtrOfToFileMotionWriter_getInnerPtrMut
+		cv_PtrOfToFileMotionWriter_delete, cv_PtrOfToFileMotionWriter_getInnerPtr_const, cv_PtrOfToFileMotionWriter_getInnerPtrMut, cv_PtrOfToFileMotionWriter_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::ToFileMotionWriter, cv_PtrOfToFileMotionWriter_new_const_ToFileMotionWriter }
@@ -21303,6 +21431,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfToFileMotionWriter, core::Ptr<crate::videostab::ImageMotionEstimatorBase>, cv_PtrOfToFileMotionWriter_to_PtrOfImageMotionEstimatorBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::ImageMotionEstimatorBase>, PtrOfToFileMotionWriter, cv_PtrOfImageMotionEstimatorBase_to_PtrOfToFileMotionWriter }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::ToFileMotionWriter> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21314,7 +21443,7 @@ mod videostab_types {
 	pub type PtrOfTranslationBasedLocalOutlierRejector = core::Ptr<crate::videostab::TranslationBasedLocalOutlierRejector>;
 	
 	ptr_extern! { crate::videostab::TranslationBasedLocalOutlierRejector,
-		cv_PtrOfTranslationBasedLocalOutlierRejector_delete, cv_PtrOfTranslationBasedLocalOutlierRejector_getInnerPtr_const, cv_PtrOfTranslationBasedLocalOutlierRejector_getInnerPtrMut
+		cv_PtrOfTranslationBasedLocalOutlierRejector_delete, cv_PtrOfTranslationBasedLocalOutlierRejector_getInnerPtr_const, cv_PtrOfTranslationBasedLocalOutlierRejector_getInnerPtrMut, cv_PtrOfTranslationBasedLocalOutlierRejector_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::TranslationBasedLocalOutlierRejector, cv_PtrOfTranslationBasedLocalOutlierRejector_new_const_TranslationBasedLocalOutlierRejector }
@@ -21341,6 +21470,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfTranslationBasedLocalOutlierRejector, core::Ptr<crate::videostab::IOutlierRejector>, cv_PtrOfTranslationBasedLocalOutlierRejector_to_PtrOfIOutlierRejector }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::IOutlierRejector>, PtrOfTranslationBasedLocalOutlierRejector, cv_PtrOfIOutlierRejector_to_PtrOfTranslationBasedLocalOutlierRejector }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::TranslationBasedLocalOutlierRejector> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21352,7 +21482,7 @@ mod videostab_types {
 	pub type PtrOfTwoPassStabilizer = core::Ptr<crate::videostab::TwoPassStabilizer>;
 	
 	ptr_extern! { crate::videostab::TwoPassStabilizer,
-		cv_PtrOfTwoPassStabilizer_delete, cv_PtrOfTwoPassStabilizer_getInnerPtr_const, cv_PtrOfTwoPassStabilizer_getInnerPtrMut
+		cv_PtrOfTwoPassStabilizer_delete, cv_PtrOfTwoPassStabilizer_getInnerPtr_const, cv_PtrOfTwoPassStabilizer_getInnerPtrMut, cv_PtrOfTwoPassStabilizer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::TwoPassStabilizer, cv_PtrOfTwoPassStabilizer_new_const_TwoPassStabilizer }
@@ -21379,6 +21509,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfTwoPassStabilizer, core::Ptr<crate::videostab::IFrameSource>, cv_PtrOfTwoPassStabilizer_to_PtrOfIFrameSource }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::IFrameSource>, PtrOfTwoPassStabilizer, cv_PtrOfIFrameSource_to_PtrOfTwoPassStabilizer }
 	impl crate::videostab::StabilizerBaseTraitConst for core::Ptr<crate::videostab::TwoPassStabilizer> {
 		#[inline] fn as_raw_StabilizerBase(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -21398,7 +21529,7 @@ mod videostab_types {
 	pub type PtrOfVideoFileSource = core::Ptr<crate::videostab::VideoFileSource>;
 	
 	ptr_extern! { crate::videostab::VideoFileSource,
-		cv_PtrOfVideoFileSource_delete, cv_PtrOfVideoFileSource_getInnerPtr_const, cv_PtrOfVideoFileSource_getInnerPtrMut
+		cv_PtrOfVideoFileSource_delete, cv_PtrOfVideoFileSource_getInnerPtr_const, cv_PtrOfVideoFileSource_getInnerPtrMut, cv_PtrOfVideoFileSource_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::VideoFileSource, cv_PtrOfVideoFileSource_new_const_VideoFileSource }
@@ -21425,6 +21556,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfVideoFileSource, core::Ptr<crate::videostab::IFrameSource>, cv_PtrOfVideoFileSource_to_PtrOfIFrameSource }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::IFrameSource>, PtrOfVideoFileSource, cv_PtrOfIFrameSource_to_PtrOfVideoFileSource }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::VideoFileSource> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21436,7 +21568,7 @@ mod videostab_types {
 	pub type PtrOfWeightingDeblurer = core::Ptr<crate::videostab::WeightingDeblurer>;
 	
 	ptr_extern! { crate::videostab::WeightingDeblurer,
-		cv_PtrOfWeightingDeblurer_delete, cv_PtrOfWeightingDeblurer_getInnerPtr_const, cv_PtrOfWeightingDeblurer_getInnerPtrMut
+		cv_PtrOfWeightingDeblurer_delete, cv_PtrOfWeightingDeblurer_getInnerPtr_const, cv_PtrOfWeightingDeblurer_getInnerPtrMut, cv_PtrOfWeightingDeblurer_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::videostab::WeightingDeblurer, cv_PtrOfWeightingDeblurer_new_const_WeightingDeblurer }
@@ -21463,6 +21595,7 @@ mod videostab_types {
 	
 	ptr_cast_base! { PtrOfWeightingDeblurer, core::Ptr<crate::videostab::DeblurerBase>, cv_PtrOfWeightingDeblurer_to_PtrOfDeblurerBase }
 	
+	ptr_cast_descendant! { core::Ptr<crate::videostab::DeblurerBase>, PtrOfWeightingDeblurer, cv_PtrOfDeblurerBase_to_PtrOfWeightingDeblurer }
 	impl std::fmt::Debug for core::Ptr<crate::videostab::WeightingDeblurer> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21474,7 +21607,7 @@ mod videostab_types {
 	pub type PtrOfWobbleSuppressorBase = core::Ptr<crate::videostab::WobbleSuppressorBase>;
 	
 	ptr_extern! { crate::videostab::WobbleSuppressorBase,
-		cv_PtrOfWobbleSuppressorBase_delete, cv_PtrOfWobbleSuppressorBase_getInnerPtr_const, cv_PtrOfWobbleSuppressorBase_getInnerPtrMut
+		cv_PtrOfWobbleSuppressorBase_delete, cv_PtrOfWobbleSuppressorBase_getInnerPtr_const, cv_PtrOfWobbleSuppressorBase_getInnerPtrMut, cv_PtrOfWobbleSuppressorBase_clone_const
 	}
 	
 	impl core::Ptr<crate::videostab::WobbleSuppressorBase> {
@@ -21509,7 +21642,7 @@ mod xfeatures2d_types {
 	pub type PtrOfAffineFeature2D = core::Ptr<crate::xfeatures2d::AffineFeature2D>;
 	
 	ptr_extern! { crate::xfeatures2d::AffineFeature2D,
-		cv_PtrOfAffineFeature2D_delete, cv_PtrOfAffineFeature2D_getInnerPtr_const, cv_PtrOfAffineFeature2D_getInnerPtrMut
+		cv_PtrOfAffineFeature2D_delete, cv_PtrOfAffineFeature2D_getInnerPtr_const, cv_PtrOfAffineFeature2D_getInnerPtrMut, cv_PtrOfAffineFeature2D_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::AffineFeature2D> {
@@ -21543,6 +21676,7 @@ mod xfeatures2d_types {
 	
 	ptr_cast_base! { PtrOfAffineFeature2D, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfAffineFeature2D_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfAffineFeature2D, cv_PtrOfFeature2D_to_PtrOfAffineFeature2D }
 	impl std::fmt::Debug for core::Ptr<crate::xfeatures2d::AffineFeature2D> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21554,7 +21688,7 @@ mod xfeatures2d_types {
 	pub type PtrOfBEBLID = core::Ptr<crate::xfeatures2d::BEBLID>;
 	
 	ptr_extern! { crate::xfeatures2d::BEBLID,
-		cv_PtrOfBEBLID_delete, cv_PtrOfBEBLID_getInnerPtr_const, cv_PtrOfBEBLID_getInnerPtrMut
+		cv_PtrOfBEBLID_delete, cv_PtrOfBEBLID_getInnerPtr_const, cv_PtrOfBEBLID_getInnerPtrMut, cv_PtrOfBEBLID_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::BEBLID> {
@@ -21588,6 +21722,7 @@ mod xfeatures2d_types {
 	
 	ptr_cast_base! { PtrOfBEBLID, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfBEBLID_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfBEBLID, cv_PtrOfFeature2D_to_PtrOfBEBLID }
 	impl std::fmt::Debug for core::Ptr<crate::xfeatures2d::BEBLID> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21599,7 +21734,7 @@ mod xfeatures2d_types {
 	pub type PtrOfBoostDesc = core::Ptr<crate::xfeatures2d::BoostDesc>;
 	
 	ptr_extern! { crate::xfeatures2d::BoostDesc,
-		cv_PtrOfBoostDesc_delete, cv_PtrOfBoostDesc_getInnerPtr_const, cv_PtrOfBoostDesc_getInnerPtrMut
+		cv_PtrOfBoostDesc_delete, cv_PtrOfBoostDesc_getInnerPtr_const, cv_PtrOfBoostDesc_getInnerPtrMut, cv_PtrOfBoostDesc_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::BoostDesc> {
@@ -21633,6 +21768,7 @@ mod xfeatures2d_types {
 	
 	ptr_cast_base! { PtrOfBoostDesc, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfBoostDesc_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfBoostDesc, cv_PtrOfFeature2D_to_PtrOfBoostDesc }
 	impl std::fmt::Debug for core::Ptr<crate::xfeatures2d::BoostDesc> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21644,7 +21780,7 @@ mod xfeatures2d_types {
 	pub type PtrOfBriefDescriptorExtractor = core::Ptr<crate::xfeatures2d::BriefDescriptorExtractor>;
 	
 	ptr_extern! { crate::xfeatures2d::BriefDescriptorExtractor,
-		cv_PtrOfBriefDescriptorExtractor_delete, cv_PtrOfBriefDescriptorExtractor_getInnerPtr_const, cv_PtrOfBriefDescriptorExtractor_getInnerPtrMut
+		cv_PtrOfBriefDescriptorExtractor_delete, cv_PtrOfBriefDescriptorExtractor_getInnerPtr_const, cv_PtrOfBriefDescriptorExtractor_getInnerPtrMut, cv_PtrOfBriefDescriptorExtractor_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::BriefDescriptorExtractor> {
@@ -21678,6 +21814,7 @@ mod xfeatures2d_types {
 	
 	ptr_cast_base! { PtrOfBriefDescriptorExtractor, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfBriefDescriptorExtractor_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfBriefDescriptorExtractor, cv_PtrOfFeature2D_to_PtrOfBriefDescriptorExtractor }
 	impl std::fmt::Debug for core::Ptr<crate::xfeatures2d::BriefDescriptorExtractor> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21689,7 +21826,7 @@ mod xfeatures2d_types {
 	pub type PtrOfDAISY = core::Ptr<crate::xfeatures2d::DAISY>;
 	
 	ptr_extern! { crate::xfeatures2d::DAISY,
-		cv_PtrOfDAISY_delete, cv_PtrOfDAISY_getInnerPtr_const, cv_PtrOfDAISY_getInnerPtrMut
+		cv_PtrOfDAISY_delete, cv_PtrOfDAISY_getInnerPtr_const, cv_PtrOfDAISY_getInnerPtrMut, cv_PtrOfDAISY_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::DAISY> {
@@ -21723,6 +21860,7 @@ mod xfeatures2d_types {
 	
 	ptr_cast_base! { PtrOfDAISY, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfDAISY_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfDAISY, cv_PtrOfFeature2D_to_PtrOfDAISY }
 	impl std::fmt::Debug for core::Ptr<crate::xfeatures2d::DAISY> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21734,7 +21872,7 @@ mod xfeatures2d_types {
 	pub type PtrOfFREAK = core::Ptr<crate::xfeatures2d::FREAK>;
 	
 	ptr_extern! { crate::xfeatures2d::FREAK,
-		cv_PtrOfFREAK_delete, cv_PtrOfFREAK_getInnerPtr_const, cv_PtrOfFREAK_getInnerPtrMut
+		cv_PtrOfFREAK_delete, cv_PtrOfFREAK_getInnerPtr_const, cv_PtrOfFREAK_getInnerPtrMut, cv_PtrOfFREAK_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::FREAK> {
@@ -21768,6 +21906,7 @@ mod xfeatures2d_types {
 	
 	ptr_cast_base! { PtrOfFREAK, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfFREAK_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfFREAK, cv_PtrOfFeature2D_to_PtrOfFREAK }
 	impl std::fmt::Debug for core::Ptr<crate::xfeatures2d::FREAK> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21779,7 +21918,7 @@ mod xfeatures2d_types {
 	pub type PtrOfHarrisLaplaceFeatureDetector = core::Ptr<crate::xfeatures2d::HarrisLaplaceFeatureDetector>;
 	
 	ptr_extern! { crate::xfeatures2d::HarrisLaplaceFeatureDetector,
-		cv_PtrOfHarrisLaplaceFeatureDetector_delete, cv_PtrOfHarrisLaplaceFeatureDetector_getInnerPtr_const, cv_PtrOfHarrisLaplaceFeatureDetector_getInnerPtrMut
+		cv_PtrOfHarrisLaplaceFeatureDetector_delete, cv_PtrOfHarrisLaplaceFeatureDetector_getInnerPtr_const, cv_PtrOfHarrisLaplaceFeatureDetector_getInnerPtrMut, cv_PtrOfHarrisLaplaceFeatureDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::HarrisLaplaceFeatureDetector> {
@@ -21813,6 +21952,7 @@ mod xfeatures2d_types {
 	
 	ptr_cast_base! { PtrOfHarrisLaplaceFeatureDetector, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfHarrisLaplaceFeatureDetector_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfHarrisLaplaceFeatureDetector, cv_PtrOfFeature2D_to_PtrOfHarrisLaplaceFeatureDetector }
 	impl std::fmt::Debug for core::Ptr<crate::xfeatures2d::HarrisLaplaceFeatureDetector> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21824,7 +21964,7 @@ mod xfeatures2d_types {
 	pub type PtrOfLATCH = core::Ptr<crate::xfeatures2d::LATCH>;
 	
 	ptr_extern! { crate::xfeatures2d::LATCH,
-		cv_PtrOfLATCH_delete, cv_PtrOfLATCH_getInnerPtr_const, cv_PtrOfLATCH_getInnerPtrMut
+		cv_PtrOfLATCH_delete, cv_PtrOfLATCH_getInnerPtr_const, cv_PtrOfLATCH_getInnerPtrMut, cv_PtrOfLATCH_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::LATCH> {
@@ -21858,6 +21998,7 @@ mod xfeatures2d_types {
 	
 	ptr_cast_base! { PtrOfLATCH, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfLATCH_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfLATCH, cv_PtrOfFeature2D_to_PtrOfLATCH }
 	impl std::fmt::Debug for core::Ptr<crate::xfeatures2d::LATCH> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21869,7 +22010,7 @@ mod xfeatures2d_types {
 	pub type PtrOfLUCID = core::Ptr<crate::xfeatures2d::LUCID>;
 	
 	ptr_extern! { crate::xfeatures2d::LUCID,
-		cv_PtrOfLUCID_delete, cv_PtrOfLUCID_getInnerPtr_const, cv_PtrOfLUCID_getInnerPtrMut
+		cv_PtrOfLUCID_delete, cv_PtrOfLUCID_getInnerPtr_const, cv_PtrOfLUCID_getInnerPtrMut, cv_PtrOfLUCID_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::LUCID> {
@@ -21903,6 +22044,7 @@ mod xfeatures2d_types {
 	
 	ptr_cast_base! { PtrOfLUCID, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfLUCID_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfLUCID, cv_PtrOfFeature2D_to_PtrOfLUCID }
 	impl std::fmt::Debug for core::Ptr<crate::xfeatures2d::LUCID> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21914,7 +22056,7 @@ mod xfeatures2d_types {
 	pub type PtrOfMSDDetector = core::Ptr<crate::xfeatures2d::MSDDetector>;
 	
 	ptr_extern! { crate::xfeatures2d::MSDDetector,
-		cv_PtrOfMSDDetector_delete, cv_PtrOfMSDDetector_getInnerPtr_const, cv_PtrOfMSDDetector_getInnerPtrMut
+		cv_PtrOfMSDDetector_delete, cv_PtrOfMSDDetector_getInnerPtr_const, cv_PtrOfMSDDetector_getInnerPtrMut, cv_PtrOfMSDDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::MSDDetector> {
@@ -21948,6 +22090,7 @@ mod xfeatures2d_types {
 	
 	ptr_cast_base! { PtrOfMSDDetector, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfMSDDetector_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfMSDDetector, cv_PtrOfFeature2D_to_PtrOfMSDDetector }
 	impl std::fmt::Debug for core::Ptr<crate::xfeatures2d::MSDDetector> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -21959,7 +22102,7 @@ mod xfeatures2d_types {
 	pub type PtrOfPCTSignatures = core::Ptr<crate::xfeatures2d::PCTSignatures>;
 	
 	ptr_extern! { crate::xfeatures2d::PCTSignatures,
-		cv_PtrOfPCTSignatures_delete, cv_PtrOfPCTSignatures_getInnerPtr_const, cv_PtrOfPCTSignatures_getInnerPtrMut
+		cv_PtrOfPCTSignatures_delete, cv_PtrOfPCTSignatures_getInnerPtr_const, cv_PtrOfPCTSignatures_getInnerPtrMut, cv_PtrOfPCTSignatures_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::PCTSignatures> {
@@ -21994,7 +22137,7 @@ mod xfeatures2d_types {
 	pub type PtrOfPCTSignaturesSQFD = core::Ptr<crate::xfeatures2d::PCTSignaturesSQFD>;
 	
 	ptr_extern! { crate::xfeatures2d::PCTSignaturesSQFD,
-		cv_PtrOfPCTSignaturesSQFD_delete, cv_PtrOfPCTSignaturesSQFD_getInnerPtr_const, cv_PtrOfPCTSignaturesSQFD_getInnerPtrMut
+		cv_PtrOfPCTSignaturesSQFD_delete, cv_PtrOfPCTSignaturesSQFD_getInnerPtr_const, cv_PtrOfPCTSignaturesSQFD_getInnerPtrMut, cv_PtrOfPCTSignaturesSQFD_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::PCTSignaturesSQFD> {
@@ -22029,7 +22172,7 @@ mod xfeatures2d_types {
 	pub type PtrOfSURF = core::Ptr<crate::xfeatures2d::SURF>;
 	
 	ptr_extern! { crate::xfeatures2d::SURF,
-		cv_PtrOfSURF_delete, cv_PtrOfSURF_getInnerPtr_const, cv_PtrOfSURF_getInnerPtrMut
+		cv_PtrOfSURF_delete, cv_PtrOfSURF_getInnerPtr_const, cv_PtrOfSURF_getInnerPtrMut, cv_PtrOfSURF_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::SURF> {
@@ -22063,6 +22206,7 @@ mod xfeatures2d_types {
 	
 	ptr_cast_base! { PtrOfSURF, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfSURF_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfSURF, cv_PtrOfFeature2D_to_PtrOfSURF }
 	impl std::fmt::Debug for core::Ptr<crate::xfeatures2d::SURF> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -22074,7 +22218,7 @@ mod xfeatures2d_types {
 	pub type PtrOfSURF_CUDA = core::Ptr<crate::xfeatures2d::SURF_CUDA>;
 	
 	ptr_extern! { crate::xfeatures2d::SURF_CUDA,
-		cv_PtrOfSURF_CUDA_delete, cv_PtrOfSURF_CUDA_getInnerPtr_const, cv_PtrOfSURF_CUDA_getInnerPtrMut
+		cv_PtrOfSURF_CUDA_delete, cv_PtrOfSURF_CUDA_getInnerPtr_const, cv_PtrOfSURF_CUDA_getInnerPtrMut, cv_PtrOfSURF_CUDA_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::xfeatures2d::SURF_CUDA, cv_PtrOfSURF_CUDA_new_const_SURF_CUDA }
@@ -22114,7 +22258,7 @@ mod xfeatures2d_types {
 	pub type PtrOfStarDetector = core::Ptr<crate::xfeatures2d::StarDetector>;
 	
 	ptr_extern! { crate::xfeatures2d::StarDetector,
-		cv_PtrOfStarDetector_delete, cv_PtrOfStarDetector_getInnerPtr_const, cv_PtrOfStarDetector_getInnerPtrMut
+		cv_PtrOfStarDetector_delete, cv_PtrOfStarDetector_getInnerPtr_const, cv_PtrOfStarDetector_getInnerPtrMut, cv_PtrOfStarDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::StarDetector> {
@@ -22148,6 +22292,7 @@ mod xfeatures2d_types {
 	
 	ptr_cast_base! { PtrOfStarDetector, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfStarDetector_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfStarDetector, cv_PtrOfFeature2D_to_PtrOfStarDetector }
 	impl std::fmt::Debug for core::Ptr<crate::xfeatures2d::StarDetector> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -22159,7 +22304,7 @@ mod xfeatures2d_types {
 	pub type PtrOfTBMR = core::Ptr<crate::xfeatures2d::TBMR>;
 	
 	ptr_extern! { crate::xfeatures2d::TBMR,
-		cv_PtrOfTBMR_delete, cv_PtrOfTBMR_getInnerPtr_const, cv_PtrOfTBMR_getInnerPtrMut
+		cv_PtrOfTBMR_delete, cv_PtrOfTBMR_getInnerPtr_const, cv_PtrOfTBMR_getInnerPtrMut, cv_PtrOfTBMR_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::TBMR> {
@@ -22193,6 +22338,7 @@ mod xfeatures2d_types {
 	
 	ptr_cast_base! { PtrOfTBMR, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfTBMR_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfTBMR, cv_PtrOfFeature2D_to_PtrOfTBMR }
 	impl crate::xfeatures2d::AffineFeature2DTraitConst for core::Ptr<crate::xfeatures2d::TBMR> {
 		#[inline] fn as_raw_AffineFeature2D(&self) -> *const c_void { self.inner_as_raw() }
 	}
@@ -22212,7 +22358,7 @@ mod xfeatures2d_types {
 	pub type PtrOfTEBLID = core::Ptr<crate::xfeatures2d::TEBLID>;
 	
 	ptr_extern! { crate::xfeatures2d::TEBLID,
-		cv_PtrOfTEBLID_delete, cv_PtrOfTEBLID_getInnerPtr_const, cv_PtrOfTEBLID_getInnerPtrMut
+		cv_PtrOfTEBLID_delete, cv_PtrOfTEBLID_getInnerPtr_const, cv_PtrOfTEBLID_getInnerPtrMut, cv_PtrOfTEBLID_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::xfeatures2d::TEBLID, cv_PtrOfTEBLID_new_const_TEBLID }
@@ -22247,6 +22393,7 @@ mod xfeatures2d_types {
 	
 	ptr_cast_base! { PtrOfTEBLID, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfTEBLID_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfTEBLID, cv_PtrOfFeature2D_to_PtrOfTEBLID }
 	impl std::fmt::Debug for core::Ptr<crate::xfeatures2d::TEBLID> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -22258,7 +22405,7 @@ mod xfeatures2d_types {
 	pub type PtrOfVGG = core::Ptr<crate::xfeatures2d::VGG>;
 	
 	ptr_extern! { crate::xfeatures2d::VGG,
-		cv_PtrOfVGG_delete, cv_PtrOfVGG_getInnerPtr_const, cv_PtrOfVGG_getInnerPtrMut
+		cv_PtrOfVGG_delete, cv_PtrOfVGG_getInnerPtr_const, cv_PtrOfVGG_getInnerPtrMut, cv_PtrOfVGG_clone_const
 	}
 	
 	impl core::Ptr<crate::xfeatures2d::VGG> {
@@ -22292,6 +22439,7 @@ mod xfeatures2d_types {
 	
 	ptr_cast_base! { PtrOfVGG, core::Ptr<crate::features2d::Feature2D>, cv_PtrOfVGG_to_PtrOfFeature2D }
 	
+	ptr_cast_descendant! { core::Ptr<crate::features2d::Feature2D>, PtrOfVGG, cv_PtrOfFeature2D_to_PtrOfVGG }
 	impl std::fmt::Debug for core::Ptr<crate::xfeatures2d::VGG> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -22329,7 +22477,7 @@ mod ximgproc_types {
 	pub type PtrOfAdaptiveManifoldFilter = core::Ptr<crate::ximgproc::AdaptiveManifoldFilter>;
 	
 	ptr_extern! { crate::ximgproc::AdaptiveManifoldFilter,
-		cv_PtrOfAdaptiveManifoldFilter_delete, cv_PtrOfAdaptiveManifoldFilter_getInnerPtr_const, cv_PtrOfAdaptiveManifoldFilter_getInnerPtrMut
+		cv_PtrOfAdaptiveManifoldFilter_delete, cv_PtrOfAdaptiveManifoldFilter_getInnerPtr_const, cv_PtrOfAdaptiveManifoldFilter_getInnerPtrMut, cv_PtrOfAdaptiveManifoldFilter_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::AdaptiveManifoldFilter> {
@@ -22364,7 +22512,7 @@ mod ximgproc_types {
 	pub type PtrOfContourFitting = core::Ptr<crate::ximgproc::ContourFitting>;
 	
 	ptr_extern! { crate::ximgproc::ContourFitting,
-		cv_PtrOfContourFitting_delete, cv_PtrOfContourFitting_getInnerPtr_const, cv_PtrOfContourFitting_getInnerPtrMut
+		cv_PtrOfContourFitting_delete, cv_PtrOfContourFitting_getInnerPtr_const, cv_PtrOfContourFitting_getInnerPtrMut, cv_PtrOfContourFitting_clone_const
 	}
 	
 	ptr_extern_ctor! { crate::ximgproc::ContourFitting, cv_PtrOfContourFitting_new_const_ContourFitting }
@@ -22400,7 +22548,7 @@ mod ximgproc_types {
 	pub type PtrOfDTFilter = core::Ptr<crate::ximgproc::DTFilter>;
 	
 	ptr_extern! { crate::ximgproc::DTFilter,
-		cv_PtrOfDTFilter_delete, cv_PtrOfDTFilter_getInnerPtr_const, cv_PtrOfDTFilter_getInnerPtrMut
+		cv_PtrOfDTFilter_delete, cv_PtrOfDTFilter_getInnerPtr_const, cv_PtrOfDTFilter_getInnerPtrMut, cv_PtrOfDTFilter_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::DTFilter> {
@@ -22435,7 +22583,7 @@ mod ximgproc_types {
 	pub type PtrOfDisparityWLSFilter = core::Ptr<crate::ximgproc::DisparityWLSFilter>;
 	
 	ptr_extern! { crate::ximgproc::DisparityWLSFilter,
-		cv_PtrOfDisparityWLSFilter_delete, cv_PtrOfDisparityWLSFilter_getInnerPtr_const, cv_PtrOfDisparityWLSFilter_getInnerPtrMut
+		cv_PtrOfDisparityWLSFilter_delete, cv_PtrOfDisparityWLSFilter_getInnerPtr_const, cv_PtrOfDisparityWLSFilter_getInnerPtrMut, cv_PtrOfDisparityWLSFilter_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::DisparityWLSFilter> {
@@ -22478,7 +22626,7 @@ mod ximgproc_types {
 	pub type PtrOfEdgeAwareInterpolator = core::Ptr<crate::ximgproc::EdgeAwareInterpolator>;
 	
 	ptr_extern! { crate::ximgproc::EdgeAwareInterpolator,
-		cv_PtrOfEdgeAwareInterpolator_delete, cv_PtrOfEdgeAwareInterpolator_getInnerPtr_const, cv_PtrOfEdgeAwareInterpolator_getInnerPtrMut
+		cv_PtrOfEdgeAwareInterpolator_delete, cv_PtrOfEdgeAwareInterpolator_getInnerPtr_const, cv_PtrOfEdgeAwareInterpolator_getInnerPtrMut, cv_PtrOfEdgeAwareInterpolator_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::EdgeAwareInterpolator> {
@@ -22521,7 +22669,7 @@ mod ximgproc_types {
 	pub type PtrOfEdgeBoxes = core::Ptr<crate::ximgproc::EdgeBoxes>;
 	
 	ptr_extern! { crate::ximgproc::EdgeBoxes,
-		cv_PtrOfEdgeBoxes_delete, cv_PtrOfEdgeBoxes_getInnerPtr_const, cv_PtrOfEdgeBoxes_getInnerPtrMut
+		cv_PtrOfEdgeBoxes_delete, cv_PtrOfEdgeBoxes_getInnerPtr_const, cv_PtrOfEdgeBoxes_getInnerPtrMut, cv_PtrOfEdgeBoxes_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::EdgeBoxes> {
@@ -22556,7 +22704,7 @@ mod ximgproc_types {
 	pub type PtrOfEdgeDrawing = core::Ptr<crate::ximgproc::EdgeDrawing>;
 	
 	ptr_extern! { crate::ximgproc::EdgeDrawing,
-		cv_PtrOfEdgeDrawing_delete, cv_PtrOfEdgeDrawing_getInnerPtr_const, cv_PtrOfEdgeDrawing_getInnerPtrMut
+		cv_PtrOfEdgeDrawing_delete, cv_PtrOfEdgeDrawing_getInnerPtr_const, cv_PtrOfEdgeDrawing_getInnerPtrMut, cv_PtrOfEdgeDrawing_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::EdgeDrawing> {
@@ -22592,7 +22740,7 @@ mod ximgproc_types {
 	pub type PtrOfFastBilateralSolverFilter = core::Ptr<crate::ximgproc::FastBilateralSolverFilter>;
 	
 	ptr_extern! { crate::ximgproc::FastBilateralSolverFilter,
-		cv_PtrOfFastBilateralSolverFilter_delete, cv_PtrOfFastBilateralSolverFilter_getInnerPtr_const, cv_PtrOfFastBilateralSolverFilter_getInnerPtrMut
+		cv_PtrOfFastBilateralSolverFilter_delete, cv_PtrOfFastBilateralSolverFilter_getInnerPtr_const, cv_PtrOfFastBilateralSolverFilter_getInnerPtrMut, cv_PtrOfFastBilateralSolverFilter_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::FastBilateralSolverFilter> {
@@ -22627,7 +22775,7 @@ mod ximgproc_types {
 	pub type PtrOfFastGlobalSmootherFilter = core::Ptr<crate::ximgproc::FastGlobalSmootherFilter>;
 	
 	ptr_extern! { crate::ximgproc::FastGlobalSmootherFilter,
-		cv_PtrOfFastGlobalSmootherFilter_delete, cv_PtrOfFastGlobalSmootherFilter_getInnerPtr_const, cv_PtrOfFastGlobalSmootherFilter_getInnerPtrMut
+		cv_PtrOfFastGlobalSmootherFilter_delete, cv_PtrOfFastGlobalSmootherFilter_getInnerPtr_const, cv_PtrOfFastGlobalSmootherFilter_getInnerPtrMut, cv_PtrOfFastGlobalSmootherFilter_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::FastGlobalSmootherFilter> {
@@ -22662,7 +22810,7 @@ mod ximgproc_types {
 	pub type PtrOfFastLineDetector = core::Ptr<crate::ximgproc::FastLineDetector>;
 	
 	ptr_extern! { crate::ximgproc::FastLineDetector,
-		cv_PtrOfFastLineDetector_delete, cv_PtrOfFastLineDetector_getInnerPtr_const, cv_PtrOfFastLineDetector_getInnerPtrMut
+		cv_PtrOfFastLineDetector_delete, cv_PtrOfFastLineDetector_getInnerPtr_const, cv_PtrOfFastLineDetector_getInnerPtrMut, cv_PtrOfFastLineDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::FastLineDetector> {
@@ -22697,7 +22845,7 @@ mod ximgproc_types {
 	pub type PtrOfGraphSegmentation = core::Ptr<crate::ximgproc::GraphSegmentation>;
 	
 	ptr_extern! { crate::ximgproc::GraphSegmentation,
-		cv_PtrOfGraphSegmentation_delete, cv_PtrOfGraphSegmentation_getInnerPtr_const, cv_PtrOfGraphSegmentation_getInnerPtrMut
+		cv_PtrOfGraphSegmentation_delete, cv_PtrOfGraphSegmentation_getInnerPtr_const, cv_PtrOfGraphSegmentation_getInnerPtrMut, cv_PtrOfGraphSegmentation_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::GraphSegmentation> {
@@ -22732,7 +22880,7 @@ mod ximgproc_types {
 	pub type PtrOfGuidedFilter = core::Ptr<crate::ximgproc::GuidedFilter>;
 	
 	ptr_extern! { crate::ximgproc::GuidedFilter,
-		cv_PtrOfGuidedFilter_delete, cv_PtrOfGuidedFilter_getInnerPtr_const, cv_PtrOfGuidedFilter_getInnerPtrMut
+		cv_PtrOfGuidedFilter_delete, cv_PtrOfGuidedFilter_getInnerPtr_const, cv_PtrOfGuidedFilter_getInnerPtrMut, cv_PtrOfGuidedFilter_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::GuidedFilter> {
@@ -22767,7 +22915,7 @@ mod ximgproc_types {
 	pub type PtrOfRFFeatureGetter = core::Ptr<crate::ximgproc::RFFeatureGetter>;
 	
 	ptr_extern! { crate::ximgproc::RFFeatureGetter,
-		cv_PtrOfRFFeatureGetter_delete, cv_PtrOfRFFeatureGetter_getInnerPtr_const, cv_PtrOfRFFeatureGetter_getInnerPtrMut
+		cv_PtrOfRFFeatureGetter_delete, cv_PtrOfRFFeatureGetter_getInnerPtr_const, cv_PtrOfRFFeatureGetter_getInnerPtrMut, cv_PtrOfRFFeatureGetter_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::RFFeatureGetter> {
@@ -22802,7 +22950,7 @@ mod ximgproc_types {
 	pub type PtrOfRICInterpolator = core::Ptr<crate::ximgproc::RICInterpolator>;
 	
 	ptr_extern! { crate::ximgproc::RICInterpolator,
-		cv_PtrOfRICInterpolator_delete, cv_PtrOfRICInterpolator_getInnerPtr_const, cv_PtrOfRICInterpolator_getInnerPtrMut
+		cv_PtrOfRICInterpolator_delete, cv_PtrOfRICInterpolator_getInnerPtr_const, cv_PtrOfRICInterpolator_getInnerPtrMut, cv_PtrOfRICInterpolator_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::RICInterpolator> {
@@ -22845,7 +22993,7 @@ mod ximgproc_types {
 	pub type PtrOfRidgeDetectionFilter = core::Ptr<crate::ximgproc::RidgeDetectionFilter>;
 	
 	ptr_extern! { crate::ximgproc::RidgeDetectionFilter,
-		cv_PtrOfRidgeDetectionFilter_delete, cv_PtrOfRidgeDetectionFilter_getInnerPtr_const, cv_PtrOfRidgeDetectionFilter_getInnerPtrMut
+		cv_PtrOfRidgeDetectionFilter_delete, cv_PtrOfRidgeDetectionFilter_getInnerPtr_const, cv_PtrOfRidgeDetectionFilter_getInnerPtrMut, cv_PtrOfRidgeDetectionFilter_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::RidgeDetectionFilter> {
@@ -22880,7 +23028,7 @@ mod ximgproc_types {
 	pub type PtrOfScanSegment = core::Ptr<crate::ximgproc::ScanSegment>;
 	
 	ptr_extern! { crate::ximgproc::ScanSegment,
-		cv_PtrOfScanSegment_delete, cv_PtrOfScanSegment_getInnerPtr_const, cv_PtrOfScanSegment_getInnerPtrMut
+		cv_PtrOfScanSegment_delete, cv_PtrOfScanSegment_getInnerPtr_const, cv_PtrOfScanSegment_getInnerPtrMut, cv_PtrOfScanSegment_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::ScanSegment> {
@@ -22915,7 +23063,7 @@ mod ximgproc_types {
 	pub type PtrOfSelectiveSearchSegmentation = core::Ptr<crate::ximgproc::SelectiveSearchSegmentation>;
 	
 	ptr_extern! { crate::ximgproc::SelectiveSearchSegmentation,
-		cv_PtrOfSelectiveSearchSegmentation_delete, cv_PtrOfSelectiveSearchSegmentation_getInnerPtr_const, cv_PtrOfSelectiveSearchSegmentation_getInnerPtrMut
+		cv_PtrOfSelectiveSearchSegmentation_delete, cv_PtrOfSelectiveSearchSegmentation_getInnerPtr_const, cv_PtrOfSelectiveSearchSegmentation_getInnerPtrMut, cv_PtrOfSelectiveSearchSegmentation_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::SelectiveSearchSegmentation> {
@@ -22950,7 +23098,7 @@ mod ximgproc_types {
 	pub type PtrOfSelectiveSearchSegmentationStrategy = core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategy>;
 	
 	ptr_extern! { crate::ximgproc::SelectiveSearchSegmentationStrategy,
-		cv_PtrOfSelectiveSearchSegmentationStrategy_delete, cv_PtrOfSelectiveSearchSegmentationStrategy_getInnerPtr_const, cv_PtrOfSelectiveSearchSegmentationStrategy_getInnerPtrMut
+		cv_PtrOfSelectiveSearchSegmentationStrategy_delete, cv_PtrOfSelectiveSearchSegmentationStrategy_getInnerPtr_const, cv_PtrOfSelectiveSearchSegmentationStrategy_getInnerPtrMut, cv_PtrOfSelectiveSearchSegmentationStrategy_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategy> {
@@ -22985,7 +23133,7 @@ mod ximgproc_types {
 	pub type PtrOfSelectiveSearchSegmentationStrategyColor = core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategyColor>;
 	
 	ptr_extern! { crate::ximgproc::SelectiveSearchSegmentationStrategyColor,
-		cv_PtrOfSelectiveSearchSegmentationStrategyColor_delete, cv_PtrOfSelectiveSearchSegmentationStrategyColor_getInnerPtr_const, cv_PtrOfSelectiveSearchSegmentationStrategyColor_getInnerPtrMut
+		cv_PtrOfSelectiveSearchSegmentationStrategyColor_delete, cv_PtrOfSelectiveSearchSegmentationStrategyColor_getInnerPtr_const, cv_PtrOfSelectiveSearchSegmentationStrategyColor_getInnerPtrMut, cv_PtrOfSelectiveSearchSegmentationStrategyColor_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategyColor> {
@@ -23019,6 +23167,7 @@ mod ximgproc_types {
 	
 	ptr_cast_base! { PtrOfSelectiveSearchSegmentationStrategyColor, core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategy>, cv_PtrOfSelectiveSearchSegmentationStrategyColor_to_PtrOfSelectiveSearchSegmentationStrategy }
 	
+	ptr_cast_descendant! { core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategy>, PtrOfSelectiveSearchSegmentationStrategyColor, cv_PtrOfSelectiveSearchSegmentationStrategy_to_PtrOfSelectiveSearchSegmentationStrategyColor }
 	impl std::fmt::Debug for core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategyColor> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -23030,7 +23179,7 @@ mod ximgproc_types {
 	pub type PtrOfSelectiveSearchSegmentationStrategyFill = core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategyFill>;
 	
 	ptr_extern! { crate::ximgproc::SelectiveSearchSegmentationStrategyFill,
-		cv_PtrOfSelectiveSearchSegmentationStrategyFill_delete, cv_PtrOfSelectiveSearchSegmentationStrategyFill_getInnerPtr_const, cv_PtrOfSelectiveSearchSegmentationStrategyFill_getInnerPtrMut
+		cv_PtrOfSelectiveSearchSegmentationStrategyFill_delete, cv_PtrOfSelectiveSearchSegmentationStrategyFill_getInnerPtr_const, cv_PtrOfSelectiveSearchSegmentationStrategyFill_getInnerPtrMut, cv_PtrOfSelectiveSearchSegmentationStrategyFill_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategyFill> {
@@ -23064,6 +23213,7 @@ mod ximgproc_types {
 	
 	ptr_cast_base! { PtrOfSelectiveSearchSegmentationStrategyFill, core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategy>, cv_PtrOfSelectiveSearchSegmentationStrategyFill_to_PtrOfSelectiveSearchSegmentationStrategy }
 	
+	ptr_cast_descendant! { core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategy>, PtrOfSelectiveSearchSegmentationStrategyFill, cv_PtrOfSelectiveSearchSegmentationStrategy_to_PtrOfSelectiveSearchSegmentationStrategyFill }
 	impl std::fmt::Debug for core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategyFill> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -23075,7 +23225,7 @@ mod ximgproc_types {
 	pub type PtrOfSelectiveSearchSegmentationStrategyMultiple = core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategyMultiple>;
 	
 	ptr_extern! { crate::ximgproc::SelectiveSearchSegmentationStrategyMultiple,
-		cv_PtrOfSelectiveSearchSegmentationStrategyMultiple_delete, cv_PtrOfSelectiveSearchSegmentationStrategyMultiple_getInnerPtr_const, cv_PtrOfSelectiveSearchSegmentationStrategyMultiple_getInnerPtrMut
+		cv_PtrOfSelectiveSearchSegmentationStrategyMultiple_delete, cv_PtrOfSelectiveSearchSegmentationStrategyMultiple_getInnerPtr_const, cv_PtrOfSelectiveSearchSegmentationStrategyMultiple_getInnerPtrMut, cv_PtrOfSelectiveSearchSegmentationStrategyMultiple_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategyMultiple> {
@@ -23109,6 +23259,7 @@ mod ximgproc_types {
 	
 	ptr_cast_base! { PtrOfSelectiveSearchSegmentationStrategyMultiple, core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategy>, cv_PtrOfSelectiveSearchSegmentationStrategyMultiple_to_PtrOfSelectiveSearchSegmentationStrategy }
 	
+	ptr_cast_descendant! { core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategy>, PtrOfSelectiveSearchSegmentationStrategyMultiple, cv_PtrOfSelectiveSearchSegmentationStrategy_to_PtrOfSelectiveSearchSegmentationStrategyMultiple }
 	impl std::fmt::Debug for core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategyMultiple> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -23120,7 +23271,7 @@ mod ximgproc_types {
 	pub type PtrOfSelectiveSearchSegmentationStrategySize = core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategySize>;
 	
 	ptr_extern! { crate::ximgproc::SelectiveSearchSegmentationStrategySize,
-		cv_PtrOfSelectiveSearchSegmentationStrategySize_delete, cv_PtrOfSelectiveSearchSegmentationStrategySize_getInnerPtr_const, cv_PtrOfSelectiveSearchSegmentationStrategySize_getInnerPtrMut
+		cv_PtrOfSelectiveSearchSegmentationStrategySize_delete, cv_PtrOfSelectiveSearchSegmentationStrategySize_getInnerPtr_const, cv_PtrOfSelectiveSearchSegmentationStrategySize_getInnerPtrMut, cv_PtrOfSelectiveSearchSegmentationStrategySize_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategySize> {
@@ -23154,6 +23305,7 @@ mod ximgproc_types {
 	
 	ptr_cast_base! { PtrOfSelectiveSearchSegmentationStrategySize, core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategy>, cv_PtrOfSelectiveSearchSegmentationStrategySize_to_PtrOfSelectiveSearchSegmentationStrategy }
 	
+	ptr_cast_descendant! { core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategy>, PtrOfSelectiveSearchSegmentationStrategySize, cv_PtrOfSelectiveSearchSegmentationStrategy_to_PtrOfSelectiveSearchSegmentationStrategySize }
 	impl std::fmt::Debug for core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategySize> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -23165,7 +23317,7 @@ mod ximgproc_types {
 	pub type PtrOfSelectiveSearchSegmentationStrategyTexture = core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategyTexture>;
 	
 	ptr_extern! { crate::ximgproc::SelectiveSearchSegmentationStrategyTexture,
-		cv_PtrOfSelectiveSearchSegmentationStrategyTexture_delete, cv_PtrOfSelectiveSearchSegmentationStrategyTexture_getInnerPtr_const, cv_PtrOfSelectiveSearchSegmentationStrategyTexture_getInnerPtrMut
+		cv_PtrOfSelectiveSearchSegmentationStrategyTexture_delete, cv_PtrOfSelectiveSearchSegmentationStrategyTexture_getInnerPtr_const, cv_PtrOfSelectiveSearchSegmentationStrategyTexture_getInnerPtrMut, cv_PtrOfSelectiveSearchSegmentationStrategyTexture_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategyTexture> {
@@ -23199,6 +23351,7 @@ mod ximgproc_types {
 	
 	ptr_cast_base! { PtrOfSelectiveSearchSegmentationStrategyTexture, core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategy>, cv_PtrOfSelectiveSearchSegmentationStrategyTexture_to_PtrOfSelectiveSearchSegmentationStrategy }
 	
+	ptr_cast_descendant! { core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategy>, PtrOfSelectiveSearchSegmentationStrategyTexture, cv_PtrOfSelectiveSearchSegmentationStrategy_to_PtrOfSelectiveSearchSegmentationStrategyTexture }
 	impl std::fmt::Debug for core::Ptr<crate::ximgproc::SelectiveSearchSegmentationStrategyTexture> {
 		#[inline]
 		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -23210,7 +23363,7 @@ mod ximgproc_types {
 	pub type PtrOfStructuredEdgeDetection = core::Ptr<crate::ximgproc::StructuredEdgeDetection>;
 	
 	ptr_extern! { crate::ximgproc::StructuredEdgeDetection,
-		cv_PtrOfStructuredEdgeDetection_delete, cv_PtrOfStructuredEdgeDetection_getInnerPtr_const, cv_PtrOfStructuredEdgeDetection_getInnerPtrMut
+		cv_PtrOfStructuredEdgeDetection_delete, cv_PtrOfStructuredEdgeDetection_getInnerPtr_const, cv_PtrOfStructuredEdgeDetection_getInnerPtrMut, cv_PtrOfStructuredEdgeDetection_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::StructuredEdgeDetection> {
@@ -23245,7 +23398,7 @@ mod ximgproc_types {
 	pub type PtrOfSuperpixelLSC = core::Ptr<crate::ximgproc::SuperpixelLSC>;
 	
 	ptr_extern! { crate::ximgproc::SuperpixelLSC,
-		cv_PtrOfSuperpixelLSC_delete, cv_PtrOfSuperpixelLSC_getInnerPtr_const, cv_PtrOfSuperpixelLSC_getInnerPtrMut
+		cv_PtrOfSuperpixelLSC_delete, cv_PtrOfSuperpixelLSC_getInnerPtr_const, cv_PtrOfSuperpixelLSC_getInnerPtrMut, cv_PtrOfSuperpixelLSC_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::SuperpixelLSC> {
@@ -23280,7 +23433,7 @@ mod ximgproc_types {
 	pub type PtrOfSuperpixelSEEDS = core::Ptr<crate::ximgproc::SuperpixelSEEDS>;
 	
 	ptr_extern! { crate::ximgproc::SuperpixelSEEDS,
-		cv_PtrOfSuperpixelSEEDS_delete, cv_PtrOfSuperpixelSEEDS_getInnerPtr_const, cv_PtrOfSuperpixelSEEDS_getInnerPtrMut
+		cv_PtrOfSuperpixelSEEDS_delete, cv_PtrOfSuperpixelSEEDS_getInnerPtr_const, cv_PtrOfSuperpixelSEEDS_getInnerPtrMut, cv_PtrOfSuperpixelSEEDS_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::SuperpixelSEEDS> {
@@ -23315,7 +23468,7 @@ mod ximgproc_types {
 	pub type PtrOfSuperpixelSLIC = core::Ptr<crate::ximgproc::SuperpixelSLIC>;
 	
 	ptr_extern! { crate::ximgproc::SuperpixelSLIC,
-		cv_PtrOfSuperpixelSLIC_delete, cv_PtrOfSuperpixelSLIC_getInnerPtr_const, cv_PtrOfSuperpixelSLIC_getInnerPtrMut
+		cv_PtrOfSuperpixelSLIC_delete, cv_PtrOfSuperpixelSLIC_getInnerPtr_const, cv_PtrOfSuperpixelSLIC_getInnerPtrMut, cv_PtrOfSuperpixelSLIC_clone_const
 	}
 	
 	impl core::Ptr<crate::ximgproc::SuperpixelSLIC> {
@@ -23379,7 +23532,7 @@ mod xobjdetect_types {
 	pub type PtrOfWBDetector = core::Ptr<crate::xobjdetect::WBDetector>;
 	
 	ptr_extern! { crate::xobjdetect::WBDetector,
-		cv_PtrOfWBDetector_delete, cv_PtrOfWBDetector_getInnerPtr_const, cv_PtrOfWBDetector_getInnerPtrMut
+		cv_PtrOfWBDetector_delete, cv_PtrOfWBDetector_getInnerPtr_const, cv_PtrOfWBDetector_getInnerPtrMut, cv_PtrOfWBDetector_clone_const
 	}
 	
 	impl core::Ptr<crate::xobjdetect::WBDetector> {
@@ -23414,7 +23567,7 @@ mod xphoto_types {
 	pub type PtrOfGrayworldWB = core::Ptr<crate::xphoto::GrayworldWB>;
 	
 	ptr_extern! { crate::xphoto::GrayworldWB,
-		cv_PtrOfGrayworldWB_delete, cv_PtrOfGrayworldWB_getInnerPtr_const, cv_PtrOfGrayworldWB_getInnerPtrMut
+		cv_PtrOfGrayworldWB_delete, cv_PtrOfGrayworldWB_getInnerPtr_const, cv_PtrOfGrayworldWB_getInnerPtrMut, cv_PtrOfGrayworldWB_clone_const
 	}
 	
 	impl core::Ptr<crate::xphoto::GrayworldWB> {
@@ -23457,7 +23610,7 @@ mod xphoto_types {
 	pub type PtrOfLearningBasedWB = core::Ptr<crate::xphoto::LearningBasedWB>;
 	
 	ptr_extern! { crate::xphoto::LearningBasedWB,
-		cv_PtrOfLearningBasedWB_delete, cv_PtrOfLearningBasedWB_getInnerPtr_const, cv_PtrOfLearningBasedWB_getInnerPtrMut
+		cv_PtrOfLearningBasedWB_delete, cv_PtrOfLearningBasedWB_getInnerPtr_const, cv_PtrOfLearningBasedWB_getInnerPtrMut, cv_PtrOfLearningBasedWB_clone_const
 	}
 	
 	impl core::Ptr<crate::xphoto::LearningBasedWB> {
@@ -23500,7 +23653,7 @@ mod xphoto_types {
 	pub type PtrOfSimpleWB = core::Ptr<crate::xphoto::SimpleWB>;
 	
 	ptr_extern! { crate::xphoto::SimpleWB,
-		cv_PtrOfSimpleWB_delete, cv_PtrOfSimpleWB_getInnerPtr_const, cv_PtrOfSimpleWB_getInnerPtrMut
+		cv_PtrOfSimpleWB_delete, cv_PtrOfSimpleWB_getInnerPtr_const, cv_PtrOfSimpleWB_getInnerPtrMut, cv_PtrOfSimpleWB_clone_const
 	}
 	
 	impl core::Ptr<crate::xphoto::SimpleWB> {
@@ -23543,7 +23696,7 @@ mod xphoto_types {
 	pub type PtrOfTonemapDurand = core::Ptr<crate::xphoto::TonemapDurand>;
 	
 	ptr_extern! { crate::xphoto::TonemapDurand,
-		cv_PtrOfTonemapDurand_delete, cv_PtrOfTonemapDurand_getInnerPtr_const, cv_PtrOfTonemapDurand_getInnerPtrMut
+		cv_PtrOfTonemapDurand_delete, cv_PtrOfTonemapDurand_getInnerPtr_const, cv_PtrOfTonemapDurand_getInnerPtrMut, cv_PtrOfTonemapDurand_clone_const
 	}
 	
 	impl core::Ptr<crate::xphoto::TonemapDurand> {
@@ -77,6 +77,7 @@ pub mod video {
 		unsafe { sys::cv_buildOpticalFlowPyramid_const__InputArrayR_const__OutputArrayR_Size_int_bool_int_int_bool(img.as_raw__InputArray(), pyramid.as_raw__OutputArray(), win_size.opencv_as_extern(), max_level, with_derivatives, pyr_border, deriv_border, try_reuse_input_image, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(pyramid);
 		Ok(ret)
 	}
 	
@@ -198,6 +199,8 @@ pub mod video {
 		unsafe { sys::cv_calcOpticalFlowPyrLK_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_Size_int_TermCriteria_int_double(prev_img.as_raw__InputArray(), next_img.as_raw__InputArray(), prev_pts.as_raw__InputArray(), next_pts.as_raw__InputOutputArray(), status.as_raw__OutputArray(), err.as_raw__OutputArray(), win_size.opencv_as_extern(), max_level, criteria.opencv_as_extern(), flags, min_eig_threshold, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(status);
+		output_array_finish!(err);
 		Ok(ret)
 	}
 	
@@ -485,8 +488,8 @@ pub mod video {
 	/// Resulting Mat has a type CV_32FC2 - floating-point, 2-channel. First channel corresponds to the
 	/// flow in the horizontal direction (u), second - vertical (v).
 	#[inline]
-	pub fn read_optical_flow(path: &str) -> Result<core::Mat> {
-		extern_container_arg!(path);
+	pub fn read_optical_flow(path: impl AsRef<std::path::Path>) -> Result<core::Mat> {
+		path_arg!(path);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_readOpticalFlow_const_StringR(path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -505,8 +508,8 @@ pub mod video {
 	/// The flow field must be a 2-channel, floating-point matrix (CV_32FC2). First channel corresponds
 	/// to the flow in the horizontal direction (u), second - vertical (v).
 	#[inline]
-	pub fn write_optical_flow(path: &str, flow: &impl core::ToInputArray) -> Result<bool> {
-		extern_container_arg!(path);
+	pub fn write_optical_flow(path: impl AsRef<std::path::Path>, flow: &impl core::ToInputArray) -> Result<bool> {
+		path_arg!(path);
 		input_array_arg!(flow);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_writeOpticalFlow_const_StringR_const__InputArrayR(path.opencv_as_extern(), flow.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -534,6 +537,7 @@ pub mod video {
 			unsafe { sys::cv_BackgroundSubtractor_getBackgroundImage_const_const__OutputArrayR(self.as_raw_BackgroundSubtractor(), background_image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(background_image);
 			Ok(ret)
 		}
 		
@@ -563,6 +567,7 @@ pub mod video {
 			unsafe { sys::cv_BackgroundSubtractor_apply_const__InputArrayR_const__OutputArrayR_double(self.as_raw_mut_BackgroundSubtractor(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(fgmask);
 			Ok(ret)
 		}
 		
@@ -1147,6 +1152,7 @@ pub mod video {
 			unsafe { sys::cv_BackgroundSubtractorMOG2_apply_const__InputArrayR_const__OutputArrayR_double(self.as_raw_mut_BackgroundSubtractorMOG2(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(fgmask);
 			Ok(ret)
 		}
 		
@@ -2293,6 +2299,8 @@ pub mod video {
 			unsafe { sys::cv_SparseOpticalFlow_calc_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SparseOpticalFlow(), prev_img.as_raw__InputArray(), next_img.as_raw__InputArray(), prev_pts.as_raw__InputArray(), next_pts.as_raw__InputOutputArray(), status.as_raw__OutputArray(), err.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(status);
+			output_array_finish!(err);
 			Ok(ret)
 		}
 		
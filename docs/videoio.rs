@@ -1243,8 +1243,8 @@ pub mod videoio {
 		/// ## C++ default parameters
 		/// * api_preference: CAP_ANY
 		#[inline]
-		fn open_file(&mut self, filename: &str, api_preference: i32) -> Result<bool> {
-			extern_container_arg!(filename);
+		fn open_file(&mut self, filename: impl AsRef<std::path::Path>, api_preference: i32) -> Result<bool> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_VideoCapture_open_const_StringR_int(self.as_raw_mut_VideoCapture(), filename.opencv_as_extern(), api_preference, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1264,8 +1264,8 @@ pub mod videoio {
 		/// 
 		/// The method first calls VideoCapture::release to close the already opened file or camera.
 		#[inline]
-		fn open_file_with_params(&mut self, filename: &str, api_preference: i32, params: &core::Vector<i32>) -> Result<bool> {
-			extern_container_arg!(filename);
+		fn open_file_with_params(&mut self, filename: impl AsRef<std::path::Path>, api_preference: i32, params: &core::Vector<i32>) -> Result<bool> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_VideoCapture_open_const_StringR_int_const_vectorLintGR(self.as_raw_mut_VideoCapture(), filename.opencv_as_extern(), api_preference, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1385,6 +1385,7 @@ pub mod videoio {
 			unsafe { sys::cv_VideoCapture_retrieve_const__OutputArrayR_int(self.as_raw_mut_VideoCapture(), image.as_raw__OutputArray(), flag, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(image);
 			Ok(ret)
 		}
 		
@@ -1411,6 +1412,7 @@ pub mod videoio {
 			unsafe { sys::cv_VideoCapture_read_const__OutputArrayR(self.as_raw_mut_VideoCapture(), image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(image);
 			Ok(ret)
 		}
 		
@@ -1543,8 +1545,8 @@ pub mod videoio {
 		/// ## C++ default parameters
 		/// * api_preference: CAP_ANY
 		#[inline]
-		pub fn from_file(filename: &str, api_preference: i32) -> Result<crate::videoio::VideoCapture> {
-			extern_container_arg!(filename);
+		pub fn from_file(filename: impl AsRef<std::path::Path>, api_preference: i32) -> Result<crate::videoio::VideoCapture> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_VideoCapture_VideoCapture_const_StringR_int(filename.opencv_as_extern(), api_preference, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1566,8 +1568,8 @@ pub mod videoio {
 		///    The `params` parameter allows to specify extra parameters encoded as pairs `(paramId_1, paramValue_1, paramId_2, paramValue_2, ...)`.
 		///    See cv::VideoCaptureProperties
 		#[inline]
-		pub fn from_file_with_params(filename: &str, api_preference: i32, params: &core::Vector<i32>) -> Result<crate::videoio::VideoCapture> {
-			extern_container_arg!(filename);
+		pub fn from_file_with_params(filename: impl AsRef<std::path::Path>, api_preference: i32, params: &core::Vector<i32>) -> Result<crate::videoio::VideoCapture> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_VideoCapture_VideoCapture_const_StringR_int_const_vectorLintGR(filename.opencv_as_extern(), api_preference, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1729,8 +1731,8 @@ pub mod videoio {
 		/// ## C++ default parameters
 		/// * is_color: true
 		#[inline]
-		fn open(&mut self, filename: &str, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<bool> {
-			extern_container_arg!(filename);
+		fn open(&mut self, filename: impl AsRef<std::path::Path>, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<bool> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_VideoWriter_open_const_StringR_int_double_Size_bool(self.as_raw_mut_VideoWriter(), filename.opencv_as_extern(), fourcc, fps, frame_size.opencv_as_extern(), is_color, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1752,8 +1754,8 @@ pub mod videoio {
 		/// ## C++ default parameters
 		/// * is_color: true
 		#[inline]
-		fn open_with_backend(&mut self, filename: &str, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<bool> {
-			extern_container_arg!(filename);
+		fn open_with_backend(&mut self, filename: impl AsRef<std::path::Path>, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<bool> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_VideoWriter_open_const_StringR_int_int_double_Size_bool(self.as_raw_mut_VideoWriter(), filename.opencv_as_extern(), api_preference, fourcc, fps, frame_size.opencv_as_extern(), is_color, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1772,8 +1774,8 @@ pub mod videoio {
 		/// 
 		/// ## Overloaded parameters
 		#[inline]
-		fn open_1(&mut self, filename: &str, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<bool> {
-			extern_container_arg!(filename);
+		fn open_1(&mut self, filename: impl AsRef<std::path::Path>, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<bool> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_VideoWriter_open_const_StringR_int_double_const_SizeR_const_vectorLintGR(self.as_raw_mut_VideoWriter(), filename.opencv_as_extern(), fourcc, fps, &frame_size, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1792,8 +1794,8 @@ pub mod videoio {
 		/// 
 		/// ## Overloaded parameters
 		#[inline]
-		fn open_2(&mut self, filename: &str, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<bool> {
-			extern_container_arg!(filename);
+		fn open_2(&mut self, filename: impl AsRef<std::path::Path>, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<bool> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_VideoWriter_open_const_StringR_int_int_double_const_SizeR_const_vectorLintGR(self.as_raw_mut_VideoWriter(), filename.opencv_as_extern(), api_preference, fourcc, fps, &frame_size, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1930,8 +1932,8 @@ pub mod videoio {
 		/// ## C++ default parameters
 		/// * is_color: true
 		#[inline]
-		pub fn new(filename: &str, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<crate::videoio::VideoWriter> {
-			extern_container_arg!(filename);
+		pub fn new(filename: impl AsRef<std::path::Path>, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<crate::videoio::VideoWriter> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_VideoWriter_VideoWriter_const_StringR_int_double_Size_bool(filename.opencv_as_extern(), fourcc, fps, frame_size.opencv_as_extern(), is_color, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1955,8 +1957,8 @@ pub mod videoio {
 		/// ## C++ default parameters
 		/// * is_color: true
 		#[inline]
-		pub fn new_with_backend(filename: &str, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<crate::videoio::VideoWriter> {
-			extern_container_arg!(filename);
+		pub fn new_with_backend(filename: impl AsRef<std::path::Path>, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<crate::videoio::VideoWriter> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_VideoWriter_VideoWriter_const_StringR_int_int_double_Size_bool(filename.opencv_as_extern(), api_preference, fourcc, fps, frame_size.opencv_as_extern(), is_color, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1977,8 +1979,8 @@ pub mod videoio {
 		///      * The `params` parameter allows to specify extra encoder parameters encoded as pairs (paramId_1, paramValue_1, paramId_2, paramValue_2, ... .)
 		///      * see cv::VideoWriterProperties
 		#[inline]
-		pub fn new_1(filename: &str, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<crate::videoio::VideoWriter> {
-			extern_container_arg!(filename);
+		pub fn new_1(filename: impl AsRef<std::path::Path>, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<crate::videoio::VideoWriter> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_VideoWriter_VideoWriter_const_StringR_int_double_const_SizeR_const_vectorLintGR(filename.opencv_as_extern(), fourcc, fps, &frame_size, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1996,8 +1998,8 @@ pub mod videoio {
 		/// 
 		/// ## Overloaded parameters
 		#[inline]
-		pub fn new_2(filename: &str, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<crate::videoio::VideoWriter> {
-			extern_container_arg!(filename);
+		pub fn new_2(filename: impl AsRef<std::path::Path>, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<crate::videoio::VideoWriter> {
+			path_arg!(filename);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_VideoWriter_VideoWriter_const_StringR_int_int_double_const_SizeR_const_vectorLintGR(filename.opencv_as_extern(), api_preference, fourcc, fps, &frame_size, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -566,6 +566,7 @@ pub mod videostab {
 			unsafe { sys::cv_videostab_DensePyrLkOptFlowEstimatorGpu_run_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR(self.as_raw_mut_DensePyrLkOptFlowEstimatorGpu(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), flow_x.as_raw__InputOutputArray(), flow_y.as_raw__InputOutputArray(), errors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(errors);
 			Ok(ret)
 		}
 		
@@ -766,8 +767,8 @@ pub mod videostab {
 	
 	impl FromFileMotionReader {
 		#[inline]
-		pub fn new(path: &str) -> Result<crate::videostab::FromFileMotionReader> {
-			extern_container_arg!(path);
+		pub fn new(path: impl AsRef<std::path::Path>) -> Result<crate::videostab::FromFileMotionReader> {
+			path_arg!(path);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_videostab_FromFileMotionReader_FromFileMotionReader_const_StringR(path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -928,6 +929,7 @@ pub mod videostab {
 			unsafe { sys::cv_videostab_IDenseOptFlowEstimator_run_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR(self.as_raw_mut_IDenseOptFlowEstimator(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), flow_x.as_raw__InputOutputArray(), flow_y.as_raw__InputOutputArray(), errors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(errors);
 			Ok(ret)
 		}
 		
@@ -1046,6 +1048,52 @@ pub mod videostab {
 		}
 	}
 	
+	/// Rust implementation of the virtual methods of [crate::videostab::IFrameSource]
+	///
+	/// Use [crate::videostab::IFrameSource::from_director] or [core::Ptr::<crate::videostab::IFrameSource>::from_director] to get an OpenCV object that forwards
+	/// the calls to this implementation. Panics and errors returned from the methods are passed to OpenCV as `cv::Exception`.
+	pub trait IFrameSourceDirector: Send + Sync {
+		fn reset(&mut self) -> Result<()>;
+		fn next_frame(&mut self) -> Result<core::Mat>;
+	}
+	
+	impl IFrameSource {
+		/// Creates [crate::videostab::IFrameSource] that forwards its virtual method calls to the passed Rust implementation
+		#[inline]
+		pub fn from_director(director: Box<dyn crate::videostab::IFrameSourceDirector>) -> Self {
+			extern "C" { fn cv_IFrameSource_director_new(ocvrs_self: *mut c_void) -> *mut c_void; }
+			let ocvrs_self = Box::into_raw(Box::new(director)) as *mut c_void;
+			unsafe { Self::from_raw(cv_IFrameSource_director_new(ocvrs_self)) }
+		}
+	}
+	
+	impl core::Ptr<crate::videostab::IFrameSource> {
+		/// Creates [crate::videostab::IFrameSource] that forwards its virtual method calls to the passed Rust implementation
+		#[inline]
+		pub fn from_director(director: Box<dyn crate::videostab::IFrameSourceDirector>) -> Self {
+			extern "C" { fn cv_PtrOfIFrameSource_director_new(ocvrs_self: *mut c_void) -> *mut c_void; }
+			let ocvrs_self = Box::into_raw(Box::new(director)) as *mut c_void;
+			unsafe { Self::from_raw(cv_PtrOfIFrameSource_director_new(ocvrs_self)) }
+		}
+	}
+	
+	#[no_mangle]
+	unsafe extern "C" fn ocvrs_director_IFrameSource_delete(ocvrs_self: *mut c_void) {
+		crate::templ::director_delete(ocvrs_self as *mut Box<dyn crate::videostab::IFrameSourceDirector>);
+	}
+	
+	#[no_mangle]
+	unsafe extern "C" fn ocvrs_director_IFrameSource_reset(ocvrs_self: *mut c_void, ocvrs_return: *mut sys::Result<()>) {
+		let ocvrs_self = &mut *(ocvrs_self as *mut Box<dyn crate::videostab::IFrameSourceDirector>);
+		crate::templ::director_call(ocvrs_return, || ocvrs_self.reset());
+	}
+	
+	#[no_mangle]
+	unsafe extern "C" fn ocvrs_director_IFrameSource_next_frame(ocvrs_self: *mut c_void, ocvrs_return: *mut sys::Result<*mut c_void>) {
+		let ocvrs_self = &mut *(ocvrs_self as *mut Box<dyn crate::videostab::IFrameSourceDirector>);
+		crate::templ::director_call(ocvrs_return, || ocvrs_self.next_frame().map(|ret| ret.into_raw()));
+	}
+	
 	/// Constant methods for [crate::videostab::ILog]
 	pub trait ILogTraitConst {
 		fn as_raw_ILog(&self) -> *const c_void;
@@ -1189,6 +1237,7 @@ pub mod videostab {
 			unsafe { sys::cv_videostab_IOutlierRejector_process_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_IOutlierRejector(), frame_size.opencv_as_extern(), points0.as_raw__InputArray(), points1.as_raw__InputArray(), mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(mask);
 			Ok(ret)
 		}
 		
@@ -1255,6 +1304,8 @@ pub mod videostab {
 			unsafe { sys::cv_videostab_ISparseOptFlowEstimator_run_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_ISparseOptFlowEstimator(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), points0.as_raw__InputArray(), points1.as_raw__InputOutputArray(), status.as_raw__OutputArray(), errors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(status);
+			output_array_finish!(errors);
 			Ok(ret)
 		}
 		
@@ -3530,6 +3581,7 @@ pub mod videostab {
 			unsafe { sys::cv_videostab_NullOutlierRejector_process_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_NullOutlierRejector(), frame_size.opencv_as_extern(), points0.as_raw__InputArray(), points1.as_raw__InputArray(), mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(mask);
 			Ok(ret)
 		}
 		
@@ -4041,6 +4093,8 @@ pub mod videostab {
 			unsafe { sys::cv_videostab_SparsePyrLkOptFlowEstimator_run_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SparsePyrLkOptFlowEstimator(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), points0.as_raw__InputArray(), points1.as_raw__InputOutputArray(), status.as_raw__OutputArray(), errors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(status);
+			output_array_finish!(errors);
 			Ok(ret)
 		}
 		
@@ -4123,6 +4177,8 @@ pub mod videostab {
 			unsafe { sys::cv_videostab_SparsePyrLkOptFlowEstimatorGpu_run_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SparsePyrLkOptFlowEstimatorGpu(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), points0.as_raw__InputArray(), points1.as_raw__InputOutputArray(), status.as_raw__OutputArray(), errors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(status);
+			output_array_finish!(errors);
 			Ok(ret)
 		}
 		
@@ -4534,8 +4590,8 @@ pub mod videostab {
 	
 	impl ToFileMotionWriter {
 		#[inline]
-		pub fn new(path: &str, mut estimator: core::Ptr<crate::videostab::ImageMotionEstimatorBase>) -> Result<crate::videostab::ToFileMotionWriter> {
-			extern_container_arg!(path);
+		pub fn new(path: impl AsRef<std::path::Path>, mut estimator: core::Ptr<crate::videostab::ImageMotionEstimatorBase>) -> Result<crate::videostab::ToFileMotionWriter> {
+			path_arg!(path);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_videostab_ToFileMotionWriter_ToFileMotionWriter_const_StringR_PtrLImageMotionEstimatorBaseG(path.opencv_as_extern(), estimator.as_raw_mut_PtrOfImageMotionEstimatorBase(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -4612,6 +4668,7 @@ pub mod videostab {
 			unsafe { sys::cv_videostab_TranslationBasedLocalOutlierRejector_process_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_TranslationBasedLocalOutlierRejector(), frame_size.opencv_as_extern(), points0.as_raw__InputArray(), points1.as_raw__InputArray(), mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
+			output_array_finish!(mask);
 			Ok(ret)
 		}
 		
@@ -4927,8 +4984,8 @@ pub mod videostab {
 		/// ## C++ default parameters
 		/// * volatile_frame: false
 		#[inline]
-		pub fn new(path: &str, volatile_frame: bool) -> Result<crate::videostab::VideoFileSource> {
-			extern_container_arg!(path);
+		pub fn new(path: impl AsRef<std::path::Path>, volatile_frame: bool) -> Result<crate::videostab::VideoFileSource> {
+			path_arg!(path);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_videostab_VideoFileSource_VideoFileSource_const_StringR_bool(path.opencv_as_extern(), volatile_frame, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
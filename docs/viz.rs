@@ -153,6 +153,7 @@ pub mod viz {
 		unsafe { sys::cv_viz_computeNormals_const_MeshR_const__OutputArrayR(mesh.as_raw_Mesh(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(normals);
 		Ok(ret)
 	}
 	
@@ -248,8 +249,8 @@ pub mod viz {
 	/// * colors: noArray()
 	/// * normals: noArray()
 	#[inline]
-	pub fn read_cloud(file: &str, colors: &mut impl core::ToOutputArray, normals: &mut impl core::ToOutputArray) -> Result<core::Mat> {
-		extern_container_arg!(file);
+	pub fn read_cloud(file: impl AsRef<std::path::Path>, colors: &mut impl core::ToOutputArray, normals: &mut impl core::ToOutputArray) -> Result<core::Mat> {
+		path_arg!(file);
 		output_array_arg!(colors);
 		output_array_arg!(normals);
 		return_send!(via ocvrs_return);
@@ -257,14 +258,16 @@ pub mod viz {
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
+		output_array_finish!(colors);
+		output_array_finish!(normals);
 		Ok(ret)
 	}
 	
 	/// ////////////////////////////////////////////////////////////////////////////////////////////
 	/// Reads mesh. Only ply format is supported now and no texture load support
 	#[inline]
-	pub fn read_mesh(file: &str) -> Result<crate::viz::Mesh> {
-		extern_container_arg!(file);
+	pub fn read_mesh(file: impl AsRef<std::path::Path>) -> Result<crate::viz::Mesh> {
+		path_arg!(file);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_readMesh_const_StringR(file.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -281,8 +284,8 @@ pub mod viz {
 	/// ## C++ default parameters
 	/// * tag: "pose"
 	#[inline]
-	pub fn read_pose(file: &str, pose: &mut core::Affine3d, tag: &str) -> Result<bool> {
-		extern_container_arg!(file);
+	pub fn read_pose(file: impl AsRef<std::path::Path>, pose: &mut core::Affine3d, tag: &str) -> Result<bool> {
+		path_arg!(file);
 		extern_container_arg!(tag);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_readPose_const_StringR_Affine3dR_const_StringR(file.opencv_as_extern(), pose, tag.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -317,6 +320,7 @@ pub mod viz {
 		unsafe { sys::cv_viz_readTrajectory_const__OutputArrayR_const_StringR_int_int_const_StringR(traj.as_raw__OutputArray(), files_format.opencv_as_extern(), start, end, tag.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
+		output_array_finish!(traj);
 		Ok(ret)
 	}
 	
@@ -343,8 +347,8 @@ pub mod viz {
 	/// * normals: noArray()
 	/// * binary: false
 	#[inline]
-	pub fn write_cloud(file: &str, cloud: &impl core::ToInputArray, colors: &impl core::ToInputArray, normals: &impl core::ToInputArray, binary: bool) -> Result<()> {
-		extern_container_arg!(file);
+	pub fn write_cloud(file: impl AsRef<std::path::Path>, cloud: &impl core::ToInputArray, colors: &impl core::ToInputArray, normals: &impl core::ToInputArray, binary: bool) -> Result<()> {
+		path_arg!(file);
 		input_array_arg!(cloud);
 		input_array_arg!(colors);
 		input_array_arg!(normals);
@@ -363,8 +367,8 @@ pub mod viz {
 	/// ## C++ default parameters
 	/// * tag: "pose"
 	#[inline]
-	pub fn write_pose(file: &str, pose: core::Affine3d, tag: &str) -> Result<()> {
-		extern_container_arg!(file);
+	pub fn write_pose(file: impl AsRef<std::path::Path>, pose: core::Affine3d, tag: &str) -> Result<()> {
+		path_arg!(file);
 		extern_container_arg!(tag);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_writePose_const_StringR_const_Affine3dR_const_StringR(file.opencv_as_extern(), &pose, tag.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -1374,8 +1378,8 @@ pub mod viz {
 		/// ## C++ default parameters
 		/// * typ: LOAD_PLY
 		#[inline]
-		pub fn load(file: &str, typ: i32) -> Result<crate::viz::Mesh> {
-			extern_container_arg!(file);
+		pub fn load(file: impl AsRef<std::path::Path>, typ: i32) -> Result<crate::viz::Mesh> {
+			path_arg!(file);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_viz_Mesh_load_const_StringR_int(file.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1824,8 +1828,8 @@ pub mod viz {
 		/// ## Parameters
 		/// * file: Name of the file.
 		#[inline]
-		fn save_screenshot(&mut self, file: &str) -> Result<()> {
-			extern_container_arg!(file);
+		fn save_screenshot(&mut self, file: impl AsRef<std::path::Path>) -> Result<()> {
+			path_arg!(file);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_viz_Viz3d_saveScreenshot_const_StringR(self.as_raw_mut_Viz3d(), file.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -1995,6 +1999,7 @@ pub mod viz {
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_viz_Viz3d_registerKeyboardCallback_KeyboardCallback_voidX(self.as_raw_mut_Viz3d(), callback_trampoline, cookie, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
+			callback_register!(callbacks, ret);
 			let ret = ret.into_result()?;
 			Ok(ret)
 		}
@@ -2014,6 +2019,7 @@ pub mod viz {
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_viz_Viz3d_registerMouseCallback_MouseCallback_voidX(self.as_raw_mut_Viz3d(), callback_trampoline, cookie, ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
+			callback_register!(callbacks, ret);
 			let ret = ret.into_result()?;
 			Ok(ret)
 		}
@@ -5025,8 +5031,8 @@ pub mod viz {
 		/// ## Parameters
 		/// * file_name: Ply file name.
 		#[inline]
-		pub fn from_ply_file(file_name: &str) -> Result<crate::viz::Widget> {
-			extern_container_arg!(file_name);
+		pub fn from_ply_file(file_name: impl AsRef<std::path::Path>) -> Result<crate::viz::Widget> {
+			path_arg!(file_name);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_viz_Widget_fromPlyFile_const_StringR(file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
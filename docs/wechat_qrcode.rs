@@ -36,6 +36,7 @@ pub mod wechat_qrcode {
 			return_receive!(unsafe ocvrs_return => ret);
 			let ret = ret.into_result()?;
 			let ret = unsafe { core::Vector::<String>::opencv_from_extern(ret) };
+			output_array_finish!(points);
 			Ok(ret)
 		}
 		
@@ -115,11 +116,11 @@ pub mod wechat_qrcode {
 		/// * super_resolution_prototxt_path: ""
 		/// * super_resolution_caffe_model_path: ""
 		#[inline]
-		pub fn new(detector_prototxt_path: &str, detector_caffe_model_path: &str, super_resolution_prototxt_path: &str, super_resolution_caffe_model_path: &str) -> Result<crate::wechat_qrcode::WeChatQRCode> {
-			extern_container_arg!(detector_prototxt_path);
-			extern_container_arg!(detector_caffe_model_path);
-			extern_container_arg!(super_resolution_prototxt_path);
-			extern_container_arg!(super_resolution_caffe_model_path);
+		pub fn new(detector_prototxt_path: impl AsRef<std::path::Path>, detector_caffe_model_path: impl AsRef<std::path::Path>, super_resolution_prototxt_path: impl AsRef<std::path::Path>, super_resolution_caffe_model_path: impl AsRef<std::path::Path>) -> Result<crate::wechat_qrcode::WeChatQRCode> {
+			path_arg!(detector_prototxt_path);
+			path_arg!(detector_caffe_model_path);
+			path_arg!(super_resolution_prototxt_path);
+			path_arg!(super_resolution_caffe_model_path);
 			return_send!(via ocvrs_return);
 			unsafe { sys::cv_wechat_qrcode_WeChatQRCode_WeChatQRCode_const_stringR_const_stringR_const_stringR_const_stringR(detector_prototxt_path.opencv_as_extern(), detector_caffe_model_path.opencv_as_extern(), super_resolution_prototxt_path.opencv_as_extern(), super_resolution_caffe_model_path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 			return_receive!(unsafe ocvrs_return => ret);
@@ -41,4 +41,20 @@ impl From<TryFromCharError> for Error {
 
 impl std::error::Error for Error {}
 
+#[cfg(feature = "serde")]
+impl serde::ser::Error for Error {
+	#[inline]
+	fn custom<T: fmt::Display>(msg: T) -> Self {
+		Self::new(core::StsError, msg.to_string())
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::de::Error for Error {
+	#[inline]
+	fn custom<T: fmt::Display>(msg: T) -> Self {
+		Self::new(core::StsParseError, msg.to_string())
+	}
+}
+
 pub type Result<T, E = Error> = ::std::result::Result<T, E>;
@@ -13,6 +13,9 @@ mod manual;
 mod opencv;
 mod traits;
 
+#[cfg(all(ocvrs_has_module_core, feature = "serde"))]
+pub mod persistence;
+
 pub mod prelude {
 	#[cfg(ocvrs_has_module_core)]
 	pub use crate::core::{DataType, Mat};
@@ -43,8 +46,8 @@ pub(crate) mod mod_prelude {
 		hub_prelude::*,
 		input_output_array_ref_forward,
 		mod_prelude_sys::*,
-		opencv_type_boxed, opencv_type_enum, opencv_type_simple, ptr_cast_base, ptr_extern, ptr_extern_ctor, tuple_extern,
-		vector_copy_non_bool, vector_extern, vector_non_copy_or_bool, Error, Result,
+		opencv_type_boxed, opencv_type_enum, opencv_type_simple, ptr_cast_base, ptr_cast_descendant, ptr_extern, ptr_extern_ctor,
+		tuple_extern, vector_copy_non_bool, vector_extern, vector_non_copy_or_bool, Error, Result,
 	};
 	pub use std::convert::TryFrom;
 }
@@ -1,36 +1,61 @@
 pub use affine3::*;
+pub use algorithm::*;
+#[cfg(all(feature = "async", not(ocvrs_opencv_branch_32)))]
+pub use async_array::*;
 pub use data_type::*;
+pub use error_handler::*;
+pub use file_node::*;
 pub use gpumat::*;
 pub use input_output_array::*;
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub use logging::*;
 pub use mat::*;
 pub use mat_ops::*;
+pub use mat_type::*;
 pub use matx::*;
+pub use parallel::*;
 pub use point::*;
 pub use point3::*;
 pub use ptr::*;
+#[cfg(feature = "rayon")]
+pub use rayon_backend::*;
 pub use rect::*;
 pub use scalar::*;
 pub use size::*;
 pub use sized::*;
+pub use sparse_mat::*;
 pub use tuple::*;
 pub use vec::*;
 pub use vector::*;
 pub use CV_MAKETYPE as CV_MAKE_TYPE;
 
 mod affine3;
+mod algorithm;
+#[cfg(all(feature = "async", not(ocvrs_opencv_branch_32)))]
+mod async_array;
 mod data_type;
+mod error_handler;
+pub(crate) mod file_node;
+mod file_storage;
 mod gpumat;
 mod input_output_array;
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod logging;
 mod mat;
 mod mat_ops;
+mod mat_type;
 mod matx;
+mod parallel;
 mod point;
 mod point3;
 pub(crate) mod ptr;
+#[cfg(feature = "rayon")]
+mod rayon_backend;
 mod rect;
 mod scalar;
 mod size;
 mod sized;
+mod sparse_mat;
 mod tuple;
 mod vec;
 mod vector;
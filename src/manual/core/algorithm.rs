@@ -0,0 +1,141 @@
+use crate::core::{
+	AlgorithmTrait, AlgorithmTraitConst, FileNodeTraitConst, FileStorage, FileStorageTrait, FileStorageTraitConst,
+	FileStorage_Mode,
+};
+use crate::{core, Error, Result};
+
+/// Format of the serialized `FileStorage` data
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FileStorageFormat {
+	Yaml,
+	Xml,
+	Json,
+}
+
+impl FileStorageFormat {
+	/// `FileStorage` flag for this format
+	#[inline]
+	pub fn flag(self) -> i32 {
+		match self {
+			Self::Yaml => FileStorage_Mode::FORMAT_YAML as i32,
+			Self::Xml => FileStorage_Mode::FORMAT_XML as i32,
+			Self::Json => FileStorage_Mode::FORMAT_JSON as i32,
+		}
+	}
+}
+
+pub trait AlgorithmTraitConstManual: AlgorithmTraitConst {
+	/// Same as [AlgorithmTraitConst::save], but returns the serialized algorithm instead of writing it to a file
+	///
+	/// The output is the same as the contents of the file written by `save()`. With `compressed` the output is gzipped, same
+	/// as the file written by `save()` when its name ends with `.gz`, this requires the `flate2` feature.
+	fn save_to_bytes(&self, format: FileStorageFormat, compressed: bool) -> Result<Vec<u8>> {
+		let flags = FileStorage_Mode::WRITE as i32 | FileStorage_Mode::MEMORY as i32 | format.flag();
+		let mut fs = FileStorage::new("", flags, "")?;
+		fs.start_write_struct(&self.get_default_name()?, core::FileNode_MAP, "")?;
+		self.write(&mut fs)?;
+		fs.end_write_struct()?;
+		let out = fs.release_and_get_string()?.into_bytes();
+		if compressed {
+			gzip::compress(&out)
+		} else {
+			Ok(out)
+		}
+	}
+}
+
+pub trait AlgorithmTraitManual: AlgorithmTrait {
+	/// Reads the algorithm state serialized with [AlgorithmTraitConstManual::save_to_bytes] or `save()`, the format and the
+	/// compression are detected automatically
+	fn load_from_bytes(&mut self, bytes: &[u8]) -> Result<()> {
+		let decompressed;
+		let bytes = if gzip::is_compressed(bytes) {
+			decompressed = gzip::decompress(bytes)?;
+			decompressed.as_slice()
+		} else {
+			bytes
+		};
+		let fs = FileStorage::from_bytes(bytes, FileStorage_Mode::READ as i32, "")?;
+		let node = fs.get_first_top_level_node()?;
+		if node.empty()? {
+			return Err(Error::new(
+				core::StsParseError,
+				"Serialized algorithm doesn't contain any data",
+			));
+		}
+		self.read(&node)
+	}
+}
+
+impl<T: AlgorithmTraitConst + ?Sized> AlgorithmTraitConstManual for T {}
+
+impl<T: AlgorithmTrait + ?Sized> AlgorithmTraitManual for T {}
+
+/// Creates the algorithm with `create` and reads its state from the bytes produced by
+/// [AlgorithmTraitConstManual::save_to_bytes] or the contents of the file written by `save()`, the in-memory counterpart of
+/// the static `load()` functions
+///
+/// `create` is the same function that C++ `cv::Algorithm::load<T>()` calls, usually the static `create()` of the algorithm,
+/// e.g. `core::load_from_bytes(&bytes, ml::SVM::create)` or
+/// `core::load_from_bytes(&bytes, || face::LBPHFaceRecognizer::create(1, 8, 8, 8, f64::MAX))`.
+#[inline]
+pub fn load_from_bytes<T: AlgorithmTrait>(bytes: &[u8], create: impl FnOnce() -> Result<T>) -> Result<T> {
+	let mut algorithm = create()?;
+	algorithm.load_from_bytes(bytes)?;
+	Ok(algorithm)
+}
+
+mod gzip {
+	use crate::Result;
+
+	const MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+	#[inline]
+	pub fn is_compressed(bytes: &[u8]) -> bool {
+		bytes.starts_with(&MAGIC)
+	}
+
+	#[cfg(feature = "flate2")]
+	pub fn compress(bytes: &[u8]) -> Result<Vec<u8>> {
+		use std::io::Write;
+
+		let mut encoder = flate2::write::GzEncoder::new(Vec::with_capacity(bytes.len() / 4), flate2::Compression::default());
+		encoder.write_all(bytes).map_err(io_error)?;
+		encoder.finish().map_err(io_error)
+	}
+
+	#[cfg(feature = "flate2")]
+	pub fn decompress(bytes: &[u8]) -> Result<Vec<u8>> {
+		use std::io::Read;
+
+		let mut out = Vec::with_capacity(bytes.len() * 4);
+		flate2::read::GzDecoder::new(bytes).read_to_end(&mut out).map_err(io_error)?;
+		Ok(out)
+	}
+
+	#[cfg(feature = "flate2")]
+	fn io_error(e: std::io::Error) -> crate::Error {
+		crate::Error::new(
+			crate::core::StsError,
+			format!("Can't process the gzipped algorithm data: {e}"),
+		)
+	}
+
+	#[cfg(not(feature = "flate2"))]
+	pub fn compress(_bytes: &[u8]) -> Result<Vec<u8>> {
+		Err(not_supported())
+	}
+
+	#[cfg(not(feature = "flate2"))]
+	pub fn decompress(_bytes: &[u8]) -> Result<Vec<u8>> {
+		Err(not_supported())
+	}
+
+	#[cfg(not(feature = "flate2"))]
+	fn not_supported() -> crate::Error {
+		crate::Error::new(
+			crate::core::StsNotImplemented,
+			"Gzipped algorithm data requires the `flate2` feature of the opencv crate",
+		)
+	}
+}
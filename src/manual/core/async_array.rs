@@ -0,0 +1,93 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+use crate::core::{self, AsyncArray, AsyncArrayTraitConst, Mat};
+use crate::{Error, Result};
+
+/// Allows `.await`-ing the result of the asynchronous operation, e.g. of `dnn::Net::forward_async()`
+impl IntoFuture for AsyncArray {
+	type Output = Result<Mat>;
+	type IntoFuture = AsyncArrayFuture;
+
+	#[inline]
+	fn into_future(self) -> Self::IntoFuture {
+		AsyncArrayFuture {
+			array: Some(self),
+			shared: Arc::new(Mutex::new(Shared::default())),
+		}
+	}
+}
+
+/// Future that resolves to the result of the [AsyncArray]
+///
+/// If the result is not yet available on the first poll then a dedicated thread is started that blocks on
+/// `AsyncArray::get()` and wakes the task once the result is ready, so the executor threads are never blocked. Dropping the
+/// future doesn't cancel the underlying operation, the waiter thread exits when it finishes.
+pub struct AsyncArrayFuture {
+	/// Moved to the waiter thread once it's started
+	array: Option<AsyncArray>,
+	shared: Arc<Mutex<Shared>>,
+}
+
+#[derive(Default)]
+struct Shared {
+	result: Option<Result<Mat>>,
+	waker: Option<Waker>,
+}
+
+#[inline]
+fn get(array: &AsyncArray) -> Result<Mat> {
+	let mut out = Mat::default();
+	array.get(&mut out)?;
+	Ok(out)
+}
+
+impl Future for AsyncArrayFuture {
+	type Output = Result<Mat>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if let Some(array) = &self.array {
+			match array.wait_for(0) {
+				Ok(true) => {
+					let res = get(array);
+					self.array = None;
+					return Poll::Ready(res);
+				}
+				Ok(false) => {}
+				Err(e) => return Poll::Ready(Err(e)),
+			}
+		}
+		{
+			let mut shared = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+			if let Some(res) = shared.result.take() {
+				return Poll::Ready(res);
+			}
+			// the waker is updated under the lock so that the waiter thread can't miss it
+			match &shared.waker {
+				Some(waker) if waker.will_wake(cx.waker()) => {}
+				_ => shared.waker = Some(cx.waker().clone()),
+			}
+		}
+		if let Some(array) = self.array.take() {
+			let shared = Arc::clone(&self.shared);
+			let spawned = thread::Builder::new().name("opencv-async-array".to_string()).spawn(move || {
+				let res = get(&array);
+				let mut shared = shared.lock().unwrap_or_else(|e| e.into_inner());
+				shared.result = Some(res);
+				if let Some(waker) = shared.waker.take() {
+					waker.wake();
+				}
+			});
+			if let Err(e) = spawned {
+				return Poll::Ready(Err(Error::new(
+					core::StsError,
+					format!("Can't start the AsyncArray waiter thread: {e}"),
+				)));
+			}
+		}
+		Poll::Pending
+	}
+}
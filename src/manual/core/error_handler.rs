@@ -0,0 +1,127 @@
+use std::ffi::{c_void, CStr};
+use std::mem;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use once_cell::sync::Lazy;
+
+use crate::templ::{CallbackData, CallbackOwner};
+use crate::{sys, Result};
+
+type ErrorCallback = unsafe extern "C" fn(
+	status: c_int,
+	func_name: *const c_char,
+	err_msg: *const c_char,
+	file_name: *const c_char,
+	line: c_int,
+	userdata: *mut c_void,
+) -> c_int;
+
+type ErrorHandler = Box<dyn Fn(&ErrorInfo) + Send + Sync>;
+
+/// Owner of the handler that is currently passed to OpenCV, it's kept apart from the window callbacks so that it's not
+/// released together with them
+static ERROR_HANDLER: Lazy<Mutex<Option<Arc<HandlerOwner>>>> = Lazy::new(|| Mutex::new(None));
+
+/// [CallbackOwner] of the [ErrorHandler] shared by the threads that are currently running it
+struct HandlerOwner(CallbackOwner);
+
+// the handler is `Sync` and the panic state of the callback is synchronized
+unsafe impl Sync for HandlerOwner {}
+
+/// Details of the OpenCV error passed to the handler installed with [set_error_handler]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorInfo {
+	/// Error code, one of the `core::Sts*` constants
+	pub code: i32,
+	/// Name of the C++ function where the error occurred, can be empty
+	pub function: String,
+	/// Error description as passed to `CV_Error()`, without the location details
+	pub message: String,
+	/// Source file where the error occurred
+	pub file: String,
+	/// Line in the source file where the error occurred
+	pub line: i32,
+}
+
+/// Sets the handler that is called for every OpenCV error, see `cv::redirectError()`
+///
+/// The handler is called from the thread where the error occurred (that can also be the OpenCV worker thread) right before
+/// the C++ exception is thrown, so the error is still returned from the called function as usual. It replaces the default
+/// OpenCV behavior of printing the error to stderr.
+///
+/// Like with other callbacks that OpenCV calls outside of the function that received them, a panic in the handler aborts
+/// the process.
+pub fn set_error_handler(handler: impl Fn(&ErrorInfo) + Send + Sync + 'static) -> Result<()> {
+	let callbacks = CallbackOwner::new(Some(Box::new(handler) as ErrorHandler));
+	callbacks.detach();
+	// installed before OpenCV can call it, `error_callback` takes the current handler from `ERROR_HANDLER`
+	let prev = set_handler_owner(Some(Arc::new(HandlerOwner(callbacks))));
+	if let Err(e) = redirect_error(Some(error_callback), ptr::null_mut()) {
+		set_handler_owner(prev);
+		return Err(e);
+	}
+	Ok(())
+}
+
+/// Removes the handler set by [set_error_handler] and restores the default OpenCV error processing
+pub fn reset_error_handler() -> Result<()> {
+	redirect_error(None, ptr::null_mut())?;
+	// dropped outside of the lock in case the captured state calls back into OpenCV
+	drop(set_handler_owner(None));
+	Ok(())
+}
+
+/// Replaces the current handler and returns the previous one, it's released once the last thread running it has finished
+fn set_handler_owner(callbacks: Option<Arc<HandlerOwner>>) -> Option<Arc<HandlerOwner>> {
+	mem::replace(&mut *ERROR_HANDLER.lock().unwrap_or_else(PoisonError::into_inner), callbacks)
+}
+
+fn redirect_error(callback: Option<ErrorCallback>, userdata: *mut c_void) -> Result<()> {
+	extern "C" {
+		fn cv_manual_redirectError(callback: Option<ErrorCallback>, userdata: *mut c_void, ocvrs_return: *mut sys::Result<()>);
+	}
+	return_send!(via ocvrs_return);
+	unsafe { cv_manual_redirectError(callback, userdata, ocvrs_return.as_mut_ptr()) }
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result()
+}
+
+#[inline]
+unsafe fn lossy_string(s: *const c_char) -> String {
+	if s.is_null() {
+		String::new()
+	} else {
+		CStr::from_ptr(s).to_string_lossy().into_owned()
+	}
+}
+
+unsafe extern "C" fn error_callback(
+	status: c_int,
+	func_name: *const c_char,
+	err_msg: *const c_char,
+	file_name: *const c_char,
+	line: c_int,
+	_userdata: *mut c_void,
+) -> c_int {
+	// OpenCV can call the handler that has just been replaced by another thread, so instead of `userdata` the current one is
+	// used and the clone of `Arc` keeps it alive until the call is finished
+	let handler = ERROR_HANDLER.lock().unwrap_or_else(PoisonError::into_inner).clone();
+	let handler = match handler {
+		Some(handler) => handler,
+		None => return 0,
+	};
+	let info = ErrorInfo {
+		code: status,
+		function: lossy_string(func_name),
+		message: lossy_string(err_msg),
+		file: lossy_string(file_name),
+		line,
+	};
+	// the handler can be called from several threads at once
+	CallbackData::call_shared_catch_unwind(handler.0.userdata() as *const CallbackData<ErrorHandler>, |handler| {
+		handler(&info)
+	});
+	0
+}
@@ -0,0 +1,431 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+use std::ops::{Deref, Index};
+
+use once_cell::unsync::OnceCell;
+
+use crate::core::{
+	self, DMatch, FileNode, FileNodeIterator, FileNodeIteratorTrait, FileNodeIteratorTraitConst, FileNodeTraitConst, KeyPoint,
+	Mat, Point_, Rect_, Size_,
+};
+use crate::{Error, Result};
+
+impl FileNode {
+	/// Returns the iterator over the child nodes of a map or a sequence, it's empty for the other node types
+	pub fn iter(&self) -> FileNodeIter {
+		let iter = if self.is_map().unwrap_or(false) || self.is_seq().unwrap_or(false) {
+			self.begin().ok()
+		} else {
+			None
+		};
+		let remaining = iter.as_ref().and_then(|iter| iter.remaining().ok()).unwrap_or(0);
+		FileNodeIter {
+			iter,
+			remaining,
+			_d: PhantomData,
+		}
+	}
+
+	/// Returns the names of the child nodes of a map in the order they appear in the file, it's empty for the other node types
+	///
+	/// Unlike the generated `FileNodeTraitConst::keys()` it doesn't collect the names into a `Vector` and is also available
+	/// with OpenCV 3.4.
+	pub fn key_names(&self) -> impl Iterator<Item = String> + '_ {
+		let is_map = self.is_map().unwrap_or(false);
+		self.iter().filter(move |_| is_map).filter_map(|node| node.name().ok())
+	}
+
+	/// Reads the node value as `T`, returns an error if the node type doesn't match
+	///
+	/// Use [FileNodePath] to navigate the tree and to get the full node path in the error messages.
+	#[inline]
+	pub fn get_as<T: FromFileNode>(&self) -> Result<T> {
+		T::from_file_node(self, &self.name().unwrap_or_default())
+	}
+}
+
+impl<'n> IntoIterator for &'n FileNode {
+	type Item = FileNode;
+	type IntoIter = FileNodeIter<'n>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// Iterator over the child nodes of a `FileNode`
+///
+/// Always yields exactly as many nodes as the parent has children, the child that can't be read is yielded as an empty node.
+pub struct FileNodeIter<'n> {
+	iter: Option<FileNodeIterator>,
+	remaining: usize,
+	_d: PhantomData<&'n FileNode>,
+}
+
+impl Iterator for FileNodeIter<'_> {
+	type Item = FileNode;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.remaining == 0 {
+			return None;
+		}
+		self.remaining -= 1;
+		self
+			.iter
+			.as_mut()
+			.and_then(|iter| iter.try_deref().and_then(|node| iter.incr().map(|_| node)).ok())
+			.or_else(|| FileNode::default().ok())
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl ExactSizeIterator for FileNodeIter<'_> {}
+
+/// `FileNode` that knows its path from the root of the file
+///
+/// The tree is navigated with [FileNodePath::get] and [FileNodePath::at], e.g. `root.get("camera")?.get("matrix")?` or
+/// `root.get("sizes")?.at(1)?`, the missing nodes are empty. The errors of [FileNodePath::get_as] include the full path of
+/// the node, e.g. `camera.distortion[2]`. Dereferences to the inner `FileNode`.
+///
+/// Indexing, e.g. `root["camera"]["distortion"][2]`, reads all the children of the node once and keeps them until the
+/// `FileNodePath` is dropped. Like for `HashMap` and `Vec` it panics if there is no such child node.
+pub struct FileNodePath {
+	node: FileNode,
+	path: String,
+	children: OnceCell<Children>,
+}
+
+/// Cached child nodes of a [FileNodePath] for indexing
+struct Children {
+	nodes: Vec<FileNodePath>,
+	/// Index into `nodes` by name, `None` unless the node is a map
+	keys: Option<HashMap<String, usize>>,
+}
+
+impl FileNodePath {
+	/// Creates the root of the navigation, the path starts with the name of the `node` if it has one
+	pub fn new(node: FileNode) -> Self {
+		let path = node.name().unwrap_or_default();
+		Self::with_path(node, path)
+	}
+
+	#[inline]
+	fn with_path(node: FileNode, path: String) -> Self {
+		Self {
+			node,
+			path,
+			children: OnceCell::new(),
+		}
+	}
+
+	/// Path of the node from the root, e.g. `camera.distortion[2]`
+	#[inline]
+	pub fn path(&self) -> &str {
+		&self.path
+	}
+
+	#[inline]
+	pub fn into_inner(self) -> FileNode {
+		self.node
+	}
+
+	/// Same as [FileNode::get_as], but the error contains the full path of the node
+	#[inline]
+	pub fn get_as<T: FromFileNode>(&self) -> Result<T> {
+		T::from_file_node(&self.node, &self.path)
+	}
+
+	/// Returns the child node of a map with the name `key`, it's empty if there is no such node
+	pub fn get(&self, key: &str) -> Result<FileNodePath> {
+		Ok(Self::with_path(self.node.get(key)?, key_path(&self.path, key)))
+	}
+
+	/// Returns the element of a sequence at `idx`, it's empty if the node is not a sequence or `idx` is out of bounds
+	pub fn at(&self, idx: usize) -> Result<FileNodePath> {
+		let node = match i32::try_from(idx) {
+			Ok(i) if self.node.is_seq()? && idx < self.node.size()? => self.node.at(i)?,
+			_ => FileNode::default()?,
+		};
+		Ok(Self::with_path(node, index_path(&self.path, idx)))
+	}
+
+	/// Returns the iterator over the child nodes with their paths
+	pub fn iter(&self) -> impl Iterator<Item = FileNodePath> + '_ {
+		let is_map = self.node.is_map().unwrap_or(false);
+		self.node.iter().enumerate().map(move |(i, node)| {
+			let path = if is_map {
+				key_path(&self.path, &node.name().unwrap_or_default())
+			} else {
+				index_path(&self.path, i)
+			};
+			Self::with_path(node, path)
+		})
+	}
+
+	fn children(&self) -> &Children {
+		self.children.get_or_init(|| {
+			let nodes = self.iter().collect::<Vec<_>>();
+			let keys = self.node.is_map().unwrap_or(false).then(|| {
+				let mut keys = HashMap::with_capacity(nodes.len());
+				for (i, node) in nodes.iter().enumerate() {
+					keys.entry(node.name().unwrap_or_default()).or_insert(i);
+				}
+				keys
+			});
+			Children { nodes, keys }
+		})
+	}
+}
+
+impl From<FileNode> for FileNodePath {
+	#[inline]
+	fn from(node: FileNode) -> Self {
+		Self::new(node)
+	}
+}
+
+impl Index<&str> for FileNodePath {
+	type Output = FileNodePath;
+
+	/// Returns the child node of a map with the name `key`, panics if there is no such node, see [FileNodePath::get] for the
+	/// non-panicking version
+	fn index(&self, key: &str) -> &Self::Output {
+		let children = self.children();
+		match children.keys.as_ref().and_then(|keys| keys.get(key)) {
+			Some(&i) => &children.nodes[i],
+			None => panic!("No FileNode {}", key_path(&self.path, key)),
+		}
+	}
+}
+
+impl Index<usize> for FileNodePath {
+	type Output = FileNodePath;
+
+	/// Returns the element of a sequence at `idx`, panics if the node is not a sequence or `idx` is out of bounds, see
+	/// [FileNodePath::at] for the non-panicking version
+	fn index(&self, idx: usize) -> &Self::Output {
+		let children = self.children();
+		match children.nodes.get(idx) {
+			Some(node) if children.keys.is_none() => node,
+			_ => panic!("No FileNode {}", index_path(&self.path, idx)),
+		}
+	}
+}
+
+impl Deref for FileNodePath {
+	type Target = FileNode;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.node
+	}
+}
+
+/// Types that can be read from a `FileNode` with [FileNode::get_as]
+pub trait FromFileNode: Sized {
+	/// Reads the value from `node`, `path` is the location of the node that is used in the error messages
+	fn from_file_node(node: &FileNode, path: &str) -> Result<Self>;
+}
+
+// The node path helpers are shared with the `persistence` deserializer so that both report the errors in the same way
+
+/// Path of the child node of a map
+pub(crate) fn key_path(parent: &str, key: &str) -> String {
+	if parent.is_empty() {
+		key.to_string()
+	} else {
+		format!("{parent}.{key}")
+	}
+}
+
+/// Path of the element of a sequence
+#[inline]
+pub(crate) fn index_path(parent: &str, idx: usize) -> String {
+	format!("{parent}[{idx}]")
+}
+
+#[inline]
+fn display_path(path: &str) -> &str {
+	if path.is_empty() {
+		"<root>"
+	} else {
+		path
+	}
+}
+
+/// Marks the error message that already contains the node path
+const PATH_MARKER: &str = " (FileNode ";
+
+/// Appends the node path to the error message unless it already has one, e.g. from one of the child nodes
+pub(crate) fn locate(mut e: Error, path: &str) -> Error {
+	if !e.message.contains(PATH_MARKER) {
+		e.message = format!("{}{PATH_MARKER}{})", e.message, display_path(path));
+	}
+	e
+}
+
+pub(crate) fn type_mismatch(node: &FileNode, path: &str, expected: &str) -> Error {
+	let actual = match node.typ().map(|typ| typ & core::FileNode_TYPE_MASK) {
+		Ok(core::FileNode_INT) => "an integer",
+		Ok(core::FileNode_REAL) => "a real number",
+		Ok(core::FileNode_STRING) => "a string",
+		Ok(core::FileNode_SEQ) => "a sequence",
+		Ok(core::FileNode_MAP) => "a map",
+		_ => "empty",
+	};
+	locate(
+		Error::new(core::StsParseError, format!("Expected {expected}, but it's {actual}")),
+		path,
+	)
+}
+
+macro_rules! from_file_node_int {
+	($($typ: ty),+ $(,)?) => {
+		$(
+			impl FromFileNode for $typ {
+				fn from_file_node(node: &FileNode, path: &str) -> Result<Self> {
+					let val = i32::from_file_node(node, path)?;
+					<$typ>::try_from(val).map_err(|_| {
+						let msg = format!("Value {val} doesn't fit into {}", stringify!($typ));
+						locate(Error::new(core::StsOutOfRange, msg), path)
+					})
+				}
+			}
+		)+
+	};
+}
+
+impl FromFileNode for i32 {
+	fn from_file_node(node: &FileNode, path: &str) -> Result<Self> {
+		if node.is_int()? {
+			node.to_i32()
+		} else {
+			Err(type_mismatch(node, path, "an integer"))
+		}
+	}
+}
+
+from_file_node_int!(i8, u8, i16, u16, u32, i64, u64, isize, usize);
+
+impl FromFileNode for f64 {
+	fn from_file_node(node: &FileNode, path: &str) -> Result<Self> {
+		if node.is_real()? || node.is_int()? {
+			node.to_f64()
+		} else {
+			Err(type_mismatch(node, path, "a number"))
+		}
+	}
+}
+
+impl FromFileNode for f32 {
+	#[inline]
+	fn from_file_node(node: &FileNode, path: &str) -> Result<Self> {
+		f64::from_file_node(node, path).map(|val| val as f32)
+	}
+}
+
+impl FromFileNode for bool {
+	fn from_file_node(node: &FileNode, path: &str) -> Result<Self> {
+		// booleans are stored as integers
+		if node.is_int()? {
+			Ok(node.to_i32()? != 0)
+		} else {
+			Err(type_mismatch(node, path, "an integer"))
+		}
+	}
+}
+
+impl FromFileNode for String {
+	fn from_file_node(node: &FileNode, path: &str) -> Result<Self> {
+		if node.is_string()? {
+			node.to_string()
+		} else {
+			Err(type_mismatch(node, path, "a string"))
+		}
+	}
+}
+
+impl FromFileNode for Mat {
+	fn from_file_node(node: &FileNode, path: &str) -> Result<Self> {
+		if node.is_map()? {
+			node.mat().map_err(|e| locate(e, path))
+		} else {
+			Err(type_mismatch(node, path, "a matrix"))
+		}
+	}
+}
+
+impl<T: FromFileNode> FromFileNode for Vec<T> {
+	fn from_file_node(node: &FileNode, path: &str) -> Result<Self> {
+		if node.is_seq()? {
+			node
+				.iter()
+				.enumerate()
+				.map(|(i, elem)| T::from_file_node(&elem, &index_path(path, i)))
+				.collect()
+		} else {
+			Err(type_mismatch(node, path, "a sequence"))
+		}
+	}
+}
+
+/// Reads the sequence of exactly `N` elements, e.g. `[x, y]` for `Point`
+fn fixed_seq<T: FromFileNode, const N: usize>(node: &FileNode, path: &str, expected: &str) -> Result<[T; N]> {
+	if node.is_seq()? && node.size()? == N {
+		let elems = Vec::<T>::from_file_node(node, path)?;
+		<[T; N]>::try_from(elems).map_err(|_| type_mismatch(node, path, expected))
+	} else {
+		Err(type_mismatch(node, path, expected))
+	}
+}
+
+impl<T: FromFileNode> FromFileNode for Point_<T> {
+	fn from_file_node(node: &FileNode, path: &str) -> Result<Self> {
+		let [x, y] = fixed_seq(node, path, "a point [x, y]")?;
+		Ok(Self::new(x, y))
+	}
+}
+
+impl<T: FromFileNode> FromFileNode for Size_<T> {
+	fn from_file_node(node: &FileNode, path: &str) -> Result<Self> {
+		let [width, height] = fixed_seq(node, path, "a size [width, height]")?;
+		Ok(Self::new(width, height))
+	}
+}
+
+impl<T: FromFileNode> FromFileNode for Rect_<T> {
+	fn from_file_node(node: &FileNode, path: &str) -> Result<Self> {
+		let [x, y, width, height] = fixed_seq(node, path, "a rect [x, y, width, height]")?;
+		Ok(Self::new(x, y, width, height))
+	}
+}
+
+impl FromFileNode for KeyPoint {
+	fn from_file_node(node: &FileNode, path: &str) -> Result<Self> {
+		let [x, y, size, angle, response, octave, class_id] =
+			fixed_seq::<f64, 7>(node, path, "a keypoint [x, y, size, angle, response, octave, class_id]")?;
+		KeyPoint::new_coords(
+			x as f32,
+			y as f32,
+			size as f32,
+			angle as f32,
+			response as f32,
+			octave as i32,
+			class_id as i32,
+		)
+	}
+}
+
+impl FromFileNode for DMatch {
+	fn from_file_node(node: &FileNode, path: &str) -> Result<Self> {
+		let [query_idx, train_idx, img_idx, distance] =
+			fixed_seq::<f64, 4>(node, path, "a match [query_idx, train_idx, img_idx, distance]")?;
+		DMatch::new_index(query_idx as i32, train_idx as i32, img_idx as i32, distance as f32)
+	}
+}
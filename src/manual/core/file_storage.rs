@@ -0,0 +1,37 @@
+use std::ffi::c_void;
+use std::os::raw::c_char;
+
+use crate::core::{FileStorage, FileStorage_Mode};
+use crate::traits::{Boxed, OpenCVTypeArg, OpenCVTypeExternContainer};
+use crate::{sys, Result};
+
+impl FileStorage {
+	/// Opens the storage reading the serialized data from memory, `FileStorage_Mode::MEMORY` is added to `flags`
+	///
+	/// Unlike passing the data as `filename` to [FileStorage::new] the data can be non-UTF-8 and contain nul bytes.
+	pub fn from_bytes(data: &[u8], flags: i32, encoding: &str) -> Result<FileStorage> {
+		extern "C" {
+			fn cv_manual_FileStorage_from_bytes(
+				data: *const c_char,
+				len: usize,
+				flags: i32,
+				encoding: *const c_char,
+				ocvrs_return: *mut sys::Result<*mut c_void>,
+			);
+		}
+		extern_container_arg!(encoding);
+		let flags = flags | FileStorage_Mode::MEMORY as i32;
+		return_send!(via ocvrs_return);
+		unsafe {
+			cv_manual_FileStorage_from_bytes(
+				data.as_ptr().cast(),
+				data.len(),
+				flags,
+				encoding.opencv_as_extern(),
+				ocvrs_return.as_mut_ptr(),
+			)
+		}
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { FileStorage::from_raw(ptr) })
+	}
+}
@@ -1,14 +1,22 @@
+use std::convert::TryFrom;
 use std::os::raw::c_void;
 
-use crate::core::{_InputArray, _InputArrayTrait, _InputOutputArray, _InputOutputArrayTrait, _OutputArray, _OutputArrayTrait};
+use super::mat::match_format;
+use crate::core::{
+	self, _InputArray, _InputArrayTrait, _InputArrayTraitConst, _InputOutputArray, _InputOutputArrayTrait, _OutputArray,
+	_OutputArrayTrait, DataType, Mat, MatTraitConst, MatTraitConstManual,
+};
 use crate::traits::Boxed;
-use crate::{input_output_array, sys, Result};
+use crate::{input_output_array, sys, Error, Result};
 
 /// Trait to serve as a replacement for `InputArray` in C++ OpenCV
 ///
 /// You can pass references to the types implementing this trait everywhere where OpenCV API expects
 /// `InputArray` or `InputArrayOfArrays`.
 ///
+/// Slices, arrays and `Vec`s of [DataType] elements are passed without copying as a single row of elements. Slices of `Mat`s
+/// can be passed as `InputArrayOfArrays`, only the `Mat` headers are copied in that case.
+///
 /// More info in [OpenCV docs](https://docs.opencv.org/master/d4/d32/classcv_1_1__InputArray.html#details).
 pub trait ToInputArray {
 	fn input_array(&self) -> Result<_InputArray>;
@@ -21,9 +29,21 @@ input_output_array! { f64, from_f64 }
 /// You can pass reference to the type implementing this trait everywhere where OpenCV API expects
 /// `OutputArray` or `OutputArrayOfArrays`.
 ///
+/// `Vec`s of [DataType] elements can also be used as an output. OpenCV writes to the temporary `Mat` of the matching element
+/// type and its contents replace the contents of the `Vec` after the successful call. If the call fails, the `Vec` is left
+/// untouched.
+///
 /// More info in [OpenCV docs](https://docs.opencv.org/master/d2/d9e/classcv_1_1__OutputArray.html#details).
 pub trait ToOutputArray {
 	fn output_array(&mut self) -> Result<_OutputArray>;
+
+	/// Called after the successful call of the OpenCV function with the `array` returned by [ToOutputArray::output_array]
+	///
+	/// The implementations that don't write to the referenced object directly can pass the output back here.
+	#[inline]
+	fn output_array_finish(&mut self, _array: &_OutputArray) -> Result<()> {
+		Ok(())
+	}
 }
 
 /// Trait to serve as a replacement for `InputOutputArray` in C++ OpenCV
@@ -75,6 +95,140 @@ impl<T: _InputOutputArrayTrait> ToInputOutputArray for T {
 	}
 }
 
+/// Creates an `_InputArray` referencing the elements of the slice without copying them
+fn slice_input_array<T: DataType>(s: &[T]) -> Result<_InputArray> {
+	extern "C" {
+		fn cv_manual_InputArray_from_slice(typ: i32, data: *const c_void, len: i32, ocvrs_return: *mut sys::Result<*mut c_void>);
+	}
+	let len = i32::try_from(s.len()).map_err(|_| {
+		Error::new(
+			core::StsOutOfRange,
+			format!("Slice is too long to be used as InputArray: {}", s.len()),
+		)
+	})?;
+	return_send!(via ocvrs_return);
+	unsafe { cv_manual_InputArray_from_slice(T::opencv_type(), s.as_ptr().cast(), len, ocvrs_return.as_mut_ptr()) }
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result().map(|ptr| unsafe { _InputArray::from_raw(ptr) })
+}
+
+/// Creates an `_InputArray` of kind `STD_VECTOR_MAT` from the `Mat` pointers, only the headers are copied
+fn mat_slice_input_array(mats: &[*const c_void]) -> Result<_InputArray> {
+	extern "C" {
+		fn cv_manual_InputArray_from_mat_slice(mats: *const *const c_void, len: usize, ocvrs_return: *mut sys::Result<*mut c_void>);
+	}
+	return_send!(via ocvrs_return);
+	unsafe { cv_manual_InputArray_from_mat_slice(mats.as_ptr(), mats.len(), ocvrs_return.as_mut_ptr()) }
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result().map(|ptr| unsafe { _InputArray::from_raw(ptr) })
+}
+
+impl<T: DataType> ToInputArray for &[T] {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		slice_input_array(self)
+	}
+}
+
+impl<T: DataType> ToInputArray for Vec<T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		slice_input_array(self)
+	}
+}
+
+impl<T: DataType> ToInputArray for &Vec<T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		slice_input_array(self)
+	}
+}
+
+impl<T: DataType, const N: usize> ToInputArray for [T; N] {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		slice_input_array(self)
+	}
+}
+
+impl<T: DataType, const N: usize> ToInputArray for &[T; N] {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		slice_input_array(*self)
+	}
+}
+
+impl ToInputArray for &[Mat] {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		let mats = self.iter().map(|m| m.as_raw_Mat()).collect::<Vec<_>>();
+		mat_slice_input_array(&mats)
+	}
+}
+
+impl ToInputArray for &[&Mat] {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		let mats = self.iter().map(|m| m.as_raw_Mat()).collect::<Vec<_>>();
+		mat_slice_input_array(&mats)
+	}
+}
+
+/// Creates an `_OutputArray` referencing the temporary `Mat` with the fixed element type of the `Vec`
+fn vec_output_array<T: DataType>() -> Result<_OutputArray> {
+	extern "C" {
+		fn cv_manual_OutputArray_for_vec(typ: i32, ocvrs_return: *mut sys::Result<*mut c_void>);
+	}
+	return_send!(via ocvrs_return);
+	unsafe { cv_manual_OutputArray_for_vec(T::opencv_type(), ocvrs_return.as_mut_ptr()) }
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result().map(|ptr| unsafe { _OutputArray::from_raw(ptr) })
+}
+
+/// Replaces the contents of the `Vec` with the contents of the temporary `Mat` referenced by `array`
+fn vec_output_array_finish<T: DataType>(vec: &mut Vec<T>, array: &_OutputArray) -> Result<()> {
+	let mat = array.get_mat(-1)?;
+	if mat.empty() {
+		vec.clear();
+		return Ok(());
+	}
+	// some functions ignore the fixed type of the output, the data can't be reinterpreted in that case
+	match_format::<T>(mat.typ())?;
+	let mat = if mat.is_continuous() {
+		mat
+	} else {
+		mat.try_clone()?
+	};
+	let data = mat.data_typed::<T>()?;
+	vec.clear();
+	vec.extend_from_slice(data);
+	Ok(())
+}
+
+impl<T: DataType> ToOutputArray for Vec<T> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		vec_output_array::<T>()
+	}
+
+	#[inline]
+	fn output_array_finish(&mut self, array: &_OutputArray) -> Result<()> {
+		vec_output_array_finish(self, array)
+	}
+}
+
+impl<T: DataType> ToOutputArray for &mut Vec<T> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		vec_output_array::<T>()
+	}
+
+	#[inline]
+	fn output_array_finish(&mut self, array: &_OutputArray) -> Result<()> {
+		vec_output_array_finish(self, array)
+	}
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! input_output_array {
@@ -132,6 +286,11 @@ macro_rules! output_array_ref_forward {
 			fn output_array(&mut self) -> $crate::Result<$crate::core::_OutputArray> {
 				(*self).output_array()
 			}
+
+			#[inline]
+			fn output_array_finish(&mut self, array: &$crate::core::_OutputArray) -> $crate::Result<()> {
+				(*self).output_array_finish(array)
+			}
 		}
 
 		impl $crate::core::ToInputOutputArray for &mut $type {
@@ -0,0 +1,153 @@
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::panic;
+
+use crate::templ::panic_message;
+use crate::{sys, Result};
+
+type LogSink = unsafe extern "C" fn(
+	level: c_int,
+	tag: *const c_char,
+	file: *const c_char,
+	line: c_int,
+	func: *const c_char,
+	message: *const c_char,
+);
+
+extern "C" {
+	fn cv_manual_utils_logging_set_sink(sink: Option<LogSink>, ocvrs_return: *mut sys::Result<()>);
+}
+
+/// Forwards the OpenCV log messages to the `tracing` (if that feature is enabled) or `log` crate instead of writing them
+/// to stderr
+///
+/// The level, the tag, the source file, line and function of each message are preserved. With `log` the tag becomes part
+/// of the target (e.g. `opencv::imgcodecs`) and the function is attached as the `function` key-value, with `tracing` the
+/// event target is `opencv` and all of those are attached as fields. The messages are still filtered by OpenCV before reaching the sink so use `core::set_log_level()` to let the
+/// more verbose messages through.
+///
+/// Requires OpenCV 4.8+, returns an error for the earlier versions.
+pub fn install_log_sink() -> Result<()> {
+	set_sink(Some(log_sink))
+}
+
+/// Restores the default OpenCV logging to stderr
+pub fn uninstall_log_sink() -> Result<()> {
+	set_sink(None)
+}
+
+fn set_sink(sink: Option<LogSink>) -> Result<()> {
+	return_send!(via ocvrs_return);
+	unsafe { cv_manual_utils_logging_set_sink(sink, ocvrs_return.as_mut_ptr()) }
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result()
+}
+
+/// Level of the message as in `core::LogLevel`
+#[derive(Clone, Copy)]
+enum Level {
+	Error,
+	Warn,
+	Info,
+	Debug,
+	Trace,
+}
+
+impl Level {
+	#[inline]
+	fn from_opencv(level: c_int) -> Self {
+		match level {
+			// LOG_LEVEL_FATAL, LOG_LEVEL_ERROR
+			level if level <= 2 => Self::Error,
+			// LOG_LEVEL_WARNING
+			3 => Self::Warn,
+			// LOG_LEVEL_INFO
+			4 => Self::Info,
+			// LOG_LEVEL_DEBUG
+			5 => Self::Debug,
+			// LOG_LEVEL_VERBOSE
+			_ => Self::Trace,
+		}
+	}
+}
+
+#[inline]
+unsafe fn opt_str<'s>(s: *const c_char) -> Option<&'s str> {
+	if s.is_null() {
+		None
+	} else {
+		CStr::from_ptr(s).to_str().ok().filter(|s| !s.is_empty())
+	}
+}
+
+unsafe extern "C" fn log_sink(
+	level: c_int,
+	tag: *const c_char,
+	file: *const c_char,
+	line: c_int,
+	func: *const c_char,
+	message: *const c_char,
+) {
+	let level = Level::from_opencv(level);
+	let tag = opt_str(tag);
+	let file = opt_str(file);
+	let line = u32::try_from(line).ok().filter(|&line| line > 0);
+	let func = opt_str(func);
+	let message = if message.is_null() {
+		Cow::Borrowed("")
+	} else {
+		CStr::from_ptr(message).to_string_lossy()
+	};
+	// OpenCV messages usually end with a newline
+	let message = message.trim_end();
+	// unwinding into the C++ code is undefined behavior, the message is written to stderr like OpenCV does by default
+	if let Err(payload) = panic::catch_unwind(|| emit(level, tag, file, line, func, message)) {
+		eprintln!(
+			"Rust panic in OpenCV log sink: {}, original message: {message}",
+			panic_message(payload.as_ref())
+		);
+	}
+}
+
+#[cfg(feature = "tracing")]
+fn emit(level: Level, tag: Option<&str>, file: Option<&str>, line: Option<u32>, func: Option<&str>, message: &str) {
+	macro_rules! event {
+		($level: expr) => {
+			tracing::event!(target: "opencv", $level, tag, file, line, function = func, "{}", message)
+		};
+	}
+	match level {
+		Level::Error => event!(tracing::Level::ERROR),
+		Level::Warn => event!(tracing::Level::WARN),
+		Level::Info => event!(tracing::Level::INFO),
+		Level::Debug => event!(tracing::Level::DEBUG),
+		Level::Trace => event!(tracing::Level::TRACE),
+	}
+}
+
+#[cfg(not(feature = "tracing"))]
+fn emit(level: Level, tag: Option<&str>, file: Option<&str>, line: Option<u32>, func: Option<&str>, message: &str) {
+	let level = match level {
+		Level::Error => log::Level::Error,
+		Level::Warn => log::Level::Warn,
+		Level::Info => log::Level::Info,
+		Level::Debug => log::Level::Debug,
+		Level::Trace => log::Level::Trace,
+	};
+	let target = tag.map_or_else(|| "opencv".into(), |tag| format!("opencv::{tag}"));
+	let logger = log::logger();
+	let meta = log::Metadata::builder().level(level).target(&target).build();
+	if logger.enabled(&meta) {
+		let function = func.map(|func| ("function", func));
+		logger.log(
+			&log::Record::builder()
+				.metadata(meta)
+				.file(file)
+				.line(line)
+				.key_values(&function)
+				.args(format_args!("{message}"))
+				.build(),
+		);
+	}
+}
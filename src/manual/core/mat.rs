@@ -1,12 +1,16 @@
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
 use std::ffi::c_void;
 use std::marker::PhantomData;
-use std::ops::Deref;
+use std::ops::{Deref, DerefMut};
 use std::{fmt, slice};
 
 pub use mat_::*;
 
-use crate::core::{MatConstIterator, MatExpr, MatSize, MatStep, Point, Scalar, UMat};
+#[cfg(ocvrs_opencv_branch_4)]
+use crate::core::AccessFlag::{ACCESS_READ, ACCESS_WRITE};
+use crate::core::{Depth, MatConstIterator, MatExpr, MatSize, MatStep, MatType, Point, Scalar, UMat};
+#[cfg(not(ocvrs_opencv_branch_4))]
+use crate::core::{ACCESS_READ, ACCESS_WRITE};
 use crate::platform_types::size_t;
 use crate::prelude::*;
 use crate::{core, input_output_array, sys, Error, Result};
@@ -24,15 +28,13 @@ unsafe fn convert_ptr_mut<'r, T>(r: *mut u8) -> &'r mut T {
 }
 
 #[inline]
-fn match_format<T: DataType>(mat_type: i32) -> Result<()> {
+pub(crate) fn match_format<T: DataType>(mat_type: i32) -> Result<()> {
 	let out_type = T::opencv_type();
 	if mat_type == out_type {
 		Ok(())
 	} else {
-		#[cfg(not(ocvrs_opencv_branch_32))]
-		let mat_type = core::type_to_string(mat_type)?;
-		#[cfg(not(ocvrs_opencv_branch_32))]
-		let out_type = core::type_to_string(out_type)?;
+		let mat_type = MatType::from(mat_type);
+		let out_type = MatType::from(out_type);
 		Err(Error::new(
 			core::StsUnmatchedFormats,
 			format!("Mat type is: {mat_type}, but requested type is: {out_type}"),
@@ -122,6 +124,62 @@ fn col_count_i32(col_count: usize) -> Result<i32> {
 	i32::try_from(col_count).map_err(|_| Error::new(core::StsBadArg, format!("Column count: {col_count} is too high")))
 }
 
+/// Appends the index along the last axis to `idx` and checks the bounds, returns the full index and the size of the last axis
+fn last_axis_idx(mat: &(impl MatTraitConst + ?Sized), idx: &[i32]) -> Result<(Vec<i32>, usize)> {
+	let mut full_idx = Vec::with_capacity(idx.len() + 1);
+	full_idx.extend_from_slice(idx);
+	full_idx.push(0);
+	match_indices(mat, &full_idx)?;
+	let len = mat.mat_size().last().map_or(0, |&len| len as usize);
+	Ok((full_idx, len))
+}
+
+/// Element that can be accessed by [MatTraitConst::at_nd] and [MatTrait::at_nd_mut]
+///
+/// A [DataType] element is accessed with the indices along all the axes. A slice `[T]` of [DataType] elements is the
+/// complete slice along the last axis and is accessed with the indices along all the other axes, e.g. for the NCHW blob
+/// `blob.at_nd::<[f32]>(&[n, c, h])` returns a single row of the image.
+pub trait MatNdElement {
+	#[doc(hidden)]
+	fn at_nd<'m>(mat: &'m (impl MatTraitConst + ?Sized), idx: &[i32]) -> Result<&'m Self>;
+
+	#[doc(hidden)]
+	fn at_nd_mut<'m>(mat: &'m mut (impl MatTrait + ?Sized), idx: &[i32]) -> Result<&'m mut Self>;
+}
+
+impl<T: DataType> MatNdElement for T {
+	#[inline]
+	fn at_nd<'m>(mat: &'m (impl MatTraitConst + ?Sized), idx: &[i32]) -> Result<&'m Self> {
+		match_format::<T>(mat.typ())
+			.and_then(|_| match_indices(mat, idx))
+			.and_then(|_| unsafe { mat.at_nd_unchecked(idx) })
+	}
+
+	#[inline]
+	fn at_nd_mut<'m>(mat: &'m mut (impl MatTrait + ?Sized), idx: &[i32]) -> Result<&'m mut Self> {
+		match_format::<T>(mat.typ()).and_then(|_| match_indices(mat, idx))?;
+		unsafe { mat.at_nd_unchecked_mut(idx) }
+	}
+}
+
+impl<T: DataType> MatNdElement for [T] {
+	#[inline]
+	fn at_nd<'m>(mat: &'m (impl MatTraitConst + ?Sized), idx: &[i32]) -> Result<&'m Self> {
+		match_format::<T>(mat.typ())?;
+		let (full_idx, len) = last_axis_idx(mat, idx)?;
+		mat.ptr_nd(&full_idx)
+			.map(|x| unsafe { slice::from_raw_parts(convert_ptr(x), len) })
+	}
+
+	#[inline]
+	fn at_nd_mut<'m>(mat: &'m mut (impl MatTrait + ?Sized), idx: &[i32]) -> Result<&'m mut Self> {
+		match_format::<T>(mat.typ())?;
+		let (full_idx, len) = last_axis_idx(mat, idx)?;
+		mat.ptr_nd_mut(&full_idx)
+			.map(|x| unsafe { slice::from_raw_parts_mut(convert_ptr_mut(x), len) })
+	}
+}
+
 impl Mat {
 	/// Create new `Mat` from the iterator of known size
 	pub fn from_exact_iter<T: DataType>(s: impl ExactSizeIterator<Item = T>) -> Result<Self> {
@@ -175,6 +233,24 @@ impl Mat {
 		Ok(out)
 	}
 
+	/// Create a new N-dimensional `Mat` with the dimensions specified by `sizes` by copying the data from a single-dimensional
+	/// slice
+	pub fn new_nd_from_slice<T: DataType>(sizes: &[i32], s: &[T]) -> Result<Self> {
+		let total = sizes.iter().try_fold(1usize, |total, &size| {
+			usize::try_from(size).ok().and_then(|size| total.checked_mul(size))
+		});
+		if total != Some(s.len()) {
+			return Err(Error::new(
+				core::StsUnmatchedSizes,
+				format!(
+					"The length of the slice: {} must match the number of elements for the passed sizes: {sizes:?} exactly",
+					s.len()
+				),
+			));
+		}
+		Self::from_slice(s)?.reshape_nd(0, sizes)
+	}
+
 	/// Create a new `Mat` by copying the data from a single-dimensional slice with custom shape
 	#[inline]
 	pub fn from_slice_rows_cols<T: DataType>(s: &[T], row_count: usize, col_count: usize) -> Result<Self> {
@@ -303,16 +379,13 @@ pub(crate) mod mat_forward {
 	}
 
 	#[inline]
-	pub fn at_nd<'s, T: DataType>(mat: &'s (impl MatTraitConst + ?Sized), idx: &[i32]) -> Result<&'s T> {
-		match_format::<T>(mat.typ())
-			.and_then(|_| match_indices(mat, idx))
-			.and_then(|_| unsafe { mat.at_nd_unchecked(idx) })
+	pub fn at_nd<'s, T: MatNdElement + ?Sized>(mat: &'s (impl MatTraitConst + ?Sized), idx: &[i32]) -> Result<&'s T> {
+		T::at_nd(mat, idx)
 	}
 
 	#[inline]
-	pub fn at_nd_mut<'s, T: DataType>(mat: &'s mut (impl MatTrait + ?Sized), idx: &[i32]) -> Result<&'s mut T> {
-		match_format::<T>(mat.typ()).and_then(|_| match_indices(mat, idx))?;
-		unsafe { mat.at_nd_unchecked_mut(idx) }
+	pub fn at_nd_mut<'s, T: MatNdElement + ?Sized>(mat: &'s mut (impl MatTrait + ?Sized), idx: &[i32]) -> Result<&'s mut T> {
+		T::at_nd_mut(mat, idx)
 	}
 }
 
@@ -384,6 +457,57 @@ pub trait MatTraitConstManual: MatTraitConst {
 		self.ptr(row).map(|x| slice::from_raw_parts(convert_ptr(x), width))
 	}
 
+	/// Returns the sizes of all dimensions of the `Mat`, the number of channels is included as the last dimension when it's
+	/// greater than 1
+	fn shape(&self) -> Vec<usize> {
+		let mat_size = self.mat_size();
+		let channels = self.channels();
+		let mut out = Vec::with_capacity(mat_size.len() + 1);
+		out.extend(mat_size.iter().map(|&size| size as usize));
+		if channels > 1 {
+			out.push(channels as usize);
+		}
+		out
+	}
+
+	/// Returns the new header for the `Mat` data with all dimensions of size 1 removed, the data is not copied
+	///
+	/// `Mat` always has at least 2 dimensions so the leading dimensions of size 1 are preserved when necessary.
+	fn squeeze(&self) -> Result<Mat> {
+		let mut sizes = self.mat_size().iter().copied().filter(|&size| size != 1).collect::<Vec<_>>();
+		while sizes.len() < 2 {
+			sizes.insert(0, 1);
+		}
+		self.reshape_nd(0, &sizes)
+	}
+
+	/// Returns the new header for the `Mat` data with a dimension of size 1 inserted at `axis`, the data is not copied
+	fn unsqueeze(&self, axis: usize) -> Result<Mat> {
+		let mut sizes = self.mat_size().to_vec();
+		if axis > sizes.len() {
+			return Err(Error::new(
+				core::StsOutOfRange,
+				format!("Axis: {axis} out of bounds 0..={}", sizes.len()),
+			));
+		}
+		sizes.insert(axis, 1);
+		self.reshape_nd(0, &sizes)
+	}
+
+	/// Returns the copy of the `Mat` with the axes reordered according to `order`, e.g. `[0, 2, 3, 1]` converts NCHW blob to
+	/// NHWC
+	///
+	/// Uses [core::transpose_nd] and requires OpenCV 4.7+.
+	#[cfg(ocvrs_opencv_since_4_7)]
+	fn permute(&self, order: &[i32]) -> Result<Mat>
+	where
+		Self: core::ToInputArray,
+	{
+		let mut out = Mat::default();
+		core::transpose_nd(self, &core::Vector::from_slice(order), &mut out)?;
+		Ok(out)
+	}
+
 	#[inline]
 	fn size(&self) -> Result<core::Size> {
 		extern "C" {
@@ -575,12 +699,8 @@ input_output_array! { Mat, from_mat, from_mat_mut }
 
 impl fmt::Debug for Mat {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		let typ = self.typ();
-		let depth = self.depth();
-		#[cfg(not(ocvrs_opencv_branch_32))]
-		let typ = core::type_to_string(typ).map_err(|_| fmt::Error)?;
-		#[cfg(not(ocvrs_opencv_branch_32))]
-		let depth = core::depth_to_string(depth).map_err(|_| fmt::Error)?;
+		let typ = MatType::from(self.typ()).to_string();
+		let depth = Depth::try_from(self.depth()).map_err(|_| fmt::Error)?.to_string();
 		f.debug_struct("Mat")
 			.field("type", &typ)
 			.field("flags", &self.flags())
@@ -614,6 +734,69 @@ pub trait UMatTraitConstManual: UMatTraitConst {
 
 impl<T: UMatTraitConst> UMatTraitConstManual for T {}
 
+impl UMat {
+	/// Create a new `UMat` by copying the data from a single-dimensional slice
+	pub fn from_slice<T: DataType>(s: &[T]) -> Result<Self> {
+		let mut out = UMat::new(core::UMatUsageFlags::USAGE_DEFAULT);
+		Mat::from_slice(s)?.copy_to(&mut out)?;
+		Ok(out)
+	}
+
+	/// Copies the data of the `UMat` into a new `Vec`, elements of all rows are returned as a flat sequence
+	pub fn to_vec<T: DataType>(&self) -> Result<Vec<T>> {
+		let mapped = self.map_read()?;
+		if mapped.is_continuous() {
+			mapped.data_typed::<T>().map(|data| data.to_vec())
+		} else {
+			mapped.try_clone()?.data_typed::<T>().map(|data| data.to_vec())
+		}
+	}
+
+	/// Maps the data of the `UMat` for reading on the host, the mapping is released when the returned guard is dropped
+	#[inline]
+	pub fn map_read(&self) -> Result<UMatMapGuard> {
+		self.get_mat(ACCESS_READ).map(|mat| UMatMapGuard { mat, _umat: PhantomData })
+	}
+
+	/// Maps the data of the `UMat` for writing on the host, the changes are visible in the `UMat` after the returned guard is
+	/// dropped
+	#[inline]
+	pub fn map_write(&mut self) -> Result<UMatMapGuard> {
+		self.get_mat(ACCESS_WRITE).map(|mat| UMatMapGuard { mat, _umat: PhantomData })
+	}
+}
+
+/// Host mapping of the `UMat` data created by [UMat::map_read] or [UMat::map_write]
+///
+/// Derefs to the `Mat` view of the mapped data and borrows the source `UMat` for as long as the mapping is alive.
+pub struct UMatMapGuard<'u> {
+	mat: Mat,
+	_umat: PhantomData<&'u UMat>,
+}
+
+impl Deref for UMatMapGuard<'_> {
+	type Target = Mat;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.mat
+	}
+}
+
+impl DerefMut for UMatMapGuard<'_> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.mat
+	}
+}
+
+impl fmt::Debug for UMatMapGuard<'_> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_tuple("UMatMapGuard").field(&self.mat).finish()
+	}
+}
+
 input_output_array! { UMat, from_umat, from_umat_mut }
 
 #[cfg(ocvrs_opencv_branch_32)]
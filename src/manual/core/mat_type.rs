@@ -0,0 +1,230 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::core::{DataType, CV_MAKETYPE};
+use crate::{core, Error, Result};
+
+/// Depth of the single channel of the `Mat` element
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum Depth {
+	U8 = 0,
+	I8 = 1,
+	U16 = 2,
+	I16 = 3,
+	I32 = 4,
+	F32 = 5,
+	F64 = 6,
+	/// Only available in OpenCV 4, the same value is `CV_USRTYPE1` in OpenCV 3
+	#[cfg(ocvrs_opencv_branch_4)]
+	F16 = 7,
+}
+
+impl Depth {
+	/// Size of the single channel in bytes
+	#[inline]
+	pub const fn size(self) -> usize {
+		match self {
+			Self::U8 | Self::I8 => 1,
+			Self::U16 | Self::I16 => 2,
+			#[cfg(ocvrs_opencv_branch_4)]
+			Self::F16 => 2,
+			Self::I32 | Self::F32 => 4,
+			Self::F64 => 8,
+		}
+	}
+
+	#[inline]
+	const fn suffix(self) -> &'static str {
+		match self {
+			Self::U8 => "8U",
+			Self::I8 => "8S",
+			Self::U16 => "16U",
+			Self::I16 => "16S",
+			Self::I32 => "32S",
+			Self::F32 => "32F",
+			Self::F64 => "64F",
+			#[cfg(ocvrs_opencv_branch_4)]
+			Self::F16 => "16F",
+		}
+	}
+
+	fn from_suffix(s: &str) -> Option<Self> {
+		Some(match s {
+			"8U" => Self::U8,
+			"8S" => Self::I8,
+			"16U" => Self::U16,
+			"16S" => Self::I16,
+			"32S" => Self::I32,
+			"32F" => Self::F32,
+			"64F" => Self::F64,
+			#[cfg(ocvrs_opencv_branch_4)]
+			"16F" => Self::F16,
+			_ => return None,
+		})
+	}
+}
+
+impl TryFrom<i32> for Depth {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(depth: i32) -> Result<Self> {
+		Ok(match depth {
+			0 => Self::U8,
+			1 => Self::I8,
+			2 => Self::U16,
+			3 => Self::I16,
+			4 => Self::I32,
+			5 => Self::F32,
+			6 => Self::F64,
+			#[cfg(ocvrs_opencv_branch_4)]
+			7 => Self::F16,
+			_ => return Err(Error::new(core::StsBadArg, format!("Invalid Mat depth: {depth}"))),
+		})
+	}
+}
+
+impl From<Depth> for i32 {
+	#[inline]
+	fn from(depth: Depth) -> Self {
+		depth as i32
+	}
+}
+
+impl fmt::Display for Depth {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CV_{}", self.suffix())
+	}
+}
+
+/// Type of the `Mat` element as produced by `CV_MAKETYPE()`, combines the depth and the number of channels
+///
+/// It's accepted by all functions that take the element type, e.g. `Mat::new_rows_cols()` or `Mat::convert_to()`, plain
+/// `i32` values like `CV_8UC3` are still accepted there too.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct MatType(i32);
+
+impl MatType {
+	/// Wraps the raw OpenCV type value
+	#[inline]
+	pub const fn new(typ: i32) -> Self {
+		Self(typ)
+	}
+
+	/// Combines the depth and the number of channels, see `CV_MAKETYPE()`
+	#[inline]
+	pub const fn from_depth_channels(depth: Depth, channels: i32) -> Self {
+		Self(CV_MAKETYPE(depth as i32, channels))
+	}
+
+	/// Type of the `Mat` element for the Rust type `T`
+	#[inline]
+	pub fn of<T: DataType>() -> Self {
+		Self(T::opencv_type())
+	}
+
+	/// Raw OpenCV type value
+	#[inline]
+	pub const fn get(self) -> i32 {
+		self.0
+	}
+
+	/// Checks that the raw value is within the range of the values produced by `CV_MAKETYPE()`, e.g. `-1` that some
+	/// functions accept to mean "same as input" is not a valid type
+	#[inline]
+	pub const fn is_valid(self) -> bool {
+		self.0 & !core::CV_MAT_TYPE_MASK == 0
+	}
+
+	/// Depth of the single channel, see `CV_MAT_DEPTH()`
+	#[inline]
+	pub fn depth(self) -> Result<Depth> {
+		if !self.is_valid() {
+			return Err(Error::new(core::StsBadArg, format!("Invalid Mat type: {}", self.0)));
+		}
+		Depth::try_from(self.0 & core::Mat_DEPTH_MASK)
+	}
+
+	/// Number of channels, see `CV_MAT_CN()`, the result is meaningless if the type is not [valid](Self::is_valid)
+	#[inline]
+	pub const fn channels(self) -> i32 {
+		((self.0 & core::CV_MAT_CN_MASK) >> core::CV_CN_SHIFT) + 1
+	}
+
+	/// Size of the whole element in bytes, see `Mat::elem_size()`
+	#[inline]
+	pub fn elem_size(self) -> Result<usize> {
+		self.elem_size1().map(|size| size * self.channels() as usize)
+	}
+
+	/// Size of the single channel of the element in bytes, see `Mat::elem_size1()`
+	#[inline]
+	pub fn elem_size1(self) -> Result<usize> {
+		self.depth().map(Depth::size)
+	}
+}
+
+impl From<i32> for MatType {
+	#[inline]
+	fn from(typ: i32) -> Self {
+		Self(typ)
+	}
+}
+
+impl From<Depth> for MatType {
+	#[inline]
+	fn from(depth: Depth) -> Self {
+		Self::from_depth_channels(depth, 1)
+	}
+}
+
+impl From<MatType> for i32 {
+	#[inline]
+	fn from(typ: MatType) -> Self {
+		typ.0
+	}
+}
+
+impl fmt::Display for MatType {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if !self.is_valid() {
+			return write!(f, "invalid type {}", self.0);
+		}
+		match self.depth() {
+			Ok(depth) => write!(f, "CV_{}C{}", depth.suffix(), self.channels()),
+			Err(_) => write!(f, "{}", self.0),
+		}
+	}
+}
+
+impl fmt::Debug for MatType {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "MatType({self})")
+	}
+}
+
+impl FromStr for MatType {
+	type Err = Error;
+
+	/// Parses the type name like `CV_8UC3`, the `CV_` prefix and the channel count suffix are optional, e.g. `32F`
+	/// gives `CV_32FC1`
+	fn from_str(s: &str) -> Result<Self> {
+		let err = || Error::new(core::StsBadArg, format!("Invalid Mat type: {s}"));
+		let name = s.strip_prefix("CV_").unwrap_or(s);
+		let (depth, channels) = match name.find('C') {
+			Some(pos) => {
+				let channels = name[pos + 1..].parse::<i32>().map_err(|_| err())?;
+				(&name[..pos], channels)
+			}
+			None => (name, 1),
+		};
+		if !(1..=core::CV_CN_MAX).contains(&channels) {
+			return Err(err());
+		}
+		let depth = Depth::from_suffix(depth).ok_or_else(err)?;
+		Ok(Self::from_depth_channels(depth, channels))
+	}
+}
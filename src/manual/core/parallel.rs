@@ -0,0 +1,53 @@
+use std::any::Any;
+use std::mem;
+use std::ops::Range;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+
+use crate::core::{self, ParallelLoopBody, ParallelLoopBodyDirector, RangeTraitConst};
+use crate::{Error, Result};
+
+struct ClosureBody<'f, F> {
+	body: &'f F,
+	panic: &'f Mutex<Option<Box<dyn Any + Send>>>,
+}
+
+impl<F: Fn(Range<i32>) -> Result<()> + Sync> ParallelLoopBodyDirector for ClosureBody<'_, F> {
+	fn apply(&self, range: &core::Range) -> Result<()> {
+		let range = range.start()..range.end();
+		panic::catch_unwind(AssertUnwindSafe(|| (self.body)(range))).unwrap_or_else(|payload| {
+			// only the first panic is kept, it's resumed in the calling thread once all the stripes are done
+			let mut panic = self.panic.lock().unwrap_or_else(|e| e.into_inner());
+			if panic.is_none() {
+				*panic = Some(payload);
+			}
+			Err(Error::new(core::StsError, "parallel_for body panicked"))
+		})
+	}
+}
+
+/// Runs `body` for the sub-ranges of `range` in parallel using the OpenCV thread pool, see `cv::parallel_for_()`
+///
+/// The number of threads is controlled by `core::set_num_threads()`. `nstripes` is the desired number of the sub-ranges, pass
+/// `-1.` to let OpenCV decide. If any of the sub-range calls returns an error, that error is returned once all the threads
+/// are done. If any of them panics then the panic is resumed in the calling thread.
+pub fn parallel_for<F>(range: Range<i32>, nstripes: f64, body: F) -> Result<()>
+where
+	F: Fn(Range<i32>) -> Result<()> + Sync,
+{
+	let panic = Mutex::new(None);
+	let director = Box::new(ClosureBody {
+		body: &body,
+		panic: &panic,
+	}) as Box<dyn ParallelLoopBodyDirector + '_>;
+	// safe because `cv::parallel_for_()` doesn't keep the body after it returns and `loop_body` is dropped before `body`
+	let director =
+		unsafe { mem::transmute::<Box<dyn ParallelLoopBodyDirector + '_>, Box<dyn ParallelLoopBodyDirector + 'static>>(director) };
+	let loop_body = ParallelLoopBody::from_director(director);
+	let res = core::parallel_for_(&core::Range::new(range.start, range.end)?, &loop_body, nstripes);
+	drop(loop_body);
+	if let Some(payload) = panic.into_inner().unwrap_or_else(|e| e.into_inner()) {
+		panic::resume_unwind(payload);
+	}
+	res
+}
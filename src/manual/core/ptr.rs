@@ -44,6 +44,24 @@ where
 	pub fn inner_as_raw_mut(&mut self) -> *mut c_void {
 		unsafe { self.extern_inner_as_ptr_mut() }
 	}
+
+	/// Returns `true` if both `Ptr`s point to the same object, like comparing 2 `cv::Ptr`s in C++
+	#[inline]
+	pub fn ptr_eq(&self, other: &Self) -> bool {
+		self.inner_as_raw() == other.inner_as_raw()
+	}
+}
+
+/// Creates a new `Ptr` that shares the ownership of the same object, the reference count is increased like when copying
+/// `cv::Ptr` in C++
+impl<T: ?Sized> Clone for Ptr<T>
+where
+	Self: PtrExtern,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		unsafe { Self::from_raw(self.extern_clone()) }
+	}
 }
 
 impl<T: ?Sized> Boxed for Ptr<T>
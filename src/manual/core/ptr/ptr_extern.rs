@@ -11,6 +11,8 @@ pub trait PtrExtern {
 	unsafe fn extern_inner_as_ptr(&self) -> *const c_void;
 	#[doc(hidden)]
 	unsafe fn extern_inner_as_ptr_mut(&mut self) -> *mut c_void;
+	#[doc(hidden)]
+	unsafe fn extern_clone(&self) -> *mut c_void;
 }
 
 #[doc(hidden)]
@@ -24,7 +26,7 @@ pub trait PtrExternCtor<T: OpenCVTypeExternContainerMove> {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! ptr_extern {
-	($type: ty, $extern_delete: ident, $extern_inner_as_ptr: ident, $extern_inner_as_ptr_mut: ident $(,)?) => {
+	($type: ty, $extern_delete: ident, $extern_inner_as_ptr: ident, $extern_inner_as_ptr_mut: ident, $extern_clone: ident $(,)?) => {
 		impl $crate::core::PtrExtern for $crate::core::Ptr<$type> {
 			#[inline]
 			unsafe fn extern_delete(&mut self) {
@@ -40,6 +42,11 @@ macro_rules! ptr_extern {
 			unsafe fn extern_inner_as_ptr_mut(&mut self) -> *mut std::ffi::c_void {
 				$crate::sys::$extern_inner_as_ptr_mut(self.as_raw_mut()) as *mut _
 			}
+
+			#[inline]
+			unsafe fn extern_clone(&self) -> *mut std::ffi::c_void {
+				$crate::sys::$extern_clone(self.as_raw())
+			}
 		}
 	};
 }
@@ -71,3 +78,30 @@ macro_rules! ptr_cast_base {
 		}
 	};
 }
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ptr_cast_descendant {
+	($type: ty, $descendant: ty, $extern_convert: ident) => {
+		impl ::std::convert::TryFrom<$type> for $descendant {
+			type Error = $crate::Error;
+
+			#[inline]
+			fn try_from(mut s: $type) -> $crate::Result<Self> {
+				let ret = unsafe { $crate::sys::$extern_convert(s.as_raw_mut()) };
+				if ret.is_null() {
+					Err($crate::Error::new(
+						$crate::core::StsBadArg,
+						format!(
+							"Unable to cast base class: {} to: {}",
+							stringify!($type),
+							stringify!($descendant)
+						),
+					))
+				} else {
+					Ok(unsafe { Self::from_raw(ret) })
+				}
+			}
+		}
+	};
+}
@@ -0,0 +1,100 @@
+use std::ffi::c_void;
+use std::os::raw::c_int;
+use std::sync::Arc;
+
+use rayon::prelude::*;
+use rayon::ThreadPool;
+
+use crate::{sys, Result};
+
+type BodyCallback = unsafe extern "C" fn(start: c_int, end: c_int, data: *mut c_void);
+
+/// Pointer to the loop body data that is shared between the rayon threads for the duration of the call
+struct BodyData(*mut c_void);
+
+impl BodyData {
+	#[inline]
+	fn get(&self) -> *mut c_void {
+		self.0
+	}
+}
+
+unsafe impl Send for BodyData {}
+
+unsafe impl Sync for BodyData {}
+
+/// Makes OpenCV run all of its internal parallel loops (e.g. in `imgproc::resize()` or `dnn`) on the global rayon thread pool
+/// instead of its own, see `cv::parallel::setParallelForBackend()`
+///
+/// The number of threads is then controlled by rayon, `core::set_num_threads()` has no effect. Requires OpenCV 4.5.2+.
+pub fn use_rayon_parallel_backend() -> Result<()> {
+	set_rayon_backend(None)
+}
+
+/// Same as [use_rayon_parallel_backend], but runs the OpenCV parallel loops on the passed rayon thread pool
+pub fn use_rayon_parallel_backend_with_pool(pool: Arc<ThreadPool>) -> Result<()> {
+	set_rayon_backend(Some(pool))
+}
+
+fn set_rayon_backend(pool: Option<Arc<ThreadPool>>) -> Result<()> {
+	extern "C" {
+		fn cv_manual_parallel_setRayonBackend(
+			pool: *mut c_void,
+			parallel_for: unsafe extern "C" fn(*const c_void, c_int, BodyCallback, *mut c_void),
+			thread_num: unsafe extern "C" fn(*const c_void) -> c_int,
+			num_threads: unsafe extern "C" fn(*const c_void) -> c_int,
+			delete_pool: unsafe extern "C" fn(*mut c_void),
+			ocvrs_return: *mut sys::Result<()>,
+		);
+	}
+	let pool = Box::into_raw(Box::new(pool)) as *mut c_void;
+	return_send!(via ocvrs_return);
+	unsafe {
+		cv_manual_parallel_setRayonBackend(
+			pool,
+			rayon_parallel_for,
+			rayon_thread_num,
+			rayon_num_threads,
+			rayon_delete_pool,
+			ocvrs_return.as_mut_ptr(),
+		)
+	}
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result()
+}
+
+#[inline]
+unsafe fn pool_ref<'p>(pool: *const c_void) -> &'p Option<Arc<ThreadPool>> {
+	&*(pool as *const Option<Arc<ThreadPool>>)
+}
+
+unsafe extern "C" fn rayon_parallel_for(pool: *const c_void, tasks: c_int, body: BodyCallback, data: *mut c_void) {
+	let data = BodyData(data);
+	// the body callback never unwinds, the exceptions are handled on the C++ side
+	let run = || (0..tasks).into_par_iter().for_each(|i| body(i, i + 1, data.get()));
+	match pool_ref(pool) {
+		Some(pool) => pool.install(run),
+		None => run(),
+	}
+}
+
+unsafe extern "C" fn rayon_thread_num(pool: *const c_void) -> c_int {
+	let idx = match pool_ref(pool) {
+		Some(pool) => pool.current_thread_index(),
+		None => rayon::current_thread_index(),
+	};
+	// threads outside of the pool are reported as the first one, like with the sequential backend
+	idx.map_or(0, |idx| idx as c_int)
+}
+
+unsafe extern "C" fn rayon_num_threads(pool: *const c_void) -> c_int {
+	let num = match pool_ref(pool) {
+		Some(pool) => pool.current_num_threads(),
+		None => rayon::current_num_threads(),
+	};
+	num as c_int
+}
+
+unsafe extern "C" fn rayon_delete_pool(pool: *mut c_void) {
+	drop(Box::from_raw(pool as *mut Option<Arc<ThreadPool>>));
+}
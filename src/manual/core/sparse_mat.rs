@@ -0,0 +1,234 @@
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::{slice, vec};
+
+use super::mat::match_format;
+use crate::core::{DataType, Mat, SparseMat, SparseMatTraitConst};
+use crate::traits::Boxed;
+use crate::{core, sys, Error, Result};
+
+impl SparseMat {
+	/// Create a new `SparseMat` with the dimensions specified by `sizes` from the `(index, value)` pairs of the non-zero
+	/// elements
+	///
+	/// When the same index is specified multiple times the last value is used. Explicit zero values are not stored, same as
+	/// in [SparseMat::from_mat], a zero value also erases the element previously set at the same index. Floating point `-0.`
+	/// is not considered zero because OpenCV checks the bytes of the element.
+	pub fn from_triplets<T: DataType, I: AsRef<[i32]>>(sizes: &[i32], triplets: impl IntoIterator<Item = (I, T)>) -> Result<Self> {
+		extern "C" {
+			fn cv_manual_SparseMat_from_triplets(
+				dims: i32,
+				sizes: *const i32,
+				typ: i32,
+				idx: *const i32,
+				values: *const c_void,
+				count: usize,
+				ocvrs_return: *mut sys::Result<*mut c_void>,
+			);
+		}
+		let dims = sizes.len();
+		let triplets = triplets.into_iter();
+		let (count_hint, _) = triplets.size_hint();
+		let mut idx = Vec::with_capacity(count_hint * dims);
+		let mut values = Vec::with_capacity(count_hint);
+		for (i, val) in triplets {
+			let i = i.as_ref();
+			if i.len() != dims {
+				return Err(Error::new(
+					core::StsUnmatchedSizes,
+					format!("Index: {i:?} has {} dimensions, but SparseMat has: {dims}", i.len()),
+				));
+			}
+			if let Some((out_dim, (_, out_size))) = i.iter().zip(sizes).enumerate().find(|(_, (&i, &size))| i < 0 || i >= size) {
+				return Err(Error::new(
+					core::StsOutOfRange,
+					format!("Index: {i:?} along dimension: {out_dim} out of bounds 0..{out_size}"),
+				));
+			}
+			idx.extend_from_slice(i);
+			values.push(val);
+		}
+		return_send!(via ocvrs_return);
+		unsafe {
+			cv_manual_SparseMat_from_triplets(
+				i32::try_from(dims).map_err(|_| Error::new(core::StsOutOfRange, format!("Too many dimensions: {dims}")))?,
+				sizes.as_ptr(),
+				T::opencv_type(),
+				idx.as_ptr(),
+				values.as_ptr().cast(),
+				values.len(),
+				ocvrs_return.as_mut_ptr(),
+			)
+		}
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { Self::from_raw(ptr) })
+	}
+
+	/// Create a new `SparseMat` from the non-zero elements of the dense `Mat`
+	///
+	/// Alias of [SparseMat::from_mat] for symmetry with [SparseMat::to_dense]. OpenCV scans all elements of the `Mat` once
+	/// and stores only the non-zero ones, so the result takes memory proportional to the number of non-zero elements.
+	#[inline]
+	pub fn from_dense(m: &Mat) -> Result<Self> {
+		Self::from_mat(m)
+	}
+
+	/// Converts the `SparseMat` to a newly allocated dense `Mat`
+	///
+	/// Alias of [SparseMatTraitConst::copy_to_mat] with a new `Mat`. OpenCV allocates the zero-filled `Mat` of the full size
+	/// and then copies only the non-zero elements into it.
+	#[inline]
+	pub fn to_dense(&self) -> Result<Mat> {
+		let mut out = Mat::default();
+		self.copy_to_mat(&mut out)?;
+		Ok(out)
+	}
+
+	/// Returns iterator over the non-zero elements of the `SparseMat` and their indices
+	///
+	/// The elements are fetched in a single call and are returned in the internal hash table order.
+	pub fn iter<T: DataType>(&self) -> Result<SparseMatIter<T>> {
+		extern "C" {
+			fn cv_manual_SparseMat_nodes(
+				instance: *const c_void,
+				idx: *mut *const i32,
+				values: *mut *const u8,
+				count: usize,
+				ocvrs_return: *mut sys::Result<usize>,
+			);
+		}
+		match_format::<T>(self.typ())?;
+		let dims = self.dims()? as usize;
+		let count = self.nzcount()?;
+		let mut idx = Vec::with_capacity(count);
+		let mut values = Vec::with_capacity(count);
+		return_send!(via ocvrs_return);
+		unsafe {
+			cv_manual_SparseMat_nodes(
+				self.as_raw_SparseMat(),
+				idx.as_mut_ptr(),
+				values.as_mut_ptr(),
+				count,
+				ocvrs_return.as_mut_ptr(),
+			)
+		}
+		return_receive!(unsafe ocvrs_return => ret);
+		let written = ret.into_result()?;
+		unsafe {
+			idx.set_len(written);
+			values.set_len(written);
+		}
+		Ok(SparseMatIter {
+			dims,
+			nodes: idx.into_iter().zip(values.into_iter()),
+			_d: PhantomData,
+		})
+	}
+}
+
+/// Iterator over the non-zero elements of the [SparseMat] and their indices, see [SparseMat::iter]
+pub struct SparseMatIter<'m, T> {
+	dims: usize,
+	nodes: std::iter::Zip<vec::IntoIter<*const i32>, vec::IntoIter<*const u8>>,
+	_d: PhantomData<&'m T>,
+}
+
+impl<'m, T: DataType> Iterator for SparseMatIter<'m, T> {
+	type Item = (&'m [i32], T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.nodes.next().map(|(idx, value)| {
+			// safe because the node pointers stay valid while the source SparseMat is borrowed
+			unsafe { (slice::from_raw_parts(idx, self.dims), value.cast::<T>().read_unaligned()) }
+		})
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.nodes.size_hint()
+	}
+}
+
+impl<T: DataType> ExactSizeIterator for SparseMatIter<'_, T> {}
+
+#[cfg(feature = "sprs")]
+mod sprs_interop {
+	use sprs::{CsMat, CsMatViewI, SpIndex};
+
+	use super::*;
+
+	impl SparseMat {
+		/// Create a new 2-dimensional `SparseMat` from the `sprs` CSR or CSC matrix
+		pub fn from_sprs<T: DataType, I: SpIndex, Iptr: SpIndex>(m: CsMatViewI<T, I, Iptr>) -> Result<Self> {
+			let (rows, cols) = m.shape();
+			let sizes = [dim_size_i32(rows)?, dim_size_i32(cols)?];
+			Self::from_triplets(
+				&sizes,
+				m.iter()
+					.map(|(&val, (row, col))| ([row.index() as i32, col.index() as i32], val)),
+			)
+		}
+
+		/// Converts the 2-dimensional `SparseMat` to the `sprs` CSR matrix
+		#[inline]
+		pub fn to_sprs_csr<T: DataType>(&self) -> Result<CsMat<T>> {
+			self.to_sprs(false)
+		}
+
+		/// Converts the 2-dimensional `SparseMat` to the `sprs` CSC matrix
+		#[inline]
+		pub fn to_sprs_csc<T: DataType>(&self) -> Result<CsMat<T>> {
+			self.to_sprs(true)
+		}
+
+		fn to_sprs<T: DataType>(&self, csc: bool) -> Result<CsMat<T>> {
+			let dims = self.dims()?;
+			if dims != 2 {
+				return Err(Error::new(
+					core::StsUnmatchedSizes,
+					format!("SparseMat dims is: {dims}, but only 2-dimensional matrices can be converted"),
+				));
+			}
+			let shape = (self.size_1(0)? as usize, self.size_1(1)? as usize);
+			let mut nodes = self
+				.iter::<T>()?
+				.map(|(idx, val)| {
+					let (row, col) = (idx[0] as usize, idx[1] as usize);
+					if csc {
+						((col, row), val)
+					} else {
+						((row, col), val)
+					}
+				})
+				.collect::<Vec<_>>();
+			nodes.sort_unstable_by_key(|&(pos, _)| pos);
+			let outer_len = if csc {
+				shape.1
+			} else {
+				shape.0
+			};
+			let mut indptr = vec![0; outer_len + 1];
+			for &((outer, _), _) in &nodes {
+				indptr[outer + 1] += 1;
+			}
+			let mut nnz = 0;
+			for ptr in &mut indptr {
+				nnz += *ptr;
+				*ptr = nnz;
+			}
+			let (indices, data) = nodes.into_iter().map(|((_, inner), val)| (inner, val)).unzip();
+			let out = if csc {
+				CsMat::try_new_csc(shape, indptr, indices, data)
+			} else {
+				CsMat::try_new(shape, indptr, indices, data)
+			};
+			out.map_err(|(_, _, _, e)| Error::new(core::StsError, format!("Can't create sprs matrix: {e}")))
+		}
+	}
+
+	#[inline]
+	fn dim_size_i32(count: usize) -> Result<i32> {
+		i32::try_from(count).map_err(|_| Error::new(core::StsBadArg, format!("Dimension size: {count} is too high")))
+	}
+}
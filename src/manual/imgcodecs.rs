@@ -0,0 +1,402 @@
+use std::path::Path;
+
+use crate::core::{self, Mat, ToInputArray, Vector};
+use crate::imgcodecs;
+use crate::prelude::*;
+use crate::{Error, Result};
+
+/// Image format that the [ImwriteOptions] can be validated against, detected from the file extension
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+	Jpeg,
+	Png,
+	Webp,
+	Tiff,
+	Exr,
+	Avif,
+}
+
+impl ImageFormat {
+	/// Accepts the bare extension (`"jpg"`), the extension with the dot (`".jpg"`, as passed to `imencode()`) or a file name
+	pub fn from_extension(ext: &str) -> Option<Self> {
+		let ext = ext.rsplit('.').next().unwrap_or(ext).to_ascii_lowercase();
+		match ext.as_str() {
+			"jpg" | "jpeg" | "jpe" => Some(Self::Jpeg),
+			"png" => Some(Self::Png),
+			"webp" => Some(Self::Webp),
+			"tif" | "tiff" => Some(Self::Tiff),
+			"exr" => Some(Self::Exr),
+			"avif" => Some(Self::Avif),
+			_ => None,
+		}
+	}
+
+	fn name(self) -> &'static str {
+		match self {
+			Self::Jpeg => "JPEG",
+			Self::Png => "PNG",
+			Self::Webp => "WebP",
+			Self::Tiff => "TIFF",
+			Self::Exr => "OpenEXR",
+			Self::Avif => "AVIF",
+		}
+	}
+}
+
+/// Chroma subsampling of JPEG, see `IMWRITE_JPEG_SAMPLING_FACTOR`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum JpegSubsampling {
+	S411 = 0x411111,
+	S420 = 0x221111,
+	S422 = 0x211111,
+	S440 = 0x121111,
+	S444 = 0x111111,
+}
+
+/// Compression strategy of PNG, see `IMWRITE_PNG_STRATEGY`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PngStrategy {
+	Default = 0,
+	Filtered = 1,
+	HuffmanOnly = 2,
+	Rle = 3,
+	Fixed = 4,
+}
+
+/// Resolution unit of TIFF, see `IMWRITE_TIFF_RESUNIT`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TiffResolutionUnit {
+	None = 1,
+	Inch = 2,
+	Centimeter = 3,
+}
+
+/// Storage type of OpenEXR, see `IMWRITE_EXR_TYPE`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExrType {
+	Half = 1,
+	Float = 2,
+}
+
+/// Compression of OpenEXR, see `IMWRITE_EXR_COMPRESSION`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExrCompression {
+	No = 0,
+	Rle = 1,
+	Zips = 2,
+	Zip = 3,
+	Piz = 4,
+	Pxr24 = 5,
+	B44 = 6,
+	B44a = 7,
+	Dwaa = 8,
+	Dwab = 9,
+}
+
+/// `IMWRITE_*` keys, the generated constants are used when the OpenCV version has them, the options that were added in the
+/// newer versions are defined here for the older ones (the values are stable across versions)
+mod key {
+	pub use crate::imgcodecs::{
+		IMWRITE_JPEG_OPTIMIZE as JPEG_OPTIMIZE, IMWRITE_JPEG_PROGRESSIVE as JPEG_PROGRESSIVE, IMWRITE_JPEG_QUALITY as JPEG_QUALITY,
+		IMWRITE_PNG_COMPRESSION as PNG_COMPRESSION, IMWRITE_PNG_STRATEGY as PNG_STRATEGY, IMWRITE_WEBP_QUALITY as WEBP_QUALITY,
+	};
+
+	// not available in all of the OpenCV 3 releases
+	#[cfg(ocvrs_opencv_branch_4)]
+	pub use crate::imgcodecs::{
+		IMWRITE_EXR_TYPE as EXR_TYPE, IMWRITE_TIFF_RESUNIT as TIFF_RESUNIT, IMWRITE_TIFF_XDPI as TIFF_XDPI,
+		IMWRITE_TIFF_YDPI as TIFF_YDPI,
+	};
+	#[cfg(not(ocvrs_opencv_branch_4))]
+	pub const EXR_TYPE: i32 = 48;
+	#[cfg(not(ocvrs_opencv_branch_4))]
+	pub const TIFF_RESUNIT: i32 = 256;
+	#[cfg(not(ocvrs_opencv_branch_4))]
+	pub const TIFF_XDPI: i32 = 257;
+	#[cfg(not(ocvrs_opencv_branch_4))]
+	pub const TIFF_YDPI: i32 = 258;
+
+	// not available in the early OpenCV 4 releases
+	#[cfg(ocvrs_opencv_since_4_5)]
+	pub use crate::imgcodecs::IMWRITE_TIFF_COMPRESSION as TIFF_COMPRESSION;
+	#[cfg(not(ocvrs_opencv_since_4_5))]
+	pub const TIFF_COMPRESSION: i32 = 259;
+
+	// added in OpenCV 4.5.5
+	#[cfg(ocvrs_opencv_since_4_6)]
+	pub use crate::imgcodecs::{IMWRITE_EXR_COMPRESSION as EXR_COMPRESSION, IMWRITE_JPEG_SAMPLING_FACTOR as JPEG_SAMPLING_FACTOR};
+	#[cfg(not(ocvrs_opencv_since_4_6))]
+	pub const EXR_COMPRESSION: i32 = 49;
+	#[cfg(not(ocvrs_opencv_since_4_6))]
+	pub const JPEG_SAMPLING_FACTOR: i32 = 7;
+
+	// added in OpenCV 4.7
+	#[cfg(ocvrs_opencv_since_4_7)]
+	pub use crate::imgcodecs::{
+		IMWRITE_AVIF_DEPTH as AVIF_DEPTH, IMWRITE_AVIF_QUALITY as AVIF_QUALITY, IMWRITE_AVIF_SPEED as AVIF_SPEED,
+	};
+	#[cfg(not(ocvrs_opencv_since_4_7))]
+	pub const AVIF_QUALITY: i32 = 512;
+	#[cfg(not(ocvrs_opencv_since_4_7))]
+	pub const AVIF_DEPTH: i32 = 513;
+	#[cfg(not(ocvrs_opencv_since_4_7))]
+	pub const AVIF_SPEED: i32 = 514;
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Param {
+	format: ImageFormat,
+	name: &'static str,
+	key: i32,
+	value: i32,
+	min: i32,
+	max: i32,
+}
+
+/// Typed `IMWRITE_*` parameters for `imwrite()` and `imencode()`
+///
+/// Every option belongs to a particular image format, using the options with a file of a different format (e.g. PNG
+/// compression when writing `.jpg`) or a value out of the allowed range is reported as an error instead of being silently
+/// ignored by OpenCV.
+#[derive(Clone, Debug, Default)]
+pub struct ImwriteOptions {
+	params: Vec<Param>,
+}
+
+impl ImwriteOptions {
+	#[inline]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	fn param(mut self, format: ImageFormat, name: &'static str, key: i32, value: i32, min: i32, max: i32) -> Self {
+		let param = Param {
+			format,
+			name,
+			key,
+			value,
+			min,
+			max,
+		};
+		match self.params.iter_mut().find(|p| p.key == key) {
+			Some(p) => *p = param,
+			None => self.params.push(param),
+		}
+		self
+	}
+
+	/// For the options with the typed values that don't need the range check
+	#[inline]
+	fn param_typed(self, format: ImageFormat, name: &'static str, key: i32, value: i32) -> Self {
+		self.param(format, name, key, value, i32::MIN, i32::MAX)
+	}
+
+	/// JPEG quality (0..=100, default 95), `IMWRITE_JPEG_QUALITY`
+	#[inline]
+	pub fn jpeg_quality(self, quality: i32) -> Self {
+		self.param(ImageFormat::Jpeg, "IMWRITE_JPEG_QUALITY", key::JPEG_QUALITY, quality, 0, 100)
+	}
+
+	/// Enables progressive JPEG, `IMWRITE_JPEG_PROGRESSIVE`
+	#[inline]
+	pub fn jpeg_progressive(self, progressive: bool) -> Self {
+		self.param(
+			ImageFormat::Jpeg,
+			"IMWRITE_JPEG_PROGRESSIVE",
+			key::JPEG_PROGRESSIVE,
+			i32::from(progressive),
+			0,
+			1,
+		)
+	}
+
+	/// Enables JPEG Huffman table optimization, `IMWRITE_JPEG_OPTIMIZE`
+	#[inline]
+	pub fn jpeg_optimize(self, optimize: bool) -> Self {
+		self.param(
+			ImageFormat::Jpeg,
+			"IMWRITE_JPEG_OPTIMIZE",
+			key::JPEG_OPTIMIZE,
+			i32::from(optimize),
+			0,
+			1,
+		)
+	}
+
+	/// JPEG chroma subsampling, `IMWRITE_JPEG_SAMPLING_FACTOR`, ignored by the older OpenCV versions
+	#[inline]
+	pub fn jpeg_subsampling(self, subsampling: JpegSubsampling) -> Self {
+		self.param_typed(
+			ImageFormat::Jpeg,
+			"IMWRITE_JPEG_SAMPLING_FACTOR",
+			key::JPEG_SAMPLING_FACTOR,
+			subsampling as i32,
+		)
+	}
+
+	/// PNG compression level (0..=9, default 1), higher value means smaller size and longer compression time,
+	/// `IMWRITE_PNG_COMPRESSION`
+	#[inline]
+	pub fn png_compression(self, level: i32) -> Self {
+		self.param(ImageFormat::Png, "IMWRITE_PNG_COMPRESSION", key::PNG_COMPRESSION, level, 0, 9)
+	}
+
+	/// PNG compression strategy, `IMWRITE_PNG_STRATEGY`
+	#[inline]
+	pub fn png_strategy(self, strategy: PngStrategy) -> Self {
+		self.param_typed(ImageFormat::Png, "IMWRITE_PNG_STRATEGY", key::PNG_STRATEGY, strategy as i32)
+	}
+
+	/// WebP quality (1..=100), values above 100 select the lossless compression, `IMWRITE_WEBP_QUALITY`
+	#[inline]
+	pub fn webp_quality(self, quality: i32) -> Self {
+		self.param(
+			ImageFormat::Webp,
+			"IMWRITE_WEBP_QUALITY",
+			key::WEBP_QUALITY,
+			quality,
+			1,
+			i32::MAX,
+		)
+	}
+
+	/// TIFF compression scheme, one of the libtiff `COMPRESSION_*` values, e.g. 1 for none or 5 for LZW,
+	/// `IMWRITE_TIFF_COMPRESSION`
+	#[inline]
+	pub fn tiff_compression(self, compression: i32) -> Self {
+		self.param(
+			ImageFormat::Tiff,
+			"IMWRITE_TIFF_COMPRESSION",
+			key::TIFF_COMPRESSION,
+			compression,
+			1,
+			i32::from(u16::MAX),
+		)
+	}
+
+	/// TIFF resolution, `IMWRITE_TIFF_RESUNIT`, `IMWRITE_TIFF_XDPI` and `IMWRITE_TIFF_YDPI`
+	pub fn tiff_resolution(self, unit: TiffResolutionUnit, x_dpi: i32, y_dpi: i32) -> Self {
+		self
+			.param_typed(ImageFormat::Tiff, "IMWRITE_TIFF_RESUNIT", key::TIFF_RESUNIT, unit as i32)
+			.param(ImageFormat::Tiff, "IMWRITE_TIFF_XDPI", key::TIFF_XDPI, x_dpi, 1, i32::MAX)
+			.param(ImageFormat::Tiff, "IMWRITE_TIFF_YDPI", key::TIFF_YDPI, y_dpi, 1, i32::MAX)
+	}
+
+	/// OpenEXR storage type, `IMWRITE_EXR_TYPE`
+	#[inline]
+	pub fn exr_type(self, typ: ExrType) -> Self {
+		self.param_typed(ImageFormat::Exr, "IMWRITE_EXR_TYPE", key::EXR_TYPE, typ as i32)
+	}
+
+	/// OpenEXR compression, `IMWRITE_EXR_COMPRESSION`, ignored by the older OpenCV versions
+	#[inline]
+	pub fn exr_compression(self, compression: ExrCompression) -> Self {
+		self.param_typed(
+			ImageFormat::Exr,
+			"IMWRITE_EXR_COMPRESSION",
+			key::EXR_COMPRESSION,
+			compression as i32,
+		)
+	}
+
+	/// AVIF quality (0..=100, default 95), `IMWRITE_AVIF_QUALITY`, requires OpenCV with AVIF support
+	#[inline]
+	pub fn avif_quality(self, quality: i32) -> Self {
+		self.param(ImageFormat::Avif, "IMWRITE_AVIF_QUALITY", key::AVIF_QUALITY, quality, 0, 100)
+	}
+
+	/// AVIF bit depth (8, 10 or 12, default 8), `IMWRITE_AVIF_DEPTH`, requires OpenCV with AVIF support
+	#[inline]
+	pub fn avif_depth(self, depth: i32) -> Self {
+		self.param(ImageFormat::Avif, "IMWRITE_AVIF_DEPTH", key::AVIF_DEPTH, depth, 8, 12)
+	}
+
+	/// AVIF encoding speed (0..=9, default 9), higher value means faster encoding and larger size, `IMWRITE_AVIF_SPEED`,
+	/// requires OpenCV with AVIF support
+	#[inline]
+	pub fn avif_speed(self, speed: i32) -> Self {
+		self.param(ImageFormat::Avif, "IMWRITE_AVIF_SPEED", key::AVIF_SPEED, speed, 0, 9)
+	}
+
+	/// Validates the options against the format of `ext` (extension or file name) and returns them as the flat key/value
+	/// pairs accepted by `imwrite()` and `imencode()`
+	pub fn to_params(&self, ext: &str) -> Result<Vector<i32>> {
+		if self.params.is_empty() {
+			return Ok(Vector::new());
+		}
+		let format = ImageFormat::from_extension(ext).ok_or_else(|| {
+			Error::new(
+				core::StsBadArg,
+				format!("Can't determine the image format of {ext:?} to validate the write options against"),
+			)
+		})?;
+		let mut out = Vector::with_capacity(self.params.len() * 2);
+		for param in &self.params {
+			if param.format != format {
+				return Err(Error::new(
+					core::StsBadArg,
+					format!(
+						"{} is a {} option, it can't be used when writing {} ({ext:?})",
+						param.name,
+						param.format.name(),
+						format.name()
+					),
+				));
+			}
+			if param.value < param.min || param.value > param.max {
+				return Err(Error::new(
+					core::StsOutOfRange,
+					format!(
+						"{} must be in range {}..={}, got: {}",
+						param.name, param.min, param.max, param.value
+					),
+				));
+			}
+			out.push(param.key);
+			out.push(param.value);
+		}
+		Ok(out)
+	}
+}
+
+/// Same as `imwrite()`, but with the typed options validated against the extension of `filename`
+///
+/// Returns an error if the image can't be written.
+pub fn imwrite_with_options(filename: impl AsRef<Path>, img: &impl ToInputArray, opts: &ImwriteOptions) -> Result<()> {
+	let filename = filename.as_ref();
+	let params = opts.to_params(&filename.to_string_lossy())?;
+	if imgcodecs::imwrite(filename, img, &params)? {
+		Ok(())
+	} else {
+		Err(Error::new(
+			core::StsError,
+			format!("Can't write image to: {}", filename.display()),
+		))
+	}
+}
+
+/// Encodes the image into the format of `ext` (e.g. `".jpg"`) and returns the encoded bytes, see `imencode()`
+pub fn imencode_to_vec(ext: &str, img: &impl ToInputArray, opts: &ImwriteOptions) -> Result<Vec<u8>> {
+	let params = opts.to_params(ext)?;
+	let mut buf = Vector::<u8>::new();
+	if imgcodecs::imencode(ext, img, &mut buf, &params)? {
+		Ok(buf.to_vec())
+	} else {
+		Err(Error::new(core::StsError, format!("Can't encode image as: {ext}")))
+	}
+}
+
+/// Decodes the image from the encoded bytes without copying them, see `imdecode()` for the `flags`
+///
+/// Unlike `imdecode()` returns an error instead of an empty `Mat` if the data can't be decoded.
+pub fn imdecode_slice(buf: &[u8], flags: i32) -> Result<Mat> {
+	let out = imgcodecs::imdecode(&buf, flags)?;
+	if out.empty() {
+		Err(Error::new(
+			core::StsError,
+			format!("Can't decode image from {} bytes", buf.len()),
+		))
+	} else {
+		Ok(out)
+	}
+}
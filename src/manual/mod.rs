@@ -4,12 +4,19 @@ pub mod core;
 pub mod dnn;
 #[cfg(ocvrs_has_module_features2d)]
 pub mod features2d;
+#[cfg(ocvrs_has_module_imgcodecs)]
+pub mod imgcodecs;
 pub mod sys;
 pub mod types;
+#[cfg(ocvrs_has_module_videoio)]
+pub mod videoio;
 
 pub mod prelude {
 	#[cfg(all(ocvrs_has_module_core, ocvrs_opencv_branch_32))]
 	pub use super::core::MatSizeTraitConstManual;
 	#[cfg(ocvrs_has_module_core)]
-	pub use super::core::{MatConstIteratorTraitManual, MatTraitConstManual, MatTraitManual, MatxTrait, UMatTraitConstManual};
+	pub use super::core::{
+		AlgorithmTraitConstManual, AlgorithmTraitManual, MatConstIteratorTraitManual, MatTraitConstManual, MatTraitManual, MatxTrait,
+		UMatTraitConstManual,
+	};
 }
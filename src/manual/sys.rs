@@ -16,6 +16,12 @@ pub struct Result<S, O = S> {
 }
 
 impl<S: Into<O>, O> Result<S, O> {
+	/// Whether the call succeeded, can be checked before the result is consumed by [Result::into_result]
+	#[inline]
+	pub fn is_ok(&self) -> bool {
+		self.error_msg.is_null()
+	}
+
 	#[inline]
 	pub fn into_result(self) -> CrateResult<O> {
 		if self.error_msg.is_null() {
@@ -0,0 +1,18 @@
+pub use fourcc::*;
+#[cfg(feature = "tokio")]
+pub use stream::*;
+#[cfg(not(ocvrs_opencv_branch_32))]
+pub use stream_io::*;
+pub use video_capture::*;
+#[cfg(ocvrs_opencv_since_4_5_2)]
+pub use video_writer::*;
+
+mod fourcc;
+#[cfg(feature = "tokio")]
+mod stream;
+#[cfg(not(ocvrs_opencv_branch_32))]
+mod stream_io;
+mod video_capture;
+// `VideoWriter` with the `params` and `VIDEOWRITER_PROP_HW_ACCELERATION` are available since OpenCV 4.5.2
+#[cfg(ocvrs_opencv_since_4_5_2)]
+mod video_writer;
@@ -0,0 +1,103 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{core, Error};
+
+/// Four character code of the video codec, e.g. `MJPG` or `avc1`, see `VideoWriter::fourcc()`
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct FourCC(i32);
+
+impl FourCC {
+	/// Motion JPEG
+	pub const MJPG: Self = Self::new(b"MJPG");
+	/// MPEG-4 Part 2 (Xvid)
+	pub const XVID: Self = Self::new(b"XVID");
+	/// MPEG-4 Part 2 (DivX)
+	pub const DIVX: Self = Self::new(b"DIVX");
+	/// MPEG-4 Part 2, usually in the `.mp4` container
+	pub const MP4V: Self = Self::new(b"mp4v");
+	/// H.264/AVC
+	pub const H264: Self = Self::new(b"H264");
+	/// H.264/AVC, the tag used in the `.mp4` container
+	pub const AVC1: Self = Self::new(b"avc1");
+	/// H.265/HEVC
+	pub const HEVC: Self = Self::new(b"HEVC");
+	/// H.265/HEVC, the tag used in the `.mp4` container
+	pub const HVC1: Self = Self::new(b"hvc1");
+	/// VP8, usually in the `.webm` container
+	pub const VP80: Self = Self::new(b"VP80");
+	/// VP9, usually in the `.webm` container
+	pub const VP90: Self = Self::new(b"VP90");
+	/// AV1
+	pub const AV01: Self = Self::new(b"av01");
+	/// FFV1 lossless codec
+	pub const FFV1: Self = Self::new(b"FFV1");
+	/// Uncompressed planar YUV 4:2:0
+	pub const I420: Self = Self::new(b"I420");
+
+	/// Creates the code from its characters, e.g. `FourCC::new(b"MJPG")`
+	#[inline]
+	pub const fn new(code: &[u8; 4]) -> Self {
+		Self(i32::from_le_bytes(*code))
+	}
+
+	/// Returns the numeric code in the form used by the OpenCV functions
+	#[inline]
+	pub const fn get(self) -> i32 {
+		self.0
+	}
+
+	/// Returns the 4 characters of the code
+	#[inline]
+	pub const fn to_bytes(self) -> [u8; 4] {
+		self.0.to_le_bytes()
+	}
+}
+
+impl From<i32> for FourCC {
+	#[inline]
+	fn from(code: i32) -> Self {
+		Self(code)
+	}
+}
+
+impl From<FourCC> for i32 {
+	#[inline]
+	fn from(s: FourCC) -> Self {
+		s.0
+	}
+}
+
+/// Formats the code as 4 characters, or as a hex number if some of them are not printable
+impl fmt::Display for FourCC {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let bytes = self.to_bytes();
+		if bytes.iter().all(|b| b.is_ascii_graphic() || *b == b' ') {
+			bytes.iter().try_for_each(|&b| fmt::Write::write_char(f, char::from(b)))
+		} else {
+			write!(f, "{:#010x}", self.0)
+		}
+	}
+}
+
+/// Parses the code from exactly 4 ASCII characters, e.g. `"MJPG".parse::<FourCC>()`
+impl FromStr for FourCC {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.as_bytes() {
+			&[a, b, c, d] if s.is_ascii() => Ok(Self::new(&[a, b, c, d])),
+			_ => Err(Error::new(
+				core::StsBadArg,
+				format!("FourCC must consist of exactly 4 ASCII characters, got: {s:?}"),
+			)),
+		}
+	}
+}
+
+impl fmt::Debug for FourCC {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "FourCC({self})")
+	}
+}
@@ -0,0 +1,198 @@
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::Duration;
+
+use futures_core::Stream;
+
+use crate::core::{self, Mat};
+use crate::prelude::*;
+use crate::videoio::{self, VideoCapture};
+use crate::{Error, Result};
+
+/// Position of the frame in the video as reported by `CAP_PROP_POS_MSEC`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(Duration);
+
+impl Timestamp {
+	/// Negative and non-finite values are treated as zero
+	#[inline]
+	pub fn from_millis_f64(millis: f64) -> Self {
+		let secs = millis / 1000.;
+		if secs.is_finite() && secs > 0. {
+			if secs < u64::MAX as f64 {
+				Self(Duration::from_secs_f64(secs))
+			} else {
+				Self(Duration::MAX)
+			}
+		} else {
+			Self::default()
+		}
+	}
+
+	#[inline]
+	pub fn as_duration(self) -> Duration {
+		self.0
+	}
+
+	#[inline]
+	pub fn as_millis_f64(self) -> f64 {
+		self.0.as_secs_f64() * 1000.
+	}
+}
+
+impl From<Timestamp> for Duration {
+	#[inline]
+	fn from(ts: Timestamp) -> Self {
+		ts.0
+	}
+}
+
+/// What [VideoCaptureStream] does when the consumer doesn't keep up with the frames
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DropPolicy {
+	/// Drops the oldest buffered frame to make room for the new one, suitable for the live sources like cameras
+	DropOldest,
+	/// Pauses the reading until there is room in the buffer, suitable for files where every frame matters
+	Block,
+}
+
+type Frame = Result<(Timestamp, Mat)>;
+
+struct State {
+	frames: VecDeque<Frame>,
+	waker: Option<Waker>,
+	/// Set by the reader thread when there will be no more frames
+	finished: bool,
+	/// Set when the stream is dropped
+	cancelled: bool,
+}
+
+struct Shared {
+	state: Mutex<State>,
+	/// Notified when a frame is taken from the buffer or the stream is cancelled
+	space: Condvar,
+}
+
+impl Shared {
+	#[inline]
+	fn lock(&self) -> MutexGuard<'_, State> {
+		self.state.lock().unwrap_or_else(|e| e.into_inner())
+	}
+}
+
+/// Stream of the frames read from a `VideoCapture` by a dedicated thread, see [VideoCapture::into_stream]
+///
+/// The stream ends when `VideoCapture::read()` reports that there are no more frames. An error is yielded as the last item.
+/// Dropping the stream stops the reader thread after the frame it's currently reading, the `VideoCapture` is released
+/// by that thread.
+pub struct VideoCaptureStream {
+	shared: Arc<Shared>,
+}
+
+impl VideoCapture {
+	/// Moves the capture into a dedicated reader thread and returns the stream of the frames with their timestamps
+	///
+	/// Up to `buffer` frames (at least 1) are read ahead, `policy` specifies what happens when the buffer is full.
+	pub fn into_stream(self, buffer: usize, policy: DropPolicy) -> Result<VideoCaptureStream> {
+		let buffer = buffer.max(1);
+		let shared = Arc::new(Shared {
+			state: Mutex::new(State {
+				frames: VecDeque::with_capacity(buffer),
+				waker: None,
+				finished: false,
+				cancelled: false,
+			}),
+			space: Condvar::new(),
+		});
+		let reader_shared = Arc::clone(&shared);
+		thread::Builder::new()
+			.name("opencv-video-capture".to_string())
+			.spawn(move || read_frames(self, &reader_shared, buffer, policy))
+			.map_err(|e| Error::new(core::StsError, format!("Can't start the VideoCapture reader thread: {e}")))?;
+		Ok(VideoCaptureStream { shared })
+	}
+}
+
+fn read_frame(cap: &mut VideoCapture) -> Result<Option<(Timestamp, Mat)>> {
+	let mut frame = Mat::default();
+	if cap.read(&mut frame)? {
+		let timestamp = Timestamp::from_millis_f64(cap.get(videoio::CAP_PROP_POS_MSEC)?);
+		Ok(Some((timestamp, frame)))
+	} else {
+		Ok(None)
+	}
+}
+
+fn read_frames(mut cap: VideoCapture, shared: &Shared, buffer: usize, policy: DropPolicy) {
+	loop {
+		if shared.lock().cancelled {
+			break;
+		}
+		let (frame, last) = match read_frame(&mut cap) {
+			Ok(Some(frame)) => (Ok(frame), false),
+			Ok(None) => break,
+			Err(e) => (Err(e), true),
+		};
+		let mut state = shared.lock();
+		while !state.cancelled && state.frames.len() >= buffer {
+			match policy {
+				DropPolicy::DropOldest => {
+					state.frames.pop_front();
+				}
+				DropPolicy::Block => {
+					state = shared.space.wait(state).unwrap_or_else(|e| e.into_inner());
+				}
+			}
+		}
+		if state.cancelled {
+			break;
+		}
+		state.frames.push_back(frame);
+		let waker = state.waker.take();
+		drop(state);
+		if let Some(waker) = waker {
+			waker.wake();
+		}
+		if last {
+			break;
+		}
+	}
+	let mut state = shared.lock();
+	state.finished = true;
+	let waker = state.waker.take();
+	drop(state);
+	if let Some(waker) = waker {
+		waker.wake();
+	}
+}
+
+impl Stream for VideoCaptureStream {
+	type Item = Frame;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let mut state = self.shared.lock();
+		if let Some(frame) = state.frames.pop_front() {
+			drop(state);
+			self.shared.space.notify_one();
+			Poll::Ready(Some(frame))
+		} else if state.finished {
+			Poll::Ready(None)
+		} else {
+			match &state.waker {
+				Some(waker) if waker.will_wake(cx.waker()) => {}
+				_ => state.waker = Some(cx.waker().clone()),
+			}
+			Poll::Pending
+		}
+	}
+}
+
+impl Drop for VideoCaptureStream {
+	fn drop(&mut self) {
+		self.shared.lock().cancelled = true;
+		self.shared.space.notify_one();
+	}
+}
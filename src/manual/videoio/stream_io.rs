@@ -0,0 +1,264 @@
+use std::ffi::c_void;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::ops::{Deref, DerefMut};
+use std::os::raw::{c_char, c_int, c_longlong};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{process, slice};
+
+use crate::core::{self, Size};
+use crate::prelude::*;
+use crate::videoio::{FourCC, VideoCapture, VideoWriter};
+use crate::{sys, Error, Result};
+
+trait ReadSeek: Read + Seek + Send {}
+
+impl<T: Read + Seek + Send> ReadSeek for T {}
+
+type StreamReader = Box<dyn ReadSeek>;
+
+#[inline]
+fn io_error(action: &str, e: io::Error) -> Error {
+	Error::new(core::StsError, format!("{action}: {e}"))
+}
+
+impl VideoCapture {
+	/// Opens the capture reading the video from `reader`, e.g. from an in-memory `io::Cursor`
+	///
+	/// With OpenCV 4.10+ the data is read on demand through `cv::IStreamReader`, only some backends (e.g. `CAP_FFMPEG`)
+	/// support that. With older OpenCV versions on Linux the whole content of `reader` is copied into an anonymous
+	/// in-memory file (memfd) that is then opened by the capture, on other platforms an error is returned.
+	///
+	/// Unlike [VideoCapture::from_file] this function returns an error if the capture can't be opened.
+	pub fn from_reader(reader: impl Read + Seek + Send + 'static, api_preference: i32) -> Result<VideoCapture> {
+		extern "C" {
+			fn cv_manual_VideoCapture_has_stream_reader() -> bool;
+		}
+		if unsafe { cv_manual_VideoCapture_has_stream_reader() } {
+			open_stream_reader(Box::new(reader), api_preference)
+		} else {
+			open_memfd(reader, api_preference)
+		}
+	}
+}
+
+fn open_stream_reader(reader: StreamReader, api_preference: i32) -> Result<VideoCapture> {
+	extern "C" {
+		fn cv_manual_VideoCapture_open_reader(
+			instance: *mut c_void,
+			reader: *mut c_void,
+			read: unsafe extern "C" fn(*mut c_void, *mut c_char, c_longlong) -> c_longlong,
+			seek: unsafe extern "C" fn(*mut c_void, c_longlong, c_int) -> c_longlong,
+			delete_reader: unsafe extern "C" fn(*mut c_void),
+			api_preference: c_int,
+			ocvrs_return: *mut sys::Result<bool>,
+		);
+	}
+	let mut cap = VideoCapture::default()?;
+	let reader = Box::into_raw(Box::new(reader)) as *mut c_void;
+	return_send!(via ocvrs_return);
+	unsafe {
+		cv_manual_VideoCapture_open_reader(
+			cap.as_raw_mut_VideoCapture(),
+			reader,
+			stream_read,
+			stream_seek,
+			stream_delete,
+			api_preference,
+			ocvrs_return.as_mut_ptr(),
+		)
+	}
+	return_receive!(unsafe ocvrs_return => ret);
+	if ret.into_result()? {
+		Ok(cap)
+	} else {
+		Err(Error::new(
+			core::StsError,
+			format!("Can't open VideoCapture from the reader with API preference: {api_preference}"),
+		))
+	}
+}
+
+#[inline]
+unsafe fn reader_mut<'r>(reader: *mut c_void) -> &'r mut StreamReader {
+	&mut *(reader as *mut StreamReader)
+}
+
+/// Returns the number of bytes read, 0 at the end of the stream and -1 on error
+unsafe extern "C" fn stream_read(reader: *mut c_void, buffer: *mut c_char, size: c_longlong) -> c_longlong {
+	if size <= 0 {
+		return 0;
+	}
+	let buffer = slice::from_raw_parts_mut(buffer as *mut u8, size as usize);
+	let reader = reader_mut(reader);
+	// panics must not unwind into C++
+	panic::catch_unwind(AssertUnwindSafe(|| loop {
+		match reader.read(buffer) {
+			Ok(read) => break read as c_longlong,
+			Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+			Err(_) => break -1,
+		}
+	}))
+	.unwrap_or(-1)
+}
+
+/// `origin` is one of `SEEK_SET`, `SEEK_CUR` or `SEEK_END`, returns the new position or -1 on error
+unsafe extern "C" fn stream_seek(reader: *mut c_void, offset: c_longlong, origin: c_int) -> c_longlong {
+	let pos = match origin {
+		0 if offset >= 0 => SeekFrom::Start(offset as u64),
+		1 => SeekFrom::Current(offset),
+		2 => SeekFrom::End(offset),
+		_ => return -1,
+	};
+	let reader = reader_mut(reader);
+	panic::catch_unwind(AssertUnwindSafe(|| reader.seek(pos).map_or(-1, |pos| pos as c_longlong))).unwrap_or(-1)
+}
+
+unsafe extern "C" fn stream_delete(reader: *mut c_void) {
+	let reader = Box::from_raw(reader as *mut StreamReader);
+	let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(reader)));
+}
+
+#[cfg(target_os = "linux")]
+fn open_memfd(mut reader: impl Read + Seek, api_preference: i32) -> Result<VideoCapture> {
+	use std::os::unix::io::FromRawFd;
+
+	let fd = unsafe { libc::memfd_create(b"opencv-video-capture\0".as_ptr() as *const c_char, libc::MFD_CLOEXEC) };
+	if fd < 0 {
+		return Err(io_error("Can't create memfd for VideoCapture", io::Error::last_os_error()));
+	}
+	let mut file = unsafe { File::from_raw_fd(fd) };
+	reader
+		.seek(SeekFrom::Start(0))
+		.and_then(|_| io::copy(&mut reader, &mut file))
+		.map_err(|e| io_error("Can't copy the reader contents to memfd", e))?;
+	// the capture opens its own descriptor of the memfd so `file` can be closed afterwards
+	let cap = VideoCapture::from_file(&format!("/proc/self/fd/{fd}"), api_preference)?;
+	if cap.is_opened()? {
+		Ok(cap)
+	} else {
+		Err(Error::new(
+			core::StsError,
+			format!("Can't open VideoCapture from memfd with API preference: {api_preference}"),
+		))
+	}
+}
+
+#[cfg(not(target_os = "linux"))]
+fn open_memfd(_reader: impl Read + Seek, _api_preference: i32) -> Result<VideoCapture> {
+	Err(Error::new(
+		core::StsNotImplemented,
+		"Opening VideoCapture from a reader requires OpenCV 4.10+ with cv::IStreamReader or Linux with memfd",
+	))
+}
+
+impl VideoWriter {
+	/// Creates the writer that encodes the video into `writer`
+	///
+	/// OpenCV can only write video to a file, so the frames are first written to a temporary file in
+	/// `std::env::temp_dir()`, its contents are copied to `writer` by [VideoStreamWriter::finish]. The container format is
+	/// selected by the extension `ext` (e.g. `"avi"` or `"mp4"`), the rest of the arguments are the same as for
+	/// [VideoWriter::new_with_backend].
+	pub fn to_writer<W: Write>(
+		writer: W,
+		ext: &str,
+		api_preference: i32,
+		fourcc: FourCC,
+		fps: f64,
+		frame_size: Size,
+		is_color: bool,
+	) -> Result<VideoStreamWriter<W>> {
+		static COUNTER: AtomicUsize = AtomicUsize::new(0);
+		let ext = ext.trim_start_matches('.');
+		let path = std::env::temp_dir().join(format!(
+			"opencv-video-writer-{}-{}.{ext}",
+			process::id(),
+			COUNTER.fetch_add(1, Ordering::Relaxed)
+		));
+		// reserves the name so that concurrent processes can't pick the same one
+		OpenOptions::new()
+			.write(true)
+			.create_new(true)
+			.open(&path)
+			.map_err(|e| io_error("Can't create temporary file for VideoWriter", e))?;
+		let mut out = VideoStreamWriter {
+			video_writer: VideoWriter::default()?,
+			path,
+			writer: None,
+		};
+		let path = out.path.to_str().ok_or_else(|| {
+			Error::new(
+				core::StsBadArg,
+				format!("Temporary file path is not valid UTF-8: {}", out.path.display()),
+			)
+		})?;
+		if out
+			.video_writer
+			.open_with_backend(path, api_preference, fourcc.into(), fps, frame_size, is_color)?
+		{
+			out.writer = Some(writer);
+			Ok(out)
+		} else {
+			Err(Error::new(
+				core::StsError,
+				format!("Can't open VideoWriter for {fourcc} in .{ext} container with API preference: {api_preference}"),
+			))
+		}
+	}
+}
+
+/// `VideoWriter` that copies the encoded video to a `Write` once it's finished, see [VideoWriter::to_writer]
+///
+/// Dereferences to the underlying `VideoWriter` to write the frames. If the writer is dropped without calling
+/// [VideoStreamWriter::finish] the video is still copied, but the errors are ignored.
+pub struct VideoStreamWriter<W: Write> {
+	video_writer: VideoWriter,
+	path: PathBuf,
+	/// `None` if the writer failed to open or is already finished
+	writer: Option<W>,
+}
+
+impl<W: Write> VideoStreamWriter<W> {
+	/// Finalizes the video, copies it to the output and returns the output
+	pub fn finish(mut self) -> Result<W> {
+		let mut writer = self.writer.take().expect("Writer is only taken on finish");
+		self.copy_to(&mut writer)?;
+		Ok(writer)
+	}
+
+	fn copy_to(&mut self, writer: &mut W) -> Result<()> {
+		self.video_writer.release()?;
+		File::open(&self.path)
+			.and_then(|mut file| io::copy(&mut file, writer))
+			.and_then(|_| writer.flush())
+			.map_err(|e| io_error("Can't copy the encoded video to the writer", e))
+	}
+}
+
+impl<W: Write> Deref for VideoStreamWriter<W> {
+	type Target = VideoWriter;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.video_writer
+	}
+}
+
+impl<W: Write> DerefMut for VideoStreamWriter<W> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.video_writer
+	}
+}
+
+impl<W: Write> Drop for VideoStreamWriter<W> {
+	fn drop(&mut self) {
+		if let Some(mut writer) = self.writer.take() {
+			let _ = self.copy_to(&mut writer);
+		}
+		let _ = self.video_writer.release();
+		let _ = fs::remove_file(&self.path);
+	}
+}
@@ -0,0 +1,140 @@
+use crate::core::{self, Mat, Size};
+use crate::prelude::*;
+use crate::videoio::{self, FourCC, VideoCapture};
+use crate::{Error, Result};
+
+impl VideoCapture {
+	/// Returns the iterator over the frames of the capture, every frame is read into a newly allocated `Mat`
+	///
+	/// The iteration ends when `VideoCapture::read()` reports that there are no more frames. An error is yielded as the
+	/// last item. Use [VideoCapture::for_each_frame] to avoid allocating a buffer for every frame.
+	#[inline]
+	pub fn frames(&mut self) -> Frames<'_> {
+		Frames { cap: self, done: false }
+	}
+
+	/// Calls `f` for every frame of the capture reusing the same buffer, returns the number of frames read
+	///
+	/// An error returned by `f` stops the reading and is passed to the caller.
+	pub fn for_each_frame(&mut self, mut f: impl FnMut(&mut Mat) -> Result<()>) -> Result<usize> {
+		let mut frame = Mat::default();
+		let mut count = 0;
+		while self.read(&mut frame)? {
+			count += 1;
+			f(&mut frame)?;
+		}
+		Ok(count)
+	}
+
+	/// Size of the frames, `None` if the backend doesn't report it
+	pub fn frame_size(&self) -> Result<Option<Size>> {
+		let width = self.get(videoio::CAP_PROP_FRAME_WIDTH)?;
+		let height = self.get(videoio::CAP_PROP_FRAME_HEIGHT)?;
+		Ok(if width >= 1. && height >= 1. {
+			Some(Size::new(width as i32, height as i32))
+		} else {
+			None
+		})
+	}
+
+	/// Frame rate, `None` if the backend doesn't report it
+	pub fn fps(&self) -> Result<Option<f64>> {
+		let fps = self.get(videoio::CAP_PROP_FPS)?;
+		Ok(if fps.is_finite() && fps > 0. {
+			Some(fps)
+		} else {
+			None
+		})
+	}
+
+	/// Number of frames in the video file, `None` if the backend doesn't report it, e.g. for the live sources
+	///
+	/// OpenCV returns 0 for the properties not supported by the backend so a file with no frames also gives `None`. Some
+	/// backends (e.g. FFmpeg) estimate the count from the duration and the frame rate, so it can differ from the number of
+	/// frames actually read, don't use it as the loop bound, iterate with [VideoCapture::frames] instead.
+	pub fn frame_count(&self) -> Result<Option<u64>> {
+		let count = self.get(videoio::CAP_PROP_FRAME_COUNT)?;
+		Ok(if count.is_finite() && count >= 1. {
+			Some(count as u64)
+		} else {
+			None
+		})
+	}
+
+	/// 0-based index of the frame to be read next, `None` if the backend reports an invalid value
+	///
+	/// OpenCV returns 0 for the properties not supported by the backend, so there is no way to tell the first frame from
+	/// the missing support: backends without seeking, e.g. the cameras and the network streams, keep returning `Some(0)`.
+	/// The value is only reliable for the captures of video files, [VideoCapture::seek_frame] returns an error if the
+	/// backend doesn't support the frame position.
+	pub fn position(&self) -> Result<Option<u64>> {
+		let pos = self.get(videoio::CAP_PROP_POS_FRAMES)?;
+		Ok(if pos.is_finite() && pos >= 0. {
+			Some(pos as u64)
+		} else {
+			None
+		})
+	}
+
+	/// Makes `frame` (0-based) the next frame to be read
+	///
+	/// Returns an error with `StsNotImplemented` code if the backend doesn't support seeking.
+	pub fn seek_frame(&mut self, frame: u64) -> Result<()> {
+		if self.set(videoio::CAP_PROP_POS_FRAMES, frame as f64)? {
+			Ok(())
+		} else {
+			Err(Error::new(
+				core::StsNotImplemented,
+				format!("Can't seek to frame {frame}, backend doesn't support seeking"),
+			))
+		}
+	}
+
+	/// Codec of the stream, `None` if the backend doesn't report it
+	pub fn fourcc(&self) -> Result<Option<FourCC>> {
+		let fourcc = self.get(videoio::CAP_PROP_FOURCC)?;
+		Ok(if fourcc.is_finite() && fourcc >= 1. {
+			// the code is returned as the double representation of the unsigned value
+			Some(FourCC::from(fourcc as u32 as i32))
+		} else {
+			None
+		})
+	}
+
+	/// Name of the backend used by the capture, `None` if the capture is not opened
+	pub fn backend_name(&self) -> Result<Option<String>> {
+		if self.is_opened()? {
+			self.get_backend_name().map(Some)
+		} else {
+			Ok(None)
+		}
+	}
+}
+
+/// Iterator over the frames of a `VideoCapture`, see [VideoCapture::frames]
+pub struct Frames<'c> {
+	cap: &'c mut VideoCapture,
+	done: bool,
+}
+
+impl Iterator for Frames<'_> {
+	type Item = Result<Mat>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let mut frame = Mat::default();
+		match self.cap.read(&mut frame) {
+			Ok(true) => Some(Ok(frame)),
+			Ok(false) => {
+				self.done = true;
+				None
+			}
+			Err(e) => {
+				self.done = true;
+				Some(Err(e))
+			}
+		}
+	}
+}
@@ -0,0 +1,157 @@
+use std::path::{Path, PathBuf};
+
+use crate::core::{self, Size, Vector};
+use crate::prelude::*;
+use crate::templ::path_to_cstring;
+use crate::videoio::{self, FourCC, VideoAccelerationType, VideoCaptureAPIs, VideoWriter};
+use crate::{Error, Result};
+
+impl VideoWriter {
+	/// Returns the builder of the writer that encodes the video with the `fourcc` codec into `filename`
+	#[inline]
+	pub fn builder(filename: impl AsRef<Path>, fourcc: FourCC, fps: f64, frame_size: Size) -> VideoWriterBuilder {
+		VideoWriterBuilder::new(filename, fourcc, fps, frame_size)
+	}
+}
+
+/// Builder of [VideoWriter] with the typed `VIDEOWRITER_PROP_*` options
+///
+/// Unlike the `VideoWriter` constructors [VideoWriterBuilder::build] returns an error describing the backends that were
+/// tried if none of them could open the writer.
+#[derive(Clone, Debug)]
+pub struct VideoWriterBuilder {
+	filename: PathBuf,
+	fourcc: FourCC,
+	fps: f64,
+	frame_size: Size,
+	api_preference: i32,
+	/// Pairs of `VIDEOWRITER_PROP_*` keys and their values
+	params: Vec<(i32, i32)>,
+}
+
+impl VideoWriterBuilder {
+	pub fn new(filename: impl AsRef<Path>, fourcc: FourCC, fps: f64, frame_size: Size) -> Self {
+		Self {
+			filename: filename.as_ref().to_path_buf(),
+			fourcc,
+			fps,
+			frame_size,
+			api_preference: videoio::CAP_ANY,
+			params: vec![],
+		}
+	}
+
+	/// Backend to use, e.g. `CAP_FFMPEG`, by default all of the available writer backends are tried in turn
+	#[inline]
+	pub fn api_preference(mut self, api_preference: i32) -> Self {
+		self.api_preference = api_preference;
+		self
+	}
+
+	/// Sets the raw `VIDEOWRITER_PROP_*` parameter, replaces the value that was previously set for the same `key`
+	pub fn param(mut self, key: i32, value: i32) -> Self {
+		match self.params.iter_mut().find(|(k, _)| *k == key) {
+			Some((_, v)) => *v = value,
+			None => self.params.push((key, value)),
+		}
+		self
+	}
+
+	/// Whether the frames are color or grayscale, `VIDEOWRITER_PROP_IS_COLOR`, defaults to color
+	#[inline]
+	pub fn is_color(self, is_color: bool) -> Self {
+		self.param(videoio::VIDEOWRITER_PROP_IS_COLOR, i32::from(is_color))
+	}
+
+	/// Quality of the encoded video in percent (0..=100), `VIDEOWRITER_PROP_QUALITY`, only supported by some codecs
+	#[inline]
+	pub fn quality(self, quality: i32) -> Self {
+		self.param(videoio::VIDEOWRITER_PROP_QUALITY, quality)
+	}
+
+	/// Depth of the frames, e.g. `CV_8U` (default) or `CV_16U`, `VIDEOWRITER_PROP_DEPTH`
+	#[inline]
+	pub fn depth(self, depth: i32) -> Self {
+		self.param(videoio::VIDEOWRITER_PROP_DEPTH, depth)
+	}
+
+	/// Number of stripes for the parallel encoding, -1 for auto detection, `VIDEOWRITER_PROP_NSTRIPES`
+	#[inline]
+	pub fn nstripes(self, nstripes: i32) -> Self {
+		self.param(videoio::VIDEOWRITER_PROP_NSTRIPES, nstripes)
+	}
+
+	/// Hardware acceleration type, `VIDEOWRITER_PROP_HW_ACCELERATION`
+	#[inline]
+	pub fn hw_acceleration(self, acceleration: VideoAccelerationType) -> Self {
+		self.param(videoio::VIDEOWRITER_PROP_HW_ACCELERATION, acceleration as i32)
+	}
+
+	/// Index of the hardware device (GPU) for the acceleration, `VIDEOWRITER_PROP_HW_DEVICE`
+	#[inline]
+	pub fn hw_device(self, device: i32) -> Self {
+		self.param(videoio::VIDEOWRITER_PROP_HW_DEVICE, device)
+	}
+
+	/// Whether to create and bind the OpenCL context for the hardware acceleration,
+	/// `VIDEOWRITER_PROP_HW_ACCELERATION_USE_OPENCL`
+	#[inline]
+	pub fn hw_acceleration_use_opencl(self, use_opencl: bool) -> Self {
+		self.param(videoio::VIDEOWRITER_PROP_HW_ACCELERATION_USE_OPENCL, i32::from(use_opencl))
+	}
+
+	/// Opens the writer trying the preferred backend or all of the available writer backends
+	pub fn build(&self) -> Result<VideoWriter> {
+		if let Some(&(_, quality)) = self
+			.params
+			.iter()
+			.find(|(k, v)| *k == videoio::VIDEOWRITER_PROP_QUALITY && !(0..=100).contains(v))
+		{
+			return Err(Error::new(
+				core::StsOutOfRange,
+				format!("VideoWriter quality must be in range 0..=100, got: {quality}"),
+			));
+		}
+		// report the unrepresentable path once instead of as the failure of every backend
+		path_to_cstring(&self.filename)?;
+		let params = self.params.iter().flat_map(|&(k, v)| [k, v]).collect::<Vector<i32>>();
+		let available = videoio::get_writer_backends()?;
+		let backends = if self.api_preference == videoio::CAP_ANY {
+			available
+				.iter()
+				.map(|api| (api as i32, backend_name(api)))
+				.collect::<Vec<_>>()
+		} else {
+			let name = available
+				.iter()
+				.find(|&api| api as i32 == self.api_preference)
+				.map_or_else(|| format!("API {} (not available)", self.api_preference), backend_name);
+			vec![(self.api_preference, name)]
+		};
+		let mut tried = Vec::with_capacity(backends.len());
+		for (api, name) in backends {
+			match VideoWriter::new_2(&self.filename, api, self.fourcc.into(), self.fps, self.frame_size, &params) {
+				Ok(writer) if writer.is_opened()? => return Ok(writer),
+				Ok(_) => tried.push(name),
+				Err(e) => tried.push(format!("{name} ({})", e.message)),
+			}
+		}
+		let tried = if tried.is_empty() {
+			"none available".to_string()
+		} else {
+			tried.join(", ")
+		};
+		Err(Error::new(
+			core::StsError,
+			format!(
+				"Can't open VideoWriter for {:?} with codec {} at {} fps and frame size {}x{}, tried backends: {tried}",
+				self.filename, self.fourcc, self.fps, self.frame_size.width, self.frame_size.height
+			),
+		))
+	}
+}
+
+#[inline]
+fn backend_name(api: VideoCaptureAPIs) -> String {
+	videoio::get_backend_name(api).unwrap_or_else(|_| format!("API {}", api as i32))
+}
@@ -0,0 +1,63 @@
+//! [serde](https://serde.rs) data format backed by `FileStorage`
+//!
+//! Structs and maps are stored as `FileStorage` maps, sequences and tuples as `FileStorage` sequences and [Mat](crate::core::Mat)
+//! as `!!opencv-matrix` so the output can be read by the C++ code using `cv::FileStorage` and vice versa. Enum unit variants
+//! are stored as strings, other variants as maps with a single key. `FileStorage` only supports 32-bit integers so larger
+//! values produce an error, booleans are stored as integers. The top-level value must be a struct or a map.
+//!
+//! Map keys must be strings or enum unit variants, other key types (e.g. integers) produce an error. `None` is stored by
+//! omitting the struct field or the map entry, `FileStorage` has no null value so `None` as an element of a sequence or a
+//! tuple produces an error, wrap such elements in a struct or use a map instead.
+//!
+//! ```no_run
+//! # use opencv::persistence::{self, Format};
+//! # #[derive(serde::Serialize, serde::Deserialize)]
+//! # struct Calibration { fx: f64 }
+//! # fn main() -> opencv::Result<()> {
+//! let calib = Calibration { fx: 1200. };
+//! let yaml = persistence::to_string(&calib, Format::Yaml)?;
+//! let calib: Calibration = persistence::from_str(&yaml)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+pub use crate::core::FileStorageFormat as Format;
+use crate::core::{FileNode, FileStorage, FileStorageTrait, FileStorageTraitConst, FileStorage_Mode};
+use crate::Result;
+
+mod de;
+mod mat;
+mod ser;
+
+/// Name of the struct that is stored as `cv::Mat`, the same as the type name used by OpenCV
+const MATRIX_TYPE_NAME: &str = "opencv-matrix";
+
+/// Serializes `value` into a string in the specified format
+pub fn to_string<T: ?Sized + Serialize>(value: &T, format: Format) -> Result<String> {
+	let mut fs = FileStorage::new(
+		"",
+		FileStorage_Mode::WRITE as i32 | FileStorage_Mode::MEMORY as i32 | format.flag(),
+		"",
+	)?;
+	to_file_storage(value, &mut fs)?;
+	fs.release_and_get_string()
+}
+
+/// Deserializes the value from a string in any of the formats supported by `FileStorage`, the format is detected automatically
+pub fn from_str<T: DeserializeOwned>(s: &str) -> Result<T> {
+	let fs = FileStorage::new(s, FileStorage_Mode::READ as i32 | FileStorage_Mode::MEMORY as i32, "")?;
+	from_file_node(fs.root(0)?)
+}
+
+/// Writes the fields of `value` into the already opened `FileStorage` at the current level
+pub fn to_file_storage<T: ?Sized + Serialize>(value: &T, fs: &mut FileStorage) -> Result<()> {
+	value.serialize(ser::Serializer::root(fs))
+}
+
+/// Deserializes the value from the `FileNode`, e.g. from `FileStorage::get()` or `FileStorage::root()`
+pub fn from_file_node<'de, T: Deserialize<'de>>(node: FileNode) -> Result<T> {
+	T::deserialize(de::Deserializer::new(node))
+}
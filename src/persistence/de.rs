@@ -0,0 +1,228 @@
+use serde::de::{self, DeserializeSeed, IntoDeserializer, Visitor};
+
+use crate::core::{self, FileNode, FileNodeIterator, FileNodeIteratorTrait, FileNodeIteratorTraitConst, FileNodeTraitConst};
+use crate::manual::core::file_node::{index_path, key_path, locate, type_mismatch};
+use crate::{Error, Result};
+
+/// Reads a value from the `FileNode`, keeps track of the node path for the error messages
+pub struct Deserializer {
+	node: FileNode,
+	path: String,
+}
+
+impl Deserializer {
+	pub fn new(node: FileNode) -> Self {
+		Self {
+			node,
+			path: String::new(),
+		}
+	}
+
+	#[inline]
+	fn typ(&self) -> Result<i32> {
+		Ok(self.node.typ()? & core::FileNode_TYPE_MASK)
+	}
+
+	/// Appends the path of the current node to the error message unless the error comes from one of the child nodes
+	#[inline]
+	fn locate(&self, e: Error) -> Error {
+		locate(e, &self.path)
+	}
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer {
+	type Error = Error;
+
+	fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		let res = match self.typ()? {
+			core::FileNode_INT => visitor.visit_i32(self.node.to_i32()?),
+			core::FileNode_REAL => visitor.visit_f64(self.node.to_f64()?),
+			core::FileNode_STRING => visitor.visit_string(self.node.to_string()?),
+			core::FileNode_SEQ => visitor.visit_seq(Children::new(&self)?),
+			core::FileNode_MAP => visitor.visit_map(Children::new(&self)?),
+			_ => visitor.visit_none(),
+		};
+		res.map_err(|e| self.locate(e))
+	}
+
+	fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		// booleans are stored as integers
+		if self.typ()? == core::FileNode_INT {
+			visitor.visit_bool(self.node.to_i32()? != 0).map_err(|e| self.locate(e))
+		} else {
+			self.deserialize_any(visitor)
+		}
+	}
+
+	fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		if self.typ()? == core::FileNode_NONE {
+			visitor.visit_none().map_err(|e| self.locate(e))
+		} else {
+			visitor.visit_some(self)
+		}
+	}
+
+	fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		visitor.visit_unit().map_err(|e| self.locate(e))
+	}
+
+	fn deserialize_unit_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+		self.deserialize_unit(visitor)
+	}
+
+	fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+		visitor.visit_newtype_struct(self)
+	}
+
+	fn deserialize_enum<V: Visitor<'de>>(
+		self,
+		_name: &'static str,
+		_variants: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value> {
+		let res = match self.typ()? {
+			core::FileNode_STRING => visitor.visit_enum(self.node.to_string()?.into_deserializer()),
+			core::FileNode_MAP if self.node.size()? == 1 => match Children::new(&self)?.next_child()? {
+				Some((key, value)) => visitor.visit_enum(Variant { key, value }),
+				None => Err(Error::new(core::StsParseError, "Enum variant map is empty")),
+			},
+			_ => Err(type_mismatch(
+				&self.node,
+				&self.path,
+				"an enum (a string or a map with a single key)",
+			)),
+		};
+		res.map_err(|e| self.locate(e))
+	}
+
+	fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		visitor.visit_unit()
+	}
+
+	serde::forward_to_deserialize_any! {
+		i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes byte_buf seq tuple tuple_struct map struct identifier
+	}
+}
+
+/// Child nodes of a sequence or a map
+struct Children<'d> {
+	parent: &'d Deserializer,
+	it: FileNodeIterator,
+	map: bool,
+	idx: usize,
+	len: usize,
+	/// Map value waiting to be deserialized after its key
+	value: Option<Deserializer>,
+}
+
+impl<'d> Children<'d> {
+	fn new(parent: &'d Deserializer) -> Result<Self> {
+		let it = parent.node.begin()?;
+		let len = it.remaining()?;
+		Ok(Self {
+			parent,
+			it,
+			map: parent.node.is_map()?,
+			idx: 0,
+			len,
+			value: None,
+		})
+	}
+
+	/// Returns the name (empty for the sequence elements) and the deserializer of the next child node
+	fn next_child(&mut self) -> Result<Option<(String, Deserializer)>> {
+		if self.idx >= self.len {
+			return Ok(None);
+		}
+		let node = self.it.try_deref()?;
+		self.it.incr()?;
+		let parent = &self.parent.path;
+		let (name, path) = if self.map {
+			let name = node.name()?;
+			let path = key_path(parent, &name);
+			(name, path)
+		} else {
+			(String::new(), index_path(parent, self.idx))
+		};
+		self.idx += 1;
+		Ok(Some((name, Deserializer { node, path })))
+	}
+}
+
+impl<'de> de::SeqAccess<'de> for Children<'_> {
+	type Error = Error;
+
+	fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+		match self.next_child()? {
+			Some((_, value)) => seed.deserialize(value).map(Some),
+			None => Ok(None),
+		}
+	}
+
+	fn size_hint(&self) -> Option<usize> {
+		Some(self.len - self.idx)
+	}
+}
+
+impl<'de> de::MapAccess<'de> for Children<'_> {
+	type Error = Error;
+
+	fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+		match self.next_child()? {
+			Some((key, value)) => {
+				self.value = Some(value);
+				seed.deserialize(key.into_deserializer()).map(Some)
+			}
+			None => Ok(None),
+		}
+	}
+
+	fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+		let value = self
+			.value
+			.take()
+			.ok_or_else(|| Error::new(core::StsError, "Map value is requested before its key"))?;
+		seed.deserialize(value)
+	}
+
+	fn size_hint(&self) -> Option<usize> {
+		Some(self.len - self.idx)
+	}
+}
+
+/// Enum variant stored as `{variant: value}`
+struct Variant {
+	key: String,
+	value: Deserializer,
+}
+
+impl<'de> de::EnumAccess<'de> for Variant {
+	type Error = Error;
+	type Variant = Deserializer;
+
+	fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Deserializer)> {
+		let key: de::value::StringDeserializer<Error> = self.key.into_deserializer();
+		let variant = seed.deserialize(key)?;
+		Ok((variant, self.value))
+	}
+}
+
+impl<'de> de::VariantAccess<'de> for Deserializer {
+	type Error = Error;
+
+	fn unit_variant(self) -> Result<()> {
+		Ok(())
+	}
+
+	fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+		seed.deserialize(self)
+	}
+
+	fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+		de::Deserializer::deserialize_seq(self, visitor)
+	}
+
+	fn struct_variant<V: Visitor<'de>>(self, _fields: &'static [&'static str], visitor: V) -> Result<V::Value> {
+		de::Deserializer::deserialize_map(self, visitor)
+	}
+}
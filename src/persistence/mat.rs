@@ -0,0 +1,211 @@
+use std::fmt;
+
+use num_traits::NumCast;
+use serde::de::{Error as _, IgnoredAny, MapAccess, Visitor};
+use serde::ser::{Error as _, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::MATRIX_TYPE_NAME;
+use crate::core::{self, DataType, Depth, Mat, MatType, Scalar};
+use crate::prelude::*;
+use crate::{Error, Result};
+
+const FIELDS: &[&str] = &["rows", "cols", "dt", "data"];
+
+/// Element type in the format used by `FileStorage`, e.g. `3u` for `CV_8UC3`
+fn dt_to_string(typ: MatType) -> Result<String> {
+	let depth = match typ.depth()? {
+		Depth::U8 => 'u',
+		Depth::I8 => 'c',
+		Depth::U16 => 'w',
+		Depth::I16 => 's',
+		Depth::I32 => 'i',
+		Depth::F32 => 'f',
+		Depth::F64 => 'd',
+		#[cfg(ocvrs_opencv_branch_4)]
+		Depth::F16 => 'h',
+	};
+	let channels = typ.channels();
+	Ok(if channels == 1 {
+		depth.to_string()
+	} else {
+		format!("{channels}{depth}")
+	})
+}
+
+fn dt_from_str(dt: &str) -> Result<MatType> {
+	let invalid = || Error::new(core::StsParseError, format!("Invalid matrix element type: {dt}"));
+	let depth = match dt.chars().last().ok_or_else(invalid)? {
+		'u' => Depth::U8,
+		'c' => Depth::I8,
+		'w' => Depth::U16,
+		's' => Depth::I16,
+		'i' => Depth::I32,
+		'f' => Depth::F32,
+		'd' => Depth::F64,
+		#[cfg(ocvrs_opencv_branch_4)]
+		'h' => Depth::F16,
+		_ => return Err(invalid()),
+	};
+	let channels = &dt[..dt.len() - 1];
+	let channels = if channels.is_empty() {
+		1
+	} else {
+		channels.parse().map_err(|_| invalid())?
+	};
+	if (1..=core::CV_CN_MAX).contains(&channels) {
+		Ok(MatType::from_depth_channels(depth, channels))
+	} else {
+		Err(invalid())
+	}
+}
+
+/// Continuous single channel view of the matrix data
+fn flat(mat: &Mat) -> Result<Mat> {
+	if mat.is_continuous() {
+		mat.reshape(1, 0)
+	} else {
+		mat.try_clone()?.reshape(1, 0)
+	}
+}
+
+/// Matrix elements as a flat sequence of numbers
+struct Data<'m>(&'m Mat);
+
+impl Serialize for Data<'_> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		fn collect<T: DataType + Serialize, S: Serializer>(mat: &Mat, serializer: S) -> Result<S::Ok, S::Error> {
+			serializer.collect_seq(mat.data_typed::<T>().map_err(S::Error::custom)?)
+		}
+
+		let mat = self.0;
+		if mat.empty() {
+			return serializer.collect_seq(&[] as &[u8]);
+		}
+		let flat = flat(mat).map_err(S::Error::custom)?;
+		match Depth::try_from(mat.depth()).map_err(S::Error::custom)? {
+			Depth::U8 => collect::<u8, S>(&flat, serializer),
+			Depth::I8 => collect::<i8, S>(&flat, serializer),
+			Depth::U16 => collect::<u16, S>(&flat, serializer),
+			Depth::I16 => collect::<i16, S>(&flat, serializer),
+			Depth::I32 => collect::<i32, S>(&flat, serializer),
+			Depth::F32 => collect::<f32, S>(&flat, serializer),
+			Depth::F64 => collect::<f64, S>(&flat, serializer),
+			#[cfg(ocvrs_opencv_branch_4)]
+			Depth::F16 => Err(S::Error::custom(
+				"Serialization of the matrix with CV_16F depth is not supported",
+			)),
+		}
+	}
+}
+
+/// Serialized as the struct named `opencv-matrix` with the same fields that OpenCV uses (`rows`, `cols`, `dt` and
+/// `data`), so with the `persistence` serializer the output is the same as with `FileStorage::write_mat()`
+///
+/// Only the 2-dimensional matrices are supported.
+impl Serialize for Mat {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		if self.dims() > 2 {
+			return Err(S::Error::custom(format!(
+				"Only 2-dimensional matrices can be serialized, this one has {} dimensions",
+				self.dims()
+			)));
+		}
+		let dt = dt_to_string(MatType::from(self.typ())).map_err(S::Error::custom)?;
+		let mut out = serializer.serialize_struct(MATRIX_TYPE_NAME, FIELDS.len())?;
+		out.serialize_field("rows", &self.rows())?;
+		out.serialize_field("cols", &self.cols())?;
+		out.serialize_field("dt", &dt)?;
+		out.serialize_field("data", &Data(self))?;
+		out.end()
+	}
+}
+
+impl<'de> Deserialize<'de> for Mat {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_struct(MATRIX_TYPE_NAME, FIELDS, MatVisitor)
+	}
+}
+
+struct MatVisitor;
+
+impl MatVisitor {
+	fn build(rows: i32, cols: i32, dt: &str, data: &[f64]) -> Result<Mat> {
+		fn fill<T: DataType + NumCast>(mat: &mut Mat, data: &[f64]) -> Result<()> {
+			for (dst, &src) in mat.data_typed_mut::<T>()?.iter_mut().zip(data) {
+				*dst = T::from(src).ok_or_else(|| {
+					Error::new(
+						core::StsOutOfRange,
+						format!("Matrix element {src} is out of range for the matrix type"),
+					)
+				})?;
+			}
+			Ok(())
+		}
+
+		let typ = dt_from_str(dt)?;
+		let mut out = Mat::new_rows_cols_with_default(rows, cols, typ.get(), Scalar::all(0.))?;
+		let expected = out.total() * typ.channels() as usize;
+		if data.len() != expected {
+			return Err(Error::new(
+				core::StsUnmatchedSizes,
+				format!(
+					"Matrix {rows}x{cols} of type {typ} must have {expected} data elements, but it has {}",
+					data.len()
+				),
+			));
+		}
+		if !out.empty() {
+			let mut flat = out.reshape(1, 0)?;
+			match typ.depth()? {
+				Depth::U8 => fill::<u8>(&mut flat, data)?,
+				Depth::I8 => fill::<i8>(&mut flat, data)?,
+				Depth::U16 => fill::<u16>(&mut flat, data)?,
+				Depth::I16 => fill::<i16>(&mut flat, data)?,
+				Depth::I32 => fill::<i32>(&mut flat, data)?,
+				Depth::F32 => fill::<f32>(&mut flat, data)?,
+				Depth::F64 => fill::<f64>(&mut flat, data)?,
+				#[cfg(ocvrs_opencv_branch_4)]
+				Depth::F16 => {
+					return Err(Error::new(
+						core::StsNotImplemented,
+						"Deserialization of the matrix with CV_16F depth is not supported",
+					))
+				}
+			}
+		}
+		Ok(out)
+	}
+}
+
+impl<'de> Visitor<'de> for MatVisitor {
+	type Value = Mat;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("opencv-matrix with rows, cols, dt and data")
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Mat, A::Error> {
+		let mut rows = None;
+		let mut cols = None;
+		let mut dt = None;
+		let mut data = None;
+		while let Some(key) = map.next_key::<String>()? {
+			match key.as_str() {
+				"rows" => rows = Some(map.next_value::<i32>()?),
+				"cols" => cols = Some(map.next_value::<i32>()?),
+				"dt" => dt = Some(map.next_value::<String>()?),
+				"data" => data = Some(map.next_value::<Vec<f64>>()?),
+				// e.g. `type_id` in JSON
+				_ => {
+					map.next_value::<IgnoredAny>()?;
+				}
+			}
+		}
+		let rows = rows.ok_or_else(|| A::Error::missing_field("rows"))?;
+		let cols = cols.ok_or_else(|| A::Error::missing_field("cols"))?;
+		let dt = dt.ok_or_else(|| A::Error::missing_field("dt"))?;
+		let data = data.ok_or_else(|| A::Error::missing_field("data"))?;
+		Self::build(rows, cols, &dt, &data).map_err(A::Error::custom)
+	}
+}
@@ -0,0 +1,573 @@
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use serde::ser::{self, Impossible, Serialize};
+
+use super::MATRIX_TYPE_NAME;
+use crate::core::{self, FileStorage, FileStorageTrait};
+use crate::{Error, Result};
+
+/// Writes a single value into `FileStorage` under the specified key
+///
+/// The key is empty for the elements of a sequence. The root serializer writes the fields of the top-level struct or map
+/// directly into the current `FileStorage` structure.
+pub struct Serializer<'f, 'n> {
+	fs: &'f mut FileStorage,
+	name: &'n str,
+	root: bool,
+	flow: bool,
+}
+
+impl<'f> Serializer<'f, 'static> {
+	pub fn root(fs: &'f mut FileStorage) -> Self {
+		Self {
+			fs,
+			name: "",
+			root: true,
+			flow: false,
+		}
+	}
+}
+
+impl<'f, 'n> Serializer<'f, 'n> {
+	#[inline]
+	fn in_seq(&self) -> bool {
+		!self.root && self.name.is_empty()
+	}
+
+	fn check_scalar(&self) -> Result<()> {
+		if self.root {
+			Err(Error::new(
+				core::StsBadArg,
+				"Only structs and maps can be serialized at the top level of FileStorage",
+			))
+		} else {
+			Ok(())
+		}
+	}
+
+	fn write_i32(self, v: i32) -> Result<()> {
+		self.check_scalar()?;
+		self.fs.write_i32(self.name, v)
+	}
+
+	fn write_int<T: Copy + Display>(self, v: T) -> Result<()>
+	where
+		i32: TryFrom<T>,
+	{
+		let v = i32::try_from(v).map_err(|_| {
+			Error::new(
+				core::StsOutOfRange,
+				format!("Integer {v} doesn't fit into 32 bits supported by FileStorage"),
+			)
+		})?;
+		self.write_i32(v)
+	}
+
+	fn write_str(self, v: &str) -> Result<()> {
+		self.check_scalar()?;
+		self.fs.write_str(self.name, v)
+	}
+
+	fn start(self, flags: i32, type_name: &str, ends: usize) -> Result<Compound<'f>> {
+		// the matrix data is written inline like OpenCV does it
+		let flow = self.flow || flags & core::FileNode_FLOW != 0 || type_name == MATRIX_TYPE_NAME;
+		self.fs.start_write_struct(self.name, flags, type_name)?;
+		Ok(Compound {
+			fs: self.fs,
+			flow,
+			ends,
+			key: None,
+		})
+	}
+
+	fn start_seq(self) -> Result<Compound<'f>> {
+		self.check_scalar()?;
+		let flags = if self.flow {
+			core::FileNode_SEQ | core::FileNode_FLOW
+		} else {
+			core::FileNode_SEQ
+		};
+		self.start(flags, "", 1)
+	}
+
+	fn start_map(self, type_name: &str) -> Result<Compound<'f>> {
+		if self.root {
+			Ok(Compound {
+				fs: self.fs,
+				flow: false,
+				ends: 0,
+				key: None,
+			})
+		} else {
+			self.start(core::FileNode_MAP, type_name, 1)
+		}
+	}
+
+	/// Starts the `{variant: ...}` map for the enum variant with data, `flags` are for the structure containing that data
+	fn start_variant(self, variant: &'static str, flags: i32) -> Result<Compound<'f>> {
+		let mut out = if self.root {
+			Compound {
+				fs: self.fs,
+				flow: false,
+				ends: 0,
+				key: None,
+			}
+		} else {
+			self.start(core::FileNode_MAP, "", 1)?
+		};
+		out.fs.start_write_struct(variant, flags, "")?;
+		out.ends += 1;
+		Ok(out)
+	}
+}
+
+impl<'f, 'n> ser::Serializer for Serializer<'f, 'n> {
+	type Ok = ();
+	type Error = Error;
+	type SerializeSeq = Compound<'f>;
+	type SerializeTuple = Compound<'f>;
+	type SerializeTupleStruct = Compound<'f>;
+	type SerializeTupleVariant = Compound<'f>;
+	type SerializeMap = Compound<'f>;
+	type SerializeStruct = Compound<'f>;
+	type SerializeStructVariant = Compound<'f>;
+
+	fn serialize_bool(self, v: bool) -> Result<()> {
+		self.write_i32(i32::from(v))
+	}
+
+	fn serialize_i8(self, v: i8) -> Result<()> {
+		self.write_i32(i32::from(v))
+	}
+
+	fn serialize_i16(self, v: i16) -> Result<()> {
+		self.write_i32(i32::from(v))
+	}
+
+	fn serialize_i32(self, v: i32) -> Result<()> {
+		self.write_i32(v)
+	}
+
+	fn serialize_i64(self, v: i64) -> Result<()> {
+		self.write_int(v)
+	}
+
+	fn serialize_u8(self, v: u8) -> Result<()> {
+		self.write_i32(i32::from(v))
+	}
+
+	fn serialize_u16(self, v: u16) -> Result<()> {
+		self.write_i32(i32::from(v))
+	}
+
+	fn serialize_u32(self, v: u32) -> Result<()> {
+		self.write_int(v)
+	}
+
+	fn serialize_u64(self, v: u64) -> Result<()> {
+		self.write_int(v)
+	}
+
+	fn serialize_f32(self, v: f32) -> Result<()> {
+		self.check_scalar()?;
+		core::write_f32(self.fs, self.name, v)
+	}
+
+	fn serialize_f64(self, v: f64) -> Result<()> {
+		self.check_scalar()?;
+		self.fs.write_f64(self.name, v)
+	}
+
+	fn serialize_char(self, v: char) -> Result<()> {
+		self.write_str(v.encode_utf8(&mut [0; 4]))
+	}
+
+	fn serialize_str(self, v: &str) -> Result<()> {
+		self.write_str(v)
+	}
+
+	fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+		self.check_scalar()?;
+		let mut out = self.start(core::FileNode_SEQ | core::FileNode_FLOW, "", 1)?;
+		for &b in v {
+			out.fs.write_i32("", i32::from(b))?;
+		}
+		out.finish()
+	}
+
+	fn serialize_none(self) -> Result<()> {
+		// the missing key is read back as `None`, but there is no way to skip an element of a sequence
+		if self.in_seq() {
+			Err(Error::new(
+				core::StsBadArg,
+				"None can't be serialized as an element of a FileStorage sequence",
+			))
+		} else {
+			Ok(())
+		}
+	}
+
+	fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<()> {
+		value.serialize(self)
+	}
+
+	fn serialize_unit(self) -> Result<()> {
+		self.check_scalar()?;
+		self.start(core::FileNode_MAP | core::FileNode_FLOW, "", 1)?.finish()
+	}
+
+	fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+		self.serialize_unit()
+	}
+
+	fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str) -> Result<()> {
+		self.write_str(variant)
+	}
+
+	fn serialize_newtype_struct<T: ?Sized + Serialize>(self, _name: &'static str, value: &T) -> Result<()> {
+		value.serialize(self)
+	}
+
+	fn serialize_newtype_variant<T: ?Sized + Serialize>(
+		self,
+		_name: &'static str,
+		_variant_index: u32,
+		variant: &'static str,
+		value: &T,
+	) -> Result<()> {
+		let mut out = self.start_map("")?;
+		ser::SerializeStruct::serialize_field(&mut out, variant, value)?;
+		out.finish()
+	}
+
+	fn serialize_seq(self, _len: Option<usize>) -> Result<Compound<'f>> {
+		self.start_seq()
+	}
+
+	fn serialize_tuple(self, _len: usize) -> Result<Compound<'f>> {
+		self.start_seq()
+	}
+
+	fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Compound<'f>> {
+		self.start_seq()
+	}
+
+	fn serialize_tuple_variant(
+		self,
+		_name: &'static str,
+		_variant_index: u32,
+		variant: &'static str,
+		_len: usize,
+	) -> Result<Compound<'f>> {
+		self.start_variant(variant, core::FileNode_SEQ)
+	}
+
+	fn serialize_map(self, _len: Option<usize>) -> Result<Compound<'f>> {
+		self.start_map("")
+	}
+
+	fn serialize_struct(self, name: &'static str, _len: usize) -> Result<Compound<'f>> {
+		if name == MATRIX_TYPE_NAME {
+			self.check_scalar()?;
+			self.start_map(MATRIX_TYPE_NAME)
+		} else {
+			self.start_map("")
+		}
+	}
+
+	fn serialize_struct_variant(
+		self,
+		_name: &'static str,
+		_variant_index: u32,
+		variant: &'static str,
+		_len: usize,
+	) -> Result<Compound<'f>> {
+		self.start_variant(variant, core::FileNode_MAP)
+	}
+}
+
+/// Currently written `FileStorage` structure (sequence or map)
+pub struct Compound<'f> {
+	fs: &'f mut FileStorage,
+	flow: bool,
+	/// Number of the structures to close when done
+	ends: usize,
+	/// Map key waiting for its value
+	key: Option<String>,
+}
+
+impl Compound<'_> {
+	fn element<T: ?Sized + Serialize>(&mut self, name: &str, value: &T) -> Result<()> {
+		value.serialize(Serializer {
+			fs: &mut *self.fs,
+			name,
+			root: false,
+			flow: self.flow,
+		})
+	}
+
+	fn finish(self) -> Result<()> {
+		for _ in 0..self.ends {
+			self.fs.end_write_struct()?;
+		}
+		Ok(())
+	}
+}
+
+impl ser::SerializeSeq for Compound<'_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+		self.element("", value)
+	}
+
+	fn end(self) -> Result<()> {
+		self.finish()
+	}
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+		self.element("", value)
+	}
+
+	fn end(self) -> Result<()> {
+		self.finish()
+	}
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+		self.element("", value)
+	}
+
+	fn end(self) -> Result<()> {
+		self.finish()
+	}
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+		self.element("", value)
+	}
+
+	fn end(self) -> Result<()> {
+		self.finish()
+	}
+}
+
+impl ser::SerializeMap for Compound<'_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+		self.key = Some(key.serialize(KeySerializer)?);
+		Ok(())
+	}
+
+	fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+		let key = self
+			.key
+			.take()
+			.ok_or_else(|| Error::new(core::StsError, "Map value is serialized before its key"))?;
+		self.element(&key, value)
+	}
+
+	fn end(self) -> Result<()> {
+		self.finish()
+	}
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<()> {
+		self.element(key, value)
+	}
+
+	fn end(self) -> Result<()> {
+		self.finish()
+	}
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<()> {
+		self.element(key, value)
+	}
+
+	fn end(self) -> Result<()> {
+		self.finish()
+	}
+}
+
+/// Extracts the map keys, `FileStorage` only supports string keys
+///
+/// Other scalars are rejected instead of being converted to strings: OpenCV requires the keys to start with a letter or `_`
+/// and they are always read back as strings.
+struct KeySerializer;
+
+impl KeySerializer {
+	fn unsupported(kind: &str) -> Error {
+		Error::new(
+			core::StsBadArg,
+			format!("FileStorage map keys must be strings or unit enum variants, got: {kind}"),
+		)
+	}
+}
+
+impl ser::Serializer for KeySerializer {
+	type Ok = String;
+	type Error = Error;
+	type SerializeSeq = Impossible<String, Error>;
+	type SerializeTuple = Impossible<String, Error>;
+	type SerializeTupleStruct = Impossible<String, Error>;
+	type SerializeTupleVariant = Impossible<String, Error>;
+	type SerializeMap = Impossible<String, Error>;
+	type SerializeStruct = Impossible<String, Error>;
+	type SerializeStructVariant = Impossible<String, Error>;
+
+	fn serialize_bool(self, _v: bool) -> Result<String> {
+		Err(Self::unsupported("bool"))
+	}
+
+	fn serialize_i8(self, _v: i8) -> Result<String> {
+		Err(Self::unsupported("integer"))
+	}
+
+	fn serialize_i16(self, _v: i16) -> Result<String> {
+		Err(Self::unsupported("integer"))
+	}
+
+	fn serialize_i32(self, _v: i32) -> Result<String> {
+		Err(Self::unsupported("integer"))
+	}
+
+	fn serialize_i64(self, _v: i64) -> Result<String> {
+		Err(Self::unsupported("integer"))
+	}
+
+	fn serialize_u8(self, _v: u8) -> Result<String> {
+		Err(Self::unsupported("integer"))
+	}
+
+	fn serialize_u16(self, _v: u16) -> Result<String> {
+		Err(Self::unsupported("integer"))
+	}
+
+	fn serialize_u32(self, _v: u32) -> Result<String> {
+		Err(Self::unsupported("integer"))
+	}
+
+	fn serialize_u64(self, _v: u64) -> Result<String> {
+		Err(Self::unsupported("integer"))
+	}
+
+	fn serialize_f32(self, _v: f32) -> Result<String> {
+		Err(Self::unsupported("float"))
+	}
+
+	fn serialize_f64(self, _v: f64) -> Result<String> {
+		Err(Self::unsupported("float"))
+	}
+
+	fn serialize_char(self, _v: char) -> Result<String> {
+		Err(Self::unsupported("char"))
+	}
+
+	fn serialize_str(self, v: &str) -> Result<String> {
+		Ok(v.to_string())
+	}
+
+	fn serialize_bytes(self, _v: &[u8]) -> Result<String> {
+		Err(Self::unsupported("bytes"))
+	}
+
+	fn serialize_none(self) -> Result<String> {
+		Err(Self::unsupported("None"))
+	}
+
+	fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<String> {
+		value.serialize(self)
+	}
+
+	fn serialize_unit(self) -> Result<String> {
+		Err(Self::unsupported("unit"))
+	}
+
+	fn serialize_unit_struct(self, _name: &'static str) -> Result<String> {
+		Err(Self::unsupported("unit struct"))
+	}
+
+	fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str) -> Result<String> {
+		Ok(variant.to_string())
+	}
+
+	fn serialize_newtype_struct<T: ?Sized + Serialize>(self, _name: &'static str, value: &T) -> Result<String> {
+		value.serialize(self)
+	}
+
+	fn serialize_newtype_variant<T: ?Sized + Serialize>(
+		self,
+		_name: &'static str,
+		_variant_index: u32,
+		_variant: &'static str,
+		_value: &T,
+	) -> Result<String> {
+		Err(Self::unsupported("newtype enum variant"))
+	}
+
+	fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+		Err(Self::unsupported("sequence"))
+	}
+
+	fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+		Err(Self::unsupported("tuple"))
+	}
+
+	fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeTupleStruct> {
+		Err(Self::unsupported("tuple struct"))
+	}
+
+	fn serialize_tuple_variant(
+		self,
+		_name: &'static str,
+		_variant_index: u32,
+		_variant: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeTupleVariant> {
+		Err(Self::unsupported("tuple enum variant"))
+	}
+
+	fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+		Err(Self::unsupported("map"))
+	}
+
+	fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+		Err(Self::unsupported("struct"))
+	}
+
+	fn serialize_struct_variant(
+		self,
+		_name: &'static str,
+		_variant_index: u32,
+		_variant: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeStructVariant> {
+		Err(Self::unsupported("struct enum variant"))
+	}
+}
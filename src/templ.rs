@@ -1,6 +1,29 @@
-use std::{ffi::CStr, os::raw::c_char, slice};
+use std::{
+	any::Any,
+	borrow::Cow,
+	collections::HashMap,
+	ffi::{c_void, CStr, CString},
+	mem::{self, MaybeUninit},
+	ops::Deref,
+	os::raw::c_char,
+	panic::{self, AssertUnwindSafe},
+	path::Path,
+	process, ptr, slice,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Mutex, MutexGuard, PoisonError,
+	},
+};
 
-use crate::platform_types::size_t;
+use once_cell::sync::Lazy;
+
+use crate::{
+	core::{_OutputArray, ToOutputArray},
+	platform_types::size_t,
+	sys,
+	traits::cstring_new_nofail,
+	Error, Result,
+};
 
 macro_rules! extern_container_arg {
 	(nofail mut $name: ident) => {
@@ -17,6 +40,21 @@ macro_rules! extern_container_arg {
 	};
 }
 
+macro_rules! path_arg {
+	(nofail mut $name: ident) => {
+		let mut $name = $crate::templ::path_to_cstring_nofail($name.as_ref());
+	};
+	(nofail $name: ident) => {
+		let $name = $crate::templ::path_to_cstring_nofail($name.as_ref());
+	};
+	(mut $name: ident) => {
+		let mut $name = $crate::templ::path_to_cstring($name.as_ref())?;
+	};
+	($name: ident) => {
+		let $name = $crate::templ::path_to_cstring($name.as_ref())?;
+	};
+}
+
 macro_rules! string_arg_output_send {
 	(via $name_via: ident) => {
 		let mut $name_via = ::std::ptr::null_mut();
@@ -40,10 +78,8 @@ macro_rules! byte_string_arg_output_receive {
 macro_rules! callback_arg {
 	($tr_name: ident($($tr_arg_name: ident: $tr_arg_type: ty),*) -> $tr_ret: ty => $tr_userdata_name: ident in $callbacks_name: ident => $callback_name: ident($($fw_arg_name: ident: $fw_arg_type: ty),*) -> $fw_ret: ty) => {
 		unsafe extern "C" fn trampoline($($tr_arg_name: $tr_arg_type),*) -> $tr_ret {
-			let mut callback: Box<Box<dyn FnMut($($fw_arg_type),*) -> $fw_ret + Send + Sync>> = Box::from_raw($tr_userdata_name as _);
-			let out = callback($($fw_arg_name),*);
-			Box::into_raw(callback);
-			out
+			let callback = $tr_userdata_name as *mut $crate::templ::CallbackData<Box<dyn FnMut($($fw_arg_type),*) -> $fw_ret + Send + Sync>>;
+			$crate::templ::CallbackData::call_catch_unwind(callback, |callback| callback($($fw_arg_name),*))
 		}
 
 		let $tr_name = if $callback_name.is_some() {
@@ -56,11 +92,40 @@ macro_rules! callback_arg {
 
 macro_rules! userdata_arg {
 	($userdata_name: ident in $callbacks_name: ident => $callback_name: ident) => {
-		let $userdata_name = if let Some(callback) = $callback_name {
-			Box::into_raw(Box::new(callback)) as *mut ::std::ffi::c_void
+		let $callbacks_name = $crate::templ::CallbackOwner::new($callback_name);
+		let $userdata_name = $callbacks_name.userdata();
+	};
+}
+
+/// Passes the ownership of the callback on right after the OpenCV function that received it has returned and only then
+/// resumes the panic that happened in the callback during the call, OpenCV can hold the callback by that time so it must be
+/// registered or leaked before the function is left by unwinding or by the error return
+macro_rules! callback_register {
+	($callbacks_name: ident, $ret: ident => $slot: literal, $window: expr, $name: expr) => {
+		let ocvrs_panic = $callbacks_name.take_panic();
+		if $ret.is_ok() {
+			$crate::templ::callback_registry_set($slot, $window, $name, $callbacks_name);
 		} else {
-			0 as _ // fixme, remove previous callback
-		};
+			// the call could've failed after OpenCV had already stored the new callback, so it's leaked and the previous
+			// one is kept registered
+			$callbacks_name.leak();
+		}
+		$crate::templ::callback_resume_unwind(ocvrs_panic);
+	};
+	// it's unknown for how long OpenCV keeps the callback so it's leaked
+	($callbacks_name: ident, $ret: ident) => {
+		let ocvrs_panic = $callbacks_name.take_panic();
+		$callbacks_name.leak();
+		$crate::templ::callback_resume_unwind(ocvrs_panic);
+	};
+}
+
+macro_rules! callback_release {
+	($window: expr) => {
+		$crate::templ::callback_registry_release(Some($window));
+	};
+	() => {
+		$crate::templ::callback_registry_release(None);
 	};
 }
 
@@ -72,7 +137,13 @@ macro_rules! input_array_arg {
 
 macro_rules! output_array_arg {
 	($name: ident) => {
-		let $name = $name.output_array()?;
+		let $name = $crate::templ::OutputArrayArg::new($name)?;
+	};
+}
+
+macro_rules! output_array_finish {
+	($name: ident) => {
+		$name.finish()?;
 	};
 }
 
@@ -124,6 +195,242 @@ unsafe extern "C" fn ocvrs_create_byte_string(v: *const u8, len: size_t) -> *mut
 	Box::into_raw(Box::new(v))
 }
 
+/// Frees the error message that was passed to C++ from the Rust implementation of a director class method
+#[no_mangle]
+unsafe extern "C" fn ocvrs_free_cstring(s: *mut c_char) {
+	drop(CString::from_raw(s));
+}
+
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> &str {
+	if let Some(msg) = payload.downcast_ref::<&str>() {
+		msg
+	} else if let Some(msg) = payload.downcast_ref::<String>() {
+		msg
+	} else {
+		"Box<dyn Any>"
+	}
+}
+
+/// Userdata of the callback that is passed to OpenCV, it also holds the panic that happened in the callback during the call
+/// of the OpenCV function that received it, see [CallbackOwner::take_panic]
+#[repr(C)]
+pub struct CallbackData<T> {
+	// must be the first field so that it can be accessed through the type-erased userdata pointer
+	state: CallbackState,
+	callback: T,
+}
+
+#[derive(Default)]
+struct CallbackState {
+	/// Set once the OpenCV function that received the callback has returned
+	detached: AtomicBool,
+	/// The first panic in the callback during the call, the callback can be called from several threads
+	panic: Mutex<Option<Box<dyn Any + Send>>>,
+}
+
+impl<T> CallbackData<T> {
+	/// Runs the user callback that was called from C++, panics must not unwind into C++ so they are caught and stored to be
+	/// resumed by the enclosing OpenCV call
+	///
+	/// If the callback is called after the function that received it has returned (e.g. a mouse callback that's called
+	/// during `wait_key()`), there is no Rust caller to resume the panic in and the process is aborted.
+	///
+	/// # Safety
+	/// `data` must be the userdata of a live [CallbackOwner]. In case of panic the zeroed value is returned to C++, so `R` must
+	/// be valid when zeroed (the return types of C callbacks are).
+	#[inline]
+	pub unsafe fn call_catch_unwind<R>(data: *mut Self, f: impl FnOnce(&mut T) -> R) -> R {
+		(*data).state.catch_unwind(|| f(&mut (*data).callback))
+	}
+
+	/// Same as [CallbackData::call_catch_unwind], but the callback is only shared so that it can run in several threads at
+	/// once
+	///
+	/// # Safety
+	/// See [CallbackData::call_catch_unwind]
+	#[inline]
+	pub unsafe fn call_shared_catch_unwind<R>(data: *const Self, f: impl FnOnce(&T) -> R) -> R {
+		(*data).state.catch_unwind(|| f(&(*data).callback))
+	}
+}
+
+impl CallbackState {
+	unsafe fn catch_unwind<R>(&self, f: impl FnOnce() -> R) -> R {
+		match panic::catch_unwind(AssertUnwindSafe(f)) {
+			Ok(out) => out,
+			Err(payload) => {
+				if self.detached.load(Ordering::Acquire) {
+					eprintln!(
+						"Rust panic in callback called outside of the OpenCV function that received it: {}",
+						panic_message(payload.as_ref())
+					);
+					process::abort();
+				}
+				let mut panic = self.panic.lock().unwrap_or_else(PoisonError::into_inner);
+				if panic.is_none() {
+					*panic = Some(payload);
+				}
+				MaybeUninit::zeroed().assume_init()
+			}
+		}
+	}
+}
+
+/// Boxed callback that is passed to OpenCV as a userdata pointer, it's dropped together with this object unless it's
+/// registered in the callback registry or leaked
+pub struct CallbackOwner {
+	userdata: *mut c_void,
+	drop_userdata: unsafe fn(*mut c_void),
+}
+
+// the callbacks themselves are `Send`
+unsafe impl Send for CallbackOwner {}
+
+impl CallbackOwner {
+	#[inline]
+	pub fn new<T: Send + 'static>(callback: Option<T>) -> Self {
+		unsafe fn drop_userdata<T>(userdata: *mut c_void) {
+			drop(Box::from_raw(userdata as *mut CallbackData<T>));
+		}
+
+		Self {
+			userdata: callback.map_or(ptr::null_mut(), |callback| {
+				Box::into_raw(Box::new(CallbackData {
+					state: CallbackState::default(),
+					callback,
+				})) as *mut c_void
+			}),
+			drop_userdata: drop_userdata::<T>,
+		}
+	}
+
+	#[inline]
+	pub fn userdata(&self) -> *mut c_void {
+		self.userdata
+	}
+
+	/// Called right after the OpenCV function that received the callback has returned, detaches the callback and takes the
+	/// panic that happened in it during that call, see [callback_resume_unwind]
+	pub fn take_panic(&self) -> Option<Box<dyn Any + Send>> {
+		if self.userdata.is_null() {
+			return None;
+		}
+		self.detach();
+		self.state().panic.lock().unwrap_or_else(PoisonError::into_inner).take()
+	}
+
+	/// Marks the callback as called outside of any OpenCV function call, so a panic in it aborts the process, see
+	/// [CallbackData::call_catch_unwind]
+	pub fn detach(&self) {
+		if !self.userdata.is_null() {
+			self.state().detached.store(true, Ordering::Release);
+		}
+	}
+
+	#[inline]
+	fn state(&self) -> &CallbackState {
+		// `CallbackData` is `repr(C)` with `CallbackState` as the first field
+		unsafe { &*(self.userdata as *const CallbackState) }
+	}
+
+	#[inline]
+	pub fn leak(self) {
+		mem::forget(self);
+	}
+}
+
+impl Drop for CallbackOwner {
+	fn drop(&mut self) {
+		if !self.userdata.is_null() {
+			unsafe { (self.drop_userdata)(self.userdata) };
+		}
+	}
+}
+
+/// Resumes the unwinding of the panic taken by [CallbackOwner::take_panic]
+///
+/// The panic takes precedence over the result of the call, the callback could've been in the middle of the processing, so
+/// the result can't be trusted.
+#[inline]
+pub fn callback_resume_unwind(payload: Option<Box<dyn Any + Send>>) {
+	if let Some(payload) = payload {
+		panic::resume_unwind(payload);
+	}
+}
+
+/// (function that registered the callback, window name, callback name)
+type CallbackSlotKey = (&'static str, Vec<u8>, Vec<u8>);
+
+static CALLBACK_REGISTRY: Lazy<Mutex<HashMap<CallbackSlotKey, CallbackOwner>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn callback_registry() -> MutexGuard<'static, HashMap<CallbackSlotKey, CallbackOwner>> {
+	CALLBACK_REGISTRY.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Stores the callback that was passed to OpenCV replacing (and dropping) the previous one in the same slot, `None` callback
+/// just drops the previous one
+pub fn callback_registry_set(slot: &'static str, window: &[u8], name: &[u8], callback: CallbackOwner) {
+	let key = (slot, window.to_vec(), name.to_vec());
+	let prev = if callback.userdata().is_null() {
+		callback_registry().remove(&key)
+	} else {
+		callback_registry().insert(key, callback)
+	};
+	// dropped outside of the lock in case the captured state calls back into OpenCV
+	drop(prev);
+}
+
+/// Drops the callbacks of the window that was destroyed, `None` drops all the callbacks
+pub fn callback_registry_release(window: Option<&[u8]>) {
+	let released = {
+		let mut registry = callback_registry();
+		if let Some(window) = window {
+			let (released, kept) = mem::take(&mut *registry)
+				.into_iter()
+				.partition::<HashMap<_, _>, _>(|((_, slot_window, _), _)| slot_window == window);
+			*registry = kept;
+			released
+		} else {
+			mem::take(&mut *registry)
+		}
+	};
+	drop(released);
+}
+
+/// Calls the Rust implementation of a director class method and stores the outcome in `ocvrs_return`
+///
+/// Panics are not allowed to cross the FFI boundary so they are caught and reported as errors. The error message is passed
+/// as a C string and is freed by the C++ side (`ocvrs_director_rethrow`) after it's converted to `cv::Exception`.
+pub unsafe fn director_call<S>(ocvrs_return: *mut sys::Result<S>, f: impl FnOnce() -> Result<S>) {
+	let res = panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+		Err(Error::new(
+			crate::core::StsError,
+			format!("Rust panic in director class method: {}", panic_message(payload.as_ref())),
+		))
+	});
+	let ocvrs_return = &mut *ocvrs_return;
+	match res {
+		Ok(val) => {
+			ocvrs_return.error_code = 0;
+			ocvrs_return.error_msg = ptr::null_mut();
+			ocvrs_return.result = MaybeUninit::new(val);
+		}
+		Err(e) => {
+			ocvrs_return.error_code = e.code;
+			ocvrs_return.error_msg = cstring_new_nofail(e.message).into_raw().cast();
+		}
+	}
+}
+
+/// Drops the Rust implementation of a director class, called from the destructor of the C++ director object
+///
+/// The destructor can't report errors and panics are not allowed to cross the FFI boundary so a panic in the `Drop` of the
+/// implementation is caught and ignored.
+pub unsafe fn director_delete<T: ?Sized>(ocvrs_self: *mut Box<T>) {
+	let director = Box::from_raw(ocvrs_self);
+	let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(director)));
+}
+
 #[inline]
 pub unsafe fn receive_string(s: *mut String) -> String {
 	if s.is_null() {
@@ -139,3 +446,62 @@ pub unsafe fn receive_byte_string(s: *mut Vec<u8>) -> Vec<u8> {
 	}
 	*Box::from_raw(s)
 }
+
+/// Raw bytes of the path as passed to OpenCV, on Unix the OS bytes are passed through as is so that non-UTF-8 paths work
+#[cfg(unix)]
+fn path_bytes(path: &Path) -> Result<Cow<[u8]>> {
+	use std::os::unix::ffi::OsStrExt;
+	Ok(Cow::Borrowed(path.as_os_str().as_bytes()))
+}
+
+#[cfg(not(unix))]
+fn path_bytes(path: &Path) -> Result<Cow<[u8]>> {
+	path.to_str().map(|path| Cow::Borrowed(path.as_bytes())).ok_or_else(|| {
+		crate::Error::new(
+			crate::core::StsBadArg,
+			format!("Passed path is not valid UTF-8: {}", path.display()),
+		)
+	})
+}
+
+#[inline]
+pub fn path_to_cstring(path: &Path) -> Result<CString> {
+	CString::new(path_bytes(path)?).map_err(|e| e.into())
+}
+
+#[inline]
+pub fn path_to_cstring_nofail(path: &Path) -> CString {
+	match path_bytes(path) {
+		Ok(bytes) => cstring_new_nofail(bytes),
+		Err(_) => cstring_new_nofail(path.to_string_lossy().into_owned()),
+	}
+}
+
+/// `OutputArray` argument of the generated function, it keeps the passed object borrowed so that the output can be passed
+/// back to it with [ToOutputArray::output_array_finish] after the successful call
+pub struct OutputArrayArg<'t, T: ToOutputArray> {
+	target: &'t mut T,
+	array: _OutputArray,
+}
+
+impl<'t, T: ToOutputArray> OutputArrayArg<'t, T> {
+	#[inline]
+	pub fn new(target: &'t mut T) -> Result<Self> {
+		let array = target.output_array()?;
+		Ok(Self { target, array })
+	}
+
+	#[inline]
+	pub fn finish(self) -> Result<()> {
+		self.target.output_array_finish(&self.array)
+	}
+}
+
+impl<T: ToOutputArray> Deref for OutputArrayArg<'_, T> {
+	type Target = _OutputArray;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.array
+	}
+}
@@ -1 +1,2 @@
 mod sys;
+mod templ;
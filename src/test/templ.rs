@@ -0,0 +1,230 @@
+use std::ffi::c_void;
+use std::mem::MaybeUninit;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread;
+
+use once_cell::sync::Lazy;
+
+use crate::sys;
+use crate::templ::{callback_registry_release, callback_registry_set, CallbackData, CallbackOwner};
+
+type Callback = Option<Box<dyn FnMut(i32) -> i32 + Send + Sync>>;
+
+/// Sets up the callback the same way the generated wrappers do, returns its owner, trampoline and userdata
+fn callback(
+	callback: impl FnMut(i32) -> i32 + Send + Sync + 'static,
+) -> (CallbackOwner, unsafe extern "C" fn(i32, *mut c_void) -> i32, *mut c_void) {
+	let callback: Callback = Some(Box::new(callback));
+	callback_arg!(callback_trampoline(val: i32, userdata: *mut c_void) -> i32 => userdata in callbacks => callback(val: i32) -> i32);
+	userdata_arg!(userdata in callbacks => callback);
+	(
+		callbacks,
+		callback_trampoline.expect("Trampoline must be set for Some callback"),
+		userdata,
+	)
+}
+
+#[test]
+fn callback_no_panic() {
+	let (callbacks, trampoline, userdata) = callback(|val| val * 2);
+	assert_eq!(4, unsafe { trampoline(2, userdata) });
+	assert!(callbacks.take_panic().is_none());
+}
+
+#[test]
+fn callback_panic_resumed() {
+	let (callbacks, trampoline, userdata) = callback(|val| {
+		if val > 1 {
+			panic!("callback panic")
+		} else {
+			val
+		}
+	});
+	assert_eq!(1, unsafe { trampoline(1, userdata) });
+	// the zeroed value is returned to C++ on panic, the following calls run normally
+	assert_eq!(0, unsafe { trampoline(2, userdata) });
+	assert_eq!(1, unsafe { trampoline(1, userdata) });
+	let payload = callbacks.take_panic().expect("Must store the panic");
+	assert_eq!(Some(&"callback panic"), payload.downcast_ref::<&str>());
+}
+
+#[test]
+fn callback_panic_from_other_thread() {
+	let (callbacks, trampoline, userdata) = callback(|val| {
+		if val == 3 {
+			panic!("thread {val}")
+		} else {
+			val
+		}
+	});
+	// the pointer is passed as usize because raw pointers are not `Send`
+	let userdata_addr = userdata as usize;
+	let workers = (0..4)
+		.map(|val| thread::spawn(move || unsafe { trampoline(val, userdata_addr as *mut c_void) }))
+		.collect::<Vec<_>>();
+	let res = workers
+		.into_iter()
+		.map(|worker| worker.join().expect("Panic must not escape the trampoline"))
+		.collect::<Vec<_>>();
+	assert_eq!(vec![0, 1, 2, 0], res);
+	let payload = callbacks.take_panic().expect("Must store the panic");
+	assert_eq!(Some("thread 3"), payload.downcast_ref::<String>().map(|s| s.as_str()));
+}
+
+#[test]
+fn callback_panic_is_per_call() {
+	let (callbacks_1, trampoline_1, userdata_1) = callback(|_| panic!("first"));
+	let (callbacks_2, trampoline_2, userdata_2) = callback(|val| val);
+	assert_eq!(0, unsafe { trampoline_1(1, userdata_1) });
+	assert_eq!(2, unsafe { trampoline_2(2, userdata_2) });
+	// the panic of the first call doesn't leak into the unrelated second one
+	assert!(callbacks_2.take_panic().is_none());
+	let payload = callbacks_1.take_panic().expect("Must store the panic");
+	assert_eq!(Some(&"first"), payload.downcast_ref::<&str>());
+	// the panic is taken only once
+	assert!(callbacks_1.take_panic().is_none());
+}
+
+#[test]
+fn callback_shared_call() {
+	type Shared = Box<dyn Fn(i32) -> i32 + Send + Sync>;
+	let callbacks = CallbackOwner::new(Some(Box::new(|val: i32| {
+		if val == 2 {
+			panic!("shared")
+		} else {
+			val + 1
+		}
+	}) as Shared));
+	let userdata_addr = callbacks.userdata() as usize;
+	let call = move |val| unsafe {
+		CallbackData::call_shared_catch_unwind(userdata_addr as *const CallbackData<Shared>, |callback| callback(val))
+	};
+	let workers = (0..3).map(|val| thread::spawn(move || call(val))).collect::<Vec<_>>();
+	let res = workers
+		.into_iter()
+		.map(|worker| worker.join().expect("Panic must not escape the call"))
+		.collect::<Vec<_>>();
+	assert_eq!(vec![1, 2, 0], res);
+	let payload = callbacks.take_panic().expect("Must store the panic");
+	assert_eq!(Some(&"shared"), payload.downcast_ref::<&str>());
+}
+
+/// Increments the shared counter when dropped
+struct DropCount(Arc<AtomicUsize>);
+
+impl Drop for DropCount {
+	fn drop(&mut self) {
+		self.0.fetch_add(1, Ordering::SeqCst);
+	}
+}
+
+fn counted_callback() -> (CallbackOwner, Arc<AtomicUsize>) {
+	let drops = Arc::new(AtomicUsize::new(0));
+	(CallbackOwner::new(Some(DropCount(Arc::clone(&drops)))), drops)
+}
+
+/// The registry is global and `callback_registry_release(None)` drops everything, so the tests using it are serialized
+static REGISTRY_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+#[test]
+fn callback_registry_set_replace() {
+	let _lock = REGISTRY_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+	let (first, first_drops) = counted_callback();
+	let (second, second_drops) = counted_callback();
+	let (other, other_drops) = counted_callback();
+	callback_registry_set("test::set", b"win", b"cb", first);
+	callback_registry_set("test::set", b"win", b"other", other);
+	assert_eq!(0, first_drops.load(Ordering::SeqCst));
+	// the same slot, the previous callback is dropped
+	callback_registry_set("test::set", b"win", b"cb", second);
+	assert_eq!(1, first_drops.load(Ordering::SeqCst));
+	assert_eq!(0, second_drops.load(Ordering::SeqCst));
+	// `None` callback only drops the previous one
+	callback_registry_set("test::set", b"win", b"cb", CallbackOwner::new(None::<DropCount>));
+	assert_eq!(1, first_drops.load(Ordering::SeqCst));
+	assert_eq!(1, second_drops.load(Ordering::SeqCst));
+	assert_eq!(0, other_drops.load(Ordering::SeqCst));
+	callback_registry_release(Some(b"win".as_slice()));
+	assert_eq!(1, other_drops.load(Ordering::SeqCst));
+	// released callbacks are not dropped again
+	callback_registry_release(Some(b"win".as_slice()));
+	assert_eq!(1, first_drops.load(Ordering::SeqCst));
+	assert_eq!(1, second_drops.load(Ordering::SeqCst));
+	assert_eq!(1, other_drops.load(Ordering::SeqCst));
+}
+
+#[test]
+fn callback_registry_release_window() {
+	let _lock = REGISTRY_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+	let (mouse_1, mouse_1_drops) = counted_callback();
+	let (trackbar_1, trackbar_1_drops) = counted_callback();
+	let (mouse_2, mouse_2_drops) = counted_callback();
+	callback_registry_set("test::mouse", b"win_1", b"", mouse_1);
+	callback_registry_set("test::trackbar", b"win_1", b"bar", trackbar_1);
+	callback_registry_set("test::mouse", b"win_2", b"", mouse_2);
+	callback_registry_release(Some(b"win_1".as_slice()));
+	assert_eq!(1, mouse_1_drops.load(Ordering::SeqCst));
+	assert_eq!(1, trackbar_1_drops.load(Ordering::SeqCst));
+	assert_eq!(0, mouse_2_drops.load(Ordering::SeqCst));
+	callback_registry_release(None);
+	assert_eq!(1, mouse_1_drops.load(Ordering::SeqCst));
+	assert_eq!(1, trackbar_1_drops.load(Ordering::SeqCst));
+	assert_eq!(1, mouse_2_drops.load(Ordering::SeqCst));
+}
+
+/// Does the same as the generated wrapper of the slot setter after the OpenCV call that succeeded or failed
+fn register_in_slot(callbacks: CallbackOwner, call_ok: bool) {
+	let mut ret = unsafe { MaybeUninit::<sys::Result<i32>>::zeroed().assume_init() };
+	if !call_ok {
+		ret.error_msg = NonNull::dangling().as_ptr();
+	}
+	callback_register!(callbacks, ret => "test::register", b"win_register", b"");
+}
+
+#[test]
+fn callback_register_before_resume() {
+	let _lock = REGISTRY_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+	let drops = Arc::new(AtomicUsize::new(0));
+	let drop_count = DropCount(Arc::clone(&drops));
+	let (callbacks, trampoline, userdata) = callback(move |_| {
+		let _ = &drop_count;
+		panic!("registered")
+	});
+	assert_eq!(0, unsafe { trampoline(1, userdata) });
+	let payload = panic::catch_unwind(AssertUnwindSafe(|| register_in_slot(callbacks, true))).expect_err("Must resume the panic");
+	assert_eq!(Some(&"registered"), payload.downcast_ref::<&str>());
+	// OpenCV still holds the callback, so it's registered and not dropped by the unwinding
+	assert_eq!(0, drops.load(Ordering::SeqCst));
+	callback_registry_release(Some(b"win_register".as_slice()));
+	assert_eq!(1, drops.load(Ordering::SeqCst));
+}
+
+#[test]
+fn callback_register_failed_call() {
+	let _lock = REGISTRY_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+	let (first, first_drops) = counted_callback();
+	let (second, second_drops) = counted_callback();
+	register_in_slot(first, true);
+	register_in_slot(second, false);
+	// it's unknown which of the callbacks OpenCV holds after the failed call, so neither is dropped
+	assert_eq!(0, first_drops.load(Ordering::SeqCst));
+	assert_eq!(0, second_drops.load(Ordering::SeqCst));
+	callback_registry_release(Some(b"win_register".as_slice()));
+	assert_eq!(1, first_drops.load(Ordering::SeqCst));
+	assert_eq!(0, second_drops.load(Ordering::SeqCst));
+}
+
+#[test]
+fn callback_owner_drop() {
+	let (owner, drops) = counted_callback();
+	assert!(!owner.userdata().is_null());
+	drop(owner);
+	assert_eq!(1, drops.load(Ordering::SeqCst));
+	let (owner, drops) = counted_callback();
+	owner.leak();
+	assert_eq!(0, drops.load(Ordering::SeqCst));
+	assert!(CallbackOwner::new(None::<DropCount>).userdata().is_null());
+}
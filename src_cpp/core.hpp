@@ -21,3 +21,9 @@
 namespace cv {
 	static const char *CV_VERSION_OCVRS_OVERRIDE = CV_VERSION;
 }
+
+// releases the `_InputArray` together with the storage that it might own, see `manual-core.cpp`
+void ocvrs_input_array_delete(cv::_InputArray* instance);
+
+// releases the `_OutputArray` together with the storage that it might own, see `manual-core.cpp`
+void ocvrs_output_array_delete(cv::_OutputArray* instance);
@@ -1,4 +1,12 @@
 #include "core.hpp"
+#include <atomic>
+#include <cstdint>
+#include <memory>
+#include <utility>
+#if (CV_VERSION_MAJOR == 4 && (CV_VERSION_MINOR > 5 || (CV_VERSION_MINOR == 5 && CV_VERSION_REVISION >= 2))) || CV_VERSION_MAJOR > 4 /* 4.5.2+ */
+	#include <opencv2/core/parallel/parallel_backend.hpp>
+	#define OCVRS_HAS_PARALLEL_BACKEND
+#endif
 
 template struct Result<void*>;
 template struct Result<cv::Size>;
@@ -22,6 +30,157 @@ template<typename T> inline void ocvrs_input_output_array(T* instance, Result<vo
 	} OCVRS_CATCH(Result<void*>, ocvrs_return)
 }
 
+// `_InputArray` and `_OutputArray` don't own the object they reference and don't have a virtual destructor, so the temporary
+// objects that are created for the Rust slices and `Vec`s are owned by the derived holders below. A holder is recognized in
+// `ocvrs_input_array_delete()` or `ocvrs_output_array_delete()` by referencing its own member, the copies of the holder
+// reference the member of the original and are deleted as plain arrays.
+template<typename Holder, typename Array>
+inline bool ocvrs_is_array_holder(const Array* array, const void* obj) {
+	// offset of the referenced member from the `Array` base, it's the same for all instances of `Holder`
+	static const std::ptrdiff_t offset = Holder::member_offset();
+	return reinterpret_cast<std::uintptr_t>(obj) == reinterpret_cast<std::uintptr_t>(array) + offset;
+}
+
+// Mat headers for the Rust slices of `Mat`s
+struct ocvrs_MatSliceInputArray : public cv::_InputArray {
+	std::vector<cv::Mat> mats;
+
+	explicit ocvrs_MatSliceInputArray(std::vector<cv::Mat>&& mats) : cv::_InputArray(), mats(std::move(mats)) {
+		init(STD_VECTOR_MAT + cv::ACCESS_READ, &this->mats);
+	}
+
+	static std::ptrdiff_t member_offset() {
+		const ocvrs_MatSliceInputArray probe((std::vector<cv::Mat>()));
+		return reinterpret_cast<const char*>(&probe.mats) - reinterpret_cast<const char*>(static_cast<const cv::_InputArray*>(&probe));
+	}
+};
+
+void ocvrs_input_array_delete(cv::_InputArray* instance) {
+	if (instance->kind() == cv::_InputArray::STD_VECTOR_MAT && ocvrs_is_array_holder<ocvrs_MatSliceInputArray>(instance, instance->getObj())) {
+		delete static_cast<ocvrs_MatSliceInputArray*>(instance);
+	} else {
+		delete instance;
+	}
+}
+
+// temporary `Mat` of the fixed element type for the Rust `Vec`s, same as `_OutputArray(Mat_<_Tp>& m)`, but with the runtime
+// element type, the output is copied to the `Vec` after the call
+struct ocvrs_VecOutputArray : public cv::_OutputArray {
+	cv::Mat mat;
+
+	explicit ocvrs_VecOutputArray(int type) : cv::_OutputArray(), mat(0, 0, type) {
+		init(FIXED_TYPE + MAT + type + cv::ACCESS_WRITE, &mat);
+	}
+
+	static std::ptrdiff_t member_offset() {
+		const ocvrs_VecOutputArray probe(CV_8U);
+		return reinterpret_cast<const char*>(&probe.mat) - reinterpret_cast<const char*>(static_cast<const cv::_OutputArray*>(&probe));
+	}
+};
+
+void ocvrs_output_array_delete(cv::_OutputArray* instance) {
+	if (instance->kind() == cv::_InputArray::MAT && ocvrs_is_array_holder<ocvrs_VecOutputArray>(instance, instance->getObj())) {
+		delete static_cast<ocvrs_VecOutputArray*>(instance);
+	} else {
+		delete instance;
+	}
+}
+
+// exposes the protected `_InputArray::init()` to reference the memory owned by Rust
+struct ocvrs_InputArrayInit : public cv::_InputArray {
+	ocvrs_InputArrayInit(int flags, const void* obj, cv::Size sz) {
+		init(flags, obj, sz);
+	}
+};
+
+
+// all bytes of the element are zero, that's how `SparseMat` checks for zero elements
+static bool ocvrs_is_zero_elem(const unsigned char* elem, size_t esz) {
+	for (size_t i = 0; i < esz; ++i) {
+		if (elem[i] != 0) {
+			return false;
+		}
+	}
+	return true;
+}
+
+// receives the OpenCV log messages in Rust, `tag`, `file` and `func` can be null
+typedef void (*ocvrs_log_sink)(int level, const char* tag, const char* file, int line, const char* func, const char* message);
+
+#if (CV_VERSION_MAJOR == 4 && CV_VERSION_MINOR >= 8) || CV_VERSION_MAJOR > 4 /* 4.8+ */
+	#define OCVRS_HAS_LOG_SINK
+
+	static std::atomic<ocvrs_log_sink> ocvrs_log_sink_fn(nullptr);
+
+	static void ocvrs_write_log_message(cv::utils::logging::LogLevel level, const char* message) {
+		if (ocvrs_log_sink sink = ocvrs_log_sink_fn.load()) {
+			sink(level, nullptr, nullptr, 0, nullptr, message);
+		}
+	}
+
+	static void ocvrs_write_log_message_ex(cv::utils::logging::LogLevel level, const char* tag, const char* file, int line, const char* func, const char* message) {
+		if (ocvrs_log_sink sink = ocvrs_log_sink_fn.load()) {
+			sink(level, tag, file, line, func, message);
+		}
+	}
+#endif
+
+// `cv::parallel::ParallelForAPI` that dispatches the stripes to the rayon thread pool, the pool is owned by Rust and is
+// released with `delete_pool`
+typedef void (*ocvrs_parallel_body_cb)(int start, int end, void* data);
+typedef void (*ocvrs_rayon_parallel_for)(const void* pool, int tasks, ocvrs_parallel_body_cb body, void* data);
+typedef int (*ocvrs_rayon_thread_num)(const void* pool);
+typedef void (*ocvrs_rayon_delete_pool)(void* pool);
+
+#ifdef OCVRS_HAS_PARALLEL_BACKEND
+	class ocvrs_RayonParallelForAPI : public cv::parallel::ParallelForAPI {
+		void* pool;
+		ocvrs_rayon_parallel_for parallel_for_fn;
+		ocvrs_rayon_thread_num thread_num_fn;
+		ocvrs_rayon_thread_num num_threads_fn;
+		ocvrs_rayon_delete_pool delete_pool_fn;
+
+		// OpenCV catches the exceptions of the loop body itself, but they must never reach Rust
+		static void CV_CDECL body_cb(int start, int end, void* data) {
+			try {
+				const std::pair<FN_parallel_for_body_cb_t, void*>& body = *static_cast<std::pair<FN_parallel_for_body_cb_t, void*>*>(data);
+				body.first(start, end, body.second);
+			} catch (...) {
+			}
+		}
+
+	public:
+		ocvrs_RayonParallelForAPI(void* pool, ocvrs_rayon_parallel_for parallel_for_fn, ocvrs_rayon_thread_num thread_num_fn, ocvrs_rayon_thread_num num_threads_fn, ocvrs_rayon_delete_pool delete_pool_fn)
+			: pool(pool), parallel_for_fn(parallel_for_fn), thread_num_fn(thread_num_fn), num_threads_fn(num_threads_fn), delete_pool_fn(delete_pool_fn) {}
+
+		~ocvrs_RayonParallelForAPI() CV_OVERRIDE {
+			delete_pool_fn(pool);
+		}
+
+		void parallel_for(int tasks, FN_parallel_for_body_cb_t body_callback, void* callback_data) CV_OVERRIDE {
+			std::pair<FN_parallel_for_body_cb_t, void*> body(body_callback, callback_data);
+			parallel_for_fn(pool, tasks, body_cb, &body);
+		}
+
+		int getThreadNum() const CV_OVERRIDE {
+			return thread_num_fn(pool);
+		}
+
+		int getNumThreads() const CV_OVERRIDE {
+			return num_threads_fn(pool);
+		}
+
+		// the size of the rayon thread pool is fixed
+		int setNumThreads(int) CV_OVERRIDE {
+			return num_threads_fn(pool);
+		}
+
+		const char* getName() const CV_OVERRIDE {
+			return "rayon";
+		}
+	};
+#endif
+
 #define ocvrs_ioa(base) \
 	void cv_##base##_input_array(const cv::base* instance, Result<void*>* ocvrs_return) { return ocvrs_input_array(instance, ocvrs_return); } \
 	void cv_##base##_output_array(cv::base* instance, Result<void*>* ocvrs_return) { return ocvrs_output_array(instance, ocvrs_return); } \
@@ -79,6 +238,109 @@ extern "C" {
 		return instance->ptr != instance->sliceEnd;
 	}
 
+	// same as `_InputArray(const _Tp* vec, int n)`, but with the runtime element type
+	void cv_manual_InputArray_from_slice(int type, const void* data, int len, Result<void*>* ocvrs_return) {
+		try {
+			const int flags = cv::_InputArray::FIXED_TYPE + cv::_InputArray::FIXED_SIZE + cv::_InputArray::MATX + CV_MAT_TYPE(type) + cv::ACCESS_READ;
+			Ok<void*>(new cv::_InputArray(ocvrs_InputArrayInit(flags, data, cv::Size(len, 1))), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
+	void cv_manual_InputArray_from_mat_slice(const cv::Mat* const* mats, size_t len, Result<void*>* ocvrs_return) {
+		try {
+			std::vector<cv::Mat> headers;
+			headers.reserve(len);
+			for (size_t i = 0; i < len; ++i) {
+				headers.push_back(*mats[i]);
+			}
+			Ok<void*>(static_cast<cv::_InputArray*>(new ocvrs_MatSliceInputArray(std::move(headers))), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
+	void cv_manual_OutputArray_for_vec(int type, Result<void*>* ocvrs_return) {
+		try {
+			Ok<void*>(static_cast<cv::_OutputArray*>(new ocvrs_VecOutputArray(type)), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
+	// the data is copied to `std::string` so it can contain nul bytes
+	void cv_manual_FileStorage_from_bytes(const char* data, size_t len, int flags, const char* encoding, Result<void*>* ocvrs_return) {
+		try {
+			Ok<void*>(new cv::FileStorage(std::string(data, len), flags, std::string(encoding)), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
+	// zero elements are not stored, same as in `SparseMat(const Mat&)`, so a zero value also erases the previously set element
+	void cv_manual_SparseMat_from_triplets(int dims, const int* sizes, int type, const int* idx, const unsigned char* values, size_t count, Result<void*>* ocvrs_return) {
+		try {
+			std::unique_ptr<cv::SparseMat> out(new cv::SparseMat(dims, sizes, type));
+			const size_t esz = out->elemSize();
+			for (size_t i = 0; i < count; ++i) {
+				const int* elem_idx = idx + i * dims;
+				const unsigned char* elem = values + i * esz;
+				if (ocvrs_is_zero_elem(elem, esz)) {
+					out->erase(elem_idx);
+				} else {
+					memcpy(out->ptr(elem_idx, true), elem, esz);
+				}
+			}
+			Ok<void*>(out.release(), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
+	// writes the pointers to the indices and values of at most `count` non-zero elements, returns the number of written elements
+	void cv_manual_SparseMat_nodes(const cv::SparseMat* instance, const int** idx, const unsigned char** values, size_t count, Result<size_t>* ocvrs_return) {
+		try {
+			size_t i = 0;
+			const cv::SparseMatConstIterator end = instance->end();
+			for (cv::SparseMatConstIterator it = instance->begin(); it != end && i < count; ++it, ++i) {
+				idx[i] = it.node()->idx;
+				values[i] = it.ptr;
+			}
+			Ok<size_t>(i, ocvrs_return);
+		} OCVRS_CATCH(Result<size_t>, ocvrs_return)
+	}
+
+	// `pool` is released with `delete_pool` when OpenCV no longer uses the backend, also in case of an error
+	void cv_manual_parallel_setRayonBackend(void* pool, ocvrs_rayon_parallel_for parallel_for_fn, ocvrs_rayon_thread_num thread_num_fn, ocvrs_rayon_thread_num num_threads_fn, ocvrs_rayon_delete_pool delete_pool_fn, Result_void* ocvrs_return) {
+		try {
+#ifdef OCVRS_HAS_PARALLEL_BACKEND
+			std::shared_ptr<cv::parallel::ParallelForAPI> api = std::make_shared<ocvrs_RayonParallelForAPI>(pool, parallel_for_fn, thread_num_fn, num_threads_fn, delete_pool_fn);
+			cv::parallel::setParallelForBackend(api, false);
+			Ok(ocvrs_return);
+#else
+			(void)parallel_for_fn;
+			(void)thread_num_fn;
+			(void)num_threads_fn;
+			delete_pool_fn(pool);
+			CV_Error(cv::Error::StsNotImplemented, "Custom parallel backends require OpenCV 4.5.2+ with cv::parallel::setParallelForBackend()");
+#endif
+		} OCVRS_CATCH(Result_void, ocvrs_return)
+	}
+
+	// pass null `callback` to restore the default error processing
+	void cv_manual_redirectError(cv::ErrorCallback callback, void* userdata, Result_void* ocvrs_return) {
+		try {
+			cv::redirectError(callback, userdata);
+			Ok(ocvrs_return);
+		} OCVRS_CATCH(Result_void, ocvrs_return)
+	}
+
+	// pass null `sink` to restore the default OpenCV logging to stderr
+	void cv_manual_utils_logging_set_sink(ocvrs_log_sink sink, Result_void* ocvrs_return) {
+		try {
+#ifdef OCVRS_HAS_LOG_SINK
+			ocvrs_log_sink_fn.store(sink);
+			cv::utils::logging::internal::replaceWriteLogMessage(sink ? ocvrs_write_log_message : nullptr);
+			cv::utils::logging::internal::replaceWriteLogMessageEx(sink ? ocvrs_write_log_message_ex : nullptr);
+			Ok(ocvrs_return);
+#else
+			(void)sink;
+			CV_Error(cv::Error::StsNotImplemented, "Redirecting OpenCV log messages requires OpenCV 4.8+ with cv::utils::logging::internal::replaceWriteLogMessage()");
+#endif
+		} OCVRS_CATCH(Result_void, ocvrs_return)
+	}
+
 	void cv_InputArray_input_array(cv::_InputArray* instance, Result<void*>* ocvrs_return) { return ocvrs_input_array(instance, ocvrs_return); }
 	void cv_OutputArray_output_array(cv::_OutputArray* instance, Result<void*>* ocvrs_return) { return ocvrs_output_array(instance, ocvrs_return); }
 	void cv_InputOutputArray_input_output_array(cv::_InputOutputArray* instance, Result<void*>* ocvrs_return) { return ocvrs_input_output_array(instance, ocvrs_return); }
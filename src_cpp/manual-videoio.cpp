@@ -0,0 +1,65 @@
+#include "videoio.hpp"
+#include <memory>
+
+template struct Result<bool>;
+
+// reads the Rust `Read + Seek` object, `reader` is released with `delete_fn`
+typedef long long (*ocvrs_stream_read)(void* reader, char* buffer, long long size);
+typedef long long (*ocvrs_stream_seek)(void* reader, long long offset, int origin);
+typedef void (*ocvrs_stream_delete)(void* reader);
+
+#if (CV_VERSION_MAJOR == 4 && CV_VERSION_MINOR >= 10) || CV_VERSION_MAJOR > 4 /* 4.10+ */
+	#define OCVRS_HAS_STREAM_READER
+
+	class ocvrs_StreamReader : public cv::IStreamReader {
+		void* reader;
+		ocvrs_stream_read read_fn;
+		ocvrs_stream_seek seek_fn;
+		ocvrs_stream_delete delete_fn;
+
+	public:
+		ocvrs_StreamReader(void* reader, ocvrs_stream_read read_fn, ocvrs_stream_seek seek_fn, ocvrs_stream_delete delete_fn)
+			: reader(reader), read_fn(read_fn), seek_fn(seek_fn), delete_fn(delete_fn) {}
+
+		~ocvrs_StreamReader() CV_OVERRIDE {
+			delete_fn(reader);
+		}
+
+		long long read(char* buffer, long long size) CV_OVERRIDE {
+			return read_fn(reader, buffer, size);
+		}
+
+		long long seek(long long offset, int origin) CV_OVERRIDE {
+			return seek_fn(reader, offset, origin);
+		}
+	};
+#endif
+
+extern "C" {
+	bool cv_manual_VideoCapture_has_stream_reader() {
+#ifdef OCVRS_HAS_STREAM_READER
+		return true;
+#else
+		return false;
+#endif
+	}
+
+	// `reader` is released with `delete_fn` when the capture no longer uses it, also in case of an error
+	void cv_manual_VideoCapture_open_reader(cv::VideoCapture* instance, void* reader, ocvrs_stream_read read_fn, ocvrs_stream_seek seek_fn, ocvrs_stream_delete delete_fn, int api_preference, Result<bool>* ocvrs_return) {
+		// owns `reader` until it's handed over to `ocvrs_StreamReader` so that it's released on every error path
+		std::unique_ptr<void, ocvrs_stream_delete> reader_guard(reader, delete_fn);
+		try {
+#ifdef OCVRS_HAS_STREAM_READER
+			cv::Ptr<cv::IStreamReader> source = cv::makePtr<ocvrs_StreamReader>(reader, read_fn, seek_fn, delete_fn);
+			reader_guard.release();
+			Ok<bool>(instance->open(source, api_preference, std::vector<int>()), ocvrs_return);
+#else
+			(void)instance;
+			(void)read_fn;
+			(void)seek_fn;
+			(void)api_preference;
+			CV_Error(cv::Error::StsNotImplemented, "Opening VideoCapture from a stream requires OpenCV 4.10+ with cv::IStreamReader");
+#endif
+		} OCVRS_CATCH(Result<bool>, ocvrs_return)
+	}
+}
@@ -36,6 +36,7 @@ catch (cv::Exception& e) { \
 // defined in src/templ.rs
 extern "C" void* ocvrs_create_string(const char*);
 extern "C" void* ocvrs_create_byte_string(const char*, size_t);
+extern "C" void ocvrs_free_cstring(char*);
 
 template<typename T> struct Result {
 	int error_code;
@@ -65,4 +66,23 @@ template<typename T> inline void Err(int code, const char* msg, T* ocvrs_return)
 	// it's ok to leave result uninitialized because the Rust implementation only assumes it as init if error_msg is NULL
 }
 
+// Rethrows the error returned from the Rust implementation of a director class method as cv::Exception, for those calls
+// error_msg is a C string allocated on the Rust side
+template<typename T> inline void ocvrs_director_rethrow(const T& ocvrs_return, const char* func) {
+	if (ocvrs_return.error_msg != NULL) {
+		char* msg_ptr = static_cast<char*>(ocvrs_return.error_msg);
+		cv::String msg(msg_ptr);
+		ocvrs_free_cstring(msg_ptr);
+		throw cv::Exception(ocvrs_return.error_code, msg, func, __FILE__, __LINE__);
+	}
+}
+
+// Takes ownership of the heap allocated object returned from the Rust implementation of a director class method
+template<typename T> inline T ocvrs_director_receive(void* val) {
+	T* val_ptr = static_cast<T*>(val);
+	T out(std::move(*val_ptr));
+	delete val_ptr;
+	return out;
+}
+
 #endif
@@ -0,0 +1,36 @@
+#![cfg(all(feature = "async", not(ocvrs_opencv_branch_32)))]
+
+use std::future::IntoFuture;
+use std::thread;
+use std::time::Duration;
+
+use common::block_on;
+use opencv::core::{AsyncPromise, Mat, Scalar, CV_32S};
+use opencv::prelude::*;
+use opencv::Result;
+
+mod common;
+
+#[test]
+fn async_array_await() -> Result<()> {
+	let mut promise = AsyncPromise::default();
+	let array = promise.get_array_result()?;
+	let setter = thread::spawn(move || -> Result<()> {
+		thread::sleep(Duration::from_millis(50));
+		let value = Mat::new_rows_cols_with_default(2, 3, CV_32S, Scalar::all(7.))?;
+		promise.set_value(&value)
+	});
+	let res = block_on(async { array.await })?;
+	setter.join().expect("Setter thread panicked")?;
+	assert_eq!(2, res.rows());
+	assert_eq!(3, res.cols());
+	assert_eq!(7, *res.at_2d::<i32>(1, 2)?);
+
+	// already available result
+	let mut promise = AsyncPromise::default();
+	let array = promise.get_array_result()?;
+	promise.set_value(&Mat::new_rows_cols_with_default(1, 1, CV_32S, Scalar::all(1.))?)?;
+	let res = block_on(array.into_future())?;
+	assert_eq!(1, *res.at_2d::<i32>(0, 0)?);
+	Ok(())
+}
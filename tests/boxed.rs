@@ -97,6 +97,32 @@ fn smart_ptr_cast_base() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn smart_ptr_clone_cast_descendant() -> Result<()> {
+	#![cfg(ocvrs_has_module_features2d)]
+	use opencv::core::Ptr;
+	#[cfg(ocvrs_opencv_branch_4)]
+	use opencv::features2d::{AKAZE_DescriptorType::DESCRIPTOR_MLDB, KAZE_DiffusivityType::DIFF_PM_G2, AKAZE, BRISK};
+	#[cfg(not(ocvrs_opencv_branch_4))]
+	use opencv::features2d::{AKAZE, AKAZE_DESCRIPTOR_MLDB as DESCRIPTOR_MLDB, BRISK, KAZE_DIFF_PM_G2 as DIFF_PM_G2};
+	use std::convert::TryFrom;
+
+	let mut d = AKAZE::create(DESCRIPTOR_MLDB, 0, 3, 0.001, 4, 4, DIFF_PM_G2)?;
+	let d_clone = d.clone();
+	assert!(d.ptr_eq(&d_clone));
+	d.set_threshold(0.005)?;
+	assert_eq!(0.005, d_clone.get_threshold()?);
+	assert!(!d.ptr_eq(&AKAZE::create(DESCRIPTOR_MLDB, 0, 3, 0.001, 4, 4, DIFF_PM_G2)?));
+
+	let base = PtrOfFeature2D::from(d);
+	assert_eq!("Feature2D.AKAZE", base.get_default_name()?);
+	assert!(Ptr::<BRISK>::try_from(base.clone()).is_err());
+	let d = Ptr::<AKAZE>::try_from(base)?;
+	assert!(d.ptr_eq(&d_clone));
+	assert_eq!(0.005, d.get_threshold()?);
+	Ok(())
+}
+
 #[test]
 fn cast_base() -> Result<()> {
 	#![cfg(ocvrs_has_module_features2d)]
@@ -0,0 +1,70 @@
+//! Helpers shared by the integration tests, every test crate uses only some of them
+#![allow(dead_code)]
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake};
+use std::thread::{self, Thread};
+
+#[cfg(ocvrs_has_module_videoio)]
+pub use video::*;
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+	fn wake(self: Arc<Self>) {
+		self.0.unpark();
+	}
+}
+
+/// Runs the future to completion on the current thread, parking it while the future is pending
+pub fn block_on<F: Future>(fut: F) -> F::Output {
+	let waker = Arc::new(ThreadWaker(thread::current())).into();
+	let mut cx = Context::from_waker(&waker);
+	let mut fut = Box::pin(fut);
+	loop {
+		match Pin::as_mut(&mut fut).poll(&mut cx) {
+			Poll::Ready(res) => return res,
+			Poll::Pending => thread::park(),
+		}
+	}
+}
+
+#[cfg(ocvrs_has_module_videoio)]
+mod video {
+	use std::path::PathBuf;
+
+	use opencv::core::{Mat, Scalar, Size, CV_8UC3};
+	use opencv::prelude::*;
+	use opencv::videoio::VideoWriter;
+	use opencv::Result;
+
+	/// Number of frames in the generated video
+	pub const FRAMES: i32 = 10;
+
+	/// Size of the frames in the generated video
+	pub const FRAME_SIZE: Size = Size::new(64, 48);
+
+	/// Frame `i` of the generated video, each frame has a different solid color
+	pub fn frame(i: i32) -> Result<Mat> {
+		Mat::new_rows_cols_with_default(FRAME_SIZE.height, FRAME_SIZE.width, CV_8UC3, Scalar::all(f64::from(i * 20)))
+	}
+
+	/// Writes the MJPG video of [FRAMES] frames at 25 fps to the temporary directory, returns its path
+	pub fn generate_video(name: &str) -> Result<PathBuf> {
+		let path = std::env::temp_dir().join(name);
+		let mut writer = VideoWriter::new(
+			path.to_str().unwrap(),
+			VideoWriter::fourcc('M', 'J', 'P', 'G')?,
+			25.,
+			FRAME_SIZE,
+			true,
+		)?;
+		for i in 0..FRAMES {
+			writer.write(&frame(i)?)?;
+		}
+		writer.release()?;
+		Ok(path)
+	}
+}
@@ -1,8 +1,12 @@
+use std::panic;
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::{Arc, Mutex};
+
 use opencv::{
 	core::{self, Moments, Point2f, RotatedRect, Scalar, Size2f, Vec3b, CV_32S, CV_64F, CV_8U, CV_MAKETYPE},
 	prelude::*,
 	types::VectorOfMat,
-	Result,
+	Error, Result,
 };
 
 #[test]
@@ -97,3 +101,54 @@ fn min_max_loc() -> Result<()> {
 	assert_eq!(30., max_val);
 	Ok(())
 }
+
+#[test]
+fn error_handler() -> Result<()> {
+	let errors = Arc::new(Mutex::new(vec![]));
+	core::set_error_handler({
+		let errors = Arc::clone(&errors);
+		move |info: &core::ErrorInfo| {
+			if info.message == "test error handler" {
+				errors.lock().unwrap().push(info.clone());
+			}
+		}
+	})?;
+	let res = core::error(core::StsBadArg, "test error handler", "test_func", "test.cpp", 42);
+	core::reset_error_handler()?;
+	assert_eq!(core::StsBadArg, res.unwrap_err().code);
+	let _ = core::error(core::StsBadArg, "test error handler", "test_func", "test.cpp", 43);
+	let errors = errors.lock().unwrap();
+	assert_eq!(1, errors.len());
+	assert_eq!(core::StsBadArg, errors[0].code);
+	assert_eq!("test_func", errors[0].function);
+	assert_eq!("test.cpp", errors[0].file);
+	assert_eq!(42, errors[0].line);
+	Ok(())
+}
+
+#[test]
+fn parallel_for() -> Result<()> {
+	let sum = AtomicI32::new(0);
+	core::parallel_for(0..100, -1., |range| {
+		for i in range {
+			sum.fetch_add(i, Ordering::Relaxed);
+		}
+		Ok(())
+	})?;
+	assert_eq!(4950, sum.load(Ordering::Relaxed));
+
+	let err = core::parallel_for(0..10, 2., |range| {
+		if range.contains(&9) {
+			Err(Error::new(core::StsBadArg, "last stripe"))
+		} else {
+			Ok(())
+		}
+	})
+	.expect_err("Must fail");
+	assert_eq!(core::StsBadArg, err.code);
+	assert!(err.message.contains("last stripe"));
+
+	let panic = panic::catch_unwind(|| core::parallel_for(0..10, 2., |_| panic!("stripe panic"))).expect_err("Must panic");
+	assert_eq!(Some(&"stripe panic"), panic.downcast_ref::<&str>());
+	Ok(())
+}
@@ -0,0 +1,67 @@
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::Arc;
+
+use opencv::{
+	core::{self, ParallelLoopBody, ParallelLoopBodyDirector, Range},
+	prelude::*,
+	Error, Result,
+};
+
+struct Summer {
+	sum: Arc<AtomicI32>,
+}
+
+impl ParallelLoopBodyDirector for Summer {
+	fn apply(&self, range: &Range) -> Result<()> {
+		for i in range.start()..range.end() {
+			self.sum.fetch_add(i, Ordering::Relaxed);
+		}
+		Ok(())
+	}
+}
+
+struct Failing;
+
+impl ParallelLoopBodyDirector for Failing {
+	fn apply(&self, _range: &Range) -> Result<()> {
+		Err(Error::new(core::StsBadArg, "failing body"))
+	}
+}
+
+struct Panicking;
+
+impl ParallelLoopBodyDirector for Panicking {
+	fn apply(&self, _range: &Range) -> Result<()> {
+		panic!("panicking body")
+	}
+}
+
+#[test]
+fn director_parallel_loop_body() -> Result<()> {
+	let sum = Arc::new(AtomicI32::new(0));
+	let body = ParallelLoopBody::from_director(Box::new(Summer { sum: Arc::clone(&sum) }));
+	core::parallel_for_(&Range::new(0, 100)?, &body, -1.)?;
+	assert_eq!(4950, sum.load(Ordering::Relaxed));
+	drop(body);
+	// the director is dropped together with the C++ object
+	assert_eq!(1, Arc::strong_count(&sum));
+
+	let body = core::Ptr::<ParallelLoopBody>::from_director(Box::new(Summer { sum: Arc::clone(&sum) }));
+	body.apply(&Range::new(0, 10)?)?;
+	assert_eq!(4995, sum.load(Ordering::Relaxed));
+	Ok(())
+}
+
+#[test]
+fn director_error() -> Result<()> {
+	let body = ParallelLoopBody::from_director(Box::new(Failing));
+	let err = core::parallel_for_(&Range::new(0, 10)?, &body, 1.).expect_err("Must fail");
+	assert_eq!(core::StsBadArg, err.code);
+	assert!(err.message.contains("failing body"));
+
+	let body = ParallelLoopBody::from_director(Box::new(Panicking));
+	let err = core::parallel_for_(&Range::new(0, 10)?, &body, 1.).expect_err("Must fail");
+	assert_eq!(core::StsError, err.code);
+	assert!(err.message.contains("panicking body"));
+	Ok(())
+}
@@ -0,0 +1,106 @@
+use opencv::core::{DMatch, FileNodePath, FileStorage, FileStorage_Mode, KeyPoint, Mat, Point, Rect2f};
+use opencv::prelude::*;
+use opencv::Result;
+
+const SRC: &str = "%YAML:1.0
+---
+camera:
+  name: front
+  fps: 29.97
+  matrix: !!opencv-matrix
+    rows: 2
+    cols: 2
+    dt: d
+    data: [ 1., 0., 0., 1. ]
+  distortion: [ 0.1, -0.2, \"bad\" ]
+sizes: [ 640, 480 ]
+origin: [ 10, 20 ]
+roi: [ 1.5, 2.5, 100., 200. ]
+keypoint: [ 1., 2., 3., -1., 0., 0, -1 ]
+match: [ 1, 2, 0, 0.5 ]
+";
+
+fn open() -> Result<FileStorage> {
+	FileStorage::new(SRC, FileStorage_Mode::READ as i32 | FileStorage_Mode::MEMORY as i32, "")
+}
+
+#[test]
+fn file_node_iter() -> Result<()> {
+	let fs = open()?;
+	let root = fs.root(0)?;
+	assert_eq!(
+		vec!["camera", "sizes", "origin", "roi", "keypoint", "match"],
+		root.key_names().collect::<Vec<_>>()
+	);
+	let sizes = fs.get("sizes")?;
+	let mut vals = vec![];
+	for node in &sizes {
+		vals.push(node.to_i32()?);
+	}
+	assert_eq!(vec![640, 480], vals);
+	assert_eq!(0, sizes.key_names().count());
+	assert_eq!(2, sizes.iter().len());
+	let mut iter = root.iter();
+	iter.next();
+	assert_eq!((5, Some(5)), iter.size_hint());
+	assert_eq!(0, fs.get("missing")?.iter().count());
+	Ok(())
+}
+
+#[test]
+fn file_node_get_as() -> Result<()> {
+	let fs = open()?;
+	let root = FileNodePath::new(fs.root(0)?);
+	assert_eq!("front", root.get("camera")?.get("name")?.get_as::<String>()?);
+	assert_eq!(29.97, root.get("camera")?.get("fps")?.get_as::<f64>()?);
+	assert_eq!(480, root.get("sizes")?.at(1)?.get_as::<u16>()?);
+	assert_eq!(vec![640, 480], root.get("sizes")?.get_as::<Vec<i32>>()?);
+	assert_eq!(Point::new(10, 20), root.get("origin")?.get_as::<Point>()?);
+	assert_eq!(Rect2f::new(1.5, 2.5, 100., 200.), root.get("roi")?.get_as::<Rect2f>()?);
+	let matrix = root.get("camera")?.get("matrix")?.get_as::<Mat>()?;
+	assert_eq!(1., *matrix.at_2d::<f64>(1, 1)?);
+	let kp = root.get("keypoint")?.get_as::<KeyPoint>()?;
+	assert_eq!(3., kp.size());
+	let m = root.get("match")?.get_as::<DMatch>()?;
+	assert_eq!(2, m.train_idx);
+	assert_eq!(0.5, m.distance);
+	assert_eq!(480, fs.get("sizes")?.at(1)?.get_as::<i32>()?);
+
+	let err = root.get("camera")?.get("distortion")?.get_as::<Vec<f64>>().unwrap_err();
+	assert!(err.message.contains("camera.distortion[2]"), "{}", err.message);
+	let err = root.get("camera")?.get("missing")?.get_as::<i32>().unwrap_err();
+	assert!(err.message.contains("camera.missing"), "{}", err.message);
+	assert!(root.get("sizes")?.at(1)?.get_as::<i8>().is_err());
+	assert!(root.get("sizes")?.at(5)?.empty()?);
+	assert_eq!("camera.distortion[1]", root.get("camera")?.get("distortion")?.at(1)?.path());
+	Ok(())
+}
+
+#[test]
+fn file_node_path_index() -> Result<()> {
+	let fs = open()?;
+	let root = FileNodePath::new(fs.root(0)?);
+	assert_eq!("front", root["camera"]["name"].get_as::<String>()?);
+	assert_eq!(480, root["sizes"][1].get_as::<i32>()?);
+	let distortion = &root["camera"]["distortion"];
+	assert_eq!("camera.distortion[2]", distortion[2].path());
+	assert_eq!(-0.2, distortion[1].get_as::<f64>()?);
+	let err = distortion[2].get_as::<f64>().unwrap_err();
+	assert!(err.message.contains("camera.distortion[2]"), "{}", err.message);
+	Ok(())
+}
+
+#[test]
+#[should_panic(expected = "camera.missing")]
+fn file_node_path_index_missing() {
+	let fs = open().unwrap();
+	let root = FileNodePath::new(fs.root(0).unwrap());
+	let _ = &root["camera"]["missing"];
+}
+
+#[test]
+fn file_storage_from_bytes() -> Result<()> {
+	let fs = FileStorage::from_bytes(SRC.as_bytes(), FileStorage_Mode::READ as i32, "")?;
+	assert_eq!("front", fs.get("camera")?.get("name")?.to_string()?);
+	Ok(())
+}
@@ -4,7 +4,7 @@ use std::ffi::c_void;
 
 use opencv::{
 	core::{self, Size, Vec3b},
-	imgcodecs,
+	imgcodecs::{self, ImwriteOptions, PngStrategy},
 	prelude::*,
 	Result,
 };
@@ -41,3 +41,50 @@ fn decode() -> Result<()> {
 
 	Ok(())
 }
+
+#[test]
+#[cfg(unix)]
+fn non_utf8_path() -> Result<()> {
+	use std::ffi::OsStr;
+	use std::os::unix::ffi::OsStrExt;
+
+	let dir = std::env::temp_dir().join(format!("opencv-rust-imgcodecs-{}", std::process::id()));
+	std::fs::create_dir_all(&dir).unwrap();
+	let path = dir.join(OsStr::from_bytes(b"pixel-\xff.png"));
+	std::fs::write(&path, PIXEL).unwrap();
+	let dest = imgcodecs::imread(&path, imgcodecs::IMREAD_COLOR)?;
+	assert_eq!(dest.size()?, Size::new(1, 1));
+	assert_eq!(*dest.at_2d::<Vec3b>(0, 0)?, Vec3b::from([56u8, 56, 191]));
+	let out_path = dir.join(OsStr::from_bytes(b"out-\xfe.png"));
+	assert!(imgcodecs::imwrite(&out_path, &dest, &core::Vector::new())?);
+	assert!(out_path.exists());
+	std::fs::remove_dir_all(&dir).unwrap();
+	Ok(())
+}
+
+#[test]
+fn encode_decode_with_options() -> Result<()> {
+	let img = imgcodecs::imdecode_slice(PIXEL, imgcodecs::IMREAD_COLOR)?;
+	assert_eq!(*img.at_2d::<Vec3b>(0, 0)?, Vec3b::from([56u8, 56, 191]));
+
+	let opts = ImwriteOptions::new().png_compression(9).png_strategy(PngStrategy::Rle);
+	let png = imgcodecs::imencode_to_vec(".png", &img, &opts)?;
+	let decoded = imgcodecs::imdecode_slice(&png, imgcodecs::IMREAD_COLOR)?;
+	assert_eq!(*decoded.at_2d::<Vec3b>(0, 0)?, Vec3b::from([56u8, 56, 191]));
+
+	let jpg = imgcodecs::imencode_to_vec(".jpg", &img, &ImwriteOptions::new().jpeg_quality(80).jpeg_progressive(true))?;
+	assert_eq!(&jpg[..2], &[0xff, 0xd8]);
+
+	let err = imgcodecs::imencode_to_vec(".jpg", &img, &opts).unwrap_err();
+	assert_eq!(core::StsBadArg, err.code);
+	assert!(err.message.contains("IMWRITE_PNG_COMPRESSION"), "{}", err.message);
+	let err = imgcodecs::imencode_to_vec(".jpg", &img, &ImwriteOptions::new().jpeg_quality(101)).unwrap_err();
+	assert_eq!(core::StsOutOfRange, err.code);
+	assert_eq!(
+		ImwriteOptions::new().jpeg_quality(90).to_params("out.JPEG")?.to_vec(),
+		vec![imgcodecs::IMWRITE_JPEG_QUALITY, 90]
+	);
+
+	assert!(imgcodecs::imdecode_slice(b"not an image", imgcodecs::IMREAD_COLOR).is_err());
+	Ok(())
+}
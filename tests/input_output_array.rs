@@ -5,7 +5,7 @@ use opencv::core::AccessFlag::ACCESS_READ;
 #[cfg(not(ocvrs_opencv_branch_4))]
 use opencv::core::ACCESS_READ;
 use opencv::{
-	core::{self, Matx12d, Scalar, ToInputArray, ToInputOutputArray, ToOutputArray, UMat, UMatUsageFlags, Vec2b, VecN},
+	core::{self, Matx12d, Point2f, Scalar, ToInputArray, ToInputOutputArray, ToOutputArray, UMat, UMatUsageFlags, Vec2b, VecN},
 	prelude::*,
 	types::{VectorOff64, VectorOfu8},
 	Result,
@@ -116,3 +116,67 @@ fn no_array() -> Result<()> {
 	}
 	Ok(())
 }
+
+#[test]
+fn input_array_slice() -> Result<()> {
+	let data = [1u8, 2, 3, 4];
+	assert_eq!(Scalar::from(10.), core::sum_elems(&data)?);
+	assert_eq!(Scalar::from(10.), core::sum_elems(&&data[..])?);
+	assert_eq!(Scalar::from(10.), core::sum_elems(&data.to_vec())?);
+
+	let input_array = data.input_array()?;
+	assert!(input_array.is_matx()?);
+	assert_eq!(core::Size::new(4, 1), input_array.size(-1)?);
+	assert_eq!(u8::opencv_type(), input_array.typ(-1)?);
+
+	let pts = vec![Point2f::new(0., 0.), Point2f::new(3., 0.), Point2f::new(3., 4.)];
+	let input_array = pts.input_array()?;
+	assert_eq!(Point2f::opencv_type(), input_array.typ(-1)?);
+	assert_eq!(3, input_array.total(-1)?);
+	assert_eq!(Scalar::new(6., 4., 0., 0.), core::sum_elems(&pts)?);
+
+	let empty: &[f32] = &[];
+	assert_eq!(0, empty.input_array()?.total(-1)?);
+	Ok(())
+}
+
+#[test]
+fn input_array_mat_slice() -> Result<()> {
+	let b = Mat::new_rows_cols_with_default(2, 2, u8::opencv_type(), Scalar::all(1.))?;
+	let g = Mat::new_rows_cols_with_default(2, 2, u8::opencv_type(), Scalar::all(2.))?;
+	let r = Mat::new_rows_cols_with_default(2, 2, u8::opencv_type(), Scalar::all(3.))?;
+	let mut merged = Mat::default();
+	core::merge(&[&b, &g, &r][..], &mut merged)?;
+	assert_eq!(core::Vec3b::from([1, 2, 3]), *merged.at_2d::<core::Vec3b>(1, 1)?);
+
+	let mats = [b, g, r];
+	let input_array = (&mats[..]).input_array()?;
+	assert!(input_array.is_mat_vector()?);
+	assert_eq!(3, input_array.total(-1)?);
+	let mut concat = Mat::default();
+	core::hconcat(&&mats[..], &mut concat)?;
+	assert_eq!(core::Size::new(6, 2), concat.size()?);
+	Ok(())
+}
+
+#[test]
+fn output_array_vec() -> Result<()> {
+	let mut out = vec![0u8; 10];
+	core::add(&[1u8, 2, 3], &[3u8, 4, 5], &mut out, &core::no_array(), -1)?;
+	assert_eq!(vec![4, 6, 8], out);
+
+	let mut out = Vec::<f64>::new();
+	core::add(&[1f32, 2.], &[0.5f32, 1.], &mut &mut out, &core::no_array(), -1)?;
+	assert_eq!(vec![1.5, 3.], out);
+
+	let mut pts = Vec::<Point2f>::new();
+	let src = Mat::from_slice_2d(&[&[0f32, 0.], &[3., 4.]])?;
+	src.reshape(2, 0)?.copy_to(&mut pts)?;
+	assert_eq!(vec![Point2f::new(0., 0.), Point2f::new(3., 4.)], pts);
+
+	// the Vec is left untouched if the call fails
+	let mut out = vec![1u8, 2];
+	assert!(core::add(&[1u8, 2, 3], &[3u8, 4], &mut out, &core::no_array(), -1).is_err());
+	assert_eq!(vec![1, 2], out);
+	Ok(())
+}
@@ -0,0 +1,71 @@
+#![cfg(all(feature = "log", not(feature = "tracing"), ocvrs_opencv_branch_4))]
+
+use std::sync::Mutex;
+
+use log::kv::Key;
+use log::{Level, Log, Metadata, Record};
+use once_cell::sync::Lazy;
+use opencv::{core, Result};
+
+type Message = (Level, String, Option<String>, Option<u32>, Option<String>, String);
+
+static MESSAGES: Lazy<Mutex<Vec<Message>>> = Lazy::new(|| Mutex::new(vec![]));
+
+struct TestLogger;
+
+impl Log for TestLogger {
+	fn enabled(&self, _metadata: &Metadata) -> bool {
+		true
+	}
+
+	fn log(&self, record: &Record) {
+		MESSAGES.lock().unwrap().push((
+			record.level(),
+			record.target().to_string(),
+			record.file().map(str::to_string),
+			record.line(),
+			record.key_values().get(Key::from_str("function")).map(|v| v.to_string()),
+			record.args().to_string(),
+		));
+	}
+
+	fn flush(&self) {}
+}
+
+#[test]
+fn log_sink() -> Result<()> {
+	if core::get_version_major()? == 4 && core::get_version_minor()? < 8 {
+		assert!(core::install_log_sink().is_err());
+		return Ok(());
+	}
+	log::set_logger(&TestLogger).unwrap();
+	log::set_max_level(log::LevelFilter::Trace);
+	core::install_log_sink()?;
+	core::write_log_message_ex(
+		core::LogLevel::LOG_LEVEL_WARNING,
+		"test_tag",
+		"test.cpp",
+		42,
+		"test_func",
+		"test message",
+	)?;
+	core::uninstall_log_sink()?;
+	core::write_log_message_ex(
+		core::LogLevel::LOG_LEVEL_WARNING,
+		"test_tag",
+		"test.cpp",
+		43,
+		"test_func",
+		"to stderr",
+	)?;
+	let messages = MESSAGES.lock().unwrap();
+	assert_eq!(1, messages.len());
+	let (level, target, file, line, function, message) = &messages[0];
+	assert_eq!(Level::Warn, *level);
+	assert_eq!("opencv::test_tag", target);
+	assert_eq!(Some("test.cpp"), file.as_deref());
+	assert_eq!(Some(42), *line);
+	assert_eq!(Some("test_func"), function.as_deref());
+	assert_eq!("test message", message);
+	Ok(())
+}
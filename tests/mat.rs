@@ -909,3 +909,39 @@ fn mat_custom_data_type() -> Result<()> {
 	assert!(data.iter().all(|el| el.a == -10 && el.b == 20));
 	Ok(())
 }
+
+#[test]
+fn mat_nd() -> Result<()> {
+	let data = (0..24).map(|x| x as f32).collect::<Vec<_>>();
+	let mat = Mat::new_nd_from_slice(&[1, 2, 3, 4], &data)?;
+	assert_eq!(4, mat.dims());
+	assert_eq!(vec![1, 2, 3, 4], mat.shape());
+	assert_eq!(&[4., 5., 6., 7.], mat.at_nd::<[f32]>(&[0, 0, 1])?);
+	assert_eq!(&[20., 21., 22., 23.], mat.at_nd::<[f32]>(&[0, 1, 2])?);
+	assert!(mat.at_nd::<[f32]>(&[0, 2, 0]).is_err());
+	assert!(mat.at_nd::<[f32]>(&[0, 0]).is_err());
+	assert!(mat.at_nd::<[u8]>(&[0, 0, 0]).is_err());
+	assert!(Mat::new_nd_from_slice(&[2, 3, 5], &data).is_err());
+
+	let squeezed = mat.squeeze()?;
+	assert_eq!(vec![2, 3, 4], squeezed.shape());
+	assert_eq!(vec![2, 1, 3, 4], squeezed.unsqueeze(1)?.shape());
+	assert_eq!(vec![2, 3, 4, 1], squeezed.unsqueeze(3)?.shape());
+	assert!(squeezed.unsqueeze(4).is_err());
+	assert_eq!(vec![1, 4], Mat::from_slice(&[1u8, 2, 3, 4])?.squeeze()?.shape());
+
+	let mut mat = mat;
+	mat.at_nd_mut::<[f32]>(&[0, 1, 2])?[3] = 100.;
+	assert_eq!(100., *mat.at_nd::<f32>(&[0, 1, 2, 3])?);
+
+	let multichannel = Mat::new_nd_with_default(&[2, 3, 4], Vec3f::opencv_type(), Scalar::all(1.))?;
+	assert_eq!(vec![2, 3, 4, 3], multichannel.shape());
+
+	#[cfg(ocvrs_opencv_since_4_7)]
+	{
+		let permuted = mat.permute(&[0, 2, 3, 1])?;
+		assert_eq!(vec![1, 3, 4, 2], permuted.shape());
+		assert_eq!(13., *permuted.at_nd::<f32>(&[0, 0, 1, 1])?);
+	}
+	Ok(())
+}
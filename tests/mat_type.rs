@@ -0,0 +1,54 @@
+use opencv::{
+	core::{self, Depth, Mat, MatType, Vec3b, Vec4f},
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn mat_type_decode() -> Result<()> {
+	let typ = MatType::from(core::CV_8UC3);
+	assert_eq!(Depth::U8, typ.depth()?);
+	assert_eq!(3, typ.channels());
+	assert_eq!(3, typ.elem_size()?);
+	assert_eq!(typ, MatType::of::<Vec3b>());
+	assert_eq!(core::CV_32FC4, i32::from(MatType::of::<Vec4f>()));
+	assert_eq!(16, MatType::of::<Vec4f>().elem_size()?);
+	assert_eq!(core::CV_64FC2, MatType::from_depth_channels(Depth::F64, 2).get());
+	assert_eq!(Depth::I16, Depth::try_from(core::CV_16S)?);
+	assert!(Depth::try_from(8).is_err());
+	let invalid = MatType::from(-1);
+	assert!(!invalid.is_valid());
+	assert!(invalid.depth().is_err());
+	assert!(MatType::new(core::CV_CN_MAX << core::CV_CN_SHIFT).depth().is_err());
+	Ok(())
+}
+
+#[test]
+fn mat_type_display_parse() -> Result<()> {
+	assert_eq!("CV_8UC3", MatType::from(core::CV_8UC3).to_string());
+	assert_eq!("CV_64FC1", MatType::from(Depth::F64).to_string());
+	assert_eq!("CV_16S", Depth::I16.to_string());
+	assert_eq!(MatType::from(core::CV_32FC2), "CV_32FC2".parse()?);
+	assert_eq!(MatType::from(core::CV_16UC1), "16U".parse()?);
+	assert!("CV_8XC3".parse::<MatType>().is_err());
+	assert!("CV_8UC0".parse::<MatType>().is_err());
+	assert!("CV_8UC".parse::<MatType>().is_err());
+	assert_eq!("invalid type -1", MatType::from(-1).to_string());
+	Ok(())
+}
+
+#[test]
+fn mat_type_arg() -> Result<()> {
+	let mat = Mat::new_rows_cols_with_default(2, 3, MatType::of::<f32>(), 1.into())?;
+	assert_eq!(core::CV_32FC1, mat.typ());
+	let mut out = Mat::default();
+	mat.convert_to(&mut out, MatType::from_depth_channels(Depth::F64, 1), 1., 0.)?;
+	assert_eq!(core::CV_64FC1, out.typ());
+	mat.convert_to(&mut out, core::CV_8U, 1., 0.)?;
+	assert_eq!(core::CV_8UC1, out.typ());
+
+	let err = mat.at_2d::<u8>(0, 0).unwrap_err();
+	assert!(err.message.contains("CV_32FC1"));
+	assert!(err.message.contains("CV_8UC1"));
+	Ok(())
+}
@@ -23,3 +23,35 @@ fn knn() -> Result<()> {
 	assert_eq!(Size::new(1, 1), dist.size()?);
 	Ok(())
 }
+
+#[test]
+fn save_load_bytes() -> Result<()> {
+	use opencv::core::{self, FileStorageFormat};
+
+	let mut knn = ml::KNearest::create()?;
+	let samp = Mat::from_slice_2d(&[[1f32, 2.], [3., 4.], [5., 6.]])?;
+	let resp = Mat::from_slice_2d(&[[10f32], [20.], [30.]])?;
+	knn.train(&samp, ml::ROW_SAMPLE, &resp)?;
+	for format in [FileStorageFormat::Yaml, FileStorageFormat::Xml, FileStorageFormat::Json] {
+		for compressed in [false, true] {
+			let bytes = match knn.save_to_bytes(format, compressed) {
+				Ok(bytes) => bytes,
+				Err(e) if compressed && !cfg!(feature = "flate2") => {
+					assert_eq!(core::StsNotImplemented, e.code);
+					continue;
+				}
+				Err(e) => return Err(e),
+			};
+			assert!(!bytes.is_empty());
+			assert_eq!(compressed, bytes.starts_with(&[0x1f, 0x8b]));
+			let loaded = core::load_from_bytes(&bytes, ml::KNearest::create)?;
+			assert!(!ml::StatModelTraitConst::empty(&loaded)?);
+			assert_eq!(knn.get_default_k()?, loaded.get_default_k()?);
+			let mut results = Mat::default();
+			loaded.find_nearest(&samp, 1, &mut results, &mut core::no_array(), &mut core::no_array())?;
+			assert_eq!(&[10., 20., 30.], results.data_typed::<f32>()?);
+		}
+	}
+	assert!(core::load_from_bytes(b"", ml::KNearest::create).is_err());
+	Ok(())
+}
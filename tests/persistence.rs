@@ -0,0 +1,132 @@
+#![cfg(all(ocvrs_has_module_core, feature = "serde"))]
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use opencv::core::{self, FileStorage, FileStorage_Mode, Mat, Vec3b};
+use opencv::persistence::{self, Format};
+use opencv::prelude::*;
+use opencv::Result;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Mode {
+	Fast,
+	Precise { iterations: u16 },
+	Scaled(f64),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+	name: String,
+	enabled: bool,
+	threshold: f64,
+	levels: Vec<i32>,
+	offset: (i8, f32),
+	comment: Option<String>,
+	modes: Vec<Mode>,
+	weights: BTreeMap<String, u8>,
+}
+
+fn config() -> Config {
+	Config {
+		name: "detector".to_string(),
+		enabled: true,
+		threshold: 0.25,
+		levels: vec![1, 2, 4],
+		offset: (-3, 1.5),
+		comment: None,
+		modes: vec![Mode::Fast, Mode::Precise { iterations: 10 }, Mode::Scaled(0.5)],
+		weights: [("a".to_string(), 1), ("b".to_string(), 255)].into_iter().collect(),
+	}
+}
+
+#[test]
+fn persistence_roundtrip() -> Result<()> {
+	let src = config();
+	for format in [Format::Yaml, Format::Xml, Format::Json] {
+		let serialized = persistence::to_string(&src, format)?;
+		let dst: Config = persistence::from_str(&serialized)?;
+		assert_eq!(src, dst);
+	}
+	let yaml = persistence::to_string(&src, Format::Yaml)?;
+	assert!(yaml.starts_with("%YAML:1.0"));
+	assert!(yaml.contains("name: detector"));
+	assert!(!yaml.contains("comment"));
+	Ok(())
+}
+
+#[test]
+fn persistence_mat() -> Result<()> {
+	#[derive(Serialize, Deserialize)]
+	struct Image {
+		mat: Mat,
+	}
+
+	let mat = Mat::from_slice_2d(&[[Vec3b::from([1, 2, 3]), Vec3b::from([4, 5, 6])]])?;
+	let yaml = persistence::to_string(&Image { mat }, Format::Yaml)?;
+	assert!(yaml.contains("mat: !!opencv-matrix"));
+	assert!(yaml.contains("dt: \"3u\"") || yaml.contains("dt: 3u"));
+
+	// readable by the regular FileStorage
+	let mut fs = FileStorage::new(&yaml, FileStorage_Mode::READ as i32 | FileStorage_Mode::MEMORY as i32, "")?;
+	let cpp_mat = fs.get("mat")?.mat()?;
+	assert_eq!(Vec3b::from([4, 5, 6]), *cpp_mat.at_2d::<Vec3b>(0, 1)?);
+	fs.release()?;
+
+	let img: Image = persistence::from_str(&yaml)?;
+	assert_eq!(1, img.mat.rows());
+	assert_eq!(2, img.mat.cols());
+	assert_eq!(Vec3b::from([1, 2, 3]), *img.mat.at_2d::<Vec3b>(0, 0)?);
+	assert_eq!(Vec3b::from([4, 5, 6]), *img.mat.at_2d::<Vec3b>(0, 1)?);
+	Ok(())
+}
+
+#[test]
+fn persistence_errors() -> Result<()> {
+	#[derive(Debug, Serialize)]
+	struct Big {
+		value: u64,
+	}
+
+	#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+	enum Key {
+		Width,
+	}
+
+	#[derive(Debug, Serialize)]
+	struct Optional {
+		values: Vec<Option<i32>>,
+	}
+
+	#[derive(Debug, Deserialize)]
+	struct Nested {
+		inner: Inner,
+	}
+
+	#[derive(Debug, Deserialize)]
+	struct Inner {
+		#[allow(dead_code)]
+		values: Vec<i32>,
+	}
+
+	assert!(persistence::to_string(&Big { value: u64::MAX }, Format::Yaml).is_err());
+	assert!(persistence::to_string(&[1, 2, 3], Format::Yaml).is_err());
+
+	let err = persistence::to_string(&BTreeMap::from([(1, 2)]), Format::Yaml).unwrap_err();
+	assert_eq!(core::StsBadArg, err.code);
+	assert!(err.message.contains("got: integer"), "{}", err.message);
+	let err = persistence::to_string(&BTreeMap::from([(true, 2)]), Format::Yaml).unwrap_err();
+	assert!(err.message.contains("got: bool"), "{}", err.message);
+	let err = persistence::to_string(&BTreeMap::from([('a', 2)]), Format::Yaml).unwrap_err();
+	assert!(err.message.contains("got: char"), "{}", err.message);
+	let yaml = persistence::to_string(&BTreeMap::from([(Key::Width, 2)]), Format::Yaml)?;
+	assert!(yaml.contains("Width: 2"), "{yaml}");
+
+	let values = vec![Some(1), None];
+	assert!(persistence::to_string(&Optional { values }, Format::Yaml).is_err());
+
+	let err = persistence::from_str::<Nested>("%YAML:1.0\n---\ninner:\n  values: [ 1, \"two\" ]\n").unwrap_err();
+	assert!(err.message.contains("inner.values[1]"), "{}", err.message);
+	Ok(())
+}
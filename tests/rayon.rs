@@ -0,0 +1,33 @@
+#![cfg(feature = "rayon")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use opencv::{core, Result};
+
+#[test]
+fn rayon_parallel_backend() -> Result<()> {
+	let version = (
+		core::get_version_major()?,
+		core::get_version_minor()?,
+		core::get_version_revision()?,
+	);
+	let pool = Arc::new(rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap());
+	if version < (4, 5, 2) {
+		assert!(core::use_rayon_parallel_backend_with_pool(pool).is_err());
+		return Ok(());
+	}
+	core::use_rayon_parallel_backend_with_pool(Arc::clone(&pool))?;
+	assert_eq!(2, core::get_num_threads()?);
+	let in_pool = AtomicUsize::new(0);
+	core::parallel_for(0..64, 64., |range| {
+		if pool.current_thread_index().is_some() {
+			in_pool.fetch_add(range.len(), Ordering::Relaxed);
+		}
+		Ok(())
+	})?;
+	assert_eq!(64, in_pool.load(Ordering::Relaxed));
+	core::use_rayon_parallel_backend()?;
+	assert_eq!(rayon::current_num_threads() as i32, core::get_num_threads()?);
+	Ok(())
+}
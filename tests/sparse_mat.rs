@@ -0,0 +1,57 @@
+use opencv::{
+	core::{SparseMat, Vec2f},
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn sparse_mat_from_triplets() -> Result<()> {
+	let m = SparseMat::from_triplets(&[100, 200], [([1, 2], 5f32), ([99, 199], 7.), ([1, 2], 6.)])?;
+	assert_eq!(f32::opencv_type(), m.typ());
+	assert_eq!(2, m.dims()?);
+	assert_eq!(100, m.size_1(0)?);
+	assert_eq!(200, m.size_1(1)?);
+	assert_eq!(2, m.nzcount()?);
+
+	let mut elems = m.iter::<f32>()?.map(|(idx, val)| (idx.to_vec(), val)).collect::<Vec<_>>();
+	elems.sort_by(|a, b| a.0.cmp(&b.0));
+	assert_eq!(vec![(vec![1, 2], 6.), (vec![99, 199], 7.)], elems);
+	assert!(m.iter::<f64>().is_err());
+
+	assert!(SparseMat::from_triplets(&[10, 10], [([1, 2, 3], 1u8)]).is_err());
+	assert!(SparseMat::from_triplets(&[10, 10], [([1, 10], 1u8)]).is_err());
+	assert!(SparseMat::from_triplets(&[10, 10], [([-1, 0], 1u8)]).is_err());
+
+	let m = SparseMat::from_triplets(&[3, 3, 3], vec![(vec![0, 1, 2], Vec2f::from([1., 2.]))])?;
+	assert_eq!(1, m.iter::<Vec2f>()?.len());
+
+	// zero values are not stored and erase the previously set elements
+	let m = SparseMat::from_triplets(&[10, 10], [([1, 2], 5i32), ([3, 4], 0), ([5, 6], 1), ([5, 6], 0)])?;
+	assert_eq!(1, m.nzcount()?);
+	assert_eq!(
+		vec![(vec![1, 2], 5)],
+		m.iter::<i32>()?.map(|(idx, val)| (idx.to_vec(), val)).collect::<Vec<_>>()
+	);
+	Ok(())
+}
+
+#[test]
+fn sparse_mat_dense() -> Result<()> {
+	let dense = Mat::from_slice_2d(&[[0u16, 1, 0], [0, 0, 2]])?;
+	let m = SparseMat::from_dense(&dense)?;
+	assert_eq!(2, m.nzcount()?);
+	let dense = m.to_dense()?;
+	assert_eq!(vec![vec![0u16, 1, 0], vec![0, 0, 2]], dense.to_vec_2d::<u16>()?);
+	Ok(())
+}
+
+#[cfg(feature = "sprs")]
+#[test]
+fn sparse_mat_sprs() -> Result<()> {
+	let csr = sprs::CsMat::new((2, 3), vec![0, 1, 3], vec![1, 0, 2], vec![1f64, 2., 3.]);
+	let m = SparseMat::from_sprs(csr.view())?;
+	assert_eq!(3, m.nzcount()?);
+	assert_eq!(csr, m.to_sprs_csr::<f64>()?);
+	assert_eq!(csr.to_csc(), m.to_sprs_csc::<f64>()?);
+	Ok(())
+}
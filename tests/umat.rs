@@ -122,3 +122,24 @@ fn umat_continuous() -> Result<()> {
 
 	Ok(())
 }
+
+#[test]
+fn umat_map() -> Result<()> {
+	let mut umat = UMat::from_slice(&[1u16, 2, 3, 4])?;
+	assert_eq!(Size::new(4, 1), umat.size()?);
+	assert_eq!(vec![1, 2, 3, 4], umat.to_vec::<u16>()?);
+	{
+		let mapped = umat.map_read()?;
+		assert_eq!(&[1, 2, 3, 4], mapped.data_typed::<u16>()?);
+	}
+	{
+		let mut mapped = umat.map_write()?;
+		mapped.data_typed_mut::<u16>()?[2] = 30;
+	}
+	assert_eq!(vec![1, 2, 30, 4], umat.to_vec::<u16>()?);
+	assert!(umat.to_vec::<f32>().is_err());
+
+	let roi = UMat::roi(&umat, Rect::new(1, 0, 2, 1))?;
+	assert_eq!(vec![2, 30], roi.to_vec::<u16>()?);
+	Ok(())
+}
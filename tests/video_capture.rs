@@ -0,0 +1,116 @@
+#![cfg(ocvrs_has_module_videoio)]
+
+use common::{generate_video, FRAMES, FRAME_SIZE};
+use opencv::prelude::*;
+use opencv::videoio::{self, FourCC, VideoCapture};
+use opencv::Result;
+
+mod common;
+
+#[test]
+fn fourcc() -> Result<()> {
+	let fourcc = FourCC::new(b"avc1");
+	assert_eq!(0x31637661, fourcc.get());
+	assert_eq!("avc1", fourcc.to_string());
+	assert_eq!("FourCC(avc1)", format!("{fourcc:?}"));
+	assert_eq!(fourcc, FourCC::from(0x31637661));
+	assert_eq!("0x00000001", FourCC::from(1).to_string());
+	assert_eq!(FourCC::MJPG, "MJPG".parse()?);
+	assert_eq!(FourCC::AVC1, "avc1".parse()?);
+	assert!("MJPEG".parse::<FourCC>().is_err());
+	assert!("MJ\u{e9}".parse::<FourCC>().is_err());
+	Ok(())
+}
+
+#[test]
+fn video_capture_frames() -> Result<()> {
+	let path = generate_video("opencv-rust-video-capture-frames.avi")?;
+	let mut cap = VideoCapture::from_file(path.to_str().unwrap(), videoio::CAP_ANY)?;
+	assert_eq!(Some(FRAME_SIZE), cap.frame_size()?);
+	assert_eq!(Some(25.), cap.fps()?);
+	assert_eq!(Some(FRAMES as u64), cap.frame_count()?);
+	assert_eq!(Some(FourCC::new(b"MJPG")), cap.fourcc()?);
+	assert!(cap.backend_name()?.is_some());
+	assert_eq!(Some(0), cap.position()?);
+
+	let frames = cap.frames().collect::<Result<Vec<_>>>()?;
+	assert_eq!(FRAMES as usize, frames.len());
+	assert_eq!(FRAME_SIZE, frames[0].size()?);
+
+	cap.seek_frame(4)?;
+	assert_eq!(Some(4), cap.position()?);
+	let mut sizes = vec![];
+	let count = cap.for_each_frame(|frame| {
+		sizes.push(frame.size()?);
+		Ok(())
+	})?;
+	assert_eq!(FRAMES as usize - 4, count);
+	assert_eq!(count, sizes.len());
+
+	cap.release()?;
+	assert_eq!(None, cap.backend_name()?);
+	std::fs::remove_file(path).ok();
+	Ok(())
+}
+
+#[test]
+// older OpenCV versions don't have `cv::IStreamReader`, the memfd fallback is Linux-only
+#[cfg(all(not(ocvrs_opencv_branch_32), any(target_os = "linux", ocvrs_opencv_since_4_10)))]
+fn video_capture_from_reader() -> Result<()> {
+	use std::io::Cursor;
+
+	use common::frame;
+	use opencv::videoio::VideoWriter;
+
+	let mut writer = VideoWriter::to_writer(
+		Vec::new(),
+		"avi",
+		videoio::CAP_ANY,
+		FourCC::new(b"MJPG"),
+		25.,
+		FRAME_SIZE,
+		true,
+	)?;
+	for i in 0..FRAMES {
+		writer.write(&frame(i)?)?;
+	}
+	let data = writer.finish()?;
+	assert!(!data.is_empty());
+
+	let mut cap = VideoCapture::from_reader(Cursor::new(data), videoio::CAP_ANY)?;
+	assert_eq!(FRAMES as usize, cap.for_each_frame(|_| Ok(()))?);
+
+	let res = VideoCapture::from_reader(Cursor::new(b"not a video".to_vec()), videoio::CAP_ANY);
+	assert!(res.is_err());
+	Ok(())
+}
+
+#[test]
+#[cfg(ocvrs_opencv_since_4_5_2)]
+fn video_writer_builder() -> Result<()> {
+	use common::frame;
+	use opencv::core;
+	use opencv::videoio::VideoWriter;
+
+	let path = std::env::temp_dir().join("opencv-rust-video-writer-builder.avi");
+	let mut writer = VideoWriter::builder(&path, FourCC::MJPG, 25., FRAME_SIZE)
+		.quality(90)
+		.is_color(true)
+		.build()?;
+	assert!(writer.is_opened()?);
+	writer.write(&frame(0)?)?;
+	writer.release()?;
+	std::fs::remove_file(path).ok();
+
+	let err = VideoWriter::builder("out.avi", FourCC::MJPG, 25., FRAME_SIZE)
+		.quality(101)
+		.build()
+		.unwrap_err();
+	assert_eq!(core::StsOutOfRange, err.code);
+
+	let err = VideoWriter::builder("/nonexistent/dir/out.avi", FourCC::MJPG, 25., FRAME_SIZE)
+		.build()
+		.unwrap_err();
+	assert!(err.message.contains("tried backends"), "{}", err.message);
+	Ok(())
+}
@@ -0,0 +1,56 @@
+#![cfg(all(ocvrs_has_module_videoio, feature = "tokio"))]
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use common::{block_on, generate_video, FRAMES, FRAME_SIZE};
+use futures_core::Stream;
+use opencv::core::Mat;
+use opencv::prelude::*;
+use opencv::videoio::{self, DropPolicy, VideoCapture, VideoCaptureStream};
+use opencv::Result;
+
+mod common;
+
+/// Future that resolves to the next item of the stream
+struct Next<'s, S>(&'s mut S);
+
+impl<S: Stream + Unpin> Future for Next<'_, S> {
+	type Output = Option<S::Item>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		Pin::new(&mut *self.0).poll_next(cx)
+	}
+}
+
+fn next(stream: &mut VideoCaptureStream) -> Option<Result<(videoio::Timestamp, Mat)>> {
+	block_on(Next(stream))
+}
+
+#[test]
+fn video_capture_stream() -> Result<()> {
+	let path = generate_video("opencv-rust-video-capture-stream.avi")?;
+	let cap = VideoCapture::from_file(path.to_str().unwrap(), videoio::CAP_ANY)?;
+	let mut stream = cap.into_stream(2, DropPolicy::Block)?;
+	let mut count = 0;
+	let mut last_ts = None;
+	while let Some(frame) = next(&mut stream) {
+		let (ts, frame) = frame?;
+		assert_eq!(FRAME_SIZE, frame.size()?);
+		if let Some(last_ts) = last_ts {
+			assert!(ts > last_ts);
+		}
+		last_ts = Some(ts);
+		count += 1;
+	}
+	assert_eq!(FRAMES, count);
+
+	// dropping the stream early stops the reader thread
+	let cap = VideoCapture::from_file(path.to_str().unwrap(), videoio::CAP_ANY)?;
+	let mut stream = cap.into_stream(1, DropPolicy::DropOldest)?;
+	assert!(next(&mut stream).is_some());
+	drop(stream);
+	std::fs::remove_file(path).ok();
+	Ok(())
+}